@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
 
 /// Accordion item
 #[derive(Debug, Clone)]
@@ -68,8 +69,9 @@ pub struct Accordion {
 }
 
 impl Accordion {
-    /// Create a new Accordion component
+    /// Create a new Accordion component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             items: Vec::new(),
@@ -77,14 +79,28 @@ impl Accordion {
             width: 400.0,
             item_height: 48.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            header_color: (249, 250, 251, 255),
-            border_color: (229, 231, 235, 255),
+            background_color: colors.surface,
+            header_color: colors.surface_variant,
+            border_color: colors.border,
             border_radius: 8.0,
             on_change: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.header_color = colors.surface_variant;
+        self.border_color = colors.border;
+    }
+
     /// Add an item
     pub fn add_item(mut self, id: impl Into<String>, title: impl Into<String>, content: impl Into<String>) -> Self {
         self.items.push(AccordionItem::new(id, title, content));
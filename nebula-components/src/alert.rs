@@ -2,7 +2,10 @@
 // Essential for showing important messages
 
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::accessibility::{AccessibilityAudit, ContrastSource};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{mix, Theme, ThemeColor, ThemeProvider};
+use nebula_core::{announce, Politeness};
 
 /// Alert severity
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,12 +41,15 @@ pub struct Alert {
     pub border_color: (u8, u8, u8, u8),
     pub border_width: f32,
     pub border_radius: f32,
+    pub politeness: Option<Politeness>, // None = by severity
     pub on_close: Option<Box<dyn Fn()>>,
 }
 
 impl Alert {
     /// Create a new Alert component
     pub fn new(message: impl Into<String>) -> Self {
+        let (background_color, text_color, border_color) =
+            Self::severity_colors(&ThemeProvider::current(), AlertSeverity::Info);
         Self {
             node_id: None,
             message: Signal::new(message.into()),
@@ -54,11 +60,12 @@ impl Alert {
             icon: None,
             width: 400.0,
             padding: 16.0,
-            background_color: (239, 246, 255, 255), // Light blue
-            text_color: (30, 64, 175, 255),
-            border_color: (191, 219, 254, 255),
+            background_color,
+            text_color,
+            border_color,
             border_width: 1.0,
             border_radius: 8.0,
+            politeness: None,
             on_close: None,
         }
     }
@@ -78,13 +85,38 @@ impl Alert {
     /// Set the severity
     pub fn severity(mut self, severity: AlertSeverity) -> Self {
         self.severity = severity;
-        let (bg, text, border) = Self::severity_colors(severity);
+        self.apply_theme(&ThemeProvider::current());
+        self
+    }
+
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let (bg, text, border) = Self::severity_colors(theme, self.severity);
         self.background_color = bg;
         self.text_color = text;
         self.border_color = border;
+    }
+
+    /// How screen readers announce the alert
+    pub fn politeness(mut self, politeness: Politeness) -> Self {
+        self.politeness = Some(politeness);
         self
     }
 
+    /// The politeness used when shown or changed (warnings and errors interrupt)
+    pub fn get_politeness(&self) -> Politeness {
+        self.politeness.unwrap_or(match self.severity {
+            AlertSeverity::Warning | AlertSeverity::Error => Politeness::Assertive,
+            _ => Politeness::Polite,
+        })
+    }
+
     /// Set closable
     pub fn closable(mut self, closable: bool) -> Self {
         self.closable = closable;
@@ -123,9 +155,12 @@ impl Alert {
         self.message.get()
     }
 
-    /// Set the message
+    /// Set the message (a visible alert announces the new message)
     pub fn set_message(&mut self, message: impl Into<String>) {
         self.message.set(message.into());
+        if self.is_visible() {
+            self.announce();
+        }
     }
 
     /// Get the title
@@ -138,9 +173,19 @@ impl Alert {
         self.title.set(title);
     }
 
-    /// Show the alert
+    /// Show the alert (and announce it to screen readers)
     pub fn show(&mut self) {
         self.visible.set(true);
+        self.announce();
+    }
+
+    /// Read the alert out: "title: message"
+    pub fn announce(&self) {
+        let text = match self.title.get() {
+            Some(title) => format!("{}: {}", title, self.message.get()),
+            None => self.message.get(),
+        };
+        announce(text, self.get_politeness());
     }
 
     /// Hide the alert
@@ -173,29 +218,21 @@ impl Alert {
         self.icon.is_some()
     }
 
-    /// Get severity colors (background, text, border)
-    fn severity_colors(severity: AlertSeverity) -> ((u8, u8, u8, u8), (u8, u8, u8, u8), (u8, u8, u8, u8)) {
-        match severity {
-            AlertSeverity::Info => (
-                (239, 246, 255, 255), // bg
-                (30, 64, 175, 255),   // text
-                (191, 219, 254, 255), // border
-            ),
-            AlertSeverity::Success => (
-                (240, 253, 244, 255), // bg
-                (22, 101, 52, 255),   // text
-                (187, 247, 208, 255), // border
-            ),
-            AlertSeverity::Warning => (
-                (254, 252, 232, 255), // bg
-                (133, 77, 14, 255),   // text
-                (253, 230, 138, 255), // border
-            ),
-            AlertSeverity::Error => (
-                (254, 242, 242, 255), // bg
-                (153, 27, 27, 255),   // text
-                (254, 202, 202, 255), // border
-            ),
+    /// Get severity colors (background, text, border) from a theme:
+    /// a light tint of the severity color with body text on top, or no
+    /// tint and a full-strength border in high contrast
+    fn severity_colors(theme: &Theme, severity: AlertSeverity) -> (ThemeColor, ThemeColor, ThemeColor) {
+        let colors = &theme.colors;
+        let accent = match severity {
+            AlertSeverity::Info => colors.primary,
+            AlertSeverity::Success => colors.success,
+            AlertSeverity::Warning => colors.warning,
+            AlertSeverity::Error => colors.danger,
+        };
+        if theme.high_contrast {
+            (colors.surface, colors.text, accent)
+        } else {
+            (mix(colors.surface, accent, 0.1), colors.text, mix(colors.surface, accent, 0.4))
         }
     }
 
@@ -241,9 +278,20 @@ impl Default for Alert {
     }
 }
 
+impl ContrastSource for Alert {
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+        let id = match self.node_id {
+            Some(node) => format!("Alert({:?})", node),
+            None => "Alert".to_string(),
+        };
+        audit.check_contrast(id, self.text_color, self.background_color, false);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nebula_core::theme::ThemeMode;
 
     #[test]
     fn alert_creation() {
@@ -259,6 +307,20 @@ mod tests {
         assert_eq!(alert.get_message(), "New message");
     }
 
+    #[test]
+    fn alert_changes_are_announced() {
+        nebula_core::take_announcements();
+        let mut alert = Alert::new("Disk almost full").title("Storage").severity(AlertSeverity::Warning);
+        alert.set_message("Disk full");
+        alert.hide();
+        alert.set_message("Hidden alerts stay quiet");
+
+        let announcements = nebula_core::take_announcements();
+        assert_eq!(announcements.len(), 1);
+        assert_eq!(announcements[0].text, "Storage: Disk full");
+        assert_eq!(announcements[0].politeness, Politeness::Assertive);
+    }
+
     #[test]
     fn alert_with_title() {
         let alert = Alert::new("Message").title("Title");
@@ -304,7 +366,7 @@ mod tests {
     fn alert_severities() {
         let alert = Alert::new("Test").severity(AlertSeverity::Success);
         assert_eq!(alert.severity, AlertSeverity::Success);
-        assert_eq!(alert.background_color, (240, 253, 244, 255));
+        assert_eq!(alert.background_color, (233, 249, 239, 255));
     }
 
     #[test]
@@ -316,10 +378,15 @@ mod tests {
 
     #[test]
     fn alert_severity_colors() {
-        let (bg, text, border) = Alert::severity_colors(AlertSeverity::Info);
-        assert_eq!(bg, (239, 246, 255, 255));
-        assert_eq!(text, (30, 64, 175, 255));
-        assert_eq!(border, (191, 219, 254, 255));
+        let (bg, text, border) = Alert::severity_colors(&Theme::light(), AlertSeverity::Info);
+        assert_eq!(bg, (235, 243, 254, 255));
+        assert_eq!(text, (0, 0, 0, 255));
+        assert_eq!(border, (177, 205, 251, 255));
+
+        // High contrast: no tint, the severity shows in the border
+        let (bg, text, border) = Alert::severity_colors(&Theme::high_contrast(ThemeMode::Dark), AlertSeverity::Error);
+        assert_eq!((bg, text), ((0, 0, 0, 255), (255, 255, 255, 255)));
+        assert_eq!(border, (255, 110, 110, 255));
     }
 
     #[test]
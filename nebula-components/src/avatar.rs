@@ -3,6 +3,9 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use crate::placeholder::{CrossFade, ImagePlaceholder, PlaceholderImage};
+use image::DynamicImage;
 
 /// Avatar size preset
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,34 +42,55 @@ pub struct Avatar {
     pub fallback_text: Signal<Option<String>>,
     pub size_preset: AvatarSize,
     pub custom_size: Option<f32>,
-    pub background_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub border_width: f32,
-    pub border_color: (u8, u8, u8, u8),
+    pub border_color: ThemeColor,
     pub show_status: bool,
-    pub status_color: (u8, u8, u8, u8),
+    pub status_color: ThemeColor,
     pub on_click: Option<Box<dyn Fn()>>,
+    /// Preview shown until the image is loaded
+    pub placeholder: Option<PlaceholderImage>,
+    pub fade: CrossFade,
 }
 
 impl Avatar {
-    /// Create a new Avatar component
+    /// Create a new Avatar component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             image: Signal::new(None),
             fallback_text: Signal::new(None),
             size_preset: AvatarSize::Medium,
             custom_size: None,
-            background_color: (156, 163, 175, 255), // Gray
-            text_color: (255, 255, 255, 255),
+            background_color: colors.text_muted,
+            text_color: colors.surface,
             border_width: 0.0,
-            border_color: (255, 255, 255, 255),
+            border_color: colors.surface,
             show_status: false,
-            status_color: (34, 197, 94, 255), // Green (online)
+            status_color: colors.success,
             on_click: None,
+            placeholder: None,
+            fade: CrossFade::default(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.text_muted;
+        self.text_color = colors.surface;
+        self.border_color = colors.surface;
+        self.status_color = colors.success;
+    }
+
     /// Set the image URL
     pub fn image(self, url: impl Into<String>) -> Self {
         self.image.set(Some(url.into()));
@@ -136,11 +160,33 @@ impl Avatar {
         self.image.get()
     }
 
-    /// Set the image URL
+    /// Set the image URL (shows the placeholder again until it loads)
     pub fn set_image(&mut self, url: Option<String>) {
+        self.fade.reset();
         self.image.set(url);
     }
 
+    /// Show a BlurHash or tiny preview while the image loads
+    pub fn placeholder(mut self, placeholder: ImagePlaceholder) -> Self {
+        self.placeholder = Some(PlaceholderImage::start(placeholder, 16, 16));
+        self
+    }
+
+    /// Decoded placeholder, if any and ready
+    pub fn placeholder_image(&self) -> Option<DynamicImage> {
+        self.placeholder.as_ref().and_then(PlaceholderImage::image)
+    }
+
+    /// The image finished loading; cross-fade it in
+    pub fn image_loaded(&mut self) {
+        self.fade.start();
+    }
+
+    /// Opacity to draw the image with (the placeholder gets the rest)
+    pub fn image_opacity(&self) -> f32 {
+        if self.placeholder.is_some() { self.fade.progress() } else { 1.0 }
+    }
+
     /// Get the fallback text
     pub fn get_fallback_text(&self) -> Option<String> {
         self.fallback_text.get()
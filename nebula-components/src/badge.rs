@@ -1,8 +1,14 @@
 // Badge Component - Small badge indicator for notifications and counts
 // Essential for showing counts, status, and notifications
 
+use nebula_core::animation::{MotionPreference, SpringAnimation};
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::accessibility::{AccessibilityAudit, ContrastSource};
 use nebula_core::signal::Signal;
+use nebula_core::theme::ThemeProvider;
+use nebula_gfx::draw_list::{DrawList, Rect};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 /// Badge variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -190,6 +196,165 @@ impl Default for Badge {
     }
 }
 
+/// Animation that draws the eye when an item's count goes up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Attention {
+    #[default]
+    None,
+    /// The badge pops bigger and springs back
+    Pulse,
+    /// The badge wiggles sideways
+    Shake,
+}
+
+/// Unread badge built into `NavItem` and `Tab` 🔴
+///
+/// Bound to a count Signal: hidden at zero, "99+" above `max`, or a plain
+/// dot. When the count goes up it can pulse or shake (skipped under
+/// reduced motion). Call `update` each frame and `paint` with the item's
+/// bounds; the badge sits on the top-right corner.
+#[derive(Clone)]
+pub struct ItemBadge {
+    pub count: Signal<u32>,
+    /// Show a dot instead of the number
+    pub dot: bool,
+    /// Larger counts show as "max+"
+    pub max: u32,
+    pub attention: Attention,
+    seen: Rc<Cell<u32>>,
+    spring: Rc<RefCell<Option<SpringAnimation>>>,
+}
+
+impl ItemBadge {
+    /// Badge showing `count`
+    pub fn new(count: &Signal<u32>) -> Self {
+        Self {
+            count: count.clone(),
+            dot: false,
+            max: 99,
+            attention: Attention::None,
+            seen: Rc::new(Cell::new(count.get())),
+            spring: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Dot shown while `count` is above zero
+    pub fn dot(count: &Signal<u32>) -> Self {
+        Self { dot: true, ..Self::new(count) }
+    }
+
+    /// Animate when the count goes up
+    pub fn attention(mut self, attention: Attention) -> Self {
+        self.attention = attention;
+        self
+    }
+
+    /// Set the largest count shown as a number
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Anything to show?
+    pub fn is_visible(&self) -> bool {
+        self.count.get() > 0
+    }
+
+    /// Text in the badge (None when hidden, empty for a dot)
+    pub fn label(&self) -> Option<String> {
+        match self.count.get() {
+            0 => None,
+            _ if self.dot => Some(String::new()),
+            count if count > self.max => Some(format!("{}+", self.max)),
+            count => Some(count.to_string()),
+        }
+    }
+
+    /// Start the attention animation if the count went up; advance it.
+    /// Returns true while animating.
+    pub fn update(&self, delta_time: f32) -> bool {
+        let count = self.count.get();
+        if count > self.seen.get() && !MotionPreference::is_reduced() {
+            let spring = match self.attention {
+                Attention::None => None,
+                Attention::Pulse => Some(SpringAnimation::new(1.6, 1.0).stiffness(400.0).damping(14.0)),
+                Attention::Shake => Some(SpringAnimation::new(6.0, 0.0).stiffness(900.0).damping(6.0)),
+            };
+            *self.spring.borrow_mut() = spring.map(|spring| spring.label("badge attention"));
+        }
+        self.seen.set(count);
+        let mut spring = self.spring.borrow_mut();
+        let running = spring.as_mut().is_some_and(|spring| spring.update(delta_time));
+        if !running {
+            *spring = None;
+        }
+        running
+    }
+
+    /// Attention animation running?
+    pub fn is_animating(&self) -> bool {
+        self.spring.borrow().is_some()
+    }
+
+    /// Draw on the top-right corner of `bounds`
+    pub fn paint(&self, list: &mut DrawList, bounds: Rect) {
+        let Some(label) = self.label() else {
+            return;
+        };
+        let theme = ThemeProvider::current();
+        let (x, y, width, _) = bounds;
+        let animated = self.spring.borrow().as_ref().map(SpringAnimation::value);
+        let (scale, offset) = match (self.attention, animated) {
+            (Attention::Pulse, Some(value)) => (value, 0.0),
+            (Attention::Shake, Some(value)) => (1.0, value),
+            _ => (1.0, 0.0),
+        };
+
+        let start = list.len();
+        let (badge_width, badge_height) = if self.dot {
+            (8.0, 8.0)
+        } else {
+            (((label.chars().count() as f32) * 7.0 + 8.0).max(16.0), 16.0)
+        };
+        let (left, top) = (x + width - badge_width / 2.0 - 4.0 + offset, y - badge_height / 2.0 + 4.0);
+        list.rounded_rect((left, top, badge_width, badge_height), badge_height / 2.0, theme.colors.danger);
+        if !self.dot {
+            list.text(label, left + 4.0, top + 2.0, 11.0, (255, 255, 255, 255));
+        }
+        if scale != 1.0 {
+            list.transform_since(start, (left + badge_width / 2.0, top + badge_height / 2.0), scale, 1.0);
+        }
+    }
+}
+
+impl std::fmt::Debug for ItemBadge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ItemBadge")
+            .field("count", &self.count.get())
+            .field("dot", &self.dot)
+            .field("max", &self.max)
+            .field("attention", &self.attention)
+            .finish()
+    }
+}
+
+/// Equal when showing the same thing
+impl PartialEq for ItemBadge {
+    fn eq(&self, other: &Self) -> bool {
+        self.count.get() == other.count.get() && self.dot == other.dot && self.max == other.max && self.attention == other.attention
+    }
+}
+
+impl ContrastSource for Badge {
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+        let id = match self.node_id {
+            Some(node) => format!("Badge({:?})", node),
+            None => "Badge".to_string(),
+        };
+        audit.check_contrast(id, self.text_color, self.background_color, false);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,6 +417,40 @@ mod tests {
         assert!(!badge.is_visible());
     }
 
+    #[test]
+    fn item_badge_follows_count_and_pulses() {
+        let unread = Signal::new(0);
+        let badge = ItemBadge::new(&unread).max(9).attention(Attention::Pulse);
+        assert_eq!(badge.label(), None);
+
+        unread.set(3);
+        assert_eq!(badge.label(), Some("3".to_string()));
+        assert!(badge.update(0.016));
+        // Going down doesn't restart it
+        while badge.update(0.016) {}
+        unread.set(2);
+        assert!(!badge.update(0.016));
+
+        unread.set(12);
+        assert_eq!(badge.label(), Some("9+".to_string()));
+        assert_eq!(ItemBadge::dot(&unread).label(), Some(String::new()));
+
+        let mut list = DrawList::new();
+        badge.paint(&mut list, (0.0, 0.0, 100.0, 40.0));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn item_badge_stays_still_under_reduced_motion() {
+        MotionPreference::set_override(Some(MotionPreference::Reduced));
+        let unread = Signal::new(0);
+        let badge = ItemBadge::new(&unread).attention(Attention::Shake);
+        unread.set(1);
+        assert!(!badge.update(0.016));
+        assert!(!badge.is_animating());
+        MotionPreference::set_override(None);
+    }
+
     #[test]
     fn badge_build_creates_node() {
         let mut engine = LayoutEngine::new();
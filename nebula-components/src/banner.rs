@@ -2,7 +2,9 @@
 // Essential for site-wide notifications and announcements
 
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::accessibility::{AccessibilityAudit, ContrastSource};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeColors, ThemeProvider};
 
 /// Banner position
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -44,15 +46,16 @@ pub struct Banner {
     pub width: f32,
     pub height: f32,
     pub padding: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub on_action: Option<Box<dyn Fn()>>,
     pub on_close: Option<Box<dyn Fn()>>,
 }
 
 impl Banner {
-    /// Create a new Banner component
+    /// Create a new Banner component (colors come from the current theme)
     pub fn new(message: impl Into<String>) -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             message: Signal::new(message.into()),
@@ -65,8 +68,8 @@ impl Banner {
             width: 0.0, // Full width
             height: 48.0,
             padding: 16.0,
-            background_color: (59, 130, 246, 255), // Blue
-            text_color: (255, 255, 255, 255),
+            background_color: colors.primary,
+            text_color: colors.on_primary,
             on_action: None,
             on_close: None,
         }
@@ -81,7 +84,7 @@ impl Banner {
     /// Set the variant
     pub fn variant(mut self, variant: BannerVariant) -> Self {
         self.variant = variant;
-        self.background_color = Self::variant_color(variant);
+        self.background_color = Self::variant_color(variant, &ThemeProvider::current().colors);
         self
     }
 
@@ -187,16 +190,28 @@ impl Banner {
         self.icon.is_some()
     }
 
-    /// Get variant color
-    fn variant_color(variant: BannerVariant) -> (u8, u8, u8, u8) {
+    /// Get variant color from the theme's status tokens
+    fn variant_color(variant: BannerVariant, colors: &ThemeColors) -> ThemeColor {
         match variant {
-            BannerVariant::Info => (59, 130, 246, 255),    // Blue
-            BannerVariant::Success => (34, 197, 94, 255),  // Green
-            BannerVariant::Warning => (251, 191, 36, 255), // Yellow
-            BannerVariant::Error => (220, 38, 38, 255),    // Red
+            BannerVariant::Info => colors.primary,
+            BannerVariant::Success => colors.success,
+            BannerVariant::Warning => colors.warning,
+            BannerVariant::Error => colors.danger,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.background_color = Self::variant_color(self.variant, &theme.colors);
+        self.text_color = theme.colors.on_primary;
+    }
+
     /// Build the banner layout
     pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
         let style = taffy::style::Style {
@@ -240,6 +255,16 @@ impl Default for Banner {
     }
 }
 
+impl ContrastSource for Banner {
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+        let id = match self.node_id {
+            Some(node) => format!("Banner({:?})", node),
+            None => "Banner".to_string(),
+        };
+        audit.check_contrast(id, self.text_color, self.background_color, false);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,7 +338,7 @@ mod tests {
     fn banner_variants() {
         let banner = Banner::new("Test").variant(BannerVariant::Success);
         assert_eq!(banner.variant, BannerVariant::Success);
-        assert_eq!(banner.background_color, (34, 197, 94, 255));
+        assert_eq!(banner.background_color, ThemeProvider::current().colors.success);
     }
 
     #[test]
@@ -338,10 +363,11 @@ mod tests {
 
     #[test]
     fn banner_variant_colors() {
-        assert_eq!(Banner::variant_color(BannerVariant::Info), (59, 130, 246, 255));
-        assert_eq!(Banner::variant_color(BannerVariant::Success), (34, 197, 94, 255));
-        assert_eq!(Banner::variant_color(BannerVariant::Warning), (251, 191, 36, 255));
-        assert_eq!(Banner::variant_color(BannerVariant::Error), (220, 38, 38, 255));
+        let colors = Theme::light().colors;
+        assert_eq!(Banner::variant_color(BannerVariant::Info, &colors), colors.primary);
+        assert_eq!(Banner::variant_color(BannerVariant::Success, &colors), colors.success);
+        assert_eq!(Banner::variant_color(BannerVariant::Warning, &colors), colors.warning);
+        assert_eq!(Banner::variant_color(BannerVariant::Error, &colors), colors.danger);
     }
 
     #[test]
@@ -372,4 +398,12 @@ mod tests {
         assert!(result.is_ok());
         assert!(banner.node_id.is_some());
     }
+
+    #[test]
+    fn banner_audit_contrast() {
+        let mut audit = AccessibilityAudit::new();
+        audit.set_enabled(true);
+        audit.audit(&Banner::new("Saved"));
+        assert_eq!(audit.checked_count(), 1);
+    }
 }
@@ -2,6 +2,11 @@
 // Essential for showing current location in navigation hierarchy
 
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use nebula_core::text::TextRenderer;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use std::cell::RefCell;
+use std::rc::Rc;
 
 /// Breadcrumb item
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +54,36 @@ impl BreadcrumbItem {
     }
 }
 
+/// One slot of a breadcrumb laid out to fit its width
+#[derive(Debug, Clone, PartialEq)]
+pub enum BreadcrumbSlot {
+    /// Item at this index, drawn at x with this width
+    Item { index: usize, x: f32, width: f32 },
+    /// "…" button; its dropdown lists the hidden item indices
+    Overflow { hidden: Vec<usize>, x: f32, width: f32 },
+}
+
+/// Items for a route path, one per prefix ("/", "/docs", "/docs/api")
+/// `label` turns a prefix into an item (None skips it); ids are the prefixes
+pub fn route_items(path: &str, label: &dyn Fn(&str) -> Option<BreadcrumbItem>) -> Vec<BreadcrumbItem> {
+    let mut prefix = String::new();
+    let mut prefixes = vec!["/".to_string()];
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        prefix.push('/');
+        prefix.push_str(segment);
+        prefixes.push(prefix.clone());
+    }
+    prefixes
+        .iter()
+        .filter_map(|prefix| {
+            label(prefix).map(|mut item| {
+                item.id = prefix.clone();
+                item
+            })
+        })
+        .collect()
+}
+
 /// Breadcrumb component - breadcrumb navigation for hierarchical navigation
 /// 
 /// # Example
@@ -69,17 +104,25 @@ pub struct Breadcrumb {
     pub height: f32,
     pub padding: f32,
     pub spacing: f32,
-    pub text_color: (u8, u8, u8, u8),
-    pub active_color: (u8, u8, u8, u8),
-    pub separator_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
-    pub background_color: (u8, u8, u8, u8),
+    pub text_color: ThemeColor,
+    pub active_color: ThemeColor,
+    pub separator_color: ThemeColor,
+    pub hover_color: ThemeColor,
+    pub background_color: ThemeColor,
     pub on_navigate: Option<Box<dyn Fn(&str)>>,
+    pub font_size: u32,
+    /// Width reserved for an item's icon (plus `spacing` after it)
+    pub icon_size: f32,
+    /// Overflow "…" dropdown open?
+    pub overflow_open: Signal<bool>,
+    /// Items rebuilt by the followed route, applied on the next `sync_route`
+    routed_items: Option<Rc<RefCell<Option<Vec<BreadcrumbItem>>>>>,
 }
 
 impl Breadcrumb {
-    /// Create a new Breadcrumb component
+    /// Create a new Breadcrumb component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             items: Vec::new(),
@@ -89,15 +132,34 @@ impl Breadcrumb {
             height: 40.0,
             padding: 8.0,
             spacing: 8.0,
-            text_color: (100, 100, 100, 255),
-            active_color: (0, 0, 0, 255),
-            separator_color: (150, 150, 150, 255),
-            hover_color: (59, 130, 246, 255), // Blue
+            text_color: colors.text_muted,
+            active_color: colors.text,
+            separator_color: colors.disabled,
+            hover_color: colors.primary,
             background_color: (255, 255, 255, 0), // Transparent
             on_navigate: None,
+            font_size: 14,
+            icon_size: 16.0,
+            overflow_open: Signal::new(false),
+            routed_items: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.text_color = colors.text_muted;
+        self.active_color = colors.text;
+        self.separator_color = colors.disabled;
+        self.hover_color = colors.primary;
+    }
+
     /// Set the separator
     pub fn separator(mut self, separator: impl Into<String>) -> Self {
         self.separator = separator.into();
@@ -182,6 +244,18 @@ impl Breadcrumb {
         self
     }
 
+    /// Add an item with an icon
+    pub fn add_item_with_icon(mut self, label: impl Into<String>, id: impl Into<String>, icon: impl Into<String>) -> Self {
+        self.items.push(BreadcrumbItem::new(label, id).with_icon(icon));
+        self
+    }
+
+    /// Set the label font size
+    pub fn font_size(mut self, size: u32) -> Self {
+        self.font_size = size;
+        self
+    }
+
     /// Set all items at once
     pub fn items(mut self, items: Vec<BreadcrumbItem>) -> Self {
         self.items = items;
@@ -197,8 +271,40 @@ impl Breadcrumb {
         self
     }
 
+    /// Mirror the router's active route (a path signal such as "/docs/api")
+    ///
+    /// The items are rebuilt from the path on every change (see
+    /// `route_items`), so the trail can't fall out of sync with navigation.
+    /// Item ids are route prefixes, so `on_navigate` receives the path to go to.
+    pub fn follow_route<F>(mut self, route: &Signal<String>, label: F) -> Self
+    where
+        F: Fn(&str) -> Option<BreadcrumbItem> + 'static,
+    {
+        self.items = route_items(&route.get(), &label);
+        let pending = Rc::new(RefCell::new(None));
+        let sink = pending.clone();
+        route.subscribe(move |path: &String| {
+            *sink.borrow_mut() = Some(route_items(path, &label));
+        });
+        self.routed_items = Some(pending);
+        self
+    }
+
+    /// Apply the latest route (done by `layout_items` and `navigate_to`)
+    /// Returns true if the items changed
+    pub fn sync_route(&mut self) -> bool {
+        let Some(items) = self.routed_items.as_ref().and_then(|pending| pending.borrow_mut().take()) else {
+            return false;
+        };
+        self.overflow_open.set(false);
+        self.items = items;
+        true
+    }
+
     /// Navigate to an item by index
     pub fn navigate_to(&mut self, index: usize) {
+        self.sync_route();
+        self.overflow_open.set(false);
         if index < self.items.len() && !self.items[index].disabled {
             if let Some(ref callback) = self.on_navigate {
                 callback(&self.items[index].id);
@@ -269,6 +375,67 @@ impl Breadcrumb {
         }
     }
 
+    /// Fit the trail into `available_width`, measuring labels with the renderer
+    pub fn layout_items(&mut self, available_width: f32, renderer: &mut TextRenderer) -> Vec<BreadcrumbSlot> {
+        let size = self.font_size;
+        self.layout_items_with(available_width, |text| renderer.measure_text(text, size))
+    }
+
+    /// Fit the trail into `available_width` with a custom text measure
+    ///
+    /// When everything doesn't fit, middle items collapse into one "…"
+    /// slot starting next to the first item, so the root and the current
+    /// page stay visible. `max_items` still applies.
+    pub fn layout_items_with(&mut self, available_width: f32, mut measure: impl FnMut(&str) -> f32) -> Vec<BreadcrumbSlot> {
+        self.sync_route();
+        let count = self.items.len();
+        if count == 0 {
+            return Vec::new();
+        }
+        let widths: Vec<f32> = self
+            .items
+            .iter()
+            .map(|item| measure(&item.label) + item.icon.as_ref().map_or(0.0, |_| self.icon_size + self.spacing))
+            .collect();
+        let gap = measure(&self.separator) + self.spacing * 2.0;
+        let ellipsis = measure("…");
+        let available = available_width - self.padding * 2.0;
+
+        // Shown items: first, then the last `tail` items, with "…" between when hidden
+        let fits = |tail: usize| {
+            let hidden = count - 1 - tail;
+            let shown = widths[0] + widths[count - tail..].iter().sum::<f32>();
+            let slots = 1 + tail + usize::from(hidden > 0);
+            let overflow = if hidden > 0 { ellipsis } else { 0.0 };
+            shown + overflow + gap * (slots - 1) as f32 <= available
+        };
+        let limit = self.max_items.filter(|&max| max >= 2).map_or(count - 1, |max| (max - 1).min(count - 1));
+        let tail = if count == 1 { 0 } else { (1..=limit).rev().find(|&tail| fits(tail)).unwrap_or(1) };
+
+        let hidden: Vec<usize> = (1..count - tail).collect();
+        let mut shown: Vec<(Option<usize>, f32)> = vec![(Some(0), widths[0])];
+        if !hidden.is_empty() {
+            shown.push((None, ellipsis));
+        }
+        shown.extend((count - tail..count).filter(|&index| index > 0).map(|index| (Some(index), widths[index])));
+
+        let mut x = self.padding;
+        let mut slots = Vec::with_capacity(shown.len());
+        for (index, width) in shown {
+            slots.push(match index {
+                Some(index) => BreadcrumbSlot::Item { index, x, width },
+                None => BreadcrumbSlot::Overflow { hidden: hidden.clone(), x, width },
+            });
+            x += width + gap;
+        }
+        slots
+    }
+
+    /// Open or close the "…" dropdown
+    pub fn toggle_overflow(&mut self) {
+        self.overflow_open.set(!self.overflow_open.get());
+    }
+
     /// Build the breadcrumb layout
     pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
         let style = taffy::style::Style {
@@ -511,6 +678,51 @@ mod tests {
         assert!(breadcrumb.node_id.is_some());
     }
 
+    #[test]
+    fn breadcrumb_collapses_middle_items_to_fit() {
+        let mut breadcrumb = Breadcrumb::new()
+            .padding(0.0)
+            .spacing(0.0)
+            .add_item_with_icon("Home", "home", "🏠")
+            .add_item("Products", "products")
+            .add_item("Electronics", "electronics")
+            .add_item("Phones", "phones");
+        // 10px per character, separator 10px, icon 16px
+        let measure = |text: &str| text.chars().count() as f32 * 10.0;
+
+        let slots = breadcrumb.layout_items_with(1000.0, measure);
+        assert_eq!(slots.len(), 4);
+        assert!(matches!(slots[1], BreadcrumbSlot::Item { index: 1, x, .. } if x == 66.0));
+
+        // Home(56) + …(10) + Phones(60) + 2 separators(20) = 146
+        let slots = breadcrumb.layout_items_with(150.0, measure);
+        assert_eq!(slots.len(), 3);
+        assert!(matches!(&slots[1], BreadcrumbSlot::Overflow { hidden, .. } if *hidden == vec![1, 2]));
+        assert!(matches!(slots[2], BreadcrumbSlot::Item { index: 3, .. }));
+    }
+
+    #[test]
+    fn breadcrumb_follows_route() {
+        use std::sync::{Arc, Mutex};
+
+        let route = Signal::new("/docs/api".to_string());
+        let navigated = Arc::new(Mutex::new(String::new()));
+        let navigated_clone = navigated.clone();
+        let mut breadcrumb = Breadcrumb::new()
+            .on_navigate(move |id| *navigated_clone.lock().unwrap() = id.to_string())
+            .follow_route(&route, |prefix| {
+                let label = prefix.rsplit('/').next().filter(|segment| !segment.is_empty()).unwrap_or("Home");
+                Some(BreadcrumbItem::new(label, ""))
+            });
+        assert_eq!(breadcrumb.get_current_item().unwrap().id, "/docs/api");
+
+        route.set("/blog".to_string());
+        breadcrumb.navigate_to(0);
+        assert_eq!(*navigated.lock().unwrap(), "/");
+        assert_eq!(breadcrumb.item_count(), 2);
+        assert_eq!(breadcrumb.get_current_item().unwrap().label, "blog");
+    }
+
     #[test]
     fn breadcrumb_item_disabled() {
         let item = BreadcrumbItem::disabled("Disabled", "disabled");
@@ -1,7 +1,14 @@
-use nebula_core::Signal;
+use nebula_core::{AccessibilityAudit, Analytics, ContrastSource, EventQueue, FocusKey, Focusable, LayoutEngine, NodeId, Signal, TextMeasure, ThemeColor, ThemeProvider};
+use nebula_gfx::draw_list::{DrawList, Rect};
 use tracing::info;
 use std::rc::Rc;
 
+use super::style::ComputedStyle;
+use super::text::estimate_text_width;
+use super::events::ButtonEvent;
+use super::shortcuts::ShortcutRegistry;
+use super::widget::{Widget, WidgetEvent};
+
 /// Button component - Interactive, reactive, beautiful! 🔘
 /// 
 /// This is a REAL component that will work on ANY hardware!
@@ -12,14 +19,32 @@ use std::rc::Rc;
 pub struct Button {
     /// Button label
     pub label: String,
+    /// Reactive label (e.g. a translation); wins over `label` when set
+    pub label_signal: Option<Signal<String>>,
     /// Button position (x, y)
     pub position: (f32, f32),
     /// Button size (width, height)
     pub size: (f32, f32),
+    /// Size to the label instead of `size` (which stays the minimum height)
+    pub fit_content: bool,
     /// Is the button currently pressed?
     pub is_pressed: Signal<bool>,
+    /// Waiting for an async action (spinner, clicks ignored)
+    pub loading: Signal<bool>,
+    /// Clicks ignored, drawn dimmed
+    pub disabled: Signal<bool>,
+    /// Stable name for tests and analytics
+    pub test_id: Option<String>,
+    /// Tooltip text (defaults to the label when a shortcut hint is shown)
+    pub tooltip: Option<String>,
+    /// Action id the button triggers, for shortcut lookups
+    pub action: Option<String>,
+    /// Shortcut hint from a ShortcutRegistry ("Ctrl+S")
+    pub shortcut_hint: Option<String>,
     /// Click handler
     on_click: Option<Rc<dyn Fn()>>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<ButtonEvent>>,
 }
 
 impl Button {
@@ -27,10 +52,19 @@ impl Button {
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
+            label_signal: None,
             position: (0.0, 0.0),
             size: (100.0, 40.0),
+            fit_content: false,
             is_pressed: Signal::new(false),
+            loading: Signal::new(false),
+            disabled: Signal::new(false),
+            test_id: None,
+            tooltip: None,
+            action: None,
+            shortcut_hint: None,
             on_click: None,
+            events: None,
         }
     }
 
@@ -46,6 +80,12 @@ impl Button {
         self
     }
 
+    /// Grow or shrink to fit the label (plus padding) when laid out
+    pub fn fit_content(mut self, fit: bool) -> Self {
+        self.fit_content = fit;
+        self
+    }
+
     /// Set click handler
     pub fn on_click<F>(mut self, handler: F) -> Self
     where
@@ -55,10 +95,83 @@ impl Button {
         self
     }
 
+    /// Emit `ButtonEvent::Clicked` into a queue (alongside `on_click`)
+    pub fn events(mut self, events: &EventQueue<ButtonEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    /// Name the button for tests and analytics (clicks are reported as interactions)
+    pub fn test_id(mut self, test_id: impl Into<String>) -> Self {
+        self.test_id = Some(test_id.into());
+        self
+    }
+
+    /// Set the tooltip
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Name the action this button runs (its shortcut shows in the tooltip)
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Look the button's shortcut up in a registry
+    pub fn shortcuts(mut self, shortcuts: &ShortcutRegistry) -> Self {
+        self.shortcut_hint = self.action.as_deref().and_then(|action| shortcuts.hint_for(action));
+        self
+    }
+
+    /// Tooltip with the shortcut hint, e.g. "Save (Ctrl+S)"
+    pub fn tooltip_text(&self) -> Option<String> {
+        match (&self.tooltip, &self.shortcut_hint) {
+            (Some(text), Some(hint)) => Some(format!("{} ({})", text, hint)),
+            (None, Some(hint)) => Some(format!("{} ({})", self.current_label(), hint)),
+            (text, None) => text.clone(),
+        }
+    }
+
+    /// Follow a label signal, e.g. `t!("save")` from nebula-i18n
+    pub fn bind_label(mut self, label: Signal<String>) -> Self {
+        self.label_signal = Some(label);
+        self
+    }
+
+    /// The label to show right now
+    pub fn current_label(&self) -> String {
+        match self.label_signal {
+            Some(ref label) => label.get(),
+            None => self.label.clone(),
+        }
+    }
+
+    /// Follow a loading signal, e.g. `Mutation::pending`
+    pub fn bind_loading(mut self, loading: Signal<bool>) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Follow a disabled signal
+    pub fn bind_disabled(mut self, disabled: Signal<bool>) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Can the button be clicked right now?
+    pub fn is_interactive(&self) -> bool {
+        !self.loading.get() && !self.disabled.get()
+    }
+
     /// Handle mouse down event
     pub fn handle_mouse_down(&self, mouse_x: f32, mouse_y: f32) -> bool {
+        if !self.is_interactive() {
+            return false;
+        }
         if self.is_point_inside(mouse_x, mouse_y) {
-            info!("🔘 Button '{}' pressed!", self.label);
+            info!("🔘 Button '{}' pressed!", self.current_label());
             self.is_pressed.set(true);
             true
         } else {
@@ -71,18 +184,37 @@ impl Button {
         if self.is_pressed.get() {
             self.is_pressed.set(false);
             
-            // Trigger click if mouse is still inside
-            if self.is_point_inside(mouse_x, mouse_y) {
-                info!("🔘 Button '{}' clicked!", self.label);
-                if let Some(handler) = &self.on_click {
-                    handler();
-                }
+            // Trigger click if mouse is still inside (and nothing started loading meanwhile)
+            if self.is_point_inside(mouse_x, mouse_y) && self.is_interactive() {
+                self.fire_click();
                 return true;
             }
         }
         false
     }
 
+    /// Click from the keyboard (Enter / Space)
+    pub fn click(&self) {
+        if !self.is_interactive() {
+            return;
+        }
+        self.fire_click();
+    }
+
+    fn fire_click(&self) {
+        info!("🔘 Button '{}' clicked!", self.current_label());
+        if let Some(ref test_id) = self.test_id {
+            Analytics::interaction(test_id, "click");
+        }
+        if let Some(handler) = &self.on_click {
+            handler();
+        }
+        if let Some(ref events) = self.events {
+            let key = self.action.clone().or_else(|| self.test_id.clone()).unwrap_or_else(|| self.current_label());
+            events.emit(ButtonEvent::Clicked(key));
+        }
+    }
+
     /// Check if a point is inside the button
     pub fn is_point_inside(&self, x: f32, y: f32) -> bool {
         let (bx, by) = self.position;
@@ -97,6 +229,126 @@ impl Button {
     }
 }
 
+impl Focusable for Button {
+    fn handle_key(&self, key: FocusKey, _shift: bool) -> bool {
+        if matches!(key, FocusKey::Enter | FocusKey::Space) {
+            self.click();
+            return true;
+        }
+        false
+    }
+
+    fn focus_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        Some(self.bounds())
+    }
+}
+
+impl Widget for Button {
+    fn build_node(&mut self, engine: &mut LayoutEngine, _children: &[NodeId]) -> Result<NodeId, String> {
+        if self.fit_content {
+            let font_size = ThemeProvider::current().font_size.round() as u32;
+            let measure = TextMeasure::new(self.current_label(), font_size).padding(font_size as f32 * 2.0, 0.0).no_wrap();
+            return engine
+                .new_text_leaf(
+                    taffy::style::Style {
+                        min_size: taffy::geometry::Size {
+                            width: taffy::style::Dimension::Auto,
+                            height: taffy::style::Dimension::Length(self.size.1),
+                        },
+                        flex_shrink: 0.0,
+                        ..Default::default()
+                    },
+                    measure,
+                )
+                .map_err(|e| format!("Failed to create button node: {:?}", e));
+        }
+        engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.size.0),
+                    height: taffy::style::Dimension::Length(self.size.1),
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create button node: {:?}", e))
+    }
+
+    fn paint(&self, bounds: Rect, list: &mut DrawList) {
+        self.paint_styled(bounds, &ComputedStyle::default(), list);
+    }
+
+    fn paint_styled(&self, bounds: Rect, style: &ComputedStyle, list: &mut DrawList) {
+        let theme = ThemeProvider::current();
+        // A Style background is already painted by the tree; otherwise use the theme
+        if style.background.is_none() {
+            let color = if self.disabled.get() {
+                theme.colors.disabled
+            } else if self.is_pressed.get() || self.loading.get() {
+                theme.colors.primary_pressed
+            } else {
+                theme.colors.primary
+            };
+            list.rounded_rect(bounds, theme.radius.md, color);
+        }
+        let font_size = style.font_size.unwrap_or(theme.font_size);
+        let color = if self.disabled.get() {
+            theme.colors.text_muted
+        } else {
+            style.text_color.unwrap_or(theme.colors.on_primary)
+        };
+        let label = self.current_label();
+        let text_width = estimate_text_width(&label, font_size);
+        // The spinner sits before the label, so the pair stays centered
+        let spinner = if self.loading.get() { font_size * 1.4 } else { 0.0 };
+        let x = bounds.0 + (bounds.2 - text_width - spinner) / 2.0;
+        if self.loading.get() {
+            paint_spinner(list, x + font_size * 0.5, bounds.1 + bounds.3 / 2.0, font_size * 0.5, color);
+        }
+        let y = bounds.1 + (bounds.3 - font_size * 1.2) / 2.0;
+        list.text(label, x + spinner, y, font_size, color);
+    }
+
+    fn event(&mut self, event: &WidgetEvent, bounds: Rect) -> bool {
+        // Layout decides where the button is
+        self.position = (bounds.0, bounds.1);
+        self.size = (bounds.2, bounds.3);
+        match *event {
+            WidgetEvent::PointerDown { x, y } => self.handle_mouse_down(x, y),
+            WidgetEvent::PointerUp { x, y } => self.handle_mouse_up(x, y),
+            WidgetEvent::PointerMove { .. } => false,
+        }
+    }
+}
+
+/// Ring of dots with a fading tail, turning with the clock
+fn paint_spinner(list: &mut DrawList, cx: f32, cy: f32, radius: f32, color: ThemeColor) {
+    const DOTS: usize = 8;
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let head = (millis / 100) as usize % DOTS;
+    let dot = (radius * 0.35).max(1.5);
+    for i in 0..DOTS {
+        let angle = i as f32 / DOTS as f32 * std::f32::consts::TAU;
+        let age = (head + DOTS - i) % DOTS;
+        let alpha = (color.3 as f32 * (1.0 - age as f32 / DOTS as f32)) as u8;
+        let (x, y) = (cx + angle.cos() * radius, cy + angle.sin() * radius);
+        list.rounded_rect((x - dot / 2.0, y - dot / 2.0, dot, dot), dot / 2.0, (color.0, color.1, color.2, alpha));
+    }
+}
+
+impl ContrastSource for Button {
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+        let theme = ThemeProvider::current();
+        let id = format!("Button({})", self.test_id.clone().unwrap_or_else(|| self.current_label()));
+        // Disabled buttons are exempt from WCAG contrast, so only the live states are checked
+        audit.check_contrast(id.clone(), theme.colors.on_primary, theme.colors.primary, false);
+        audit.check_contrast(format!("{}.pressed", id), theme.colors.on_primary, theme.colors.primary_pressed, false);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +364,15 @@ mod tests {
         assert_eq!(button.is_pressed.get(), false);
     }
 
+    #[test]
+    fn button_follows_bound_label() {
+        let label = Signal::new("Save".to_string());
+        let button = Button::new("").bind_label(label.clone());
+        assert_eq!(button.current_label(), "Save");
+        label.set("Speichern".to_string());
+        assert_eq!(button.current_label(), "Speichern");
+    }
+
     #[test]
     fn button_builder_pattern() {
         let button = Button::new("Test")
@@ -210,6 +471,32 @@ mod tests {
         assert_eq!(h, 50.0);
     }
 
+    #[test]
+    fn loading_and_disabled_ignore_clicks() {
+        let clicks = Rc::new(RefCell::new(0));
+        let counter = clicks.clone();
+        let pending = Signal::new(true);
+        let button = Button::new("Save")
+            .size(100.0, 40.0)
+            .bind_loading(pending.clone())
+            .on_click(move || *counter.borrow_mut() += 1);
+
+        assert!(!button.handle_mouse_down(50.0, 20.0));
+        button.click();
+        assert_eq!(*clicks.borrow(), 0);
+
+        pending.set(false);
+        button.click();
+        button.disabled.set(true);
+        button.handle_key(FocusKey::Enter, false);
+        assert_eq!(*clicks.borrow(), 1);
+
+        let mut list = DrawList::new();
+        pending.set(true);
+        button.paint((0.0, 0.0, 100.0, 40.0), &mut list);
+        assert!(list.len() > 2, "spinner dots drawn");
+    }
+
     #[test]
     fn button_clone() {
         let button1 = Button::new("Test");
@@ -218,4 +505,29 @@ mod tests {
         assert_eq!(button1.label, button2.label);
         assert_eq!(button1.position, button2.position);
     }
+
+    #[test]
+    fn fit_content_sizes_to_the_label() {
+        let mut engine = LayoutEngine::new();
+        let mut short = Button::new("OK").fit_content(true);
+        let mut long = Button::new("Export all selected invoices").fit_content(true);
+        let row = [short.build_node(&mut engine, &[]).unwrap(), long.build_node(&mut engine, &[]).unwrap()];
+        let root = engine.create_hstack(&row).unwrap();
+        engine
+            .compute_layout(root, taffy::geometry::Size { width: taffy::style::AvailableSpace::MaxContent, height: taffy::style::AvailableSpace::MaxContent })
+            .unwrap();
+
+        let short_size = engine.get_layout(row[0]).unwrap().size;
+        let long_size = engine.get_layout(row[1]).unwrap().size;
+        assert!(short_size.width < 100.0 && long_size.width > 100.0);
+        assert_eq!(short_size.height, 40.0); // `size` height is the minimum
+    }
+
+    #[test]
+    fn button_audit_contrast() {
+        let mut audit = AccessibilityAudit::new();
+        audit.set_enabled(true);
+        audit.audit(&Button::new("Save"));
+        assert_eq!(audit.checked_count(), 2);
+    }
 }
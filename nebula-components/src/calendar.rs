@@ -1,11 +1,69 @@
 // Calendar Component - Full calendar for date selection
 // Essential for date pickers and scheduling
 
+use std::cell::Cell;
+
+use nebula_core::events::EventQueue;
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{mix, Theme, ThemeColor, ThemeProvider};
+use crate::events::CalendarEvent;
+use crate::locale::{weekday, DateFormat, Locale};
+
+thread_local! {
+    static FIXED_NOW: Cell<Option<i64>> = const { Cell::new(None) };
+    static UTC_OFFSET: Cell<Option<i64>> = const { Cell::new(None) };
+}
+
+/// Date Clock - Where "today" comes from 🕰️
+///
+/// Reads the system clock; tests and screenshots pin it with `set_now`.
+/// Dates are taken in the OS time zone unless `set_utc_offset` overrides it.
+pub struct DateClock;
+
+impl DateClock {
+    /// Current Unix time in seconds
+    pub fn now() -> i64 {
+        FIXED_NOW.with(|fixed| fixed.get()).unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0)
+        })
+    }
+
+    /// Pin the clock to a Unix time (None goes back to the system clock)
+    pub fn set_now(seconds: Option<i64>) {
+        FIXED_NOW.with(|fixed| fixed.set(seconds));
+    }
+
+    /// Offset from UTC used for "today", in seconds
+    ///
+    /// The OS time zone's offset at `now()` (DST included) unless overridden.
+    pub fn utc_offset() -> i64 {
+        UTC_OFFSET.with(|offset| offset.get()).unwrap_or_else(|| {
+            use chrono::{Local, Offset, TimeZone};
+            Local
+                .timestamp_opt(Self::now(), 0)
+                .single()
+                .map(|local| local.offset().fix().local_minus_utc() as i64)
+                .unwrap_or(0)
+        })
+    }
+
+    /// Override the offset from UTC (e.g. Some(3600) for CET; None follows the OS again)
+    pub fn set_utc_offset(seconds: Option<i64>) {
+        UTC_OFFSET.with(|offset| offset.set(seconds));
+    }
+
+    /// Today's date at the configured offset
+    pub fn today() -> CalendarDate {
+        CalendarDate::from_unix_timestamp(Self::now(), Self::utc_offset())
+    }
+}
 
 /// Simple date representation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CalendarDate {
     pub year: i32,
     pub month: u8,  // 1-12
@@ -18,9 +76,50 @@ impl CalendarDate {
         Self { year, month, day }
     }
 
-    /// Get today's date (simplified - would use system time in real impl)
+    /// Get today's date (from `DateClock`)
     pub fn today() -> Self {
-        Self::new(2025, 11, 22)
+        DateClock::today()
+    }
+
+    /// Date of a Unix timestamp (seconds), shifted by a UTC offset
+    pub fn from_unix_timestamp(seconds: i64, utc_offset_seconds: i64) -> Self {
+        Self::from_days((seconds + utc_offset_seconds).div_euclid(86_400))
+    }
+
+    /// Date a number of days after 1970-01-01
+    pub fn from_days(days: i64) -> Self {
+        // Civil-from-days (Howard Hinnant's algorithm)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        Self::new(year as i32, month, day)
+    }
+
+    /// Days since 1970-01-01 (negative before)
+    pub fn to_days(&self) -> i64 {
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let month = self.month as i64;
+        let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Date `days` later (or earlier, if negative)
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Days from `other` to this date (positive when this is later)
+    pub fn diff(&self, other: &CalendarDate) -> i64 {
+        self.to_days() - other.to_days()
     }
 
     /// Check if this is today
@@ -28,6 +127,16 @@ impl CalendarDate {
         *self == Self::today()
     }
 
+    /// Format for people, in a locale (`format` stays ISO for storage and logs)
+    pub fn format_with(&self, locale: &Locale, format: &DateFormat) -> String {
+        locale.format_parts(self.year, self.month, self.day, format)
+    }
+
+    /// Day of the week (0 = Sunday)
+    pub fn weekday(&self) -> u8 {
+        weekday(self.year, self.month, self.day)
+    }
+
     /// Get the first day of the month
     pub fn first_of_month(&self) -> Self {
         Self::new(self.year, self.month, 1)
@@ -88,6 +197,14 @@ pub enum CalendarView {
     Decade,
 }
 
+/// Single dates or a start/end range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarSelection {
+    Single,
+    /// First click sets the start, second the end
+    Range,
+}
+
 /// Calendar component - full calendar for date selection
 /// 
 /// # Example
@@ -100,49 +217,88 @@ pub struct Calendar {
     pub node_id: Option<NodeId>,
     pub current_date: Signal<CalendarDate>,
     pub selected_date: Signal<Option<CalendarDate>>,
+    pub selection_mode: CalendarSelection,
+    /// Range mode: the ends picked so far
+    pub range_start: Signal<Option<CalendarDate>>,
+    pub range_end: Signal<Option<CalendarDate>>,
+    /// Day under the pointer (previews the range before the second click)
+    pub hover_date: Signal<Option<CalendarDate>>,
+    pub range_color: ThemeColor,
     pub view: Signal<CalendarView>,
     pub min_date: Option<CalendarDate>,
     pub max_date: Option<CalendarDate>,
     pub disabled_dates: Vec<CalendarDate>,
     pub show_week_numbers: bool,
     pub first_day_of_week: u8, // 0 = Sunday, 1 = Monday
+    /// Month and weekday names
+    pub locale: Locale,
     pub cell_size: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub header_color: (u8, u8, u8, u8),
-    pub today_color: (u8, u8, u8, u8),
-    pub selected_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub header_color: ThemeColor,
+    pub today_color: ThemeColor,
+    pub selected_color: ThemeColor,
+    pub disabled_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub on_select: Option<Box<dyn Fn(CalendarDate)>>,
+    pub on_range_select: Option<Box<dyn Fn(CalendarDate, CalendarDate)>>,
     pub on_month_change: Option<Box<dyn Fn(i32, u8)>>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<CalendarEvent>>,
 }
 
 impl Calendar {
-    /// Create a new Calendar component
+    /// Create a new Calendar component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         let today = CalendarDate::today();
         Self {
             node_id: None,
             current_date: Signal::new(today),
             selected_date: Signal::new(None),
+            selection_mode: CalendarSelection::Single,
+            range_start: Signal::new(None),
+            range_end: Signal::new(None),
+            hover_date: Signal::new(None),
+            range_color: colors.selection,
             view: Signal::new(CalendarView::Month),
             min_date: None,
             max_date: None,
             disabled_dates: Vec::new(),
             show_week_numbers: false,
             first_day_of_week: 0, // Sunday
+            locale: Locale::default(),
             cell_size: 40.0,
-            background_color: (255, 255, 255, 255),
-            header_color: (250, 250, 250, 255),
-            today_color: (59, 130, 246, 50), // Light blue
-            selected_color: (59, 130, 246, 255), // Blue
-            disabled_color: (200, 200, 200, 255),
-            text_color: (0, 0, 0, 255),
+            background_color: colors.surface,
+            header_color: colors.surface_variant,
+            today_color: mix(colors.surface, colors.primary, 0.2),
+            selected_color: colors.primary,
+            disabled_color: colors.disabled,
+            text_color: colors.text,
             on_select: None,
+            on_range_select: None,
             on_month_change: None,
+            events: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.range_color = colors.selection;
+        self.background_color = colors.surface;
+        self.header_color = colors.surface_variant;
+        self.today_color = mix(colors.surface, colors.primary, 0.2);
+        self.selected_color = colors.primary;
+        self.disabled_color = colors.disabled;
+        self.text_color = colors.text;
+    }
+
     /// Set the selected date
     pub fn selected_date(mut self, date: CalendarDate) -> Self {
         self.selected_date.set(Some(date));
@@ -180,6 +336,13 @@ impl Calendar {
         self
     }
 
+    /// Use a locale's month and weekday names and its first day of the week
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.first_day_of_week = locale.first_day_of_week;
+        self.locale = locale;
+        self
+    }
+
     /// Set cell size
     pub fn cell_size(mut self, size: f32) -> Self {
         self.cell_size = size;
@@ -207,6 +370,21 @@ impl Calendar {
         self
     }
 
+    /// Pick single dates or ranges
+    pub fn selection_mode(mut self, mode: CalendarSelection) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Set the range callback (fires when the end is picked)
+    pub fn on_range_select<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(CalendarDate, CalendarDate) + 'static,
+    {
+        self.on_range_select = Some(Box::new(callback));
+        self
+    }
+
     /// Set the month change callback
     pub fn on_month_change<F>(mut self, callback: F) -> Self
     where
@@ -216,23 +394,94 @@ impl Calendar {
         self
     }
 
+    /// Emit typed events into a queue (alongside any callbacks)
+    pub fn events(mut self, events: &EventQueue<CalendarEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    fn emit(&self, event: CalendarEvent) {
+        if let Some(ref events) = self.events {
+            events.emit(event);
+        }
+    }
+
     /// Select a date
     pub fn select_date(&mut self, date: CalendarDate) {
         if self.is_date_disabled(&date) {
             return;
         }
 
+        if self.selection_mode == CalendarSelection::Range {
+            self.select_range_end(date);
+            return;
+        }
+
         self.selected_date.set(Some(date));
         self.current_date.set(date);
 
         if let Some(ref callback) = self.on_select {
             callback(date);
         }
+        self.emit(CalendarEvent::DateSelected(date));
+    }
+
+    fn select_range_end(&mut self, date: CalendarDate) {
+        match (self.range_start.get(), self.range_end.get()) {
+            (Some(start), None) => {
+                let (start, end) = if date < start { (date, start) } else { (start, date) };
+                if self.range_has_disabled_day(start, end) {
+                    return;
+                }
+                self.range_start.set(Some(start));
+                self.range_end.set(Some(end));
+                self.hover_date.set(None);
+                if let Some(ref callback) = self.on_range_select {
+                    callback(start, end);
+                }
+                self.emit(CalendarEvent::RangeSelected { start, end });
+            }
+            // Nothing picked yet, or starting over after a full range
+            _ => {
+                self.range_start.set(Some(date));
+                self.range_end.set(None);
+            }
+        }
+    }
+
+    fn range_has_disabled_day(&self, start: CalendarDate, end: CalendarDate) -> bool {
+        (0..=end.diff(&start)).any(|offset| self.is_date_disabled(&start.add_days(offset)))
+    }
+
+    /// The pointer moved over a day (or off the grid)
+    pub fn hover(&mut self, date: Option<CalendarDate>) {
+        self.hover_date.set(date);
+    }
+
+    /// The picked range, once both ends are set
+    pub fn selected_range(&self) -> Option<(CalendarDate, CalendarDate)> {
+        Some((self.range_start.get()?, self.range_end.get()?))
+    }
+
+    /// Range to highlight: the picked one, or start → hovered day while choosing the end
+    pub fn range_preview(&self) -> Option<(CalendarDate, CalendarDate)> {
+        if let Some(range) = self.selected_range() {
+            return Some(range);
+        }
+        let (start, hover) = (self.range_start.get()?, self.hover_date.get()?);
+        Some(if hover < start { (hover, start) } else { (start, hover) })
+    }
+
+    /// Is a day inside the highlighted range?
+    pub fn is_in_range(&self, date: &CalendarDate) -> bool {
+        self.range_preview().is_some_and(|(start, end)| start <= *date && *date <= end)
     }
 
     /// Clear selection
     pub fn clear_selection(&mut self) {
         self.selected_date.set(None);
+        self.range_start.set(None);
+        self.range_end.set(None);
     }
 
     /// Get selected date
@@ -254,6 +503,7 @@ impl Calendar {
         if let Some(ref callback) = self.on_month_change {
             callback(next.year, next.month);
         }
+        self.emit(CalendarEvent::MonthChanged { year: next.year, month: next.month });
     }
 
     /// Go to previous month
@@ -265,6 +515,7 @@ impl Calendar {
         if let Some(ref callback) = self.on_month_change {
             callback(prev.year, prev.month);
         }
+        self.emit(CalendarEvent::MonthChanged { year: prev.year, month: prev.month });
     }
 
     /// Go to today
@@ -273,6 +524,29 @@ impl Calendar {
         self.current_date.set(today);
     }
 
+    /// Heading for the shown month ("November 2025", "novembre 2025")
+    pub fn month_title(&self) -> String {
+        let current = self.get_current_date();
+        self.locale.format_pattern(current.year, current.month, 1, "MMMM yyyy")
+    }
+
+    /// Weekday column headers, starting at `first_day_of_week`
+    pub fn weekday_headers(&self) -> Vec<&'static str> {
+        self.locale.weekday_headers(self.first_day_of_week)
+    }
+
+    /// Empty cells before the 1st in the month grid
+    pub fn leading_blank_days(&self) -> u8 {
+        (self.get_current_date().first_of_month().weekday() + 7 - self.first_day_of_week) % 7
+    }
+
+    /// The 42 days (six weeks) of the month grid, including the tail of the
+    /// previous month and the start of the next
+    pub fn month_grid(&self) -> Vec<CalendarDate> {
+        let start = self.get_current_date().first_of_month().add_days(-(self.leading_blank_days() as i64));
+        (0..42).map(|i| start.add_days(i)).collect()
+    }
+
     /// Check if date is disabled
     pub fn is_date_disabled(&self, date: &CalendarDate) -> bool {
         if let Some(min) = self.min_date {
@@ -348,6 +622,15 @@ mod tests {
         assert_eq!(current, CalendarDate::today());
     }
 
+    #[test]
+    fn calendar_date_from_unix_timestamp() {
+        assert_eq!(CalendarDate::from_unix_timestamp(0, 0), CalendarDate::new(1970, 1, 1));
+        assert_eq!(CalendarDate::from_unix_timestamp(951_825_600, 0), CalendarDate::new(2000, 2, 29));
+        // 23:00 UTC is already the next day at UTC+2
+        assert_eq!(CalendarDate::from_unix_timestamp(82_800, 7_200), CalendarDate::new(1970, 1, 2));
+        assert_eq!(CalendarDate::from_unix_timestamp(-1, 0), CalendarDate::new(1969, 12, 31));
+    }
+
     #[test]
     fn calendar_select_date() {
         let mut calendar = Calendar::new();
@@ -554,4 +837,75 @@ mod tests {
         assert!(result.is_ok());
         assert!(calendar.node_id.is_some());
     }
+
+    #[test]
+    fn calendar_uses_locale_names_and_week_start() {
+        let us = Calendar::new().selected_date(CalendarDate::new(2025, 11, 22));
+        assert_eq!(us.month_title(), "November 2025");
+        assert_eq!(us.weekday_headers()[0], "Sun");
+        assert_eq!(us.leading_blank_days(), 6);
+
+        let de = Calendar::new().locale(Locale::de_de()).selected_date(CalendarDate::new(2025, 11, 22));
+        assert_eq!(de.first_day_of_week, 1);
+        assert_eq!(de.weekday_headers()[0], "Mo");
+        assert_eq!(de.leading_blank_days(), 5);
+        assert_eq!(CalendarDate::new(2025, 11, 22).format_with(&Locale::de_de(), &DateFormat::Long), "Samstag, 22. November 2025");
+    }
+
+    #[test]
+    fn date_arithmetic_and_injected_clock() {
+        let date = CalendarDate::new(2024, 2, 28);
+        assert_eq!(date.add_days(1), CalendarDate::new(2024, 2, 29));
+        assert_eq!(date.add_days(2), CalendarDate::new(2024, 3, 1));
+        assert_eq!(CalendarDate::new(2025, 1, 1).diff(&CalendarDate::new(2024, 1, 1)), 366);
+        assert_eq!(CalendarDate::new(1970, 1, 1).to_days(), 0);
+        assert_eq!(CalendarDate::from_days(CalendarDate::new(1969, 12, 31).to_days()), CalendarDate::new(1969, 12, 31));
+
+        // 2025-11-22 23:30 UTC
+        DateClock::set_now(Some(1_763_854_200));
+        DateClock::set_utc_offset(Some(0));
+        assert_eq!(CalendarDate::today(), CalendarDate::new(2025, 11, 22));
+        DateClock::set_utc_offset(Some(3600));
+        assert_eq!(CalendarDate::today(), CalendarDate::new(2025, 11, 23));
+        DateClock::set_utc_offset(None);
+        assert_eq!(
+            CalendarDate::today(),
+            CalendarDate::from_unix_timestamp(1_763_854_200, DateClock::utc_offset())
+        );
+
+        let calendar = Calendar::new().locale(Locale::de_de());
+        let grid = calendar.month_grid();
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid[0], CalendarDate::new(2025, 10, 27)); // Monday before Nov 1
+        assert!(grid.iter().all(|day| (day.weekday() + 7 - 1) % 7 == (day.diff(&grid[0]) % 7) as u8));
+        DateClock::set_now(None);
+    }
+
+    #[test]
+    fn range_mode_previews_and_orders_ends() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let picked = Rc::new(RefCell::new(None));
+        let sink = picked.clone();
+        let mut calendar = Calendar::new()
+            .selection_mode(CalendarSelection::Range)
+            .add_disabled_date(CalendarDate::new(2025, 11, 28))
+            .on_range_select(move |start, end| *sink.borrow_mut() = Some((start, end)));
+
+        calendar.select_date(CalendarDate::new(2025, 11, 20));
+        calendar.hover(Some(CalendarDate::new(2025, 11, 17)));
+        assert!(calendar.is_in_range(&CalendarDate::new(2025, 11, 18)));
+        assert!(!calendar.is_in_range(&CalendarDate::new(2025, 11, 21)));
+
+        calendar.select_date(CalendarDate::new(2025, 11, 30)); // Spans a disabled day
+        assert_eq!(calendar.selected_range(), None);
+        calendar.select_date(CalendarDate::new(2025, 11, 15));
+        assert_eq!(*picked.borrow(), Some((CalendarDate::new(2025, 11, 15), CalendarDate::new(2025, 11, 20))));
+        assert_eq!(calendar.get_selected_date(), None);
+
+        calendar.select_date(CalendarDate::new(2025, 12, 1)); // Starts over
+        assert_eq!(calendar.selected_range(), None);
+        assert_eq!(calendar.range_start.get(), Some(CalendarDate::new(2025, 12, 1)));
+    }
 }
@@ -0,0 +1,907 @@
+// CameraView Component - Live camera preview, device selection and still capture
+// Enabled with the `camera` feature; frames come from a pluggable CameraBackend
+
+use crate::code_scanner::{CodeDecoder, CodeScanner, DecodedCode};
+use image::{DynamicImage, RgbaImage};
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use nebula_gfx::draw_list::{DrawList, ImageId, Rect};
+use nebula_gfx::StreamingTexture;
+use tracing::{info, warn};
+
+#[cfg(all(feature = "camera-native", not(target_arch = "wasm32")))]
+pub use native::NokhwaCamera;
+#[cfg(target_arch = "wasm32")]
+pub use web::WebCamera;
+
+/// Image id the preview is drawn as unless `preview_image` picks another
+pub const CAMERA_PREVIEW_IMAGE: ImageId = ImageId(u32::MAX);
+
+/// A camera the backend can open
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CameraDevice {
+    pub id: String,
+    pub name: String,
+}
+
+impl CameraDevice {
+    /// Create a device entry
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self { id: id.into(), name: name.into() }
+    }
+}
+
+/// One RGBA8 frame from a camera
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Tightly packed RGBA8 pixels
+    pub data: Vec<u8>,
+}
+
+impl CameraFrame {
+    /// Create a frame (data must be width * height * 4 bytes)
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Result<Self, String> {
+        if data.len() != width as usize * height as usize * 4 {
+            return Err(format!("Frame is {} bytes, expected {}x{} RGBA", data.len(), width, height));
+        }
+        Ok(Self { width, height, data })
+    }
+
+    /// Convert to an image
+    pub fn to_image(&self) -> Option<DynamicImage> {
+        RgbaImage::from_raw(self.width, self.height, self.data.clone()).map(DynamicImage::ImageRgba8)
+    }
+}
+
+/// Where frames come from 📷
+///
+/// The browser backend (`WebCamera`, getUserMedia) ships with the
+/// `camera` feature on wasm, the desktop one (`NokhwaCamera`: V4L2,
+/// AVFoundation, Media Foundation) with `camera-native`. Anything else
+/// plugs in by implementing this trait.
+pub trait CameraBackend {
+    /// Cameras that can be opened
+    fn devices(&self) -> Vec<CameraDevice>;
+
+    /// Start streaming from a device at (about) the requested resolution
+    /// An empty id means the system's default camera. Must not block:
+    /// backends that open in the background report failures via `take_error`
+    fn open(&mut self, device_id: &str, width: u32, height: u32) -> Result<(), String>;
+
+    /// Stop streaming (without waiting for the device)
+    fn close(&mut self);
+
+    /// Newest frame since the last poll (None if nothing new)
+    fn poll_frame(&mut self) -> Option<CameraFrame>;
+
+    /// Why a background open failed, once (None if nothing went wrong)
+    fn take_error(&mut self) -> Option<String> {
+        None // Default: open reports everything itself
+    }
+}
+
+/// Synthetic moving gradient, for demos and tests without a camera
+#[derive(Debug, Clone, Default)]
+pub struct TestPatternCamera {
+    open: Option<(u32, u32)>,
+    tick: u32,
+}
+
+impl TestPatternCamera {
+    /// Create a test-pattern backend
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CameraBackend for TestPatternCamera {
+    fn devices(&self) -> Vec<CameraDevice> {
+        vec![CameraDevice::new("test-pattern", "Test Pattern")]
+    }
+
+    fn open(&mut self, device_id: &str, width: u32, height: u32) -> Result<(), String> {
+        if !device_id.is_empty() && device_id != "test-pattern" {
+            return Err(format!("Unknown camera '{}'", device_id));
+        }
+        self.open = Some((width.max(1), height.max(1)));
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.open = None;
+    }
+
+    fn poll_frame(&mut self) -> Option<CameraFrame> {
+        let (width, height) = self.open?;
+        self.tick = self.tick.wrapping_add(1);
+        let mut data = Vec::with_capacity(width as usize * height as usize * 4);
+        for y in 0..height {
+            for x in 0..width {
+                data.extend_from_slice(&[
+                    (x * 255 / width) as u8,
+                    (y * 255 / height) as u8,
+                    (self.tick % 256) as u8,
+                    255,
+                ]);
+            }
+        }
+        Some(CameraFrame { width, height, data })
+    }
+}
+
+/// CameraView - Live camera preview 🎥
+///
+/// - Lists cameras and switches between them
+/// - Polls the backend every frame and writes new frames into a
+///   `StreamingTexture`; attach it to the renderer once and the preview
+///   texture is updated in place instead of re-uploaded
+/// - `capture` grabs the current frame as a `DynamicImage` (profile photos!)
+/// - `enable_scanning` decodes QR / barcodes on a background thread and
+///   publishes them through the `scanned` Signal, outlined in the preview
+///
+/// # Example
+/// ```rust,ignore
+/// let mut camera = CameraView::new(Box::new(NokhwaCamera::new()))
+///     .resolution(1280, 720)
+///     .size(640.0, 360.0);
+/// camera.start()?;
+/// renderer.attach_stream(camera.preview().clone());
+///
+/// // every frame
+/// camera.update();
+/// camera.paint(&mut list);
+///
+/// let photo = camera.capture()?;
+/// ```
+pub struct CameraView {
+    pub node_id: Option<NodeId>,
+    backend: Box<dyn CameraBackend>,
+    /// Device being streamed (None = stopped)
+    pub selected_device: Signal<Option<String>>,
+    pub requested_resolution: (u32, u32),
+    /// Flip frames horizontally, preview and captures alike (selfie view)
+    pub mirrored: bool,
+    pub width: f32,
+    pub height: f32,
+    pub position: (f32, f32),
+    pub background_color: (u8, u8, u8, u8),
+    /// Outline color for detected codes
+    pub highlight_color: (u8, u8, u8, u8),
+    /// Latest decoded code (only changes when a different payload shows up)
+    pub scanned: Signal<Option<DecodedCode>>,
+    /// Why the camera last failed to open (cleared when one starts)
+    pub error: Signal<Option<String>>,
+    frame: Option<CameraFrame>,
+    frame_version: u64,
+    preview: StreamingTexture,
+    scanner: Option<CodeScanner>,
+    detections: Vec<DecodedCode>,
+}
+
+impl CameraView {
+    /// Create a camera view over a backend (not streaming yet)
+    pub fn new(backend: Box<dyn CameraBackend>) -> Self {
+        info!("🎥 Creating CameraView");
+        Self {
+            node_id: None,
+            backend,
+            selected_device: Signal::new(None),
+            requested_resolution: (1280, 720),
+            mirrored: false,
+            width: 640.0,
+            height: 360.0,
+            position: (0.0, 0.0),
+            background_color: (0, 0, 0, 255),
+            highlight_color: (34, 197, 94, 255), // Green
+            scanned: Signal::new(None),
+            error: Signal::new(None),
+            frame: None,
+            frame_version: 0,
+            preview: StreamingTexture::new(CAMERA_PREVIEW_IMAGE),
+            scanner: None,
+            detections: Vec::new(),
+        }
+    }
+
+    /// Resolution to ask the camera for
+    pub fn resolution(mut self, width: u32, height: u32) -> Self {
+        self.requested_resolution = (width, height);
+        self
+    }
+
+    /// Mirror the image (front cameras)
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    /// Image id the preview is drawn as (one per CameraView on screen)
+    pub fn preview_image(mut self, id: ImageId) -> Self {
+        self.preview = StreamingTexture::new(id);
+        self
+    }
+
+    /// Set the preview size
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the position
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    /// Cameras to choose from
+    pub fn devices(&self) -> Vec<CameraDevice> {
+        self.backend.devices()
+    }
+
+    /// Start streaming from the first camera (or the default one if none are listed yet)
+    pub fn start(&mut self) -> Result<(), String> {
+        let device_id = self.devices().into_iter().next().map(|device| device.id).unwrap_or_default();
+        self.select_device(&device_id)
+    }
+
+    /// Switch to (or start) a camera
+    pub fn select_device(&mut self, device_id: &str) -> Result<(), String> {
+        if self.selected_device.get().is_some() {
+            self.backend.close();
+        }
+        let (width, height) = self.requested_resolution;
+        if let Err(e) = self.backend.open(device_id, width, height) {
+            warn!("⚠️ Could not open camera {}: {}", device_id, e);
+            self.selected_device.set(None);
+            self.error.set(Some(e.clone()));
+            return Err(e);
+        }
+        info!("🎥 Camera '{}' started", device_id);
+        self.frame = None;
+        self.error.set(None);
+        self.selected_device.set(Some(device_id.to_string()));
+        Ok(())
+    }
+
+    /// Stop streaming
+    pub fn stop(&mut self) {
+        self.backend.close();
+        self.selected_device.set(None);
+        info!("🎥 Camera stopped");
+    }
+
+    /// Is a camera streaming?
+    pub fn is_running(&self) -> bool {
+        self.selected_device.get().is_some()
+    }
+
+    /// Pull the newest frame (call every frame)
+    /// Returns true if a new frame arrived
+    pub fn update(&mut self) -> bool {
+        if !self.is_running() {
+            return false;
+        }
+        if let Some(e) = self.backend.take_error() {
+            warn!("⚠️ Camera failed: {}", e);
+            self.backend.close();
+            self.selected_device.set(None);
+            self.error.set(Some(e));
+            return false;
+        }
+        if let Some(codes) = self.scanner.as_mut().and_then(CodeScanner::poll) {
+            self.apply_detections(codes);
+        }
+        let Some(mut frame) = self.backend.poll_frame() else {
+            return false;
+        };
+        if let Some(scanner) = self.scanner.as_mut() {
+            // Decoders want the unmirrored image; highlights flip back when drawn
+            scanner.submit(&frame);
+        }
+        if self.mirrored {
+            mirror(&mut frame);
+        }
+        if let Err(e) = self.preview.write(frame.width, frame.height, &frame.data) {
+            warn!("⚠️ Dropped camera frame: {}", e);
+            return false;
+        }
+        self.frame = Some(frame);
+        self.frame_version += 1;
+        true
+    }
+
+    /// Newest frame
+    pub fn frame(&self) -> Option<&CameraFrame> {
+        self.frame.as_ref()
+    }
+
+    /// Bumped on every new frame
+    pub fn frame_version(&self) -> u64 {
+        self.frame_version
+    }
+
+    /// Texture the frames stream into (attach a clone to the renderer)
+    pub fn preview(&self) -> &StreamingTexture {
+        &self.preview
+    }
+
+    /// Grab the current frame as an image
+    pub fn capture(&self) -> Result<DynamicImage, String> {
+        let frame = self.frame.as_ref().ok_or("No camera frame yet")?;
+        let image = frame.to_image().ok_or("Camera frame has the wrong size")?;
+        info!("📸 Captured {}x{} photo", frame.width, frame.height);
+        Ok(image)
+    }
+
+    /// Start decoding QR / barcodes from the feed 🔍
+    pub fn enable_scanning(&mut self, decoder: impl CodeDecoder) {
+        self.scanner = Some(CodeScanner::new(decoder));
+        self.detections.clear();
+    }
+
+    /// Stop decoding (clears the highlight, keeps the last `scanned` value)
+    pub fn disable_scanning(&mut self) {
+        self.scanner = None;
+        self.detections.clear();
+    }
+
+    /// Is scanning mode on?
+    pub fn is_scanning(&self) -> bool {
+        self.scanner.is_some()
+    }
+
+    /// Codes found in the most recently decoded frame
+    pub fn detections(&self) -> &[DecodedCode] {
+        &self.detections
+    }
+
+    fn apply_detections(&mut self, codes: Vec<DecodedCode>) {
+        if let Some(code) = codes.first() {
+            let is_new = self.scanned.get().is_none_or(|last| last.payload != code.payload);
+            if is_new {
+                info!("🔍 Scanned {:?}: {}", code.format, code.payload);
+                self.scanned.set(Some(code.clone()));
+            }
+        }
+        self.detections = codes;
+    }
+
+    /// Detected codes as rectangles in view coordinates
+    pub fn highlight_rects(&self) -> Vec<Rect> {
+        let Some(frame) = self.frame.as_ref().filter(|frame| frame.width > 0) else {
+            return Vec::new();
+        };
+        let (px, py, pw, _) = self.preview_rect();
+        let scale = pw / frame.width as f32;
+        self.detections
+            .iter()
+            .map(|code| {
+                let (x, y, width, height) = code.bounds();
+                let x = if self.mirrored { frame.width as f32 - x - width } else { x };
+                (px + x * scale, py + y * scale, width * scale, height * scale)
+            })
+            .collect()
+    }
+
+    /// Where the frame is drawn: fitted into the view, letterboxed
+    pub fn preview_rect(&self) -> Rect {
+        let (x, y) = self.position;
+        let Some(frame) = self.frame.as_ref().filter(|frame| frame.width > 0 && frame.height > 0) else {
+            return (x, y, self.width, self.height);
+        };
+        let scale = (self.width / frame.width as f32).min(self.height / frame.height as f32);
+        let (width, height) = (frame.width as f32 * scale, frame.height as f32 * scale);
+        (x + (self.width - width) / 2.0, y + (self.height - height) / 2.0, width, height)
+    }
+
+    /// Draw the preview (the renderer must have `preview()` attached)
+    pub fn paint(&self, list: &mut DrawList) {
+        list.rect((self.position.0, self.position.1, self.width, self.height), self.background_color);
+        if self.frame.is_some() {
+            list.image(self.preview.id(), self.preview_rect());
+        }
+        let (r, g, b, a) = self.highlight_color;
+        let border = 3.0;
+        for (x, y, width, height) in self.highlight_rects() {
+            list.rect((x, y, width, height), (r, g, b, a / 4));
+            list.rect((x, y, width, border), self.highlight_color);
+            list.rect((x, y + height - border, width, border), self.highlight_color);
+            list.rect((x, y, border, height), self.highlight_color);
+            list.rect((x + width - border, y, border, height), self.highlight_color);
+        }
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.width),
+                    height: taffy::style::Dimension::Length(self.height),
+                },
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create CameraView: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+fn mirror(frame: &mut CameraFrame) {
+    let row_bytes = frame.width as usize * 4;
+    for row in frame.data.chunks_exact_mut(row_bytes) {
+        let pixels = row.len() / 4;
+        for x in 0..pixels / 2 {
+            let (left, right) = (x * 4, (pixels - 1 - x) * 4);
+            for channel in 0..4 {
+                row.swap(left + channel, right + channel);
+            }
+        }
+    }
+}
+
+impl Drop for CameraView {
+    fn drop(&mut self) {
+        if self.is_running() {
+            self.backend.close();
+        }
+    }
+}
+
+#[cfg(all(feature = "camera-native", not(target_arch = "wasm32")))]
+mod native {
+    use super::{CameraBackend, CameraDevice, CameraFrame};
+    use nokhwa::pixel_format::RgbAFormat;
+    use nokhwa::utils::{
+        ApiBackend, CameraFormat, CameraIndex, FrameFormat, RequestedFormat, RequestedFormatType, Resolution,
+    };
+    use nokhwa::Camera;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread::JoinHandle;
+    use tracing::{info, warn};
+
+    /// Desktop camera via nokhwa (V4L2, AVFoundation, Media Foundation)
+    ///
+    /// nokhwa blocks while opening and until the device delivers a frame,
+    /// so opening, capture and decoding all run on their own thread and
+    /// `poll_frame` only takes the newest RGBA frame. Neither `open` nor
+    /// `close` waits for the device. On macOS, ask for camera permission
+    /// with `nokhwa::nokhwa_initialize` before opening.
+    #[derive(Default)]
+    pub struct NokhwaCamera {
+        /// Per stream, so a thread that's still stopping can't leak frames
+        /// or errors into the next one
+        latest: Arc<Mutex<Option<CameraFrame>>>,
+        error: Arc<Mutex<Option<String>>>,
+        running: Arc<AtomicBool>,
+        /// Thread of the latest stream (may still be finishing after close)
+        capture: Option<JoinHandle<()>>,
+    }
+
+    impl NokhwaCamera {
+        /// Create the backend (nothing is opened until `open`)
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    fn api() -> ApiBackend {
+        nokhwa::native_api_backend().unwrap_or(ApiBackend::Auto)
+    }
+
+    /// Device ids are nokhwa indices ("0", "1"…); empty = first camera
+    fn camera_index(device_id: &str) -> CameraIndex {
+        if device_id.is_empty() {
+            return CameraIndex::Index(0);
+        }
+        device_id.parse().map(CameraIndex::Index).unwrap_or_else(|_| CameraIndex::String(device_id.to_string()))
+    }
+
+    /// Closest MJPEG mode to the requested size, else whatever runs fastest
+    fn open_camera(index: CameraIndex, width: u32, height: u32) -> Result<Camera, String> {
+        let closest = CameraFormat::new(Resolution::new(width, height), FrameFormat::MJPEG, 30);
+        let mut camera = Camera::with_backend(
+            index.clone(),
+            RequestedFormat::new::<RgbAFormat>(RequestedFormatType::Closest(closest)),
+            api(),
+        )
+        .or_else(|_| {
+            let fastest = RequestedFormat::new::<RgbAFormat>(RequestedFormatType::AbsoluteHighestFrameRate);
+            Camera::with_backend(index, fastest, api())
+        })
+        .map_err(|e| format!("Could not open camera: {}", e))?;
+        camera.open_stream().map_err(|e| format!("Could not start camera stream: {}", e))?;
+        Ok(camera)
+    }
+
+    impl CameraBackend for NokhwaCamera {
+        fn devices(&self) -> Vec<CameraDevice> {
+            match nokhwa::query(api()) {
+                Ok(cameras) => cameras
+                    .iter()
+                    .map(|camera| CameraDevice::new(camera.index().as_string(), camera.human_name()))
+                    .collect(),
+                Err(e) => {
+                    warn!("⚠️ Could not list cameras: {}", e);
+                    Vec::new()
+                }
+            }
+        }
+
+        fn open(&mut self, device_id: &str, width: u32, height: u32) -> Result<(), String> {
+            self.close();
+            let index = camera_index(device_id);
+            self.running.store(true, Ordering::Release);
+            let (latest, error, running) = (self.latest.clone(), self.error.clone(), self.running.clone());
+            let previous = self.capture.take();
+            // nokhwa cameras aren't Send, so the camera lives on the capture thread
+            let capture = std::thread::Builder::new()
+                .name("nebula-camera".to_string())
+                .spawn(move || {
+                    // The last stream lets go of the device after its pending frame
+                    if let Some(previous) = previous {
+                        previous.join().ok();
+                    }
+                    if !running.load(Ordering::Acquire) {
+                        return;
+                    }
+                    let mut camera = match open_camera(index, width, height) {
+                        Ok(camera) => camera,
+                        Err(e) => {
+                            *error.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(e);
+                            return;
+                        }
+                    };
+                    info!("🎥 Streaming '{}' at {}", camera.info().human_name(), camera.camera_format());
+                    while running.load(Ordering::Acquire) {
+                        let buffer = match camera.frame() {
+                            Ok(buffer) => buffer,
+                            Err(e) => {
+                                warn!("⚠️ Camera stream ended: {}", e);
+                                break;
+                            }
+                        };
+                        let Resolution { width_x: width, height_y: height } = buffer.resolution();
+                        let mut data = vec![0; width as usize * height as usize * 4];
+                        if let Err(e) = buffer.decode_image_to_buffer::<RgbAFormat>(&mut data) {
+                            warn!("⚠️ Could not decode camera frame: {}", e);
+                            continue;
+                        }
+                        *latest.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+                            Some(CameraFrame { width, height, data });
+                    }
+                    // Dropping the camera stops the stream
+                })
+                .map_err(|e| format!("Could not start camera thread: {}", e))?;
+            self.capture = Some(capture);
+            Ok(())
+        }
+
+        fn close(&mut self) {
+            // The thread notices after the frame it's waiting on; it isn't
+            // joined here, and the next `open` starts from fresh state
+            self.running.store(false, Ordering::Release);
+            self.running = Arc::default();
+            self.latest = Arc::default();
+            self.error = Arc::default();
+        }
+
+        fn take_error(&mut self) -> Option<String> {
+            self.error.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+        }
+
+        fn poll_frame(&mut self) -> Option<CameraFrame> {
+            self.latest.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+        }
+    }
+
+    impl Drop for NokhwaCamera {
+        fn drop(&mut self) {
+            self.close();
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::{CameraBackend, CameraDevice, CameraFrame};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use tracing::warn;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::{spawn_local, JsFuture};
+    use web_sys::{
+        CanvasRenderingContext2d, HtmlCanvasElement, HtmlVideoElement, MediaDeviceInfo, MediaDeviceKind,
+        MediaStream, MediaStreamConstraints, MediaStreamTrack,
+    };
+
+    #[derive(Default)]
+    struct Shared {
+        devices: Vec<CameraDevice>,
+        stream: Option<MediaStream>,
+        /// getUserMedia rejected the last open
+        error: Option<String>,
+    }
+
+    /// Browser camera via getUserMedia
+    ///
+    /// Frames are drawn from a detached `<video>` into a canvas and read
+    /// back with `getImageData`. Device names only show up once the user
+    /// has granted camera permission, so the list refreshes after `open`.
+    pub struct WebCamera {
+        shared: Rc<RefCell<Shared>>,
+        video: HtmlVideoElement,
+        canvas: HtmlCanvasElement,
+        context: CanvasRenderingContext2d,
+        last_time: f64,
+    }
+
+    impl WebCamera {
+        /// Create the backend (starts listing cameras in the background)
+        pub fn new() -> Result<Self, String> {
+            let document = web_sys::window().and_then(|window| window.document()).ok_or("No document")?;
+            let video: HtmlVideoElement = document
+                .create_element("video")
+                .map_err(|e| format!("{:?}", e))?
+                .dyn_into()
+                .map_err(|_| "Not a video element")?;
+            video.set_autoplay(true);
+            video.set_muted(true);
+            video.set_attribute("playsinline", "").ok();
+            let canvas: HtmlCanvasElement = document
+                .create_element("canvas")
+                .map_err(|e| format!("{:?}", e))?
+                .dyn_into()
+                .map_err(|_| "Not a canvas element")?;
+            let context: CanvasRenderingContext2d = canvas
+                .get_context("2d")
+                .map_err(|e| format!("{:?}", e))?
+                .ok_or("No 2D context")?
+                .dyn_into()
+                .map_err(|_| "Not a 2D context")?;
+
+            let camera = Self { shared: Rc::new(RefCell::new(Shared::default())), video, canvas, context, last_time: -1.0 };
+            camera.refresh_devices();
+            Ok(camera)
+        }
+
+        /// List cameras again (finishes in the background)
+        pub fn refresh_devices(&self) {
+            refresh_devices(self.shared.clone());
+        }
+    }
+
+    fn refresh_devices(shared: Rc<RefCell<Shared>>) {
+        let Some(media) = web_sys::window().and_then(|window| window.navigator().media_devices().ok()) else {
+            return;
+        };
+        let Ok(promise) = media.enumerate_devices() else {
+            return;
+        };
+        spawn_local(async move {
+            let Ok(list) = JsFuture::from(promise).await else {
+                return;
+            };
+            let devices = js_sys::Array::from(&list)
+                .iter()
+                .filter_map(|info| info.dyn_into::<MediaDeviceInfo>().ok())
+                .filter(|info| info.kind() == MediaDeviceKind::Videoinput)
+                .enumerate()
+                .map(|(i, info)| {
+                    let name = if info.label().is_empty() { format!("Camera {}", i + 1) } else { info.label() };
+                    CameraDevice::new(info.device_id(), name)
+                })
+                .collect();
+            shared.borrow_mut().devices = devices;
+        });
+    }
+
+    impl CameraBackend for WebCamera {
+        fn devices(&self) -> Vec<CameraDevice> {
+            self.shared.borrow().devices.clone()
+        }
+
+        fn open(&mut self, device_id: &str, width: u32, height: u32) -> Result<(), String> {
+            let media = web_sys::window()
+                .ok_or("No window")?
+                .navigator()
+                .media_devices()
+                .map_err(|_| "Camera access is not available (needs HTTPS)")?;
+            let video = js_sys::Object::new();
+            let set = |key: &str, value: wasm_bindgen::JsValue| {
+                js_sys::Reflect::set(&video, &key.into(), &value).ok();
+            };
+            if !device_id.is_empty() {
+                let exact = js_sys::Object::new();
+                js_sys::Reflect::set(&exact, &"exact".into(), &device_id.into()).ok();
+                set("deviceId", exact.into());
+            }
+            set("width", width.into());
+            set("height", height.into());
+            let constraints = MediaStreamConstraints::new();
+            constraints.set_video(&video);
+            let promise = media.get_user_media_with_constraints(&constraints).map_err(|e| format!("{:?}", e))?;
+
+            let shared = self.shared.clone();
+            let element = self.video.clone();
+            spawn_local(async move {
+                match JsFuture::from(promise).await {
+                    Ok(stream) => {
+                        let stream: MediaStream = stream.unchecked_into();
+                        element.set_src_object(Some(&stream));
+                        if let Ok(play) = element.play() {
+                            let _ = JsFuture::from(play).await;
+                        }
+                        shared.borrow_mut().stream = Some(stream);
+                        // Labels appear once permission is granted
+                        refresh_devices(shared);
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Camera permission denied: {:?}", e);
+                        shared.borrow_mut().error = Some(format!("Camera permission denied: {:?}", e));
+                    }
+                }
+            });
+            self.last_time = -1.0;
+            Ok(())
+        }
+
+        fn close(&mut self) {
+            if let Some(stream) = self.shared.borrow_mut().stream.take() {
+                for track in stream.get_tracks().iter() {
+                    if let Ok(track) = track.dyn_into::<MediaStreamTrack>() {
+                        track.stop();
+                    }
+                }
+            }
+            self.video.set_src_object(None);
+        }
+
+        fn take_error(&mut self) -> Option<String> {
+            self.shared.borrow_mut().error.take()
+        }
+
+        fn poll_frame(&mut self) -> Option<CameraFrame> {
+            // HAVE_CURRENT_DATA or better, and only when the video moved on
+            if self.video.ready_state() < 2 || self.video.current_time() == self.last_time {
+                return None;
+            }
+            self.last_time = self.video.current_time();
+            let (width, height) = (self.video.video_width(), self.video.video_height());
+            if width == 0 || height == 0 {
+                return None;
+            }
+            if self.canvas.width() != width || self.canvas.height() != height {
+                self.canvas.set_width(width);
+                self.canvas.set_height(height);
+            }
+            self.context.draw_image_with_html_video_element(&self.video, 0.0, 0.0).ok()?;
+            let pixels = self.context.get_image_data(0.0, 0.0, width as f64, height as f64).ok()?;
+            Some(CameraFrame { width, height, data: pixels.data().0 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::code_scanner::CodeFormat;
+
+    #[test]
+    fn camera_streams_and_captures() {
+        let mut camera = CameraView::new(Box::new(TestPatternCamera::new()))
+            .resolution(8, 4)
+            .size(80.0, 80.0)
+            .mirrored(true);
+        assert!(!camera.update());
+        assert!(camera.capture().is_err());
+
+        camera.start().unwrap();
+        assert_eq!(camera.selected_device.get().as_deref(), Some("test-pattern"));
+        assert!(camera.update());
+        assert_eq!(camera.frame_version(), 1);
+        assert_eq!(camera.preview_rect(), (0.0, 20.0, 80.0, 40.0));
+        assert_eq!(camera.preview().version(), 1);
+        assert_eq!(camera.preview().size(), (8, 4));
+        let mut list = DrawList::new();
+        camera.paint(&mut list);
+        assert_eq!(list.images(), vec![CAMERA_PREVIEW_IMAGE]);
+
+        let photo = camera.capture().unwrap();
+        assert_eq!((photo.width(), photo.height()), (8, 4));
+        // Mirrored: the red gradient runs right to left
+        let pixel = photo.to_rgba8().get_pixel(0, 0).0;
+        assert!(pixel[0] > 200);
+
+        assert!(camera.select_device("missing").is_err());
+        assert!(!camera.is_running());
+    }
+
+    /// Opens at once, then fails in the background like a busy device
+    struct LateFailCamera {
+        failed: bool,
+    }
+
+    impl CameraBackend for LateFailCamera {
+        fn devices(&self) -> Vec<CameraDevice> {
+            vec![CameraDevice::new("busy", "Busy Camera")]
+        }
+
+        fn open(&mut self, _device_id: &str, _width: u32, _height: u32) -> Result<(), String> {
+            self.failed = true;
+            Ok(())
+        }
+
+        fn close(&mut self) {}
+
+        fn poll_frame(&mut self) -> Option<CameraFrame> {
+            None
+        }
+
+        fn take_error(&mut self) -> Option<String> {
+            std::mem::take(&mut self.failed).then(|| "Device busy".to_string())
+        }
+    }
+
+    #[test]
+    fn camera_reports_background_open_errors() {
+        let mut camera = CameraView::new(Box::new(LateFailCamera { failed: false }));
+        camera.start().unwrap();
+        assert!(camera.is_running());
+        assert_eq!(camera.error.get(), None);
+
+        assert!(!camera.update());
+        assert!(!camera.is_running());
+        assert_eq!(camera.error.get().as_deref(), Some("Device busy"));
+
+        camera.start().unwrap();
+        assert_eq!(camera.error.get(), None);
+    }
+
+    struct FixedDecoder;
+
+    impl CodeDecoder for FixedDecoder {
+        fn decode(&mut self, _width: u32, _height: u32, _luma: &[u8]) -> Vec<DecodedCode> {
+            let corners = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)];
+            vec![DecodedCode::new("https://nebula.dev", CodeFormat::QrCode, corners)]
+        }
+    }
+
+    #[test]
+    fn camera_scans_codes_in_background() {
+        let mut camera = CameraView::new(Box::new(TestPatternCamera::new()))
+            .resolution(8, 4)
+            .size(80.0, 40.0)
+            .mirrored(true);
+        camera.start().unwrap();
+        camera.enable_scanning(FixedDecoder);
+        assert!(camera.is_scanning());
+
+        let start = std::time::Instant::now();
+        while camera.scanned.get().is_none() {
+            assert!(start.elapsed() < std::time::Duration::from_secs(5), "no code scanned");
+            camera.update();
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        let code = camera.scanned.get().unwrap();
+        assert_eq!(code.payload, "https://nebula.dev");
+        assert_eq!(code.format, CodeFormat::QrCode);
+
+        // Mirrored: the top-left code is outlined on the right
+        assert_eq!(camera.highlight_rects(), vec![(60.0, 0.0, 20.0, 20.0)]);
+
+        camera.disable_scanning();
+        assert!(camera.highlight_rects().is_empty());
+        assert!(camera.scanned.get().is_some());
+    }
+
+    #[test]
+    fn camera_frame_checks_size() {
+        assert!(CameraFrame::new(2, 2, vec![0; 16]).is_ok());
+        assert!(CameraFrame::new(2, 2, vec![0; 15]).is_err());
+    }
+}
@@ -3,6 +3,9 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
+use nebula_core::QualitySettings;
+use nebula_gfx::RendererCapabilities;
 
 /// Card variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,8 +44,9 @@ pub struct Card {
 }
 
 impl Card {
-    /// Create a new Card component
+    /// Create a new Card component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             title: Signal::new(None),
@@ -51,8 +55,8 @@ impl Card {
             width: 300.0,
             height: 200.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            border_color: (229, 231, 235, 255),
+            background_color: colors.surface,
+            border_color: colors.border,
             border_width: 1.0,
             border_radius: 8.0,
             shadow_elevation: 2,
@@ -62,6 +66,19 @@ impl Card {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.border_color = colors.border;
+    }
+
     /// Set the title
     pub fn title(self, title: impl Into<String>) -> Self {
         self.title.set(Some(title.into()));
@@ -175,6 +192,26 @@ impl Card {
         self.subtitle.get().is_some()
     }
 
+    /// Shadow elevation to draw (0 when the renderer or quality settings can't do
+    /// shadows, or a high-contrast theme wants borders instead)
+    pub fn effective_shadow_elevation(&self) -> u8 {
+        let shadows = RendererCapabilities::current().shadows
+            && QualitySettings::current().shadows
+            && !ThemeProvider::current().high_contrast;
+        if shadows && self.variant == CardVariant::Elevated { self.shadow_elevation } else { 0 }
+    }
+
+    /// Border to draw (width, color); an elevated card without its shadow gets
+    /// a border instead so it still stands off the page
+    pub fn effective_border(&self) -> (f32, (u8, u8, u8, u8)) {
+        let lost_shadow = self.variant == CardVariant::Elevated && self.shadow_elevation > 0 && self.effective_shadow_elevation() == 0;
+        if lost_shadow {
+            (self.border_width.max(1.0), self.border_color)
+        } else {
+            (self.border_width, self.border_color)
+        }
+    }
+
     /// Handle click
     pub fn click(&mut self) {
         if self.clickable {
@@ -283,6 +320,18 @@ mod tests {
         assert!(*clicked.lock().unwrap());
     }
 
+    #[test]
+    fn card_falls_back_to_border_without_shadows() {
+        let card = Card::new().shadow_elevation(3).border(0.0, 229, 231, 235, 255);
+        assert_eq!(card.effective_shadow_elevation(), 3);
+        assert_eq!(card.effective_border().0, 0.0);
+
+        RendererCapabilities::set_current(RendererCapabilities::BASIC);
+        assert_eq!(card.effective_shadow_elevation(), 0);
+        assert_eq!(card.effective_border().0, 1.0);
+        RendererCapabilities::set_current(RendererCapabilities::FULL);
+    }
+
     #[test]
     fn card_shadow_elevation() {
         let card = Card::new().shadow_elevation(3);
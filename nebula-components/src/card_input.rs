@@ -0,0 +1,895 @@
+// CardInput Component - Credit card number, expiry, and CVC inputs
+// Brand detection, digit grouping, Luhn validation, and linked focus advance
+
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use crate::datepicker::Date;
+
+/// Card network detected from the number prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    Discover,
+    DinersClub,
+    Jcb,
+    UnionPay,
+    Unknown,
+}
+
+impl CardBrand {
+    /// Detect the brand from the leading digits (IIN ranges)
+    pub fn detect(digits: &str) -> Self {
+        let prefix = |n: usize| -> u32 {
+            digits.get(..n).and_then(|p| p.parse().ok()).unwrap_or(0)
+        };
+
+        if digits.starts_with('4') {
+            CardBrand::Visa
+        } else if (51..=55).contains(&prefix(2)) || (2221..=2720).contains(&prefix(4)) {
+            CardBrand::Mastercard
+        } else if prefix(2) == 34 || prefix(2) == 37 {
+            CardBrand::Amex
+        } else if prefix(4) == 6011 || prefix(2) == 65 || (644..=649).contains(&prefix(3)) {
+            CardBrand::Discover
+        } else if prefix(2) == 36 || prefix(2) == 38 || (300..=305).contains(&prefix(3)) {
+            CardBrand::DinersClub
+        } else if (3528..=3589).contains(&prefix(4)) {
+            CardBrand::Jcb
+        } else if prefix(2) == 62 {
+            CardBrand::UnionPay
+        } else {
+            CardBrand::Unknown
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "Visa",
+            CardBrand::Mastercard => "Mastercard",
+            CardBrand::Amex => "American Express",
+            CardBrand::Discover => "Discover",
+            CardBrand::DinersClub => "Diners Club",
+            CardBrand::Jcb => "JCB",
+            CardBrand::UnionPay => "UnionPay",
+            CardBrand::Unknown => "Card",
+        }
+    }
+
+    /// Digit group sizes used for display
+    pub fn groups(&self) -> &'static [usize] {
+        match self {
+            CardBrand::Amex => &[4, 6, 5],
+            CardBrand::DinersClub => &[4, 6, 4],
+            _ => &[4, 4, 4, 4, 3],
+        }
+    }
+
+    /// Allowed card number lengths
+    pub fn valid_lengths(&self) -> &'static [usize] {
+        match self {
+            CardBrand::Visa => &[13, 16, 19],
+            CardBrand::Mastercard => &[16],
+            CardBrand::Amex => &[15],
+            CardBrand::Discover | CardBrand::Jcb => &[16],
+            CardBrand::DinersClub => &[14, 16],
+            CardBrand::UnionPay => &[16, 17, 18, 19],
+            CardBrand::Unknown => &[12, 13, 14, 15, 16, 17, 18, 19],
+        }
+    }
+
+    /// Longest allowed card number
+    pub fn max_length(&self) -> usize {
+        self.valid_lengths().iter().copied().max().unwrap_or(19)
+    }
+
+    /// Number of CVC digits
+    pub fn cvc_length(&self) -> usize {
+        match self {
+            CardBrand::Amex => 4,
+            _ => 3,
+        }
+    }
+}
+
+/// Luhn checksum (mod 10) over a string of digits
+pub fn luhn_check(digits: &str) -> bool {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| {
+            let d = c.to_digit(10).unwrap_or(0);
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+/// Group card digits for display ("4242424242424242" -> "4242 4242 4242 4242")
+pub fn format_card_number(digits: &str, brand: CardBrand) -> String {
+    let mut result = String::new();
+    let mut rest = digits;
+
+    for &size in brand.groups() {
+        if rest.is_empty() {
+            break;
+        }
+        let (group, tail) = rest.split_at(size.min(rest.len()));
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        result.push_str(group);
+        rest = tail;
+    }
+    if !rest.is_empty() {
+        result.push(' ');
+        result.push_str(rest);
+    }
+
+    result
+}
+
+/// Card number input with brand detection and Luhn validation
+///
+/// # Example
+/// ```rust,ignore
+/// let mut card = CardNumberInput::new()
+///     .on_complete(|| println!("Card number entered!"));
+/// card.set_value("4242 4242 4242 4242");
+/// assert!(card.is_valid());
+/// ```
+pub struct CardNumberInput {
+    pub node_id: Option<NodeId>,
+    /// Raw digits (no spaces)
+    pub digits: Signal<String>,
+    pub brand: Signal<CardBrand>,
+    /// Complete and passes the Luhn check
+    pub is_valid: Signal<bool>,
+    pub is_focused: Signal<bool>,
+    pub placeholder: String,
+    pub width: f32,
+    pub height: f32,
+    pub error_color: (u8, u8, u8, u8),
+    pub on_change: Option<Box<dyn Fn(String)>>,
+    pub on_complete: Option<Box<dyn Fn()>>,
+}
+
+impl CardNumberInput {
+    /// Create a new card number input
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            digits: Signal::new(String::new()),
+            brand: Signal::new(CardBrand::Unknown),
+            is_valid: Signal::new(false),
+            is_focused: Signal::new(false),
+            placeholder: "1234 1234 1234 1234".to_string(),
+            width: 240.0,
+            height: 40.0,
+            error_color: (220, 38, 38, 255),
+            on_change: None,
+            on_complete: None,
+        }
+    }
+
+    /// Set the placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the change callback (receives the raw digits)
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(String) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the callback fired when a valid number is completed
+    pub fn on_complete<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the value (non-digits are ignored, extra digits are dropped)
+    pub fn set_value(&mut self, input: &str) {
+        let digits: String = input.chars().filter(|c| c.is_ascii_digit()).collect();
+        let brand = CardBrand::detect(&digits);
+        let digits: String = digits.chars().take(brand.max_length()).collect();
+        self.update(digits);
+    }
+
+    /// Type a character
+    pub fn insert_char(&mut self, c: char) {
+        if !c.is_ascii_digit() {
+            return;
+        }
+        let mut digits = self.digits.get();
+        digits.push(c);
+        if digits.len() <= CardBrand::detect(&digits).max_length() {
+            self.update(digits);
+        }
+    }
+
+    /// Remove the last digit
+    pub fn backspace(&mut self) {
+        let mut digits = self.digits.get();
+        if digits.pop().is_some() {
+            self.update(digits);
+        }
+    }
+
+    /// Clear the input
+    pub fn clear(&mut self) {
+        self.update(String::new());
+    }
+
+    fn update(&mut self, digits: String) {
+        let brand = CardBrand::detect(&digits);
+        let was_valid = self.is_valid.get();
+        let valid = brand.valid_lengths().contains(&digits.len()) && luhn_check(&digits);
+
+        self.brand.set(brand);
+        self.is_valid.set(valid);
+        self.digits.set(digits.clone());
+
+        if let Some(ref callback) = self.on_change {
+            callback(digits.clone());
+        }
+        if valid && !was_valid {
+            if let Some(ref callback) = self.on_complete {
+                callback();
+            }
+        }
+    }
+
+    /// Raw digits
+    pub fn value(&self) -> String {
+        self.digits.get()
+    }
+
+    /// Digits grouped for display
+    pub fn formatted(&self) -> String {
+        format_card_number(&self.digits.get(), self.brand.get())
+    }
+
+    /// Detected brand
+    pub fn get_brand(&self) -> CardBrand {
+        self.brand.get()
+    }
+
+    /// Is the number complete and valid?
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.get()
+    }
+
+    /// Has the user typed a complete number for this brand?
+    ///
+    /// True at the brand's longest length, or earlier once the digits form
+    /// a valid number (a 13- or 16-digit Visa, a 16-digit UnionPay, ...).
+    pub fn is_full(&self) -> bool {
+        self.is_valid() || self.at_max_length()
+    }
+
+    /// Should the error state be shown? (longest length but failing Luhn)
+    pub fn has_error(&self) -> bool {
+        self.at_max_length() && !self.is_valid()
+    }
+
+    fn at_max_length(&self) -> bool {
+        self.digits.get().len() >= self.brand.get().max_length()
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = build_input_leaf(engine, self.width, self.height, "card number input")?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for CardNumberInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Card expiry input (MM/YY)
+pub struct ExpiryInput {
+    pub node_id: Option<NodeId>,
+    /// Raw digits (MMYY)
+    pub digits: Signal<String>,
+    /// Complete, a real month, and not in the past
+    pub is_valid: Signal<bool>,
+    pub is_focused: Signal<bool>,
+    /// Date used to reject expired cards
+    pub reference_date: Date,
+    pub width: f32,
+    pub height: f32,
+    pub on_complete: Option<Box<dyn Fn()>>,
+}
+
+impl ExpiryInput {
+    /// Create a new expiry input
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            digits: Signal::new(String::new()),
+            is_valid: Signal::new(false),
+            is_focused: Signal::new(false),
+            reference_date: Date::today(),
+            width: 100.0,
+            height: 40.0,
+            on_complete: None,
+        }
+    }
+
+    /// Set the date expiry is checked against
+    pub fn reference_date(mut self, date: Date) -> Self {
+        self.reference_date = date;
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the callback fired when a valid expiry is completed
+    pub fn on_complete<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the value ("12/27", "1227", ...)
+    pub fn set_value(&mut self, input: &str) {
+        let digits: String = input.chars().filter(|c| c.is_ascii_digit()).take(4).collect();
+        self.update(digits);
+    }
+
+    /// Type a character
+    /// A leading 2-9 is expanded to 02-09 since no month starts with it
+    pub fn insert_char(&mut self, c: char) {
+        if !c.is_ascii_digit() {
+            return;
+        }
+        let mut digits = self.digits.get();
+        if digits.len() >= 4 {
+            return;
+        }
+        if digits.is_empty() && c > '1' {
+            digits.push('0');
+        }
+        digits.push(c);
+        self.update(digits);
+    }
+
+    /// Remove the last digit
+    pub fn backspace(&mut self) {
+        let mut digits = self.digits.get();
+        if digits.pop().is_some() {
+            self.update(digits);
+        }
+    }
+
+    fn update(&mut self, digits: String) {
+        let was_valid = self.is_valid.get();
+        self.digits.set(digits);
+        let valid = self.month_year().is_some_and(|(month, year)| {
+            (year, month) >= (self.reference_date.year, self.reference_date.month)
+        });
+        self.is_valid.set(valid);
+
+        if valid && !was_valid {
+            if let Some(ref callback) = self.on_complete {
+                callback();
+            }
+        }
+    }
+
+    /// Parsed (month, full year), if complete and the month is 1-12
+    pub fn month_year(&self) -> Option<(u8, i32)> {
+        let digits = self.digits.get();
+        if digits.len() != 4 {
+            return None;
+        }
+        let month: u8 = digits[..2].parse().ok()?;
+        let year: i32 = digits[2..].parse().ok()?;
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        Some((month, 2000 + year))
+    }
+
+    /// Display text ("MM/YY" as typed so far)
+    pub fn formatted(&self) -> String {
+        let digits = self.digits.get();
+        if digits.len() > 2 {
+            format!("{}/{}", &digits[..2], &digits[2..])
+        } else {
+            digits
+        }
+    }
+
+    /// Is the expiry complete and valid?
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.get()
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = build_input_leaf(engine, self.width, self.height, "expiry input")?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for ExpiryInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Card security code input
+pub struct CvcInput {
+    pub node_id: Option<NodeId>,
+    pub digits: Signal<String>,
+    /// Expected length (3, or 4 for Amex)
+    pub length: usize,
+    pub is_valid: Signal<bool>,
+    pub is_focused: Signal<bool>,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl CvcInput {
+    /// Create a new CVC input
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            digits: Signal::new(String::new()),
+            length: 3,
+            is_valid: Signal::new(false),
+            is_focused: Signal::new(false),
+            width: 80.0,
+            height: 40.0,
+        }
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the expected length (re-validates the current value)
+    pub fn set_length(&mut self, length: usize) {
+        self.length = length;
+        let digits: String = self.digits.get().chars().take(length).collect();
+        self.update(digits);
+    }
+
+    /// Set the value
+    pub fn set_value(&mut self, input: &str) {
+        let digits: String = input.chars().filter(|c| c.is_ascii_digit()).take(self.length).collect();
+        self.update(digits);
+    }
+
+    /// Type a character
+    pub fn insert_char(&mut self, c: char) {
+        let mut digits = self.digits.get();
+        if c.is_ascii_digit() && digits.len() < self.length {
+            digits.push(c);
+            self.update(digits);
+        }
+    }
+
+    /// Remove the last digit
+    pub fn backspace(&mut self) {
+        let mut digits = self.digits.get();
+        if digits.pop().is_some() {
+            self.update(digits);
+        }
+    }
+
+    fn update(&mut self, digits: String) {
+        self.is_valid.set(digits.len() == self.length);
+        self.digits.set(digits);
+    }
+
+    /// Is the CVC complete?
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.get()
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = build_input_leaf(engine, self.width, self.height, "CVC input")?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for CvcInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Which part of the card form has focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardField {
+    Number,
+    Expiry,
+    Cvc,
+}
+
+/// Card number + expiry + CVC with linked focus advance
+///
+/// Typing routes to the focused field; completing a field moves focus to
+/// the next one, and backspace in an empty field moves back. The number
+/// only counts as complete at its brand's longest length (a 16-digit Visa
+/// could still be a 19-digit one), so shorter numbers need a Tab.
+pub struct CardInput {
+    pub node_id: Option<NodeId>,
+    pub number: CardNumberInput,
+    pub expiry: ExpiryInput,
+    pub cvc: CvcInput,
+    pub focused_field: Signal<CardField>,
+    /// All three fields are valid
+    pub is_valid: Signal<bool>,
+    pub spacing: f32,
+}
+
+impl CardInput {
+    /// Create a new card input group
+    pub fn new() -> Self {
+        let input = Self {
+            node_id: None,
+            number: CardNumberInput::new(),
+            expiry: ExpiryInput::new(),
+            cvc: CvcInput::new(),
+            focused_field: Signal::new(CardField::Number),
+            is_valid: Signal::new(false),
+            spacing: 8.0,
+        };
+        input.sync_focus();
+        input
+    }
+
+    /// Set the date expiry is checked against
+    pub fn reference_date(mut self, date: Date) -> Self {
+        self.expiry.reference_date = date;
+        self
+    }
+
+    /// Focus a field
+    pub fn focus(&mut self, field: CardField) {
+        self.focused_field.set(field);
+        self.sync_focus();
+    }
+
+    fn sync_focus(&self) {
+        let field = self.focused_field.get();
+        self.number.is_focused.set(field == CardField::Number);
+        self.expiry.is_focused.set(field == CardField::Expiry);
+        self.cvc.is_focused.set(field == CardField::Cvc);
+    }
+
+    /// Type a character into the focused field
+    pub fn insert_char(&mut self, c: char) {
+        match self.focused_field.get() {
+            CardField::Number => {
+                self.number.insert_char(c);
+                self.cvc.set_length(self.number.get_brand().cvc_length());
+                // A shorter valid prefix (13 of a 16-digit Visa) may still grow
+                if self.number.is_valid() && self.number.at_max_length() {
+                    self.focus(CardField::Expiry);
+                }
+            }
+            CardField::Expiry => {
+                self.expiry.insert_char(c);
+                if self.expiry.is_valid() {
+                    self.focus(CardField::Cvc);
+                }
+            }
+            CardField::Cvc => self.cvc.insert_char(c),
+        }
+        self.update_validity();
+    }
+
+    /// Backspace in the focused field, moving back when it's empty
+    pub fn backspace(&mut self) {
+        match self.focused_field.get() {
+            CardField::Number => self.number.backspace(),
+            CardField::Expiry => {
+                if self.expiry.digits.get().is_empty() {
+                    self.focus(CardField::Number);
+                    self.number.backspace();
+                } else {
+                    self.expiry.backspace();
+                }
+            }
+            CardField::Cvc => {
+                if self.cvc.digits.get().is_empty() {
+                    self.focus(CardField::Expiry);
+                    self.expiry.backspace();
+                } else {
+                    self.cvc.backspace();
+                }
+            }
+        }
+        self.update_validity();
+    }
+
+    fn update_validity(&self) {
+        self.is_valid.set(self.number.is_valid() && self.expiry.is_valid() && self.cvc.is_valid());
+    }
+
+    /// Are all fields valid?
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.get()
+    }
+
+    /// Build the layout (number, expiry, and CVC in a row)
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let number = self.number.build(engine)?;
+        let expiry = self.expiry.build(engine)?;
+        let cvc = self.cvc.build(engine)?;
+
+        let style = taffy::style::Style {
+            display: taffy::style::Display::Flex,
+            flex_direction: taffy::style::FlexDirection::Row,
+            gap: taffy::geometry::Size {
+                width: taffy::style::LengthPercentage::Length(self.spacing),
+                height: taffy::style::LengthPercentage::Length(0.0),
+            },
+            ..Default::default()
+        };
+
+        let node = engine
+            .new_with_children(style, &[number, expiry, cvc])
+            .map_err(|e| format!("Failed to create card input node: {:?}", e))?;
+        self.node_id = Some(node);
+
+        Ok(node)
+    }
+}
+
+impl Default for CardInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn build_input_leaf(engine: &mut LayoutEngine, width: f32, height: f32, name: &str) -> Result<NodeId, String> {
+    let style = taffy::style::Style {
+        size: taffy::geometry::Size {
+            width: taffy::style::Dimension::Length(width),
+            height: taffy::style::Dimension::Length(height),
+        },
+        ..Default::default()
+    };
+
+    engine
+        .new_leaf(style)
+        .map_err(|e| format!("Failed to create {} node: {:?}", name, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn card_brand_detection() {
+        assert_eq!(CardBrand::detect("4242"), CardBrand::Visa);
+        assert_eq!(CardBrand::detect("5555"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("2221"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("3782"), CardBrand::Amex);
+        assert_eq!(CardBrand::detect("6011"), CardBrand::Discover);
+        assert_eq!(CardBrand::detect("3530"), CardBrand::Jcb);
+        assert_eq!(CardBrand::detect("3056"), CardBrand::DinersClub);
+        assert_eq!(CardBrand::detect("9999"), CardBrand::Unknown);
+        assert_eq!(CardBrand::Amex.cvc_length(), 4);
+    }
+
+    #[test]
+    fn card_luhn() {
+        assert!(luhn_check("4242424242424242"));
+        assert!(luhn_check("378282246310005"));
+        assert!(!luhn_check("4242424242424241"));
+        assert!(!luhn_check(""));
+        assert!(!luhn_check("4242a"));
+    }
+
+    #[test]
+    fn card_number_formatting() {
+        assert_eq!(format_card_number("4242424242424242", CardBrand::Visa), "4242 4242 4242 4242");
+        assert_eq!(format_card_number("378282246310005", CardBrand::Amex), "3782 822463 10005");
+        assert_eq!(format_card_number("42424", CardBrand::Visa), "4242 4");
+    }
+
+    #[test]
+    fn card_number_input_validation() {
+        let mut input = CardNumberInput::new();
+        input.set_value("4242-4242-4242-4242");
+        assert_eq!(input.value(), "4242424242424242");
+        assert_eq!(input.get_brand(), CardBrand::Visa);
+        assert!(input.is_valid());
+
+        input.backspace();
+        assert!(!input.is_valid());
+        input.insert_char('1');
+        assert!(!input.is_full());
+        assert!(!input.has_error());
+        input.set_value("4242 4242 4242 4241 000");
+        assert!(input.is_full());
+        assert!(input.has_error());
+    }
+
+    #[test]
+    fn card_number_full_at_any_valid_length() {
+        let mut input = CardNumberInput::new();
+        input.set_value("4222 2222 2222 2");
+        assert!(input.is_valid());
+        assert!(input.is_full());
+
+        // Still typing a longer Visa: neither full nor an error yet
+        input.set_value("4242 4242 4242 4242 1");
+        assert!(!input.is_full());
+        assert!(!input.has_error());
+
+        input.set_value("6200 0000 0000 0005");
+        assert_eq!(input.get_brand(), CardBrand::UnionPay);
+        assert!(input.is_full());
+        assert!(!input.has_error());
+    }
+
+    #[test]
+    fn card_number_input_limits_length() {
+        let mut input = CardNumberInput::new();
+        input.set_value("3782 822463 10005 9999");
+        assert_eq!(input.value(), "378282246310005");
+        input.insert_char('1');
+        assert_eq!(input.value().len(), 15);
+    }
+
+    #[test]
+    fn card_number_input_accepts_19_digit_visa() {
+        let mut input = CardNumberInput::new();
+        input.set_value("4242 4242 4242 4242 428");
+        assert_eq!(input.value(), "4242424242424242428");
+        assert!(input.is_valid());
+        assert_eq!(format_card_number(&input.value(), CardBrand::Visa), "4242 4242 4242 4242 428");
+        input.insert_char('1');
+        assert_eq!(input.value().len(), 19);
+    }
+
+    #[test]
+    fn card_number_on_complete() {
+        let completed = Rc::new(Cell::new(0));
+        let completed_clone = completed.clone();
+        let mut input = CardNumberInput::new().on_complete(move || completed_clone.set(completed_clone.get() + 1));
+        for c in "4242424242424242".chars() {
+            input.insert_char(c);
+        }
+        assert_eq!(completed.get(), 1);
+    }
+
+    #[test]
+    fn expiry_input_parsing() {
+        let mut expiry = ExpiryInput::new().reference_date(Date::new(2025, 6, 1));
+        expiry.insert_char('4');
+        assert_eq!(expiry.formatted(), "04");
+        expiry.insert_char('2');
+        expiry.insert_char('7');
+        assert_eq!(expiry.formatted(), "04/27");
+        assert_eq!(expiry.month_year(), Some((4, 2027)));
+        assert!(expiry.is_valid());
+
+        expiry.set_value("13/27");
+        assert!(!expiry.is_valid());
+        expiry.set_value("05/25");
+        assert!(!expiry.is_valid());
+        expiry.set_value("06/25");
+        assert!(expiry.is_valid());
+    }
+
+    #[test]
+    fn cvc_input_length() {
+        let mut cvc = CvcInput::new();
+        cvc.set_value("12345");
+        assert_eq!(cvc.digits.get(), "123");
+        assert!(cvc.is_valid());
+        cvc.set_length(4);
+        assert!(!cvc.is_valid());
+    }
+
+    #[test]
+    fn card_input_focus_advance() {
+        let mut card = CardInput::new().reference_date(Date::new(2025, 1, 1));
+        assert!(card.number.is_focused.get());
+
+        for c in "5555555555554444".chars() {
+            card.insert_char(c);
+        }
+        assert_eq!(card.focused_field.get(), CardField::Expiry);
+        assert!(card.expiry.is_focused.get());
+
+        for c in "1228".chars() {
+            card.insert_char(c);
+        }
+        assert_eq!(card.focused_field.get(), CardField::Cvc);
+
+        for c in "123".chars() {
+            card.insert_char(c);
+        }
+        assert!(card.is_valid());
+
+        card.backspace();
+        card.backspace();
+        card.backspace();
+        card.backspace();
+        assert_eq!(card.focused_field.get(), CardField::Expiry);
+        assert_eq!(card.expiry.formatted(), "12/2");
+        assert!(!card.is_valid());
+    }
+
+    #[test]
+    fn card_input_keeps_focus_on_a_valid_prefix() {
+        let mut card = CardInput::new();
+        // The first 13 digits are a valid Visa number on their own
+        for c in "4222222222222006".chars() {
+            card.insert_char(c);
+        }
+        assert_eq!(card.number.value(), "4222222222222006");
+        assert!(card.number.is_valid());
+        assert_eq!(card.focused_field.get(), CardField::Number);
+        assert_eq!(card.expiry.formatted(), "");
+    }
+
+    #[test]
+    fn card_input_build() {
+        let mut engine = LayoutEngine::new();
+        let mut card = CardInput::new();
+        assert!(card.build(&mut engine).is_ok());
+        assert!(card.node_id.is_some());
+        assert!(card.cvc.node_id.is_some());
+    }
+}
@@ -0,0 +1,884 @@
+// Chart Components - LineChart and BarChart with zoom, pan, and brushing
+// Interactive data visualization with shared crosshairs across charts
+
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use crate::chart_stream::{decimate, RingBuffer, StreamFollow};
+use crate::palette::{Palette, PaletteMode, SeriesColors};
+use std::ops::Range;
+
+/// What a mouse drag does on the plot area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragMode {
+    /// Move the visible x-range
+    Pan,
+    /// Select an x-range (emitted through the brush Signal)
+    Brush,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DragState {
+    mode: DragMode,
+    start_px: f32,
+    start_domain: (f64, f64),
+}
+
+/// Zoom, pan, brush, and crosshair state for a chart's x-axis 🔍
+///
+/// Signals are shared by cloning, so `link` makes several charts zoom,
+/// pan, and show the crosshair together.
+pub struct ChartInteraction {
+    /// Visible x-range (min, max)
+    pub x_domain: Signal<(f64, f64)>,
+    /// Brush selection in data units (None = nothing selected)
+    pub brush: Signal<Option<(f64, f64)>>,
+    /// Crosshair x position in data units (None = hidden)
+    pub crosshair_x: Signal<Option<f64>>,
+    /// Full data extent - zooming out stops here
+    pub data_bounds: (f64, f64),
+    /// Smallest visible span
+    pub min_span: f64,
+    pub zoom_enabled: bool,
+    pub pan_enabled: bool,
+    pub brush_enabled: bool,
+    /// Plot area in pixels (left edge, width)
+    pub plot_left: f32,
+    pub plot_width: f32,
+    drag: Option<DragState>,
+}
+
+impl ChartInteraction {
+    /// Create interaction state for an x-range
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            x_domain: Signal::new((min, max)),
+            brush: Signal::new(None),
+            crosshair_x: Signal::new(None),
+            data_bounds: (min, max),
+            min_span: ((max - min) / 1000.0).max(f64::EPSILON),
+            zoom_enabled: true,
+            pan_enabled: true,
+            brush_enabled: true,
+            plot_left: 0.0,
+            plot_width: 1.0,
+            drag: None,
+        }
+    }
+
+    /// Share zoom/pan and crosshair with another chart
+    pub fn link(&mut self, other: &ChartInteraction) {
+        self.x_domain = other.x_domain.clone();
+        self.crosshair_x = other.crosshair_x.clone();
+    }
+
+    /// Set the plot area in pixels
+    pub fn set_plot_area(&mut self, left: f32, width: f32) {
+        self.plot_left = left;
+        self.plot_width = width.max(1.0);
+    }
+
+    /// Set the full data extent, keeping the current view if it still fits
+    pub fn set_data_bounds(&mut self, min: f64, max: f64) {
+        let was_full = self.x_domain.get() == self.data_bounds;
+        self.data_bounds = (min, max.max(min + f64::EPSILON));
+        if was_full {
+            self.x_domain.set(self.data_bounds);
+        } else {
+            let (lo, hi) = self.x_domain.get();
+            self.set_domain(lo, hi);
+        }
+    }
+
+    /// Pixel x to data x
+    pub fn px_to_x(&self, px: f32) -> f64 {
+        let (min, max) = self.x_domain.get();
+        let t = (px - self.plot_left) as f64 / self.plot_width as f64;
+        min + t * (max - min)
+    }
+
+    /// Data x to pixel x
+    pub fn x_to_px(&self, x: f64) -> f32 {
+        let (min, max) = self.x_domain.get();
+        self.plot_left + (((x - min) / (max - min)) as f32) * self.plot_width
+    }
+
+    /// Set the visible range, clamped to the data bounds and minimum span
+    pub fn set_domain(&self, min: f64, max: f64) {
+        let (bound_min, bound_max) = self.data_bounds;
+        let full_span = bound_max - bound_min;
+        let span = (max - min).clamp(self.min_span.min(full_span), full_span);
+
+        let mut lo = min + ((max - min) - span) / 2.0;
+        lo = lo.clamp(bound_min, bound_max - span);
+        self.x_domain.set((lo, lo + span));
+    }
+
+    /// Zoom around a pixel position (factor > 1 zooms in)
+    pub fn zoom(&self, factor: f64, anchor_px: f32) {
+        if !self.zoom_enabled || factor <= 0.0 {
+            return;
+        }
+        let (min, max) = self.x_domain.get();
+        let anchor = self.px_to_x(anchor_px);
+        let new_span = ((max - min) / factor).max(self.min_span);
+        let t = (anchor - min) / (max - min);
+
+        let (bound_min, bound_max) = self.data_bounds;
+        let new_span = new_span.min(bound_max - bound_min);
+        let lo = (anchor - t * new_span).clamp(bound_min, bound_max - new_span);
+        self.x_domain.set((lo, lo + new_span));
+    }
+
+    /// Mouse wheel (positive delta = scroll up = zoom in)
+    pub fn handle_wheel(&self, delta: f32, px: f32) {
+        self.zoom(1.15f64.powf(delta as f64), px);
+    }
+
+    /// Trackpad / touch pinch (scale > 1 = fingers apart = zoom in)
+    pub fn handle_pinch(&self, scale: f32, center_px: f32) {
+        self.zoom(scale as f64, center_px);
+    }
+
+    /// Pan by a pixel distance (positive = content moves right)
+    pub fn pan_by_px(&self, delta_px: f32) {
+        if !self.pan_enabled {
+            return;
+        }
+        let (min, max) = self.x_domain.get();
+        let delta = -(delta_px as f64 / self.plot_width as f64) * (max - min);
+        self.pan_from((min, max), delta);
+    }
+
+    fn pan_from(&self, (min, max): (f64, f64), delta: f64) {
+        let (bound_min, bound_max) = self.data_bounds;
+        // A linked chart may have set a domain wider than our data
+        let span = (max - min).min(bound_max - bound_min);
+        let lo = (min + delta).clamp(bound_min, bound_max - span);
+        self.x_domain.set((lo, lo + span));
+    }
+
+    /// Start a drag on the plot area
+    pub fn begin_drag(&mut self, px: f32, mode: DragMode) {
+        let allowed = match mode {
+            DragMode::Pan => self.pan_enabled,
+            DragMode::Brush => self.brush_enabled,
+        };
+        if !allowed {
+            return;
+        }
+        self.drag = Some(DragState { mode, start_px: px, start_domain: self.x_domain.get() });
+        if mode == DragMode::Brush {
+            self.brush.set(None);
+        }
+    }
+
+    /// Continue a drag
+    pub fn drag_to(&mut self, px: f32) {
+        let Some(drag) = self.drag else { return };
+        match drag.mode {
+            DragMode::Pan => {
+                let (min, max) = drag.start_domain;
+                let delta = -((px - drag.start_px) as f64 / self.plot_width as f64) * (max - min);
+                self.pan_from(drag.start_domain, delta);
+            }
+            DragMode::Brush => {
+                let a = self.px_to_x(drag.start_px.clamp(self.plot_left, self.plot_left + self.plot_width));
+                let b = self.px_to_x(px.clamp(self.plot_left, self.plot_left + self.plot_width));
+                self.brush.set(Some((a.min(b), a.max(b))));
+            }
+        }
+    }
+
+    /// Finish a drag; an empty brush clears the selection
+    pub fn end_drag(&mut self) {
+        if let Some(DragState { mode: DragMode::Brush, .. }) = self.drag {
+            if let Some((a, b)) = self.brush.get() {
+                if b - a <= 0.0 {
+                    self.brush.set(None);
+                }
+            }
+        }
+        self.drag = None;
+    }
+
+    /// Is a drag in progress?
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Zoom to the current brush selection and clear it
+    pub fn zoom_to_brush(&self) {
+        if let Some((min, max)) = self.brush.get() {
+            self.set_domain(min, max);
+            self.brush.set(None);
+        }
+    }
+
+    /// Show the full data range
+    pub fn reset_zoom(&self) {
+        self.x_domain.set(self.data_bounds);
+    }
+
+    /// Move the crosshair to a pixel position (None if outside the plot)
+    pub fn hover(&self, px: f32) {
+        let inside = px >= self.plot_left && px <= self.plot_left + self.plot_width;
+        self.crosshair_x.set(if inside { Some(self.px_to_x(px)) } else { None });
+    }
+
+    /// Hide the crosshair
+    pub fn leave(&self) {
+        self.crosshair_x.set(None);
+    }
+}
+
+/// One line in a LineChart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub name: String,
+    /// Points sorted by x
+    pub points: Vec<(f64, f64)>,
+}
+
+impl ChartSeries {
+    /// Create a series (points are sorted by x)
+    pub fn new(name: impl Into<String>, mut points: Vec<(f64, f64)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { name: name.into(), points }
+    }
+
+    /// Indices of points inside an x-range, plus one neighbor on each side
+    /// so lines run to the plot edges
+    pub fn visible_range(&self, min: f64, max: f64) -> Range<usize> {
+        let start = self.points.partition_point(|p| p.0 < min).saturating_sub(1);
+        let end = (self.points.partition_point(|p| p.0 <= max) + 1).min(self.points.len());
+        start..end.max(start)
+    }
+
+    /// Point nearest to an x value
+    pub fn nearest(&self, x: f64) -> Option<(f64, f64)> {
+        let i = self.points.partition_point(|p| p.0 < x);
+        let candidates = [i.checked_sub(1), Some(i)];
+        candidates
+            .iter()
+            .flatten()
+            .filter_map(|&i| self.points.get(i).copied())
+            .min_by(|a, b| (a.0 - x).abs().total_cmp(&(b.0 - x).abs()))
+    }
+}
+
+/// LineChart component - series of (x, y) points with interactive x-axis
+///
+/// Static series hold their points; streams read from a shared
+/// `Signal<RingBuffer>` so producers can append without touching the chart.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut cpu = LineChart::new()
+///     .series("user", vec![(0.0, 10.0), (1.0, 12.0)])
+///     .series("system", vec![(0.0, 3.0), (1.0, 4.0)]);
+/// let mut memory = LineChart::new().series("rss", vec![(0.0, 512.0)]);
+/// memory.link_x_axis(&cpu); // zoom and crosshair move together
+///
+/// // Streaming: show the newest 30 seconds, scrolling smoothly
+/// let samples = Signal::new(RingBuffer::new(50_000));
+/// let mut live = LineChart::new().stream("latency", samples.clone()).follow_latest(30.0);
+/// live.tick(dt); // once per frame
+/// ```
+pub struct LineChart {
+    pub node_id: Option<NodeId>,
+    pub series: Vec<ChartSeries>,
+    /// Live series backed by ring buffers
+    pub streams: Vec<(String, Signal<RingBuffer>)>,
+    /// Keep the newest data in view (None = static x-axis)
+    pub follow: Option<StreamFollow>,
+    pub interaction: ChartInteraction,
+    pub colors: SeriesColors,
+    pub width: f32,
+    pub height: f32,
+    pub padding: f32,
+    pub line_width: f32,
+    pub background_color: (u8, u8, u8, u8),
+    pub grid_color: (u8, u8, u8, u8),
+    pub crosshair_color: (u8, u8, u8, u8),
+    pub brush_color: (u8, u8, u8, u8),
+}
+
+impl LineChart {
+    /// Create an empty line chart
+    pub fn new() -> Self {
+        let mut chart = Self {
+            node_id: None,
+            series: Vec::new(),
+            streams: Vec::new(),
+            follow: None,
+            interaction: ChartInteraction::new(0.0, 1.0),
+            colors: SeriesColors::new(Palette::categorical(PaletteMode::Light)),
+            width: 400.0,
+            height: 240.0,
+            padding: 32.0,
+            line_width: 2.0,
+            background_color: (255, 255, 255, 255),
+            grid_color: (230, 230, 230, 255),
+            crosshair_color: (120, 120, 120, 255),
+            brush_color: (68, 119, 170, 60),
+        };
+        chart.update_plot_area();
+        chart
+    }
+
+    /// Add a series
+    pub fn series(mut self, name: impl Into<String>, points: Vec<(f64, f64)>) -> Self {
+        self.add_series(name, points);
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self.update_plot_area();
+        self
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the padding around the plot area
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self.update_plot_area();
+        self
+    }
+
+    /// Set the palette used for automatic series colors
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.colors.set_palette(palette);
+        self
+    }
+
+    /// Add a series and extend the data bounds
+    pub fn add_series(&mut self, name: impl Into<String>, points: Vec<(f64, f64)>) {
+        let series = ChartSeries::new(name, points);
+        self.colors.color_for(&series.name);
+        self.series.push(series);
+        self.update_bounds();
+    }
+
+    /// Add a live series backed by a ring buffer
+    pub fn stream(mut self, name: impl Into<String>, buffer: Signal<RingBuffer>) -> Self {
+        self.add_stream(name, buffer);
+        self
+    }
+
+    /// Keep the newest `window` of x in view, scrolling smoothly as data arrives
+    pub fn follow_latest(mut self, window: f64) -> Self {
+        self.follow = Some(StreamFollow::new(window));
+        self
+    }
+
+    /// Add a live series backed by a ring buffer
+    pub fn add_stream(&mut self, name: impl Into<String>, buffer: Signal<RingBuffer>) {
+        let name = name.into();
+        self.colors.color_for(&name);
+        self.streams.push((name, buffer));
+        self.update_bounds();
+    }
+
+    /// Advance streaming by one frame: refresh bounds from the buffers and
+    /// scroll toward the newest point (unless the user paused following)
+    pub fn tick(&mut self, dt: f32) {
+        self.update_bounds();
+
+        let Some(follow) = self.follow else { return };
+        if follow.paused {
+            return;
+        }
+        let latest = self
+            .streams
+            .iter()
+            .filter_map(|(_, buffer)| buffer.with(|buffer| buffer.last().map(|p| p.0)))
+            .fold(f64::NEG_INFINITY, f64::max);
+        if latest.is_finite() {
+            let (min, max) = follow.step(self.interaction.x_domain.get(), latest, dt);
+            self.interaction.set_domain(min, max);
+        }
+    }
+
+    /// Stop following the newest data (e.g. while the user inspects history)
+    pub fn pause_following(&mut self) {
+        if let Some(follow) = &mut self.follow {
+            follow.paused = true;
+        }
+    }
+
+    /// Resume following the newest data
+    pub fn resume_following(&mut self) {
+        if let Some(follow) = &mut self.follow {
+            follow.paused = false;
+        }
+    }
+
+    /// Is the chart scrolling with incoming data?
+    pub fn is_following(&self) -> bool {
+        self.follow.is_some_and(|f| !f.paused)
+    }
+
+    fn update_bounds(&mut self) {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for series in &self.series {
+            if let (Some(first), Some(last)) = (series.points.first(), series.points.last()) {
+                min = min.min(first.0);
+                max = max.max(last.0);
+            }
+        }
+        for (_, buffer) in &self.streams {
+            buffer.with(|buffer| {
+                if let (Some(first), Some(last)) = (buffer.first(), buffer.last()) {
+                    min = min.min(first.0);
+                    max = max.max(last.0);
+                }
+            });
+        }
+        if min.is_finite() && max.is_finite() && (min, max) != self.interaction.data_bounds {
+            self.interaction.set_data_bounds(min, max);
+        }
+    }
+
+    /// Visible points of every series and stream, decimated to ~4 per pixel
+    /// column so huge datasets draw in constant time
+    pub fn plot_points(&self) -> Vec<(String, Vec<(f64, f64)>)> {
+        let (min, max) = self.interaction.x_domain.get();
+        let columns = self.interaction.plot_width.max(1.0) as usize;
+
+        let mut plotted: Vec<(String, Vec<(f64, f64)>)> = self
+            .series
+            .iter()
+            .map(|s| {
+                let visible = s.points[s.visible_range(min, max)].iter().copied();
+                (s.name.clone(), decimate(visible, min, max, columns))
+            })
+            .collect();
+        for (name, buffer) in &self.streams {
+            let points = buffer.with(|buffer| decimate(buffer.range(buffer.visible_range(min, max)), min, max, columns));
+            plotted.push((name.clone(), points));
+        }
+        plotted
+    }
+
+    fn update_plot_area(&mut self) {
+        self.interaction.set_plot_area(self.padding, self.width - self.padding * 2.0);
+    }
+
+    /// Share zoom/pan and the crosshair with another chart
+    pub fn link_x_axis(&mut self, other: &LineChart) {
+        self.interaction.link(&other.interaction);
+    }
+
+    /// Color for a series
+    pub fn series_color(&mut self, name: &str) -> (u8, u8, u8, u8) {
+        self.colors.color_for(name)
+    }
+
+    /// Y-range of the visible points (for auto-scaling the y-axis)
+    pub fn visible_y_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = self.interaction.x_domain.get();
+        let static_ys = self
+            .series
+            .iter()
+            .flat_map(|s| s.points[s.visible_range(min, max)].iter().map(|p| p.1));
+        let stream_ys: Vec<f64> = self
+            .streams
+            .iter()
+            .flat_map(|(_, buffer)| {
+                buffer.with(|buffer| buffer.range(buffer.visible_range(min, max)).map(|p| p.1).collect::<Vec<_>>())
+            })
+            .collect();
+
+        static_ys
+            .chain(stream_ys)
+            .fold(None, |acc, y| match acc {
+                None => Some((y, y)),
+                Some((lo, hi)) => Some((lo.min(y), hi.max(y))),
+            })
+    }
+
+    /// Tooltip rows (series name, x, y) at the crosshair
+    pub fn tooltip(&self) -> Vec<(String, f64, f64)> {
+        let Some(x) = self.interaction.crosshair_x.get() else {
+            return Vec::new();
+        };
+        let streams = self
+            .streams
+            .iter()
+            .filter_map(|(name, buffer)| buffer.with(|buffer| buffer.nearest(x)).map(|(px, py)| (name.clone(), px, py)));
+        self.series
+            .iter()
+            .filter_map(|s| s.nearest(x).map(|(px, py)| (s.name.clone(), px, py)))
+            .chain(streams)
+            .collect()
+    }
+
+    /// Handle mouse movement over the chart
+    pub fn handle_mouse_move(&mut self, x: f32) {
+        if self.interaction.is_dragging() {
+            self.interaction.drag_to(x);
+        }
+        self.interaction.hover(x);
+    }
+
+    /// Handle mouse press (shift = brush, otherwise pan)
+    /// Panning pauses stream following until `resume_following`
+    pub fn handle_mouse_down(&mut self, x: f32, shift: bool) {
+        let mode = if shift { DragMode::Brush } else { DragMode::Pan };
+        if mode == DragMode::Pan {
+            self.pause_following();
+        }
+        self.interaction.begin_drag(x, mode);
+    }
+
+    /// Handle mouse release
+    pub fn handle_mouse_up(&mut self) {
+        self.interaction.end_drag();
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = build_chart_leaf(engine, self.width, self.height, "line chart")?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for LineChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// BarChart component - one bar per category with interactive x-axis
+///
+/// The x-domain is in category slots: bar i spans [i, i + 1).
+pub struct BarChart {
+    pub node_id: Option<NodeId>,
+    pub categories: Vec<String>,
+    pub values: Vec<f64>,
+    pub interaction: ChartInteraction,
+    pub width: f32,
+    pub height: f32,
+    pub padding: f32,
+    /// Fraction of each slot filled by the bar
+    pub bar_ratio: f32,
+    pub bar_color: (u8, u8, u8, u8),
+    pub highlight_color: (u8, u8, u8, u8),
+}
+
+impl BarChart {
+    /// Create an empty bar chart
+    pub fn new() -> Self {
+        let palette = Palette::categorical(PaletteMode::Light);
+        let mut chart = Self {
+            node_id: None,
+            categories: Vec::new(),
+            values: Vec::new(),
+            interaction: ChartInteraction::new(0.0, 1.0),
+            width: 400.0,
+            height: 240.0,
+            padding: 32.0,
+            bar_ratio: 0.8,
+            bar_color: palette.color(0),
+            highlight_color: palette.color(1),
+        };
+        chart.interaction.min_span = 1.0;
+        chart.interaction.set_plot_area(chart.padding, chart.width - chart.padding * 2.0);
+        chart
+    }
+
+    /// Add a bar
+    pub fn bar(mut self, category: impl Into<String>, value: f64) -> Self {
+        self.add_bar(category, value);
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self.interaction.set_plot_area(self.padding, width - self.padding * 2.0);
+        self
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Add a bar and extend the data bounds
+    pub fn add_bar(&mut self, category: impl Into<String>, value: f64) {
+        self.categories.push(category.into());
+        self.values.push(value);
+        self.interaction.set_data_bounds(0.0, self.values.len() as f64);
+    }
+
+    /// Indices of bars at least partly visible
+    pub fn visible_bars(&self) -> Range<usize> {
+        let (min, max) = self.interaction.x_domain.get();
+        let start = (min.floor().max(0.0) as usize).min(self.values.len());
+        let end = (max.ceil().max(0.0) as usize).min(self.values.len());
+        start..end
+    }
+
+    /// Bar under the crosshair (for tooltips and highlighting)
+    pub fn hovered_bar(&self) -> Option<usize> {
+        let x = self.interaction.crosshair_x.get()?;
+        let index = x.floor();
+        (index >= 0.0 && (index as usize) < self.values.len()).then_some(index as usize)
+    }
+
+    /// Bars inside the brush selection
+    pub fn brushed_bars(&self) -> Range<usize> {
+        match self.interaction.brush.get() {
+            Some((min, max)) => {
+                let start = (min.floor().max(0.0) as usize).min(self.values.len());
+                let end = (max.ceil().max(0.0) as usize).min(self.values.len());
+                start..end
+            }
+            None => 0..0,
+        }
+    }
+
+    /// Handle mouse movement over the chart
+    pub fn handle_mouse_move(&mut self, x: f32) {
+        if self.interaction.is_dragging() {
+            self.interaction.drag_to(x);
+        }
+        self.interaction.hover(x);
+    }
+
+    /// Handle mouse press (shift = brush, otherwise pan)
+    pub fn handle_mouse_down(&mut self, x: f32, shift: bool) {
+        let mode = if shift { DragMode::Brush } else { DragMode::Pan };
+        self.interaction.begin_drag(x, mode);
+    }
+
+    /// Handle mouse release
+    pub fn handle_mouse_up(&mut self) {
+        self.interaction.end_drag();
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = build_chart_leaf(engine, self.width, self.height, "bar chart")?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for BarChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn build_chart_leaf(engine: &mut LayoutEngine, width: f32, height: f32, name: &str) -> Result<NodeId, String> {
+    let style = taffy::style::Style {
+        size: taffy::geometry::Size {
+            width: taffy::style::Dimension::Length(width),
+            height: taffy::style::Dimension::Length(height),
+        },
+        ..Default::default()
+    };
+
+    engine
+        .new_leaf(style)
+        .map_err(|e| format!("Failed to create {} node: {:?}", name, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: (f64, f64), b: (f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+    }
+
+    fn chart() -> LineChart {
+        // Plot area: x 0..100 px maps to data 0..10
+        LineChart::new()
+            .width(100.0)
+            .padding(0.0)
+            .series("a", (0..=10).map(|i| (i as f64, i as f64 * 2.0)).collect())
+    }
+
+    #[test]
+    fn chart_zoom_around_anchor() {
+        let chart = chart();
+        assert!(approx(chart.interaction.x_domain.get(), (0.0, 10.0)));
+
+        chart.interaction.zoom(2.0, 50.0);
+        assert!(approx(chart.interaction.x_domain.get(), (2.5, 7.5)));
+
+        chart.interaction.zoom(2.0, 0.0);
+        assert!(approx(chart.interaction.x_domain.get(), (2.5, 5.0)));
+    }
+
+    #[test]
+    fn chart_zoom_out_is_clamped() {
+        let chart = chart();
+        chart.interaction.handle_wheel(-10.0, 50.0);
+        assert!(approx(chart.interaction.x_domain.get(), (0.0, 10.0)));
+    }
+
+    #[test]
+    fn chart_drag_pan() {
+        let mut chart = chart();
+        chart.interaction.zoom(2.0, 50.0);
+        chart.handle_mouse_down(50.0, false);
+        chart.handle_mouse_move(30.0);
+        chart.handle_mouse_up();
+        assert!(approx(chart.interaction.x_domain.get(), (3.5, 8.5)));
+
+        chart.interaction.pan_by_px(-1000.0);
+        assert!(approx(chart.interaction.x_domain.get(), (5.0, 10.0)));
+    }
+
+    #[test]
+    fn chart_brush_selection() {
+        let mut chart = chart();
+        chart.handle_mouse_down(20.0, true);
+        chart.handle_mouse_move(60.0);
+        chart.handle_mouse_up();
+        assert_eq!(chart.interaction.brush.get(), Some((2.0, 6.0)));
+
+        chart.interaction.zoom_to_brush();
+        assert!(approx(chart.interaction.x_domain.get(), (2.0, 6.0)));
+        assert_eq!(chart.interaction.brush.get(), None);
+    }
+
+    #[test]
+    fn chart_linked_crosshair_and_zoom() {
+        let a = chart();
+        let mut b = LineChart::new().width(100.0).padding(0.0).series("b", vec![(0.0, 1.0), (10.0, 5.0)]);
+        b.link_x_axis(&a);
+
+        a.interaction.hover(40.0);
+        assert_eq!(b.interaction.crosshair_x.get(), Some(4.0));
+        assert_eq!(b.tooltip(), vec![("b".to_string(), 0.0, 1.0)]);
+        assert_eq!(a.tooltip(), vec![("a".to_string(), 4.0, 8.0)]);
+
+        a.interaction.zoom(2.0, 50.0);
+        assert!(approx(b.interaction.x_domain.get(), (2.5, 7.5)));
+
+        b.interaction.leave();
+        assert!(a.tooltip().is_empty());
+    }
+
+    #[test]
+    fn chart_linked_pan_with_different_extents() {
+        let mut narrow = chart();
+        let wide = LineChart::new().width(100.0).padding(0.0).series("b", vec![(0.0, 1.0), (100.0, 5.0)]);
+        narrow.link_x_axis(&wide);
+
+        wide.interaction.set_domain(25.0, 75.0);
+        narrow.interaction.pan_by_px(5.0);
+        assert!(approx(wide.interaction.x_domain.get(), (0.0, 10.0)));
+
+        wide.interaction.set_domain(25.0, 75.0);
+        narrow.handle_mouse_down(50.0, false);
+        narrow.handle_mouse_move(40.0);
+        narrow.handle_mouse_up();
+        assert!(approx(narrow.interaction.x_domain.get(), (0.0, 10.0)));
+
+        wide.interaction.pan_by_px(-10.0);
+        assert!(approx(wide.interaction.x_domain.get(), (1.0, 11.0)));
+    }
+
+    #[test]
+    fn chart_visible_y_range() {
+        let chart = chart();
+        chart.interaction.set_domain(2.0, 4.0);
+        assert_eq!(chart.visible_y_range(), Some((2.0, 10.0)));
+    }
+
+    #[test]
+    fn chart_stream_follows_latest() {
+        let samples = Signal::new(RingBuffer::new(1_000));
+        let mut live = LineChart::new()
+            .width(100.0)
+            .padding(0.0)
+            .stream("latency", samples.clone())
+            .follow_latest(10.0);
+
+        samples.update(|b| {
+            let mut b = b.clone();
+            b.extend((0..=50).map(|i| (i as f64, i as f64)));
+            b
+        });
+        live.tick(1.0 / 60.0);
+        assert!(approx(live.interaction.x_domain.get(), (40.0, 50.0)));
+        assert!(live.tooltip().is_empty());
+
+        // New data eases in rather than jumping
+        samples.update(|b| {
+            let mut b = b.clone();
+            b.push(52.0, 1.0);
+            b
+        });
+        live.tick(1.0 / 60.0);
+        let (_, hi) = live.interaction.x_domain.get();
+        assert!(hi > 50.0 && hi < 52.0);
+        assert_eq!(live.visible_y_range().map(|r| r.1), Some(50.0));
+
+        // Panning pauses following
+        live.handle_mouse_down(50.0, false);
+        live.handle_mouse_up();
+        assert!(!live.is_following());
+        live.resume_following();
+        assert!(live.is_following());
+    }
+
+    #[test]
+    fn chart_plot_points_are_decimated() {
+        let samples = Signal::new(RingBuffer::new(100_000));
+        samples.update(|b| {
+            let mut b = b.clone();
+            b.extend((0..50_000).map(|i| (i as f64, (i % 13) as f64)));
+            b
+        });
+        let live = LineChart::new().width(200.0).padding(0.0).stream("noise", samples);
+
+        let plotted = live.plot_points();
+        assert_eq!(plotted.len(), 1);
+        assert!(plotted[0].1.len() <= 4 * 200);
+    }
+
+    #[test]
+    fn bar_chart_slots() {
+        let mut bars = BarChart::new()
+            .width(100.0)
+            .bar("a", 1.0)
+            .bar("b", 2.0)
+            .bar("c", 3.0)
+            .bar("d", 4.0);
+        bars.padding = 0.0;
+        bars.interaction.set_plot_area(0.0, 100.0);
+
+        assert_eq!(bars.visible_bars(), 0..4);
+        bars.handle_mouse_move(60.0);
+        assert_eq!(bars.hovered_bar(), Some(2));
+
+        bars.interaction.zoom(100.0, 50.0);
+        assert!(approx(bars.interaction.x_domain.get(), (1.5, 2.5)));
+        assert_eq!(bars.visible_bars(), 1..3);
+    }
+
+    #[test]
+    fn chart_build() {
+        let mut engine = LayoutEngine::new();
+        let mut chart = chart();
+        assert!(chart.build(&mut engine).is_ok());
+        assert!(BarChart::new().build(&mut engine).is_ok());
+    }
+}
@@ -0,0 +1,504 @@
+// Chart Export - Save charts as PNG (headless CPU renderer) or standalone SVG
+// Charts describe themselves as a ChartScene, which both exporters consume;
+// PNG needs the `export-png` feature (it pulls in the CPU renderer)
+
+#[cfg(feature = "export-png")]
+use nebula_core::text::TextRenderer;
+#[cfg(feature = "export-png")]
+use nebula_gfx::{PathBuilder, RgbaImage};
+#[cfg(feature = "export-png")]
+use nebula_renderer_cpu::{Color, RenderTarget};
+use std::fmt::Write;
+use std::path::Path;
+use tracing::info;
+
+use crate::chart::{BarChart, LineChart};
+
+/// Horizontal text alignment relative to the anchor point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// One drawing operation in chart-local pixels (origin top-left)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartPrimitive {
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: (u8, u8, u8, u8),
+    },
+    Line {
+        points: Vec<(f32, f32)>,
+        width: f32,
+        color: (u8, u8, u8, u8),
+    },
+    /// `y` is the text baseline
+    Text {
+        x: f32,
+        y: f32,
+        text: String,
+        size: f32,
+        color: (u8, u8, u8, u8),
+        anchor: TextAnchor,
+    },
+}
+
+/// Backend-independent snapshot of a chart: axes, gridlines, series, legend 🖼️
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartScene {
+    pub width: f32,
+    pub height: f32,
+    pub primitives: Vec<ChartPrimitive>,
+}
+
+impl ChartScene {
+    /// Create an empty scene
+    pub fn new(width: f32, height: f32) -> Self {
+        Self { width, height, primitives: Vec::new() }
+    }
+
+    /// Add a filled rectangle
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: (u8, u8, u8, u8)) {
+        self.primitives.push(ChartPrimitive::Rect { x, y, width, height, color });
+    }
+
+    /// Add a polyline
+    pub fn line(&mut self, points: Vec<(f32, f32)>, width: f32, color: (u8, u8, u8, u8)) {
+        if points.len() > 1 {
+            self.primitives.push(ChartPrimitive::Line { points, width, color });
+        }
+    }
+
+    /// Add a text label
+    pub fn text(&mut self, x: f32, y: f32, text: impl Into<String>, size: f32, color: (u8, u8, u8, u8), anchor: TextAnchor) {
+        self.primitives.push(ChartPrimitive::Text { x, y, text: text.into(), size, color, anchor });
+    }
+
+    /// Standalone SVG document
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width,
+            h = self.height
+        );
+
+        for primitive in &self.primitives {
+            match primitive {
+                ChartPrimitive::Rect { x, y, width, height, color } => {
+                    let _ = writeln!(
+                        svg,
+                        r#"  <rect x="{}" y="{}" width="{}" height="{}" {}/>"#,
+                        x, y, width, height, svg_paint("fill", *color)
+                    );
+                }
+                ChartPrimitive::Line { points, width, color } => {
+                    let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.2},{:.2}", x, y)).collect();
+                    let _ = writeln!(
+                        svg,
+                        r#"  <polyline points="{}" fill="none" stroke-width="{}" stroke-linejoin="round" {}/>"#,
+                        points.join(" "), width, svg_paint("stroke", *color)
+                    );
+                }
+                ChartPrimitive::Text { x, y, text, size, color, anchor } => {
+                    let anchor = match anchor {
+                        TextAnchor::Start => "start",
+                        TextAnchor::Middle => "middle",
+                        TextAnchor::End => "end",
+                    };
+                    let _ = writeln!(
+                        svg,
+                        r#"  <text x="{}" y="{}" font-family="Roboto, sans-serif" font-size="{}" text-anchor="{}" {}>{}</text>"#,
+                        x, y, size, anchor, svg_paint("fill", *color), escape_xml(text)
+                    );
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Rasterize with the CPU renderer (scale 2.0 = HiDPI export)
+    #[cfg(feature = "export-png")]
+    pub fn render(&self, scale: f32) -> Result<RgbaImage, String> {
+        let scale = scale.max(0.01);
+        let mut target = RenderTarget::new(
+            (self.width * scale).ceil() as u32,
+            (self.height * scale).ceil() as u32,
+        )?;
+        let mut text_renderer: Option<TextRenderer> = None;
+
+        for primitive in &self.primitives {
+            match primitive {
+                ChartPrimitive::Rect { x, y, width, height, color } => {
+                    target.fill_rect(x * scale, y * scale, width * scale, height * scale, to_color(*color));
+                }
+                ChartPrimitive::Line { points, width, color } => {
+                    let mut path = PathBuilder::new().move_to(points[0].0 * scale, points[0].1 * scale);
+                    for &(x, y) in &points[1..] {
+                        path = path.line_to(x * scale, y * scale);
+                    }
+                    target.draw_path(&path.build(), None, Some((to_color(*color), width * scale)));
+                }
+                ChartPrimitive::Text { x, y, text, size, color, anchor } => {
+                    // Only load the font if the scene has text
+                    let renderer = match &mut text_renderer {
+                        Some(renderer) => renderer,
+                        None => text_renderer.insert(TextRenderer::new()?),
+                    };
+                    let size = (size * scale).round().max(1.0) as u32;
+                    let text_width = renderer.measure_text(text, size);
+                    let mut pen_x = x * scale - match anchor {
+                        TextAnchor::Start => 0.0,
+                        TextAnchor::Middle => text_width / 2.0,
+                        TextAnchor::End => text_width,
+                    };
+                    let baseline = y * scale;
+                    for glyph in renderer.rasterize_text(text, size) {
+                        let left = (pen_x + glyph.x_offset as f32).round() as i32;
+                        let top = (baseline - glyph.y_offset as f32 - glyph.height as f32).round() as i32;
+                        target.draw_mask(left, top, glyph.width, &glyph.bitmap, to_color(*color));
+                        pen_x += glyph.advance_width;
+                    }
+                }
+            }
+        }
+
+        Ok(target.capture())
+    }
+
+    /// Encode as PNG
+    #[cfg(feature = "export-png")]
+    pub fn to_png(&self, scale: f32) -> Result<Vec<u8>, String> {
+        self.render(scale)?.encode_png()
+    }
+}
+
+#[cfg(feature = "export-png")]
+fn to_color((r, g, b, a): (u8, u8, u8, u8)) -> Color {
+    Color::rgba(r, g, b, a)
+}
+
+fn svg_paint(attribute: &str, (r, g, b, a): (u8, u8, u8, u8)) -> String {
+    if a == 255 {
+        format!(r##"{}="#{:02x}{:02x}{:02x}""##, attribute, r, g, b)
+    } else {
+        format!(
+            r##"{attr}="#{:02x}{:02x}{:02x}" {attr}-opacity="{:.3}""##,
+            r, g, b, a as f32 / 255.0,
+            attr = attribute
+        )
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Evenly spaced "nice" tick values (steps of 1, 2, or 5 × 10ⁿ) covering a range
+pub fn nice_ticks(min: f64, max: f64, target_count: usize) -> Vec<f64> {
+    if !min.is_finite() || !max.is_finite() || max <= min {
+        return if min.is_finite() { vec![min] } else { Vec::new() };
+    }
+    let step = nice_step((max - min) / target_count.max(1) as f64);
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+fn nice_step(rough: f64) -> f64 {
+    let magnitude = 10f64.powf(rough.log10().floor());
+    let residual = rough / magnitude;
+    let nice = if residual <= 1.0 {
+        1.0
+    } else if residual <= 2.0 {
+        2.0
+    } else if residual <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// Tick label with just enough decimals for the step
+pub fn format_tick(value: f64, step: f64) -> String {
+    let decimals = if step >= 1.0 { 0 } else { (-step.log10().floor()) as usize };
+    let label = format!("{:.*}", decimals, value);
+    // Avoid "-0"
+    if label.trim_start_matches('-').chars().all(|c| c == '0' || c == '.') {
+        label.trim_start_matches('-').to_string()
+    } else {
+        label
+    }
+}
+
+const AXIS_COLOR: (u8, u8, u8, u8) = (120, 120, 120, 255);
+const LABEL_COLOR: (u8, u8, u8, u8) = (60, 60, 60, 255);
+const LABEL_SIZE: f32 = 11.0;
+
+/// Gridlines, axis lines, and y tick labels for a plot area
+fn draw_y_axis(scene: &mut ChartScene, plot: (f32, f32, f32, f32), y_range: (f64, f64), grid_color: (u8, u8, u8, u8)) {
+    let (left, top, width, height) = plot;
+    let (y_min, y_max) = y_range;
+    let ticks = nice_ticks(y_min, y_max, 5);
+    let step = if ticks.len() > 1 { ticks[1] - ticks[0] } else { 1.0 };
+
+    for tick in ticks {
+        let y = top + height - ((tick - y_min) / (y_max - y_min)) as f32 * height;
+        scene.line(vec![(left, y), (left + width, y)], 1.0, grid_color);
+        scene.text(left - 6.0, y + LABEL_SIZE / 3.0, format_tick(tick, step), LABEL_SIZE, LABEL_COLOR, TextAnchor::End);
+    }
+    scene.line(vec![(left, top), (left, top + height), (left + width, top + height)], 1.0, AXIS_COLOR);
+}
+
+/// Pad a data range so lines don't touch the plot edges
+fn padded_range((min, max): (f64, f64)) -> (f64, f64) {
+    if max > min {
+        let pad = (max - min) * 0.05;
+        (min - pad, max + pad)
+    } else {
+        (min - 1.0, max + 1.0)
+    }
+}
+
+impl LineChart {
+    /// Snapshot of the current view (zoom, series, axes, legend) for export
+    pub fn scene(&mut self) -> ChartScene {
+        let mut scene = ChartScene::new(self.width, self.height);
+        scene.rect(0.0, 0.0, self.width, self.height, self.background_color);
+
+        let plot = (
+            self.interaction.plot_left,
+            self.padding,
+            self.interaction.plot_width,
+            (self.height - self.padding * 2.0).max(1.0),
+        );
+        let (left, top, width, height) = plot;
+        let (x_min, x_max) = self.interaction.x_domain.get();
+        let (y_min, y_max) = padded_range(self.visible_y_range().unwrap_or((0.0, 1.0)));
+
+        draw_y_axis(&mut scene, plot, (y_min, y_max), self.grid_color);
+
+        // X tick labels
+        let x_ticks = nice_ticks(x_min, x_max, 5);
+        let x_step = if x_ticks.len() > 1 { x_ticks[1] - x_ticks[0] } else { 1.0 };
+        for tick in x_ticks {
+            let x = self.interaction.x_to_px(tick);
+            scene.line(vec![(x, top + height), (x, top + height + 4.0)], 1.0, AXIS_COLOR);
+            scene.text(x, top + height + 6.0 + LABEL_SIZE, format_tick(tick, x_step), LABEL_SIZE, LABEL_COLOR, TextAnchor::Middle);
+        }
+
+        // Series, clipped to the plot by the visible range
+        let to_px = |(x, y): (f64, f64)| {
+            let px = left + ((x - x_min) / (x_max - x_min)) as f32 * width;
+            let py = top + height - ((y - y_min) / (y_max - y_min)) as f32 * height;
+            (px.clamp(left, left + width), py)
+        };
+        for (name, points) in self.plot_points() {
+            let color = self.colors.color_for(&name);
+            scene.line(points.into_iter().map(to_px).collect(), self.line_width, color);
+        }
+
+        // Legend along the top padding
+        let mut legend_x = left;
+        let legend_y = (self.padding - LABEL_SIZE) / 2.0;
+        let names: Vec<String> = self
+            .series
+            .iter()
+            .map(|s| s.name.clone())
+            .chain(self.streams.iter().map(|(name, _)| name.clone()))
+            .collect();
+        for name in names {
+            let color = self.colors.color_for(&name);
+            scene.rect(legend_x, legend_y + 1.0, LABEL_SIZE - 2.0, LABEL_SIZE - 2.0, color);
+            scene.text(legend_x + LABEL_SIZE + 2.0, legend_y + LABEL_SIZE - 1.0, name.clone(), LABEL_SIZE, LABEL_COLOR, TextAnchor::Start);
+            legend_x += LABEL_SIZE + 12.0 + name.chars().count() as f32 * LABEL_SIZE * 0.6;
+        }
+
+        scene
+    }
+
+    /// Export the current view as a standalone SVG document
+    pub fn export_svg(&mut self) -> String {
+        self.scene().to_svg()
+    }
+
+    /// Export the current view as PNG bytes (scale 2.0 = HiDPI)
+    #[cfg(feature = "export-png")]
+    pub fn export_png(&mut self, scale: f32) -> Result<Vec<u8>, String> {
+        self.scene().to_png(scale)
+    }
+
+    /// Save the current view, picking PNG or SVG from the file extension
+    /// (PNG only with the `export-png` feature)
+    pub fn export_to_file(&mut self, path: impl AsRef<Path>) -> Result<(), String> {
+        let scene = self.scene();
+        write_scene(&scene, path.as_ref())
+    }
+}
+
+impl BarChart {
+    /// Snapshot of the current view (visible bars, axes, labels) for export
+    pub fn scene(&self) -> ChartScene {
+        let mut scene = ChartScene::new(self.width, self.height);
+        scene.rect(0.0, 0.0, self.width, self.height, (255, 255, 255, 255));
+
+        let plot = (
+            self.interaction.plot_left,
+            self.padding,
+            self.interaction.plot_width,
+            (self.height - self.padding * 2.0).max(1.0),
+        );
+        let (_, top, _, height) = plot;
+        let visible = self.visible_bars();
+
+        // Bars grow from zero (or from the lowest value if it's negative)
+        let values = &self.values[visible.clone()];
+        let y_max = values.iter().copied().fold(0.0, f64::max);
+        let y_min = values.iter().copied().fold(0.0, f64::min);
+        let (y_min, y_max) = if y_max > y_min { (y_min, y_max * 1.05) } else { (0.0, 1.0) };
+        draw_y_axis(&mut scene, plot, (y_min, y_max), (230, 230, 230, 255));
+
+        let brushed = self.brushed_bars();
+        let to_y = |value: f64| top + height - ((value - y_min) / (y_max - y_min)) as f32 * height;
+        for i in visible {
+            let slot_left = self.interaction.x_to_px(i as f64);
+            let slot_right = self.interaction.x_to_px(i as f64 + 1.0);
+            let slot = slot_right - slot_left;
+            let bar_width = slot * self.bar_ratio;
+            let x = slot_left + (slot - bar_width) / 2.0;
+
+            let (y0, y1) = (to_y(0.0), to_y(self.values[i]));
+            let color = if brushed.contains(&i) { self.highlight_color } else { self.bar_color };
+            scene.rect(x, y0.min(y1), bar_width, (y1 - y0).abs(), color);
+            scene.text(
+                slot_left + slot / 2.0,
+                top + height + 6.0 + LABEL_SIZE,
+                self.categories[i].clone(),
+                LABEL_SIZE,
+                LABEL_COLOR,
+                TextAnchor::Middle,
+            );
+        }
+
+        scene
+    }
+
+    /// Export the current view as a standalone SVG document
+    pub fn export_svg(&self) -> String {
+        self.scene().to_svg()
+    }
+
+    /// Export the current view as PNG bytes (scale 2.0 = HiDPI)
+    #[cfg(feature = "export-png")]
+    pub fn export_png(&self, scale: f32) -> Result<Vec<u8>, String> {
+        self.scene().to_png(scale)
+    }
+
+    /// Save the current view, picking PNG or SVG from the file extension
+    /// (PNG only with the `export-png` feature)
+    pub fn export_to_file(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        write_scene(&self.scene(), path.as_ref())
+    }
+}
+
+fn write_scene(scene: &ChartScene, path: &Path) -> Result<(), String> {
+    let extension = path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase());
+    let bytes = match extension.as_deref() {
+        Some("svg") => scene.to_svg().into_bytes(),
+        #[cfg(feature = "export-png")]
+        Some("png") => scene.to_png(1.0)?,
+        #[cfg(not(feature = "export-png"))]
+        Some("png") => return Err("PNG export needs the export-png feature".to_string()),
+        _ => return Err(format!("Unsupported export format for {} (use .png or .svg)", path.display())),
+    };
+    std::fs::write(path, bytes).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    info!("📊 Exported chart to {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart() -> LineChart {
+        LineChart::new()
+            .width(200.0)
+            .height(120.0)
+            .series("cpu", (0..=10).map(|i| (i as f64, i as f64)).collect())
+            .series("mem & swap", vec![(0.0, 5.0), (10.0, 5.0)])
+    }
+
+    #[test]
+    fn nice_ticks_use_round_steps() {
+        assert_eq!(nice_ticks(0.0, 10.0, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        let fractional = nice_ticks(0.13, 0.61, 4);
+        assert_eq!(fractional.len(), 3);
+        assert!((fractional[0] - 0.2).abs() < 1e-9 && (fractional[2] - 0.6).abs() < 1e-9);
+        assert_eq!(nice_ticks(5.0, 5.0, 5), vec![5.0]);
+    }
+
+    #[test]
+    fn format_tick_decimals() {
+        assert_eq!(format_tick(4.0, 2.0), "4");
+        assert_eq!(format_tick(0.25, 0.05), "0.25");
+        assert_eq!(format_tick(-0.0001, 0.5), "0.0");
+    }
+
+    #[test]
+    fn line_chart_svg_has_series_legend_and_axes() {
+        let svg = chart().export_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.matches("<polyline").count() >= 3);
+        assert!(svg.contains(">cpu</text>"));
+        assert!(svg.contains(">mem &amp; swap</text>"));
+        assert!(svg.contains(">10</text>"));
+    }
+
+    #[test]
+    #[cfg(feature = "export-png")]
+    fn line_chart_png_renders_background_and_lines() {
+        let image = chart().scene().render(1.0).unwrap();
+        assert_eq!((image.width, image.height), (200, 120));
+        assert_eq!(image.pixel(1, 1), Some([255, 255, 255, 255]));
+        // Something other than the background was drawn in the plot area
+        let drawn = (40..160).any(|x| image.pixel(x, 60) != Some([255, 255, 255, 255]));
+        assert!(drawn);
+
+        let png = chart().export_png(2.0).unwrap();
+        assert_eq!(&png[..4], &[137, 80, 78, 71]);
+    }
+
+    #[test]
+    fn bar_chart_scene_has_visible_bars() {
+        let bars = BarChart::new().width(200.0).bar("a", 1.0).bar("b", 3.0);
+        let scene = bars.scene();
+        let labels: Vec<&str> = scene
+            .primitives
+            .iter()
+            .filter_map(|p| match p {
+                ChartPrimitive::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert!(labels.contains(&"a") && labels.contains(&"b"));
+        assert!(bars.export_svg().contains("<rect"));
+    }
+
+    #[test]
+    fn export_to_file_rejects_unknown_extension() {
+        assert!(chart().export_to_file("chart.bmp").is_err());
+    }
+}
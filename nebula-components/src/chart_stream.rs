@@ -0,0 +1,389 @@
+// Chart Streaming - Ring buffers, decimation, and Sparkline for live data
+// Append-only telemetry that scrolls smoothly at 60fps with tens of thousands of points
+
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Bounded, append-only buffer of (x, y) points 📈
+///
+/// When full, the oldest point is dropped. X values must not decrease;
+/// out-of-order points are rejected so the buffer stays sorted for
+/// binary searches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingBuffer {
+    capacity: usize,
+    points: VecDeque<(f64, f64)>,
+}
+
+impl RingBuffer {
+    /// Create an empty buffer holding at most `capacity` points
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self { capacity, points: VecDeque::with_capacity(capacity) }
+    }
+
+    /// Maximum number of points
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of points
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Is the buffer empty?
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Append a point, evicting the oldest when full
+    /// Returns false if x is older than the newest point
+    pub fn push(&mut self, x: f64, y: f64) -> bool {
+        if self.points.back().is_some_and(|&(last, _)| x < last) {
+            return false;
+        }
+        if self.points.len() == self.capacity {
+            self.points.pop_front();
+        }
+        self.points.push_back((x, y));
+        true
+    }
+
+    /// Append several points
+    pub fn extend(&mut self, points: impl IntoIterator<Item = (f64, f64)>) {
+        for (x, y) in points {
+            self.push(x, y);
+        }
+    }
+
+    /// Remove all points
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Oldest point
+    pub fn first(&self) -> Option<(f64, f64)> {
+        self.points.front().copied()
+    }
+
+    /// Newest point
+    pub fn last(&self) -> Option<(f64, f64)> {
+        self.points.back().copied()
+    }
+
+    /// Point at an index (0 = oldest)
+    pub fn get(&self, index: usize) -> Option<(f64, f64)> {
+        self.points.get(index).copied()
+    }
+
+    /// Iterate oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// Indices of points inside an x-range, plus one neighbor on each side
+    pub fn visible_range(&self, min: f64, max: f64) -> Range<usize> {
+        let start = self.points.partition_point(|p| p.0 < min).saturating_sub(1);
+        let end = (self.points.partition_point(|p| p.0 <= max) + 1).min(self.points.len());
+        start..end.max(start)
+    }
+
+    /// Points in an index range
+    pub fn range(&self, range: Range<usize>) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.points.range(range).copied()
+    }
+
+    /// Point nearest to an x value
+    pub fn nearest(&self, x: f64) -> Option<(f64, f64)> {
+        let i = self.points.partition_point(|p| p.0 < x);
+        [i.checked_sub(1), Some(i)]
+            .iter()
+            .flatten()
+            .filter_map(|&i| self.get(i))
+            .min_by(|a, b| (a.0 - x).abs().total_cmp(&(b.0 - x).abs()))
+    }
+}
+
+/// Reduce points to at most ~4 per pixel column without changing the drawn shape
+///
+/// Each column keeps its first, lowest, highest, and last point (M4
+/// decimation), so spikes survive while 100k points draw as ~4 × width.
+pub fn decimate(
+    points: impl IntoIterator<Item = (f64, f64)>,
+    x_min: f64,
+    x_max: f64,
+    columns: usize,
+) -> Vec<(f64, f64)> {
+    let columns = columns.max(1);
+    let span = (x_max - x_min).max(f64::EPSILON);
+    let mut output = Vec::new();
+
+    // Per column: (input order, point) for the first, min, max, and last point
+    type Bucket = [(usize, (f64, f64)); 4];
+    let mut bucket: Option<(i64, Bucket)> = None;
+    let flush = |bucket: &Bucket, output: &mut Vec<(f64, f64)>| {
+        let mut kept = bucket.to_vec();
+        kept.sort_by_key(|&(order, _)| order);
+        kept.dedup_by_key(|&mut (order, _)| order);
+        output.extend(kept.into_iter().map(|(_, p)| p));
+    };
+
+    for (order, point) in points.into_iter().enumerate() {
+        let column = (((point.0 - x_min) / span) * columns as f64).floor() as i64;
+        match &mut bucket {
+            Some((current, [_, min, max, last])) if *current == column => {
+                if point.1 < min.1 .1 {
+                    *min = (order, point);
+                }
+                if point.1 > max.1 .1 {
+                    *max = (order, point);
+                }
+                *last = (order, point);
+            }
+            _ => {
+                if let Some((_, b)) = &bucket {
+                    flush(b, &mut output);
+                }
+                bucket = Some((column, [(order, point); 4]));
+            }
+        }
+    }
+    if let Some((_, b)) = &bucket {
+        flush(b, &mut output);
+    }
+
+    output
+}
+
+/// Keeps the visible window pinned to the newest data, easing as it scrolls
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StreamFollow {
+    /// Width of the visible x-range
+    pub window: f64,
+    /// How quickly the view catches up (per second, higher = snappier)
+    pub smoothing: f64,
+    /// Paused while the user pans or zooms
+    pub paused: bool,
+}
+
+impl StreamFollow {
+    /// Follow the newest `window` of data
+    pub fn new(window: f64) -> Self {
+        Self { window, smoothing: 12.0, paused: false }
+    }
+
+    /// Next view range for a frame, easing the right edge toward `latest`
+    /// Jumps straight there if the view is more than a window behind
+    pub fn step(&self, current: (f64, f64), latest: f64, dt: f32) -> (f64, f64) {
+        let target = latest;
+        let right = if (target - current.1).abs() > self.window || dt <= 0.0 {
+            target
+        } else {
+            let t = 1.0 - (-self.smoothing * dt as f64).exp();
+            current.1 + (target - current.1) * t
+        };
+        (right - self.window, right)
+    }
+}
+
+/// Sparkline component - tiny axis-less line chart for live values
+///
+/// # Example
+/// ```rust,ignore
+/// let fps = Signal::new(RingBuffer::new(120));
+/// let mut sparkline = Sparkline::new(fps.clone()).width(80.0).height(20.0);
+/// sparkline.push(59.8);
+/// ```
+pub struct Sparkline {
+    pub node_id: Option<NodeId>,
+    /// Shared data - push from anywhere holding a clone of the Signal
+    pub data: Signal<RingBuffer>,
+    pub width: f32,
+    pub height: f32,
+    pub line_width: f32,
+    pub line_color: (u8, u8, u8, u8),
+    /// Area under the line (None = no fill)
+    pub fill_color: Option<(u8, u8, u8, u8)>,
+    pub show_last_point: bool,
+}
+
+impl Sparkline {
+    /// Create a sparkline over a shared buffer
+    pub fn new(data: Signal<RingBuffer>) -> Self {
+        Self {
+            node_id: None,
+            data,
+            width: 100.0,
+            height: 24.0,
+            line_width: 1.5,
+            line_color: (68, 119, 170, 255),
+            fill_color: None,
+            show_last_point: true,
+        }
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the line color
+    pub fn color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.line_color = (r, g, b, a);
+        self
+    }
+
+    /// Fill the area under the line
+    pub fn fill(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.fill_color = Some((r, g, b, a));
+        self
+    }
+
+    /// Append a value (x is one step after the newest point)
+    pub fn push(&self, value: f64) {
+        self.data.update_in_place(|buffer| {
+            let x = buffer.last().map_or(0.0, |(x, _)| x + 1.0);
+            buffer.push(x, value);
+        });
+    }
+
+    /// Newest value
+    pub fn latest(&self) -> Option<f64> {
+        self.data.with(|buffer| buffer.last().map(|(_, y)| y))
+    }
+
+    /// Line points in local pixels (origin top-left), decimated to the width
+    pub fn plot_points(&self) -> Vec<(f32, f32)> {
+        let columns = self.width.max(1.0) as usize;
+        let Some((x_min, x_max, points)) = self.data.with(|buffer| {
+            let (x_min, x_max) = (buffer.first()?.0, buffer.last()?.0);
+            Some((x_min, x_max, decimate(buffer.iter(), x_min, x_max, columns)))
+        }) else {
+            return Vec::new();
+        };
+
+        let (y_min, y_max) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| {
+            (lo.min(p.1), hi.max(p.1))
+        });
+        let x_span = (x_max - x_min).max(f64::EPSILON);
+        let y_span = y_max - y_min;
+        let inset = self.line_width / 2.0;
+        let usable = (self.height - self.line_width).max(0.0);
+
+        points
+            .iter()
+            .map(|&(x, y)| {
+                let px = ((x - x_min) / x_span) as f32 * self.width;
+                // Flat data sits in the middle
+                let t = if y_span > 0.0 { ((y - y_min) / y_span) as f32 } else { 0.5 };
+                (px, inset + (1.0 - t) * usable)
+            })
+            .collect()
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let style = taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Length(self.width),
+                height: taffy::style::Dimension::Length(self.height),
+            },
+            ..Default::default()
+        };
+
+        let node = engine
+            .new_leaf(style)
+            .map_err(|e| format!("Failed to create sparkline node: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_buffer_evicts_oldest() {
+        let mut buffer = RingBuffer::new(3);
+        buffer.extend([(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.first(), Some((1.0, 1.0)));
+        assert_eq!(buffer.last(), Some((3.0, 3.0)));
+    }
+
+    #[test]
+    fn ring_buffer_rejects_out_of_order() {
+        let mut buffer = RingBuffer::new(4);
+        assert!(buffer.push(5.0, 1.0));
+        assert!(!buffer.push(4.0, 1.0));
+        assert!(buffer.push(5.0, 2.0));
+        assert_eq!(buffer.len(), 2);
+    }
+
+    #[test]
+    fn ring_buffer_visible_range_and_nearest() {
+        let mut buffer = RingBuffer::new(100);
+        buffer.extend((0..10).map(|i| (i as f64, i as f64)));
+        assert_eq!(buffer.visible_range(3.0, 5.0), 2..7);
+        assert_eq!(buffer.nearest(6.4), Some((6.0, 6.0)));
+    }
+
+    #[test]
+    fn decimate_keeps_spikes() {
+        // 10k points into 10 columns with one spike
+        let points: Vec<(f64, f64)> = (0..10_000)
+            .map(|i| (i as f64, if i == 5_123 { 100.0 } else { (i % 7) as f64 }))
+            .collect();
+        let reduced = decimate(points.iter().copied(), 0.0, 10_000.0, 10);
+        assert!(reduced.len() <= 40);
+        assert!(reduced.contains(&(5_123.0, 100.0)));
+        assert_eq!(reduced.first(), Some(&(0.0, 0.0)));
+        assert_eq!(reduced.last(), points.last());
+        assert!(reduced.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn decimate_sparse_data_is_unchanged() {
+        let points = vec![(0.0, 1.0), (5.0, 2.0), (9.0, 3.0)];
+        assert_eq!(decimate(points.clone(), 0.0, 10.0, 100), points);
+    }
+
+    #[test]
+    fn stream_follow_eases_and_snaps() {
+        let follow = StreamFollow::new(10.0);
+        let (lo, hi) = follow.step((0.0, 10.0), 12.0, 1.0 / 60.0);
+        assert!(hi > 10.0 && hi < 12.0);
+        assert!((hi - lo - 10.0).abs() < 1e-9);
+
+        // Far behind: jump
+        assert_eq!(follow.step((0.0, 10.0), 100.0, 1.0 / 60.0), (90.0, 100.0));
+    }
+
+    #[test]
+    fn sparkline_push_and_plot() {
+        let sparkline = Sparkline::new(Signal::new(RingBuffer::new(3))).width(20.0).height(10.0);
+        sparkline.push(1.0);
+        sparkline.push(3.0);
+        sparkline.push(2.0);
+        sparkline.push(5.0);
+        assert_eq!(sparkline.latest(), Some(5.0));
+
+        let points = sparkline.plot_points();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].0, 0.0);
+        assert_eq!(points[2].0, 20.0);
+        // Highest value at the top (inset by half the line width)
+        assert_eq!(points[2].1, 0.75);
+    }
+}
@@ -1,4 +1,4 @@
-use nebula_core::{Signal, LayoutEngine, NodeId, Layout};
+use nebula_core::{Signal, LayoutEngine, NodeId, Layout, FocusKey, Focusable};
 use taffy::prelude::*;
 use tracing::info;
 use std::rc::Rc;
@@ -175,6 +175,20 @@ impl Checkbox {
     }
 }
 
+impl Focusable for Checkbox {
+    fn handle_key(&self, key: FocusKey, _shift: bool) -> bool {
+        if key == FocusKey::Space {
+            self.toggle();
+            return true;
+        }
+        false
+    }
+
+    fn focus_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        Some(self.bounds())
+    }
+}
+
 impl Default for Checkbox {
     fn default() -> Self {
         Self::new()
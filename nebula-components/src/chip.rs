@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
 
 /// Chip variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,8 +44,9 @@ pub struct Chip {
 }
 
 impl Chip {
-    /// Create a new Chip component
+    /// Create a new Chip component (colors come from the current theme)
     pub fn new(label: impl Into<String>) -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             label: Signal::new(label.into()),
@@ -57,16 +59,31 @@ impl Chip {
             height: 32.0,
             padding_x: 12.0,
             padding_y: 6.0,
-            background_color: (229, 231, 235, 255), // Gray
-            text_color: (31, 41, 55, 255),
-            border_color: (209, 213, 219, 255),
-            selected_color: (59, 130, 246, 255), // Blue
+            background_color: colors.hover,
+            text_color: colors.text,
+            border_color: colors.border,
+            selected_color: colors.primary,
             border_radius: 16.0,
             on_click: None,
             on_close: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.hover;
+        self.text_color = colors.text;
+        self.border_color = colors.border;
+        self.selected_color = colors.primary;
+    }
+
     /// Set the label
     pub fn label(self, label: impl Into<String>) -> Self {
         self.label.set(label.into());
@@ -0,0 +1,1830 @@
+// CodeEditor Component - Monospaced multi-line editor with line numbers and multiple cursors
+// Ctrl+click adds carets, Ctrl+D selects the next occurrence, Alt+drag makes a column selection
+
+use crate::language_client::{CompletionItem, Diagnostic, HoverInfo, LanguageClient, Location};
+use crate::popover::{Popover, PopoverPosition, PopoverTrigger};
+use crate::select::Select;
+use crate::spellcheck::squiggle_points;
+use nebula_core::{ClickCounter, FocusKey, Focusable, LayoutEngine, NodeId, Signal};
+use nebula_gfx::draw_list::DrawList;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::info;
+
+/// Called with the new buffer contents after every edit
+pub type EditorChangeCallback = Rc<dyn Fn(&str)>;
+
+/// Called when go-to-definition finds a target
+pub type DefinitionCallback = Rc<dyn Fn(&Location)>;
+
+/// Computes fold regions for a buffer (language-aware folding)
+pub type FoldProvider = Rc<dyn Fn(&str) -> Vec<FoldRegion>>;
+
+/// Line / column in the buffer (columns count chars)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TextPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl TextPosition {
+    /// Create a position
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// A selection (char offsets); the caret sits at `head`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    pub anchor: usize,
+    pub head: usize,
+}
+
+impl Selection {
+    /// A selection from `anchor` to `head`
+    pub fn new(anchor: usize, head: usize) -> Self {
+        Self { anchor, head }
+    }
+
+    /// An empty selection (just a caret)
+    pub fn caret(offset: usize) -> Self {
+        Self { anchor: offset, head: offset }
+    }
+
+    /// Is this just a caret?
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.head
+    }
+
+    /// Lower end
+    pub fn start(&self) -> usize {
+        self.anchor.min(self.head)
+    }
+
+    /// Upper end
+    pub fn end(&self) -> usize {
+        self.anchor.max(self.head)
+    }
+}
+
+/// Modifier keys held during an editor key or mouse event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KeyModifiers {
+    pub shift: bool,
+    /// Ctrl (Cmd on macOS)
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+impl KeyModifiers {
+    /// Only Shift
+    pub fn shift() -> Self {
+        Self { shift: true, ..Default::default() }
+    }
+
+    /// Only Ctrl / Cmd
+    pub fn ctrl() -> Self {
+        Self { ctrl: true, ..Default::default() }
+    }
+
+    /// Only Alt
+    pub fn alt() -> Self {
+        Self { alt: true, ..Default::default() }
+    }
+}
+
+/// A foldable block: `start_line` stays visible, the lines after it
+/// through `end_line` hide when folded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoldRegion {
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+impl FoldRegion {
+    /// Create a region
+    pub fn new(start_line: usize, end_line: usize) -> Self {
+        Self { start_line, end_line }
+    }
+
+    /// Does folding this region hide `line`?
+    pub fn hides(&self, line: usize) -> bool {
+        line > self.start_line && line <= self.end_line
+    }
+}
+
+/// Fold regions from indentation: each line folds the more-indented lines
+/// below it (blank lines inside a block belong to it)
+pub fn indentation_folds(text: &str) -> Vec<FoldRegion> {
+    let mut regions = Vec::new();
+    // (start line, indent) of blocks still open
+    let mut open: Vec<(usize, usize)> = Vec::new();
+    let mut last_content = 0;
+    for (line, content) in text.split('\n').enumerate() {
+        if content.trim().is_empty() {
+            continue;
+        }
+        let indent: usize = content.chars().take_while(|c| c.is_whitespace()).map(|c| if c == '\t' { 4 } else { 1 }).sum();
+        while let Some(&(start, _)) = open.last().filter(|&&(_, open_indent)| open_indent >= indent) {
+            open.pop();
+            if last_content > start {
+                regions.push(FoldRegion::new(start, last_content));
+            }
+        }
+        open.push((line, indent));
+        last_content = line;
+    }
+    for (start, _) in open {
+        if last_content > start {
+            regions.push(FoldRegion::new(start, last_content));
+        }
+    }
+    regions.sort_by_key(|region| region.start_line);
+    regions
+}
+
+/// Buffer and carets before an edit
+#[derive(Debug, Clone)]
+struct EditorSnapshot {
+    text: String,
+    selections: Vec<Selection>,
+}
+
+/// Undo / redo stacks; edits inside a group share one undo step
+#[derive(Debug, Default)]
+struct UndoHistory {
+    undo: Vec<EditorSnapshot>,
+    redo: Vec<EditorSnapshot>,
+    group_depth: usize,
+    group_recorded: bool,
+}
+
+/// Undo steps kept per editor
+const UNDO_LIMIT: usize = 500;
+
+#[derive(Debug, Clone, Copy)]
+enum EditorDrag {
+    /// Extending the primary selection
+    Text { anchor: usize },
+    /// Alt+drag box selection
+    Column { anchor: TextPosition },
+    /// Dragging the minimap viewport
+    Minimap,
+}
+
+/// CodeEditor - Editor for source code 💻
+///
+/// - Monospaced text with a line-number gutter
+/// - Any number of carets/selections, all edited at once
+/// - Ctrl+click adds a caret, Ctrl+D selects the next occurrence of the
+///   selection (or the word under the caret), Alt+drag selects a column,
+///   Escape goes back to a single caret
+/// - Language server hooks (see `LanguageClient`): diagnostics as squiggles
+///   and gutter marks, hover docs, Ctrl+Space completions and
+///   go-to-definition
+/// - Code folding (by indentation or a `fold_provider`) with gutter
+///   markers, and an optional minimap with click-to-jump
+/// - Undo / redo (Ctrl+Z, Ctrl+Y or Ctrl+Shift+Z)
+///
+/// State lives in Signals, so clones share it (register a clone with the
+/// FocusManager for keyboard input).
+///
+/// # Example
+/// ```rust,ignore
+/// let mut editor = CodeEditor::with_text("let a = 1;\nlet b = a;")
+///     .size(600.0, 400.0)
+///     .on_change(|text| println!("{} chars", text.len()));
+/// editor.handle_mouse_down(x, y, KeyModifiers::ctrl()); // extra caret
+/// editor.handle_key_with(FocusKey::Char('d'), KeyModifiers::ctrl());
+/// editor.insert_text("value");
+/// ```
+#[derive(Clone)]
+pub struct CodeEditor {
+    pub node_id: Option<NodeId>,
+    /// Buffer contents
+    pub text: Signal<String>,
+    /// Carets and selections, sorted and non-overlapping
+    pub selections: Signal<Vec<Selection>>,
+    /// Index of the primary selection (the one added last)
+    pub primary: Signal<usize>,
+    pub is_focused: Signal<bool>,
+    pub position: (f32, f32),
+    pub width: f32,
+    pub height: f32,
+    pub font_size: f32,
+    pub line_height: f32,
+    pub gutter_width: f32,
+    /// Spaces inserted for Tab
+    pub tab_size: usize,
+    pub scroll_y: Signal<f32>,
+    pub background_color: (u8, u8, u8, u8),
+    pub gutter_color: (u8, u8, u8, u8),
+    pub line_number_color: (u8, u8, u8, u8),
+    pub text_color: (u8, u8, u8, u8),
+    pub selection_color: (u8, u8, u8, u8),
+    pub caret_color: (u8, u8, u8, u8),
+    /// Find-and-replace match highlight
+    pub match_color: (u8, u8, u8, u8),
+    /// Ranges highlighted by a FindReplace bar
+    pub search_highlights: Signal<Vec<(usize, usize)>>,
+    history: Rc<RefCell<UndoHistory>>,
+    /// Bumped on every edit (sent to the language client)
+    pub version: Signal<u64>,
+    /// Diagnostics pushed by the language client
+    pub diagnostics: Signal<Vec<Diagnostic>>,
+    /// Hover docs and where they were asked for
+    pub hover: Signal<Option<(TextPosition, HoverInfo)>>,
+    /// Open completion list (empty = closed)
+    pub completions: Signal<Vec<CompletionItem>>,
+    /// Highlighted row in the filtered completion list
+    pub completion_index: Signal<usize>,
+    /// Offset where the word being completed starts
+    completion_start: Signal<usize>,
+    language_client: Option<Rc<RefCell<dyn LanguageClient>>>,
+    on_definition: Option<DefinitionCallback>,
+    /// Start lines of folded regions (save and restore to persist folds)
+    pub folded: Signal<Vec<usize>>,
+    fold_provider: Option<FoldProvider>,
+    /// Draw the minimap column on the right
+    pub show_minimap: bool,
+    pub minimap_width: f32,
+    /// Height of one line in the minimap
+    pub minimap_line_height: f32,
+    drag: Option<EditorDrag>,
+    clicks: ClickCounter,
+    on_change: Option<EditorChangeCallback>,
+}
+
+impl CodeEditor {
+    /// Create an empty editor
+    pub fn new() -> Self {
+        Self::with_text("")
+    }
+
+    /// Create an editor with initial text (caret at the start)
+    pub fn with_text(text: impl Into<String>) -> Self {
+        info!("💻 Creating CodeEditor");
+        Self {
+            node_id: None,
+            text: Signal::new(text.into()),
+            selections: Signal::new(vec![Selection::caret(0)]),
+            primary: Signal::new(0),
+            is_focused: Signal::new(false),
+            position: (0.0, 0.0),
+            width: 600.0,
+            height: 400.0,
+            font_size: 14.0,
+            line_height: 20.0,
+            gutter_width: 48.0,
+            tab_size: 4,
+            scroll_y: Signal::new(0.0),
+            background_color: (30, 30, 30, 255),
+            gutter_color: (37, 37, 38, 255),
+            line_number_color: (133, 133, 133, 255),
+            text_color: (212, 212, 212, 255),
+            selection_color: (38, 79, 120, 255),
+            caret_color: (174, 175, 173, 255),
+            match_color: (234, 92, 0, 90),
+            search_highlights: Signal::new(Vec::new()),
+            history: Rc::new(RefCell::new(UndoHistory::default())),
+            version: Signal::new(0),
+            diagnostics: Signal::new(Vec::new()),
+            hover: Signal::new(None),
+            completions: Signal::new(Vec::new()),
+            completion_index: Signal::new(0),
+            completion_start: Signal::new(0),
+            language_client: None,
+            on_definition: None,
+            folded: Signal::new(Vec::new()),
+            fold_provider: None,
+            show_minimap: false,
+            minimap_width: 80.0,
+            minimap_line_height: 2.0,
+            drag: None,
+            clicks: ClickCounter::new(),
+            on_change: None,
+        }
+    }
+
+    /// Set the size
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the position
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    /// Set the font size (line height follows at 1.4×)
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self.line_height = (font_size * 1.4).round();
+        self
+    }
+
+    /// Set the change handler
+    pub fn on_change<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Connect a language server client
+    pub fn language_client(mut self, client: impl LanguageClient + 'static) -> Self {
+        self.language_client = Some(Rc::new(RefCell::new(client)));
+        self
+    }
+
+    /// Set the go-to-definition handler (needed for targets in other documents)
+    pub fn on_definition<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&Location) + 'static,
+    {
+        self.on_definition = Some(Rc::new(handler));
+        self
+    }
+
+    /// Compute fold regions with a language callback instead of indentation
+    pub fn fold_provider<F>(mut self, provider: F) -> Self
+    where
+        F: Fn(&str) -> Vec<FoldRegion> + 'static,
+    {
+        self.fold_provider = Some(Rc::new(provider));
+        self
+    }
+
+    /// Show the minimap
+    pub fn minimap(mut self, show: bool) -> Self {
+        self.show_minimap = show;
+        self
+    }
+
+    /// Buffer contents
+    pub fn get_text(&self) -> String {
+        self.text.get()
+    }
+
+    /// Replace the whole buffer (single caret at the start)
+    pub fn set_text(&self, text: impl Into<String>) {
+        let text = text.into();
+        self.text.set(text.clone());
+        self.selections.set(vec![Selection::caret(0)]);
+        self.primary.set(0);
+        self.folded.set(Vec::new());
+        *self.history.borrow_mut() = UndoHistory::default();
+        self.notify(&text);
+    }
+
+    /// Number of lines
+    pub fn line_count(&self) -> usize {
+        self.get_text().split('\n').count()
+    }
+
+    /// One line's text (without the newline)
+    pub fn line(&self, line: usize) -> Option<String> {
+        self.get_text().split('\n').nth(line).map(str::to_string)
+    }
+
+    /// Char offset of every line start
+    fn line_starts(text: &str) -> Vec<usize> {
+        let mut starts = vec![0];
+        for (index, c) in text.chars().enumerate() {
+            if c == '\n' {
+                starts.push(index + 1);
+            }
+        }
+        starts
+    }
+
+    /// Offset → line/column
+    pub fn offset_to_position(&self, offset: usize) -> TextPosition {
+        let starts = Self::line_starts(&self.get_text());
+        let line = starts.partition_point(|&start| start <= offset).saturating_sub(1);
+        TextPosition::new(line, offset - starts[line])
+    }
+
+    /// Line/column → offset (clamped to the line and buffer)
+    pub fn position_to_offset(&self, position: TextPosition) -> usize {
+        let text = self.get_text();
+        let starts = Self::line_starts(&text);
+        let total = text.chars().count();
+        let line = position.line.min(starts.len() - 1);
+        let line_end = starts.get(line + 1).map_or(total, |&next| next - 1);
+        starts[line].saturating_add(position.column).min(line_end)
+    }
+
+    // ---- selections ----
+
+    /// Number of carets
+    pub fn cursor_count(&self) -> usize {
+        self.selections.get().len()
+    }
+
+    /// The primary selection
+    pub fn primary_selection(&self) -> Selection {
+        let selections = self.selections.get();
+        selections[self.primary.get().min(selections.len() - 1)]
+    }
+
+    /// Text under every selection, in order
+    pub fn selected_texts(&self) -> Vec<String> {
+        let chars: Vec<char> = self.get_text().chars().collect();
+        self.selections.get().iter().map(|sel| chars[sel.start()..sel.end()].iter().collect()).collect()
+    }
+
+    /// Single caret at an offset
+    pub fn set_cursor(&self, offset: usize) {
+        self.set_selection(Selection::caret(offset));
+    }
+
+    /// Single selection
+    pub fn set_selection(&self, selection: Selection) {
+        let total = self.get_text().chars().count();
+        let clamped = Selection::new(selection.anchor.min(total), selection.head.min(total));
+        self.reveal(&[clamped]);
+        self.selections.set(vec![clamped]);
+        self.primary.set(0);
+    }
+
+    /// Add a caret (Ctrl+click); it becomes primary
+    pub fn add_cursor(&self, offset: usize) {
+        self.add_selection(Selection::caret(offset));
+    }
+
+    /// Add a selection; it becomes primary
+    pub fn add_selection(&self, selection: Selection) {
+        let mut selections = self.selections.get();
+        selections.push(selection);
+        let primary_head = selection.head;
+        self.store_selections(selections, primary_head);
+    }
+
+    /// Drop every caret except the primary one (Escape)
+    /// Returns true if there was more than one
+    pub fn collapse_selections(&self) -> bool {
+        if self.cursor_count() < 2 {
+            return false;
+        }
+        let primary = self.primary_selection();
+        self.selections.set(vec![primary]);
+        self.primary.set(0);
+        true
+    }
+
+    /// Sort, merge overlaps and keep track of the primary caret
+    fn store_selections(&self, mut selections: Vec<Selection>, primary_head: usize) {
+        let total = self.get_text().chars().count();
+        for selection in &mut selections {
+            selection.anchor = selection.anchor.min(total);
+            selection.head = selection.head.min(total);
+        }
+        selections.sort_by_key(|selection| (selection.start(), selection.end()));
+        let mut merged: Vec<Selection> = Vec::with_capacity(selections.len());
+        for selection in selections {
+            match merged.last_mut() {
+                Some(last) if selection.start() < last.end() || selection.start() == last.start() => {
+                    let (start, end) = (last.start(), last.end().max(selection.end()));
+                    *last = if last.anchor <= last.head { Selection::new(start, end) } else { Selection::new(end, start) };
+                }
+                _ => merged.push(selection),
+            }
+        }
+        let primary = merged
+            .iter()
+            .position(|selection| selection.start() <= primary_head && primary_head <= selection.end())
+            .unwrap_or(merged.len() - 1);
+        self.reveal(&merged);
+        self.selections.set(merged);
+        self.primary.set(primary);
+    }
+
+    /// Select the next match of the primary selection (Ctrl+D)
+    /// With an empty caret, selects the word under it first
+    pub fn select_next_occurrence(&self) -> bool {
+        let chars: Vec<char> = self.get_text().chars().collect();
+        let primary = self.primary_selection();
+        if primary.is_empty() {
+            let (start, end) = word_range(&chars, primary.head);
+            if start == end {
+                return false;
+            }
+            let mut selections = self.selections.get();
+            let index = self.primary.get().min(selections.len() - 1);
+            selections[index] = Selection::new(start, end);
+            self.store_selections(selections, end);
+            return true;
+        }
+
+        let needle = &chars[primary.start()..primary.end()];
+        let existing = self.selections.get();
+        let taken = |start: usize| existing.iter().any(|selection| selection.start() == start);
+        let candidates = (primary.end()..chars.len()).chain(0..primary.start());
+        for start in candidates {
+            if start + needle.len() <= chars.len() && &chars[start..start + needle.len()] == needle && !taken(start) {
+                self.add_selection(Selection::new(start, start + needle.len()));
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Box selection between two corners, one selection per line
+    pub fn select_column(&self, from: TextPosition, to: TextPosition) {
+        let (first, last) = (from.line.min(to.line), from.line.max(to.line));
+        let last = last.min(self.line_count() - 1);
+        let selections: Vec<Selection> = (first..=last)
+            .map(|line| {
+                let anchor = self.position_to_offset(TextPosition::new(line, from.column));
+                let head = self.position_to_offset(TextPosition::new(line, to.column));
+                Selection::new(anchor, head)
+            })
+            .collect();
+        // The caret under the pointer is primary
+        let primary = if to.line >= from.line { selections.len() - 1 } else { 0 };
+        self.selections.set(selections);
+        self.primary.set(primary);
+    }
+
+    // ---- editing ----
+
+    /// Apply one (start, end, replacement) edit per selection, in selection order,
+    /// leaving a caret after each replacement
+    fn apply_edits(&self, edits: Vec<(usize, usize, String)>) {
+        self.record_undo();
+        let chars: Vec<char> = self.get_text().chars().collect();
+        let mut result = String::with_capacity(chars.len());
+        let mut carets = Vec::with_capacity(edits.len());
+        let mut copied = 0;
+        let mut delta: isize = 0;
+        let newlines = |range: &[char]| range.iter().filter(|&&c| c == '\n').count();
+        // (first line, last line, line count change) of each edit, for moving folds
+        let mut line_edits = Vec::with_capacity(edits.len());
+        let mut line = 0;
+        for (start, end, replacement) in edits {
+            let start = start.clamp(copied, chars.len());
+            let end = end.clamp(start, chars.len());
+            line += newlines(&chars[copied..start]);
+            let removed = newlines(&chars[start..end]);
+            let added = replacement.matches('\n').count();
+            line_edits.push((line, line + removed, added as isize - removed as isize));
+            line += removed;
+            result.extend(&chars[copied..start]);
+            result.push_str(&replacement);
+            let inserted = replacement.chars().count();
+            let caret = (start as isize + delta) as usize + inserted;
+            delta += inserted as isize - (end - start) as isize;
+            carets.push(Selection::caret(caret));
+            copied = end;
+        }
+        result.extend(&chars[copied..]);
+
+        // Folds below an edit move with it; folds whose header was merged away open
+        let folded = self
+            .folded
+            .get()
+            .into_iter()
+            .filter_map(|header| {
+                let mut moved = header as isize;
+                for &(first, last, change) in &line_edits {
+                    if last < header {
+                        moved += change;
+                    } else if first < header {
+                        return None;
+                    }
+                }
+                Some(moved as usize)
+            })
+            .collect();
+
+        let primary_head = carets.get(self.primary.get()).map_or(0, |caret| caret.head);
+        self.text.set(result.clone());
+        self.restore_fold_state(folded);
+        self.store_selections(carets, primary_head);
+        self.notify(&result);
+    }
+
+    /// Apply (start, end, replacement) edits in any order as one undo step
+    /// Overlapping edits are skipped.
+    pub fn replace_ranges(&self, mut edits: Vec<(usize, usize, String)>) {
+        edits.sort_by_key(|&(start, end, _)| (start, end));
+        let mut kept: Vec<(usize, usize, String)> = Vec::with_capacity(edits.len());
+        for edit in edits {
+            if kept.last().is_none_or(|last| edit.0 >= last.1) {
+                kept.push(edit);
+            }
+        }
+        if !kept.is_empty() {
+            self.undo_group(|| self.apply_edits(kept));
+        }
+    }
+
+    // ---- undo ----
+
+    fn record_undo(&self) {
+        let mut history = self.history.borrow_mut();
+        if history.group_depth > 0 {
+            if history.group_recorded {
+                return;
+            }
+            history.group_recorded = true;
+        }
+        history.undo.push(EditorSnapshot { text: self.get_text(), selections: self.selections.get() });
+        if history.undo.len() > UNDO_LIMIT {
+            history.undo.remove(0);
+        }
+        history.redo.clear();
+    }
+
+    /// Run `f` so all its edits undo together
+    pub fn undo_group<R>(&self, f: impl FnOnce() -> R) -> R {
+        {
+            let mut history = self.history.borrow_mut();
+            if history.group_depth == 0 {
+                history.group_recorded = false;
+            }
+            history.group_depth += 1;
+        }
+        let result = f();
+        self.history.borrow_mut().group_depth -= 1;
+        result
+    }
+
+    /// Can `undo` do anything?
+    pub fn can_undo(&self) -> bool {
+        !self.history.borrow().undo.is_empty()
+    }
+
+    /// Can `redo` do anything?
+    pub fn can_redo(&self) -> bool {
+        !self.history.borrow().redo.is_empty()
+    }
+
+    /// Revert the last edit (or edit group)
+    pub fn undo(&self) -> bool {
+        let current = EditorSnapshot { text: self.get_text(), selections: self.selections.get() };
+        let Some(snapshot) = self.history.borrow_mut().undo.pop() else {
+            return false;
+        };
+        self.history.borrow_mut().redo.push(current);
+        self.restore_snapshot(snapshot);
+        true
+    }
+
+    /// Re-apply the last undone edit
+    pub fn redo(&self) -> bool {
+        let current = EditorSnapshot { text: self.get_text(), selections: self.selections.get() };
+        let Some(snapshot) = self.history.borrow_mut().redo.pop() else {
+            return false;
+        };
+        self.history.borrow_mut().undo.push(current);
+        self.restore_snapshot(snapshot);
+        true
+    }
+
+    fn restore_snapshot(&self, snapshot: EditorSnapshot) {
+        self.text.set(snapshot.text.clone());
+        self.restore_fold_state(self.folded.get());
+        let primary_head = snapshot.selections.last().map_or(0, |selection| selection.head);
+        self.store_selections(snapshot.selections, primary_head);
+        self.notify(&snapshot.text);
+    }
+
+    /// Type text at every caret (replacing selections)
+    pub fn insert_text(&self, text: &str) {
+        let edits = self
+            .selections
+            .get()
+            .iter()
+            .map(|selection| (selection.start(), selection.end(), text.to_string()))
+            .collect();
+        self.apply_edits(edits);
+    }
+
+    /// New line at every caret, keeping each line's indentation
+    pub fn insert_newline(&self) {
+        let edits = self
+            .selections
+            .get()
+            .iter()
+            .map(|selection| {
+                let line = self.line(self.offset_to_position(selection.start()).line).unwrap_or_default();
+                let indent: String = line.chars().take_while(|c| *c == ' ' || *c == '\t').collect();
+                (selection.start(), selection.end(), format!("\n{}", indent))
+            })
+            .collect();
+        self.apply_edits(edits);
+    }
+
+    /// Backspace at every caret
+    pub fn backspace(&self) {
+        let edits = self
+            .selections
+            .get()
+            .iter()
+            .map(|selection| match selection.is_empty() {
+                true => (selection.start().saturating_sub(1), selection.end(), String::new()),
+                false => (selection.start(), selection.end(), String::new()),
+            })
+            .collect();
+        self.apply_edits(edits);
+    }
+
+    /// Delete forward at every caret
+    pub fn delete_forward(&self) {
+        let edits = self
+            .selections
+            .get()
+            .iter()
+            .map(|selection| match selection.is_empty() {
+                true => (selection.start(), selection.end() + 1, String::new()),
+                false => (selection.start(), selection.end(), String::new()),
+            })
+            .collect();
+        self.apply_edits(edits);
+    }
+
+    // ---- folding ----
+
+    /// Foldable regions of the current buffer
+    pub fn fold_regions(&self) -> Vec<FoldRegion> {
+        let text = self.get_text();
+        let mut regions = match &self.fold_provider {
+            Some(provider) => provider(&text),
+            None => indentation_folds(&text),
+        };
+        regions.retain(|region| region.end_line > region.start_line);
+        regions
+    }
+
+    /// The region starting at `line`
+    pub fn fold_region_at(&self, line: usize) -> Option<FoldRegion> {
+        self.fold_regions().into_iter().find(|region| region.start_line == line)
+    }
+
+    /// Is the region starting at `line` folded?
+    pub fn is_folded(&self, line: usize) -> bool {
+        self.folded.get().contains(&line)
+    }
+
+    /// Fold the region starting at `line`; carets inside move to its first line
+    pub fn fold(&self, line: usize) -> bool {
+        let Some(region) = self.fold_region_at(line) else {
+            return false;
+        };
+        let mut folded = self.folded.get();
+        if folded.contains(&line) {
+            return false;
+        }
+        folded.push(line);
+        folded.sort_unstable();
+        self.folded.set(folded);
+
+        let header_end = self.position_to_offset(TextPosition::new(region.start_line, usize::MAX));
+        let selections: Vec<Selection> = self
+            .selections
+            .get()
+            .into_iter()
+            .map(|selection| {
+                let head_line = self.offset_to_position(selection.head).line;
+                if region.hides(head_line) { Selection::caret(header_end) } else { selection }
+            })
+            .collect();
+        let primary_head = selections[self.primary.get().min(selections.len() - 1)].head;
+        self.store_selections(selections, primary_head);
+        true
+    }
+
+    /// Unfold the region starting at `line`
+    pub fn unfold(&self, line: usize) -> bool {
+        let mut folded = self.folded.get();
+        let count = folded.len();
+        folded.retain(|&header| header != line);
+        let changed = folded.len() != count;
+        self.folded.set(folded);
+        changed
+    }
+
+    /// Fold or unfold the region starting at `line`
+    pub fn toggle_fold(&self, line: usize) -> bool {
+        self.unfold(line) || self.fold(line)
+    }
+
+    /// Fold every region
+    pub fn fold_all(&self) {
+        let starts: Vec<usize> = self.fold_regions().iter().map(|region| region.start_line).collect();
+        self.restore_fold_state(starts);
+        // Carets end up on the outermost visible lines
+        let selections = self.selections.get();
+        let headers = self.hidden_regions();
+        let moved: Vec<Selection> = selections
+            .into_iter()
+            .map(|selection| {
+                let line = self.offset_to_position(selection.head).line;
+                match headers.iter().filter(|region| region.hides(line)).min_by_key(|region| region.start_line) {
+                    Some(region) => Selection::caret(self.position_to_offset(TextPosition::new(region.start_line, usize::MAX))),
+                    None => selection,
+                }
+            })
+            .collect();
+        let primary_head = moved[self.primary.get().min(moved.len() - 1)].head;
+        self.store_selections(moved, primary_head);
+    }
+
+    /// Unfold everything
+    pub fn unfold_all(&self) {
+        self.folded.set(Vec::new());
+    }
+
+    /// Folded start lines, to save between sessions
+    pub fn fold_state(&self) -> Vec<usize> {
+        self.folded.get()
+    }
+
+    /// Fold the given start lines (lines that no longer start a region are skipped)
+    pub fn restore_fold_state(&self, lines: Vec<usize>) {
+        let regions = self.fold_regions();
+        let mut folded: Vec<usize> = lines
+            .into_iter()
+            .filter(|&line| regions.iter().any(|region| region.start_line == line))
+            .collect();
+        folded.sort_unstable();
+        folded.dedup();
+        self.folded.set(folded);
+    }
+
+    /// Regions currently folded
+    fn hidden_regions(&self) -> Vec<FoldRegion> {
+        let folded = self.folded.get();
+        if folded.is_empty() {
+            return Vec::new();
+        }
+        self.fold_regions().into_iter().filter(|region| folded.contains(&region.start_line)).collect()
+    }
+
+    /// Is `line` inside a folded region?
+    pub fn is_line_hidden(&self, line: usize) -> bool {
+        self.hidden_regions().iter().any(|region| region.hides(line))
+    }
+
+    /// Lines on screen, in order
+    pub fn visible_lines(&self) -> Vec<usize> {
+        let hidden = self.hidden_regions();
+        (0..self.line_count()).filter(|&line| !hidden.iter().any(|region| region.hides(line))).collect()
+    }
+
+    /// Unfold regions hiding any caret
+    fn reveal(&self, selections: &[Selection]) {
+        let hidden = self.hidden_regions();
+        if hidden.is_empty() {
+            return;
+        }
+        let lines: Vec<usize> = selections.iter().map(|selection| self.offset_to_position(selection.head).line).collect();
+        let folded: Vec<usize> = self
+            .folded
+            .get()
+            .into_iter()
+            .filter(|&header| {
+                let region = hidden.iter().find(|region| region.start_line == header);
+                !region.is_some_and(|region| lines.iter().any(|&line| region.hides(line)))
+            })
+            .collect();
+        self.folded.set(folded);
+    }
+
+    /// Next visible line above (`down` false) or below `line`
+    fn step_visible_line(&self, line: usize, down: bool) -> Option<usize> {
+        let hidden = self.hidden_regions();
+        let mut target = if down { line + 1 } else { line.checked_sub(1)? };
+        while let Some(region) = hidden.iter().filter(|region| region.hides(target)).min_by_key(|region| region.start_line) {
+            target = if down { region.end_line + 1 } else { region.start_line };
+        }
+        (target < self.line_count()).then_some(target)
+    }
+
+    fn notify(&self, text: &str) {
+        self.version.update(|version| version + 1);
+        self.hover.set(None);
+        if let Some(client) = &self.language_client {
+            client.borrow_mut().did_change(text, self.version.get());
+        }
+        self.refresh_completions();
+        if let Some(handler) = &self.on_change {
+            handler(text);
+        }
+    }
+
+    // ---- language server ----
+
+    /// Replace the diagnostics (LSP publishDiagnostics)
+    pub fn set_diagnostics(&self, diagnostics: Vec<Diagnostic>) {
+        self.diagnostics.set(diagnostics);
+    }
+
+    /// Diagnostics touching a line, most severe first
+    pub fn diagnostics_on_line(&self, line: usize) -> Vec<Diagnostic> {
+        let mut found: Vec<Diagnostic> = self
+            .diagnostics
+            .get()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.start.line <= line && line <= diagnostic.end.line)
+            .collect();
+        found.sort_by_key(|diagnostic| diagnostic.severity);
+        found
+    }
+
+    /// Show hover docs for `position` (call from the mouse-move handler)
+    ///
+    /// Diagnostics under the pointer win over the language client.
+    pub fn hover_at(&self, position: TextPosition) {
+        if let Some(diagnostic) = self.diagnostics.get().into_iter().find(|diagnostic| diagnostic.contains(position)) {
+            let mut info = HoverInfo::new(diagnostic.message);
+            info.range = Some((diagnostic.start, diagnostic.end));
+            self.show_hover(position, info);
+            return;
+        }
+        let info = self.language_client.as_ref().and_then(|client| client.borrow_mut().hover(position));
+        match info {
+            Some(info) => self.show_hover(position, info),
+            None => self.hide_hover(),
+        }
+    }
+
+    /// Show hover docs (for clients that answer later)
+    pub fn show_hover(&self, position: TextPosition, info: HoverInfo) {
+        self.hover.set(Some((position, info)));
+    }
+
+    /// Close the hover
+    pub fn hide_hover(&self) {
+        self.hover.set(None);
+    }
+
+    /// The hover as a Popover anchored above the symbol
+    pub fn hover_popover(&self) -> Option<Popover> {
+        let (_, info) = self.hover.get()?;
+        let popover = Popover::new()
+            .content(info.contents)
+            .position(PopoverPosition::Top)
+            .trigger(PopoverTrigger::Hover)
+            .show_arrow(false)
+            .padding(8.0)
+            .background_color(37, 37, 38, 255)
+            .text_color(212, 212, 212, 255)
+            .border_color(69, 69, 69, 255)
+            .border_radius(4.0);
+        popover.is_visible.set(true);
+        Some(popover)
+    }
+
+    /// Ask the language client for completions at the primary caret
+    pub fn trigger_completion(&self) -> bool {
+        let Some(client) = &self.language_client else {
+            return false;
+        };
+        let caret = self.offset_to_position(self.primary_selection().head);
+        let items = client.borrow_mut().completions(caret);
+        self.show_completions(items)
+    }
+
+    /// Open the completion list (for clients that answer later)
+    pub fn show_completions(&self, items: Vec<CompletionItem>) -> bool {
+        let head = self.primary_selection().head;
+        let chars: Vec<char> = self.get_text().chars().collect();
+        let start = (0..head).rev().take_while(|&i| is_word_char(chars[i])).last().unwrap_or(head);
+        self.completion_start.set(start);
+        self.completion_index.set(0);
+        self.completions.set(items);
+        self.is_completing()
+    }
+
+    /// Is the completion list open?
+    pub fn is_completing(&self) -> bool {
+        !self.completions.get().is_empty() && !self.filtered_completions().is_empty()
+    }
+
+    /// Close the completion list
+    pub fn close_completions(&self) {
+        self.completions.set(Vec::new());
+    }
+
+    /// Word typed since the list opened
+    fn completion_prefix(&self) -> String {
+        let head = self.primary_selection().head;
+        self.get_text().chars().skip(self.completion_start.get()).take(head.saturating_sub(self.completion_start.get())).collect()
+    }
+
+    /// The completion list as a searchable Select, filtered by the typed word
+    pub fn completion_menu(&self) -> Option<Select> {
+        let items = self.completions.get();
+        if items.is_empty() {
+            return None;
+        }
+        let mut menu = Select::new().searchable(true).width(260.0).max_height(8.0 * self.line_height);
+        for (index, item) in items.iter().enumerate() {
+            menu = menu.add_option(item.label.clone(), index.to_string());
+        }
+        menu.set_search_query(self.completion_prefix());
+        Some(menu)
+    }
+
+    /// Completions matching the typed word
+    pub fn filtered_completions(&self) -> Vec<CompletionItem> {
+        let Some(menu) = self.completion_menu() else {
+            return Vec::new();
+        };
+        let items = self.completions.get();
+        menu.get_filtered_options().into_iter().map(|(index, _)| items[index].clone()).collect()
+    }
+
+    /// Insert the highlighted completion at every caret
+    pub fn accept_completion(&self) -> bool {
+        let filtered = self.filtered_completions();
+        let Some(item) = filtered.get(self.completion_index.get().min(filtered.len().saturating_sub(1))) else {
+            return false;
+        };
+        let prefix_len = self.completion_prefix().chars().count();
+        let text = item.text().to_string();
+        self.close_completions();
+        let edits = self
+            .selections
+            .get()
+            .iter()
+            .map(|selection| (selection.head.saturating_sub(prefix_len), selection.head, text.clone()))
+            .collect();
+        self.apply_edits(edits);
+        true
+    }
+
+    /// Close the list once the caret leaves the word being completed
+    fn refresh_completions(&self) {
+        if self.completions.get().is_empty() {
+            return;
+        }
+        let head = self.primary_selection().head;
+        let start = self.completion_start.get();
+        let still_word = head >= start && self.completion_prefix().chars().all(is_word_char);
+        let count = self.filtered_completions().len();
+        if !still_word || count == 0 {
+            self.close_completions();
+        } else {
+            self.completion_index.set(self.completion_index.get().min(count - 1));
+        }
+    }
+
+    /// Jump to the definition of the symbol at the primary caret
+    ///
+    /// Targets in this document move the caret; every target goes to the
+    /// `on_definition` handler.
+    pub fn go_to_definition(&self) -> Option<Location> {
+        let client = self.language_client.as_ref()?;
+        let caret = self.offset_to_position(self.primary_selection().head);
+        let location = client.borrow_mut().definition(caret)?;
+        if location.uri.is_none() {
+            self.set_cursor(self.position_to_offset(location.start));
+            self.scroll_to_line(location.start.line);
+        }
+        if let Some(handler) = &self.on_definition {
+            handler(&location);
+        }
+        Some(location)
+    }
+
+    /// Scroll so a line is visible
+    pub fn scroll_to_line(&self, line: usize) {
+        let rows = self.visible_lines();
+        let row = rows.partition_point(|&visible| visible < line);
+        let top = row as f32 * self.line_height;
+        let scroll = self.scroll_y.get();
+        if top < scroll {
+            self.scroll_y.set(top);
+        } else if top + self.line_height > scroll + self.height {
+            self.scroll_y.set(top + self.line_height - self.height);
+        }
+    }
+
+    // ---- caret movement ----
+
+    /// Move every caret; `shift` extends the selections instead
+    fn move_carets(&self, shift: bool, target: impl Fn(&Selection) -> usize) {
+        let selections: Vec<Selection> = self
+            .selections
+            .get()
+            .iter()
+            .map(|selection| {
+                let head = target(selection);
+                if shift { Selection::new(selection.anchor, head) } else { Selection::caret(head) }
+            })
+            .collect();
+        let primary_head = selections[self.primary.get().min(selections.len() - 1)].head;
+        self.store_selections(selections, primary_head);
+    }
+
+    /// Left arrow
+    pub fn move_left(&self, shift: bool) {
+        self.move_carets(shift, |selection| match (shift, selection.is_empty()) {
+            (false, false) => selection.start(),
+            _ => selection.head.saturating_sub(1),
+        });
+    }
+
+    /// Right arrow
+    pub fn move_right(&self, shift: bool) {
+        let total = self.get_text().chars().count();
+        self.move_carets(shift, |selection| match (shift, selection.is_empty()) {
+            (false, false) => selection.end(),
+            _ => (selection.head + 1).min(total),
+        });
+    }
+
+    /// Up arrow
+    pub fn move_up(&self, shift: bool) {
+        self.move_carets(shift, |selection| {
+            let position = self.offset_to_position(selection.head);
+            match self.step_visible_line(position.line, false) {
+                Some(line) => self.position_to_offset(TextPosition::new(line, position.column)),
+                None => 0,
+            }
+        });
+    }
+
+    /// Down arrow
+    pub fn move_down(&self, shift: bool) {
+        let total = self.get_text().chars().count();
+        self.move_carets(shift, |selection| {
+            let position = self.offset_to_position(selection.head);
+            match self.step_visible_line(position.line, true) {
+                Some(line) => self.position_to_offset(TextPosition::new(line, position.column)),
+                None => total,
+            }
+        });
+    }
+
+    /// Home: start of each caret's line
+    pub fn move_home(&self, shift: bool) {
+        self.move_carets(shift, |selection| {
+            let line = self.offset_to_position(selection.head).line;
+            self.position_to_offset(TextPosition::new(line, 0))
+        });
+    }
+
+    /// End: end of each caret's line
+    pub fn move_end(&self, shift: bool) {
+        self.move_carets(shift, |selection| {
+            let line = self.offset_to_position(selection.head).line;
+            self.position_to_offset(TextPosition::new(line, usize::MAX))
+        });
+    }
+
+    /// Handle a key with modifiers; returns true if it was used
+    pub fn handle_key_with(&self, key: FocusKey, modifiers: KeyModifiers) -> bool {
+        let shift = modifiers.shift;
+        if self.is_completing() {
+            let count = self.filtered_completions().len();
+            let index = self.completion_index.get();
+            match key {
+                FocusKey::Up => {
+                    self.completion_index.set((index + count - 1) % count);
+                    return true;
+                }
+                FocusKey::Down => {
+                    self.completion_index.set((index + 1) % count);
+                    return true;
+                }
+                FocusKey::Enter | FocusKey::Tab => return self.accept_completion(),
+                FocusKey::Escape => {
+                    self.close_completions();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        match key {
+            FocusKey::Space if modifiers.ctrl => self.trigger_completion(),
+            FocusKey::Char(c) if modifiers.ctrl => match c.to_ascii_lowercase() {
+                'd' => self.select_next_occurrence(),
+                'z' if modifiers.shift => self.redo(),
+                'z' => self.undo(),
+                'y' => self.redo(),
+                'a' => {
+                    let total = self.get_text().chars().count();
+                    self.set_selection(Selection::new(0, total));
+                    true
+                }
+                _ => false,
+            },
+            FocusKey::Char(c) => {
+                self.insert_text(&c.to_string());
+                true
+            }
+            FocusKey::Space => {
+                self.insert_text(" ");
+                true
+            }
+            FocusKey::Tab => {
+                self.insert_text(&" ".repeat(self.tab_size));
+                true
+            }
+            FocusKey::Enter => {
+                self.insert_newline();
+                true
+            }
+            FocusKey::Backspace => {
+                self.backspace();
+                true
+            }
+            FocusKey::Delete => {
+                self.delete_forward();
+                true
+            }
+            FocusKey::Left => {
+                self.move_left(shift);
+                true
+            }
+            FocusKey::Right => {
+                self.move_right(shift);
+                true
+            }
+            FocusKey::Up => {
+                self.move_up(shift);
+                true
+            }
+            FocusKey::Down => {
+                self.move_down(shift);
+                true
+            }
+            FocusKey::Home => {
+                self.move_home(shift);
+                true
+            }
+            FocusKey::End => {
+                self.move_end(shift);
+                true
+            }
+            FocusKey::Escape => self.collapse_selections(),
+        }
+    }
+
+    // ---- mouse ----
+
+    /// Width of one monospaced glyph
+    pub fn char_width(&self) -> f32 {
+        self.font_size * 0.6
+    }
+
+    /// Buffer position under a point
+    pub fn position_at(&self, x: f32, y: f32) -> TextPosition {
+        let text_x = x - self.position.0 - self.gutter_width;
+        let text_y = y - self.position.1 + self.scroll_y.get();
+        let rows = self.visible_lines();
+        let row = ((text_y / self.line_height).max(0.0) as usize).min(rows.len() - 1);
+        let column = (text_x / self.char_width()).round().max(0.0) as usize;
+        let line = rows[row];
+        let line_len = self.line(line).map_or(0, |text| text.chars().count());
+        TextPosition::new(line, column.min(line_len))
+    }
+
+    /// Mouse down: place, add (Ctrl) or start a column selection (Alt)
+    /// Double-click selects a word and triple-click a line (timing from `InputSettings`)
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32, modifiers: KeyModifiers) {
+        let position = self.position_at(x, y);
+        let offset = self.position_to_offset(position);
+        self.is_focused.set(true);
+        if self.show_minimap && x >= self.position.0 + self.width - self.minimap_width {
+            self.minimap_jump(y);
+            self.drag = Some(EditorDrag::Minimap);
+            return;
+        }
+        let marker_x = self.position.0 + self.gutter_width - FOLD_MARKER_WIDTH;
+        if (marker_x..self.position.0 + self.gutter_width).contains(&x) && self.fold_region_at(position.line).is_some() {
+            self.toggle_fold(position.line);
+            return;
+        }
+        if modifiers.alt {
+            self.drag = Some(EditorDrag::Column { anchor: position });
+            self.select_column(position, position);
+        } else if modifiers.ctrl {
+            self.add_cursor(offset);
+            self.drag = Some(EditorDrag::Text { anchor: offset });
+        } else if modifiers.shift {
+            let anchor = self.primary_selection().anchor;
+            self.set_selection(Selection::new(anchor, offset));
+            self.drag = Some(EditorDrag::Text { anchor });
+        } else {
+            let (start, end) = match self.clicks.press(x, y) {
+                1 => (offset, offset),
+                2 => word_range(&self.get_text().chars().collect::<Vec<_>>(), offset),
+                _ => {
+                    let start = self.position_to_offset(TextPosition::new(position.line, 0));
+                    let end = self.line(position.line + 1).map_or(self.get_text().chars().count(), |_| {
+                        self.position_to_offset(TextPosition::new(position.line + 1, 0))
+                    });
+                    (start, end)
+                }
+            };
+            self.set_selection(Selection::new(start, end));
+            self.drag = Some(EditorDrag::Text { anchor: start });
+        }
+    }
+
+    /// Mouse move: extend a drag, or show hover docs
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        let position = self.position_at(x, y);
+        match self.drag {
+            Some(EditorDrag::Column { anchor }) => self.select_column(anchor, position),
+            Some(EditorDrag::Minimap) => self.minimap_jump(y),
+            Some(EditorDrag::Text { anchor }) => {
+                let head = self.position_to_offset(position);
+                let mut selections = self.selections.get();
+                let index = self.primary.get().min(selections.len() - 1);
+                selections[index] = Selection::new(anchor, head);
+                self.store_selections(selections, head);
+            }
+            None => {
+                let column = ((x - self.position.0 - self.gutter_width) / self.char_width()).floor();
+                let on_text = column >= 0.0
+                    && self.line(position.line).is_some_and(|line| (column as usize) < line.chars().count());
+                if on_text {
+                    let at = TextPosition::new(position.line, column as usize);
+                    if self.hover.get().map(|(hovered, _)| hovered) != Some(at) {
+                        self.hover_at(at);
+                    }
+                } else {
+                    self.hide_hover();
+                }
+            }
+        }
+    }
+
+    /// Mouse up: end the drag
+    pub fn handle_mouse_up(&mut self) {
+        self.drag = None;
+    }
+
+    /// Scroll by a pixel delta
+    pub fn scroll_by(&self, delta_y: f32) {
+        self.scroll_y.set((self.scroll_y.get() + delta_y).clamp(0.0, self.max_scroll()));
+    }
+
+    fn max_scroll(&self) -> f32 {
+        let content = self.visible_lines().len() as f32 * self.line_height;
+        (content - self.height).max(0.0)
+    }
+
+    // ---- minimap ----
+
+    /// How far the minimap is scrolled (it scrolls once the file is taller than it)
+    fn minimap_offset(&self) -> f32 {
+        let content = self.visible_lines().len() as f32 * self.minimap_line_height;
+        let max_scroll = self.max_scroll();
+        if content <= self.height || max_scroll <= 0.0 {
+            return 0.0;
+        }
+        (content - self.height) * self.scroll_y.get() / max_scroll
+    }
+
+    /// Viewport indicator in the minimap
+    pub fn minimap_viewport(&self) -> (f32, f32, f32, f32) {
+        let scale = self.minimap_line_height / self.line_height;
+        let (x, y) = self.position;
+        let top = y + self.scroll_y.get() * scale - self.minimap_offset();
+        (x + self.width - self.minimap_width, top, self.minimap_width, self.height * scale)
+    }
+
+    /// Scroll so the line under a minimap point is centered
+    pub fn minimap_jump(&self, y: f32) {
+        let row = (y - self.position.1 + self.minimap_offset()) / self.minimap_line_height;
+        let target = row * self.line_height - self.height / 2.0;
+        self.scroll_y.set(target.clamp(0.0, self.max_scroll()));
+    }
+
+    // ---- drawing ----
+
+    /// Draw the editor
+    pub fn paint(&self, list: &mut DrawList) {
+        let (x, y) = self.position;
+        let minimap_width = if self.show_minimap { self.minimap_width } else { 0.0 };
+        let text_width = self.width - minimap_width;
+        list.rect((x, y, self.width, self.height), self.background_color);
+        list.rect((x, y, self.gutter_width, self.height), self.gutter_color);
+        list.push_clip((x, y, text_width, self.height));
+
+        let text = self.get_text();
+        let scroll = self.scroll_y.get();
+        let first_row = (scroll / self.line_height) as usize;
+        let visible = (self.height / self.line_height).ceil() as usize + 1;
+        let char_width = self.char_width();
+        let text_x = x + self.gutter_width;
+        let lines: Vec<&str> = text.split('\n').collect();
+        let rows = self.visible_lines();
+        let on_screen = &rows[first_row.min(rows.len())..(first_row + visible).min(rows.len())];
+        let (first, last) = (on_screen.first().copied().unwrap_or(0), on_screen.last().copied().unwrap_or(0));
+        // Screen y of a line; None when folded away
+        let line_y = |line: usize| rows.binary_search(&line).ok().map(|row| y + row as f32 * self.line_height - scroll);
+        let anchor_y = |line: usize| line_y(line).unwrap_or(y);
+
+        // Search matches, then selections, under the text
+        let highlights = self.search_highlights.get().into_iter().map(|range| (range, self.match_color));
+        let selections = self.selections.get().into_iter().map(|selection| ((selection.start(), selection.end()), self.selection_color));
+        for ((range_start, range_end), color) in highlights.chain(selections).filter(|((start, end), _)| start != end) {
+            let (start, end) = (self.offset_to_position(range_start), self.offset_to_position(range_end));
+            for line in start.line.max(first)..=end.line.min(last) {
+                let Some(row_y) = line_y(line) else { continue };
+                let from = if line == start.line { start.column } else { 0 };
+                let line_len = lines.get(line).map_or(0, |text| text.chars().count());
+                // Selected newlines show as one extra cell
+                let to = if line == end.line { end.column } else { line_len + 1 };
+                let rect = (text_x + from as f32 * char_width, row_y, (to - from) as f32 * char_width, self.line_height);
+                list.rect(rect, color);
+            }
+        }
+
+        let text_offset = (self.line_height - self.font_size * 1.2) / 2.0;
+        let foldable: Vec<usize> = self.fold_regions().iter().map(|region| region.start_line).collect();
+        let folded = self.folded.get();
+        for &line in on_screen {
+            let row_y = anchor_y(line);
+            let content = lines[line];
+            let number = (line + 1).to_string();
+            let number_x = x + self.gutter_width - FOLD_MARKER_WIDTH - 2.0 - number.len() as f32 * char_width;
+            list.text(number, number_x, row_y + text_offset, self.font_size, self.line_number_color);
+            if foldable.contains(&line) {
+                let marker = if folded.contains(&line) { "▸" } else { "▾" };
+                let marker_x = x + self.gutter_width - FOLD_MARKER_WIDTH + 2.0;
+                list.text(marker, marker_x, row_y + text_offset, self.font_size, self.line_number_color);
+            }
+            if !content.is_empty() {
+                list.text(content.to_string(), text_x, row_y + text_offset, self.font_size, self.text_color);
+            }
+            if folded.contains(&line) {
+                // "⋯" pill after a folded line
+                let pill_x = text_x + (content.chars().count() + 1) as f32 * char_width;
+                let pill = (pill_x, row_y + 3.0, char_width * 3.0, self.line_height - 6.0);
+                list.rounded_rect(pill, 3.0, self.selection_color);
+                list.text("⋯", pill_x + char_width, row_y + text_offset, self.font_size, self.text_color);
+            }
+        }
+
+        // Diagnostics: squiggles under the range, a dot in the gutter
+        for diagnostic in self.diagnostics.get() {
+            let color = diagnostic.severity.color();
+            for line in diagnostic.start.line.max(first)..=diagnostic.end.line.min(last) {
+                let Some(row_y) = line_y(line) else { continue };
+                let line_len = lines.get(line).map_or(0, |text| text.chars().count());
+                let from = if line == diagnostic.start.line { diagnostic.start.column } else { 0 };
+                let to = if line == diagnostic.end.line { diagnostic.end.column } else { line_len };
+                // Zero-width ranges still get one cell
+                let to = to.max(from + 1);
+                let (start_x, end_x) = (text_x + from as f32 * char_width, text_x + to as f32 * char_width);
+                for pair in squiggle_points(start_x, end_x, row_y + self.line_height - 3.0, 2.0, 4.0).windows(2) {
+                    list.rect((pair[0].0, pair[0].1, pair[1].0 - pair[0].0, 1.0), color);
+                }
+            }
+        }
+        for &line in on_screen {
+            if let Some(worst) = self.diagnostics_on_line(line).first() {
+                let dot = 6.0;
+                let rect = (x + 4.0, anchor_y(line) + (self.line_height - dot) / 2.0, dot, dot);
+                list.rounded_rect(rect, dot / 2.0, worst.severity.color());
+            }
+        }
+
+        if self.is_focused.get() {
+            for selection in self.selections.get() {
+                let caret = self.offset_to_position(selection.head);
+                let Some(row_y) = line_y(caret.line) else { continue };
+                let rect = (text_x + caret.column as f32 * char_width, row_y, 2.0, self.line_height);
+                list.rect(rect, self.caret_color);
+            }
+        }
+        list.pop_clip();
+
+        if self.show_minimap {
+            self.paint_minimap(list, &lines, &rows);
+        }
+
+        // Popups may spill outside the editor
+        if let (Some((at, _)), Some(popover)) = (self.hover.get(), self.hover_popover()) {
+            let content_lines: Vec<&str> = popover.content.lines().collect();
+            let text_size = self.font_size - 1.0;
+            let longest = content_lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+            let width = (longest as f32 * text_size * 0.6 + popover.padding * 2.0).min(popover.max_width);
+            let height = content_lines.len() as f32 * text_size * 1.4 + popover.padding * 2.0;
+            let card_x = text_x + at.column as f32 * char_width;
+            let above = anchor_y(at.line) - height - 4.0;
+            let card_y = if above >= 0.0 { above } else { anchor_y(at.line) + self.line_height + 4.0 };
+            let card = (card_x, card_y, width, height);
+            list.rounded_rect(card, popover.border_radius, popover.border_color);
+            let inner = (card_x + popover.border_width, card_y + popover.border_width, width - popover.border_width * 2.0, height - popover.border_width * 2.0);
+            list.rounded_rect(inner, popover.border_radius, popover.background_color);
+            for (index, line) in content_lines.iter().enumerate() {
+                let line_y = card_y + popover.padding + index as f32 * text_size * 1.4;
+                list.text(line.to_string(), card_x + popover.padding, line_y, text_size, popover.text_color);
+            }
+        }
+
+        let items = self.filtered_completions();
+        if let (false, Some(menu)) = (items.is_empty(), self.completion_menu()) {
+            let caret = self.offset_to_position(self.completion_start.get());
+            let row_height = self.line_height;
+            let rows = items.len().min((menu.max_height / row_height) as usize).max(1);
+            let selected = self.completion_index.get().min(items.len() - 1);
+            let first_row = (selected + 1).saturating_sub(rows);
+            let menu_x = text_x + caret.column as f32 * char_width;
+            let menu_y = anchor_y(caret.line) + self.line_height;
+            list.rect((menu_x, menu_y, menu.width, rows as f32 * row_height), self.gutter_color);
+            for (row, item) in items.iter().enumerate().skip(first_row).take(rows) {
+                let row_y = menu_y + (row - first_row) as f32 * row_height;
+                if row == selected {
+                    list.rect((menu_x, row_y, menu.width, row_height), self.selection_color);
+                }
+                list.text(item.label.clone(), menu_x + 8.0, row_y + text_offset, self.font_size, self.text_color);
+                if let Some(detail) = &item.detail {
+                    let detail_x = menu_x + menu.width - 8.0 - detail.chars().count() as f32 * char_width;
+                    list.text(detail.clone(), detail_x, row_y + text_offset, self.font_size, self.line_number_color);
+                }
+            }
+        }
+    }
+
+    /// Minimap: one thin bar per run of text, plus the viewport indicator
+    fn paint_minimap(&self, list: &mut DrawList, lines: &[&str], rows: &[usize]) {
+        let (x, y) = self.position;
+        let minimap_x = x + self.width - self.minimap_width;
+        list.rect((minimap_x, y, self.minimap_width, self.height), self.gutter_color);
+        list.push_clip((minimap_x, y, self.minimap_width, self.height));
+
+        let offset = self.minimap_offset();
+        let first = (offset / self.minimap_line_height) as usize;
+        let count = (self.height / self.minimap_line_height).ceil() as usize + 1;
+        let (r, g, b, _) = self.text_color;
+        let cell = (self.minimap_width - 8.0) / 80.0; // 80 columns fit
+        for (row, &line) in rows.iter().enumerate().skip(first).take(count) {
+            let row_y = y + row as f32 * self.minimap_line_height - offset;
+            let mut column = 0;
+            for word in lines[line].split(' ') {
+                let length = word.chars().count();
+                if length > 0 {
+                    let rect = (minimap_x + 4.0 + column as f32 * cell, row_y, length as f32 * cell, self.minimap_line_height * 0.75);
+                    list.rect(rect, (r, g, b, 110));
+                }
+                column += length + 1;
+            }
+        }
+        for diagnostic in self.diagnostics.get() {
+            if let Ok(row) = rows.binary_search(&diagnostic.start.line) {
+                let row_y = y + row as f32 * self.minimap_line_height - offset;
+                list.rect((minimap_x + self.minimap_width - 4.0, row_y, 4.0, self.minimap_line_height), diagnostic.severity.color());
+            }
+        }
+        list.rect(self.minimap_viewport(), (255, 255, 255, 30));
+        list.pop_clip();
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.width),
+                    height: taffy::style::Dimension::Length(self.height),
+                },
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create CodeEditor: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+/// Clickable strip at the right of the gutter
+const FOLD_MARKER_WIDTH: f32 = 16.0;
+
+/// Identifier characters (completion prefixes)
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Char range of the word around `offset` (empty when not on a word)
+fn word_range(chars: &[char], offset: usize) -> (usize, usize) {
+    let mut start = offset.min(chars.len());
+    while start > 0 && is_word_char(chars[start - 1]) {
+        start -= 1;
+    }
+    let mut end = offset.min(chars.len());
+    while end < chars.len() && is_word_char(chars[end]) {
+        end += 1;
+    }
+    (start, end)
+}
+
+impl Default for CodeEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Focusable for CodeEditor {
+    fn set_focused(&self, focused: bool) {
+        self.is_focused.set(focused);
+    }
+
+    fn handle_key(&self, key: FocusKey, shift: bool) -> bool {
+        self.handle_key_with(key, KeyModifiers { shift, ..Default::default() })
+    }
+
+    fn focus_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        Some((self.position.0, self.position.1, self.width, self.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_caret_typing_and_backspace() {
+        let editor = CodeEditor::with_text("ab\ncd\nef");
+        editor.set_cursor(1);
+        editor.add_cursor(4);
+        editor.add_cursor(7);
+        assert_eq!(editor.cursor_count(), 3);
+        assert_eq!(editor.primary.get(), 2);
+
+        editor.insert_text("X");
+        assert_eq!(editor.get_text(), "aXb\ncXd\neXf");
+        editor.backspace();
+        editor.backspace();
+        assert_eq!(editor.get_text(), "b\nd\nf");
+        // Carets that meet merge into one
+        editor.move_home(false);
+        editor.move_left(false);
+        editor.move_left(false);
+        assert_eq!(editor.selections.get(), vec![Selection::caret(0), Selection::caret(2)]);
+
+        assert!(editor.handle_key_with(FocusKey::Escape, KeyModifiers::default()));
+        assert_eq!(editor.cursor_count(), 1);
+    }
+
+    #[test]
+    fn ctrl_d_selects_next_occurrence() {
+        let editor = CodeEditor::with_text("let foo = foo + foo_bar;\nfoo()");
+        editor.set_cursor(5);
+        let ctrl = KeyModifiers::ctrl();
+        assert!(editor.handle_key_with(FocusKey::Char('d'), ctrl));
+        assert_eq!(editor.selected_texts(), vec!["foo"]);
+
+        editor.handle_key_with(FocusKey::Char('d'), ctrl);
+        editor.handle_key_with(FocusKey::Char('d'), ctrl);
+        editor.handle_key_with(FocusKey::Char('d'), ctrl);
+        assert_eq!(editor.cursor_count(), 4);
+
+        editor.insert_text("x");
+        assert_eq!(editor.get_text(), "let x = x + x_bar;\nx()");
+    }
+
+    #[test]
+    fn alt_drag_makes_column_selection() {
+        let mut editor = CodeEditor::with_text("abcdef\nab\nabcdef").position(0.0, 0.0);
+        let char_width = editor.char_width();
+        let (gutter, line_height) = (editor.gutter_width, editor.line_height);
+        let point = |column: f32, line: f32| (gutter + column * char_width, line * line_height + 2.0);
+
+        let (x, y) = point(1.0, 0.0);
+        editor.handle_mouse_down(x, y, KeyModifiers::alt());
+        let (x, y) = point(4.0, 2.0);
+        editor.handle_mouse_move(x, y);
+        editor.handle_mouse_up();
+
+        // The short middle line is selected up to its end
+        assert_eq!(editor.selected_texts(), vec!["bcd", "b", "bcd"]);
+        editor.insert_text("_");
+        assert_eq!(editor.get_text(), "a_ef\na_\na_ef");
+    }
+
+    #[test]
+    fn double_and_triple_click_select_word_and_line() {
+        let mut editor = CodeEditor::with_text("let total = 1;\nnext").position(0.0, 0.0);
+        let (x, y) = (editor.gutter_width + 6.0 * editor.char_width(), 2.0);
+        editor.handle_mouse_down(x, y, KeyModifiers::default());
+        assert_eq!(editor.selected_texts(), vec![""]);
+        editor.handle_mouse_down(x, y, KeyModifiers::default());
+        assert_eq!(editor.selected_texts(), vec!["total"]);
+        editor.handle_mouse_down(x, y, KeyModifiers::default());
+        assert_eq!(editor.selected_texts(), vec!["let total = 1;\n"]);
+    }
+
+    #[test]
+    fn folding_hides_lines_and_moves_with_edits() {
+        let source = "fn main() {\n    let a = 1;\n    if a > 0 {\n        print(a);\n    }\n}\nfn other() {}";
+        let editor = CodeEditor::with_text(source);
+        assert_eq!(editor.fold_regions(), vec![FoldRegion::new(0, 4), FoldRegion::new(2, 3)]);
+
+        editor.set_cursor(editor.position_to_offset(TextPosition::new(3, 4)));
+        assert!(editor.fold(2));
+        assert_eq!(editor.visible_lines(), vec![0, 1, 2, 4, 5, 6]);
+        // The caret left the hidden line; arrows skip the fold
+        assert_eq!(editor.offset_to_position(editor.primary_selection().head), TextPosition::new(2, 14));
+        editor.move_down(false);
+        assert_eq!(editor.offset_to_position(editor.primary_selection().head).line, 4);
+
+        // Lines inserted above move the fold
+        editor.set_cursor(0);
+        editor.insert_text("// header\n");
+        assert_eq!(editor.fold_state(), vec![3]);
+
+        // Restoring saved state skips lines that no longer fold
+        let restored = CodeEditor::with_text(editor.get_text());
+        restored.restore_fold_state(vec![3, 6]);
+        assert_eq!(restored.fold_state(), vec![3]);
+        let mut list = DrawList::new();
+        restored.paint(&mut list);
+    }
+
+    #[test]
+    fn minimap_click_jumps() {
+        let text = (0..200).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n");
+        let mut editor = CodeEditor::with_text(text).size(400.0, 200.0).minimap(true);
+        // 2px per line: y = 200 is line 100
+        editor.handle_mouse_down(390.0, 200.0 - editor.minimap_offset(), KeyModifiers::default());
+        editor.handle_mouse_up();
+        let scroll = editor.scroll_y.get();
+        assert!(scroll > 0.0);
+        let (_, top, _, height) = editor.minimap_viewport();
+        assert!(top < 200.0 && top + height > 0.0);
+        assert_eq!(editor.cursor_count(), 1);
+        assert_eq!(editor.primary_selection(), Selection::caret(0));
+    }
+
+    struct StubClient {
+        versions: Rc<RefCell<Vec<u64>>>,
+    }
+
+    impl LanguageClient for StubClient {
+        fn did_change(&mut self, _text: &str, version: u64) {
+            self.versions.borrow_mut().push(version);
+        }
+
+        fn hover(&mut self, position: TextPosition) -> Option<HoverInfo> {
+            (position.line == 0).then(|| HoverInfo::new("fn count() -> usize"))
+        }
+
+        fn completions(&mut self, _position: TextPosition) -> Vec<CompletionItem> {
+            vec![CompletionItem::new("count").detail("usize"), CompletionItem::new("counter"), CompletionItem::new("reset")]
+        }
+
+        fn definition(&mut self, _position: TextPosition) -> Option<Location> {
+            Some(Location::here(TextPosition::new(0, 3), TextPosition::new(0, 8)))
+        }
+    }
+
+    #[test]
+    fn language_client_hooks() {
+        use crate::language_client::DiagnosticSeverity;
+
+        let versions = Rc::new(RefCell::new(Vec::new()));
+        let jumped = Rc::new(RefCell::new(None));
+        let target = jumped.clone();
+        let editor = CodeEditor::with_text("fn count() {}\nco")
+            .language_client(StubClient { versions: versions.clone() })
+            .on_definition(move |location| *target.borrow_mut() = Some(location.clone()));
+
+        // Ctrl+Space opens completions filtered by the word before the caret
+        editor.set_cursor(16);
+        assert!(editor.handle_key_with(FocusKey::Space, KeyModifiers::ctrl()));
+        assert_eq!(editor.filtered_completions().len(), 2);
+        editor.handle_key_with(FocusKey::Char('u'), KeyModifiers::default());
+        editor.handle_key_with(FocusKey::Down, KeyModifiers::default());
+        assert!(editor.handle_key_with(FocusKey::Enter, KeyModifiers::default()));
+        assert_eq!(editor.get_text(), "fn count() {}\ncounter");
+        assert!(!editor.is_completing());
+        assert_eq!(*versions.borrow(), vec![1, 2]);
+
+        editor.hover_at(TextPosition::new(0, 4));
+        assert_eq!(editor.hover_popover().unwrap().content, "fn count() -> usize");
+
+        // Diagnostics take over the hover and get a gutter mark
+        editor.set_diagnostics(vec![Diagnostic::new(
+            TextPosition::new(1, 0),
+            TextPosition::new(1, 7),
+            DiagnosticSeverity::Error,
+            "unknown name `counter`",
+        )]);
+        editor.hover_at(TextPosition::new(1, 2));
+        assert_eq!(editor.hover.get().unwrap().1.contents, "unknown name `counter`");
+        assert_eq!(editor.diagnostics_on_line(1).len(), 1);
+        let mut list = DrawList::new();
+        editor.paint(&mut list);
+        assert!(list.commands().count() > 10);
+
+        let location = editor.go_to_definition().unwrap();
+        assert_eq!(editor.primary_selection(), Selection::caret(3));
+        assert_eq!(*jumped.borrow(), Some(location));
+    }
+}
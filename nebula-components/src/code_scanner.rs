@@ -0,0 +1,268 @@
+// CodeScanner - QR / barcode decoding over camera frames, off the UI thread
+// Used by CameraView's scanning mode; the decoder is pluggable, with rqrr behind the `qr` feature
+
+use crate::camera::CameraFrame;
+use tracing::{info, warn};
+
+/// Symbology of a decoded code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeFormat {
+    QrCode,
+    DataMatrix,
+    Ean13,
+    Ean8,
+    UpcA,
+    Code128,
+    Code39,
+    /// Anything else the decoder reports
+    Other(String),
+}
+
+/// A code found in a frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCode {
+    pub payload: String,
+    pub format: CodeFormat,
+    /// Corners in frame pixels (clockwise from top-left)
+    pub corners: [(f32, f32); 4],
+}
+
+impl DecodedCode {
+    /// Create a decoded code
+    pub fn new(payload: impl Into<String>, format: CodeFormat, corners: [(f32, f32); 4]) -> Self {
+        Self { payload: payload.into(), format, corners }
+    }
+
+    /// Axis-aligned box around the corners (x, y, width, height)
+    pub fn bounds(&self) -> (f32, f32, f32, f32) {
+        let xs = self.corners.iter().map(|corner| corner.0);
+        let ys = self.corners.iter().map(|corner| corner.1);
+        let (min_x, max_x) = xs.fold((f32::MAX, f32::MIN), |(lo, hi), x| (lo.min(x), hi.max(x)));
+        let (min_y, max_y) = ys.fold((f32::MAX, f32::MIN), |(lo, hi), y| (lo.min(y), hi.max(y)));
+        (min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+}
+
+/// Finds codes in a grayscale image 🔍
+///
+/// `QrDecoder` (the `qr` feature) wraps rqrr; wrap another library
+/// (rxing, zbar…) for 1D barcodes. Runs on the scanner's worker thread,
+/// hence `Send`.
+pub trait CodeDecoder: Send + 'static {
+    /// `luma` is `width * height` bytes, row by row
+    fn decode(&mut self, width: u32, height: u32, luma: &[u8]) -> Vec<DecodedCode>;
+}
+
+/// QR codes through rqrr 🔳
+#[cfg(feature = "qr")]
+#[derive(Debug, Default)]
+pub struct QrDecoder;
+
+#[cfg(feature = "qr")]
+impl CodeDecoder for QrDecoder {
+    fn decode(&mut self, width: u32, height: u32, luma: &[u8]) -> Vec<DecodedCode> {
+        let (w, h) = (width as usize, height as usize);
+        if luma.len() < w * h {
+            return Vec::new();
+        }
+        let mut image = rqrr::PreparedImage::prepare_from_greyscale(w, h, |x, y| luma[y * w + x]);
+        image
+            .detect_grids()
+            .into_iter()
+            .filter_map(|grid| {
+                let corners = grid.bounds.map(|point| (point.x as f32, point.y as f32));
+                match grid.decode() {
+                    Ok((_, payload)) => Some(DecodedCode::new(payload, CodeFormat::QrCode, corners)),
+                    Err(e) => {
+                        warn!("⚠️ Found a QR code but couldn't read it: {:?}", e);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Grayscale (BT.601) copy of an RGBA frame
+pub fn frame_to_luma(frame: &CameraFrame) -> Vec<u8> {
+    frame
+        .data
+        .chunks_exact(4)
+        .map(|pixel| ((pixel[0] as u32 * 77 + pixel[1] as u32 * 150 + pixel[2] as u32 * 29) >> 8) as u8)
+        .collect()
+}
+
+/// Runs a CodeDecoder on a background thread 🧵
+///
+/// Only one frame is decoded at a time; frames submitted while the worker
+/// is busy are dropped, so a slow decoder never backs up the camera.
+/// On wasm (no threads) frames are decoded inline.
+pub struct CodeScanner {
+    #[cfg(not(target_arch = "wasm32"))]
+    jobs: Option<std::sync::mpsc::Sender<CameraFrame>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    results: std::sync::mpsc::Receiver<Vec<DecodedCode>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    worker: Option<std::thread::JoinHandle<()>>,
+    #[cfg(target_arch = "wasm32")]
+    decoder: Box<dyn CodeDecoder>,
+    #[cfg(target_arch = "wasm32")]
+    pending: Option<Vec<DecodedCode>>,
+    busy: bool,
+}
+
+impl CodeScanner {
+    /// Start a scanner around a decoder
+    pub fn new(decoder: impl CodeDecoder) -> Self {
+        info!("🔍 Starting code scanner");
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (jobs, job_rx) = std::sync::mpsc::channel::<CameraFrame>();
+            let (result_tx, results) = std::sync::mpsc::channel();
+            let mut decoder = decoder;
+            let worker = std::thread::Builder::new()
+                .name("nebula-code-scanner".to_string())
+                .spawn(move || {
+                    for frame in job_rx {
+                        let luma = frame_to_luma(&frame);
+                        let codes = decoder.decode(frame.width, frame.height, &luma);
+                        if result_tx.send(codes).is_err() {
+                            break;
+                        }
+                    }
+                });
+            let worker = match worker {
+                Ok(worker) => Some(worker),
+                Err(e) => {
+                    warn!("⚠️ Could not start scanner thread: {}", e);
+                    None
+                }
+            };
+            Self { jobs: worker.as_ref().map(|_| jobs), results, worker, busy: false }
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            Self { decoder: Box::new(decoder), pending: None, busy: false }
+        }
+    }
+
+    /// Is a frame being decoded?
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    /// Hand a frame to the decoder
+    /// Returns false (and drops the frame) while the previous one is still decoding
+    pub fn submit(&mut self, frame: &CameraFrame) -> bool {
+        if self.busy {
+            return false;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(jobs) = self.jobs.as_ref() else {
+                return false;
+            };
+            self.busy = jobs.send(frame.clone()).is_ok();
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let luma = frame_to_luma(frame);
+            self.pending = Some(self.decoder.decode(frame.width, frame.height, &luma));
+            self.busy = true;
+        }
+        self.busy
+    }
+
+    /// Results of the last submitted frame, once it's done
+    pub fn poll(&mut self) -> Option<Vec<DecodedCode>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let codes = self.results.try_recv().ok();
+        #[cfg(target_arch = "wasm32")]
+        let codes = self.pending.take();
+
+        if codes.is_some() {
+            self.busy = false;
+        }
+        codes
+    }
+}
+
+impl Drop for CodeScanner {
+    fn drop(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            // Closing the channel ends the worker loop
+            self.jobs = None;
+            if let Some(worker) = self.worker.take() {
+                let _ = worker.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// "Finds" a code wherever the frame has a pure black pixel
+    struct DarkPixelDecoder;
+
+    impl CodeDecoder for DarkPixelDecoder {
+        fn decode(&mut self, width: u32, _height: u32, luma: &[u8]) -> Vec<DecodedCode> {
+            luma.iter()
+                .position(|&value| value == 0)
+                .map(|index| {
+                    let (x, y) = ((index as u32 % width) as f32, (index as u32 / width) as f32);
+                    let corners = [(x, y), (x + 1.0, y), (x + 1.0, y + 1.0), (x, y + 1.0)];
+                    DecodedCode::new(format!("dark@{},{}", x, y), CodeFormat::QrCode, corners)
+                })
+                .into_iter()
+                .collect()
+        }
+    }
+
+    fn wait_for(scanner: &mut CodeScanner) -> Vec<DecodedCode> {
+        let start = Instant::now();
+        loop {
+            if let Some(codes) = scanner.poll() {
+                return codes;
+            }
+            assert!(start.elapsed() < Duration::from_secs(5), "scanner timed out");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn scanner_decodes_off_thread() {
+        let mut scanner = CodeScanner::new(DarkPixelDecoder);
+        let mut data = vec![255; 4 * 4 * 4];
+        data[(4 + 2) * 4..(4 + 2) * 4 + 3].fill(0); // (2, 1) is black
+        let frame = CameraFrame::new(4, 4, data).unwrap();
+
+        assert!(scanner.submit(&frame));
+        assert!(!scanner.submit(&frame)); // busy: dropped
+        let codes = wait_for(&mut scanner);
+        assert_eq!(codes.len(), 1);
+        assert_eq!(codes[0].payload, "dark@2,1");
+        assert_eq!(codes[0].bounds(), (2.0, 1.0, 1.0, 1.0));
+        assert!(!scanner.is_busy());
+    }
+
+    #[cfg(feature = "qr")]
+    #[test]
+    fn qr_decoder_reads_fixture() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/qr-scan.png");
+        let image = image::open(path).unwrap().to_rgba8();
+        let frame = CameraFrame::new(image.width(), image.height(), image.into_raw()).unwrap();
+
+        let mut scanner = CodeScanner::new(QrDecoder);
+        assert!(scanner.submit(&frame));
+        let codes = wait_for(&mut scanner);
+        assert_eq!(codes.len(), 1);
+        assert_eq!(codes[0].payload, "https://nebula-ui.dev/scan");
+        assert_eq!(codes[0].format, CodeFormat::QrCode);
+        let (x, y, width, height) = codes[0].bounds();
+        assert!(x > 0.0 && y > 0.0 && width > 50.0 && height > 50.0);
+    }
+}
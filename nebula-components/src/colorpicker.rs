@@ -2,7 +2,9 @@
 // Essential for color input in design tools and forms
 
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::accessibility::{contrast_ratio, WcagLevel};
 use nebula_core::signal::Signal;
+use nebula_gfx::RgbaImage;
 
 /// Color representation (RGBA)
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -107,6 +109,61 @@ impl Color {
     }
 }
 
+/// Which swatch of a foreground/background pair the picker edits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairTarget {
+    Foreground,
+    Background,
+}
+
+/// Live contrast of the selected pair against WCAG
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastReport {
+    /// Contrast ratio (1.0 to 21.0)
+    pub ratio: f32,
+    pub aa: bool,
+    pub aa_large: bool,
+    pub aaa: bool,
+    pub aaa_large: bool,
+}
+
+impl ContrastReport {
+    /// Measure a text/background pair
+    pub fn measure(foreground: Color, background: Color) -> Self {
+        let ratio = contrast_ratio(foreground.into(), background.into());
+        let passes = |level: WcagLevel, large: bool| ratio >= level.required_ratio(large);
+        Self {
+            ratio,
+            aa: passes(WcagLevel::AA, false),
+            aa_large: passes(WcagLevel::AA, true),
+            aaa: passes(WcagLevel::AAA, false),
+            aaa_large: passes(WcagLevel::AAA, true),
+        }
+    }
+
+    /// Does the pair pass at this level?
+    pub fn passes(&self, level: WcagLevel, large_text: bool) -> bool {
+        match (level, large_text) {
+            (WcagLevel::AA, false) => self.aa,
+            (WcagLevel::AA, true) => self.aa_large,
+            (WcagLevel::AAA, false) => self.aaa,
+            (WcagLevel::AAA, true) => self.aaa_large,
+        }
+    }
+
+    /// Short summary for the preview, e.g. "4.61:1 AA ✓ AAA ✗"
+    pub fn label(&self) -> String {
+        let mark = |pass: bool| if pass { "✓" } else { "✗" };
+        format!("{:.2}:1 AA {} AAA {}", self.ratio, mark(self.aa), mark(self.aaa))
+    }
+}
+
+impl From<Color> for (u8, u8, u8, u8) {
+    fn from(color: Color) -> Self {
+        (color.r, color.g, color.b, color.a)
+    }
+}
+
 /// ColorPicker component - color selection component
 /// 
 /// # Example
@@ -128,6 +185,12 @@ pub struct ColorPicker {
     pub picker_width: f32,
     pub picker_height: f32,
     pub on_change: Option<Box<dyn Fn(Color)>>,
+    /// Background of the pair in contrast mode (None = single color)
+    pub background_color: Option<Signal<Color>>,
+    /// Swatch that `select_color` edits in contrast mode
+    pub editing: PairTarget,
+    /// Next click samples the screen instead of the palette
+    pub eyedropper_active: Signal<bool>,
 }
 
 impl ColorPicker {
@@ -145,9 +208,18 @@ impl ColorPicker {
             picker_width: 280.0,
             picker_height: 320.0,
             on_change: None,
+            background_color: None,
+            editing: PairTarget::Foreground,
+            eyedropper_active: Signal::new(false),
         }
     }
 
+    /// Pick a foreground/background pair and preview their contrast 🌓
+    pub fn contrast_pair(mut self, background: Color) -> Self {
+        self.background_color = Some(Signal::new(background));
+        self
+    }
+
     /// Set the selected color
     pub fn selected_color(self, color: Color) -> Self {
         self.selected_color.set(color);
@@ -193,10 +265,13 @@ impl ColorPicker {
         self
     }
 
-    /// Select a color
+    /// Select a color (into the swatch being edited in contrast mode)
     pub fn select_color(&mut self, color: Color) {
         if !self.disabled {
-            self.selected_color.set(color);
+            match (&self.background_color, self.editing) {
+                (Some(background), PairTarget::Background) => background.set(color),
+                _ => self.selected_color.set(color),
+            }
             if let Some(ref callback) = self.on_change {
                 callback(color);
             }
@@ -208,6 +283,58 @@ impl ColorPicker {
         self.selected_color.get()
     }
 
+    /// Background of the pair (None outside contrast mode)
+    pub fn get_background_color(&self) -> Option<Color> {
+        self.background_color.as_ref().map(|background| background.get())
+    }
+
+    /// Choose which swatch of the pair to edit
+    pub fn edit(&mut self, target: PairTarget) {
+        if self.background_color.is_some() {
+            self.editing = target;
+        }
+    }
+
+    /// Swap foreground and background
+    pub fn swap_pair(&mut self) {
+        if let Some(background) = &self.background_color {
+            let foreground = self.selected_color.get();
+            self.selected_color.set(background.get());
+            background.set(foreground);
+        }
+    }
+
+    /// Contrast of the pair right now (None outside contrast mode)
+    pub fn contrast(&self) -> Option<ContrastReport> {
+        self.get_background_color().map(|background| ContrastReport::measure(self.get_selected_color(), background))
+    }
+
+    /// Arm the eyedropper: the next `eyedropper_pick` samples the screen
+    pub fn start_eyedropper(&mut self) {
+        if !self.disabled {
+            self.eyedropper_active.set(true);
+        }
+    }
+
+    /// Disarm the eyedropper (Escape)
+    pub fn cancel_eyedropper(&mut self) {
+        self.eyedropper_active.set(false);
+    }
+
+    /// Sample a screenshot at a physical pixel and select that color
+    /// Returns the picked color, or None if the eyedropper wasn't armed or the point is off the image
+    pub fn eyedropper_pick(&mut self, screenshot: &RgbaImage, x: u32, y: u32) -> Option<Color> {
+        if !self.eyedropper_active.get() {
+            return None;
+        }
+        let [r, g, b, _] = screenshot.pixel(x, y)?;
+        self.eyedropper_active.set(false);
+        // Screen pixels are what the user sees, so the pick is always opaque
+        let color = Color::rgb(r, g, b);
+        self.select_color(color);
+        Some(color)
+    }
+
     /// Set color from hex string
     pub fn set_from_hex(&mut self, hex: &str) -> Result<(), String> {
         if let Some(color) = Color::from_hex(hex) {
@@ -403,6 +530,29 @@ mod tests {
         assert!(colorpicker.node_id.is_some());
     }
 
+    #[test]
+    fn colorpicker_contrast_pair_and_eyedropper() {
+        let mut colorpicker = ColorPicker::new()
+            .selected_color(Color::rgb(119, 119, 119))
+            .contrast_pair(Color::rgb(255, 255, 255));
+        let report = colorpicker.contrast().unwrap();
+        assert!(report.aa_large && !report.aa && !report.aaa);
+
+        colorpicker.edit(PairTarget::Background);
+        let mut screenshot = RgbaImage::new(2, 2);
+        screenshot.pixels[12..16].copy_from_slice(&[0, 0, 0, 255]);
+        assert_eq!(colorpicker.eyedropper_pick(&screenshot, 1, 1), None);
+        colorpicker.start_eyedropper();
+        assert_eq!(colorpicker.eyedropper_pick(&screenshot, 1, 1), Some(Color::rgb(0, 0, 0)));
+        assert!(!colorpicker.eyedropper_active.get());
+        assert_eq!(colorpicker.get_background_color(), Some(Color::rgb(0, 0, 0)));
+        assert_eq!(colorpicker.get_selected_color(), Color::rgb(119, 119, 119));
+
+        colorpicker.swap_pair();
+        assert_eq!(colorpicker.get_selected_color(), Color::rgb(0, 0, 0));
+        assert!(ColorPicker::new().contrast().is_none());
+    }
+
     #[test]
     fn color_invalid_hex() {
         assert!(Color::from_hex("#GGGGGG").is_none());
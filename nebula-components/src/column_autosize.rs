@@ -0,0 +1,154 @@
+// Column Autosize - Fit Table and DataGrid columns to their content
+// Measures the widest cell (and the header) with a text measurer, capped
+
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use nebula_core::input_settings::ClickCounter;
+use nebula_core::text::TextRenderer;
+use tracing::warn;
+
+use crate::text::estimate_text_width;
+
+/// Measures a line of text at a font size; shareable with a worker thread
+pub type TextMeasure = Arc<dyn Fn(&str, f32) -> f32 + Send + Sync>;
+
+/// Measurer that uses a real font through a `TextRenderer`
+pub fn renderer_measure(renderer: TextRenderer) -> TextMeasure {
+    let renderer = Mutex::new(renderer);
+    Arc::new(move |text, font_size| {
+        let mut renderer = renderer.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        renderer.measure_text(text, font_size.round() as u32)
+    })
+}
+
+/// Widest of a header and some cells, plus padding, clamped to [min, max]
+pub fn fit_width<'a>(
+    header: &str,
+    cells: impl IntoIterator<Item = &'a str>,
+    measure: &dyn Fn(&str, f32) -> f32,
+    font_size: f32,
+    padding: f32,
+    (min_width, max_width): (f32, f32),
+) -> f32 {
+    let widest = cells
+        .into_iter()
+        .map(|cell| measure(cell, font_size))
+        .fold(measure(header, font_size), f32::max);
+    (widest + padding * 2.0).ceil().clamp(min_width, max_width.max(min_width))
+}
+
+/// How columns are fitted to content 📏
+///
+/// Shared by Table and DataGrid. The header menu's "Autosize Column" and a
+/// double-click on a header resize handle measure `visible_rows`; use the
+/// grid's `autosize_column_async` to measure every row off the UI thread.
+#[derive(Clone)]
+pub struct ColumnAutosizer {
+    pub measure: TextMeasure,
+    pub font_size: f32,
+    pub min_width: f32,
+    /// Long cells never make a column wider than this
+    pub max_width: f32,
+    /// Rows measured by the synchronous autosize (set from `visible_rows`)
+    pub visible_rows: Range<usize>,
+    resize_clicks: ClickCounter,
+    resize_column: Option<String>,
+}
+
+impl ColumnAutosizer {
+    /// Autosizer using the width estimate until a font is attached
+    pub fn new() -> Self {
+        Self {
+            measure: Arc::new(estimate_text_width),
+            font_size: 14.0,
+            min_width: 48.0,
+            max_width: 480.0,
+            visible_rows: 0..100,
+            resize_clicks: ClickCounter::new(),
+            resize_column: None,
+        }
+    }
+
+    /// Fitted width for a header and some cells
+    pub fn fit<'a>(&self, header: &str, cells: impl IntoIterator<Item = &'a str>, padding: f32) -> f32 {
+        fit_width(header, cells, &*self.measure, self.font_size, padding, (self.min_width, self.max_width))
+    }
+
+    /// Mouse down on a column's resize handle; true on a double-click
+    pub fn press_resize_handle(&mut self, column_id: &str, x: f32, y: f32) -> bool {
+        if self.resize_column.as_deref() != Some(column_id) {
+            self.resize_clicks.reset();
+            self.resize_column = Some(column_id.to_string());
+        }
+        self.resize_clicks.press(x, y) == 2
+    }
+
+    /// Measure every cell of a column on a worker thread (inline on wasm)
+    pub fn fit_async(&self, header: String, cells: Vec<String>, padding: f32) -> AutosizeJob {
+        let width = Arc::new(Mutex::new(None));
+        let slot = width.clone();
+        let sizer = (self.measure.clone(), self.font_size, (self.min_width, self.max_width));
+        let fit = move || {
+            let (measure, font_size, limits) = sizer;
+            let fitted = fit_width(&header, cells.iter().map(String::as_str), &*measure, font_size, padding, limits);
+            *slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(fitted);
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = std::thread::Builder::new().name("nebula-autosize".to_string()).spawn(fit) {
+            warn!("⚠️ Could not start autosize thread: {}", e);
+        }
+        #[cfg(target_arch = "wasm32")]
+        fit();
+        AutosizeJob { width }
+    }
+}
+
+impl Default for ColumnAutosizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A column being measured in the background 🧵
+///
+/// Poll `width()` each frame (or hand it to the grid's `apply_autosize`).
+#[derive(Clone)]
+pub struct AutosizeJob {
+    width: Arc<Mutex<Option<f32>>>,
+}
+
+impl AutosizeJob {
+    /// The fitted width, once measured
+    pub fn width(&self) -> Option<f32> {
+        *self.width.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Finished measuring?
+    pub fn is_ready(&self) -> bool {
+        self.width().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn fits_widest_cell_with_cap() {
+        let measure = |text: &str, _size: f32| text.len() as f32 * 10.0;
+        assert_eq!(fit_width("Id", ["7", "1234"], &measure, 14.0, 8.0, (48.0, 480.0)), 56.0);
+        assert_eq!(fit_width("Id", ["x"], &measure, 14.0, 8.0, (48.0, 480.0)), 48.0);
+        assert_eq!(fit_width("Notes", ["a".repeat(100).as_str()], &measure, 14.0, 8.0, (48.0, 480.0)), 480.0);
+
+        let mut sizer = ColumnAutosizer::new();
+        sizer.measure = Arc::new(measure);
+        let job = sizer.fit_async("Id".to_string(), vec!["12345".to_string()], 8.0);
+        let started = Instant::now();
+        while !job.is_ready() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(job.width(), Some(66.0));
+    }
+}
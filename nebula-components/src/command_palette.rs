@@ -0,0 +1,218 @@
+// CommandPalette Component - Searchable list of every app action
+// Rows come from the CommandBus and show the shortcut the registry binds
+
+use nebula_core::command::CommandBus;
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use tracing::info;
+
+use super::shortcuts::ShortcutRegistry;
+
+/// One row in the palette
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteRow {
+    pub action: String,
+    pub label: String,
+    /// Shortcut hint ("Ctrl+S"), right-aligned in the row
+    pub shortcut: Option<String>,
+    pub enabled: bool,
+}
+
+/// CommandPalette component - type to find an action, Enter to run it 🔎
+///
+/// # Example
+/// ```rust,ignore
+/// let mut palette = CommandPalette::new(bus.clone()).shortcuts(&shortcuts);
+/// palette.open();
+/// palette.set_query("sav");
+/// palette.run_selected(); // "Save"
+/// ```
+pub struct CommandPalette {
+    pub node_id: Option<NodeId>,
+    pub commands: CommandBus,
+    pub shortcuts: ShortcutRegistry,
+    pub query: Signal<String>,
+    pub selected: Signal<usize>,
+    pub is_open: Signal<bool>,
+    pub width: f32,
+    pub row_height: f32,
+    pub max_rows: usize,
+}
+
+impl CommandPalette {
+    /// Palette over a CommandBus's actions
+    pub fn new(commands: CommandBus) -> Self {
+        Self {
+            node_id: None,
+            commands,
+            shortcuts: ShortcutRegistry::new(),
+            query: Signal::new(String::new()),
+            selected: Signal::new(0),
+            is_open: Signal::new(false),
+            width: 560.0,
+            row_height: 32.0,
+            max_rows: 10,
+        }
+    }
+
+    /// Registry the rows' shortcut hints come from
+    pub fn shortcuts(mut self, shortcuts: &ShortcutRegistry) -> Self {
+        self.shortcuts = shortcuts.clone();
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set how many rows show at once
+    pub fn max_rows(mut self, rows: usize) -> Self {
+        self.max_rows = rows.max(1);
+        self
+    }
+
+    /// Open with an empty query
+    pub fn open(&mut self) {
+        self.query.set(String::new());
+        self.selected.set(0);
+        self.is_open.set(true);
+    }
+
+    /// Close without running anything
+    pub fn close(&mut self) {
+        self.is_open.set(false);
+    }
+
+    /// Is the palette showing?
+    pub fn is_open(&self) -> bool {
+        self.is_open.get()
+    }
+
+    /// Change the search text (selection goes back to the top)
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query.set(query.into());
+        self.selected.set(0);
+    }
+
+    /// Actions matching the query, in registration order
+    ///
+    /// A row matches when the query's letters appear in its label (or
+    /// action id) in order, ignoring case: "sva" finds "Save As".
+    pub fn rows(&self) -> Vec<PaletteRow> {
+        let query = self.query.get().to_lowercase();
+        self.commands
+            .action_ids()
+            .into_iter()
+            .filter_map(|action| {
+                let label = self.commands.label(&action)?;
+                if !matches_query(&label.to_lowercase(), &query) && !matches_query(&action.to_lowercase(), &query) {
+                    return None;
+                }
+                Some(PaletteRow {
+                    shortcut: self.shortcuts.hint_for(&action),
+                    enabled: self.commands.is_enabled(&action),
+                    label,
+                    action,
+                })
+            })
+            .collect()
+    }
+
+    /// Move the highlight by `delta` rows (clamped)
+    pub fn move_selection(&mut self, delta: i32) {
+        let count = self.rows().len();
+        if count == 0 {
+            return;
+        }
+        let next = (self.selected.get() as i32 + delta).clamp(0, count as i32 - 1);
+        self.selected.set(next as usize);
+    }
+
+    /// Run the highlighted action and close; false if it's disabled or nothing matches
+    pub fn run_selected(&mut self) -> bool {
+        let Some(row) = self.rows().into_iter().nth(self.selected.get()) else {
+            return false;
+        };
+        if !row.enabled {
+            return false;
+        }
+        info!("🔎 Palette → {}", row.action);
+        self.close();
+        self.commands.dispatch(&row.action)
+    }
+
+    /// Build the palette layout
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let visible_rows = self.rows().len().min(self.max_rows) as f32;
+        let style = taffy::style::Style {
+            display: if self.is_open() { taffy::style::Display::Flex } else { taffy::style::Display::None },
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Length(self.width),
+                height: taffy::style::Dimension::Length(self.row_height * (visible_rows + 1.0)),
+            },
+            ..Default::default()
+        };
+
+        let node = engine
+            .new_leaf(style)
+            .map_err(|e| format!("Failed to create command palette node: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+fn matches_query(text: &str, query: &str) -> bool {
+    let mut chars = text.chars();
+    query.chars().filter(|c| !c.is_whitespace()).all(|q| chars.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::Button;
+    use crate::menubar::{Menu, MenuBar};
+    use crate::shortcuts::{ShortcutHints, ShortcutPlatform};
+
+    #[test]
+    fn hints_follow_registry_bindings() {
+        let saved = Signal::new(0);
+        let bus = CommandBus::new();
+        let target = saved.clone();
+        bus.register_action("file.save", "Save", move || target.update(|n| n + 1));
+        bus.register_action("file.save_as", "Save As…", || {});
+
+        let mut shortcuts = ShortcutRegistry::standard()
+            .hints(ShortcutHints::new(ShortcutPlatform::Standard).modifier_names("Strg", "Alt", "Umschalt"));
+        shortcuts.bind("Ctrl+S", "file.save").unwrap();
+        shortcuts.bind("Ctrl+Shift+S", "file.save_as").unwrap();
+
+        // The menu declares an outdated shortcut; the registry wins
+        let menubar = MenuBar::new()
+            .add_menu(Menu::new("File").add_item_with_shortcut("Save", "file.save", "Ctrl+W"))
+            .shortcuts(&shortcuts);
+        assert_eq!(menubar.find_item("file.save").and_then(|item| item.trailing_text()), Some("Strg+S"));
+
+        let button = Button::new("Save").action("file.save").shortcuts(&shortcuts);
+        assert_eq!(button.tooltip_text().as_deref(), Some("Save (Strg+S)"));
+        assert_eq!(Button::new("Plain").tooltip_text(), None);
+
+        let mut palette = CommandPalette::new(bus.clone()).shortcuts(&shortcuts);
+        palette.open();
+        palette.set_query("sva");
+        let rows = palette.rows();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].shortcut.as_deref(), Some("Strg+Umschalt+S"));
+
+        palette.set_query("save");
+        assert_eq!(palette.rows().len(), 2);
+        assert!(palette.run_selected());
+        assert_eq!(saved.get(), 1);
+        assert!(!palette.is_open());
+
+        palette.set_query("undo");
+        assert_eq!(palette.rows()[0].shortcut.as_deref(), Some("Strg+Z"));
+        assert!(!palette.run_selected()); // Nothing to undo
+    }
+}
@@ -1,6 +1,9 @@
-use nebula_core::{LayoutEngine, NodeId, Layout};
+use nebula_core::{LayoutEngine, LayoutDirection, NodeId, Layout};
+use taffy::prelude::*;
 use tracing::info;
 
+use super::widget::Widget;
+
 /// Alignment options for containers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Alignment {
@@ -83,6 +86,17 @@ impl VStack {
     }
 }
 
+impl Widget for VStack {
+    fn build_node(&mut self, engine: &mut LayoutEngine, children: &[NodeId]) -> Result<NodeId, String> {
+        let style = stack_style(FlexDirection::Column, self.spacing, self.padding, self.alignment);
+        let node = engine
+            .new_with_children(style, children)
+            .map_err(|e| format!("Failed to create VStack: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
 impl Default for VStack {
     fn default() -> Self {
         Self::new()
@@ -158,6 +172,17 @@ impl HStack {
     }
 }
 
+impl Widget for HStack {
+    fn build_node(&mut self, engine: &mut LayoutEngine, children: &[NodeId]) -> Result<NodeId, String> {
+        let style = stack_style(LayoutDirection::current().row(), self.spacing, self.padding, self.alignment);
+        let node = engine
+            .new_with_children(style, children)
+            .map_err(|e| format!("Failed to create HStack: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
 impl Default for HStack {
     fn default() -> Self {
         Self::new()
@@ -218,17 +243,72 @@ impl ZStack {
     }
 }
 
+impl Widget for ZStack {
+    fn build_node(&mut self, engine: &mut LayoutEngine, children: &[NodeId]) -> Result<NodeId, String> {
+        // Every child goes into the same grid cell, so they overlap
+        for &child in children {
+            let mut style = engine
+                .get_style(child)
+                .map_err(|e| format!("Failed to read ZStack child style: {:?}", e))?;
+            style.grid_row = line(1);
+            style.grid_column = line(1);
+            engine
+                .set_style(child, style)
+                .map_err(|e| format!("Failed to place ZStack child: {:?}", e))?;
+        }
+        let style = Style {
+            display: Display::Grid,
+            align_items: Some(align_items(self.alignment)),
+            justify_items: Some(align_items(self.alignment)),
+            ..Default::default()
+        };
+        let node = engine
+            .new_with_children(style, children)
+            .map_err(|e| format!("Failed to create ZStack: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
 impl Default for ZStack {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Flex style shared by VStack and HStack widgets
+fn stack_style(direction: FlexDirection, spacing: f32, padding: f32, alignment: Alignment) -> Style {
+    Style {
+        display: Display::Flex,
+        flex_direction: direction,
+        gap: Size {
+            width: LengthPercentage::Length(spacing),
+            height: LengthPercentage::Length(spacing),
+        },
+        padding: Rect {
+            left: LengthPercentage::Length(padding),
+            right: LengthPercentage::Length(padding),
+            top: LengthPercentage::Length(padding),
+            bottom: LengthPercentage::Length(padding),
+        },
+        align_items: Some(align_items(alignment)),
+        ..Default::default()
+    }
+}
+
+fn align_items(alignment: Alignment) -> AlignItems {
+    match alignment {
+        Alignment::Start => AlignItems::FlexStart,
+        Alignment::Center => AlignItems::Center,
+        Alignment::End => AlignItems::FlexEnd,
+        Alignment::Stretch => AlignItems::Stretch,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use nebula_core::layout::styles;
-    use taffy::prelude::*;
 
     #[test]
     fn vstack_creation() {
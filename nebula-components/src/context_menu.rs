@@ -1,8 +1,89 @@
 // ContextMenu Component - Right-click menu with actions
-// Shows a menu of options at the cursor position
+// Shows a menu of options at the cursor, the touch point after a long-press, or under the focused element
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Long-press gesture: a touch held still opens the context menu 👆
+///
+/// Feed it the touch start / move / end and poll it each frame; it fires
+/// once the finger has stayed within `slop` pixels for `delay`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LongPress {
+    pub delay: Duration,
+    /// How far the finger may drift before it counts as a scroll or drag
+    pub slop: f32,
+    pressed: Option<(f32, f32, Instant)>,
+    fired: bool,
+}
+
+impl LongPress {
+    /// Platform-typical timing (500 ms, 10 px)
+    pub fn new() -> Self {
+        Self { delay: Duration::from_millis(500), slop: 10.0, pressed: None, fired: false }
+    }
+
+    /// Finger down
+    pub fn press(&mut self, x: f32, y: f32) {
+        self.press_at(x, y, Instant::now());
+    }
+
+    /// Finger down at a given time
+    pub fn press_at(&mut self, x: f32, y: f32, now: Instant) {
+        self.pressed = Some((x, y, now));
+        self.fired = false;
+    }
+
+    /// Finger moved; drifting past the slop cancels the gesture
+    pub fn move_to(&mut self, x: f32, y: f32) {
+        if let Some((start_x, start_y, _)) = self.pressed {
+            if (x - start_x).hypot(y - start_y) > self.slop {
+                self.pressed = None;
+            }
+        }
+    }
+
+    /// Finger up; returns true if the long-press fired (swallow the tap)
+    pub fn release(&mut self) -> bool {
+        self.pressed = None;
+        std::mem::take(&mut self.fired)
+    }
+
+    /// Touch cancelled by the OS
+    pub fn cancel(&mut self) {
+        self.pressed = None;
+        self.fired = false;
+    }
+
+    /// Where the press was held, once `delay` has passed (fires once per press)
+    pub fn poll_at(&mut self, now: Instant) -> Option<(f32, f32)> {
+        let (x, y, started) = self.pressed?;
+        if self.fired || now.saturating_duration_since(started) < self.delay {
+            return None;
+        }
+        self.fired = true;
+        Some((x, y))
+    }
+
+    /// Poll against the current time
+    pub fn poll(&mut self) -> Option<(f32, f32)> {
+        self.poll_at(Instant::now())
+    }
+
+    /// Waiting for the delay (keep requesting frames)?
+    pub fn is_pending(&self) -> bool {
+        self.pressed.is_some() && !self.fired
+    }
+}
+
+impl Default for LongPress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// Context menu item
 #[derive(Debug, Clone, PartialEq)]
@@ -66,6 +147,11 @@ impl ContextMenuItem {
 }
 
 /// ContextMenu component - displays a menu on right-click
+///
+/// Not mouse-only: forward touches to `touch_start` / `touch_move` /
+/// `touch_end` and call `update` each frame for long-press, and open it
+/// with `show_for_element` when `Key::opens_context_menu` (the menu key or
+/// Shift+F10) is pressed.
 /// 
 /// # Example
 /// ```
@@ -85,19 +171,22 @@ pub struct ContextMenu {
     pub width: f32,
     pub max_height: f32,
     pub padding: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub text_color: ThemeColor,
+    pub hover_color: ThemeColor,
+    pub disabled_color: ThemeColor,
     pub border_radius: f32,
     pub on_select: Option<Box<dyn Fn(&str)>>,
     pub on_open: Option<Box<dyn Fn()>>,
     pub on_close: Option<Box<dyn Fn()>>,
+    /// Long-press tracker for touch invocation
+    pub long_press: LongPress,
 }
 
 impl ContextMenu {
-    /// Create a new ContextMenu component
+    /// Create a new ContextMenu component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             items: Vec::new(),
@@ -107,17 +196,33 @@ impl ContextMenu {
             width: 200.0,
             max_height: 400.0,
             padding: 4.0,
-            background_color: (255, 255, 255, 255),
-            text_color: (0, 0, 0, 255),
-            hover_color: (240, 240, 240, 255),
-            disabled_color: (150, 150, 150, 255),
+            background_color: colors.surface,
+            text_color: colors.text,
+            hover_color: colors.hover,
+            disabled_color: colors.text_muted,
             border_radius: 8.0,
             on_select: None,
             on_open: None,
             on_close: None,
+            long_press: LongPress::new(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.text_color = colors.text;
+        self.hover_color = colors.hover;
+        self.disabled_color = colors.text_muted;
+    }
+
     /// Set the width
     pub fn width(mut self, width: f32) -> Self {
         self.width = width;
@@ -234,6 +339,51 @@ impl ContextMenu {
         }
     }
 
+    /// Show for a long-press, anchored at the touch point
+    pub fn show_for_touch(&mut self, x: f32, y: f32) {
+        info!("👆 Context menu opened by long-press");
+        self.show_at(x, y);
+    }
+
+    /// Show from the keyboard, anchored under the focused element's
+    /// bounds (x, y, width, height), e.g. `FocusManager::focused_bounds`
+    pub fn show_for_element(&mut self, bounds: (f32, f32, f32, f32)) {
+        let (x, y, _, height) = bounds;
+        info!("⌨️ Context menu opened from the keyboard");
+        self.show_at(x, y + height);
+    }
+
+    /// A finger touched the menu's target
+    pub fn touch_start(&mut self, x: f32, y: f32) {
+        self.long_press.press(x, y);
+    }
+
+    /// The finger moved (scrolling cancels the long-press)
+    pub fn touch_move(&mut self, x: f32, y: f32) {
+        self.long_press.move_to(x, y);
+    }
+
+    /// The finger lifted; true if it opened the menu (don't treat it as a tap)
+    pub fn touch_end(&mut self) -> bool {
+        self.long_press.release()
+    }
+
+    /// Call each frame while touching; opens the menu once the press is long enough
+    pub fn update(&mut self) -> bool {
+        self.update_at(Instant::now())
+    }
+
+    /// `update` at a given time
+    pub fn update_at(&mut self, now: Instant) -> bool {
+        match self.long_press.poll_at(now) {
+            Some((x, y)) => {
+                self.show_for_touch(x, y);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Hide the menu
     pub fn hide(&mut self) {
         self.is_visible.set(false);
@@ -514,6 +664,34 @@ mod tests {
         assert!(*closed.lock().unwrap());
     }
 
+    #[test]
+    fn context_menu_opens_on_long_press() {
+        let mut menu = ContextMenu::new().add_item("Copy", "copy");
+        let start = Instant::now();
+        menu.long_press.press_at(40.0, 60.0, start);
+        assert!(!menu.update_at(start + Duration::from_millis(200)));
+        assert!(menu.update_at(start + Duration::from_millis(600)));
+        assert_eq!(menu.get_position(), (40.0, 60.0));
+        assert!(!menu.update_at(start + Duration::from_millis(700)));
+        assert!(menu.touch_end());
+
+        // Dragging past the slop is a scroll, not a long-press
+        menu.hide();
+        menu.long_press.press_at(40.0, 60.0, start);
+        menu.touch_move(40.0, 90.0);
+        assert!(!menu.update_at(start + Duration::from_secs(1)));
+        assert!(!menu.touch_end());
+        assert!(!menu.is_visible());
+    }
+
+    #[test]
+    fn context_menu_anchors_under_focused_element() {
+        let mut menu = ContextMenu::new();
+        menu.show_for_element((20.0, 100.0, 160.0, 32.0));
+        assert!(menu.is_visible());
+        assert_eq!(menu.get_position(), (20.0, 132.0));
+    }
+
     #[test]
     fn context_menu_build_creates_node() {
         let mut engine = LayoutEngine::new();
@@ -1,9 +1,21 @@
 // DataGrid Component - Advanced data grid with filtering and sorting
 // Essential for complex data display and manipulation
 
+use nebula_core::command::{Command, UndoManager};
+use nebula_core::events::EventQueue;
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use tracing::info;
 use super::table::{TableColumn, TableRow, ColumnAlign, SortDirection};
+use super::column_autosize::{AutosizeJob, ColumnAutosizer};
+use super::context_menu::ContextMenu;
+use super::events::TableEvent;
+use super::grid_edit::{parse_clipboard_table, CellChange, CellPatch, CellRange, ColumnEditor, GridEditCommand, PasteReport, PendingPatches, RejectedCell};
+use super::locale::Locale;
+use super::header_menu::{display_columns, group_rows, header_menu, HeaderAction, HeaderActionCallback, HeaderMenuState};
+use super::row_expansion::{ExpandableRows, ExpansionMode};
+use super::scroll_memory::{ScrollKey, ScrollPosition};
 
 /// Filter operator
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -70,6 +82,10 @@ pub struct DataGrid {
     pub sort_column: Signal<Option<String>>,
     pub sort_direction: Signal<SortDirection>,
     pub filters: Signal<Vec<ColumnFilter>>,
+    /// Column whose filter editor is open (from the header menu)
+    pub filter_editor: Signal<Option<String>>,
+    /// Column the rows are grouped by
+    pub group_by: Signal<Option<String>>,
     pub page: Signal<usize>,
     pub page_size: usize,
     pub row_height: f32,
@@ -77,21 +93,45 @@ pub struct DataGrid {
     pub padding: f32,
     pub filterable: bool,
     pub paginated: bool,
-    pub background_color: (u8, u8, u8, u8),
-    pub header_color: (u8, u8, u8, u8),
-    pub row_color: (u8, u8, u8, u8),
-    pub alt_row_color: (u8, u8, u8, u8),
-    pub selected_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub header_color: ThemeColor,
+    pub row_color: ThemeColor,
+    pub alt_row_color: ThemeColor,
+    pub selected_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub on_row_click: Option<Box<dyn Fn(&str)>>,
     pub on_sort: Option<Box<dyn Fn(&str, SortDirection)>>,
     pub on_filter: Option<Box<dyn Fn(&[ColumnFilter])>>,
     pub on_page_change: Option<Box<dyn Fn(usize)>>,
+    /// Called for every header menu action (after the grid has applied it)
+    pub on_header_action: Option<HeaderActionCallback>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<TableEvent>>,
+    /// Fits columns to content (header menu, resize-handle double-click)
+    pub autosizer: ColumnAutosizer,
+    /// Cells can be changed (by paste)
+    pub editable: bool,
+    /// A paste running past the last row appends rows
+    pub paste_adds_rows: bool,
+    /// Per-column editors (column id, editor); columns without one accept anything
+    pub editors: Vec<(String, ColumnEditor)>,
+    /// Reads numbers for editor rules
+    pub locale: Locale,
+    /// Selected cells (rows are positions in `filtered_rows`, columns in `display_columns()`)
+    pub selected_cells: Signal<Option<CellRange>>,
+    /// Undo history for edits (share the app's to get one Edit > Undo)
+    pub history: UndoManager,
+    pending_edits: PendingPatches,
+    /// Expandable rows: state, detail builder and callback
+    pub expansion: ExpandableRows,
+    /// Page restoration across navigation
+    pub scroll_memory: ScrollKey,
 }
 
 impl DataGrid {
-    /// Create a new DataGrid component
+    /// Create a new DataGrid component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             columns: Vec::new(),
@@ -101,6 +141,8 @@ impl DataGrid {
             sort_column: Signal::new(None),
             sort_direction: Signal::new(SortDirection::Ascending),
             filters: Signal::new(Vec::new()),
+            filter_editor: Signal::new(None),
+            group_by: Signal::new(None),
             page: Signal::new(0),
             page_size: 10,
             row_height: 48.0,
@@ -108,19 +150,48 @@ impl DataGrid {
             padding: 16.0,
             filterable: true,
             paginated: true,
-            background_color: (255, 255, 255, 255),
-            header_color: (250, 250, 250, 255),
-            row_color: (255, 255, 255, 255),
-            alt_row_color: (249, 249, 249, 255),
-            selected_color: (59, 130, 246, 20),
-            text_color: (0, 0, 0, 255),
+            background_color: colors.surface,
+            header_color: colors.surface_variant,
+            row_color: colors.surface,
+            alt_row_color: colors.surface_alt,
+            selected_color: colors.selection,
+            text_color: colors.text,
             on_row_click: None,
             on_sort: None,
             on_filter: None,
             on_page_change: None,
+            on_header_action: None,
+            events: None,
+            autosizer: ColumnAutosizer::new(),
+            editable: false,
+            paste_adds_rows: false,
+            editors: Vec::new(),
+            locale: Locale::en_us(),
+            selected_cells: Signal::new(None),
+            history: UndoManager::new(),
+            pending_edits: PendingPatches::default(),
+            expansion: ExpandableRows::default(),
+            scroll_memory: ScrollKey::default(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.header_color = colors.surface_variant;
+        self.row_color = colors.surface;
+        self.alt_row_color = colors.surface_alt;
+        self.selected_color = colors.selection;
+        self.text_color = colors.text;
+    }
+
     /// Enable or disable filtering
     pub fn filterable(mut self, filterable: bool) -> Self {
         self.filterable = filterable;
@@ -139,6 +210,36 @@ impl DataGrid {
         self
     }
 
+    /// Allow editing cells
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Let a paste append rows past the last one
+    pub fn paste_adds_rows(mut self, adds_rows: bool) -> Self {
+        self.paste_adds_rows = adds_rows;
+        self
+    }
+
+    /// Validate a column's edits
+    pub fn editor(mut self, column_id: impl Into<String>, editor: ColumnEditor) -> Self {
+        self.editors.push((column_id.into(), editor));
+        self
+    }
+
+    /// Locale for reading numbers in edits
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Record edits in a shared undo history
+    pub fn history(mut self, history: UndoManager) -> Self {
+        self.history = history;
+        self
+    }
+
     /// Set row height
     pub fn row_height(mut self, height: f32) -> Self {
         self.row_height = height;
@@ -217,6 +318,114 @@ impl DataGrid {
         self
     }
 
+    /// Set the header menu callback (for app-specific follow-ups like saving column state)
+    pub fn on_header_action<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, HeaderAction) + 'static,
+    {
+        self.on_header_action = Some(Box::new(callback));
+        self
+    }
+
+    /// Emit typed events into a queue (alongside any callbacks)
+    pub fn events(mut self, events: &EventQueue<TableEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    /// Remember the page under this id on the current route
+    /// (restored when the screen is built again after navigating back)
+    pub fn scroll_id(mut self, id: impl Into<String>) -> Self {
+        self.scroll_memory.id = Some(id.into());
+        self
+    }
+
+    /// Opt this grid out of page restoration
+    pub fn remember_scroll(mut self, remember: bool) -> Self {
+        self.scroll_memory.enabled = remember;
+        self
+    }
+
+    fn emit(&self, event: TableEvent) {
+        if let Some(ref events) = self.events {
+            events.emit(event);
+        }
+    }
+
+    /// Make rows expandable with an expander column
+    pub fn expandable(mut self, mode: ExpansionMode) -> Self {
+        self.expansion.enable(mode);
+        self
+    }
+
+    /// Set the component tree shown under an expanded row
+    pub fn detail<F>(mut self, builder: F) -> Self
+    where
+        F: Fn(&TableRow, &mut LayoutEngine) -> Result<NodeId, String> + 'static,
+    {
+        self.expansion.set_detail(Box::new(builder));
+        self
+    }
+
+    /// Set the expand/collapse callback
+    pub fn on_expand<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, bool) + 'static,
+    {
+        self.expansion.on_expand = Some(Box::new(callback));
+        self
+    }
+
+    /// Toggle a row's detail panel (expander column click)
+    pub fn toggle_expanded(&mut self, id: &str) {
+        if self.rows.iter().all(|r| r.id != id || r.disabled) {
+            return;
+        }
+        if let Some(expanded) = self.expansion.toggle(id) {
+            self.emit(TableEvent::RowExpanded { id: id.to_string(), expanded });
+        }
+    }
+
+    /// Check if a row is expanded
+    pub fn is_row_expanded(&self, id: &str) -> bool {
+        self.expansion.is_expanded(id)
+    }
+
+    /// Advance expand/collapse animations
+    /// Returns true while a panel is still moving
+    pub fn update_expansion(&mut self, dt: f32) -> bool {
+        self.expansion.update(dt)
+    }
+
+    /// Size detail panels to their content (call after `compute_layout`)
+    /// Returns true if a panel's height changed; build and lay out again then
+    pub fn measure_details(&mut self, engine: &mut LayoutEngine) -> Result<bool, String> {
+        self.expansion.measure_details(engine)
+    }
+
+    /// Row indices shown on the current page (after filtering)
+    pub fn page_rows(&self) -> Vec<usize> {
+        if !self.paginated || self.page_size == 0 {
+            return self.filtered_rows.get();
+        }
+        let start = self.page.get() * self.page_size;
+        self.filtered_rows.with(|filtered| filtered.iter().skip(start).take(self.page_size).copied().collect())
+    }
+
+    /// Height of the current page's rows and open detail panels
+    pub fn content_height(&self) -> f32 {
+        let rows = self.page_rows();
+        let ids: Vec<&str> = rows.iter().map(|&i| self.rows[i].id.as_str()).collect();
+        self.expansion.total_height(&ids, self.row_height)
+    }
+
+    /// Positions within `page_rows()` intersecting the viewport
+    pub fn visible_rows(&self, scroll_y: f32, viewport_height: f32) -> std::ops::Range<usize> {
+        let rows = self.page_rows();
+        let ids: Vec<&str> = rows.iter().map(|&i| self.rows[i].id.as_str()).collect();
+        self.expansion.visible_rows(&ids, self.row_height, scroll_y, viewport_height)
+    }
+
     /// Add a filter
     pub fn add_filter(&mut self, filter: ColumnFilter) {
         if !self.filterable {
@@ -231,6 +440,7 @@ impl DataGrid {
         if let Some(ref callback) = self.on_filter {
             callback(&filters);
         }
+        self.emit(TableEvent::FiltersChanged(filters));
     }
 
     /// Remove a filter by column ID
@@ -243,6 +453,7 @@ impl DataGrid {
         if let Some(ref callback) = self.on_filter {
             callback(&filters);
         }
+        self.emit(TableEvent::FiltersChanged(filters));
     }
 
     /// Clear all filters
@@ -253,6 +464,7 @@ impl DataGrid {
         if let Some(ref callback) = self.on_filter {
             callback(&[]);
         }
+        self.emit(TableEvent::FiltersChanged(Vec::new()));
     }
 
     /// Apply filters to rows
@@ -292,7 +504,7 @@ impl DataGrid {
 
     /// Get filtered row count
     pub fn filtered_row_count(&self) -> usize {
-        self.filtered_rows.get().len()
+        self.filtered_rows.with(Vec::len)
     }
 
     /// Get total row count
@@ -313,6 +525,14 @@ impl DataGrid {
         (self.filtered_row_count() + self.page_size - 1) / self.page_size
     }
 
+    /// Go back to the remembered page (once, after rows are set; `build` calls this)
+    pub fn restore_scroll(&mut self) {
+        if let Some(position) = self.scroll_memory.take_restore() {
+            let max_page = self.total_pages().saturating_sub(1);
+            self.page.set(position.page.min(max_page));
+        }
+    }
+
     /// Go to page
     pub fn go_to_page(&mut self, page: usize) {
         if !self.paginated {
@@ -322,10 +542,12 @@ impl DataGrid {
         let max_page = self.total_pages().saturating_sub(1);
         let new_page = page.min(max_page);
         self.page.set(new_page);
+        self.scroll_memory.save(ScrollPosition { offset: (0.0, 0.0), page: new_page });
 
         if let Some(ref callback) = self.on_page_change {
             callback(new_page);
         }
+        self.emit(TableEvent::PageChanged(new_page));
     }
 
     /// Go to next page
@@ -364,7 +586,265 @@ impl DataGrid {
             if let Some(ref callback) = self.on_sort {
                 callback(column_id, direction);
             }
+            self.emit(TableEvent::SortChanged { column: column_id.to_string(), direction });
+        }
+    }
+
+    /// Sort by column in a given direction
+    pub fn sort(&mut self, column_id: &str, direction: SortDirection) {
+        if !self.columns.iter().any(|c| c.id == column_id && c.sortable) {
+            return;
+        }
+        self.sort_column.set(Some(column_id.to_string()));
+        self.sort_direction.set(direction);
+
+        if let Some(ref callback) = self.on_sort {
+            callback(column_id, direction);
         }
+        self.emit(TableEvent::SortChanged { column: column_id.to_string(), direction });
+    }
+
+    /// Remove the sort
+    pub fn clear_sort(&mut self) {
+        self.sort_column.set(None);
+        self.sort_direction.set(SortDirection::Ascending);
+    }
+
+    /// Header context menu for a column (show it on right-click or the menu key)
+    pub fn header_menu(&self, column_id: &str) -> Option<ContextMenu> {
+        let column = self.columns.iter().find(|c| c.id == column_id)?;
+        let sorted = self.sort_column.get().as_deref() == Some(column_id);
+        let state = HeaderMenuState {
+            sort: sorted.then(|| self.sort_direction.get()),
+            filterable: self.filterable,
+            grouped: self.group_by.get().as_deref() == Some(column_id),
+        };
+        Some(header_menu(column, state))
+    }
+
+    /// Apply a header menu action string; returns false if it isn't one
+    /// (route `ContextMenu::on_select` here)
+    pub fn handle_header_action(&mut self, action: &str) -> bool {
+        let Some((action, column_id)) = HeaderAction::parse(action) else {
+            return false;
+        };
+        let Some(index) = self.columns.iter().position(|c| c.id == column_id) else {
+            return false;
+        };
+        match action {
+            HeaderAction::SortAscending => self.sort(&column_id, SortDirection::Ascending),
+            HeaderAction::SortDescending => self.sort(&column_id, SortDirection::Descending),
+            HeaderAction::ClearSort => self.clear_sort(),
+            HeaderAction::Filter => self.filter_editor.set(Some(column_id.clone())),
+            HeaderAction::Pin => self.columns[index].pinned = true,
+            HeaderAction::Unpin => self.columns[index].pinned = false,
+            HeaderAction::Hide => self.columns[index].hidden = true,
+            HeaderAction::GroupBy => self.group_by.set(Some(column_id.clone())),
+            HeaderAction::Ungroup => self.group_by.set(None),
+            HeaderAction::Autosize => {
+                self.autosize_column(&column_id);
+            }
+        }
+        if let Some(ref callback) = self.on_header_action {
+            callback(&column_id, action);
+        }
+        self.emit(TableEvent::HeaderAction { column: column_id, action });
+        true
+    }
+
+    /// Filter the column whose editor is open and close the editor
+    /// (replaces any filter already on that column)
+    pub fn apply_filter_editor(&mut self, operator: FilterOperator, value: impl Into<String>) {
+        let Some(column_id) = self.filter_editor.get() else {
+            return;
+        };
+        self.filter_editor.set(None);
+        let mut filters = self.filters.get();
+        filters.retain(|f| f.column_id != column_id);
+        self.filters.set(filters);
+        self.add_filter(ColumnFilter::new(column_id, operator, value));
+    }
+
+    /// Close the filter editor without filtering
+    pub fn close_filter_editor(&mut self) {
+        self.filter_editor.set(None);
+    }
+
+    /// Fit a column to its header and the rows on the current page (capped at `autosizer.max_width`)
+    pub fn autosize_column(&mut self, column_id: &str) -> Option<f32> {
+        let index = self.columns.iter().position(|c| c.id == column_id && c.resizable)?;
+        let rows = self.page_rows();
+        let cells = rows.iter().filter_map(|&i| self.rows[i].cells.get(index)).map(String::as_str);
+        let width = self.autosizer.fit(&self.columns[index].label, cells, self.padding);
+        self.columns[index].width = Some(width);
+        Some(width)
+    }
+
+    /// Measure every row of a column in the background; pass the job to `apply_autosize`
+    pub fn autosize_column_async(&self, column_id: &str) -> Option<AutosizeJob> {
+        let index = self.columns.iter().position(|c| c.id == column_id && c.resizable)?;
+        let cells = self.rows.iter().filter_map(|row| row.cells.get(index).cloned()).collect();
+        Some(self.autosizer.fit_async(self.columns[index].label.clone(), cells, self.padding))
+    }
+
+    /// Use a background measurement once it's done; returns true when applied
+    pub fn apply_autosize(&mut self, column_id: &str, job: &AutosizeJob) -> bool {
+        let (Some(width), Some(column)) = (job.width(), self.columns.iter_mut().find(|c| c.id == column_id)) else {
+            return false;
+        };
+        column.width = Some(width);
+        true
+    }
+
+    /// Mouse down on a column's header resize handle; a double-click autosizes it
+    pub fn press_resize_handle(&mut self, column_id: &str, x: f32, y: f32) -> bool {
+        self.autosizer.press_resize_handle(column_id, x, y) && self.autosize_column(column_id).is_some()
+    }
+
+    /// Show a hidden column again
+    pub fn show_column(&mut self, column_id: &str) {
+        if let Some(column) = self.columns.iter_mut().find(|c| c.id == column_id) {
+            column.hidden = false;
+        }
+    }
+
+    /// Column indices in display order (visible only, pinned first)
+    pub fn display_columns(&self) -> Vec<usize> {
+        display_columns(&self.columns)
+    }
+
+    /// Filtered rows grouped by the `group_by` column (None when not grouped)
+    pub fn grouped_rows(&self) -> Option<Vec<(String, Vec<usize>)>> {
+        let column_id = self.group_by.get()?;
+        let index = self.columns.iter().position(|c| c.id == column_id)?;
+        Some(group_rows(&self.rows, &self.filtered_rows.get_ref(), index))
+    }
+
+    /// Select a range of cells (the paste target)
+    pub fn select_cells(&mut self, range: CellRange) {
+        self.selected_cells.set(Some(range));
+    }
+
+    /// Paste TSV/CSV text (from the clipboard) into the selected cells
+    ///
+    /// A block pastes from the selection's top-left corner; a selection
+    /// that is a whole multiple of the block (one cell into a column, say)
+    /// is filled by repeating it. Every cell goes through its column editor
+    /// and the whole paste is one undo step.
+    pub fn paste_text(&mut self, text: &str) -> Result<PasteReport, String> {
+        if !self.editable {
+            return Err("This grid isn't editable".to_string());
+        }
+        let block = parse_clipboard_table(text);
+        let block_height = block.len();
+        let block_width = block.iter().map(Vec::len).max().unwrap_or(0);
+        if block_height == 0 || block_width == 0 {
+            return Err("Nothing to paste".to_string());
+        }
+        let range = self.selected_cells.get().ok_or("Select a cell to paste into")?;
+        self.apply_pending_edits();
+        self.apply_filters();
+
+        let tiles = range.height() % block_height == 0 && range.width() % block_width == 0;
+        let (height, width) = if tiles { (range.height(), range.width()) } else { (block_height, block_width) };
+        let visible = self.filtered_rows.get();
+        let display = self.display_columns();
+        let mut report = PasteReport::default();
+        let mut patch = CellPatch::default();
+
+        for r in 0..height {
+            let position = range.start.0 + r;
+            let row_id = match visible.get(position) {
+                Some(&index) => self.rows[index].id.clone(),
+                None if self.paste_adds_rows => {
+                    let row = TableRow::new(self.new_row_id(&patch), vec![String::new(); self.columns.len()]);
+                    let id = row.id.clone();
+                    patch.added_rows.push(row);
+                    id
+                }
+                None => {
+                    report.clipped += width;
+                    continue;
+                }
+            };
+            for c in 0..width {
+                let Some(&column) = display.get(range.start.1 + c) else {
+                    report.clipped += 1;
+                    continue;
+                };
+                let value = block[r % block_height].get(c % block_width).cloned().unwrap_or_default();
+                let column_id = &self.columns[column].id;
+                if let Some((_, editor)) = self.editors.iter().find(|(id, _)| id == column_id) {
+                    if let Err(error) = editor.validate(&value, &self.locale) {
+                        report.rejected.push(RejectedCell { row_id: row_id.clone(), column_id: column_id.clone(), value, error });
+                        continue;
+                    }
+                }
+                let before = self
+                    .rows
+                    .iter()
+                    .chain(&patch.added_rows)
+                    .find(|row| row.id == row_id)
+                    .and_then(|row| row.cells.get(column).cloned())
+                    .unwrap_or_default();
+                if before != value {
+                    patch.changes.push(CellChange { row_id: row_id.clone(), column, before, after: value });
+                }
+            }
+        }
+
+        report.changed = patch.changes.len();
+        report.added_rows = patch.added_rows.len();
+        if !patch.is_empty() {
+            let label = format!("Paste {} cell{}", report.changed, if report.changed == 1 { "" } else { "s" });
+            let mut command = GridEditCommand::new(label, patch, self.pending_edits.clone());
+            command.execute()?;
+            self.history.record(Box::new(command));
+            self.apply_pending_edits();
+        }
+        self.selected_cells.set(Some(CellRange::new(range.start, (range.start.0 + height - 1, range.start.1 + width - 1))));
+        info!("📋 Pasted {} cells ({} rejected, {} rows added)", report.changed, report.rejected.len(), report.added_rows);
+        Ok(report)
+    }
+
+    /// Apply edits queued by the undo history (undo / redo of pastes);
+    /// `build` calls this too. Returns true if rows changed
+    pub fn apply_pending_edits(&mut self) -> bool {
+        let pending: Vec<(CellPatch, bool)> = self.pending_edits.borrow_mut().drain(..).collect();
+        if pending.is_empty() {
+            return false;
+        }
+        for (patch, forward) in pending {
+            if forward {
+                patch.apply(&mut self.rows);
+            } else {
+                patch.revert(&mut self.rows);
+            }
+        }
+        self.apply_filters();
+        true
+    }
+
+    /// Undo the last edit
+    pub fn undo(&mut self) -> bool {
+        let undone = self.history.undo();
+        self.apply_pending_edits();
+        undone
+    }
+
+    /// Redo the last undone edit
+    pub fn redo(&mut self) -> bool {
+        let redone = self.history.redo();
+        self.apply_pending_edits();
+        redone
+    }
+
+    fn new_row_id(&self, patch: &CellPatch) -> String {
+        let taken = |id: &str| self.rows.iter().chain(&patch.added_rows).any(|row| row.id == id);
+        (self.rows.len() + patch.added_rows.len() + 1..)
+            .map(|n| format!("row{}", n))
+            .find(|id| !taken(id))
+            .unwrap_or_default()
     }
 
     /// Get active filters
@@ -379,11 +859,14 @@ impl DataGrid {
 
     /// Build the data grid layout
     pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        self.apply_pending_edits();
+
         // Initialize filtered rows if empty
-        if self.filtered_rows.get().is_empty() && !self.rows.is_empty() {
+        if self.filtered_rows.with(Vec::is_empty) && !self.rows.is_empty() {
             let all_indices: Vec<usize> = (0..self.rows.len()).collect();
             self.filtered_rows.set(all_indices);
         }
+        self.restore_scroll();
 
         let style = taffy::style::Style {
             size: taffy::geometry::Size {
@@ -395,9 +878,14 @@ impl DataGrid {
             ..Default::default()
         };
 
-        let node = engine
-            .new_leaf(style)
-            .map_err(|e| format!("Failed to create data grid node: {:?}", e))?;
+        let page_rows = self.page_rows();
+        let rows: Vec<&TableRow> = page_rows.iter().map(|&i| &self.rows[i]).collect();
+        self.expansion.begin_build();
+        let node = match self.expansion.build_rows(engine, &rows, self.row_height) {
+            Some(children) => engine.new_with_children(style, &children?),
+            None => engine.new_leaf(style),
+        }
+        .map_err(|e| format!("Failed to create data grid node: {:?}", e))?;
         self.node_id = Some(node);
 
         Ok(node)
@@ -413,6 +901,7 @@ impl Default for DataGrid {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validation::ValidationError;
 
     #[test]
     fn datagrid_starts_empty() {
@@ -665,4 +1154,105 @@ mod tests {
         assert!(result.is_ok());
         assert!(grid.node_id.is_some());
     }
+
+    #[test]
+    fn datagrid_expandable_rows_on_page() {
+        let mut engine = LayoutEngine::new();
+        let mut grid = DataGrid::new()
+            .expandable(ExpansionMode::Multiple)
+            .page_size(2)
+            .add_row("row1", vec!["A".to_string()])
+            .add_row("row2", vec!["B".to_string()])
+            .add_row("row3", vec!["C".to_string()])
+            .detail(|_, engine| {
+                engine
+                    .new_leaf(taffy::style::Style::default())
+                    .map_err(|e| format!("{:?}", e))
+            });
+        grid.build(&mut engine).unwrap();
+        assert_eq!(grid.page_rows(), vec![0, 1]);
+
+        grid.toggle_expanded("row1");
+        grid.toggle_expanded("row2");
+        assert!(grid.is_row_expanded("row1") && grid.is_row_expanded("row2"));
+        while grid.update_expansion(1.0 / 60.0) {}
+        assert_eq!(grid.content_height(), 2.0 * 48.0 + 2.0 * 120.0);
+
+        grid.next_page();
+        assert_eq!(grid.page_rows(), vec![2]);
+        assert_eq!(grid.content_height(), 48.0);
+    }
+
+    #[test]
+    fn datagrid_header_menu_actions() {
+        let mut grid = DataGrid::new()
+            .add_column("name", "Name")
+            .add_column("team", "Team")
+            .add_row("row1", vec!["Alice".to_string(), "Core".to_string()])
+            .add_row("row2", vec!["Bob".to_string(), "Web".to_string()])
+            .add_row("row3", vec!["Carol".to_string(), "Core".to_string()]);
+        grid.build(&mut LayoutEngine::new()).unwrap();
+
+        let mut menu = grid.header_menu("name").unwrap();
+        assert!(menu.items.iter().any(|item| item.label == "Filter…"));
+        let selected = std::rc::Rc::new(std::cell::RefCell::new(String::new()));
+        let sink = selected.clone();
+        menu = menu.on_select(move |action| *sink.borrow_mut() = action.to_string());
+        menu.select_by_action(&HeaderAction::SortDescending.action_for("name"));
+        assert!(grid.handle_header_action(&selected.borrow()));
+        assert_eq!(grid.sort_column.get().as_deref(), Some("name"));
+        assert_eq!(grid.sort_direction.get(), SortDirection::Descending);
+
+        grid.handle_header_action(&HeaderAction::Filter.action_for("name"));
+        grid.apply_filter_editor(FilterOperator::Contains, "o");
+        assert_eq!(grid.filtered_row_count(), 2);
+        assert_eq!(grid.filter_editor.get(), None);
+
+        grid.handle_header_action(&HeaderAction::GroupBy.action_for("team"));
+        let groups = grid.grouped_rows().unwrap();
+        assert_eq!(groups, vec![("Web".to_string(), vec![1]), ("Core".to_string(), vec![2])]);
+
+        grid.handle_header_action(&HeaderAction::Pin.action_for("team"));
+        grid.handle_header_action(&HeaderAction::Hide.action_for("name"));
+        assert_eq!(grid.display_columns(), vec![1]);
+        assert!(!grid.handle_header_action("copy"));
+    }
+
+    #[test]
+    fn datagrid_paste_is_validated_and_undoable() {
+        use crate::validation::Rule;
+        let mut grid = DataGrid::new()
+            .add_column("name", "Name")
+            .add_column("age", "Age")
+            .add_row("row1", vec!["Alice".to_string(), "30".to_string()])
+            .editable(true)
+            .paste_adds_rows(true)
+            .editor("age", ColumnEditor::new(vec![Rule::range(0.0, 150.0)]));
+        grid.build(&mut LayoutEngine::new()).unwrap();
+
+        assert!(grid.paste_text("x").is_err());
+        grid.select_cells(CellRange::cell(0, 0));
+        let report = grid.paste_text("Ann\t31\nBob\tabc\nCy\t40\tignored\n").unwrap();
+        assert_eq!(report.changed, 5);
+        assert_eq!(report.added_rows, 2);
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].error, ValidationError::NotANumber);
+        assert_eq!(report.clipped, 3);
+        assert_eq!(grid.rows[0].cells, vec!["Ann".to_string(), "31".to_string()]);
+        assert_eq!(grid.rows[2].cells, vec!["Cy".to_string(), "40".to_string()]);
+        assert_eq!(grid.filtered_row_count(), 3);
+
+        // One cell fills a selected column
+        grid.select_cells(CellRange::new((0, 1), (2, 1)));
+        grid.paste_text("18").unwrap();
+        assert!(grid.rows.iter().all(|row| row.cells[1] == "18"));
+
+        assert!(grid.undo());
+        assert_eq!(grid.rows[1].cells[1], "");
+        assert!(grid.undo());
+        assert_eq!(grid.rows.len(), 1);
+        assert_eq!(grid.rows[0].cells, vec!["Alice".to_string(), "30".to_string()]);
+        assert!(grid.redo());
+        assert_eq!(grid.rows.len(), 3);
+    }
 }
@@ -1,8 +1,14 @@
 // DatePicker Component - Date selection component
 // Essential for date input in forms
 
+use nebula_core::events::EventQueue;
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use crate::calendar::{CalendarDate, DateClock};
+use crate::events::PickerEvent;
+use crate::timepicker::{DateTime, TimePicker};
+use crate::locale::{DateFormat, Locale};
 
 /// Simple date representation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,9 +24,10 @@ impl Date {
         Self { year, month, day }
     }
 
-    /// Get today's date (placeholder - would use system time in real impl)
+    /// Get today's date (from `DateClock`)
     pub fn today() -> Self {
-        Self::new(2025, 11, 22)
+        let today = DateClock::today();
+        Self::new(today.year, today.month, today.day)
     }
 
     /// Format as YYYY-MM-DD
@@ -71,16 +78,24 @@ pub struct DatePicker {
     pub height: f32,
     pub calendar_width: f32,
     pub calendar_height: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub selected_color: (u8, u8, u8, u8),
-    pub today_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub selected_color: ThemeColor,
+    pub today_color: ThemeColor,
+    pub disabled_color: ThemeColor,
+    pub locale: Locale,
+    /// How the selected date is shown
+    pub date_format: DateFormat,
+    /// Time of day next to the date (see `with_time`)
+    pub time_picker: Option<TimePicker>,
     pub on_change: Option<Box<dyn Fn(Date)>>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<PickerEvent>>,
 }
 
 impl DatePicker {
-    /// Create a new DatePicker component
+    /// Create a new DatePicker component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             selected_date: Signal::new(None),
@@ -92,14 +107,33 @@ impl DatePicker {
             height: 40.0,
             calendar_width: 280.0,
             calendar_height: 320.0,
-            background_color: (255, 255, 255, 255),
-            selected_color: (59, 130, 246, 255), // Blue
-            today_color: (220, 220, 220, 255),
-            disabled_color: (200, 200, 200, 255),
+            background_color: colors.surface,
+            selected_color: colors.primary,
+            today_color: colors.border,
+            disabled_color: colors.disabled,
+            locale: Locale::default(),
+            date_format: DateFormat::Short,
+            time_picker: None,
             on_change: None,
+            events: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.selected_color = colors.primary;
+        self.today_color = colors.border;
+        self.disabled_color = colors.disabled;
+    }
+
     /// Set the selected date
     pub fn selected_date(self, date: Date) -> Self {
         self.selected_date.set(Some(date));
@@ -136,6 +170,31 @@ impl DatePicker {
         self
     }
 
+    /// Set the locale used to parse and display typed dates
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Set how the selected date is shown (typed input is parsed the same either way)
+    pub fn date_format(mut self, format: DateFormat) -> Self {
+        self.date_format = format;
+        self
+    }
+
+    /// Pick a time of day as well as a date
+    pub fn with_time(mut self, picker: TimePicker) -> Self {
+        self.time_picker = Some(picker);
+        self
+    }
+
+    /// Selected date and time (None until both are picked)
+    pub fn selected_datetime(&self) -> Option<DateTime> {
+        let date = self.get_selected_date()?;
+        let time = self.time_picker.as_ref()?.get_selected_time()?;
+        Some(DateTime::new(CalendarDate::new(date.year, date.month, date.day), time))
+    }
+
     /// Set the change callback
     pub fn on_change<F>(mut self, callback: F) -> Self
     where
@@ -145,6 +204,12 @@ impl DatePicker {
         self
     }
 
+    /// Emit `PickerEvent::DateChanged` into a queue (alongside `on_change`)
+    pub fn events(mut self, events: &EventQueue<PickerEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
     /// Select a date
     pub fn select_date(&mut self, date: Date) {
         if !self.disabled && date.is_valid() && self.is_date_selectable(&date) {
@@ -152,6 +217,41 @@ impl DatePicker {
             if let Some(ref callback) = self.on_change {
                 callback(date);
             }
+            if let Some(ref events) = self.events {
+                events.emit(PickerEvent::DateChanged(date));
+            }
+        }
+    }
+
+    /// Select a date from text typed into the field
+    /// Parsed with the picker's locale (e.g. "22.11.2025" in de-DE, "Nov 22, 2025" in en-US)
+    pub fn select_from_input(&mut self, input: &str) -> Result<Date, String> {
+        if self.disabled {
+            return Err("DatePicker is disabled".to_string());
+        }
+
+        let date = self.locale.parse_date(input)
+            .ok_or_else(|| format!("Could not parse date '{}' (expected {})", input, self.placeholder()))?;
+
+        if !self.is_date_selectable(&date) {
+            return Err(format!("Date {} is out of range", date.format()));
+        }
+
+        self.select_date(date);
+        Ok(date)
+    }
+
+    /// Placeholder hint for the input field (e.g. "MM/DD/YYYY")
+    pub fn placeholder(&self) -> &'static str {
+        self.locale.date_placeholder
+    }
+
+    /// Selected date formatted for display in the picker's locale
+    pub fn display_text(&self) -> Option<String> {
+        let date = self.locale.format_date_as(&self.get_selected_date()?, &self.date_format);
+        match self.time_picker.as_ref().and_then(TimePicker::display_text) {
+            Some(time) => Some(format!("{} {}", date, time)),
+            None => Some(date),
         }
     }
 
@@ -390,4 +490,42 @@ mod tests {
         assert!(result.is_ok());
         assert!(datepicker.node_id.is_some());
     }
+
+    #[test]
+    fn datepicker_select_from_localized_input() {
+        let mut datepicker = DatePicker::new().locale(Locale::de_de());
+        assert_eq!(datepicker.placeholder(), "TT.MM.JJJJ");
+
+        let date = datepicker.select_from_input("22.11.2025").unwrap();
+        assert_eq!(date, Date::new(2025, 11, 22));
+        assert_eq!(datepicker.get_selected_date(), Some(date));
+        assert_eq!(datepicker.display_text(), Some("22.11.2025".to_string()));
+
+        assert!(datepicker.select_from_input("31.02.2025").is_err());
+    }
+
+    #[test]
+    fn datepicker_select_from_input_respects_range() {
+        let mut datepicker = DatePicker::new().max_date(Date::new(2025, 12, 31));
+        assert!(datepicker.select_from_input("Jan 5, 2026").is_err());
+        assert!(!datepicker.has_selected_date());
+
+        assert!(datepicker.select_from_input("Dec 5, 2025").is_ok());
+        assert_eq!(datepicker.get_selected_date(), Some(Date::new(2025, 12, 5)));
+    }
+
+    #[test]
+    fn datepicker_with_time_shows_both() {
+        use crate::timepicker::Time;
+
+        let mut picker = DatePicker::new()
+            .locale(Locale::en_us())
+            .with_time(TimePicker::new().locale(Locale::en_us()))
+            .selected_date(Date::new(2025, 11, 22));
+        assert_eq!(picker.selected_datetime(), None);
+
+        picker.time_picker.as_mut().unwrap().select_time(Time::new(18, 0, 0));
+        assert_eq!(picker.display_text().as_deref(), Some("11/22/2025 6:00 PM"));
+        assert_eq!(picker.selected_datetime().map(|dt| dt.time), Some(Time::new(18, 0, 0)));
+    }
 }
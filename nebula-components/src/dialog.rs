@@ -4,6 +4,7 @@
 use crate::container::VStack;
 use crate::modal::Modal;
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Dialog type determines the visual style and default buttons
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,9 +42,9 @@ pub struct Dialog {
     pub height: Option<f32>, // None = auto height
     pub padding: f32,
     pub border_radius: f32,
-    pub background_color: (u8, u8, u8, u8), // RGBA
-    pub title_color: (u8, u8, u8, u8),
-    pub message_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub title_color: ThemeColor,
+    pub message_color: ThemeColor,
     pub on_confirm: Option<Box<dyn Fn()>>,
     pub on_cancel: Option<Box<dyn Fn()>>,
     pub on_close: Option<Box<dyn Fn()>>,
@@ -54,8 +55,9 @@ pub struct Dialog {
 }
 
 impl Dialog {
-    /// Create a new Dialog component
+    /// Create a new Dialog component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             modal: Modal::new(),
@@ -66,9 +68,9 @@ impl Dialog {
             height: None,
             padding: 24.0,
             border_radius: 12.0,
-            background_color: (255, 255, 255, 255), // White
-            title_color: (0, 0, 0, 255), // Black
-            message_color: (64, 64, 64, 255), // Dark gray
+            background_color: colors.surface,
+            title_color: colors.text,
+            message_color: colors.text_muted,
             on_confirm: None,
             on_cancel: None,
             on_close: None,
@@ -79,6 +81,20 @@ impl Dialog {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.title_color = colors.text;
+        self.message_color = colors.text_muted;
+    }
+
     /// Set the dialog title
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = title.into();
@@ -251,13 +267,13 @@ impl Dialog {
     }
 
     /// Get the accent color based on dialog type
-    pub fn get_accent_color(&self) -> (u8, u8, u8, u8) {
+    pub fn get_accent_color(&self) -> ThemeColor {
+        let colors = ThemeProvider::current().colors;
         match self.dialog_type {
-            DialogType::Info => (0, 122, 255, 255),      // Blue
-            DialogType::Warning => (255, 149, 0, 255),   // Orange
-            DialogType::Error => (255, 59, 48, 255),     // Red
-            DialogType::Confirm => (52, 199, 89, 255),   // Green
-            DialogType::Custom => (0, 122, 255, 255),    // Blue (default)
+            DialogType::Info | DialogType::Custom => colors.primary,
+            DialogType::Warning => colors.warning,
+            DialogType::Error => colors.danger,
+            DialogType::Confirm => colors.success,
         }
     }
 
@@ -379,17 +395,18 @@ mod tests {
 
     #[test]
     fn dialog_accent_colors() {
+        let colors = ThemeProvider::current().colors;
         let info = Dialog::new().dialog_type(DialogType::Info);
-        assert_eq!(info.get_accent_color(), (0, 122, 255, 255)); // Blue
+        assert_eq!(info.get_accent_color(), colors.primary);
 
         let warning = Dialog::new().dialog_type(DialogType::Warning);
-        assert_eq!(warning.get_accent_color(), (255, 149, 0, 255)); // Orange
+        assert_eq!(warning.get_accent_color(), colors.warning);
 
         let error = Dialog::new().dialog_type(DialogType::Error);
-        assert_eq!(error.get_accent_color(), (255, 59, 48, 255)); // Red
+        assert_eq!(error.get_accent_color(), colors.danger);
 
         let confirm = Dialog::new().dialog_type(DialogType::Confirm);
-        assert_eq!(confirm.get_accent_color(), (52, 199, 89, 255)); // Green
+        assert_eq!(confirm.get_accent_color(), colors.success);
     }
 
     #[test]
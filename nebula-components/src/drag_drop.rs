@@ -0,0 +1,408 @@
+// Drag and Drop - Move things between widgets
+// DragSource hands out a payload, DropTarget takes it, DragController runs the gesture
+
+use std::any::Any;
+use std::rc::Rc;
+
+use nebula_core::input_settings::InputSettings;
+use nebula_core::theme::ThemeProvider;
+use nebula_gfx::draw_list::{DrawList, Rect};
+
+/// What's being dragged
+///
+/// `kind` is the drag group: targets only accept kinds they know, so list
+/// rows never land in a tab strip unless both share a group.
+#[derive(Clone)]
+pub struct DragPayload {
+    pub kind: String,
+    pub id: String,
+    pub label: String,
+    data: Option<Rc<dyn Any>>,
+}
+
+impl DragPayload {
+    /// Payload without attached data
+    pub fn new(kind: impl Into<String>, id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self { kind: kind.into(), id: id.into(), label: label.into(), data: None }
+    }
+
+    /// Attach the dragged value itself (a ListItem, Tab, TreeNode…)
+    pub fn with_data<T: Any>(mut self, data: T) -> Self {
+        self.data = Some(Rc::new(data));
+        self
+    }
+
+    /// Attached value, if it is a `T`
+    pub fn data<T: Any>(&self) -> Option<&T> {
+        self.data.as_ref()?.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragPayload").field("kind", &self.kind).field("id", &self.id).field("label", &self.label).finish()
+    }
+}
+
+/// Ghost drawn under the pointer while dragging
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragImage {
+    pub label: String,
+    pub size: (f32, f32),
+    pub opacity: f32,
+}
+
+impl DragImage {
+    /// Label-sized chip for a payload
+    pub fn from_payload(payload: &DragPayload) -> Self {
+        Self { label: payload.label.clone(), size: (payload.label.chars().count() as f32 * 8.0 + 24.0, 32.0), opacity: 0.85 }
+    }
+
+    /// Set the size
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width, height);
+        self
+    }
+}
+
+/// Where a drop would land, in the target's local coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropIndicator {
+    /// Insertion line across the target at this y (lists, trees)
+    HorizontalLine(f32),
+    /// Insertion line down the target at this x (tab strips)
+    VerticalLine(f32),
+    /// Highlight a row (dropping into a tree node)
+    Row { y: f32, height: f32 },
+    /// Highlight the whole target
+    Zone,
+}
+
+/// How a drag ended, as told to its source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragOutcome {
+    /// Dropped on another target; the source usually removes the item
+    Transferred,
+    /// Dropped back onto its own zone (a reorder)
+    Rearranged,
+    /// Escape, a rejected drop or released over nothing
+    Cancelled,
+}
+
+/// Something items can be dragged out of
+pub trait DragSource {
+    /// Payload for a drag starting at `position` (local), None if nothing draggable is there
+    fn drag_payload(&self, position: (f32, f32)) -> Option<DragPayload>;
+
+    /// Ghost shown under the pointer
+    fn drag_image(&self, payload: &DragPayload) -> DragImage {
+        DragImage::from_payload(payload)
+    }
+
+    /// The drag ended
+    fn drag_finished(&mut self, _payload: &DragPayload, _outcome: DragOutcome) {}
+}
+
+/// Something items can be dropped onto
+pub trait DropTarget {
+    /// Would this target take the payload at all?
+    fn accepts(&self, payload: &DragPayload) -> bool;
+
+    /// Pointer moved over the target (local); None rejects this spot
+    fn drag_over(&mut self, payload: &DragPayload, position: (f32, f32)) -> Option<DropIndicator>;
+
+    /// Pointer left the target (or the drag ended)
+    fn drag_leave(&mut self) {}
+
+    /// Take the payload; false rejects it
+    fn drop_payload(&mut self, payload: &DragPayload, position: (f32, f32)) -> bool;
+}
+
+/// A widget that takes part in drag and drop as a source, a target or both
+pub trait DragZone {
+    fn as_source(&mut self) -> Option<&mut dyn DragSource> {
+        None
+    }
+
+    fn as_target(&mut self) -> Option<&mut dyn DropTarget> {
+        None
+    }
+}
+
+/// Zones with their on-screen bounds, topmost last
+/// Pass them in the same order on every call of a gesture.
+pub type DragZones<'a> = [(Rect, &'a mut dyn DragZone)];
+
+#[derive(Debug)]
+struct ActiveDrag {
+    source: usize,
+    payload: DragPayload,
+    image: DragImage,
+    pointer: (f32, f32),
+    hovered: Option<usize>,
+    /// Hovered zone bounds with the indicator in window coordinates
+    indicator: Option<(Rect, DropIndicator)>,
+}
+
+#[derive(Debug, Default)]
+enum DragState {
+    #[default]
+    Idle,
+    /// Pointer is down on a source, not moved far enough yet
+    Pressed { source: usize, origin: (f32, f32), payload: DragPayload },
+    Dragging(ActiveDrag),
+}
+
+/// DragController - Runs one drag gesture across zones 🫳
+///
+/// # Example
+/// ```rust,ignore
+/// let mut zones: Vec<(Rect, &mut dyn DragZone)> = vec![(inbox_bounds, &mut inbox), (archive_bounds, &mut archive)];
+/// drag.press(&mut zones, pointer);
+/// drag.move_to(&mut zones, pointer);   // hover feedback via drag.indicator()
+/// drag.release(&mut zones);            // or drag.cancel(&mut zones) on Escape
+/// drag.paint(&mut list);
+/// ```
+#[derive(Debug)]
+pub struct DragController {
+    /// Distance the pointer must travel before a press becomes a drag
+    /// (the OS drag threshold from `InputSettings` unless set)
+    pub threshold: f32,
+    state: DragState,
+}
+
+impl DragController {
+    /// Idle controller
+    pub fn new() -> Self {
+        Self { threshold: InputSettings::current().drag_threshold, state: DragState::Idle }
+    }
+
+    /// Set the drag threshold
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Pointer down: arm a drag if a source has something under the pointer
+    pub fn press(&mut self, zones: &mut DragZones<'_>, pointer: (f32, f32)) -> bool {
+        self.state = DragState::Idle;
+        for (index, (bounds, zone)) in zones.iter_mut().enumerate().rev() {
+            if !contains(*bounds, pointer) {
+                continue;
+            }
+            // Topmost zone under the pointer decides
+            let position = local(*bounds, pointer);
+            let Some(payload) = zone.as_source().and_then(|source| source.drag_payload(position)) else {
+                return false;
+            };
+            self.state = DragState::Pressed { source: index, origin: pointer, payload };
+            return true;
+        }
+        false
+    }
+
+    /// Pointer moved: start the drag past the threshold, then track hover
+    /// Returns true while dragging
+    pub fn move_to(&mut self, zones: &mut DragZones<'_>, pointer: (f32, f32)) -> bool {
+        let armed = match &self.state {
+            DragState::Pressed { origin, .. } => {
+                if (pointer.0 - origin.0).hypot(pointer.1 - origin.1) < self.threshold {
+                    return false;
+                }
+                true
+            }
+            _ => false,
+        };
+        let pressed = if armed { std::mem::take(&mut self.state) } else { DragState::Idle };
+        if let DragState::Pressed { source, payload, .. } = pressed {
+            let image = zones
+                .get_mut(source)
+                .and_then(|(_, zone)| zone.as_source().map(|source| source.drag_image(&payload)))
+                .unwrap_or_else(|| DragImage::from_payload(&payload));
+            self.state = DragState::Dragging(ActiveDrag { source, payload, image, pointer, hovered: None, indicator: None });
+        }
+        let DragState::Dragging(drag) = &mut self.state else {
+            return false;
+        };
+        drag.pointer = pointer;
+
+        let mut hovered = None;
+        for (index, (bounds, zone)) in zones.iter_mut().enumerate().rev() {
+            if !contains(*bounds, pointer) {
+                continue;
+            }
+            if let Some(target) = zone.as_target().filter(|target| target.accepts(&drag.payload)) {
+                hovered = target.drag_over(&drag.payload, local(*bounds, pointer)).map(|indicator| (index, indicator));
+            }
+            // Topmost zone under the pointer wins, even if it says no
+            break;
+        }
+
+        let now = hovered.map(|(index, _)| index);
+        if drag.hovered.is_some() && drag.hovered != now {
+            leave(zones, drag.hovered);
+        }
+        drag.hovered = now;
+        drag.indicator = hovered.map(|(index, indicator)| (zones[index].0, to_window(zones[index].0, indicator)));
+        true
+    }
+
+    /// Pointer up: drop on the hovered target, or cancel
+    /// Returns the outcome if a drag was in progress
+    pub fn release(&mut self, zones: &mut DragZones<'_>) -> Option<DragOutcome> {
+        let DragState::Dragging(drag) = std::mem::take(&mut self.state) else {
+            return None;
+        };
+        let dropped = drag.hovered.is_some_and(|index| {
+            let (bounds, zone) = &mut zones[index];
+            let position = local(*bounds, drag.pointer);
+            zone.as_target().is_some_and(|target| {
+                let dropped = target.drop_payload(&drag.payload, position);
+                target.drag_leave();
+                dropped
+            })
+        });
+        let outcome = match drag.hovered {
+            Some(index) if dropped && index == drag.source => DragOutcome::Rearranged,
+            Some(_) if dropped => DragOutcome::Transferred,
+            _ => DragOutcome::Cancelled,
+        };
+        finish(zones, &drag, outcome);
+        Some(outcome)
+    }
+
+    /// Abandon the drag (Escape); the source keeps its item
+    pub fn cancel(&mut self, zones: &mut DragZones<'_>) {
+        if let DragState::Dragging(drag) = std::mem::take(&mut self.state) {
+            leave(zones, drag.hovered);
+            finish(zones, &drag, DragOutcome::Cancelled);
+        }
+    }
+
+    /// Is a drag in progress (past the threshold)?
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.state, DragState::Dragging(_))
+    }
+
+    /// Payload being dragged
+    pub fn payload(&self) -> Option<&DragPayload> {
+        match &self.state {
+            DragState::Dragging(drag) => Some(&drag.payload),
+            _ => None,
+        }
+    }
+
+    /// Zone index under the pointer that would take the drop
+    pub fn hovered(&self) -> Option<usize> {
+        match &self.state {
+            DragState::Dragging(drag) => drag.hovered,
+            _ => None,
+        }
+    }
+
+    /// Drop feedback in window coordinates
+    pub fn indicator(&self) -> Option<DropIndicator> {
+        match &self.state {
+            DragState::Dragging(drag) => drag.indicator.map(|(_, indicator)| indicator),
+            _ => None,
+        }
+    }
+
+    /// Draw the drop indicator and the drag image (call after the zones paint)
+    pub fn paint(&self, list: &mut DrawList) {
+        let DragState::Dragging(drag) = &self.state else {
+            return;
+        };
+        let theme = ThemeProvider::current();
+        let primary = theme.colors.primary;
+        if let Some(((x, y, width, height), indicator)) = drag.indicator {
+            match indicator {
+                DropIndicator::HorizontalLine(line) => list.rect((x, line - 1.0, width, 2.0), primary),
+                DropIndicator::VerticalLine(line) => list.rect((line - 1.0, y, 2.0, height), primary),
+                DropIndicator::Row { y, height } => list.rect((x, y, width, height), (primary.0, primary.1, primary.2, 48)),
+                DropIndicator::Zone => list.rect((x, y, width, height), (primary.0, primary.1, primary.2, 32)),
+            };
+        }
+
+        let (width, height) = drag.image.size;
+        let fade = |color: (u8, u8, u8, u8)| (color.0, color.1, color.2, (color.3 as f32 * drag.image.opacity) as u8);
+        let rect = (drag.pointer.0 - 12.0, drag.pointer.1 - height / 2.0, width, height);
+        list.rounded_rect(rect, theme.radius.md, fade(theme.colors.surface_variant));
+        list.text(drag.image.label.clone(), rect.0 + 12.0, rect.1 + (height - theme.font_size * 1.2) / 2.0, theme.font_size, fade(theme.colors.text));
+    }
+}
+
+impl Default for DragController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn contains((x, y, width, height): Rect, (px, py): (f32, f32)) -> bool {
+    px >= x && px <= x + width && py >= y && py <= y + height
+}
+
+fn local(bounds: Rect, (x, y): (f32, f32)) -> (f32, f32) {
+    (x - bounds.0, y - bounds.1)
+}
+
+fn to_window(bounds: Rect, indicator: DropIndicator) -> DropIndicator {
+    match indicator {
+        DropIndicator::HorizontalLine(y) => DropIndicator::HorizontalLine(bounds.1 + y),
+        DropIndicator::VerticalLine(x) => DropIndicator::VerticalLine(bounds.0 + x),
+        DropIndicator::Row { y, height } => DropIndicator::Row { y: bounds.1 + y, height },
+        DropIndicator::Zone => DropIndicator::Zone,
+    }
+}
+
+fn leave(zones: &mut DragZones<'_>, hovered: Option<usize>) {
+    if let Some(target) = hovered.and_then(|index| zones.get_mut(index)).and_then(|(_, zone)| zone.as_target()) {
+        target.drag_leave();
+    }
+}
+
+fn finish(zones: &mut DragZones<'_>, drag: &ActiveDrag, outcome: DragOutcome) {
+    if let Some(source) = zones.get_mut(drag.source).and_then(|(_, zone)| zone.as_source()) {
+        source.drag_finished(&drag.payload, outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::List;
+
+    #[test]
+    fn rows_move_between_lists_and_cancel_restores() {
+        let mut inbox = List::new().item_height(40.0).drag_group("mail").add_item("a", "Alpha").add_item("b", "Beta");
+        let mut archive = List::new().item_height(40.0).drag_group("mail").add_item("c", "Gamma");
+        let mut drag = DragController::new();
+        let inbox_bounds = (0.0, 0.0, 200.0, 200.0);
+        let archive_bounds = (300.0, 0.0, 200.0, 200.0);
+
+        {
+            let mut zones: Vec<(Rect, &mut dyn DragZone)> = vec![(inbox_bounds, &mut inbox), (archive_bounds, &mut archive)];
+            // "Beta", dropped below "Gamma"
+            assert!(drag.press(&mut zones, (10.0, 50.0)));
+            assert!(!drag.move_to(&mut zones, (11.0, 50.0)));
+            assert!(drag.move_to(&mut zones, (310.0, 45.0)));
+            assert_eq!(drag.hovered(), Some(1));
+            assert_eq!(drag.indicator(), Some(DropIndicator::HorizontalLine(40.0)));
+            assert_eq!(drag.release(&mut zones), Some(DragOutcome::Transferred));
+        }
+        assert_eq!(inbox.items.iter().map(|item| item.id.as_str()).collect::<Vec<_>>(), vec!["a"]);
+        assert_eq!(archive.items.iter().map(|item| item.id.as_str()).collect::<Vec<_>>(), vec!["c", "b"]);
+
+        let mut zones: Vec<(Rect, &mut dyn DragZone)> = vec![(inbox_bounds, &mut inbox), (archive_bounds, &mut archive)];
+        drag.press(&mut zones, (10.0, 10.0));
+        drag.move_to(&mut zones, (320.0, 10.0));
+        let mut list = DrawList::new();
+        drag.paint(&mut list);
+        assert!(!list.is_empty());
+        drag.cancel(&mut zones);
+        assert!(!drag.is_dragging());
+        assert_eq!(drag.release(&mut zones), None);
+        drop(zones);
+        assert_eq!((inbox.items.len(), archive.items.len()), (1, 2));
+    }
+}
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Drawer position
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -43,9 +44,9 @@ pub struct Drawer {
     pub show_backdrop: bool,
     pub close_on_backdrop_click: bool,
     pub close_on_escape: bool,
-    pub background_color: (u8, u8, u8, u8),
-    pub backdrop_color: (u8, u8, u8, u8),
-    pub shadow_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub backdrop_color: ThemeColor,
+    pub shadow_color: ThemeColor,
     pub shadow_blur: f32,
     pub animation_duration: f32,
     pub on_open: Option<Box<dyn Fn()>>,
@@ -54,8 +55,9 @@ pub struct Drawer {
 }
 
 impl Drawer {
-    /// Create a new Drawer component
+    /// Create a new Drawer component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             is_open: Signal::new(false),
@@ -67,7 +69,7 @@ impl Drawer {
             show_backdrop: true,
             close_on_backdrop_click: true,
             close_on_escape: true,
-            background_color: (255, 255, 255, 255),
+            background_color: colors.surface,
             backdrop_color: (0, 0, 0, 128),
             shadow_color: (0, 0, 0, 50),
             shadow_blur: 10.0,
@@ -78,6 +80,18 @@ impl Drawer {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+    }
+
     /// Set the position
     pub fn position(mut self, position: DrawerPosition) -> Self {
         self.position = position;
@@ -0,0 +1,109 @@
+// Events - Typed events components emit into an EventQueue
+// The message-based alternative to on_* callbacks (Elm-style updates, replayable tests)
+
+use crate::calendar::CalendarDate;
+use crate::datagrid::ColumnFilter;
+use crate::datepicker::Date;
+use crate::header_menu::HeaderAction;
+use crate::table::SortDirection;
+use crate::timepicker::Time;
+
+/// Something a Button did
+#[derive(Debug, Clone, PartialEq)]
+pub enum ButtonEvent {
+    /// Clicked; carries the button's action, else its test id, else its label
+    Clicked(String),
+}
+
+/// Something a Table or DataGrid did
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableEvent {
+    RowClicked(String),
+    SortChanged { column: String, direction: SortDirection },
+    RowExpanded { id: String, expanded: bool },
+    /// A header menu action, after the table applied it
+    HeaderAction { column: String, action: HeaderAction },
+    /// DataGrid only: the active filters after a change
+    FiltersChanged(Vec<ColumnFilter>),
+    /// DataGrid only
+    PageChanged(usize),
+}
+
+/// Something a Calendar did
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalendarEvent {
+    DateSelected(CalendarDate),
+    RangeSelected { start: CalendarDate, end: CalendarDate },
+    MonthChanged { year: i32, month: u8 },
+}
+
+/// Something a DatePicker or TimePicker did
+#[derive(Debug, Clone, PartialEq)]
+pub enum PickerEvent {
+    DateChanged(Date),
+    TimeChanged(Time),
+}
+
+/// Something a MenuBar did
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuEvent {
+    /// An item ran (including actions the CommandBus handled)
+    Action(String),
+    Opened(String),
+    Closed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Table, TableColumn, TableRow};
+    use nebula_core::events::EventQueue;
+    use nebula_core::store::Store;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Model {
+        sorted_by: Option<String>,
+        hidden: Vec<String>,
+    }
+
+    fn update(model: &Model, event: &TableEvent) -> Model {
+        let mut next = model.clone();
+        match event {
+            TableEvent::SortChanged { column, .. } => next.sorted_by = Some(column.clone()),
+            TableEvent::HeaderAction { column, action: HeaderAction::Hide } => next.hidden.push(column.clone()),
+            _ => {}
+        }
+        next
+    }
+
+    #[test]
+    fn table_events_drive_a_store_and_replay() {
+        let events = EventQueue::new();
+        let mut table = Table::new()
+            .add_column_object(TableColumn::new("name", "Name").sortable(true))
+            .add_column("age", "Age")
+            .add_row_object(TableRow::new("r1", vec!["Ada".to_string(), "36".to_string()]))
+            .events(&events);
+
+        table.handle_header_action(&HeaderAction::SortDescending.action_for("name"));
+        table.handle_header_action(&HeaderAction::Hide.action_for("age"));
+        table.toggle_expanded("r1"); // No expansion configured: nothing emitted
+
+        let recorded = events.drain();
+        assert_eq!(
+            recorded,
+            vec![
+                TableEvent::SortChanged { column: "name".to_string(), direction: SortDirection::Descending },
+                TableEvent::HeaderAction { column: "name".to_string(), action: HeaderAction::SortDescending },
+                TableEvent::HeaderAction { column: "age".to_string(), action: HeaderAction::Hide },
+            ]
+        );
+
+        let store = Store::new(Model::default(), update);
+        recorded.iter().cloned().for_each(|event| store.dispatch(event));
+        let replayed = Store::new(Model::default(), update);
+        recorded.into_iter().for_each(|event| replayed.dispatch(event));
+        assert_eq!(store.state(), replayed.state());
+        assert_eq!(store.state().hidden, vec!["age".to_string()]);
+    }
+}
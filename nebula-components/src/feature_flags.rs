@@ -0,0 +1,160 @@
+// Feature Flags - Gate subtrees on flags and flip them at runtime
+// show_if_flag hides a widget until its flag is on; FeatureFlagPanel is the debug switchboard
+
+use nebula_core::feature_flags::FeatureFlags;
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::theme::ThemeProvider;
+use nebula_gfx::draw_list::{DrawList, Rect};
+
+use super::widget::{Widget, WidgetEvent, WidgetId, WidgetTree};
+
+/// Only show `id` (and everything under it) while flag `name` is on
+///
+/// # Example
+/// ```rust,ignore
+/// let checkout = tree.append_child(root, VStack::new());
+/// show_if_flag(&mut tree, checkout, &FeatureFlags::current(), "new_checkout");
+/// ```
+pub fn show_if_flag(tree: &mut WidgetTree, id: WidgetId, flags: &FeatureFlags, name: &str) {
+    tree.show_when(id, flags.flag(name));
+}
+
+/// FeatureFlagPanel - Debug list of every flag with an on/off switch 🚩
+///
+/// # Example
+/// ```rust,ignore
+/// if cfg!(debug_assertions) {
+///     tree.append_child(root, FeatureFlagPanel::from_context());
+/// }
+/// ```
+pub struct FeatureFlagPanel {
+    pub flags: FeatureFlags,
+    pub width: f32,
+    pub row_height: f32,
+    /// Row under the last PointerDown (toggled on PointerUp)
+    pressed: Option<usize>,
+}
+
+impl FeatureFlagPanel {
+    /// Panel for these flags
+    pub fn new(flags: FeatureFlags) -> Self {
+        Self { flags, width: 280.0, row_height: 32.0, pressed: None }
+    }
+
+    /// Panel for the flags provided through Context
+    pub fn from_context() -> Self {
+        Self::new(FeatureFlags::current())
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the row height
+    pub fn row_height(mut self, height: f32) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    /// Flip the flag in row `index`; returns its new value
+    pub fn toggle_row(&self, index: usize) -> Option<bool> {
+        let (name, _) = self.flags.snapshot().into_iter().nth(index)?;
+        self.flags.toggle(&name);
+        Some(self.flags.is_enabled(&name))
+    }
+
+    /// Row under a point inside `bounds`
+    fn row_at(&self, bounds: Rect, (x, y): (f32, f32)) -> Option<usize> {
+        if x < bounds.0 || x > bounds.0 + bounds.2 || y < bounds.1 || y > bounds.1 + bounds.3 {
+            return None;
+        }
+        let index = ((y - bounds.1) / self.row_height) as usize;
+        (index < self.flags.snapshot().len()).then_some(index)
+    }
+}
+
+impl Widget for FeatureFlagPanel {
+    fn build_node(&mut self, engine: &mut LayoutEngine, _children: &[NodeId]) -> Result<NodeId, String> {
+        let rows = self.flags.snapshot().len().max(1);
+        engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.width),
+                    height: taffy::style::Dimension::Length(rows as f32 * self.row_height),
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create feature flag panel node: {:?}", e))
+    }
+
+    fn paint(&self, bounds: Rect, list: &mut DrawList) {
+        let theme = ThemeProvider::current();
+        list.rounded_rect(bounds, theme.radius.md, theme.colors.surface);
+        let switch_width = self.row_height * 1.4;
+        let switch_height = self.row_height * 0.5;
+        for (index, (name, enabled)) in self.flags.snapshot().into_iter().enumerate() {
+            let y = bounds.1 + index as f32 * self.row_height;
+            list.text(name, bounds.0 + 8.0, y + (self.row_height - theme.font_size * 1.2) / 2.0, theme.font_size, theme.colors.text);
+
+            // Switch: track plus a knob on the left (off) or right (on)
+            let track = (bounds.0 + bounds.2 - switch_width - 8.0, y + (self.row_height - switch_height) / 2.0, switch_width, switch_height);
+            let track_color = if enabled { theme.colors.primary } else { theme.colors.disabled };
+            list.rounded_rect(track, switch_height / 2.0, track_color);
+            let knob_x = if enabled { track.0 + track.2 - switch_height } else { track.0 };
+            list.rounded_rect((knob_x + 2.0, track.1 + 2.0, switch_height - 4.0, switch_height - 4.0), switch_height / 2.0, theme.colors.on_primary);
+        }
+    }
+
+    fn event(&mut self, event: &WidgetEvent, bounds: Rect) -> bool {
+        let row = self.row_at(bounds, event.position());
+        match *event {
+            WidgetEvent::PointerDown { .. } => {
+                self.pressed = row;
+                row.is_some()
+            }
+            WidgetEvent::PointerUp { .. } => match self.pressed.take() {
+                Some(pressed) if row == Some(pressed) => {
+                    self.toggle_row(pressed);
+                    true
+                }
+                _ => false,
+            },
+            WidgetEvent::PointerMove { .. } => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Spacer, VStack};
+
+    #[test]
+    fn flagged_subtree_follows_the_panel() {
+        let flags = FeatureFlags::new().define("beta", false).define("new_checkout", false);
+        let mut tree = WidgetTree::new();
+        let root = tree.insert(VStack::new());
+        let panel = tree.append_child(root, FeatureFlagPanel::new(flags.clone()).row_height(30.0));
+        let checkout = tree.append_child(root, VStack::new());
+        let inner = tree.append_child(checkout, Spacer::fixed(50.0, 50.0));
+        show_if_flag(&mut tree, checkout, &flags, "new_checkout");
+        tree.set_root(root);
+        tree.layout(400.0, 300.0).unwrap();
+        assert!(tree.bounds(checkout).is_none() && tree.bounds(inner).is_none());
+
+        // Second row is "new_checkout"
+        let (x, y, _, _) = tree.bounds(panel).unwrap();
+        tree.dispatch(&WidgetEvent::PointerDown { x: x + 10.0, y: y + 45.0 });
+        tree.dispatch(&WidgetEvent::PointerUp { x: x + 10.0, y: y + 45.0 });
+        assert!(flags.is_enabled("new_checkout") && !flags.is_enabled("beta"));
+        tree.layout(400.0, 300.0).unwrap();
+        assert_eq!(tree.bounds(inner).unwrap().3, 50.0);
+
+        flags.reset();
+        tree.layout(400.0, 300.0).unwrap();
+        assert!(tree.bounds(inner).is_none());
+    }
+}
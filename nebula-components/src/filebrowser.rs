@@ -1,8 +1,10 @@
 // FileBrowser Component - File system browser
 // Essential for file navigation and selection
 
+use nebula_core::input_settings::ClickCounter;
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// File entry type
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -113,20 +115,24 @@ pub struct FileBrowser {
     pub show_modified: bool,
     pub item_height: f32,
     pub padding: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub selected_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
-    pub directory_color: (u8, u8, u8, u8),
-    pub file_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub selected_color: ThemeColor,
+    pub hover_color: ThemeColor,
+    pub directory_color: ThemeColor,
+    pub file_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub on_select: Option<Box<dyn Fn(&FileEntry)>>,
     pub on_navigate: Option<Box<dyn Fn(&str)>>,
     pub on_double_click: Option<Box<dyn Fn(&FileEntry)>>,
+    /// "Browse…" was clicked (pick a folder, then `navigate_to`)
+    pub on_browse: Option<Box<dyn Fn()>>,
+    clicks: ClickCounter,
 }
 
 impl FileBrowser {
-    /// Create a new FileBrowser component
+    /// Create a new FileBrowser component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             current_path: Signal::new("/".to_string()),
@@ -137,18 +143,37 @@ impl FileBrowser {
             show_modified: true,
             item_height: 40.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            selected_color: (59, 130, 246, 20), // Light blue
-            hover_color: (245, 245, 245, 255),
-            directory_color: (59, 130, 246, 255), // Blue
-            file_color: (100, 100, 100, 255), // Gray
-            text_color: (0, 0, 0, 255),
+            background_color: colors.surface,
+            selected_color: colors.selection,
+            hover_color: colors.hover,
+            directory_color: colors.primary,
+            file_color: colors.text_muted,
+            text_color: colors.text,
             on_select: None,
             on_navigate: None,
             on_double_click: None,
+            on_browse: None,
+            clicks: ClickCounter::new(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.selected_color = colors.selection;
+        self.hover_color = colors.hover;
+        self.directory_color = colors.primary;
+        self.file_color = colors.text_muted;
+        self.text_color = colors.text;
+    }
+
     /// Set current path
     pub fn current_path(mut self, path: impl Into<String>) -> Self {
         self.current_path.set(path.into());
@@ -238,6 +263,24 @@ impl FileBrowser {
         self
     }
 
+    /// Set the "Browse…" callback (usually `nebula_platform::dialogs::pick_folder`)
+    pub fn on_browse<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_browse = Some(Box::new(callback));
+        self
+    }
+
+    /// "Browse…" clicked; returns false if nothing handles it
+    pub fn browse(&self) -> bool {
+        if let Some(ref callback) = self.on_browse {
+            callback();
+            return true;
+        }
+        false
+    }
+
     /// Select an entry by path
     pub fn select_entry(&mut self, path: &str) {
         if let Some(entry) = self.find_entry(path) {
@@ -296,6 +339,16 @@ impl FileBrowser {
         }
     }
 
+    /// Handle a mouse click on an entry at (x, y)
+    /// Selects it; a second click within the OS double-click time opens it
+    pub fn handle_click(&mut self, path: &str, x: f32, y: f32) {
+        self.select_entry(path);
+        if self.clicks.press(x, y) == 2 {
+            self.clicks.reset();
+            self.handle_double_click(path);
+        }
+    }
+
     /// Handle double click on entry
     pub fn handle_double_click(&mut self, path: &str) {
         if let Some(entry) = self.find_entry(path) {
@@ -460,6 +513,18 @@ mod tests {
         assert_eq!(browser.get_current_path(), "/home/docs");
     }
 
+    #[test]
+    fn filebrowser_two_quick_clicks_open_directory() {
+        let mut browser = FileBrowser::new()
+            .add_entry(FileEntry::directory("docs", "/home/docs"));
+
+        browser.handle_click("/home/docs", 40.0, 20.0);
+        assert_eq!(browser.selected_entry.get(), Some("/home/docs".to_string()));
+        assert_eq!(browser.get_current_path(), "/");
+        browser.handle_click("/home/docs", 41.0, 20.0);
+        assert_eq!(browser.get_current_path(), "/home/docs");
+    }
+
     #[test]
     fn filebrowser_find_entry() {
         let browser = FileBrowser::new()
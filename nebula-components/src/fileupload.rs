@@ -3,6 +3,8 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{mix, Theme, ThemeColor, ThemeProvider};
+use nebula_gfx::draw_list::Rect;
 
 /// Uploaded file information
 #[derive(Debug, Clone, PartialEq)]
@@ -24,6 +26,11 @@ impl UploadedFile {
         }
     }
 
+    /// Create from file contents (e.g. a file dropped onto the window)
+    pub fn from_bytes(name: impl Into<String>, mime_type: impl Into<String>, data: Vec<u8>) -> Self {
+        Self::new(name, data.len(), mime_type, data)
+    }
+
     /// Get file size in KB
     pub fn size_kb(&self) -> f64 {
         self.size as f64 / 1024.0
@@ -74,22 +81,25 @@ pub struct FileUpload {
     pub disabled: bool,
     pub width: f32,
     pub height: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub drag_color: (u8, u8, u8, u8),
-    pub border_color: (u8, u8, u8, u8),
-    pub drag_border_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub drag_color: ThemeColor,
+    pub border_color: ThemeColor,
+    pub drag_border_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub icon: String,
     pub label: String,
     pub hint: String,
     pub on_upload: Option<Box<dyn Fn(&[UploadedFile])>>,
     pub on_error: Option<Box<dyn Fn(&str)>>,
     pub on_remove: Option<Box<dyn Fn(&str)>>,
+    /// "Browse…" was clicked (open a file dialog, then `add_files`)
+    pub on_browse: Option<Box<dyn Fn()>>,
 }
 
 impl FileUpload {
-    /// Create a new FileUpload component
+    /// Create a new FileUpload component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             files: Signal::new(Vec::new()),
@@ -101,20 +111,37 @@ impl FileUpload {
             disabled: false,
             width: 400.0,
             height: 200.0,
-            background_color: (250, 250, 250, 255),
-            drag_color: (240, 248, 255, 255), // Light blue
-            border_color: (220, 220, 220, 255),
-            drag_border_color: (59, 130, 246, 255), // Blue
-            text_color: (100, 100, 100, 255),
+            background_color: colors.surface_variant,
+            drag_color: mix(colors.surface, colors.primary, 0.08),
+            border_color: colors.border,
+            drag_border_color: colors.primary,
+            text_color: colors.text_muted,
             icon: "📁".to_string(),
             label: "Drop files here or click to upload".to_string(),
             hint: "".to_string(),
             on_upload: None,
             on_error: None,
             on_remove: None,
+            on_browse: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface_variant;
+        self.drag_color = mix(colors.surface, colors.primary, 0.08);
+        self.border_color = colors.border;
+        self.drag_border_color = colors.primary;
+        self.text_color = colors.text_muted;
+    }
+
     /// Set accepted file types (MIME types)
     pub fn accept(mut self, accept: impl Into<String>) -> Self {
         self.accept = Some(accept.into());
@@ -214,6 +241,27 @@ impl FileUpload {
         self
     }
 
+    /// Set the "Browse…" callback
+    /// Usually opens `nebula_platform::dialogs::open_files(FileDialog::new().accept(..))`
+    pub fn on_browse<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_browse = Some(Box::new(callback));
+        self
+    }
+
+    /// "Browse…" clicked; returns false if nothing handles it
+    pub fn browse(&self) -> bool {
+        match &self.on_browse {
+            Some(callback) if !self.disabled => {
+                callback();
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Add files
     pub fn add_files(&mut self, new_files: Vec<UploadedFile>) {
         if self.disabled {
@@ -369,6 +417,65 @@ impl Default for FileUpload {
     }
 }
 
+/// Routes files dragged in from the OS to the FileUpload under the cursor 📂
+///
+/// Feed it the window's file hover/drop events (see `InputHandler::on_file_hover`
+/// and `on_file_dropped` in nebula-platform) along with the uploads on screen;
+/// it highlights the one under the cursor and delivers dropped files there.
+///
+/// # Example
+/// ```rust,ignore
+/// fn on_file_dropped(&mut self, file: DroppedFile, position: MousePosition) {
+///     let file = UploadedFile::from_bytes(file.name, file.mime_type, file.data);
+///     let mut uploads = [(self.avatar_bounds, &mut self.avatar), (self.docs_bounds, &mut self.docs)];
+///     self.file_drop.drop_file(&mut uploads, (position.x as f32, position.y as f32), file);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct FileDropRouter {
+    hovered: Option<usize>,
+}
+
+impl FileDropRouter {
+    /// Idle router
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files moved over the window (`hovering` false when they left or dropped)
+    /// Returns the index of the highlighted upload
+    pub fn hover(&mut self, uploads: &mut [(Rect, &mut FileUpload)], position: (f32, f32), hovering: bool) -> Option<usize> {
+        let target = if hovering { upload_at(uploads, position) } else { None };
+        if self.hovered != target {
+            if let Some((_, upload)) = self.hovered.and_then(|index| uploads.get_mut(index)) {
+                upload.set_dragging(false);
+            }
+            if let Some((_, upload)) = target.and_then(|index| uploads.get_mut(index)) {
+                upload.set_dragging(true);
+            }
+            self.hovered = target;
+        }
+        target
+    }
+
+    /// A file was dropped; returns the index of the upload that got it
+    pub fn drop_file(&mut self, uploads: &mut [(Rect, &mut FileUpload)], position: (f32, f32), file: UploadedFile) -> Option<usize> {
+        let target = upload_at(uploads, position);
+        if let Some((_, upload)) = target.and_then(|index| uploads.get_mut(index)) {
+            upload.add_files(vec![file]);
+        }
+        self.hover(uploads, position, false);
+        target
+    }
+}
+
+/// Topmost enabled upload containing the point (uploads are listed bottom to top)
+fn upload_at(uploads: &[(Rect, &mut FileUpload)], (x, y): (f32, f32)) -> Option<usize> {
+    uploads.iter().rposition(|((left, top, width, height), upload)| {
+        !upload.disabled && x >= *left && x <= left + width && y >= *top && y <= top + height
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -487,6 +594,17 @@ mod tests {
         assert_eq!(upload.file_count(), 0); // Should not add
     }
 
+    #[test]
+    fn fileupload_browse_runs_callback_unless_disabled() {
+        let clicks = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = clicks.clone();
+        let upload = FileUpload::new().on_browse(move || counter.set(counter.get() + 1));
+        assert!(upload.browse());
+        assert!(!upload.disabled(true).browse());
+        assert!(!FileUpload::new().browse());
+        assert_eq!(clicks.get(), 1);
+    }
+
     #[test]
     fn fileupload_dragging_state() {
         let mut upload = FileUpload::new();
@@ -518,6 +636,13 @@ mod tests {
         assert!((file.size_mb() - 0.001953125).abs() < 0.0001);
     }
 
+    #[test]
+    fn uploaded_file_from_bytes() {
+        let file = UploadedFile::from_bytes("a.png", "image/png", vec![0; 2048]);
+        assert_eq!(file.size, 2048);
+        assert!(file.is_image());
+    }
+
     #[test]
     fn uploaded_file_type_checks() {
         let image = UploadedFile::new("photo.jpg", 1024, "image/jpeg", vec![]);
@@ -597,4 +722,23 @@ mod tests {
         assert!(result.is_ok());
         assert!(upload.node_id.is_some());
     }
+
+    #[test]
+    fn dropped_files_go_to_upload_under_cursor() {
+        let mut avatar = FileUpload::new().accept("image/*");
+        let mut docs = FileUpload::new().multiple(true);
+        let mut router = FileDropRouter::new();
+        let mut uploads = [((0.0, 0.0, 100.0, 100.0), &mut avatar), ((0.0, 200.0, 100.0, 100.0), &mut docs)];
+
+        assert_eq!(router.hover(&mut uploads, (50.0, 50.0), true), Some(0));
+        assert!(uploads[0].1.is_file_dragging());
+        router.hover(&mut uploads, (50.0, 250.0), true);
+        assert!(!uploads[0].1.is_file_dragging() && uploads[1].1.is_file_dragging());
+
+        let file = UploadedFile::from_bytes("notes.txt", "text/plain", vec![1, 2, 3]);
+        assert_eq!(router.drop_file(&mut uploads, (50.0, 250.0), file.clone()), Some(1));
+        assert_eq!(router.drop_file(&mut uploads, (50.0, 150.0), file), None);
+        assert!(!uploads[1].1.is_file_dragging());
+        assert_eq!((avatar.file_count(), docs.file_count()), (0, 1));
+    }
 }
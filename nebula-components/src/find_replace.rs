@@ -0,0 +1,623 @@
+// FindReplace Component - Search bar for editors with live match highlighting
+// Regex and case toggles, Enter / Shift+Enter to step, replace one or all in a single undo step
+
+use crate::code_editor::{CodeEditor, Selection};
+use crate::textfield::TextField;
+use nebula_core::{FocusKey, LayoutEngine, NodeId, Signal};
+use nebula_gfx::draw_list::DrawList;
+use regex::{NoExpand, Regex, RegexBuilder};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::info;
+
+/// A match in the target text (char offsets)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Text a FindReplace bar can search and edit
+pub trait FindTarget {
+    /// Current text
+    fn find_text(&self) -> String;
+
+    /// Caret (char offset) where searching starts
+    fn caret(&self) -> usize;
+
+    /// Select (or at least move to) a match
+    fn select_match(&self, start: usize, end: usize);
+
+    /// Apply (start, end, replacement) char-offset edits as one undo step
+    fn replace_ranges(&self, edits: Vec<(usize, usize, String)>);
+
+    /// Highlight every match (empty clears)
+    fn set_highlights(&self, _ranges: Vec<(usize, usize)>) {}
+}
+
+impl FindTarget for CodeEditor {
+    fn find_text(&self) -> String {
+        self.get_text()
+    }
+
+    fn caret(&self) -> usize {
+        self.primary_selection().start()
+    }
+
+    fn select_match(&self, start: usize, end: usize) {
+        self.set_selection(Selection::new(start, end));
+        self.scroll_to_line(self.offset_to_position(start).line);
+    }
+
+    fn replace_ranges(&self, edits: Vec<(usize, usize, String)>) {
+        CodeEditor::replace_ranges(self, edits);
+    }
+
+    fn set_highlights(&self, ranges: Vec<(usize, usize)>) {
+        self.search_highlights.set(ranges);
+    }
+}
+
+impl FindTarget for TextField {
+    fn find_text(&self) -> String {
+        self.get_text()
+    }
+
+    fn caret(&self) -> usize {
+        let text = self.get_text();
+        text[..self.cursor_position.get().min(text.len())].chars().count()
+    }
+
+    fn select_match(&self, _start: usize, end: usize) {
+        // Single caret: park it after the match
+        let text = self.get_text();
+        self.cursor_position.set(text.char_indices().nth(end).map_or(text.len(), |(index, _)| index));
+    }
+
+    fn replace_ranges(&self, mut edits: Vec<(usize, usize, String)>) {
+        edits.sort_by_key(|&(start, end, _)| (start, end));
+        let chars: Vec<char> = self.get_text().chars().collect();
+        let mut result = String::with_capacity(chars.len());
+        let mut copied = 0;
+        for (start, end, replacement) in edits {
+            if start < copied || end > chars.len() {
+                continue;
+            }
+            result.extend(&chars[copied..start]);
+            result.push_str(&replacement);
+            copied = end;
+        }
+        result.extend(&chars[copied..]);
+        self.set_text(result);
+    }
+}
+
+/// FindReplace - Find bar for CodeEditor and TextField 🔍
+///
+/// - Incremental: every keystroke re-searches and highlights all matches
+/// - "3 of 12" counter
+/// - Case-sensitivity (Aa) and regex (.*) toggles; regex replacements can
+///   use `$1` / `${name}` groups
+/// - Enter / Shift+Enter step forwards / backwards, wrapping around
+/// - Replace and Replace All (one undo step in CodeEditor)
+///
+/// # Example
+/// ```rust,ignore
+/// let editor = CodeEditor::with_text(source);
+/// let mut find = FindReplace::new().target(editor.clone());
+/// find.open();
+/// find.set_query("foo");
+/// find.handle_key(FocusKey::Enter, false); // next match
+/// find.set_replacement("bar");
+/// find.replace_all();
+/// ```
+pub struct FindReplace {
+    pub node_id: Option<NodeId>,
+    pub query: Signal<String>,
+    pub replacement: Signal<String>,
+    pub case_sensitive: Signal<bool>,
+    pub use_regex: Signal<bool>,
+    pub is_open: Signal<bool>,
+    /// Show the replace row
+    pub show_replace: Signal<bool>,
+    /// Typing goes to the replace field
+    pub editing_replacement: Signal<bool>,
+    pub matches: Signal<Vec<MatchRange>>,
+    /// Index of the selected match
+    pub current: Signal<Option<usize>>,
+    /// Invalid regex message
+    pub error: Signal<Option<String>>,
+    pub position: (f32, f32),
+    pub width: f32,
+    /// Height of one row (find / replace)
+    pub row_height: f32,
+    pub font_size: f32,
+    pub background_color: (u8, u8, u8, u8),
+    pub input_color: (u8, u8, u8, u8),
+    pub text_color: (u8, u8, u8, u8),
+    pub muted_color: (u8, u8, u8, u8),
+    pub active_color: (u8, u8, u8, u8),
+    pub error_color: (u8, u8, u8, u8),
+    target: Option<Rc<dyn FindTarget>>,
+    /// Text the matches were computed for
+    searched: RefCell<Option<String>>,
+}
+
+impl FindReplace {
+    /// Create a closed find bar
+    pub fn new() -> Self {
+        info!("🔍 Creating FindReplace");
+        Self {
+            node_id: None,
+            query: Signal::new(String::new()),
+            replacement: Signal::new(String::new()),
+            case_sensitive: Signal::new(false),
+            use_regex: Signal::new(false),
+            is_open: Signal::new(false),
+            show_replace: Signal::new(false),
+            editing_replacement: Signal::new(false),
+            matches: Signal::new(Vec::new()),
+            current: Signal::new(None),
+            error: Signal::new(None),
+            position: (0.0, 0.0),
+            width: 420.0,
+            row_height: 32.0,
+            font_size: 13.0,
+            background_color: (37, 37, 38, 255),
+            input_color: (60, 60, 60, 255),
+            text_color: (212, 212, 212, 255),
+            muted_color: (150, 150, 150, 255),
+            active_color: (0, 122, 204, 255),
+            error_color: (244, 71, 71, 255),
+            target: None,
+            searched: RefCell::new(None),
+        }
+    }
+
+    /// Search this editor or field
+    pub fn target(mut self, target: impl FindTarget + 'static) -> Self {
+        self.target = Some(Rc::new(target));
+        self
+    }
+
+    /// Switch to another editor or field
+    pub fn set_target(&mut self, target: impl FindTarget + 'static) {
+        self.clear_highlights();
+        self.target = Some(Rc::new(target));
+        self.refresh();
+    }
+
+    /// Set the position
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Open the find row (Ctrl+F)
+    pub fn open(&self) {
+        self.is_open.set(true);
+        self.editing_replacement.set(false);
+        self.refresh();
+    }
+
+    /// Open with the replace row (Ctrl+H)
+    pub fn open_replace(&self) {
+        self.show_replace.set(true);
+        self.open();
+    }
+
+    /// Close and clear highlights
+    pub fn close(&self) {
+        self.is_open.set(false);
+        self.clear_highlights();
+    }
+
+    /// Total height
+    pub fn height(&self) -> f32 {
+        if self.show_replace.get() { self.row_height * 2.0 } else { self.row_height }
+    }
+
+    /// Change the search text (re-searches immediately)
+    pub fn set_query(&self, query: impl Into<String>) {
+        self.query.set(query.into());
+        self.refresh();
+    }
+
+    /// Change the replacement text
+    pub fn set_replacement(&self, replacement: impl Into<String>) {
+        self.replacement.set(replacement.into());
+    }
+
+    /// Toggle Aa
+    pub fn toggle_case_sensitive(&self) {
+        self.case_sensitive.set(!self.case_sensitive.get());
+        self.refresh();
+    }
+
+    /// Toggle .*
+    pub fn toggle_regex(&self) {
+        self.use_regex.set(!self.use_regex.get());
+        self.refresh();
+    }
+
+    fn build_regex(&self) -> Result<Option<Regex>, String> {
+        let query = self.query.get();
+        if query.is_empty() {
+            return Ok(None);
+        }
+        let pattern = if self.use_regex.get() { query } else { regex::escape(&query) };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive.get())
+            .multi_line(true)
+            .build()
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Matches as char offsets (empty regex matches are skipped)
+    fn find_matches(regex: &Regex, text: &str) -> Vec<MatchRange> {
+        let mut matches = Vec::new();
+        let mut chars_before = 0;
+        let mut scanned = 0;
+        for found in regex.find_iter(text).filter(|found| !found.is_empty()) {
+            chars_before += text[scanned..found.start()].chars().count();
+            let length = found.as_str().chars().count();
+            matches.push(MatchRange { start: chars_before, end: chars_before + length });
+            chars_before += length;
+            scanned = found.end();
+        }
+        matches
+    }
+
+    /// Search again and select the first match at or after the caret
+    pub fn refresh(&self) {
+        self.search(true);
+    }
+
+    /// Re-search if the target's text changed (call once per frame)
+    pub fn update(&self) {
+        let Some(target) = &self.target else {
+            return;
+        };
+        if self.is_open.get() && self.searched.borrow().as_deref() != Some(target.find_text().as_str()) {
+            self.search(false);
+        }
+    }
+
+    fn search(&self, select: bool) {
+        let Some(target) = self.target.clone() else {
+            return;
+        };
+        let text = target.find_text();
+        *self.searched.borrow_mut() = Some(text.clone());
+        let regex = match self.build_regex() {
+            Ok(regex) => {
+                self.error.set(None);
+                regex
+            }
+            Err(e) => {
+                self.error.set(Some(e));
+                None
+            }
+        };
+        let matches = regex.map(|regex| Self::find_matches(&regex, &text)).unwrap_or_default();
+        let caret = target.caret();
+        let current = match (select, self.current.get()) {
+            (false, Some(index)) if !matches.is_empty() => Some(index.min(matches.len() - 1)),
+            _ if matches.is_empty() => None,
+            _ => Some(matches.iter().position(|found| found.start >= caret).unwrap_or(0)),
+        };
+        target.set_highlights(if self.is_open.get() { matches.iter().map(|found| (found.start, found.end)).collect() } else { Vec::new() });
+        self.matches.set(matches);
+        self.current.set(current);
+        if select {
+            self.select_current();
+        }
+    }
+
+    fn clear_highlights(&self) {
+        if let Some(target) = &self.target {
+            target.set_highlights(Vec::new());
+        }
+    }
+
+    fn select_current(&self) {
+        let (Some(target), Some(index)) = (&self.target, self.current.get()) else {
+            return;
+        };
+        if let Some(found) = self.matches.get().get(index) {
+            target.select_match(found.start, found.end);
+        }
+    }
+
+    /// Number of matches
+    pub fn match_count(&self) -> usize {
+        self.matches.get().len()
+    }
+
+    /// "3 of 12", "No results" or "" with no query
+    pub fn counter_label(&self) -> String {
+        if self.query.get().is_empty() {
+            return String::new();
+        }
+        match (self.current.get(), self.match_count()) {
+            (_, 0) => "No results".to_string(),
+            (Some(index), count) => format!("{} of {}", index + 1, count),
+            (None, count) => format!("{} results", count),
+        }
+    }
+
+    /// Select the next match (wraps)
+    pub fn find_next(&self) -> bool {
+        self.step(true)
+    }
+
+    /// Select the previous match (wraps)
+    pub fn find_previous(&self) -> bool {
+        self.step(false)
+    }
+
+    fn step(&self, forward: bool) -> bool {
+        self.update();
+        let count = self.match_count();
+        if count == 0 {
+            return false;
+        }
+        let next = match self.current.get() {
+            Some(index) if forward => (index + 1) % count,
+            Some(index) => (index + count - 1) % count,
+            None => 0,
+        };
+        self.current.set(Some(next));
+        self.select_current();
+        true
+    }
+
+    /// Replacement text for each match, in order
+    fn replacements(&self, text: &str) -> Vec<String> {
+        let Ok(Some(regex)) = self.build_regex() else {
+            return Vec::new();
+        };
+        let replacement = self.replacement.get();
+        regex
+            .captures_iter(text)
+            .filter(|captures| captures.get(0).is_some_and(|found| !found.is_empty()))
+            .map(|captures| {
+                if self.use_regex.get() {
+                    let mut expanded = String::new();
+                    captures.expand(&replacement, &mut expanded);
+                    expanded
+                } else {
+                    // Literal: `$` means `$`
+                    regex.replace(captures.get(0).map_or("", |found| found.as_str()), NoExpand(&replacement)).into_owned()
+                }
+            })
+            .collect()
+    }
+
+    /// Replace the selected match and move to the next one
+    pub fn replace_current(&self) -> bool {
+        self.update();
+        let (Some(target), Some(index)) = (self.target.clone(), self.current.get()) else {
+            return false;
+        };
+        let text = target.find_text();
+        let matches = self.matches.get();
+        let (Some(found), Some(replacement)) = (matches.get(index), self.replacements(&text).get(index).cloned()) else {
+            return false;
+        };
+        target.replace_ranges(vec![(found.start, found.end, replacement)]);
+        self.search(false);
+        // The next match now sits at the same index
+        if self.match_count() > 0 {
+            self.current.set(Some(index % self.match_count()));
+            self.select_current();
+        }
+        true
+    }
+
+    /// Replace every match (one undo step); returns how many
+    pub fn replace_all(&self) -> usize {
+        self.update();
+        let Some(target) = self.target.clone() else {
+            return 0;
+        };
+        let text = target.find_text();
+        let edits: Vec<(usize, usize, String)> = self
+            .matches
+            .get()
+            .iter()
+            .zip(self.replacements(&text))
+            .map(|(found, replacement)| (found.start, found.end, replacement))
+            .collect();
+        let count = edits.len();
+        if count > 0 {
+            target.replace_ranges(edits);
+            info!("🔍 Replaced {} matches", count);
+        }
+        self.search(false);
+        count
+    }
+
+    /// Keyboard input while the bar has focus; returns true if used
+    pub fn handle_key(&self, key: FocusKey, shift: bool) -> bool {
+        if !self.is_open.get() {
+            return false;
+        }
+        let field = if self.editing_replacement.get() { &self.replacement } else { &self.query };
+        match key {
+            FocusKey::Enter if self.editing_replacement.get() => self.replace_current(),
+            FocusKey::Enter if shift => self.find_previous(),
+            FocusKey::Enter => self.find_next(),
+            FocusKey::Escape => {
+                self.close();
+                true
+            }
+            FocusKey::Tab if self.show_replace.get() => {
+                self.editing_replacement.set(!self.editing_replacement.get());
+                true
+            }
+            FocusKey::Char(c) => {
+                field.update(|text| format!("{}{}", text, c));
+                if !self.editing_replacement.get() {
+                    self.refresh();
+                }
+                true
+            }
+            FocusKey::Space => self.handle_key(FocusKey::Char(' '), shift),
+            FocusKey::Backspace => {
+                field.update(|text| {
+                    let mut text = text.clone();
+                    text.pop();
+                    text
+                });
+                if !self.editing_replacement.get() {
+                    self.refresh();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Draw the bar
+    pub fn paint(&self, list: &mut DrawList) {
+        if !self.is_open.get() {
+            return;
+        }
+        let (x, y) = self.position;
+        let padding = 4.0;
+        list.rounded_rect((x, y, self.width, self.height()), 4.0, self.background_color);
+
+        let char_width = self.font_size * 0.6;
+        let text_y = |row_y: f32| row_y + (self.row_height - self.font_size * 1.2) / 2.0;
+        let input_width = self.width - 150.0;
+        let rows = [(&self.query, false), (&self.replacement, true)];
+        for (row, (field, is_replacement)) in rows.iter().enumerate().take(if self.show_replace.get() { 2 } else { 1 }) {
+            let row_y = y + row as f32 * self.row_height;
+            let focused = self.editing_replacement.get() == *is_replacement;
+            let input = (x + padding, row_y + padding, input_width, self.row_height - padding * 2.0);
+            if focused {
+                list.rounded_rect((input.0 - 1.0, input.1 - 1.0, input.2 + 2.0, input.3 + 2.0), 3.0, self.active_color);
+            }
+            list.rounded_rect(input, 3.0, self.input_color);
+            let value = field.get();
+            let (shown, color) = match (value.is_empty(), *is_replacement) {
+                (true, false) => ("Find".to_string(), self.muted_color),
+                (true, true) => ("Replace".to_string(), self.muted_color),
+                _ => (value, self.text_color),
+            };
+            list.text(shown, input.0 + 6.0, text_y(row_y), self.font_size, color);
+        }
+
+        // Toggles and counter next to the find field
+        let mut toggle_x = x + padding + input_width + 6.0;
+        for (label, on) in [("Aa", self.case_sensitive.get()), (".*", self.use_regex.get())] {
+            let toggle = (toggle_x, y + padding, 24.0, self.row_height - padding * 2.0);
+            if on {
+                list.rounded_rect(toggle, 3.0, self.active_color);
+            }
+            list.text(label, toggle_x + 12.0 - label.len() as f32 * char_width / 2.0, text_y(y), self.font_size, self.text_color);
+            toggle_x += 28.0;
+        }
+        let (label, color) = match self.error.get() {
+            Some(_) => ("Invalid regex".to_string(), self.error_color),
+            None if self.match_count() == 0 && !self.query.get().is_empty() => (self.counter_label(), self.error_color),
+            None => (self.counter_label(), self.muted_color),
+        };
+        list.text(label, toggle_x + 4.0, text_y(y), self.font_size - 1.0, color);
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.width),
+                    height: taffy::style::Dimension::Length(self.height()),
+                },
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create FindReplace: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for FindReplace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incremental_search_and_navigation() {
+        let editor = CodeEditor::with_text("Foo foo\nfood FOO");
+        let find = FindReplace::new().target(editor.clone());
+        find.open();
+        for c in "foo".chars() {
+            find.handle_key(FocusKey::Char(c), false);
+        }
+        assert_eq!(find.match_count(), 4);
+        assert_eq!(find.counter_label(), "1 of 4");
+        assert_eq!(editor.search_highlights.get().len(), 4);
+
+        find.handle_key(FocusKey::Enter, false);
+        assert_eq!(editor.selected_texts(), vec!["foo"]);
+        find.handle_key(FocusKey::Enter, true);
+        find.handle_key(FocusKey::Enter, true);
+        assert_eq!(find.counter_label(), "4 of 4");
+
+        find.toggle_case_sensitive();
+        assert_eq!(find.match_count(), 2);
+        find.toggle_regex();
+        find.set_query("fo(");
+        assert!(find.error.get().is_some());
+        assert_eq!(find.counter_label(), "No results");
+
+        find.close();
+        assert!(editor.search_highlights.get().is_empty());
+    }
+
+    #[test]
+    fn replace_all_is_one_undo_step() {
+        let editor = CodeEditor::with_text("let a1 = 1;\nlet b2 = 2;");
+        let find = FindReplace::new().target(editor.clone());
+        find.open_replace();
+        find.toggle_regex();
+        find.set_query(r"([a-z])(\d)");
+        find.set_replacement("${2}_$1");
+
+        assert!(find.replace_current());
+        assert_eq!(editor.get_text(), "let 1_a = 1;\nlet b2 = 2;");
+        assert_eq!(find.replace_all(), 1);
+        assert_eq!(editor.get_text(), "let 1_a = 1;\nlet 2_b = 2;");
+        assert_eq!(find.counter_label(), "No results");
+
+        editor.undo();
+        assert_eq!(editor.get_text(), "let 1_a = 1;\nlet b2 = 2;");
+        editor.undo();
+        assert_eq!(editor.get_text(), "let a1 = 1;\nlet b2 = 2;");
+        assert!(editor.handle_key_with(FocusKey::Char('y'), crate::code_editor::KeyModifiers::ctrl()));
+        assert_eq!(editor.get_text(), "let 1_a = 1;\nlet b2 = 2;");
+
+        // Plain mode takes `$` literally and works on TextField too
+        let field = TextField::with_text("cost: 5, tax: 5");
+        let find = FindReplace::new().target(field.clone());
+        find.open();
+        find.set_query("5");
+        find.set_replacement("$5");
+        assert_eq!(find.replace_all(), 2);
+        assert_eq!(field.get_text(), "cost: $5, tax: $5");
+    }
+}
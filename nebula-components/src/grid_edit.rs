@@ -0,0 +1,253 @@
+// Grid Edit - Cell ranges, column editors and undoable edits for DataGrid
+// Turns pasted TSV/CSV into a patch that applies and reverts as one command
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nebula_core::command::Command;
+
+use crate::locale::Locale;
+use crate::table::TableRow;
+use crate::validation::{Rule, ValidationError};
+
+/// A rectangle of cells: rows are positions among the visible (filtered)
+/// rows, columns are positions among the displayed columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRange {
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+impl CellRange {
+    /// Range between two corners (in any order)
+    pub fn new(a: (usize, usize), b: (usize, usize)) -> Self {
+        Self { start: (a.0.min(b.0), a.1.min(b.1)), end: (a.0.max(b.0), a.1.max(b.1)) }
+    }
+
+    /// A single cell
+    pub fn cell(row: usize, column: usize) -> Self {
+        Self::new((row, column), (row, column))
+    }
+
+    /// Number of rows covered
+    pub fn height(&self) -> usize {
+        self.end.0 - self.start.0 + 1
+    }
+
+    /// Number of columns covered
+    pub fn width(&self) -> usize {
+        self.end.1 - self.start.1 + 1
+    }
+}
+
+/// How a column's cells are edited ✏️
+///
+/// Pasted and typed values go through the same rules as form fields.
+#[derive(Clone, Default)]
+pub struct ColumnEditor {
+    pub rules: Vec<Rule>,
+    pub read_only: bool,
+}
+
+impl ColumnEditor {
+    /// Editor checking these rules
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self { rules, read_only: false }
+    }
+
+    /// Editor that refuses every edit
+    pub fn read_only() -> Self {
+        Self { rules: Vec::new(), read_only: true }
+    }
+
+    /// Check a value (numbers are read with `locale`)
+    pub fn validate(&self, value: &str, locale: &Locale) -> Result<(), ValidationError> {
+        if self.read_only {
+            return Err(ValidationError::Custom("This column is read-only".to_string()));
+        }
+        self.rules.iter().try_for_each(|rule| rule.check(value, locale, &|_| None))
+    }
+}
+
+/// Split clipboard text into rows of cells
+///
+/// Tab-separated when the text has a tab (what spreadsheets copy),
+/// comma-separated otherwise. Quoted cells may contain separators,
+/// newlines and doubled quotes.
+pub fn parse_clipboard_table(text: &str) -> Vec<Vec<String>> {
+    let separator = if text.contains('\t') { '\t' } else { ',' };
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut chars = text.chars().peekable();
+    let mut quoted = false;
+    let mut at_cell_start = true;
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = false,
+                _ => cell.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if at_cell_start => {
+                quoted = true;
+                at_cell_start = false;
+            }
+            c if c == separator => {
+                row.push(std::mem::take(&mut cell));
+                at_cell_start = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+                at_cell_start = true;
+            }
+            _ => {
+                cell.push(c);
+                at_cell_start = false;
+            }
+        }
+    }
+    // The trailing newline spreadsheets add doesn't start another row
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
+
+/// One cell's value before and after an edit
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellChange {
+    pub row_id: String,
+    pub column: usize,
+    pub before: String,
+    pub after: String,
+}
+
+/// A set of edits that apply and revert together
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CellPatch {
+    pub changes: Vec<CellChange>,
+    /// Rows the edit appended (removed again on revert)
+    pub added_rows: Vec<TableRow>,
+}
+
+impl CellPatch {
+    /// Nothing to do?
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.added_rows.is_empty()
+    }
+
+    /// Apply to rows
+    pub fn apply(&self, rows: &mut Vec<TableRow>) {
+        rows.extend(self.added_rows.iter().cloned());
+        for change in &self.changes {
+            set_cell(rows, &change.row_id, change.column, &change.after);
+        }
+    }
+
+    /// Take back from rows
+    pub fn revert(&self, rows: &mut Vec<TableRow>) {
+        for change in self.changes.iter().rev() {
+            set_cell(rows, &change.row_id, change.column, &change.before);
+        }
+        rows.retain(|row| !self.added_rows.iter().any(|added| added.id == row.id));
+    }
+}
+
+fn set_cell(rows: &mut [TableRow], row_id: &str, column: usize, value: &str) {
+    if let Some(row) = rows.iter_mut().find(|row| row.id == row_id) {
+        if row.cells.len() <= column {
+            row.cells.resize(column + 1, String::new());
+        }
+        row.cells[column] = value.to_string();
+    }
+}
+
+/// Patches waiting to be applied to a grid (true = apply, false = revert)
+pub type PendingPatches = Rc<RefCell<Vec<(CellPatch, bool)>>>;
+
+/// Undo step for a grid edit
+///
+/// The grid owns its rows, so the command queues the patch and the grid
+/// applies it (see `DataGrid::apply_pending_edits`).
+pub struct GridEditCommand {
+    pub label: String,
+    pub patch: CellPatch,
+    pending: PendingPatches,
+}
+
+impl GridEditCommand {
+    /// Command for a patch on the grid owning `pending`
+    pub fn new(label: impl Into<String>, patch: CellPatch, pending: PendingPatches) -> Self {
+        Self { label: label.into(), patch, pending }
+    }
+}
+
+impl Command for GridEditCommand {
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn execute(&mut self) -> Result<(), String> {
+        self.pending.borrow_mut().push((self.patch.clone(), true));
+        Ok(())
+    }
+
+    fn undo(&mut self) {
+        self.pending.borrow_mut().push((self.patch.clone(), false));
+    }
+}
+
+/// A cell a paste left alone
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedCell {
+    pub row_id: String,
+    pub column_id: String,
+    pub value: String,
+    pub error: ValidationError,
+}
+
+/// What a paste did
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PasteReport {
+    /// Cells whose value changed
+    pub changed: usize,
+    pub added_rows: usize,
+    /// Cells that failed their column editor
+    pub rejected: Vec<RejectedCell>,
+    /// Cells that fell outside the grid (past the last column, or past the
+    /// last row when rows can't be added)
+    pub clipped: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tsv_and_quoted_csv() {
+        assert_eq!(
+            parse_clipboard_table("a\tb\r\nc\td\r\n"),
+            vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), "d".to_string()]]
+        );
+        assert_eq!(
+            parse_clipboard_table("\"Smith, J\",\"say \"\"hi\"\"\"\n\"two\nlines\",x"),
+            vec![
+                vec!["Smith, J".to_string(), "say \"hi\"".to_string()],
+                vec!["two\nlines".to_string(), "x".to_string()]
+            ]
+        );
+        assert_eq!(parse_clipboard_table("solo"), vec![vec!["solo".to_string()]]);
+        assert!(parse_clipboard_table("").is_empty());
+    }
+}
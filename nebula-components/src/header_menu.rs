@@ -0,0 +1,180 @@
+// Header Menu - Right-click menu on Table and DataGrid column headers
+// Sort, filter, pin, hide, autosize and group-by without a custom toolbar
+
+use crate::context_menu::{ContextMenu, ContextMenuItem};
+use crate::table::{SortDirection, TableColumn, TableRow};
+
+/// Prefix of every header menu action ("header:<action>:<column id>")
+pub const HEADER_ACTION_PREFIX: &str = "header:";
+
+/// Called with the column id and the chosen action
+pub type HeaderActionCallback = Box<dyn Fn(&str, HeaderAction)>;
+
+/// Something the header menu can do to a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderAction {
+    SortAscending,
+    SortDescending,
+    ClearSort,
+    /// Open the filter editor for the column
+    Filter,
+    Pin,
+    Unpin,
+    Hide,
+    /// Fit the column width to its content
+    Autosize,
+    GroupBy,
+    Ungroup,
+}
+
+impl HeaderAction {
+    /// Stable name used in menu action strings
+    pub fn name(&self) -> &'static str {
+        match self {
+            HeaderAction::SortAscending => "sort_asc",
+            HeaderAction::SortDescending => "sort_desc",
+            HeaderAction::ClearSort => "clear_sort",
+            HeaderAction::Filter => "filter",
+            HeaderAction::Pin => "pin",
+            HeaderAction::Unpin => "unpin",
+            HeaderAction::Hide => "hide",
+            HeaderAction::Autosize => "autosize",
+            HeaderAction::GroupBy => "group_by",
+            HeaderAction::Ungroup => "ungroup",
+        }
+    }
+
+    /// Menu action string for a column
+    pub fn action_for(&self, column_id: &str) -> String {
+        format!("{}{}:{}", HEADER_ACTION_PREFIX, self.name(), column_id)
+    }
+
+    /// Split a menu action string into the action and its column id
+    pub fn parse(action: &str) -> Option<(HeaderAction, String)> {
+        let rest = action.strip_prefix(HEADER_ACTION_PREFIX)?;
+        let (name, column_id) = rest.split_once(':')?;
+        let action = [
+            HeaderAction::SortAscending,
+            HeaderAction::SortDescending,
+            HeaderAction::ClearSort,
+            HeaderAction::Filter,
+            HeaderAction::Pin,
+            HeaderAction::Unpin,
+            HeaderAction::Hide,
+            HeaderAction::Autosize,
+            HeaderAction::GroupBy,
+            HeaderAction::Ungroup,
+        ]
+        .into_iter()
+        .find(|action| action.name() == name)?;
+        Some((action, column_id.to_string()))
+    }
+}
+
+/// What the grid knows about a column when its menu opens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeaderMenuState {
+    /// Current sort on this column, if it is the sort column
+    pub sort: Option<SortDirection>,
+    /// Does the grid support filtering?
+    pub filterable: bool,
+    /// Is the grid grouped by this column?
+    pub grouped: bool,
+}
+
+/// Context menu items for a column header
+///
+/// Items that don't apply (sorting an unsortable column, clearing a sort
+/// that isn't there) stay in the menu disabled so the layout doesn't jump.
+pub fn header_menu_items(column: &TableColumn, state: HeaderMenuState) -> Vec<ContextMenuItem> {
+    let item = |label: &str, action: HeaderAction, enabled: bool| {
+        let action = action.action_for(&column.id);
+        if enabled {
+            ContextMenuItem::new(label, action)
+        } else {
+            ContextMenuItem::disabled(label, action)
+        }
+    };
+
+    let mut items = vec![
+        item("Sort Ascending", HeaderAction::SortAscending, column.sortable && state.sort != Some(SortDirection::Ascending)),
+        item("Sort Descending", HeaderAction::SortDescending, column.sortable && state.sort != Some(SortDirection::Descending)),
+        item("Clear Sort", HeaderAction::ClearSort, state.sort.is_some()),
+        ContextMenuItem::separator(),
+    ];
+    if state.filterable {
+        items.push(item("Filter…", HeaderAction::Filter, true));
+    }
+    items.push(if column.pinned {
+        item("Unpin Column", HeaderAction::Unpin, true)
+    } else {
+        item("Pin Column", HeaderAction::Pin, true)
+    });
+    items.push(item("Hide Column", HeaderAction::Hide, true));
+    items.push(item("Autosize Column", HeaderAction::Autosize, column.resizable));
+    items.push(ContextMenuItem::separator());
+    items.push(if state.grouped {
+        item("Ungroup", HeaderAction::Ungroup, true)
+    } else {
+        item(&format!("Group by {}", column.label), HeaderAction::GroupBy, true)
+    });
+    items
+}
+
+/// Build the header context menu for a column
+pub fn header_menu(column: &TableColumn, state: HeaderMenuState) -> ContextMenu {
+    ContextMenu::new().items(header_menu_items(column, state))
+}
+
+/// Columns in display order: visible only, pinned ones first
+pub fn display_columns(columns: &[TableColumn]) -> Vec<usize> {
+    let visible = || columns.iter().enumerate().filter(|(_, column)| !column.hidden);
+    visible()
+        .filter(|(_, column)| column.pinned)
+        .chain(visible().filter(|(_, column)| !column.pinned))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Group row indices by the value in one column, in order of first appearance
+pub fn group_rows(rows: &[TableRow], indices: &[usize], column_index: usize) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for &index in indices {
+        let key = rows[index].cells.get(column_index).cloned().unwrap_or_default();
+        match groups.iter_mut().find(|(value, _)| *value == key) {
+            Some((_, members)) => members.push(index),
+            None => groups.push((key, vec![index])),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actions_round_trip_through_menu_strings() {
+        let action = HeaderAction::GroupBy.action_for("status:code");
+        assert_eq!(action, "header:group_by:status:code");
+        assert_eq!(HeaderAction::parse(&action), Some((HeaderAction::GroupBy, "status:code".to_string())));
+        assert_eq!(HeaderAction::parse("spell:ignore"), None);
+
+        let column = TableColumn::new("name", "Name").sortable(false);
+        let state = HeaderMenuState { sort: None, filterable: false, grouped: false };
+        let items = header_menu_items(&column, state);
+        assert!(items.iter().filter(|item| item.label.starts_with("Sort")).all(|item| item.disabled));
+        assert!(!items.iter().any(|item| item.label == "Filter…"));
+        assert!(items.iter().any(|item| item.label == "Group by Name"));
+    }
+
+    #[test]
+    fn pinned_columns_come_first_and_hidden_ones_drop_out() {
+        let columns = vec![
+            TableColumn::new("a", "A"),
+            TableColumn::new("b", "B").hidden(true),
+            TableColumn::new("c", "C").pinned(true),
+        ];
+        assert_eq!(display_columns(&columns), vec![2, 0]);
+    }
+}
@@ -0,0 +1,296 @@
+// IbanInput Component - International bank account number input
+// Groups characters in blocks of four and validates the mod-97 checksum
+
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+
+/// Expected IBAN length for a country code (None = unknown country)
+pub fn iban_length(country: &str) -> Option<usize> {
+    let length = match country {
+        "AD" => 24, "AE" => 23, "AT" => 20, "BA" => 20, "BE" => 16, "BG" => 22,
+        "BH" => 22, "BR" => 29, "CH" => 21, "CY" => 28, "CZ" => 24, "DE" => 22,
+        "DK" => 18, "EE" => 20, "ES" => 24, "FI" => 18, "FO" => 18, "FR" => 27,
+        "GB" => 22, "GI" => 23, "GL" => 18, "GR" => 27, "HR" => 21, "HU" => 28,
+        "IE" => 22, "IL" => 23, "IS" => 26, "IT" => 27, "KW" => 30, "KZ" => 20,
+        "LB" => 28, "LI" => 21, "LT" => 20, "LU" => 20, "LV" => 21, "MC" => 27,
+        "MD" => 24, "ME" => 22, "MK" => 19, "MT" => 31, "MU" => 30, "NL" => 18,
+        "NO" => 15, "PK" => 24, "PL" => 28, "PT" => 25, "QA" => 29, "RO" => 24,
+        "RS" => 22, "SA" => 24, "SE" => 24, "SI" => 19, "SK" => 24, "SM" => 27,
+        "TN" => 24, "TR" => 26, "UA" => 29,
+        _ => return None,
+    };
+    Some(length)
+}
+
+/// Strip spaces and uppercase ("de89 3704..." -> "DE893704...")
+pub fn normalize_iban(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Group an IBAN in blocks of four for display
+pub fn format_iban(iban: &str) -> String {
+    let normalized = normalize_iban(iban);
+    normalized
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Validate an IBAN: country length and ISO 7064 mod-97 checksum
+pub fn validate_iban(iban: &str) -> Result<(), String> {
+    let iban = normalize_iban(iban);
+
+    if iban.len() < 4 {
+        return Err("IBAN is too short".to_string());
+    }
+
+    let country = &iban[..2];
+    if !country.chars().all(|c| c.is_ascii_uppercase()) || !iban[2..4].chars().all(|c| c.is_ascii_digit()) {
+        return Err("IBAN must start with a country code and check digits".to_string());
+    }
+
+    let expected = iban_length(country).ok_or_else(|| format!("Unknown IBAN country '{}'", country))?;
+    if iban.len() != expected {
+        return Err(format!("{} IBANs have {} characters, got {}", country, expected, iban.len()));
+    }
+
+    // Move the first four characters to the end, map letters to 10..35
+    let remainder = iban[4..].chars().chain(iban[..4].chars()).fold(0u32, |acc, c| {
+        let value = c.to_digit(36).unwrap_or(0);
+        if value >= 10 {
+            (acc * 100 + value) % 97
+        } else {
+            (acc * 10 + value) % 97
+        }
+    });
+
+    if remainder == 1 {
+        Ok(())
+    } else {
+        Err("IBAN checksum is invalid".to_string())
+    }
+}
+
+/// IbanInput component - bank account input with live validation
+///
+/// # Example
+/// ```rust,ignore
+/// let mut iban = IbanInput::new();
+/// iban.set_value("de89370400440532013000");
+/// assert_eq!(iban.formatted(), "DE89 3704 0044 0532 0130 00");
+/// assert!(iban.is_valid());
+/// ```
+pub struct IbanInput {
+    pub node_id: Option<NodeId>,
+    /// Normalized value (uppercase, no spaces)
+    pub value: Signal<String>,
+    pub is_valid: Signal<bool>,
+    /// Validation message for the current value (None when valid or empty)
+    pub error: Signal<Option<String>>,
+    pub is_focused: Signal<bool>,
+    pub placeholder: String,
+    pub width: f32,
+    pub height: f32,
+    pub error_color: (u8, u8, u8, u8),
+    pub on_change: Option<Box<dyn Fn(String)>>,
+}
+
+impl IbanInput {
+    /// Create a new IBAN input
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            value: Signal::new(String::new()),
+            is_valid: Signal::new(false),
+            error: Signal::new(None),
+            is_focused: Signal::new(false),
+            placeholder: "DE00 0000 0000 0000 0000 00".to_string(),
+            width: 320.0,
+            height: 40.0,
+            error_color: (220, 38, 38, 255),
+            on_change: None,
+        }
+    }
+
+    /// Set the placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the change callback (receives the normalized value)
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(String) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the value (spaces and case are normalized)
+    pub fn set_value(&mut self, input: &str) {
+        let mut value = normalize_iban(input);
+        value.truncate(self.max_length(&value));
+        self.update(value);
+    }
+
+    /// Type a character
+    pub fn insert_char(&mut self, c: char) {
+        if !c.is_ascii_alphanumeric() {
+            return;
+        }
+        let mut value = self.value.get();
+        value.push(c.to_ascii_uppercase());
+        if value.len() <= self.max_length(&value) {
+            self.update(value);
+        }
+    }
+
+    /// Remove the last character
+    pub fn backspace(&mut self) {
+        let mut value = self.value.get();
+        if value.pop().is_some() {
+            self.update(value);
+        }
+    }
+
+    /// Clear the input
+    pub fn clear(&mut self) {
+        self.update(String::new());
+    }
+
+    fn max_length(&self, value: &str) -> usize {
+        value.get(..2).and_then(iban_length).unwrap_or(34)
+    }
+
+    fn update(&mut self, value: String) {
+        let result = validate_iban(&value);
+        let complete = value.len() >= self.max_length(&value);
+
+        self.is_valid.set(result.is_ok());
+        // Only report errors once the user has typed a full IBAN
+        self.error.set(match result {
+            Err(message) if complete => Some(message),
+            _ => None,
+        });
+        self.value.set(value.clone());
+
+        if let Some(ref callback) = self.on_change {
+            callback(value);
+        }
+    }
+
+    /// Normalized value
+    pub fn get_value(&self) -> String {
+        self.value.get()
+    }
+
+    /// Value grouped in blocks of four
+    pub fn formatted(&self) -> String {
+        format_iban(&self.value.get())
+    }
+
+    /// Country code, once two letters have been typed
+    pub fn country(&self) -> Option<String> {
+        let value = self.value.get();
+        value
+            .get(..2)
+            .filter(|code| code.chars().all(|c| c.is_ascii_uppercase()))
+            .map(|code| code.to_string())
+    }
+
+    /// Is the IBAN valid?
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.get()
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let style = taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Length(self.width),
+                height: taffy::style::Dimension::Length(self.height),
+            },
+            ..Default::default()
+        };
+
+        let node = engine
+            .new_leaf(style)
+            .map_err(|e| format!("Failed to create IBAN input node: {:?}", e))?;
+        self.node_id = Some(node);
+
+        Ok(node)
+    }
+}
+
+impl Default for IbanInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iban_validation() {
+        assert!(validate_iban("DE89 3704 0044 0532 0130 00").is_ok());
+        assert!(validate_iban("GB82WEST12345698765432").is_ok());
+        assert!(validate_iban("fr14 2004 1010 0505 0001 3m02 606").is_ok());
+        assert!(validate_iban("DE89 3704 0044 0532 0130 01").is_err());
+        assert!(validate_iban("DE89 3704").is_err());
+        assert!(validate_iban("XX00").is_err());
+    }
+
+    #[test]
+    fn iban_formatting() {
+        assert_eq!(format_iban("de89370400440532013000"), "DE89 3704 0044 0532 0130 00");
+        assert_eq!(format_iban("GB82 WE"), "GB82 WE");
+    }
+
+    #[test]
+    fn iban_input_validity_signal() {
+        let mut input = IbanInput::new();
+        input.set_value("GB82WEST1234569876543");
+        assert!(!input.is_valid());
+        assert_eq!(input.error.get(), None);
+
+        input.insert_char('2');
+        assert!(input.is_valid());
+        assert_eq!(input.country(), Some("GB".to_string()));
+
+        input.backspace();
+        input.insert_char('3');
+        assert!(!input.is_valid());
+        assert!(input.error.get().is_some());
+    }
+
+    #[test]
+    fn iban_input_limits_length() {
+        let mut input = IbanInput::new();
+        input.set_value("NO9386011117947999");
+        assert_eq!(input.get_value(), "NO9386011117947");
+        assert!(input.is_valid());
+        input.insert_char('1');
+        assert_eq!(input.get_value().len(), 15);
+    }
+}
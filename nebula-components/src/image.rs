@@ -4,6 +4,7 @@ use tracing::{info, warn, error};
 use std::path::PathBuf;
 use image::DynamicImage;
 use crate::image_cache::ImageCache;
+use crate::placeholder::{CrossFade, ImagePlaceholder, PlaceholderImage};
 use std::cell::RefCell;
 
 thread_local! {
@@ -42,6 +43,10 @@ pub struct Image {
     pub height: Option<f32>,
     /// Position
     pub position: (f32, f32),
+    /// Low-quality preview shown until the image loads
+    pub placeholder: Option<PlaceholderImage>,
+    /// Fade from placeholder to image
+    pub fade: CrossFade,
 }
 
 /// Image source
@@ -97,6 +102,8 @@ impl Image {
             width: None,
             height: None,
             position: (0.0, 0.0),
+            placeholder: None,
+            fade: CrossFade::default(),
         }
     }
 
@@ -114,6 +121,8 @@ impl Image {
             width: None,
             height: None,
             position: (0.0, 0.0),
+            placeholder: None,
+            fade: CrossFade::default(),
         }
     }
 
@@ -131,6 +140,8 @@ impl Image {
             width: None,
             height: None,
             position: (0.0, 0.0),
+            placeholder: None,
+            fade: CrossFade::default(),
         }
     }
 
@@ -147,6 +158,8 @@ impl Image {
             width: None,
             height: None,
             position: (0.0, 0.0),
+            placeholder: None,
+            fade: CrossFade::default(),
         }
     }
 
@@ -181,6 +194,32 @@ impl Image {
         self
     }
 
+    /// Show a placeholder (BlurHash, tiny preview or color) until the image loads
+    /// It's decoded on a background thread, so it never holds up the frame
+    pub fn placeholder(mut self, placeholder: ImagePlaceholder) -> Self {
+        self.placeholder = Some(PlaceholderImage::start(placeholder, 32, 32));
+        self
+    }
+
+    /// Decoded placeholder, if any and ready
+    pub fn placeholder_image(&self) -> Option<DynamicImage> {
+        self.placeholder.as_ref().and_then(PlaceholderImage::image)
+    }
+
+    /// Opacity to draw the full image with (fades in after loading)
+    pub fn image_opacity(&self) -> f32 {
+        match (self.is_loaded(), &self.placeholder) {
+            (false, _) => 0.0,
+            (true, None) => 1.0,
+            (true, Some(_)) => self.fade.progress(),
+        }
+    }
+
+    /// Opacity to draw the placeholder with (0 once the image has faded in)
+    pub fn placeholder_opacity(&self) -> f32 {
+        if self.placeholder.is_some() { 1.0 - self.image_opacity() } else { 0.0 }
+    }
+
     /// Load the image - NOW WITH CACHING! 🚀🎨
     /// Supports PNG, JPEG, GIF, BMP, ICO, TIFF, WebP, and more!
     /// Uses a thread-local cache to avoid reloading the same image!
@@ -209,6 +248,7 @@ impl Image {
                     self.decoded_image = Some(img);
                     self.actual_dimensions = Some((width, height));
                     self.state = ImageState::Loaded;
+                    self.fade.start();
                     return Ok(());
                 }
                 
@@ -227,6 +267,7 @@ impl Image {
                         self.decoded_image = Some(img);
                         self.actual_dimensions = Some((width, height));
                         self.state = ImageState::Loaded;
+                        self.fade.start();
                         Ok(())
                     }
                     Err(e) => {
@@ -254,6 +295,7 @@ impl Image {
                     self.decoded_image = Some(img);
                     self.actual_dimensions = Some((width, height));
                     self.state = ImageState::Loaded;
+                    self.fade.start();
                     return Ok(());
                 }
                 
@@ -275,6 +317,7 @@ impl Image {
                         self.decoded_image = Some(img);
                         self.actual_dimensions = Some((width, height));
                         self.state = ImageState::Loaded;
+                        self.fade.start();
                         Ok(())
                     }
                     Err(e) => {
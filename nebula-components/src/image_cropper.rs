@@ -0,0 +1,473 @@
+// ImageCropper Component - Crop rectangle over an image, with aspect lock and rotation
+// Produces the cropped DynamicImage (e.g. for avatar uploads)
+
+use crate::image::Image;
+use crate::zoom_view::ZoomView;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use nebula_gfx::draw_list::Rect;
+use tracing::info;
+
+/// Aspect ratio constraint for the crop rectangle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CropAspect {
+    /// Any shape
+    Free,
+    /// 1:1 (avatars)
+    Square,
+    /// Same shape as the image
+    Original,
+    /// Width : height
+    Ratio(f32, f32),
+}
+
+impl CropAspect {
+    /// Width / height ratio for an image of this size (None = free)
+    pub fn ratio(&self, image_width: f32, image_height: f32) -> Option<f32> {
+        match *self {
+            CropAspect::Free => None,
+            CropAspect::Square => Some(1.0),
+            CropAspect::Original => Some(image_width / image_height.max(1.0)),
+            CropAspect::Ratio(width, height) if width > 0.0 && height > 0.0 => Some(width / height),
+            CropAspect::Ratio(..) => None,
+        }
+    }
+}
+
+/// Part of the crop rectangle being dragged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CropHandle {
+    /// The inside: moves the whole rectangle
+    Move,
+    TopLeft,
+    Top,
+    TopRight,
+    Right,
+    BottomRight,
+    Bottom,
+    BottomLeft,
+    Left,
+}
+
+impl CropHandle {
+    fn moves_left(self) -> bool {
+        matches!(self, CropHandle::TopLeft | CropHandle::Left | CropHandle::BottomLeft)
+    }
+
+    fn moves_right(self) -> bool {
+        matches!(self, CropHandle::TopRight | CropHandle::Right | CropHandle::BottomRight)
+    }
+
+    fn moves_top(self) -> bool {
+        matches!(self, CropHandle::TopLeft | CropHandle::Top | CropHandle::TopRight)
+    }
+
+    fn moves_bottom(self) -> bool {
+        matches!(self, CropHandle::BottomLeft | CropHandle::Bottom | CropHandle::BottomRight)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CropDrag {
+    handle: CropHandle,
+    start: (f32, f32),
+    start_rect: Rect,
+}
+
+/// ImageCropper - Pick the part of an image to keep ✂️
+///
+/// - Drag inside the rectangle to move it, drag its edges/corners to resize
+/// - Optional aspect lock (square for avatars, original, or any ratio)
+/// - Rotate the image in 90° steps
+/// - `output` / `output_size` give the cropped `DynamicImage`
+///
+/// The crop rectangle is in pixels of the rotated image; the viewport
+/// shows the whole image fitted, so pointer input is mapped through a ZoomView.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut cropper = ImageCropper::from_image(&avatar)?
+///     .aspect(CropAspect::Square)
+///     .viewport(400.0, 400.0);
+///
+/// cropper.handle_mouse_down(x, y);
+/// cropper.handle_mouse_move(x + 20.0, y + 20.0);
+/// cropper.handle_mouse_up();
+///
+/// let avatar = cropper.output_size(256, 256)?;
+/// ```
+pub struct ImageCropper {
+    pub source: DynamicImage,
+    /// Clockwise rotation (0, 90, 180 or 270)
+    pub rotation: u16,
+    pub aspect: CropAspect,
+    /// Smallest crop, in image pixels
+    pub min_size: f32,
+    /// Grab distance around edges and corners, in viewport pixels
+    pub handle_size: f32,
+    pub zoom: ZoomView,
+    crop: Rect,
+    drag: Option<CropDrag>,
+}
+
+impl ImageCropper {
+    /// Create a cropper for a decoded image
+    pub fn new(source: DynamicImage) -> Self {
+        let (width, height) = (source.width() as f32, source.height() as f32);
+        let mut cropper = Self {
+            source,
+            rotation: 0,
+            aspect: CropAspect::Free,
+            min_size: 16.0,
+            handle_size: 12.0,
+            zoom: ZoomView::new(width, height, 400.0, 400.0),
+            crop: (0.0, 0.0, width, height),
+            drag: None,
+        };
+        cropper.reset_crop();
+        info!("✂️ Creating ImageCropper ({}x{})", width, height);
+        cropper
+    }
+
+    /// Create a cropper for a loaded Image
+    pub fn from_image(image: &Image) -> Result<Self, String> {
+        let decoded = image.get_decoded_image().ok_or("Image is not loaded")?;
+        Ok(Self::new(decoded.clone()))
+    }
+
+    /// Lock the crop shape (resets the rectangle)
+    pub fn aspect(mut self, aspect: CropAspect) -> Self {
+        self.set_aspect(aspect);
+        self
+    }
+
+    /// Set the viewport size
+    pub fn viewport(mut self, width: f32, height: f32) -> Self {
+        self.zoom.viewport_size = (width, height);
+        self.zoom.fit();
+        self
+    }
+
+    /// Set the smallest crop size (image pixels)
+    pub fn min_size(mut self, size: f32) -> Self {
+        self.min_size = size.max(1.0);
+        self
+    }
+
+    /// Change the aspect lock (resets the rectangle)
+    pub fn set_aspect(&mut self, aspect: CropAspect) {
+        self.aspect = aspect;
+        self.reset_crop();
+    }
+
+    /// Size of the rotated image
+    pub fn image_size(&self) -> (f32, f32) {
+        let (width, height) = (self.source.width() as f32, self.source.height() as f32);
+        if self.rotation % 180 == 90 {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Crop rectangle in image pixels (x, y, width, height)
+    pub fn crop_rect(&self) -> Rect {
+        self.crop
+    }
+
+    /// Crop rectangle in viewport pixels
+    pub fn crop_rect_in_viewport(&self) -> Rect {
+        let (x, y, width, height) = self.crop;
+        let (vx, vy) = self.zoom.to_viewport(x, y);
+        (vx, vy, width * self.zoom.scale, height * self.zoom.scale)
+    }
+
+    /// Where the image is drawn, in viewport pixels
+    pub fn image_rect(&self) -> Rect {
+        self.zoom.content_rect()
+    }
+
+    /// Set the crop rectangle (clamped to the image, aspect lock applied)
+    pub fn set_crop_rect(&mut self, rect: Rect) {
+        let (image_width, image_height) = self.image_size();
+        let (x, y, width, height) = rect;
+        let mut width = width.clamp(self.min_size.min(image_width), image_width);
+        let mut height = height.clamp(self.min_size.min(image_height), image_height);
+        if let Some(ratio) = self.aspect.ratio(image_width, image_height) {
+            height = width / ratio;
+            let scale = (image_height / height).min(1.0);
+            width *= scale;
+            height *= scale;
+        }
+        self.crop = (
+            x.clamp(0.0, image_width - width),
+            y.clamp(0.0, image_height - height),
+            width,
+            height,
+        );
+    }
+
+    /// Largest centered rectangle that fits the aspect lock
+    pub fn reset_crop(&mut self) {
+        let (image_width, image_height) = self.image_size();
+        let (width, height) = match self.aspect.ratio(image_width, image_height) {
+            Some(ratio) if image_width / image_height > ratio => (image_height * ratio, image_height),
+            Some(ratio) => (image_width, image_width / ratio),
+            None => (image_width, image_height),
+        };
+        self.crop = ((image_width - width) / 2.0, (image_height - height) / 2.0, width, height);
+    }
+
+    /// Rotate 90° clockwise (the crop rectangle turns with the image)
+    pub fn rotate_clockwise(&mut self) {
+        let (_, image_height) = self.image_size();
+        let (x, y, width, height) = self.crop;
+        self.rotation = (self.rotation + 90) % 360;
+        self.after_rotate((image_height - y - height, x, height, width));
+    }
+
+    /// Rotate 90° counter-clockwise
+    pub fn rotate_counter_clockwise(&mut self) {
+        let (image_width, _) = self.image_size();
+        let (x, y, width, height) = self.crop;
+        self.rotation = (self.rotation + 270) % 360;
+        self.after_rotate((y, image_width - x - width, height, width));
+    }
+
+    fn after_rotate(&mut self, crop: Rect) {
+        let (width, height) = self.image_size();
+        self.zoom.set_content_size(width, height);
+        self.drag = None;
+        self.crop = crop;
+        // A turned rectangle no longer matches a non-square lock
+        if let Some(ratio) = self.aspect.ratio(width, height) {
+            if (crop.2 / crop.3 - ratio).abs() > 0.01 {
+                self.reset_crop();
+            }
+        }
+    }
+
+    /// Which part of the crop rectangle is under a viewport point
+    pub fn handle_at(&self, x: f32, y: f32) -> Option<CropHandle> {
+        let (left, top, width, height) = self.crop_rect_in_viewport();
+        let (right, bottom) = (left + width, top + height);
+        let reach = self.handle_size;
+        if x < left - reach || x > right + reach || y < top - reach || y > bottom + reach {
+            return None;
+        }
+        let near_left = (x - left).abs() <= reach;
+        let near_right = (x - right).abs() <= reach;
+        let near_top = (y - top).abs() <= reach;
+        let near_bottom = (y - bottom).abs() <= reach;
+        Some(match (near_left, near_right, near_top, near_bottom) {
+            (true, _, true, _) => CropHandle::TopLeft,
+            (_, true, true, _) => CropHandle::TopRight,
+            (true, _, _, true) => CropHandle::BottomLeft,
+            (_, true, _, true) => CropHandle::BottomRight,
+            (true, _, _, _) => CropHandle::Left,
+            (_, true, _, _) => CropHandle::Right,
+            (_, _, true, _) => CropHandle::Top,
+            (_, _, _, true) => CropHandle::Bottom,
+            _ if x > left && x < right && y > top && y < bottom => CropHandle::Move,
+            _ => return None,
+        })
+    }
+
+    /// Handle mouse down
+    /// Returns true if a handle was grabbed
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> bool {
+        let Some(handle) = self.handle_at(x, y) else {
+            return false;
+        };
+        self.drag = Some(CropDrag { handle, start: (x, y), start_rect: self.crop });
+        true
+    }
+
+    /// Handle mouse move (moves or resizes while dragging)
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        let Some(drag) = self.drag.clone() else {
+            return;
+        };
+        let dx = (x - drag.start.0) / self.zoom.scale;
+        let dy = (y - drag.start.1) / self.zoom.scale;
+        self.crop = match drag.handle {
+            CropHandle::Move => self.moved(drag.start_rect, dx, dy),
+            handle => self.resized(handle, drag.start_rect, dx, dy),
+        };
+    }
+
+    /// Handle mouse up
+    pub fn handle_mouse_up(&mut self) {
+        self.drag = None;
+    }
+
+    /// Is a handle being dragged?
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Nudge the rectangle (arrow keys), in image pixels
+    pub fn move_by(&mut self, dx: f32, dy: f32) {
+        self.crop = self.moved(self.crop, dx, dy);
+    }
+
+    fn moved(&self, rect: Rect, dx: f32, dy: f32) -> Rect {
+        let (image_width, image_height) = self.image_size();
+        let (x, y, width, height) = rect;
+        ((x + dx).clamp(0.0, image_width - width), (y + dy).clamp(0.0, image_height - height), width, height)
+    }
+
+    fn resized(&self, handle: CropHandle, rect: Rect, dx: f32, dy: f32) -> Rect {
+        let (image_width, image_height) = self.image_size();
+        let min = self.min_size.min(image_width).min(image_height);
+        let (mut left, mut top, mut right, mut bottom) = (rect.0, rect.1, rect.0 + rect.2, rect.1 + rect.3);
+        if handle.moves_left() {
+            left = (left + dx).clamp(0.0, right - min);
+        }
+        if handle.moves_right() {
+            right = (right + dx).clamp(left + min, image_width);
+        }
+        if handle.moves_top() {
+            top = (top + dy).clamp(0.0, bottom - min);
+        }
+        if handle.moves_bottom() {
+            bottom = (bottom + dy).clamp(top + min, image_height);
+        }
+        let Some(ratio) = self.aspect.ratio(image_width, image_height) else {
+            return (left, top, right - left, bottom - top);
+        };
+
+        // Horizontal edges and corners follow the width; top/bottom follow the height
+        let horizontal = handle.moves_left() || handle.moves_right();
+        let vertical = handle.moves_top() || handle.moves_bottom();
+        let (mut width, mut height) = if horizontal {
+            (right - left, (right - left) / ratio)
+        } else {
+            ((bottom - top) * ratio, bottom - top)
+        };
+
+        // Shrink (keeping the ratio) until it fits beside the anchored edges
+        let (center_x, center_y) = (rect.0 + rect.2 / 2.0, rect.1 + rect.3 / 2.0);
+        let max_width = if handle.moves_left() {
+            right
+        } else if handle.moves_right() {
+            image_width - left
+        } else {
+            2.0 * center_x.min(image_width - center_x)
+        };
+        let max_height = if !vertical {
+            2.0 * center_y.min(image_height - center_y)
+        } else if handle.moves_top() {
+            bottom
+        } else {
+            image_height - top
+        };
+        let scale = (max_width / width).min(max_height / height).min(1.0);
+        width *= scale;
+        height *= scale;
+
+        let x = if handle.moves_left() {
+            right - width
+        } else if handle.moves_right() {
+            left
+        } else {
+            center_x - width / 2.0
+        };
+        let y = if !vertical {
+            center_y - height / 2.0
+        } else if handle.moves_top() {
+            bottom - height
+        } else {
+            top
+        };
+        (x, y, width, height)
+    }
+
+    /// Rotated source image
+    pub fn rotated(&self) -> DynamicImage {
+        match self.rotation {
+            90 => self.source.rotate90(),
+            180 => self.source.rotate180(),
+            270 => self.source.rotate270(),
+            _ => self.source.clone(),
+        }
+    }
+
+    /// The cropped image
+    pub fn output(&self) -> Result<DynamicImage, String> {
+        let (x, y, width, height) = self.crop;
+        let (x, y) = (x.round().max(0.0) as u32, y.round().max(0.0) as u32);
+        let (width, height) = (width.round() as u32, height.round() as u32);
+        if width == 0 || height == 0 {
+            return Err("Crop rectangle is empty".to_string());
+        }
+        Ok(self.rotated().crop_imm(x, y, width, height))
+    }
+
+    /// The cropped image scaled to an exact size (e.g. 256x256 avatars)
+    pub fn output_size(&self, width: u32, height: u32) -> Result<DynamicImage, String> {
+        if width == 0 || height == 0 {
+            return Err("Output size must not be zero".to_string());
+        }
+        Ok(self.output()?.resize_exact(width, height, FilterType::Lanczos3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cropper(width: u32, height: u32) -> ImageCropper {
+        ImageCropper::new(DynamicImage::new_rgba8(width, height)).viewport(width as f32, height as f32)
+    }
+
+    #[test]
+    fn square_crop_starts_centered() {
+        let cropper = cropper(400, 200).aspect(CropAspect::Square);
+        assert_eq!(cropper.crop_rect(), (100.0, 0.0, 200.0, 200.0));
+
+        let output = cropper.output_size(64, 64).unwrap();
+        assert_eq!((output.width(), output.height()), (64, 64));
+    }
+
+    #[test]
+    fn dragging_moves_and_resizes_with_aspect_lock() {
+        let mut cropper = cropper(400, 200).aspect(CropAspect::Square);
+
+        // Move: clamped to the image
+        assert_eq!(cropper.handle_at(200.0, 100.0), Some(CropHandle::Move));
+        assert!(cropper.handle_mouse_down(200.0, 100.0));
+        cropper.handle_mouse_move(500.0, 100.0);
+        cropper.handle_mouse_up();
+        assert_eq!(cropper.crop_rect(), (200.0, 0.0, 200.0, 200.0));
+
+        // Corner resize keeps the square anchored at the opposite corner
+        assert_eq!(cropper.handle_at(200.0, 0.0), Some(CropHandle::TopLeft));
+        cropper.handle_mouse_down(200.0, 0.0);
+        cropper.handle_mouse_move(300.0, 0.0);
+        cropper.handle_mouse_up();
+        assert_eq!(cropper.crop_rect(), (300.0, 100.0, 100.0, 100.0));
+
+        // Free aspect resizes one edge at a time
+        cropper.set_aspect(CropAspect::Free);
+        assert_eq!(cropper.crop_rect(), (0.0, 0.0, 400.0, 200.0));
+        cropper.handle_mouse_down(400.0, 100.0);
+        cropper.handle_mouse_move(250.0, 100.0);
+        assert_eq!(cropper.crop_rect(), (0.0, 0.0, 250.0, 200.0));
+    }
+
+    #[test]
+    fn rotation_turns_crop_and_output() {
+        let mut cropper = cropper(400, 200);
+        cropper.set_crop_rect((0.0, 0.0, 100.0, 50.0));
+        cropper.rotate_clockwise();
+        assert_eq!(cropper.image_size(), (200.0, 400.0));
+        assert_eq!(cropper.crop_rect(), (150.0, 0.0, 50.0, 100.0));
+        let output = cropper.output().unwrap();
+        assert_eq!((output.width(), output.height()), (50, 100));
+
+        cropper.rotate_counter_clockwise();
+        assert_eq!(cropper.rotation, 0);
+        assert_eq!(cropper.crop_rect(), (0.0, 0.0, 100.0, 50.0));
+    }
+}
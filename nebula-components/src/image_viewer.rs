@@ -0,0 +1,408 @@
+// ImageViewer Component - Full-screen lightbox for a gallery of images
+// Modal overlay + ZoomView: fit/1:1, zoom about the cursor, drag pan, rotation
+
+use crate::image::Image;
+use crate::modal::Modal;
+use crate::zoom_view::ZoomView;
+use image::DynamicImage;
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_gfx::draw_list::Rect;
+use tracing::{info, warn};
+
+/// Called with the image being downloaded
+pub type DownloadCallback = Box<dyn Fn(&Image)>;
+/// Called with the decoded image being copied
+pub type CopyCallback = Box<dyn Fn(&DynamicImage)>;
+
+/// ImageViewer - Lightbox for browsing images 🖼️
+///
+/// - Opens over everything on the modal overlay
+/// - Fit / 1:1 toggle, wheel and pinch zoom about the cursor, drag to pan
+/// - Arrow keys move through the gallery
+/// - Rotate in 90° steps, download or copy the current image
+///
+/// # Example
+/// ```rust,ignore
+/// let mut viewer = ImageViewer::new(vec![
+///     Image::from_file("beach.jpg"),
+///     Image::from_file("sunset.jpg"),
+/// ])
+/// .viewport(1280.0, 800.0)
+/// .on_copy(|image| clipboard.write_image(image));
+///
+/// viewer.open(1);
+/// viewer.handle_wheel(1.0, mouse_x, mouse_y);
+/// viewer.handle_arrow_right();
+/// ```
+pub struct ImageViewer {
+    pub node_id: Option<NodeId>,
+    pub modal: Modal,
+    pub images: Vec<Image>,
+    pub current: usize,
+    /// Clockwise rotation of the current image (0, 90, 180 or 270)
+    pub rotation: u16,
+    pub zoom: ZoomView,
+    /// Wrap from the last image back to the first
+    pub wrap_around: bool,
+    pub on_download: Option<DownloadCallback>,
+    pub on_copy: Option<CopyCallback>,
+    pub on_change: Option<Box<dyn Fn(usize)>>,
+}
+
+impl ImageViewer {
+    /// Create a viewer for a gallery (closed until `open`)
+    pub fn new(images: Vec<Image>) -> Self {
+        Self {
+            node_id: None,
+            modal: Modal::new().backdrop_color(0, 0, 0, 230),
+            images,
+            current: 0,
+            rotation: 0,
+            zoom: ZoomView::new(1.0, 1.0, 800.0, 600.0),
+            wrap_around: false,
+            on_download: None,
+            on_copy: None,
+            on_change: None,
+        }
+    }
+
+    /// Set the viewport size
+    pub fn viewport(mut self, width: f32, height: f32) -> Self {
+        self.zoom.set_viewport_size(width, height);
+        self
+    }
+
+    /// Wrap around at the ends of the gallery
+    pub fn wrap_around(mut self, wrap: bool) -> Self {
+        self.wrap_around = wrap;
+        self
+    }
+
+    /// Called with the current image when download is pressed
+    pub fn on_download<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Image) + 'static,
+    {
+        self.on_download = Some(Box::new(callback));
+        self
+    }
+
+    /// Called with the current image (rotation applied) when copy is pressed
+    pub fn on_copy<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&DynamicImage) + 'static,
+    {
+        self.on_copy = Some(Box::new(callback));
+        self
+    }
+
+    /// Called with the new index when the gallery moves
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Open the viewer on an image
+    pub fn open(&mut self, index: usize) {
+        if self.images.is_empty() {
+            return;
+        }
+        self.show_image(index.min(self.images.len() - 1));
+        self.modal.show();
+        info!("🖼️ ImageViewer opened at {}/{}", self.current + 1, self.images.len());
+    }
+
+    /// Close the viewer
+    pub fn close(&mut self) {
+        self.zoom.end_drag();
+        self.modal.hide();
+    }
+
+    /// Is the viewer showing?
+    pub fn is_open(&self) -> bool {
+        self.modal.is_visible()
+    }
+
+    /// Number of images
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    /// Is the gallery empty?
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Image being shown
+    pub fn current_image(&self) -> Option<&Image> {
+        self.images.get(self.current)
+    }
+
+    /// Show the next image
+    /// Returns false at the end (unless wrapping)
+    pub fn show_next(&mut self) -> bool {
+        let len = self.images.len();
+        if self.current + 1 < len {
+            self.go_to(self.current + 1)
+        } else if self.wrap_around && len > 1 {
+            self.go_to(0)
+        } else {
+            false
+        }
+    }
+
+    /// Show the previous image
+    /// Returns false at the start (unless wrapping)
+    pub fn show_previous(&mut self) -> bool {
+        let len = self.images.len();
+        if self.current > 0 {
+            self.go_to(self.current - 1)
+        } else if self.wrap_around && len > 1 {
+            self.go_to(len - 1)
+        } else {
+            false
+        }
+    }
+
+    /// Jump to an image
+    pub fn go_to(&mut self, index: usize) -> bool {
+        if index >= self.images.len() || index == self.current {
+            return false;
+        }
+        self.show_image(index);
+        if let Some(ref callback) = self.on_change {
+            callback(index);
+        }
+        true
+    }
+
+    /// Handle the left arrow key
+    pub fn handle_arrow_left(&mut self) -> bool {
+        self.is_open() && self.show_previous()
+    }
+
+    /// Handle the right arrow key
+    pub fn handle_arrow_right(&mut self) -> bool {
+        self.is_open() && self.show_next()
+    }
+
+    /// Handle escape key
+    pub fn handle_escape(&mut self) {
+        self.close();
+    }
+
+    /// Rotate 90° clockwise
+    pub fn rotate_clockwise(&mut self) {
+        self.rotation = (self.rotation + 90) % 360;
+        self.refit();
+    }
+
+    /// Rotate 90° counter-clockwise
+    pub fn rotate_counter_clockwise(&mut self) {
+        self.rotation = (self.rotation + 270) % 360;
+        self.refit();
+    }
+
+    /// Switch between fit and 1:1
+    pub fn toggle_fit(&mut self) {
+        self.zoom.toggle_fit();
+    }
+
+    /// Zoom in around the viewport center
+    pub fn zoom_in(&mut self) {
+        self.zoom.set_scale(self.zoom.scale * 1.25);
+    }
+
+    /// Zoom out around the viewport center
+    pub fn zoom_out(&mut self) {
+        self.zoom.set_scale(self.zoom.scale / 1.25);
+    }
+
+    /// Resize the viewport (window resized)
+    pub fn set_viewport_size(&mut self, width: f32, height: f32) {
+        self.zoom.set_viewport_size(width, height);
+    }
+
+    /// Mouse wheel zoom about the cursor
+    pub fn handle_wheel(&mut self, delta: f32, x: f32, y: f32) {
+        self.zoom.handle_wheel(delta, x, y);
+    }
+
+    /// Pinch zoom about the fingers' center
+    pub fn handle_pinch(&mut self, scale: f32, center_x: f32, center_y: f32) {
+        self.zoom.handle_pinch(scale, center_x, center_y);
+    }
+
+    /// Double click: 1:1 at the cursor, or back to fit
+    pub fn handle_double_click(&mut self, x: f32, y: f32) {
+        if self.zoom.is_fitted() {
+            self.zoom.zoom_at(1.0 / self.zoom.scale, x, y);
+        } else {
+            self.zoom.fit();
+        }
+    }
+
+    /// Handle mouse down (starts a pan, or closes on the backdrop)
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) {
+        let (ix, iy, width, height) = self.image_rect();
+        if x >= ix && x < ix + width && y >= iy && y < iy + height {
+            self.zoom.begin_drag(x, y);
+        } else {
+            self.modal.handle_backdrop_click();
+        }
+    }
+
+    /// Handle mouse move (pans while dragging)
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        self.zoom.drag_to(x, y);
+    }
+
+    /// Handle mouse up
+    pub fn handle_mouse_up(&mut self) {
+        self.zoom.end_drag();
+    }
+
+    /// Where the (rotated) image is drawn, in viewport pixels
+    pub fn image_rect(&self) -> Rect {
+        self.zoom.content_rect()
+    }
+
+    /// Current image with the rotation applied
+    pub fn rotated_image(&self) -> Option<DynamicImage> {
+        let image = self.current_image()?.get_decoded_image()?;
+        Some(match self.rotation {
+            90 => image.rotate90(),
+            180 => image.rotate180(),
+            270 => image.rotate270(),
+            _ => image.clone(),
+        })
+    }
+
+    /// Download the current image
+    pub fn download(&self) -> bool {
+        let (Some(callback), Some(image)) = (&self.on_download, self.current_image()) else {
+            return false;
+        };
+        callback(image);
+        true
+    }
+
+    /// Copy the current image (rotation applied)
+    pub fn copy(&self) -> Result<(), String> {
+        let callback = self.on_copy.as_ref().ok_or("No copy handler")?;
+        let image = self.rotated_image().ok_or("Image is not loaded")?;
+        callback(&image);
+        Ok(())
+    }
+
+    /// Build the overlay layout
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = self.modal.build(engine)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn show_image(&mut self, index: usize) {
+        self.current = index;
+        self.rotation = 0;
+        self.zoom.end_drag();
+        let image = &mut self.images[index];
+        if !image.is_loaded() && !image.is_error() {
+            if let Err(e) = image.load() {
+                warn!("⚠️ ImageViewer could not load image {}: {}", index, e);
+            }
+        }
+        self.refit();
+    }
+
+    fn refit(&mut self) {
+        let (width, height) = self.current_image().map_or((1.0, 1.0), image_size);
+        let (width, height) = if self.rotation % 180 == 90 { (height, width) } else { (width, height) };
+        self.zoom.set_content_size(width, height);
+    }
+}
+
+/// Pixel size of an image, falling back to its layout size
+fn image_size(image: &Image) -> (f32, f32) {
+    match image.get_dimensions() {
+        Some((width, height)) => (width as f32, height as f32),
+        None => (image.width.unwrap_or(1.0), image.height.unwrap_or(1.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::io::Cursor;
+    use std::rc::Rc;
+
+    fn png(width: u32, height: u32) -> Image {
+        let mut bytes = Vec::new();
+        DynamicImage::new_rgba8(width, height)
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+        Image::from_memory(bytes)
+    }
+
+    #[test]
+    fn viewer_navigates_gallery() {
+        let changes = Rc::new(Cell::new(0));
+        let counter = changes.clone();
+        let mut viewer = ImageViewer::new(vec![png(40, 20), png(20, 40), png(10, 10)])
+            .viewport(400.0, 400.0)
+            .on_change(move |_| counter.set(counter.get() + 1));
+        assert!(!viewer.handle_arrow_right());
+
+        viewer.open(1);
+        assert!(viewer.is_open());
+        assert!(viewer.images[1].is_loaded());
+        assert_eq!(viewer.image_rect(), (100.0, 0.0, 200.0, 400.0));
+
+        assert!(viewer.handle_arrow_right());
+        assert!(!viewer.show_next());
+        assert!(viewer.handle_arrow_left());
+        assert_eq!(viewer.current, 1);
+        assert_eq!(changes.get(), 2);
+
+        viewer.handle_escape();
+        assert!(!viewer.is_open());
+    }
+
+    #[test]
+    fn viewer_rotates_and_copies() {
+        let copied = Rc::new(Cell::new((0, 0)));
+        let sink = copied.clone();
+        let mut viewer = ImageViewer::new(vec![png(40, 20)])
+            .viewport(400.0, 400.0)
+            .on_copy(move |image| sink.set((image.width(), image.height())));
+        viewer.open(0);
+        assert_eq!(viewer.image_rect(), (0.0, 100.0, 400.0, 200.0));
+
+        viewer.rotate_clockwise();
+        assert_eq!(viewer.rotation, 90);
+        assert_eq!(viewer.image_rect(), (100.0, 0.0, 200.0, 400.0));
+        viewer.copy().unwrap();
+        assert_eq!(copied.get(), (20, 40));
+
+        viewer.rotate_counter_clockwise();
+        assert_eq!(viewer.rotation, 0);
+        assert!(!viewer.download());
+    }
+
+    #[test]
+    fn viewer_zooms_and_closes_on_backdrop() {
+        let mut viewer = ImageViewer::new(vec![png(40, 20)]).viewport(400.0, 400.0);
+        viewer.open(0);
+        viewer.toggle_fit();
+        assert_eq!(viewer.zoom.scale, 1.0);
+        viewer.handle_double_click(200.0, 200.0);
+        assert!(viewer.zoom.is_fitted());
+
+        viewer.handle_mouse_down(200.0, 10.0);
+        assert!(!viewer.is_open());
+    }
+}
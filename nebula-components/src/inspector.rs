@@ -0,0 +1,378 @@
+// Inspector - Devtools overlay for a running WidgetTree
+// Pick a widget, compare it against a baseline screenshot, and watch running animations
+
+use std::path::{Path, PathBuf};
+
+use nebula_core::animation::AnimationClock;
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::theme::ThemeProvider;
+use nebula_gfx::draw_list::{DrawList, Rect};
+use nebula_gfx::{ImageDiff, RgbaImage};
+use tracing::info;
+
+use super::widget::{Widget, WidgetEvent, WidgetId, WidgetTree};
+
+/// Slow-motion speeds the animation panel cycles through
+const SPEEDS: [f32; 5] = [1.0, 0.5, 0.25, 0.1, 2.0];
+
+/// Outcome of "compare against baseline"
+#[derive(Debug, Clone)]
+pub struct BaselineComparison {
+    /// Baseline name (file stem under the baseline directory)
+    pub name: String,
+    /// Compared region in logical window pixels
+    pub region: Rect,
+    pub diff: ImageDiff,
+    /// Capture faded to gray with changed pixels in hot magenta
+    pub highlight: RgbaImage,
+}
+
+/// Inspector - Devtools for a WidgetTree 🔍
+///
+/// # Example
+/// ```rust,ignore
+/// let mut inspector = Inspector::new("tests/baselines").scale_factor(window.scale_factor() as f32);
+/// inspector.pick(&tree, mouse_x, mouse_y);
+/// let frame = renderer.capture_frame()?;
+/// let result = inspector.compare_against_baseline(&frame, &tree, "checkout")?;
+/// println!("{} pixels changed", result.diff.changed_pixels());
+/// inspector.paint_overlay(&tree, &mut list); // changed pixels glow over the window
+/// ```
+pub struct Inspector {
+    pub selected: Option<WidgetId>,
+    /// Where baselines live (one PNG per name)
+    pub baseline_dir: PathBuf,
+    /// Per-channel difference still counted as equal
+    pub tolerance: u8,
+    /// Physical pixels per logical pixel (captures are physical)
+    pub scale_factor: f32,
+    pub outline_color: (u8, u8, u8, u8),
+    pub highlight_color: (u8, u8, u8, u8),
+    pub last_comparison: Option<BaselineComparison>,
+}
+
+impl Inspector {
+    /// Create an inspector storing baselines in `baseline_dir`
+    pub fn new(baseline_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            selected: None,
+            baseline_dir: baseline_dir.into(),
+            tolerance: 2,
+            scale_factor: 1.0,
+            outline_color: (0, 150, 255, 255),
+            highlight_color: (255, 0, 170, 160),
+            last_comparison: None,
+        }
+    }
+
+    /// Set the per-channel tolerance
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the scale factor of the captured window
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor.max(0.01);
+        self
+    }
+
+    /// Select a widget (None = the whole window)
+    pub fn select(&mut self, id: Option<WidgetId>) {
+        self.selected = id;
+    }
+
+    /// Select the widget under a point
+    pub fn pick(&mut self, tree: &WidgetTree, x: f32, y: f32) -> Option<WidgetId> {
+        self.selected = tree.hit_test(x, y);
+        self.selected
+    }
+
+    /// Region being inspected: the selection's bounds, or the whole frame
+    pub fn region(&self, frame: &RgbaImage, tree: &WidgetTree) -> Rect {
+        self.selected.and_then(|id| tree.bounds(id)).unwrap_or((
+            0.0,
+            0.0,
+            frame.width as f32 / self.scale_factor,
+            frame.height as f32 / self.scale_factor,
+        ))
+    }
+
+    /// Cut the inspected region out of a captured frame
+    pub fn capture(&self, frame: &RgbaImage, tree: &WidgetTree) -> RgbaImage {
+        let (x, y, width, height) = self.region(frame, tree);
+        let scale = self.scale_factor;
+        frame.crop(
+            (x * scale).round() as u32,
+            (y * scale).round() as u32,
+            (width * scale).round() as u32,
+            (height * scale).round() as u32,
+        )
+    }
+
+    /// Path of a named baseline
+    pub fn baseline_path(&self, name: &str) -> PathBuf {
+        self.baseline_dir.join(format!("{}.png", name))
+    }
+
+    /// Store the inspected region as the new baseline
+    pub fn save_baseline(&self, frame: &RgbaImage, tree: &WidgetTree, name: &str) -> Result<PathBuf, String> {
+        std::fs::create_dir_all(&self.baseline_dir)
+            .map_err(|e| format!("Failed to create {}: {}", self.baseline_dir.display(), e))?;
+        let path = self.baseline_path(name);
+        self.capture(frame, tree).save_png(&path)?;
+        Ok(path)
+    }
+
+    /// Diff the inspected region against the stored baseline
+    pub fn compare_against_baseline(&mut self, frame: &RgbaImage, tree: &WidgetTree, name: &str) -> Result<&BaselineComparison, String> {
+        let baseline = load_png(&self.baseline_path(name))?;
+        let capture = self.capture(frame, tree);
+        let diff = capture.diff(&baseline, self.tolerance)?;
+        info!("🔍 {}: {} pixels changed ({:.2}%)", name, diff.changed_pixels(), diff.changed_ratio() * 100.0);
+        let highlight = diff.highlight(&capture);
+        Ok(self.last_comparison.insert(BaselineComparison {
+            name: name.to_string(),
+            region: self.region(frame, tree),
+            diff,
+            highlight,
+        }))
+    }
+
+    /// Forget the last comparison (hides its highlight)
+    pub fn clear_comparison(&mut self) {
+        self.last_comparison = None;
+    }
+
+    /// Draw the selection outline and the changed pixels of the last comparison
+    pub fn paint_overlay(&self, tree: &WidgetTree, list: &mut DrawList) {
+        if let Some(comparison) = &self.last_comparison {
+            let (x, y, _, _) = comparison.region;
+            let pixel = 1.0 / self.scale_factor;
+            for (run_x, run_y, length) in comparison.diff.runs() {
+                list.rect((x + run_x as f32 * pixel, y + run_y as f32 * pixel, length as f32 * pixel, pixel), self.highlight_color);
+            }
+        }
+        if let Some(bounds) = self.selected.and_then(|id| tree.bounds(id)) {
+            outline(list, bounds, self.outline_color);
+        }
+    }
+}
+
+/// AnimationPanel - Running animations with slow-motion and pause/step 🐢
+///
+/// The header holds three controls: pause/resume, step one frame (while
+/// paused) and the speed (click to cycle 1× → 0.5× → 0.25× → 0.1× → 2×).
+/// Below, one row per spring that moved last frame: its label, value →
+/// target, spring params, settle time and a progress bar.
+///
+/// Creating the panel turns on `AnimationClock` recording.
+///
+/// # Example
+/// ```rust,ignore
+/// if cfg!(debug_assertions) {
+///     tree.append_child(root, AnimationPanel::new());
+/// }
+/// ```
+pub struct AnimationPanel {
+    pub width: f32,
+    pub row_height: f32,
+    /// Rows reserved below the header
+    pub max_rows: usize,
+}
+
+impl AnimationPanel {
+    /// Create the panel (and start recording animations)
+    pub fn new() -> Self {
+        AnimationClock::set_recording(true);
+        Self { width: 420.0, row_height: 28.0, max_rows: 8 }
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the number of animation rows shown
+    pub fn max_rows(mut self, rows: usize) -> Self {
+        self.max_rows = rows;
+        self
+    }
+
+    /// Pause or resume every animation
+    pub fn toggle_pause(&self) {
+        AnimationClock::set_paused(!AnimationClock::is_paused());
+    }
+
+    /// Advance one frame (only meaningful while paused)
+    pub fn step(&self) {
+        AnimationClock::step();
+    }
+
+    /// Switch to the next slow-motion speed; returns it
+    pub fn cycle_speed(&self) -> f32 {
+        let current = AnimationClock::time_scale();
+        let index = SPEEDS.iter().position(|&speed| (speed - current).abs() < 0.001).map_or(0, |index| index + 1);
+        let speed = SPEEDS[index % SPEEDS.len()];
+        AnimationClock::set_time_scale(speed);
+        speed
+    }
+
+    /// Header control rects: pause, step, speed
+    fn controls(&self, bounds: Rect) -> [Rect; 3] {
+        let size = self.row_height - 6.0;
+        let button = |index: usize, width: f32| (bounds.0 + 4.0 + index as f32 * (size * 2.0 + 4.0), bounds.1 + 3.0, width, size);
+        [button(0, size * 2.0), button(1, size * 2.0), button(2, size * 2.0)]
+    }
+}
+
+impl Default for AnimationPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for AnimationPanel {
+    fn build_node(&mut self, engine: &mut LayoutEngine, _children: &[NodeId]) -> Result<NodeId, String> {
+        engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.width),
+                    height: taffy::style::Dimension::Length((self.max_rows + 1) as f32 * self.row_height),
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create animation panel node: {:?}", e))
+    }
+
+    fn paint(&self, bounds: Rect, list: &mut DrawList) {
+        let theme = ThemeProvider::current();
+        let font_size = theme.font_size * 0.85;
+        list.rounded_rect(bounds, theme.radius.md, theme.colors.surface);
+
+        let paused = AnimationClock::is_paused();
+        let labels = [
+            if paused { "▶".to_string() } else { "⏸".to_string() },
+            "⏭".to_string(),
+            format!("{}×", AnimationClock::time_scale()),
+        ];
+        for (rect, label) in self.controls(bounds).into_iter().zip(labels) {
+            list.rounded_rect(rect, theme.radius.md, theme.colors.surface_variant);
+            list.text(label, rect.0 + 6.0, rect.1 + (rect.3 - font_size * 1.2) / 2.0, font_size, theme.colors.text);
+        }
+
+        let running = AnimationClock::running();
+        if running.is_empty() {
+            list.text("No running animations", bounds.0 + 8.0, bounds.1 + self.row_height + 6.0, font_size, theme.colors.text_muted);
+        }
+        for (index, info) in running.iter().take(self.max_rows).enumerate() {
+            let y = bounds.1 + (index + 1) as f32 * self.row_height;
+            let line = format!(
+                "{}  {:.1} → {:.1}  k={:.0} c={:.0}  {}ms",
+                info.label,
+                info.value,
+                info.target,
+                info.stiffness,
+                info.damping,
+                info.duration.as_millis()
+            );
+            list.text(line, bounds.0 + 8.0, y + 4.0, font_size, theme.colors.text);
+            let track = (bounds.0 + 8.0, y + self.row_height - 6.0, bounds.2 - 16.0, 3.0);
+            list.rect(track, theme.colors.border);
+            list.rect((track.0, track.1, track.2 * info.progress, track.3), theme.colors.primary);
+        }
+    }
+
+    fn event(&mut self, event: &WidgetEvent, bounds: Rect) -> bool {
+        let WidgetEvent::PointerUp { x, y } = *event else {
+            return false;
+        };
+        let hit = |rect: Rect| x >= rect.0 && x <= rect.0 + rect.2 && y >= rect.1 && y <= rect.1 + rect.3;
+        match self.controls(bounds).into_iter().position(hit) {
+            Some(0) => self.toggle_pause(),
+            Some(1) => self.step(),
+            Some(_) => {
+                self.cycle_speed();
+            }
+            None => return false,
+        }
+        true
+    }
+}
+
+fn load_png(path: &Path) -> Result<RgbaImage, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("No baseline at {}: {}", path.display(), e))?;
+    RgbaImage::decode_png(&bytes)
+}
+
+/// 1px frame around a rect
+fn outline(list: &mut DrawList, (x, y, width, height): Rect, color: (u8, u8, u8, u8)) {
+    list.rect((x, y, width, 1.0), color);
+    list.rect((x, y + height - 1.0, width, 1.0), color);
+    list.rect((x, y, 1.0, height), color);
+    list.rect((x + width - 1.0, y, 1.0, height), color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Spacer, VStack};
+    use nebula_gfx::DrawCommand;
+
+    #[test]
+    fn compares_a_selected_widget_against_its_baseline() {
+        let mut tree = WidgetTree::new();
+        let root = tree.insert(VStack::new().padding(2.0));
+        let card = tree.append_child(root, Spacer::fixed(4.0, 2.0));
+        tree.set_root(root);
+        tree.layout(10.0, 10.0).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("nebula-inspector-{}", std::process::id()));
+        let mut inspector = Inspector::new(&dir).scale_factor(2.0);
+        let mut frame = RgbaImage::new(20, 20);
+        assert!(inspector.compare_against_baseline(&frame, &tree, "card").is_err());
+
+        assert_eq!(inspector.pick(&tree, 3.0, 3.0), Some(card));
+        inspector.save_baseline(&frame, &tree, "card").unwrap();
+        frame.pixels[(5 * 20 + 5) * 4] = 255; // Inside the card (starts at 4, 4 physical)
+        frame.pixels[(15 * 20 + 15) * 4] = 255; // Outside it
+        let comparison = inspector.compare_against_baseline(&frame, &tree, "card").unwrap();
+        assert_eq!(comparison.diff.changed_pixels(), 1);
+        assert_eq!(comparison.diff.bounds(), Some((1, 1, 1, 1)));
+
+        let mut list = DrawList::new();
+        inspector.paint_overlay(&tree, &mut list);
+        assert_eq!(list.len(), 5); // One changed run plus the outline
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn animation_panel_controls_the_clock() {
+        use nebula_core::animation::SpringAnimation;
+
+        let mut panel = AnimationPanel::new();
+        let bounds = (0.0, 0.0, 420.0, 252.0);
+        let mut spring = SpringAnimation::new(0.0, 1.0).label("opacity");
+        spring.update(1.0 / 60.0);
+        AnimationClock::end_frame();
+
+        let mut list = DrawList::new();
+        panel.paint(bounds, &mut list);
+        assert!(list.commands().any(|command| matches!(command, DrawCommand::Text { text, .. } if text.starts_with("opacity"))));
+
+        // Speed button, then pause
+        let [pause, _, speed] = panel.controls(bounds);
+        assert!(panel.event(&WidgetEvent::PointerUp { x: speed.0 + 1.0, y: speed.1 + 1.0 }, bounds));
+        assert_eq!(AnimationClock::time_scale(), 0.5);
+        panel.event(&WidgetEvent::PointerUp { x: pause.0 + 1.0, y: pause.1 + 1.0 }, bounds);
+        let value = spring.value();
+        spring.update(1.0 / 60.0);
+        assert_eq!(spring.value(), value);
+
+        panel.toggle_pause();
+        while panel.cycle_speed() != 1.0 {}
+        AnimationClock::set_recording(false);
+    }
+}
@@ -0,0 +1,152 @@
+// Language Client - What a CodeEditor needs from a language server
+// Transport-agnostic: wrap stdio LSP, a WASM analyzer or a test stub behind one trait
+
+use crate::code_editor::TextPosition;
+
+/// How serious a diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+impl DiagnosticSeverity {
+    /// Underline / gutter color
+    pub fn color(&self) -> (u8, u8, u8, u8) {
+        match self {
+            DiagnosticSeverity::Error => (244, 71, 71, 255), // Red
+            DiagnosticSeverity::Warning => (205, 173, 0, 255), // Yellow
+            DiagnosticSeverity::Information => (55, 148, 255, 255), // Blue
+            DiagnosticSeverity::Hint => (150, 150, 150, 255), // Gray
+        }
+    }
+}
+
+/// An error or warning over a range of the buffer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub start: TextPosition,
+    pub end: TextPosition,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+    /// Who reported it ("rustc", "clippy"…)
+    pub source: Option<String>,
+}
+
+impl Diagnostic {
+    /// Create a diagnostic
+    pub fn new(start: TextPosition, end: TextPosition, severity: DiagnosticSeverity, message: impl Into<String>) -> Self {
+        Self { start, end, severity, message: message.into(), source: None }
+    }
+
+    /// Set the source
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Does the range cover `position`?
+    pub fn contains(&self, position: TextPosition) -> bool {
+        self.start <= position && position <= self.end
+    }
+}
+
+/// Hover documentation for a symbol
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoverInfo {
+    /// Plain text (markdown is shown as-is)
+    pub contents: String,
+    /// Range the hover applies to
+    pub range: Option<(TextPosition, TextPosition)>,
+}
+
+impl HoverInfo {
+    /// Create hover info
+    pub fn new(contents: impl Into<String>) -> Self {
+        Self { contents: contents.into(), range: None }
+    }
+}
+
+/// One entry in the completion list
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionItem {
+    pub label: String,
+    /// Inserted instead of the label when set
+    pub insert_text: Option<String>,
+    /// Short type or signature shown next to the label
+    pub detail: Option<String>,
+}
+
+impl CompletionItem {
+    /// Create an item that inserts its label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self { label: label.into(), insert_text: None, detail: None }
+    }
+
+    /// Set the inserted text
+    pub fn insert_text(mut self, text: impl Into<String>) -> Self {
+        self.insert_text = Some(text.into());
+        self
+    }
+
+    /// Set the detail
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Text to insert
+    pub fn text(&self) -> &str {
+        self.insert_text.as_deref().unwrap_or(&self.label)
+    }
+}
+
+/// A place in a document (go-to-definition target)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    /// Other document, or None for the open one
+    pub uri: Option<String>,
+    pub start: TextPosition,
+    pub end: TextPosition,
+}
+
+impl Location {
+    /// A range in the open document
+    pub fn here(start: TextPosition, end: TextPosition) -> Self {
+        Self { uri: None, start, end }
+    }
+
+    /// A range in another document
+    pub fn in_document(uri: impl Into<String>, start: TextPosition, end: TextPosition) -> Self {
+        Self { uri: Some(uri.into()), start, end }
+    }
+}
+
+/// LanguageClient - Bridge from a CodeEditor to a language server 🧠
+///
+/// Every method has a default, so implement only what the server supports.
+/// Requests answer synchronously; a client talking to a server in the
+/// background can return nothing and later push the answer with
+/// `CodeEditor::show_hover` / `show_completions`. Diagnostics always arrive
+/// by push (`CodeEditor::set_diagnostics`), like LSP's publishDiagnostics.
+pub trait LanguageClient {
+    /// The buffer changed (`version` goes up on every edit)
+    fn did_change(&mut self, _text: &str, _version: u64) {}
+
+    /// Documentation for the symbol at `position`
+    fn hover(&mut self, _position: TextPosition) -> Option<HoverInfo> {
+        None
+    }
+
+    /// Completions at `position`
+    fn completions(&mut self, _position: TextPosition) -> Vec<CompletionItem> {
+        Vec::new()
+    }
+
+    /// Where the symbol at `position` is defined
+    fn definition(&mut self, _position: TextPosition) -> Option<Location> {
+        None
+    }
+}
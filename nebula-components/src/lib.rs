@@ -37,19 +37,39 @@ pub mod textfield;
 pub mod grid;
 pub mod image;
 pub mod image_cache;
+pub mod placeholder;
+pub mod zoom_view;
+pub mod image_viewer;
+pub mod image_cropper;
+pub mod region_picker;
+#[cfg(feature = "camera")]
+pub mod camera;
+#[cfg(feature = "camera")]
+pub mod code_scanner;
 pub mod scroll;
+pub mod scroll_memory;
 pub mod modal;
 pub mod dialog;
+pub mod lock_screen;
 pub mod dropdown;
 pub mod select;
 pub mod tooltip;
 pub mod toast;
 pub mod context_menu;
+pub mod radial_menu;
 pub mod popover;
 pub mod menubar;
+pub mod toolbar;
+pub mod titlebar;
+pub mod shortcuts;
+pub mod events;
+pub mod command_palette;
+pub mod feature_flags;
+pub mod inspector;
 pub mod tabs;
 pub mod progress_bar;
 pub mod spinner;
+pub mod nav_stack;
 pub mod navigation;
 pub mod breadcrumb;
 pub mod pagination;
@@ -69,18 +89,44 @@ pub mod banner;
 pub mod skeleton;
 pub mod list;
 pub mod table;
+pub mod row_expansion;
+pub mod header_menu;
+pub mod column_autosize;
+pub mod grid_edit;
+pub mod virtual_list;
+pub mod reorder;
+pub mod drag_drop;
+pub mod swipe;
+pub mod message_list;
+pub mod widget;
+pub mod style;
+pub mod stylesheet;
 pub mod treeview;
+pub mod code_editor;
+pub mod language_client;
+pub mod find_replace;
 pub mod rating;
 pub mod stepper;
 pub mod drawer;
 pub mod fileupload;
 pub mod calendar;
+pub mod timepicker;
 pub mod timeline;
 pub mod datagrid;
 pub mod filebrowser;
+pub mod locale;
+pub mod validation;
+pub mod spellcheck;
+pub mod card_input;
+pub mod iban_input;
+pub mod palette;
+pub mod chart;
+pub mod chart_stream;
+pub mod chart_export;
+pub mod print_preview;
 
 pub use button::Button;
-pub use text::Text;
+pub use text::{StyledText, Text, TextSpan};
 pub use container::{VStack, HStack, ZStack, Alignment};
 pub use spacer::{Spacer, SpacerType};
 pub use divider::{Divider, DividerOrientation, DividerColor};
@@ -90,29 +136,55 @@ pub use textfield::TextField;
 pub use grid::Grid;
 pub use image::{Image, ImageSource, ImageState, ImageFit};
 pub use image_cache::{ImageCache, CachedImage};
+pub use placeholder::{decode_blurhash, CrossFade, ImagePlaceholder, PlaceholderImage};
+pub use zoom_view::ZoomView;
+pub use image_viewer::{ImageViewer, DownloadCallback, CopyCallback};
+pub use image_cropper::{ImageCropper, CropAspect, CropHandle};
+pub use region_picker::{RegionPicker, RegionCallback};
+#[cfg(feature = "camera")]
+pub use camera::{CameraView, CameraBackend, CameraDevice, CameraFrame, TestPatternCamera, CAMERA_PREVIEW_IMAGE};
+#[cfg(feature = "camera")]
+pub use code_scanner::{CodeScanner, CodeDecoder, CodeFormat, DecodedCode};
+#[cfg(feature = "qr")]
+pub use code_scanner::QrDecoder;
+#[cfg(all(feature = "camera", target_arch = "wasm32"))]
+pub use camera::WebCamera;
+#[cfg(all(feature = "camera-native", not(target_arch = "wasm32")))]
+pub use camera::NokhwaCamera;
 pub use scroll::{ScrollView, ScrollDirection};
+pub use scroll_memory::{ScrollKey, ScrollMemory, ScrollPosition};
 pub use modal::Modal;
 pub use dialog::{Dialog, DialogType};
+pub use lock_screen::LockScreen;
 pub use dropdown::{Dropdown, DropdownOption};
 pub use select::{Select, SelectOption};
 pub use tooltip::{Tooltip, TooltipPosition};
 pub use toast::{Toast, ToastType, ToastPosition};
-pub use context_menu::{ContextMenu, ContextMenuItem};
+pub use context_menu::{ContextMenu, ContextMenuItem, LongPress};
+pub use radial_menu::{RadialItem, RadialMenu};
 pub use popover::{Popover, PopoverPosition, PopoverTrigger};
 pub use menubar::{MenuBar, Menu, MenuItem};
+pub use toolbar::{Toolbar, ToolbarItem};
+pub use titlebar::{Titlebar, TitlebarAction};
+pub use shortcuts::{Shortcut, ShortcutHints, ShortcutMap, ShortcutPlatform, ShortcutRegistry};
+pub use command_palette::{CommandPalette, PaletteRow};
+pub use events::{ButtonEvent, CalendarEvent, MenuEvent, PickerEvent, TableEvent};
+pub use feature_flags::{show_if_flag, FeatureFlagPanel};
+pub use inspector::{AnimationPanel, BaselineComparison, Inspector};
 pub use tabs::{Tabs, Tab};
 pub use progress_bar::ProgressBar;
 pub use spinner::{Spinner, SpinnerSize, LabelPosition};
+pub use nav_stack::{NavDirection, NavigateCallback, NavigationStack, Screen, ScreenLayer, ScreenTransition, SharedElementFrame};
 pub use navigation::{Navigation, NavItem};
-pub use breadcrumb::{Breadcrumb, BreadcrumbItem};
+pub use breadcrumb::{route_items, Breadcrumb, BreadcrumbItem, BreadcrumbSlot};
 pub use pagination::Pagination;
 pub use slider::Slider;
 pub use toggle::{Toggle, LabelPosition as ToggleLabelPosition};
 pub use switch::Switch;
 pub use range::Range;
 pub use datepicker::{DatePicker, Date};
-pub use colorpicker::{ColorPicker, Color};
-pub use badge::{Badge, BadgeVariant};
+pub use colorpicker::{ColorPicker, Color, ContrastReport, PairTarget};
+pub use badge::{Attention, Badge, BadgeVariant, ItemBadge};
 pub use chip::{Chip, ChipVariant};
 pub use avatar::{Avatar, AvatarSize};
 pub use card::{Card, CardVariant};
@@ -120,14 +192,40 @@ pub use accordion::{Accordion, AccordionItem};
 pub use alert::{Alert, AlertSeverity};
 pub use banner::{Banner, BannerPosition, BannerVariant};
 pub use skeleton::{Skeleton, SkeletonVariant};
-pub use list::{List, ListItem, SelectionMode};
+pub use list::{List, ListItem, ListSection, SelectionMode, StickyHeader};
+pub use widget::{Widget, WidgetEvent, WidgetId, WidgetTree};
+pub use style::{Style, ComputedStyle, Edges, Border, Shadow, WidgetState};
+pub use stylesheet::Stylesheet;
+pub use message_list::{ChatMessage, MessageList, MessageRow};
+pub use swipe::{SwipeAction, SwipeCallback, SwipeEdge, SwipeOutcome, SwipeState};
 pub use table::{Table, TableColumn, TableRow, ColumnAlign, SortDirection};
+pub use row_expansion::{RowExpansion, ExpandableRows, ExpansionMode, DetailBuilder, ExpandCallback, EXPANDER_COLUMN_WIDTH};
+pub use header_menu::{HeaderAction, HeaderMenuState, HeaderActionCallback};
+pub use column_autosize::{ColumnAutosizer, AutosizeJob, TextMeasure};
+pub use grid_edit::{CellRange, ColumnEditor, PasteReport, RejectedCell, CellPatch, CellChange, GridEditCommand};
+pub use reorder::{ReorderState, ReorderCallback};
+pub use drag_drop::{DragController, DragImage, DragOutcome, DragPayload, DragSource, DragZone, DropIndicator, DropTarget};
+pub use virtual_list::{VirtualList, MeasurementCache, ScrollAlign, ItemBuilder};
 pub use treeview::{TreeView, TreeNode};
+pub use code_editor::{indentation_folds, CodeEditor, DefinitionCallback, EditorChangeCallback, FoldProvider, FoldRegion, KeyModifiers, Selection, TextPosition};
+pub use language_client::{CompletionItem, Diagnostic, DiagnosticSeverity, HoverInfo, LanguageClient, Location};
+pub use find_replace::{FindReplace, FindTarget, MatchRange};
 pub use rating::Rating;
 pub use stepper::{Stepper, Step, StepperOrientation};
 pub use drawer::{Drawer, DrawerPosition, DrawerVariant};
-pub use fileupload::{FileUpload, UploadedFile};
-pub use calendar::{Calendar, CalendarDate, CalendarView};
+pub use fileupload::{FileDropRouter, FileUpload, UploadedFile};
+pub use calendar::{Calendar, CalendarDate, CalendarView, CalendarSelection, DateClock};
+pub use timepicker::{DateTime, Time, TimePicker};
 pub use timeline::{Timeline, TimelineItem, TimelineMode};
 pub use datagrid::{DataGrid, ColumnFilter, FilterOperator};
 pub use filebrowser::{FileBrowser, FileEntry, FileType};
+pub use locale::{Locale, DateOrder, DateFormat};
+pub use validation::{CustomRule, Form, FormField, Rule, UniquenessCheck, ValidationError};
+pub use spellcheck::{SpellChecker, WordListDictionary, Misspelling};
+pub use card_input::{CardInput, CardNumberInput, ExpiryInput, CvcInput, CardBrand, CardField};
+pub use iban_input::IbanInput;
+pub use palette::{Palette, PaletteKind, PaletteMode, SeriesColors};
+pub use chart::{LineChart, BarChart, ChartSeries, ChartInteraction, DragMode};
+pub use chart_stream::{RingBuffer, Sparkline, StreamFollow};
+pub use chart_export::{ChartPrimitive, ChartScene, TextAnchor};
+pub use print_preview::{PageMargins, PageTemplate, PrintContent, PrintPreview, TextContent};
@@ -1,8 +1,17 @@
 // List Component - Simple list with items and selection
 // Essential for displaying collections of data
 
+use std::ops::Range;
+
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::layout_animation::{AnimatedRect, LayoutAnimator};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{mix, Theme, ThemeProvider};
+
+use super::drag_drop::{DragImage, DragOutcome, DragPayload, DragSource, DragZone, DropIndicator, DropTarget};
+use super::reorder::{keyboard_target, move_item, ReorderCallback, ReorderState};
+use super::swipe::{SwipeAction, SwipeCallback, SwipeEdge, SwipeOutcome, SwipeState};
+use super::virtual_list::{ScrollAlign, VirtualList};
 
 /// List item
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +22,8 @@ pub struct ListItem {
     pub icon: Option<String>,
     pub badge: Option<String>,
     pub metadata: Option<String>,
+    /// Can the row be swiped to reveal actions?
+    pub swipeable: bool,
 }
 
 impl ListItem {
@@ -25,6 +36,7 @@ impl ListItem {
             icon: None,
             badge: None,
             metadata: None,
+            swipeable: true,
         }
     }
 
@@ -37,6 +49,7 @@ impl ListItem {
             icon: None,
             badge: None,
             metadata: None,
+            swipeable: true,
         }
     }
 
@@ -57,6 +70,31 @@ impl ListItem {
         self.metadata = Some(metadata.into());
         self
     }
+
+    /// Allow or prevent swipe actions on this row
+    pub fn with_swipe(mut self, swipeable: bool) -> Self {
+        self.swipeable = swipeable;
+        self
+    }
+}
+
+/// A titled group of consecutive items
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSection {
+    pub id: String,
+    pub title: String,
+    /// Number of items in the section
+    pub len: usize,
+}
+
+/// Header pinned to the top of the viewport
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StickyHeader {
+    /// Section index
+    pub section: usize,
+    /// Vertical offset from the viewport top (negative while the next
+    /// header pushes this one out)
+    pub offset: f32,
 }
 
 /// Selection mode for the list
@@ -95,13 +133,31 @@ pub struct List {
     pub border_color: (u8, u8, u8, u8),
     pub divider_color: (u8, u8, u8, u8),
     pub show_dividers: bool,
+    pub sections: Vec<ListSection>,
+    pub header_height: f32,
+    pub show_index: bool,
+    pub reorderable: bool,
+    pub reorder: ReorderState,
+    pub on_reorder: Option<ReorderCallback>,
+    pub leading_actions: Vec<SwipeAction>,
+    pub trailing_actions: Vec<SwipeAction>,
+    pub swipe: SwipeState,
+    pub hovered_item: Option<usize>,
+    pub on_swipe_action: Option<SwipeCallback>,
     pub on_select: Option<Box<dyn Fn(&str)>>,
     pub on_deselect: Option<Box<dyn Fn(&str)>>,
+    /// Rows can be dragged between lists sharing this group
+    pub drag_group: Option<String>,
+    /// Row layout nodes from the last build (node, row id)
+    pub row_nodes: Vec<(NodeId, String)>,
+    /// Glides rows to new positions after moves, inserts and removals
+    pub row_animation: LayoutAnimator,
 }
 
 impl List {
-    /// Create a new List component
+    /// Create a new List component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             items: Vec::new(),
@@ -110,20 +166,53 @@ impl List {
             item_height: 48.0,
             padding: 16.0,
             spacing: 0.0,
-            background_color: (255, 255, 255, 255),
-            item_color: (255, 255, 255, 255),
-            selected_color: (59, 130, 246, 20), // Light blue
-            hover_color: (240, 240, 240, 255),
-            text_color: (0, 0, 0, 255),
-            selected_text_color: (59, 130, 246, 255), // Blue
-            border_color: (220, 220, 220, 255),
-            divider_color: (240, 240, 240, 255),
+            background_color: colors.surface,
+            item_color: colors.surface,
+            selected_color: colors.selection,
+            hover_color: colors.hover,
+            text_color: colors.text,
+            selected_text_color: colors.primary,
+            border_color: colors.border,
+            divider_color: mix(colors.surface, colors.border, 0.5),
             show_dividers: true,
+            sections: Vec::new(),
+            header_height: 32.0,
+            show_index: false,
+            reorderable: false,
+            reorder: ReorderState::new(),
+            on_reorder: None,
+            leading_actions: Vec::new(),
+            trailing_actions: Vec::new(),
+            swipe: SwipeState::new(),
+            hovered_item: None,
+            on_swipe_action: None,
             on_select: None,
             on_deselect: None,
+            drag_group: None,
+            row_nodes: Vec::new(),
+            row_animation: LayoutAnimator::new(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = theme.colors;
+        self.background_color = colors.surface;
+        self.item_color = colors.surface;
+        self.selected_color = colors.selection;
+        self.hover_color = colors.hover;
+        self.text_color = colors.text;
+        self.selected_text_color = colors.primary;
+        self.border_color = colors.border;
+        self.divider_color = mix(colors.surface, colors.border, 0.5);
+    }
+
     /// Set the selection mode
     pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
         self.selection_mode = mode;
@@ -166,24 +255,366 @@ impl List {
         self
     }
 
-    /// Add a list item
-    pub fn add_item(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
-        self.items.push(ListItem::new(id, label));
-        self
+    /// Add a list item (to the last section, if grouped)
+    pub fn add_item(self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.add_item_object(ListItem::new(id, label))
     }
 
     /// Add a disabled item
-    pub fn add_disabled_item(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
-        self.items.push(ListItem::disabled(id, label));
-        self
+    pub fn add_disabled_item(self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.add_item_object(ListItem::disabled(id, label))
     }
 
     /// Add a list item object
     pub fn add_item_object(mut self, item: ListItem) -> Self {
         self.items.push(item);
+        if let Some(section) = self.sections.last_mut() {
+            section.len += 1;
+        }
+        self
+    }
+
+    /// Add a titled section of items
+    pub fn add_section(mut self, title: impl Into<String>, items: Vec<ListItem>) -> Self {
+        let title = title.into();
+        if self.sections.is_empty() && !self.items.is_empty() {
+            // Items added before the first section get an untitled one
+            self.sections.push(ListSection { id: "section-0".to_string(), title: String::new(), len: self.items.len() });
+        }
+        self.sections.push(ListSection {
+            id: format!("section-{}", self.sections.len()),
+            title,
+            len: items.len(),
+        });
+        self.items.extend(items);
+        self
+    }
+
+    /// Sort items by label and group them by first letter (contacts-style)
+    /// Labels that don't start with a letter go under "#"
+    pub fn group_alphabetically(mut self) -> Self {
+        let key = |item: &ListItem| match item.label.chars().next() {
+            Some(c) if c.is_alphabetic() => c.to_uppercase().collect::<String>(),
+            _ => "#".to_string(),
+        };
+        // "#" sorts after the letters
+        self.items.sort_by_cached_key(|item| (key(item) == "#", key(item), item.label.to_lowercase()));
+
+        self.sections.clear();
+        for item in &self.items {
+            let title = key(item);
+            match self.sections.last_mut() {
+                Some(section) if section.title == title => section.len += 1,
+                _ => self.sections.push(ListSection { id: format!("section-{}", title), title, len: 1 }),
+            }
+        }
+        self
+    }
+
+    /// Set the section header height
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Show the fast-scroll index rail
+    pub fn index_rail(mut self, show: bool) -> Self {
+        self.show_index = show;
+        self
+    }
+
+    /// Enable drag-to-reorder (shows drag handles)
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Set the reorder callback (old index, new index)
+    pub fn on_reorder<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + 'static,
+    {
+        self.on_reorder = Some(Box::new(callback));
+        self
+    }
+
+    /// Let rows be dragged to and from other lists in the same group
+    pub fn drag_group(mut self, group: impl Into<String>) -> Self {
+        self.drag_group = Some(group.into());
+        self
+    }
+
+    /// Start dragging an item by its handle (`y` relative to the first item)
+    pub fn start_drag(&mut self, index: usize, y: f32) {
+        if !self.reorderable || self.items.get(index).is_none_or(|item| item.disabled) {
+            return;
+        }
+        let sizes = vec![self.item_height + self.spacing; self.items.len()];
+        self.reorder.begin(index, sizes, y);
+    }
+
+    /// Move the dragged item, returning the slot it would drop into
+    pub fn drag(&mut self, y: f32) -> Option<usize> {
+        self.reorder.drag_to(y)
+    }
+
+    /// Drop the dragged item, moving it to its target slot
+    pub fn drop_item(&mut self) {
+        let Some(index) = self.reorder.dragged() else {
+            return;
+        };
+        let target = self.reorder.target().unwrap_or(index);
+        let bounds = self.reorder_bounds(index);
+        if !bounds.contains(&target) {
+            // Items can't leave their section
+            self.reorder.cancel();
+            return;
+        }
+        if let Some((from, to)) = self.reorder.end() {
+            self.move_item(from, to);
+        }
+    }
+
+    /// Abandon the current drag
+    pub fn cancel_drag(&mut self) {
+        self.reorder.cancel();
+    }
+
+    /// Move an item up one slot (Ctrl+Up)
+    pub fn move_item_up(&mut self, index: usize) -> bool {
+        self.move_item_by(index, -1)
+    }
+
+    /// Move an item down one slot (Ctrl+Down)
+    pub fn move_item_down(&mut self, index: usize) -> bool {
+        self.move_item_by(index, 1)
+    }
+
+    fn move_item_by(&mut self, index: usize, delta: isize) -> bool {
+        if !self.reorderable || self.reorder.is_dragging() {
+            return false;
+        }
+        let bounds = self.reorder_bounds(index);
+        let Some(target) = keyboard_target(index, delta, self.items.len()).filter(|t| bounds.contains(t)) else {
+            return false;
+        };
+        self.move_item(index, target);
+        true
+    }
+
+    /// Move an item and notify `on_reorder`
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        if from >= self.items.len() || to >= self.items.len() || from == to {
+            return;
+        }
+        move_item(&mut self.items, from, to);
+        if let Some(ref callback) = self.on_reorder {
+            callback(from, to);
+        }
+    }
+
+    /// Add an action revealed by swiping toward the end (e.g. archive)
+    pub fn leading_action(mut self, action: SwipeAction) -> Self {
+        self.leading_actions.push(action);
+        self
+    }
+
+    /// Add an action revealed by swiping toward the start (e.g. delete)
+    pub fn trailing_action(mut self, action: SwipeAction) -> Self {
+        self.trailing_actions.push(action);
         self
     }
 
+    /// Set the swipe action callback (item id, action id)
+    pub fn on_swipe_action<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, &str) + 'static,
+    {
+        self.on_swipe_action = Some(Box::new(callback));
+        self
+    }
+
+    /// Can an item be swiped?
+    pub fn is_swipeable(&self, index: usize) -> bool {
+        let has_actions = !self.leading_actions.is_empty() || !self.trailing_actions.is_empty();
+        has_actions && self.items.get(index).is_some_and(|item| item.swipeable && !item.disabled)
+    }
+
+    /// Start a horizontal swipe on a row
+    pub fn start_swipe(&mut self, index: usize, x: f32, row_width: f32) {
+        if !self.is_swipeable(index) || self.reorder.is_dragging() {
+            return;
+        }
+        self.swipe.begin(&self.items[index].id, x, row_width);
+    }
+
+    /// Move the swiping pointer, returning the row's offset
+    pub fn swipe_to(&mut self, x: f32) -> f32 {
+        self.swipe.drag_to(x, self.leading_actions.len(), self.trailing_actions.len())
+    }
+
+    /// Release the swipe; past the threshold the edge's first action fires
+    pub fn end_swipe(&mut self) -> SwipeOutcome {
+        let item = self.swipe.item().map(str::to_string);
+        let outcome = self.swipe.end(self.leading_actions.len(), self.trailing_actions.len());
+        if let (SwipeOutcome::Committed(edge), Some(item)) = (outcome, item) {
+            let action = match edge {
+                SwipeEdge::Leading => self.leading_actions.first(),
+                SwipeEdge::Trailing => self.trailing_actions.first(),
+            };
+            if let (Some(action), Some(callback)) = (action, &self.on_swipe_action) {
+                callback(&item, &action.id);
+            }
+        }
+        outcome
+    }
+
+    /// Run an action from a revealed or hover button, closing the row
+    pub fn trigger_swipe_action(&mut self, index: usize, action_id: &str) -> bool {
+        let known = self.leading_actions.iter().chain(&self.trailing_actions).any(|a| a.id == action_id);
+        if !known || !self.is_swipeable(index) {
+            return false;
+        }
+        self.swipe.close();
+        if let Some(ref callback) = self.on_swipe_action {
+            callback(&self.items[index].id, action_id);
+        }
+        true
+    }
+
+    /// Close the open row
+    pub fn close_swipe(&mut self) {
+        self.swipe.close();
+    }
+
+    /// Horizontal offset of a row's content
+    pub fn swipe_offset(&self, index: usize) -> f32 {
+        self.items.get(index).map_or(0.0, |item| self.swipe.offset(&item.id))
+    }
+
+    /// Advance the swipe spring (call every frame)
+    pub fn update_swipe(&mut self, dt: f32) -> bool {
+        self.swipe.update(dt)
+    }
+
+    /// Track the row under the mouse (desktop hover buttons)
+    pub fn set_hovered(&mut self, index: Option<usize>) {
+        self.hovered_item = index;
+    }
+
+    /// Buttons shown on a hovered row when there's no touch to swipe with
+    pub fn hover_actions(&self, index: usize) -> Vec<&SwipeAction> {
+        if self.hovered_item != Some(index) || self.swipe.is_swiping() || !self.is_swipeable(index) {
+            return Vec::new();
+        }
+        self.leading_actions.iter().chain(&self.trailing_actions).collect()
+    }
+
+    /// Indices an item may be reordered within (its section, if grouped)
+    fn reorder_bounds(&self, index: usize) -> Range<usize> {
+        match self.section_of(index) {
+            Some(section) => self.section_ranges()[section].clone(),
+            None => 0..self.items.len(),
+        }
+    }
+
+    /// Is the list grouped into sections?
+    pub fn is_grouped(&self) -> bool {
+        !self.sections.is_empty()
+    }
+
+    /// Item index range of each section
+    pub fn section_ranges(&self) -> Vec<Range<usize>> {
+        let mut start = 0;
+        self.sections
+            .iter()
+            .map(|section| {
+                let range = start..start + section.len;
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+
+    /// Section containing an item
+    pub fn section_of(&self, item_index: usize) -> Option<usize> {
+        self.section_ranges().iter().position(|range| range.contains(&item_index))
+    }
+
+    /// Row ids for virtualization: a header row before each section's items
+    pub fn row_ids(&self) -> Vec<String> {
+        if !self.is_grouped() {
+            return self.items.iter().map(|item| item.id.clone()).collect();
+        }
+        let mut rows = Vec::with_capacity(self.items.len() + self.sections.len());
+        for (section, range) in self.sections.iter().zip(self.section_ranges()) {
+            rows.push(header_row_id(&section.id));
+            rows.extend(self.items[range].iter().map(|item| item.id.clone()));
+        }
+        rows
+    }
+
+    /// VirtualList over `row_ids()`, with header heights already known
+    pub fn virtual_list(&self, viewport_height: f32) -> VirtualList {
+        let mut list = VirtualList::new()
+            .estimated_item_height(self.item_height)
+            .viewport_height(viewport_height);
+        for section in &self.sections {
+            list.cache.set_fixed(header_row_id(&section.id), self.header_height);
+        }
+        list.set_items(self.row_ids());
+        list
+    }
+
+    /// Row index of a section's header within `row_ids()`
+    pub fn header_row(&self, section: usize) -> Option<usize> {
+        let range = self.section_ranges().get(section)?.clone();
+        // Each earlier section adds one header row
+        Some(range.start + section)
+    }
+
+    /// Header to pin at the top of the viewport
+    ///
+    /// The pinned header is the section the viewport top is in; once the
+    /// next section's header reaches it, it gets pushed up and out.
+    pub fn sticky_header(&self, list: &VirtualList) -> Option<StickyHeader> {
+        if !self.is_grouped() || list.is_empty() {
+            return None;
+        }
+        let scroll = list.scroll_offset();
+        let header_tops: Vec<f32> = (0..self.sections.len())
+            .filter_map(|section| self.header_row(section).map(|row| list.offset_of(row)))
+            .collect();
+
+        let section = header_tops.iter().rposition(|&top| top <= scroll)?;
+        let offset = match header_tops.get(section + 1) {
+            Some(&next_top) => (next_top - scroll - self.header_height).min(0.0),
+            None => 0.0,
+        };
+        Some(StickyHeader { section, offset })
+    }
+
+    /// Titles shown on the index rail
+    pub fn index_titles(&self) -> Vec<String> {
+        self.sections.iter().map(|section| section.title.clone()).collect()
+    }
+
+    /// Section under a point on the index rail (rail-relative y)
+    pub fn index_at(&self, y: f32, rail_height: f32) -> Option<usize> {
+        if self.sections.is_empty() || rail_height <= 0.0 || !(0.0..rail_height).contains(&y) {
+            return None;
+        }
+        let slot = rail_height / self.sections.len() as f32;
+        Some(((y / slot) as usize).min(self.sections.len() - 1))
+    }
+
+    /// Jump to a section (index rail tap or drag)
+    pub fn scroll_to_section(&self, list: &mut VirtualList, section: usize) {
+        if let Some(row) = self.header_row(section) {
+            list.scroll_to_index(row, ScrollAlign::Start);
+        }
+    }
+
     /// Set all items at once
     pub fn items(mut self, items: Vec<ListItem>) -> Self {
         self.items = items;
@@ -306,11 +737,40 @@ impl List {
     /// Remove item by ID
     pub fn remove_item(&mut self, id: &str) {
         if let Some(index) = self.find_item(id) {
+            if let Some(section) = self.section_of(index) {
+                self.sections[section].len -= 1;
+            }
             self.items.remove(index);
             self.deselect_item(id);
+            if self.swipe.item() == Some(id) {
+                self.swipe.reset();
+            }
+            self.hovered_item = None;
         }
     }
 
+    /// Diff row positions against the previous layout (call after `compute_layout`)
+    /// Moved rows then glide from where they were; new ones fade in
+    pub fn capture_row_layout(&mut self, engine: &LayoutEngine) {
+        self.row_animation.capture_nodes(engine, &self.row_nodes);
+    }
+
+    /// Advance row move/fade animations
+    /// Returns true while rows are still moving
+    pub fn update_row_animation(&mut self, dt: f32) -> bool {
+        self.row_animation.update(dt)
+    }
+
+    /// Where to draw a row this frame (layout-relative; None before a capture)
+    pub fn row_rect(&self, id: &str) -> Option<AnimatedRect> {
+        self.row_animation.rect(id)
+    }
+
+    /// Removed rows still fading out where they were
+    pub fn leaving_rows(&self) -> Vec<(&str, AnimatedRect)> {
+        self.row_animation.leaving()
+    }
+
     /// Build the list layout
     pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
         let style = taffy::style::Style {
@@ -327,8 +787,28 @@ impl List {
             ..Default::default()
         };
 
+        // One node per row (section headers included) so row moves show up in the layout
+        let mut children = Vec::new();
+        self.row_nodes.clear();
+        let headers: Vec<String> = self.sections.iter().map(|section| header_row_id(&section.id)).collect();
+        for id in self.row_ids() {
+            let height = if headers.contains(&id) { self.header_height } else { self.item_height };
+            let row = engine
+                .new_leaf(taffy::style::Style {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Percent(1.0),
+                        height: taffy::style::Dimension::Length(height),
+                    },
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                })
+                .map_err(|e| format!("Failed to create row node: {:?}", e))?;
+            children.push(row);
+            self.row_nodes.push((row, id));
+        }
+
         let node = engine
-            .new_leaf(style)
+            .new_with_children(style, &children)
             .map_err(|e| format!("Failed to create list node: {:?}", e))?;
         self.node_id = Some(node);
 
@@ -336,12 +816,81 @@ impl List {
     }
 }
 
+/// Row id used for a section header in `List::row_ids()`
+pub fn header_row_id(section_id: &str) -> String {
+    format!("header:{}", section_id)
+}
+
 impl Default for List {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl List {
+    /// Row under `y` (relative to the first item)
+    fn row_at(&self, y: f32) -> Option<usize> {
+        let size = self.item_height + self.spacing;
+        (y >= 0.0).then(|| (y / size) as usize).filter(|&index| index < self.items.len())
+    }
+
+    /// Gap nearest to `y` that a dropped row would go into
+    fn drop_slot(&self, y: f32) -> usize {
+        let size = self.item_height + self.spacing;
+        ((y / size).round().max(0.0) as usize).min(self.items.len())
+    }
+}
+
+impl DragSource for List {
+    fn drag_payload(&self, (_, y): (f32, f32)) -> Option<DragPayload> {
+        let group = self.drag_group.as_ref()?;
+        let item = self.items.get(self.row_at(y)?).filter(|item| !item.disabled)?;
+        Some(DragPayload::new(group.clone(), item.id.clone(), item.label.clone()).with_data(item.clone()))
+    }
+
+    fn drag_image(&self, payload: &DragPayload) -> DragImage {
+        DragImage::from_payload(payload).size(240.0, self.item_height)
+    }
+
+    fn drag_finished(&mut self, payload: &DragPayload, outcome: DragOutcome) {
+        if outcome == DragOutcome::Transferred {
+            self.remove_item(&payload.id);
+        }
+    }
+}
+
+impl DropTarget for List {
+    fn accepts(&self, payload: &DragPayload) -> bool {
+        // Sections would need to know which one the row lands in
+        self.drag_group.as_deref() == Some(payload.kind.as_str()) && !self.is_grouped() && payload.data::<ListItem>().is_some()
+    }
+
+    fn drag_over(&mut self, _payload: &DragPayload, (_, y): (f32, f32)) -> Option<DropIndicator> {
+        Some(DropIndicator::HorizontalLine(self.drop_slot(y) as f32 * (self.item_height + self.spacing)))
+    }
+
+    fn drop_payload(&mut self, payload: &DragPayload, (_, y): (f32, f32)) -> bool {
+        let slot = self.drop_slot(y);
+        match (self.find_item(&payload.id), payload.data::<ListItem>()) {
+            // Own row: the gap above it and below it are the same spot
+            (Some(from), _) => self.move_item(from, if slot > from { slot - 1 } else { slot }),
+            (None, Some(item)) => self.items.insert(slot, item.clone()),
+            (None, None) => return false,
+        }
+        true
+    }
+}
+
+impl DragZone for List {
+    fn as_source(&mut self) -> Option<&mut dyn DragSource> {
+        Some(self)
+    }
+
+    fn as_target(&mut self) -> Option<&mut dyn DropTarget> {
+        Some(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -571,4 +1120,180 @@ mod tests {
         assert!(result.is_ok());
         assert!(list.node_id.is_some());
     }
+
+    #[test]
+    fn moved_and_removed_items_animate() {
+        let mut engine = LayoutEngine::new();
+        let mut list = List::new().item_height(40.0).add_item("a", "A").add_item("b", "B").add_item("c", "C");
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(300.0),
+            height: taffy::style::AvailableSpace::MaxContent,
+        };
+        let mut relayout = |list: &mut List| {
+            let node = list.build(&mut engine).unwrap();
+            engine.compute_layout(node, available).unwrap();
+            list.capture_row_layout(&engine);
+        };
+        relayout(&mut list);
+
+        list.remove_item("a");
+        list.move_item(1, 0);
+        relayout(&mut list);
+        assert_eq!(list.row_rect("c").unwrap().y, 80.0);
+        assert_eq!(list.leaving_rows()[0].0, "a");
+
+        while list.update_row_animation(1.0 / 60.0) {}
+        assert_eq!(list.row_rect("c").unwrap().y, 0.0);
+        assert_eq!(list.row_rect("b").unwrap().y, 40.0);
+        assert!(list.leaving_rows().is_empty());
+    }
+
+    fn contacts() -> List {
+        List::new()
+            .header_height(20.0)
+            .item_height(40.0)
+            .add_item("c1", "bob")
+            .add_item("c2", "Alice")
+            .add_item("c3", "42 Club")
+            .add_item("c4", "Anna")
+            .add_item("c5", "Carol")
+            .group_alphabetically()
+    }
+
+    #[test]
+    fn list_groups_alphabetically() {
+        let list = contacts();
+        assert_eq!(list.index_titles(), vec!["A", "B", "C", "#"]);
+        assert_eq!(list.section_ranges(), vec![0..2, 2..3, 3..4, 4..5]);
+        assert_eq!(list.items[0].label, "Alice");
+        assert_eq!(list.items[4].label, "42 Club");
+        assert_eq!(list.row_ids()[..3], ["header:section-A", "c2", "c4"]);
+        assert_eq!(list.header_row(1), Some(3));
+    }
+
+    #[test]
+    fn list_sections_track_removal() {
+        let mut list = List::new()
+            .add_section("Fruit", vec![ListItem::new("apple", "Apple"), ListItem::new("pear", "Pear")])
+            .add_section("Veg", vec![ListItem::new("kale", "Kale")])
+            .add_item("leek", "Leek");
+        assert_eq!(list.section_ranges(), vec![0..2, 2..4]);
+
+        list.remove_item("apple");
+        assert_eq!(list.section_ranges(), vec![0..1, 1..3]);
+        assert_eq!(list.section_of(2), Some(1));
+    }
+
+    #[test]
+    fn list_sticky_header_is_pushed_by_next() {
+        let list = contacts();
+        // A: header 0..20, items 20..100; B header at 100
+        let mut rows = list.virtual_list(100.0);
+        assert_eq!(rows.total_height(), 4.0 * 20.0 + 5.0 * 40.0);
+
+        rows.scroll_to(50.0);
+        assert_eq!(list.sticky_header(&rows), Some(StickyHeader { section: 0, offset: 0.0 }));
+
+        rows.scroll_to(90.0);
+        assert_eq!(list.sticky_header(&rows), Some(StickyHeader { section: 0, offset: -10.0 }));
+
+        rows.scroll_to(100.0);
+        assert_eq!(list.sticky_header(&rows), Some(StickyHeader { section: 1, offset: 0.0 }));
+    }
+
+    #[test]
+    fn list_index_rail_jumps_to_section() {
+        let list = contacts().index_rail(true);
+        assert_eq!(list.index_at(0.0, 100.0), Some(0));
+        assert_eq!(list.index_at(60.0, 100.0), Some(2));
+        assert_eq!(list.index_at(100.0, 100.0), None);
+
+        let mut rows = list.virtual_list(100.0);
+        list.scroll_to_section(&mut rows, 2);
+        assert_eq!(rows.scroll_offset(), 20.0 + 80.0 + 20.0 + 40.0);
+    }
+
+    #[test]
+    fn list_drag_reorder() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let moves = Rc::new(RefCell::new(Vec::new()));
+        let recorded = moves.clone();
+        let mut list = List::new()
+            .item_height(40.0)
+            .reorderable(true)
+            .add_item("a", "A")
+            .add_item("b", "B")
+            .add_item("c", "C")
+            .on_reorder(move |from, to| recorded.borrow_mut().push((from, to)));
+
+        list.start_drag(0, 10.0);
+        assert_eq!(list.drag(95.0), Some(2));
+        list.drop_item();
+        assert_eq!(list.items.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["b", "c", "a"]);
+
+        assert!(list.move_item_up(2));
+        assert!(!list.move_item_up(0));
+        assert_eq!(*moves.borrow(), vec![(0, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn list_swipe_commits_first_action() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let sink = fired.clone();
+        let mut list = List::new()
+            .add_item("a", "Alpha")
+            .add_item_object(ListItem::new("b", "Beta").with_swipe(false))
+            .leading_action(SwipeAction::archive())
+            .trailing_action(SwipeAction::delete())
+            .on_swipe_action(move |item, action| sink.borrow_mut().push(format!("{}:{}", item, action)));
+
+        list.start_swipe(1, 300.0, 400.0);
+        assert!(!list.swipe.is_swiping());
+
+        list.start_swipe(0, 300.0, 400.0);
+        list.swipe_to(0.0);
+        assert_eq!(list.swipe_offset(0), -300.0);
+        assert_eq!(list.end_swipe(), SwipeOutcome::Committed(SwipeEdge::Trailing));
+        assert_eq!(*fired.borrow(), vec!["a:delete"]);
+
+        list.remove_item("a");
+        assert_eq!(list.swipe.item(), None);
+    }
+
+    #[test]
+    fn list_hover_actions_on_desktop() {
+        let mut list = List::new()
+            .add_item("a", "Alpha")
+            .add_disabled_item("b", "Beta")
+            .trailing_action(SwipeAction::delete());
+
+        assert!(list.hover_actions(0).is_empty());
+        list.set_hovered(Some(0));
+        assert_eq!(list.hover_actions(0)[0].id, "delete");
+        assert!(list.trigger_swipe_action(0, "delete"));
+        assert!(!list.trigger_swipe_action(0, "archive"));
+
+        list.set_hovered(Some(1));
+        assert!(list.hover_actions(1).is_empty());
+    }
+
+    #[test]
+    fn list_reorder_stays_in_section() {
+        let mut list = List::new()
+            .reorderable(true)
+            .add_section("One", vec![ListItem::new("a", "A"), ListItem::new("b", "B")])
+            .add_section("Two", vec![ListItem::new("c", "C")]);
+
+        assert!(!list.move_item_down(1));
+        assert!(list.move_item_up(1));
+        assert_eq!(list.items[0].id, "b");
+
+        let mut fixed = List::new().add_item("a", "A").add_item("b", "B");
+        assert!(!fixed.move_item_down(0));
+    }
 }
@@ -0,0 +1,505 @@
+// Locale - Regional number and date conventions
+// Lets inputs understand "1.234,5" or "22. November 2025" the way users type them
+
+use crate::datepicker::Date;
+
+/// Order of day, month, and year in numeric dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// 22/11/2025
+    DayMonthYear,
+    /// 11/22/2025
+    MonthDayYear,
+    /// 2025/11/22
+    YearMonthDay,
+}
+
+/// How to write a date out
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum DateFormat {
+    /// Numeric in the locale's order: 11/22/2025, 22.11.2025
+    #[default]
+    Short,
+    /// Abbreviated month: Nov 22, 2025 / 22. Nov. 2025
+    Medium,
+    /// Weekday and full month: Saturday, November 22, 2025
+    Long,
+    /// Pattern with `yyyy`/`yy`, `MMMM`/`MMM`/`MM`/`M`, `dd`/`d` and
+    /// `EEEE`/`EEE` (weekday); text in single quotes is copied as is
+    Custom(String),
+}
+
+/// Regional conventions used when parsing and formatting user input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locale {
+    /// BCP 47 language tag (e.g. "en-US")
+    pub code: &'static str,
+    /// Decimal separator ('.' or ',')
+    pub decimal_separator: char,
+    /// Digit grouping separator (',', '.', ' ', ...)
+    pub grouping_separator: char,
+    /// Order of numeric date parts
+    pub date_order: DateOrder,
+    /// Separator used when formatting numeric dates
+    pub date_separator: char,
+    /// Full month names, January first
+    pub month_names: [&'static str; 12],
+    /// Abbreviated month names, January first
+    pub month_abbreviations: [&'static str; 12],
+    /// Placeholder hint for date inputs
+    pub date_placeholder: &'static str,
+    /// Full weekday names, Sunday first
+    pub day_names: [&'static str; 7],
+    /// Abbreviated weekday names, Sunday first
+    pub day_abbreviations: [&'static str; 7],
+    /// First column of a month grid (0 = Sunday, 1 = Monday)
+    pub first_day_of_week: u8,
+    /// Pattern for `DateFormat::Medium`
+    pub medium_date_pattern: &'static str,
+    /// Pattern for `DateFormat::Long`
+    pub long_date_pattern: &'static str,
+    /// Times shown as "2:30 PM" rather than "14:30"
+    pub twelve_hour_clock: bool,
+}
+
+impl Locale {
+    /// English (United States)
+    pub fn en_us() -> Self {
+        Self {
+            code: "en-US",
+            decimal_separator: '.',
+            grouping_separator: ',',
+            date_order: DateOrder::MonthDayYear,
+            date_separator: '/',
+            month_names: [
+                "January", "February", "March", "April", "May", "June",
+                "July", "August", "September", "October", "November", "December",
+            ],
+            month_abbreviations: [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun",
+                "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            date_placeholder: "MM/DD/YYYY",
+            day_names: ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+            day_abbreviations: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            first_day_of_week: 0,
+            medium_date_pattern: "MMM d, yyyy",
+            long_date_pattern: "EEEE, MMMM d, yyyy",
+            twelve_hour_clock: true,
+        }
+    }
+
+    /// English (United Kingdom)
+    pub fn en_gb() -> Self {
+        Self {
+            code: "en-GB",
+            date_order: DateOrder::DayMonthYear,
+            date_placeholder: "DD/MM/YYYY",
+            first_day_of_week: 1,
+            medium_date_pattern: "d MMM yyyy",
+            long_date_pattern: "EEEE d MMMM yyyy",
+            twelve_hour_clock: false,
+            ..Self::en_us()
+        }
+    }
+
+    /// German (Germany)
+    pub fn de_de() -> Self {
+        Self {
+            code: "de-DE",
+            decimal_separator: ',',
+            grouping_separator: '.',
+            date_order: DateOrder::DayMonthYear,
+            date_separator: '.',
+            month_names: [
+                "Januar", "Februar", "März", "April", "Mai", "Juni",
+                "Juli", "August", "September", "Oktober", "November", "Dezember",
+            ],
+            month_abbreviations: [
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun",
+                "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ],
+            date_placeholder: "TT.MM.JJJJ",
+            day_names: ["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+            day_abbreviations: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+            first_day_of_week: 1,
+            medium_date_pattern: "d. MMM yyyy",
+            long_date_pattern: "EEEE, d. MMMM yyyy",
+            twelve_hour_clock: false,
+        }
+    }
+
+    /// French (France)
+    pub fn fr_fr() -> Self {
+        Self {
+            code: "fr-FR",
+            decimal_separator: ',',
+            grouping_separator: '\u{202F}',
+            date_order: DateOrder::DayMonthYear,
+            date_separator: '/',
+            month_names: [
+                "janvier", "février", "mars", "avril", "mai", "juin",
+                "juillet", "août", "septembre", "octobre", "novembre", "décembre",
+            ],
+            month_abbreviations: [
+                "janv", "févr", "mars", "avr", "mai", "juin",
+                "juil", "août", "sept", "oct", "nov", "déc",
+            ],
+            date_placeholder: "JJ/MM/AAAA",
+            day_names: ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+            day_abbreviations: ["dim", "lun", "mar", "mer", "jeu", "ven", "sam"],
+            first_day_of_week: 1,
+            medium_date_pattern: "d MMM yyyy",
+            long_date_pattern: "EEEE d MMMM yyyy",
+            twelve_hour_clock: false,
+        }
+    }
+
+    /// Look up a built-in locale by language tag (falls back to en-US)
+    pub fn from_code(code: &str) -> Self {
+        match code.to_ascii_lowercase().replace('_', "-").as_str() {
+            "en-gb" => Self::en_gb(),
+            "de" | "de-de" | "de-at" => Self::de_de(),
+            "fr" | "fr-fr" => Self::fr_fr(),
+            _ => Self::en_us(),
+        }
+    }
+
+    /// Parse a number typed by the user
+    /// Accepts grouping separators and the locale's decimal separator
+    pub fn parse_number(&self, input: &str) -> Option<f64> {
+        let mut normalized = String::with_capacity(input.len());
+        let mut seen_decimal = false;
+
+        for c in input.trim().chars() {
+            if c == self.decimal_separator {
+                if seen_decimal {
+                    return None;
+                }
+                seen_decimal = true;
+                normalized.push('.');
+            } else if c == self.grouping_separator || is_space(c) || c == '\'' {
+                // Grouping is only valid before the decimal separator
+                if seen_decimal {
+                    return None;
+                }
+            } else if c.is_ascii_digit() || ((c == '-' || c == '+') && normalized.is_empty()) {
+                normalized.push(c);
+            } else if c == '−' && normalized.is_empty() {
+                normalized.push('-');
+            } else {
+                return None;
+            }
+        }
+
+        normalized.parse().ok()
+    }
+
+    /// Format a number with this locale's separators
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = match formatted.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (formatted.as_str(), None),
+        };
+
+        let mut grouped = String::new();
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(self.grouping_separator);
+            }
+            grouped.push(c);
+        }
+
+        let mut result = String::new();
+        if value.is_sign_negative() && value != 0.0 {
+            result.push('-');
+        }
+        result.push_str(&grouped);
+        if let Some(fraction) = fraction {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    /// Placeholder hint for number inputs (e.g. "1,234.56")
+    pub fn number_placeholder(&self) -> String {
+        self.format_number(1234.56, 2)
+    }
+
+    /// Parse a date typed by the user
+    ///
+    /// Understands ISO dates (2025-11-22), numeric dates in the locale's order
+    /// (11/22/2025, 22.11.25), and dates with month names (22 November 2025, Nov 22, 2025).
+    pub fn parse_date(&self, input: &str) -> Option<Date> {
+        let tokens: Vec<&str> = input
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        if tokens.len() != 3 {
+            return None;
+        }
+
+        let date = match tokens.iter().position(|t| t.chars().any(|c| c.is_alphabetic())) {
+            Some(month_index) => {
+                let month = self.parse_month_name(tokens[month_index])?;
+                let numbers: Vec<&str> = tokens
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != month_index)
+                    .map(|(_, t)| *t)
+                    .collect();
+                let (day, year) = if numbers[0].len() == 4 {
+                    (numbers[1], numbers[0])
+                } else {
+                    (numbers[0], numbers[1])
+                };
+                Date::new(parse_year(year)?, month, day.parse().ok()?)
+            }
+            None => {
+                // A leading four-digit year is always year-month-day
+                let order = if tokens[0].len() == 4 {
+                    DateOrder::YearMonthDay
+                } else {
+                    self.date_order
+                };
+                let (year, month, day) = match order {
+                    DateOrder::YearMonthDay => (tokens[0], tokens[1], tokens[2]),
+                    DateOrder::DayMonthYear => (tokens[2], tokens[1], tokens[0]),
+                    DateOrder::MonthDayYear => (tokens[2], tokens[0], tokens[1]),
+                };
+                Date::new(parse_year(year)?, month.parse().ok()?, day.parse().ok()?)
+            }
+        };
+
+        if date.is_valid() {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    /// Format a date as a numeric date in this locale's order
+    pub fn format_date(&self, date: &Date) -> String {
+        let sep = self.date_separator;
+        match self.date_order {
+            DateOrder::DayMonthYear => format!("{:02}{sep}{:02}{sep}{:04}", date.day, date.month, date.year),
+            DateOrder::MonthDayYear => format!("{:02}{sep}{:02}{sep}{:04}", date.month, date.day, date.year),
+            DateOrder::YearMonthDay => format!("{:04}{sep}{:02}{sep}{:02}", date.year, date.month, date.day),
+        }
+    }
+
+    /// Format a date in one of the standard formats or a custom pattern
+    pub fn format_date_as(&self, date: &Date, format: &DateFormat) -> String {
+        self.format_parts(date.year, date.month, date.day, format)
+    }
+
+    /// Format a year, month (1-12) and day
+    pub fn format_parts(&self, year: i32, month: u8, day: u8, format: &DateFormat) -> String {
+        match format {
+            DateFormat::Short => self.format_date(&Date::new(year, month, day)),
+            DateFormat::Medium => self.format_pattern(year, month, day, self.medium_date_pattern),
+            DateFormat::Long => self.format_pattern(year, month, day, self.long_date_pattern),
+            DateFormat::Custom(pattern) => self.format_pattern(year, month, day, pattern),
+        }
+    }
+
+    /// Format with a pattern (see `DateFormat::Custom`)
+    pub fn format_pattern(&self, year: i32, month: u8, day: u8, pattern: &str) -> String {
+        let month_index = (month.clamp(1, 12) - 1) as usize;
+        let weekday = weekday(year, month, day) as usize;
+        let mut out = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                // 'literal' ('' is a quote)
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                    out.push('\'');
+                    continue;
+                }
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    out.push(c);
+                }
+                continue;
+            }
+            if !matches!(c, 'y' | 'M' | 'd' | 'E') {
+                out.push(c);
+                continue;
+            }
+            let mut run = 1;
+            while chars.peek() == Some(&c) {
+                chars.next();
+                run += 1;
+            }
+            match (c, run) {
+                ('y', 2) => out.push_str(&format!("{:02}", year.rem_euclid(100))),
+                ('y', _) => out.push_str(&format!("{:04}", year)),
+                ('M', 1) => out.push_str(&month.to_string()),
+                ('M', 2) => out.push_str(&format!("{:02}", month)),
+                ('M', 3) => out.push_str(self.month_abbreviations[month_index]),
+                ('M', _) => out.push_str(self.month_names[month_index]),
+                ('d', 1) => out.push_str(&day.to_string()),
+                ('d', _) => out.push_str(&format!("{:02}", day)),
+                ('E', 1..=3) => out.push_str(self.day_abbreviations[weekday]),
+                _ => out.push_str(self.day_names[weekday]),
+            }
+        }
+        out
+    }
+
+    /// Weekday abbreviations in month-grid order (starting at `first_day_of_week`)
+    pub fn weekday_headers(&self, first_day_of_week: u8) -> Vec<&'static str> {
+        (0..7).map(|i| self.day_abbreviations[(first_day_of_week as usize + i) % 7]).collect()
+    }
+
+    /// Match a full or abbreviated month name (case-insensitive, 3+ letters)
+    pub fn parse_month_name(&self, name: &str) -> Option<u8> {
+        let name = name.to_lowercase();
+        if name.chars().count() < 3 {
+            return None;
+        }
+
+        self.month_names
+            .iter()
+            .zip(self.month_abbreviations.iter())
+            .position(|(full, short)| {
+                let full = full.to_lowercase();
+                full.starts_with(&name) || short.to_lowercase() == name
+            })
+            .map(|index| index as u8 + 1)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+/// Day of the week (0 = Sunday) for a Gregorian date
+pub fn weekday(year: i32, month: u8, day: u8) -> u8 {
+    // Sakamoto's method
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let month = month.clamp(1, 12);
+    let year = if month < 3 { year - 1 } else { year };
+    (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + OFFSETS[month as usize - 1] + day as i32).rem_euclid(7) as u8
+}
+
+/// Spaces used as grouping separators (regular, no-break, narrow no-break)
+fn is_space(c: char) -> bool {
+    c == ' ' || c == '\u{00A0}' || c == '\u{202F}'
+}
+
+/// Parse a year, expanding two-digit years into 2000-2099
+fn parse_year(token: &str) -> Option<i32> {
+    let year: i32 = token.parse().ok()?;
+    match token.len() {
+        2 => Some(2000 + year),
+        4 => Some(year),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_number_en_us() {
+        let locale = Locale::en_us();
+        assert_eq!(locale.parse_number("1,234.5"), Some(1234.5));
+        assert_eq!(locale.parse_number("-42"), Some(-42.0));
+        assert_eq!(locale.parse_number("1.2.3"), None);
+        assert_eq!(locale.parse_number("12abc"), None);
+    }
+
+    #[test]
+    fn parse_number_decimal_comma() {
+        let de = Locale::de_de();
+        assert_eq!(de.parse_number("1.234,5"), Some(1234.5));
+        assert_eq!(de.parse_number("0,75"), Some(0.75));
+
+        let fr = Locale::fr_fr();
+        assert_eq!(fr.parse_number("1\u{202F}234,5"), Some(1234.5));
+        assert_eq!(fr.parse_number("1 234,5"), Some(1234.5));
+    }
+
+    #[test]
+    fn format_number_groups_digits() {
+        assert_eq!(Locale::en_us().format_number(1234567.891, 2), "1,234,567.89");
+        assert_eq!(Locale::de_de().format_number(-1234.5, 1), "-1.234,5");
+        assert_eq!(Locale::en_us().format_number(12.0, 0), "12");
+        assert_eq!(Locale::de_de().number_placeholder(), "1.234,56");
+    }
+
+    #[test]
+    fn parse_numeric_dates_in_locale_order() {
+        assert_eq!(Locale::en_us().parse_date("11/22/2025"), Some(Date::new(2025, 11, 22)));
+        assert_eq!(Locale::en_gb().parse_date("22/11/2025"), Some(Date::new(2025, 11, 22)));
+        assert_eq!(Locale::de_de().parse_date("22.11.25"), Some(Date::new(2025, 11, 22)));
+    }
+
+    #[test]
+    fn parse_iso_date_in_any_locale() {
+        assert_eq!(Locale::de_de().parse_date("2025-11-22"), Some(Date::new(2025, 11, 22)));
+        assert_eq!(Locale::en_us().parse_date("2025-11-22"), Some(Date::new(2025, 11, 22)));
+    }
+
+    #[test]
+    fn parse_dates_with_month_names() {
+        let en = Locale::en_us();
+        assert_eq!(en.parse_date("Nov 22, 2025"), Some(Date::new(2025, 11, 22)));
+        assert_eq!(en.parse_date("22 november 2025"), Some(Date::new(2025, 11, 22)));
+
+        let de = Locale::de_de();
+        assert_eq!(de.parse_date("3. März 2024"), Some(Date::new(2024, 3, 3)));
+
+        let fr = Locale::fr_fr();
+        assert_eq!(fr.parse_date("14 juillet 1789"), Some(Date::new(1789, 7, 14)));
+    }
+
+    #[test]
+    fn parse_date_rejects_invalid() {
+        let en = Locale::en_us();
+        assert_eq!(en.parse_date("02/30/2025"), None);
+        assert_eq!(en.parse_date("not a date"), None);
+        assert_eq!(en.parse_date("11/22"), None);
+    }
+
+    #[test]
+    fn format_date_round_trips() {
+        for locale in [Locale::en_us(), Locale::en_gb(), Locale::de_de(), Locale::fr_fr()] {
+            let date = Date::new(2025, 3, 7);
+            let text = locale.format_date(&date);
+            assert_eq!(locale.parse_date(&text), Some(date), "{}", locale.code);
+        }
+    }
+
+    #[test]
+    fn locale_from_code() {
+        assert_eq!(Locale::from_code("de_DE").code, "de-DE");
+        assert_eq!(Locale::from_code("en-gb").code, "en-GB");
+        assert_eq!(Locale::from_code("xx").code, "en-US");
+    }
+
+    #[test]
+    fn format_dates_with_locale_patterns() {
+        let date = Date::new(2025, 11, 22);
+        assert_eq!(weekday(2025, 11, 22), 6);
+        assert_eq!(weekday(2000, 1, 1), 6);
+        assert_eq!(Locale::en_us().format_date_as(&date, &DateFormat::Short), "11/22/2025");
+        assert_eq!(Locale::en_us().format_date_as(&date, &DateFormat::Medium), "Nov 22, 2025");
+        assert_eq!(Locale::en_us().format_date_as(&date, &DateFormat::Long), "Saturday, November 22, 2025");
+        assert_eq!(Locale::de_de().format_date_as(&date, &DateFormat::Long), "Samstag, 22. November 2025");
+        assert_eq!(Locale::fr_fr().format_date_as(&date, &DateFormat::Medium), "22 nov 2025");
+        let custom = DateFormat::Custom("EEE d/M/yy 'at' 'noon'''".to_string());
+        assert_eq!(Locale::en_gb().format_date_as(&date, &custom), "Sat 22/11/25 at noon'");
+        assert_eq!(Locale::de_de().weekday_headers(1)[0], "Mo");
+    }
+}
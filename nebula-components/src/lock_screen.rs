@@ -0,0 +1,281 @@
+// LockScreen Component - Covers the app until the user proves who they are
+// Locks on inactivity (or on demand) and unlocks only through a verify callback
+
+use nebula_core::{announce, FocusKey, IdleDetector, Politeness, QualitySettings, Signal, ThemeProvider};
+use nebula_gfx::draw_list::DrawList;
+use nebula_gfx::RendererCapabilities;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+type VerifyCallback = Box<dyn Fn(&str) -> bool>;
+
+/// LockScreen component - blurs the app and asks for a PIN or password 🔒
+///
+/// The secret never leaves this component except through `verify`, which
+/// decides whether it's right (check a hash, ask a badge reader's SDK,
+/// call the auth server). Without a verify callback the screen can't be
+/// unlocked, so a missing setup step fails closed.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut lock = LockScreen::new(Duration::from_secs(300))
+///     .title("Ward 3 workstation locked")
+///     .verify(move |pin| auth.check_pin(&user, pin));
+/// // Every frame:
+/// lock.update();
+/// lock.paint(&mut list, (0.0, 0.0, width, height));
+/// ```
+pub struct LockScreen {
+    pub locked: Signal<bool>,
+    pub idle: IdleDetector,
+    pub title: String,
+    pub prompt: String,
+    pub entry: Signal<String>,
+    pub error: Signal<Option<String>>,
+    pub failed_attempts: u32,
+    pub backdrop_color: (u8, u8, u8, u8),
+    pub backdrop_blur: f32,
+    verify: Option<VerifyCallback>,
+    pub on_lock: Option<Box<dyn Fn()>>,
+    pub on_unlock: Option<Box<dyn Fn()>>,
+}
+
+impl LockScreen {
+    /// Lock screen that locks after `timeout` without input
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            locked: Signal::new(false),
+            idle: IdleDetector::new(timeout),
+            title: "Locked".to_string(),
+            prompt: "Enter your PIN to continue".to_string(),
+            entry: Signal::new(String::new()),
+            error: Signal::new(None),
+            failed_attempts: 0,
+            backdrop_color: (0, 0, 0, 160),
+            backdrop_blur: 24.0,
+            verify: None,
+            on_lock: None,
+            on_unlock: None,
+        }
+    }
+
+    /// Set the title
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the prompt under the title
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Set the backdrop blur amount (in pixels)
+    pub fn backdrop_blur(mut self, blur: f32) -> Self {
+        self.backdrop_blur = blur;
+        self
+    }
+
+    /// Decide whether an entered secret unlocks the screen
+    pub fn verify<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'static,
+    {
+        self.verify = Some(Box::new(callback));
+        self
+    }
+
+    /// Called when the screen locks
+    pub fn on_lock<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_lock = Some(Box::new(callback));
+        self
+    }
+
+    /// Called after a successful unlock
+    pub fn on_unlock<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_unlock = Some(Box::new(callback));
+        self
+    }
+
+    /// Lock now
+    pub fn lock(&mut self) {
+        if self.is_locked() {
+            return;
+        }
+        self.locked.set(true);
+        self.entry.set(String::new());
+        self.error.set(None);
+        info!("🔒 Screen locked");
+        announce(format!("{}. {}", self.title, self.prompt), Politeness::Assertive);
+        if let Some(ref callback) = self.on_lock {
+            callback();
+        }
+    }
+
+    /// Is the app covered?
+    pub fn is_locked(&self) -> bool {
+        self.locked.get()
+    }
+
+    /// Lock if the user has gone idle (call every frame); returns true when it locked
+    pub fn update(&mut self) -> bool {
+        self.update_at(Instant::now())
+    }
+
+    /// Lock if the user was idle at `now`; returns true when it locked
+    pub fn update_at(&mut self, now: Instant) -> bool {
+        if self.idle.poll_at(now) && !self.is_locked() {
+            self.lock();
+            return true;
+        }
+        false
+    }
+
+    /// Type into the secret field (ignored while unlocked)
+    pub fn type_text(&mut self, text: &str) {
+        if self.is_locked() {
+            self.entry.update(|entry| entry.clone() + text);
+        }
+    }
+
+    /// Try the entered secret; returns true if it unlocked
+    pub fn submit(&mut self) -> bool {
+        if !self.is_locked() {
+            return false;
+        }
+        let entry = self.entry.get();
+        self.entry.set(String::new());
+        let verified = match &self.verify {
+            Some(verify) => verify(&entry),
+            None => {
+                warn!("⚠️ LockScreen has no verify callback; it can't be unlocked");
+                false
+            }
+        };
+        if !verified {
+            self.failed_attempts += 1;
+            self.error.set(Some("Incorrect, try again".to_string()));
+            announce("Incorrect, try again", Politeness::Assertive);
+            return false;
+        }
+        self.failed_attempts = 0;
+        self.error.set(None);
+        self.locked.set(false);
+        info!("🔓 Screen unlocked");
+        if let Some(ref callback) = self.on_unlock {
+            callback();
+        }
+        true
+    }
+
+    /// Keyboard input while locked; returns true if the key was used
+    /// (everything is swallowed so nothing reaches the app behind)
+    pub fn handle_key(&mut self, key: FocusKey) -> bool {
+        if !self.is_locked() {
+            return false;
+        }
+        match key {
+            FocusKey::Enter => {
+                self.submit();
+            }
+            FocusKey::Backspace => self.entry.update(|entry| {
+                let mut chars = entry.chars();
+                chars.next_back();
+                chars.as_str().to_string()
+            }),
+            FocusKey::Escape => self.entry.set(String::new()),
+            FocusKey::Char(c) if !c.is_control() => self.type_text(&c.to_string()),
+            _ => {}
+        }
+        true
+    }
+
+    /// Backdrop to draw (color, blur radius)
+    /// Without blur the dim is made nearly opaque: the app behind must not
+    /// be readable on a locked screen
+    pub fn effective_backdrop(&self) -> ((u8, u8, u8, u8), f32) {
+        let blur = RendererCapabilities::current().blur && QualitySettings::current().blur;
+        if blur && self.backdrop_blur > 0.0 {
+            return (self.backdrop_color, self.backdrop_blur);
+        }
+        let (r, g, b, _) = self.backdrop_color;
+        ((r, g, b, 245), 0.0)
+    }
+
+    /// Draw the overlay and the unlock card over `bounds` (the whole window)
+    pub fn paint(&self, list: &mut DrawList, bounds: (f32, f32, f32, f32)) {
+        if !self.is_locked() {
+            return;
+        }
+        let theme = ThemeProvider::current();
+        let colors = theme.colors;
+        let (x, y, width, height) = bounds;
+        list.rect(bounds, self.effective_backdrop().0);
+
+        let (card_width, card_height) = (320.0_f32.min(width - 32.0), 200.0);
+        let (card_x, card_y) = (x + (width - card_width) / 2.0, y + (height - card_height) / 2.0);
+        list.rounded_rect((card_x, card_y, card_width, card_height), 12.0, colors.surface_variant);
+        let left = card_x + 24.0;
+        list.text(self.title.clone(), left, card_y + 24.0, theme.font_size * 1.25, colors.text);
+        list.text(self.prompt.clone(), left, card_y + 60.0, theme.font_size, colors.text);
+
+        // Secret field: dots, never the characters
+        let field = (left, card_y + 96.0, card_width - 48.0, 36.0);
+        list.rounded_rect(field, 6.0, colors.border);
+        let dots = "•".repeat(self.entry.get().chars().count());
+        list.text(dots, field.0 + 12.0, field.1 + 8.0, theme.font_size, colors.text);
+
+        if let Some(error) = self.error.get() {
+            list.text(error, left, card_y + 148.0, theme.font_size * 0.9, colors.danger);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locks_when_idle_and_unlocks_with_verified_pin() {
+        let mut lock = LockScreen::new(Duration::from_secs(60)).verify(|pin| pin == "1234");
+        let start = Instant::now();
+        nebula_core::idle::record_input_at(start);
+        assert!(!lock.update_at(start + Duration::from_secs(10)));
+        assert!(lock.update_at(start + Duration::from_secs(61)));
+        assert!(lock.is_locked());
+
+        for c in "9999".chars() {
+            lock.handle_key(FocusKey::Char(c));
+        }
+        assert!(lock.handle_key(FocusKey::Enter));
+        assert!(lock.is_locked());
+        assert_eq!(lock.failed_attempts, 1);
+        assert!(lock.error.get().is_some());
+
+        lock.type_text("12345");
+        lock.handle_key(FocusKey::Backspace);
+        assert!(lock.submit());
+        assert!(!lock.is_locked());
+        assert_eq!(lock.entry.get(), "");
+    }
+
+    #[test]
+    fn without_verify_it_stays_locked() {
+        let mut lock = LockScreen::new(Duration::from_secs(60));
+        lock.lock();
+        lock.type_text("anything");
+        assert!(!lock.submit());
+        assert!(lock.is_locked());
+
+        let mut list = DrawList::new();
+        lock.paint(&mut list, (0.0, 0.0, 800.0, 600.0));
+        assert!(!list.is_empty());
+    }
+}
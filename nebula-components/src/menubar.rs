@@ -1,8 +1,14 @@
 // MenuBar Component - Native application menu bar
 // Essential for desktop applications (File, Edit, View, Help, etc.)
 
-use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::command::{CommandBus, REDO, UNDO};
+use nebula_core::events::EventQueue;
+use nebula_core::layout::{LayoutDirection, LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+
+use super::events::MenuEvent;
+use super::shortcuts::ShortcutRegistry;
 
 /// Menu item in a menu
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +18,8 @@ pub struct MenuItem {
     pub disabled: bool,
     pub is_separator: bool,
     pub shortcut: Option<String>,
+    /// Shortcut as bound in a ShortcutRegistry (see `MenuBar::shortcuts`)
+    pub shortcut_hint: Option<String>,
     pub icon: Option<String>,
     pub submenu: Option<Vec<MenuItem>>,
 }
@@ -25,6 +33,7 @@ impl MenuItem {
             disabled: false,
             is_separator: false,
             shortcut: None,
+            shortcut_hint: None,
             icon: None,
             submenu: None,
         }
@@ -38,6 +47,7 @@ impl MenuItem {
             disabled: false,
             is_separator: true,
             shortcut: None,
+            shortcut_hint: None,
             icon: None,
             submenu: None,
         }
@@ -51,6 +61,7 @@ impl MenuItem {
             disabled: true,
             is_separator: false,
             shortcut: None,
+            shortcut_hint: None,
             icon: None,
             submenu: None,
         }
@@ -74,6 +85,11 @@ impl MenuItem {
         self
     }
 
+    /// Trailing text: the registry's hint, else the declared shortcut
+    pub fn trailing_text(&self) -> Option<&str> {
+        self.shortcut_hint.as_deref().or(self.shortcut.as_deref())
+    }
+
     /// Check if has submenu
     pub fn has_submenu(&self) -> bool {
         self.submenu.is_some()
@@ -168,36 +184,59 @@ pub struct MenuBar {
     pub active_menu: Signal<Option<usize>>,
     pub height: f32,
     pub padding: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
-    pub active_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub text_color: ThemeColor,
+    pub hover_color: ThemeColor,
+    pub active_color: ThemeColor,
+    pub disabled_color: ThemeColor,
     pub on_action: Option<Box<dyn Fn(&str)>>,
     pub on_menu_open: Option<Box<dyn Fn(&str)>>,
     pub on_menu_close: Option<Box<dyn Fn()>>,
+    /// Actions the bus knows run through it (see `commands`)
+    pub commands: Option<CommandBus>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<MenuEvent>>,
 }
 
 impl MenuBar {
-    /// Create a new MenuBar component
+    /// Create a new MenuBar component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             menus: Vec::new(),
             active_menu: Signal::new(None),
             height: 32.0,
             padding: 8.0,
-            background_color: (240, 240, 240, 255),
-            text_color: (0, 0, 0, 255),
-            hover_color: (220, 220, 220, 255),
-            active_color: (200, 200, 200, 255),
-            disabled_color: (150, 150, 150, 255),
+            background_color: colors.surface_variant,
+            text_color: colors.text,
+            hover_color: colors.hover,
+            active_color: colors.border,
+            disabled_color: colors.text_muted,
             on_action: None,
             on_menu_open: None,
             on_menu_close: None,
+            commands: None,
+            events: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface_variant;
+        self.text_color = colors.text;
+        self.hover_color = colors.hover;
+        self.active_color = colors.border;
+        self.disabled_color = colors.text_muted;
+    }
+
     /// Set the height
     pub fn height(mut self, height: f32) -> Self {
         self.height = height;
@@ -273,13 +312,62 @@ impl MenuBar {
         self
     }
 
+    /// Route actions through a CommandBus
+    /// Items for actions it knows follow its enabled state, and Undo/Redo
+    /// show what they'd undo ("Undo Rename"); see `sync_commands`
+    pub fn commands(mut self, bus: CommandBus) -> Self {
+        self.commands = Some(bus);
+        self.sync_commands();
+        self
+    }
+
+    /// Emit typed events into a queue (alongside any callbacks)
+    pub fn events(mut self, events: &EventQueue<MenuEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    fn emit(&self, event: MenuEvent) {
+        if let Some(ref events) = self.events {
+            events.emit(event);
+        }
+    }
+
+    /// Refresh item labels and disabled states from the CommandBus
+    /// (call before opening a menu, or when the undo history changes)
+    pub fn sync_commands(&mut self) {
+        fn sync(bus: &CommandBus, items: &mut [MenuItem]) {
+            for item in items {
+                if let Some(ref mut submenu) = item.submenu {
+                    sync(bus, submenu);
+                }
+                if item.is_separator || !bus.knows(&item.action) {
+                    continue;
+                }
+                item.disabled = !bus.is_enabled(&item.action);
+                if item.action == UNDO || item.action == REDO {
+                    if let Some(label) = bus.label(&item.action) {
+                        item.label = label;
+                    }
+                }
+            }
+        }
+        if let Some(ref bus) = self.commands {
+            for menu in &mut self.menus {
+                sync(bus, &mut menu.items);
+            }
+        }
+    }
+
     /// Open a menu by index
     pub fn open_menu(&mut self, index: usize) {
         if index < self.menus.len() {
+            self.sync_commands();
             self.active_menu.set(Some(index));
             if let Some(ref callback) = self.on_menu_open {
                 callback(&self.menus[index].label);
             }
+            self.emit(MenuEvent::Opened(self.menus[index].label.clone()));
         }
     }
 
@@ -289,6 +377,7 @@ impl MenuBar {
         if let Some(ref callback) = self.on_menu_close {
             callback();
         }
+        self.emit(MenuEvent::Closed);
     }
 
     /// Get the active menu index
@@ -302,11 +391,21 @@ impl MenuBar {
     }
 
     /// Execute an action
+    /// Actions the CommandBus knows go to it; the rest to `on_action`
     pub fn execute_action(&mut self, action: &str) {
-        if let Some(ref callback) = self.on_action {
-            callback(action);
+        match self.commands {
+            Some(ref bus) if bus.knows(action) => {
+                bus.dispatch(action);
+            }
+            _ => {
+                if let Some(ref callback) = self.on_action {
+                    callback(action);
+                }
+            }
         }
+        self.emit(MenuEvent::Action(action.to_string()));
         self.close_menu();
+        self.sync_commands();
     }
 
     /// Select an item from the active menu
@@ -325,6 +424,42 @@ impl MenuBar {
         }
     }
 
+    /// Show the shortcuts a registry actually binds next to each item
+    pub fn shortcuts(mut self, shortcuts: &ShortcutRegistry) -> Self {
+        self.apply_shortcut_hints(shortcuts);
+        self
+    }
+
+    /// Refresh shortcut hints (call after rebinding keys)
+    pub fn apply_shortcut_hints(&mut self, shortcuts: &ShortcutRegistry) {
+        fn apply(shortcuts: &ShortcutRegistry, items: &mut [MenuItem]) {
+            for item in items {
+                if let Some(ref mut submenu) = item.submenu {
+                    apply(shortcuts, submenu);
+                }
+                if !item.is_separator {
+                    item.shortcut_hint = shortcuts.hint_for(&item.action);
+                }
+            }
+        }
+        for menu in &mut self.menus {
+            apply(shortcuts, &mut menu.items);
+        }
+    }
+
+    /// Item (in any menu or submenu) that runs an action
+    pub fn find_item(&self, action: &str) -> Option<&MenuItem> {
+        fn find<'a>(items: &'a [MenuItem], action: &str) -> Option<&'a MenuItem> {
+            items.iter().find_map(|item| {
+                if !item.is_separator && item.action == action {
+                    return Some(item);
+                }
+                item.submenu.as_deref().and_then(|submenu| find(submenu, action))
+            })
+        }
+        self.menus.iter().find_map(|menu| find(&menu.items, action))
+    }
+
     /// Get menu count
     pub fn menu_count(&self) -> usize {
         self.menus.len()
@@ -359,7 +494,8 @@ impl MenuBar {
                 bottom: taffy::style::LengthPercentage::Length(0.0),
             },
             display: taffy::style::Display::Flex,
-            flex_direction: taffy::style::FlexDirection::Row,
+            // Menus start at the right edge in RTL
+            flex_direction: LayoutDirection::current().row(),
             ..Default::default()
         };
 
@@ -411,6 +547,28 @@ mod tests {
         assert_eq!(menu.item_count(), 4);
     }
 
+    #[test]
+    fn edit_menu_follows_the_command_bus() {
+        use nebula_core::command::FnCommand;
+
+        let bus = CommandBus::new();
+        let mut menubar = MenuBar::new()
+            .add_menu(Menu::new("Edit").add_item("Undo", "edit.undo").add_item("Redo", "edit.redo").add_item("Find", "edit.find"))
+            .commands(bus.clone());
+        assert!(menubar.menus[0].items[0].disabled);
+        assert!(!menubar.menus[0].items[2].disabled); // Not a bus action
+
+        bus.execute(Box::new(FnCommand::new("Rename", || {}, || {}))).unwrap();
+        menubar.open_menu(0);
+        assert_eq!(menubar.menus[0].items[0].label, "Undo Rename");
+        assert!(!menubar.menus[0].items[0].disabled);
+
+        menubar.select_item(0);
+        assert!(!bus.history().can_undo());
+        assert_eq!(menubar.menus[0].items[0].label, "Undo");
+        assert_eq!(menubar.menus[0].items[1].label, "Redo Rename");
+    }
+
     #[test]
     fn menu_item_with_shortcut() {
         let item = MenuItem::new("Save", "file.save").with_shortcut("Ctrl+S");
@@ -0,0 +1,516 @@
+// MessageList Component - Chat transcript anchored at the newest message
+// Day separators, grouped bubbles, unread divider and typing indicator
+
+use std::collections::HashMap;
+
+use nebula_core::layout::{LayoutEngine, NodeId};
+
+use super::calendar::CalendarDate;
+use super::text::StyledText;
+use super::virtual_list::{ScrollAlign, VirtualList};
+
+/// A chat message
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatMessage {
+    pub id: String,
+    pub author: String,
+    pub body: StyledText,
+    /// Unix timestamp in seconds
+    pub timestamp: i64,
+    /// Sent by the local user (drawn on the trailing side)
+    pub outgoing: bool,
+}
+
+impl ChatMessage {
+    /// Create an incoming message
+    pub fn new(id: impl Into<String>, author: impl Into<String>, body: impl Into<StyledText>, timestamp: i64) -> Self {
+        Self {
+            id: id.into(),
+            author: author.into(),
+            body: body.into(),
+            timestamp,
+            outgoing: false,
+        }
+    }
+
+    /// Mark as sent by the local user
+    pub fn outgoing(mut self) -> Self {
+        self.outgoing = true;
+        self
+    }
+}
+
+/// One row of the transcript
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageRow {
+    /// "Today", "Yesterday" or a date, before the first message of a day
+    DaySeparator(CalendarDate),
+    /// "New messages" line before the first unread message
+    UnreadDivider,
+    /// A message bubble
+    Message {
+        index: usize,
+        /// First bubble of a run by the same author (shows name)
+        first_in_group: bool,
+        /// Last bubble of the run (shows avatar and time)
+        last_in_group: bool,
+    },
+    /// "Alice is typing…" at the very bottom
+    TypingIndicator,
+}
+
+/// MessageList component - chat view built on VirtualList 💬
+///
+/// The list follows the newest message while scrolled to the bottom, and
+/// keeps the visible message still when history is prepended above it or
+/// messages arrive while reading older ones.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut chat = MessageList::new().width(360.0).viewport_height(600.0);
+/// chat.push_message(ChatMessage::new("1", "Alice", "Hi! 👋", 1_700_000_000));
+/// chat.push_message(ChatMessage::new("2", "me", "Hello", 1_700_000_030).outgoing());
+/// chat.set_typing(vec!["Alice".to_string()]);
+/// ```
+pub struct MessageList {
+    pub node_id: Option<NodeId>,
+    pub messages: Vec<ChatMessage>,
+    /// Authors currently typing
+    pub typing: Vec<String>,
+    /// Last message the user has read (None = everything read)
+    pub last_read: Option<String>,
+    pub list: VirtualList,
+    pub width: f32,
+    pub viewport_height: f32,
+    pub font_size: f32,
+    pub bubble_padding: f32,
+    /// Bubbles are at most this fraction of the width
+    pub max_bubble_fraction: f32,
+    /// Gap between bubbles of one group / between groups
+    pub group_spacing: f32,
+    pub spacing: f32,
+    pub separator_height: f32,
+    pub typing_height: f32,
+    /// Messages further apart than this start a new group
+    pub group_window_seconds: i64,
+    /// Offset from UTC used for day separators
+    pub utc_offset_seconds: i64,
+    pub incoming_color: (u8, u8, u8, u8),
+    pub outgoing_color: (u8, u8, u8, u8),
+    pub unread_color: (u8, u8, u8, u8),
+    rows: Vec<MessageRow>,
+    unseen: usize,
+}
+
+impl MessageList {
+    /// Create an empty chat view
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            messages: Vec::new(),
+            typing: Vec::new(),
+            last_read: None,
+            list: VirtualList::new().follow_end(true).estimated_item_height(56.0).viewport_height(400.0),
+            width: 360.0,
+            viewport_height: 400.0,
+            font_size: 15.0,
+            bubble_padding: 10.0,
+            max_bubble_fraction: 0.75,
+            group_spacing: 2.0,
+            spacing: 12.0,
+            separator_height: 36.0,
+            typing_height: 40.0,
+            group_window_seconds: 5 * 60,
+            utc_offset_seconds: 0,
+            incoming_color: (229, 231, 235, 255), // Light gray
+            outgoing_color: (59, 130, 246, 255),  // Blue
+            unread_color: (239, 68, 68, 255),     // Red
+            rows: Vec::new(),
+            unseen: 0,
+        }
+    }
+
+    /// Set the width (used to estimate wrapped bubble heights)
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        // Wrapping changes, so every height has to be estimated again
+        self.list.cache.clear();
+        self.refresh();
+        self
+    }
+
+    /// Set the viewport height
+    pub fn viewport_height(mut self, height: f32) -> Self {
+        self.viewport_height = height;
+        self.list.set_viewport_height(height);
+        self
+    }
+
+    /// Set the font size
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self.list.cache.clear();
+        self.refresh();
+        self
+    }
+
+    /// Messages further apart than this start a new group
+    pub fn group_window(mut self, seconds: i64) -> Self {
+        self.group_window_seconds = seconds;
+        self.refresh();
+        self
+    }
+
+    /// Set the UTC offset used for day separators
+    pub fn utc_offset(mut self, seconds: i64) -> Self {
+        self.utc_offset_seconds = seconds;
+        self.refresh();
+        self
+    }
+
+    /// Append a new message
+    /// Incoming messages that arrive while scrolled up count as unseen
+    pub fn push_message(&mut self, message: ChatMessage) {
+        let at_latest = self.is_at_latest();
+        if !at_latest && !message.outgoing {
+            self.unseen += 1;
+        }
+        let outgoing = message.outgoing;
+        self.messages.push(message);
+        self.refresh();
+        if outgoing {
+            // Sending always jumps to your own message
+            self.jump_to_latest();
+        }
+    }
+
+    /// Insert older history above the current messages
+    /// The message on screen stays where it is
+    pub fn prepend_messages(&mut self, mut older: Vec<ChatMessage>) {
+        older.append(&mut self.messages);
+        self.messages = older;
+        self.refresh();
+    }
+
+    /// Set who is typing (empty hides the indicator)
+    pub fn set_typing(&mut self, authors: Vec<String>) {
+        self.typing = authors;
+        self.refresh();
+    }
+
+    /// Typing indicator text, e.g. "Alice and Bob are typing…"
+    pub fn typing_label(&self) -> Option<String> {
+        match self.typing.as_slice() {
+            [] => None,
+            [one] => Some(format!("{} is typing…", one)),
+            [one, two] => Some(format!("{} and {} are typing…", one, two)),
+            [one, rest @ ..] => Some(format!("{} and {} others are typing…", one, rest.len())),
+        }
+    }
+
+    /// Mark everything up to a message as read
+    pub fn mark_read(&mut self, id: &str) {
+        self.last_read = Some(id.to_string());
+        self.refresh();
+    }
+
+    /// Mark every message as read (removes the unread divider)
+    pub fn mark_all_read(&mut self) {
+        self.last_read = self.messages.last().map(|message| message.id.clone());
+        self.unseen = 0;
+        self.refresh();
+    }
+
+    /// Number of unread messages
+    pub fn unread_count(&self) -> usize {
+        match self.first_unread() {
+            Some(first) => self.messages[first..].iter().filter(|message| !message.outgoing).count(),
+            None => 0,
+        }
+    }
+
+    /// Messages that arrived while scrolled away from the bottom
+    pub fn unseen_count(&self) -> usize {
+        self.unseen
+    }
+
+    fn first_unread(&self) -> Option<usize> {
+        let last_read = self.last_read.as_ref()?;
+        let index = self.messages.iter().position(|message| &message.id == last_read)?;
+        (index + 1 < self.messages.len()).then_some(index + 1)
+    }
+
+    /// Rows in display order (oldest first)
+    pub fn rows(&self) -> &[MessageRow] {
+        &self.rows
+    }
+
+    /// Is the newest message in view?
+    pub fn is_at_latest(&self) -> bool {
+        self.list.is_empty() || self.list.is_at_end()
+    }
+
+    /// Show the "jump to latest" button?
+    /// Appears once the user scrolls more than half a screen up
+    pub fn show_jump_button(&self) -> bool {
+        let distance = self.list.max_scroll_offset() - self.list.scroll_offset();
+        distance > self.viewport_height / 2.0
+    }
+
+    /// Scroll to the newest message
+    pub fn jump_to_latest(&mut self) {
+        self.list.scroll_to_end();
+        self.unseen = 0;
+    }
+
+    /// Scroll to the unread divider
+    pub fn jump_to_unread(&mut self) {
+        if let Some(row) = self.rows.iter().position(|row| *row == MessageRow::UnreadDivider) {
+            self.list.scroll_to_index(row, ScrollAlign::Start);
+        }
+    }
+
+    /// Resize the viewport (keeps following the latest message)
+    pub fn set_viewport_height(&mut self, height: f32) {
+        self.viewport_height = height;
+        self.list.set_viewport_height(height);
+    }
+
+    /// Scroll by a delta (positive = toward newer messages)
+    pub fn scroll_by(&mut self, dy: f32) {
+        self.list.scroll_by(dy);
+        if self.is_at_latest() {
+            self.unseen = 0;
+        }
+    }
+
+    /// Should older history be loaded? (scrolled near the top)
+    pub fn needs_history(&self) -> bool {
+        !self.messages.is_empty() && self.list.scroll_offset() < self.viewport_height
+    }
+
+    /// Estimated height of a message row before it's measured
+    pub fn estimate_height(&self, index: usize, first_in_group: bool) -> f32 {
+        let Some(message) = self.messages.get(index) else {
+            return 0.0;
+        };
+        let line_height = (self.font_size * 1.35).ceil();
+        let bubble_width = self.width * self.max_bubble_fraction - self.bubble_padding * 2.0;
+        // Average glyph is about half the font size wide
+        let chars_per_line = (bubble_width / (self.font_size * 0.5)).floor().max(1.0) as usize;
+        let lines = message.body.estimate_lines(chars_per_line) as f32;
+        let author = if first_in_group && !message.outgoing { line_height } else { 0.0 };
+        let gap = if first_in_group { self.spacing } else { self.group_spacing };
+        lines * line_height + self.bubble_padding * 2.0 + author + gap
+    }
+
+    /// Recompute rows after messages, read state or typing change
+    fn refresh(&mut self) {
+        let first_unread = self.first_unread();
+        let mut rows = Vec::with_capacity(self.messages.len() + 4);
+        let mut previous: Option<(&ChatMessage, CalendarDate)> = None;
+
+        for (index, message) in self.messages.iter().enumerate() {
+            let day = CalendarDate::from_unix_timestamp(message.timestamp, self.utc_offset_seconds);
+            let new_day = previous.is_none_or(|(_, previous_day)| previous_day != day);
+            if new_day {
+                rows.push(MessageRow::DaySeparator(day));
+            }
+            let unread_here = first_unread == Some(index);
+            if unread_here {
+                rows.push(MessageRow::UnreadDivider);
+            }
+            let continues = !new_day
+                && !unread_here
+                && previous.is_some_and(|(previous, _)| self.same_group(previous, message));
+            if continues {
+                if let Some(MessageRow::Message { last_in_group, .. }) = rows.last_mut() {
+                    *last_in_group = false;
+                }
+            }
+            rows.push(MessageRow::Message { index, first_in_group: !continues, last_in_group: true });
+            previous = Some((message, day));
+        }
+        if !self.typing.is_empty() {
+            rows.push(MessageRow::TypingIndicator);
+        }
+
+        let ids: Vec<String> = rows.iter().map(|row| self.row_id(row)).collect();
+        for (row, id) in rows.iter().zip(&ids) {
+            match *row {
+                MessageRow::DaySeparator(_) | MessageRow::UnreadDivider => {
+                    self.list.cache.set_fixed(id.clone(), self.separator_height)
+                }
+                MessageRow::TypingIndicator => self.list.cache.set_fixed(id.clone(), self.typing_height),
+                MessageRow::Message { index, first_in_group, .. } => {
+                    // Estimates only; real measurements from `measure` win
+                    if self.list.cache.get(id).is_none() {
+                        let estimate = self.estimate_height(index, first_in_group);
+                        self.list.cache.insert(id.clone(), estimate);
+                    }
+                }
+            }
+        }
+        self.rows = rows;
+        self.list.set_items(ids);
+    }
+
+    fn same_group(&self, previous: &ChatMessage, message: &ChatMessage) -> bool {
+        previous.author == message.author
+            && previous.outgoing == message.outgoing
+            && message.timestamp - previous.timestamp <= self.group_window_seconds
+    }
+
+    /// Virtualization id of a row
+    pub fn row_id(&self, row: &MessageRow) -> String {
+        match row {
+            MessageRow::DaySeparator(day) => format!("day:{}", day.format()),
+            MessageRow::UnreadDivider => "unread".to_string(),
+            MessageRow::Message { index, .. } => format!("message:{}", self.messages[*index].id),
+            MessageRow::TypingIndicator => "typing".to_string(),
+        }
+    }
+
+    /// Record a measured row height
+    pub fn measure(&mut self, row: usize, height: f32) {
+        if let Some(id) = self.list.items().get(row).cloned() {
+            self.list.measure(&id, height);
+        }
+    }
+
+    /// Rows to build this frame (visible plus overscan)
+    pub fn render_rows(&self) -> Vec<(usize, &MessageRow)> {
+        self.list.render_range().map(|index| (index, &self.rows[index])).collect()
+    }
+
+    /// Build the message list layout (only the rows in view get nodes)
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let width = self.width;
+        let heights: HashMap<String, f32> = self
+            .list
+            .render_range()
+            .map(|index| (self.list.items()[index].clone(), self.list.item_height(index)))
+            .collect();
+        let node = self.list.build(engine, &mut |id: &str, engine: &mut LayoutEngine| {
+            let style = taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(width),
+                    height: taffy::style::Dimension::Length(heights.get(id).copied().unwrap_or(0.0)),
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            };
+            engine
+                .new_leaf(style)
+                .map_err(|e| format!("Failed to create message row node: {:?}", e))
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+impl Default for MessageList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+    const START: i64 = 1_700_000_000;
+
+    fn chat() -> MessageList {
+        let mut chat = MessageList::new().width(360.0).viewport_height(300.0);
+        chat.push_message(ChatMessage::new("1", "Alice", "Hi", START));
+        chat.push_message(ChatMessage::new("2", "Alice", "Are you there?", START + 30));
+        chat.push_message(ChatMessage::new("3", "me", "Yes", START + 60).outgoing());
+        chat.push_message(ChatMessage::new("4", "Alice", "Great", START + DAY));
+        chat
+    }
+
+    #[test]
+    fn rows_group_messages_and_split_days() {
+        let chat = chat();
+        let rows = chat.rows();
+        assert!(matches!(rows[0], MessageRow::DaySeparator(_)));
+        assert_eq!(rows[1], MessageRow::Message { index: 0, first_in_group: true, last_in_group: false });
+        assert_eq!(rows[2], MessageRow::Message { index: 1, first_in_group: false, last_in_group: true });
+        assert_eq!(rows[3], MessageRow::Message { index: 2, first_in_group: true, last_in_group: true });
+        assert!(matches!(rows[4], MessageRow::DaySeparator(_)));
+        assert_eq!(rows.len(), 6);
+    }
+
+    #[test]
+    fn unread_divider_and_typing_indicator() {
+        let mut chat = chat();
+        chat.mark_read("1");
+        assert_eq!(chat.rows()[2], MessageRow::UnreadDivider);
+        // The divider breaks the group
+        assert_eq!(chat.rows()[3], MessageRow::Message { index: 1, first_in_group: true, last_in_group: true });
+        assert_eq!(chat.unread_count(), 2);
+
+        chat.set_typing(vec!["Alice".to_string(), "Bob".to_string()]);
+        assert_eq!(chat.rows().last(), Some(&MessageRow::TypingIndicator));
+        assert_eq!(chat.typing_label().unwrap(), "Alice and Bob are typing…");
+
+        chat.mark_all_read();
+        assert!(!chat.rows().contains(&MessageRow::UnreadDivider));
+    }
+
+    #[test]
+    fn stays_anchored_to_latest() {
+        let mut chat = MessageList::new().viewport_height(200.0);
+        for i in 0..30 {
+            chat.push_message(ChatMessage::new(i.to_string(), "Alice", "Hello", START + i));
+        }
+        assert!(chat.is_at_latest());
+        assert!(!chat.show_jump_button());
+
+        chat.scroll_by(-500.0);
+        assert!(chat.show_jump_button());
+        chat.push_message(ChatMessage::new("new", "Alice", "Still there?", START + 100));
+        assert!(!chat.is_at_latest());
+        assert_eq!(chat.unseen_count(), 1);
+
+        chat.jump_to_latest();
+        assert!(chat.is_at_latest());
+        assert_eq!(chat.unseen_count(), 0);
+    }
+
+    #[test]
+    fn prepending_history_keeps_position() {
+        let mut chat = MessageList::new().viewport_height(200.0);
+        for i in 0..20 {
+            chat.push_message(ChatMessage::new(format!("m{}", i), "Alice", "Hello", START + i * 600));
+        }
+        chat.scroll_by(-400.0);
+        let anchor = chat.list.index_at(chat.list.scroll_offset()).unwrap();
+        let anchor_id = chat.list.items()[anchor].clone();
+        let within = chat.list.scroll_offset() - chat.list.offset_of(anchor);
+
+        let older = (0..5).map(|i| ChatMessage::new(format!("o{}", i), "Bob", "Old", START - 10_000 + i * 600)).collect();
+        chat.prepend_messages(older);
+
+        let index = chat.list.items().iter().position(|id| *id == anchor_id).unwrap();
+        assert!((chat.list.scroll_offset() - chat.list.offset_of(index) - within).abs() < 0.01);
+    }
+
+    #[test]
+    fn long_messages_estimate_taller() {
+        let mut chat = MessageList::new();
+        chat.push_message(ChatMessage::new("short", "Alice", "Hi", START));
+        chat.push_message(ChatMessage::new("long", "Bob", "word ".repeat(60), START + 10));
+        assert!(chat.estimate_height(1, true) > chat.estimate_height(0, true) * 2.0);
+    }
+
+    #[test]
+    fn build_creates_node() {
+        let mut engine = LayoutEngine::new();
+        let mut chat = chat();
+        assert!(chat.build(&mut engine).is_ok());
+        assert!(chat.node_id.is_some());
+    }
+}
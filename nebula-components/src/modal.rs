@@ -4,6 +4,8 @@
 use crate::container::ZStack;
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::QualitySettings;
+use nebula_gfx::RendererCapabilities;
 
 /// Modal component - displays content in a full-screen overlay with backdrop
 /// 
@@ -54,6 +56,18 @@ impl Modal {
         self
     }
 
+    /// Backdrop to draw (color, blur radius)
+    /// Without blur support the blur is dropped and the dim made stronger,
+    /// so the content behind still recedes
+    pub fn effective_backdrop(&self) -> ((u8, u8, u8, u8), f32) {
+        let blur = RendererCapabilities::current().blur && QualitySettings::current().blur;
+        if self.backdrop_blur <= 0.0 || blur {
+            return (self.backdrop_color, self.backdrop_blur);
+        }
+        let (r, g, b, a) = self.backdrop_color;
+        ((r, g, b, a.saturating_add(64)), 0.0)
+    }
+
     /// Set the callback for when the backdrop is clicked
     pub fn on_backdrop_click<F>(mut self, callback: F) -> Self
     where
@@ -0,0 +1,413 @@
+// NavigationStack - Push/pop screens with native-feeling transitions
+// Slide, fade and shared-element animations plus interactive back-swipe from the edge
+
+use nebula_core::animation::SpringAnimation;
+use nebula_core::signal::Signal;
+use tracing::info;
+
+use crate::scroll_memory::ScrollMemory;
+
+/// How a screen change animates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenTransition {
+    /// Swap instantly
+    None,
+    /// New screen slides in from the end edge, the old one drifts back
+    Slide,
+    /// Cross-fade
+    Fade,
+    /// Cross-fade while elements with the same test id move between their
+    /// positions on the two screens
+    SharedElement,
+}
+
+/// A screen on the stack
+#[derive(Debug, Clone, PartialEq)]
+pub struct Screen {
+    pub route: String,
+    pub title: String,
+    /// Elements that can fly between screens: (test id, x, y, width, height)
+    pub shared_elements: Vec<(String, (f32, f32, f32, f32))>,
+}
+
+impl Screen {
+    /// Create a screen
+    pub fn new(route: impl Into<String>, title: impl Into<String>) -> Self {
+        Self { route: route.into(), title: title.into(), shared_elements: Vec::new() }
+    }
+
+    /// Mark an element as shared (matched by test id across screens)
+    pub fn shared_element(mut self, test_id: impl Into<String>, bounds: (f32, f32, f32, f32)) -> Self {
+        self.shared_elements.push((test_id.into(), bounds));
+        self
+    }
+}
+
+/// Which way the stack is moving
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavDirection {
+    Push,
+    Pop,
+}
+
+/// Where to draw a screen this frame (bottom layer first)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenLayer {
+    pub route: String,
+    pub offset_x: f32,
+    pub opacity: f32,
+}
+
+/// Where to draw a shared element this frame (above both screens)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedElementFrame {
+    pub test_id: String,
+    pub bounds: (f32, f32, f32, f32),
+}
+
+#[derive(Debug, Clone)]
+struct ActiveTransition {
+    kind: ScreenTransition,
+    direction: NavDirection,
+    from: Screen,
+    to: Screen,
+    /// 0 = `from` fully shown, 1 = `to` fully shown
+    spring: Option<SpringAnimation>,
+    /// Back-swipe in progress: progress follows the finger
+    dragging: Option<(f32, f32)>,
+    progress: f32,
+}
+
+/// Called with the route shown after a push or pop
+pub type NavigateCallback = Box<dyn Fn(&str, NavDirection)>;
+
+/// NavigationStack - Screen history with push/pop transitions 📱
+///
+/// The stack changes the moment you push or pop; the transition only
+/// affects drawing. Each frame call `update`, then draw `layers()` bottom
+/// to top and `shared_elements()` on top. Reduced motion makes every
+/// transition instant (the springs jump to the end).
+///
+/// A touch that starts within `edge_width` of the start edge can drag the
+/// top screen away (`begin_back_swipe` / `drag_back_swipe` /
+/// `end_back_swipe`); letting go past half way pops it.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut nav = NavigationStack::new(Screen::new("/inbox", "Inbox")).width(390.0);
+/// nav.push(Screen::new("/inbox/42", "Message").shared_element("avatar-42", (16.0, 80.0, 64.0, 64.0)));
+/// // every frame
+/// nav.update(dt);
+/// for layer in nav.layers() { draw_screen(&layer.route, layer.offset_x, layer.opacity); }
+/// ```
+pub struct NavigationStack {
+    /// Screens from the root up; never empty, so only changed through
+    /// `push` / `pop` / back-swipes
+    stack: Signal<Vec<Screen>>,
+    pub default_transition: ScreenTransition,
+    /// Screen width, for slide distances and back-swipe progress
+    pub width: f32,
+    /// Touches starting this close to the start edge can swipe back
+    pub edge_width: f32,
+    pub on_navigate: Option<NavigateCallback>,
+    transition: Option<ActiveTransition>,
+}
+
+impl NavigationStack {
+    /// Stack with a root screen
+    pub fn new(root: Screen) -> Self {
+        ScrollMemory::set_route(&root.route);
+        Self {
+            stack: Signal::new(vec![root]),
+            default_transition: ScreenTransition::Slide,
+            width: 390.0,
+            edge_width: 24.0,
+            on_navigate: None,
+            transition: None,
+        }
+    }
+
+    /// Set the screen width
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width.max(1.0);
+        self
+    }
+
+    /// Set the transition used by `push` and `pop`
+    pub fn default_transition(mut self, transition: ScreenTransition) -> Self {
+        self.default_transition = transition;
+        self
+    }
+
+    /// Set the navigation callback (route shown after the change)
+    pub fn on_navigate<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, NavDirection) + 'static,
+    {
+        self.on_navigate = Some(Box::new(callback));
+        self
+    }
+
+    /// Screens from the root up (tracked, so effects rerun on navigation)
+    pub fn screens(&self) -> Vec<Screen> {
+        self.stack.get()
+    }
+
+    /// Screen on top
+    pub fn current(&self) -> Screen {
+        self.stack.with(|stack| stack.last().cloned().expect("navigation stack is never empty"))
+    }
+
+    /// Number of screens
+    pub fn depth(&self) -> usize {
+        self.stack.with(Vec::len)
+    }
+
+    /// Is there a screen to go back to?
+    pub fn can_go_back(&self) -> bool {
+        self.depth() > 1
+    }
+
+    /// Push with the default transition
+    pub fn push(&mut self, screen: Screen) {
+        self.push_with(screen, self.default_transition);
+    }
+
+    /// Push with a specific transition
+    pub fn push_with(&mut self, screen: Screen, transition: ScreenTransition) {
+        let from = self.current();
+        self.stack.update_in_place(|stack| stack.push(screen.clone()));
+        self.start(transition, NavDirection::Push, from, screen);
+    }
+
+    /// Pop with the default transition; false at the root
+    pub fn pop(&mut self) -> bool {
+        self.pop_with(self.default_transition)
+    }
+
+    /// Pop with a specific transition; false at the root
+    pub fn pop_with(&mut self, transition: ScreenTransition) -> bool {
+        if !self.can_go_back() {
+            return false;
+        }
+        let from = self.current();
+        self.stack.update_in_place(|stack| {
+            stack.pop();
+        });
+        let to = self.current();
+        self.start(transition, NavDirection::Pop, from, to);
+        true
+    }
+
+    fn start(&mut self, kind: ScreenTransition, direction: NavDirection, from: Screen, to: Screen) {
+        info!("📱 {:?} {} → {}", direction, from.route, to.route);
+        self.arrived(&to.route, (direction == NavDirection::Pop).then_some(from.route.as_str()));
+        self.transition = (kind != ScreenTransition::None).then(|| ActiveTransition {
+            kind,
+            direction,
+            from,
+            to,
+            spring: Some(spring(0.0, 1.0)),
+            dragging: None,
+            progress: 0.0,
+        });
+    }
+
+    /// Is a transition (or back-swipe) running?
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Transition progress (0 = old screen, 1 = new screen; None when idle)
+    pub fn progress(&self) -> Option<f32> {
+        self.transition.as_ref().map(|t| t.progress)
+    }
+
+    /// Advance the transition (call every frame)
+    /// Returns true while screens are still moving
+    pub fn update(&mut self, dt: f32) -> bool {
+        let Some(transition) = self.transition.as_mut() else {
+            return false;
+        };
+        if transition.dragging.is_some() {
+            return true;
+        }
+        let Some(spring) = transition.spring.as_mut() else {
+            return false;
+        };
+        let moving = spring.update(dt);
+        transition.progress = spring.value().clamp(0.0, 1.0);
+        if moving {
+            return true;
+        }
+        // A cancelled back-swipe springs to 0: the top screen stays
+        self.transition = None;
+        false
+    }
+
+    /// A touch went down; true if it started a back-swipe
+    pub fn begin_back_swipe(&mut self, x: f32) -> bool {
+        if x > self.edge_width || !self.can_go_back() || self.transition.is_some() {
+            return false;
+        }
+        let (from, to) = self.stack.with(|stack| (stack[stack.len() - 1].clone(), stack[stack.len() - 2].clone()));
+        self.transition = Some(ActiveTransition {
+            kind: ScreenTransition::Slide,
+            direction: NavDirection::Pop,
+            from,
+            to,
+            spring: None,
+            dragging: Some((x, 0.0)),
+            progress: 0.0,
+        });
+        true
+    }
+
+    /// The swiping touch moved
+    pub fn drag_back_swipe(&mut self, x: f32) {
+        let width = self.width;
+        if let Some(transition) = self.transition.as_mut() {
+            if let Some((start, _)) = transition.dragging {
+                transition.progress = ((x - start) / width).clamp(0.0, 1.0);
+                transition.dragging = Some((start, x));
+            }
+        }
+    }
+
+    /// The swiping touch lifted; true if the screen was popped
+    pub fn end_back_swipe(&mut self) -> bool {
+        let Some(transition) = self.transition.as_mut() else {
+            return false;
+        };
+        if transition.dragging.take().is_none() {
+            return false;
+        }
+        let commit = transition.progress >= 0.5;
+        transition.spring = Some(spring(transition.progress, if commit { 1.0 } else { 0.0 }));
+        if commit {
+            let (route, popped) = (transition.to.route.clone(), transition.from.route.clone());
+            self.stack.update_in_place(|stack| {
+                stack.pop();
+            });
+            info!("📱 Back-swipe → {}", route);
+            self.arrived(&route, Some(&popped));
+        }
+        commit
+    }
+
+    /// Point scroll memory at the new screen (forgetting a popped one) and
+    /// tell the app
+    fn arrived(&self, route: &str, popped: Option<&str>) {
+        if let Some(popped) = popped {
+            if !self.stack.with(|stack| stack.iter().any(|screen| screen.route == popped)) {
+                ScrollMemory::forget_route(popped);
+            }
+        }
+        ScrollMemory::set_route(route);
+        if let Some(ref callback) = self.on_navigate {
+            let direction = if popped.is_some() { NavDirection::Pop } else { NavDirection::Push };
+            callback(route, direction);
+        }
+    }
+
+    /// Screens to draw this frame, bottom first
+    pub fn layers(&self) -> Vec<ScreenLayer> {
+        let Some(ref t) = self.transition else {
+            return vec![ScreenLayer { route: self.current().route, offset_x: 0.0, opacity: 1.0 }];
+        };
+        let p = t.progress;
+        let w = self.width;
+        let layer = |screen: &Screen, offset_x: f32, opacity: f32| ScreenLayer { route: screen.route.clone(), offset_x, opacity };
+        match (t.kind, t.direction) {
+            (ScreenTransition::Slide, NavDirection::Push) => vec![layer(&t.from, -0.3 * w * p, 1.0), layer(&t.to, w * (1.0 - p), 1.0)],
+            (ScreenTransition::Slide, NavDirection::Pop) => vec![layer(&t.to, -0.3 * w * (1.0 - p), 1.0), layer(&t.from, w * p, 1.0)],
+            _ => vec![layer(&t.from, 0.0, 1.0 - p), layer(&t.to, 0.0, p)],
+        }
+    }
+
+    /// Shared elements in flight (empty unless a SharedElement transition runs)
+    pub fn shared_elements(&self) -> Vec<SharedElementFrame> {
+        let Some(ref t) = self.transition else {
+            return Vec::new();
+        };
+        if t.kind != ScreenTransition::SharedElement {
+            return Vec::new();
+        }
+        let lerp = |a: f32, b: f32| a + (b - a) * t.progress;
+        t.from
+            .shared_elements
+            .iter()
+            .filter_map(|(id, a)| {
+                let (_, b) = t.to.shared_elements.iter().find(|(other, _)| other == id)?;
+                Some(SharedElementFrame { test_id: id.clone(), bounds: (lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2), lerp(a.3, b.3)) })
+            })
+            .collect()
+    }
+}
+
+fn spring(from: f32, to: f32) -> SpringAnimation {
+    SpringAnimation::new(from, to).stiffness(300.0).damping(35.0).label("screen transition")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(nav: &mut NavigationStack) {
+        for _ in 0..600 {
+            if !nav.update(1.0 / 60.0) {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn push_slides_and_shared_elements_fly() {
+        let mut nav = NavigationStack::new(Screen::new("/inbox", "Inbox").shared_element("avatar", (16.0, 80.0, 40.0, 40.0))).width(400.0);
+        nav.push(Screen::new("/inbox/42", "Message"));
+        assert_eq!(nav.current().route, "/inbox/42");
+        let routes: Vec<String> = nav.screens().into_iter().map(|screen| screen.route).collect();
+        assert_eq!(routes, ["/inbox", "/inbox/42"]);
+        let layers = nav.layers();
+        assert_eq!(layers[1].route, "/inbox/42");
+        assert_eq!(layers[1].offset_x, 400.0); // Starts off-screen
+        settle(&mut nav);
+        assert!(!nav.is_transitioning());
+        assert_eq!(nav.layers()[0].offset_x, 0.0);
+
+        nav.pop_with(ScreenTransition::SharedElement);
+        nav.push_with(Screen::new("/profile", "Profile").shared_element("avatar", (150.0, 40.0, 100.0, 100.0)), ScreenTransition::SharedElement);
+        nav.update(0.05);
+        let flying = nav.shared_elements();
+        assert_eq!(flying.len(), 1);
+        assert!(flying[0].bounds.0 > 16.0 && flying[0].bounds.0 < 150.0);
+    }
+
+    #[test]
+    fn back_swipe_pops_past_half_way() {
+        let popped = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = popped.clone();
+        let mut nav = NavigationStack::new(Screen::new("/", "Home"))
+            .width(400.0)
+            .default_transition(ScreenTransition::None)
+            .on_navigate(move |route, direction| log.borrow_mut().push((route.to_string(), direction)));
+        nav.push(Screen::new("/settings", "Settings"));
+
+        assert!(!nav.begin_back_swipe(200.0)); // Not at the edge
+        assert!(nav.begin_back_swipe(10.0));
+        nav.drag_back_swipe(110.0);
+        assert_eq!(nav.progress(), Some(0.25));
+        assert_eq!(nav.layers()[1].offset_x, 100.0);
+        assert!(!nav.end_back_swipe()); // Cancelled
+        settle(&mut nav);
+        assert_eq!(nav.current().route, "/settings");
+
+        assert!(nav.begin_back_swipe(5.0));
+        nav.drag_back_swipe(305.0);
+        assert!(nav.end_back_swipe());
+        assert_eq!(nav.current().route, "/");
+        settle(&mut nav);
+        assert!(!nav.can_go_back());
+        assert_eq!(popped.borrow().last(), Some(&("/".to_string(), NavDirection::Pop)));
+    }
+}
@@ -1,8 +1,13 @@
 // Navigation Component - Navigation bar for app/website navigation
 // Essential for top-level navigation with logo, links, and actions
 
-use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::analytics::Analytics;
+use nebula_core::layout::{LayoutDirection, LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
+use nebula_gfx::draw_list::{DrawList, Rect};
+use super::badge::ItemBadge;
+use super::reorder::{index_after_move, keyboard_target, move_item, ReorderCallback, ReorderState};
 
 /// Navigation item (link or button)
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +18,8 @@ pub struct NavItem {
     pub icon: Option<String>,
     pub badge: Option<String>,
     pub href: Option<String>,
+    /// Unread count badge bound to a Signal
+    pub unread: Option<ItemBadge>,
 }
 
 impl NavItem {
@@ -25,6 +32,7 @@ impl NavItem {
             icon: None,
             badge: None,
             href: None,
+            unread: None,
         }
     }
 
@@ -37,6 +45,7 @@ impl NavItem {
             icon: None,
             badge: None,
             href: None,
+            unread: None,
         }
     }
 
@@ -52,6 +61,19 @@ impl NavItem {
         self
     }
 
+    /// Show an unread badge or dot that follows a count Signal
+    pub fn with_unread(mut self, badge: ItemBadge) -> Self {
+        self.unread = Some(badge);
+        self
+    }
+
+    /// Draw the unread badge on the item's top-right corner
+    pub fn paint_badge(&self, list: &mut DrawList, bounds: Rect) {
+        if let Some(ref badge) = self.unread {
+            badge.paint(list, bounds);
+        }
+    }
+
     /// Add a link href
     pub fn with_href(mut self, href: impl Into<String>) -> Self {
         self.href = Some(href.into());
@@ -90,11 +112,15 @@ pub struct Navigation {
     pub on_navigate: Option<Box<dyn Fn(&str)>>,
     pub on_action: Option<Box<dyn Fn(&str)>>,
     pub on_logo_click: Option<Box<dyn Fn()>>,
+    pub reorderable: bool,
+    pub reorder: ReorderState,
+    pub on_reorder: Option<ReorderCallback>,
 }
 
 impl Navigation {
-    /// Create a new Navigation component
+    /// Create a new Navigation component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             logo: None,
@@ -103,19 +129,39 @@ impl Navigation {
             active_item: Signal::new(None),
             height: 64.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            text_color: (100, 100, 100, 255),
-            active_color: (59, 130, 246, 255), // Blue
-            hover_color: (240, 240, 240, 255),
-            logo_color: (0, 0, 0, 255),
-            border_color: (220, 220, 220, 255),
+            background_color: colors.surface,
+            text_color: colors.text_muted,
+            active_color: colors.primary,
+            hover_color: colors.hover,
+            logo_color: colors.text,
+            border_color: colors.border,
             show_border: true,
             on_navigate: None,
             on_action: None,
             on_logo_click: None,
+            reorderable: false,
+            reorder: ReorderState::new(),
+            on_reorder: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.text_color = colors.text_muted;
+        self.active_color = colors.primary;
+        self.hover_color = colors.hover;
+        self.logo_color = colors.text;
+        self.border_color = colors.border;
+    }
+
     /// Set the logo text
     pub fn logo(mut self, logo: impl Into<String>) -> Self {
         self.logo = Some(logo.into());
@@ -239,10 +285,85 @@ impl Navigation {
         self
     }
 
+    /// Enable drag-to-reorder
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Set the reorder callback (old index, new index)
+    pub fn on_reorder<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + 'static,
+    {
+        self.on_reorder = Some(Box::new(callback));
+        self
+    }
+
+    /// Start dragging a nav item (`x` relative to the first one, `widths` from layout)
+    pub fn start_drag(&mut self, index: usize, x: f32, widths: Vec<f32>) {
+        if self.reorderable && index < self.items.len() && widths.len() == self.items.len() {
+            self.reorder.begin(index, widths, x);
+        }
+    }
+
+    /// Move the dragged item, returning the slot it would drop into
+    pub fn drag(&mut self, x: f32) -> Option<usize> {
+        self.reorder.drag_to(x)
+    }
+
+    /// Drop the dragged item into its target slot
+    pub fn drop_item(&mut self) {
+        if let Some((from, to)) = self.reorder.end() {
+            self.move_item(from, to);
+        }
+    }
+
+    /// Abandon the current drag
+    pub fn cancel_drag(&mut self) {
+        self.reorder.cancel();
+    }
+
+    /// Move a nav item one slot toward the start (Ctrl+Left)
+    pub fn move_item_left(&mut self, index: usize) -> bool {
+        self.move_item_by(index, -1)
+    }
+
+    /// Move a nav item one slot toward the end (Ctrl+Right)
+    pub fn move_item_right(&mut self, index: usize) -> bool {
+        self.move_item_by(index, 1)
+    }
+
+    fn move_item_by(&mut self, index: usize, delta: isize) -> bool {
+        if !self.reorderable || self.reorder.is_dragging() {
+            return false;
+        }
+        let Some(target) = keyboard_target(index, delta, self.items.len()) else {
+            return false;
+        };
+        self.move_item(index, target);
+        true
+    }
+
+    /// Move a nav item, keeping the active one active, and notify `on_reorder`
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        if from >= self.items.len() || to >= self.items.len() || from == to {
+            return;
+        }
+        move_item(&mut self.items, from, to);
+        if let Some(active) = self.active_item.get() {
+            self.active_item.set(Some(index_after_move(active, from, to)));
+        }
+        if let Some(ref callback) = self.on_reorder {
+            callback(from, to);
+        }
+    }
+
     /// Navigate to an item by index
     pub fn navigate_to(&mut self, index: usize) {
         if index < self.items.len() && !self.items[index].disabled {
             self.active_item.set(Some(index));
+            Analytics::screen_view(&self.items[index].id);
             if let Some(ref callback) = self.on_navigate {
                 callback(&self.items[index].id);
             }
@@ -256,6 +377,30 @@ impl Navigation {
         }
     }
 
+    /// Highlight the item for each state of a signal (e.g. `StateMachine::signal()`)
+    ///
+    /// `item_id` maps a state to an item id; None clears the highlight.
+    /// Add the items first. `on_navigate` isn't called, since the state
+    /// already changed.
+    pub fn follow_state<S, F>(self, state: &Signal<S>, item_id: F) -> Self
+    where
+        S: Clone + 'static,
+        F: Fn(&S) -> Option<String> + 'static,
+    {
+        let ids: Vec<String> = self.items.iter().map(|item| item.id.clone()).collect();
+        let active = self.active_item.clone();
+        let sync = move |state: &S| {
+            let id = item_id(state);
+            if let Some(ref id) = id {
+                Analytics::screen_view(id);
+            }
+            active.set(id.and_then(|id| ids.iter().position(|item| *item == id)));
+        };
+        sync(&state.get());
+        state.subscribe(sync);
+        self
+    }
+
     /// Execute an action by index
     pub fn execute_action(&mut self, index: usize) {
         if index < self.actions.len() && !self.actions[index].disabled {
@@ -296,6 +441,16 @@ impl Navigation {
         self.active_item.get() == Some(index)
     }
 
+    /// Advance unread badge animations (call each frame); true while any runs
+    pub fn update_badges(&self, delta_time: f32) -> bool {
+        self.items
+            .iter()
+            .chain(&self.actions)
+            .filter_map(|item| item.unread.as_ref())
+            .map(|badge| badge.update(delta_time))
+            .fold(false, |running, animating| running | animating)
+    }
+
     /// Get item count
     pub fn item_count(&self) -> usize {
         self.items.len()
@@ -355,7 +510,8 @@ impl Navigation {
                 bottom: taffy::style::LengthPercentage::Length(0.0),
             },
             display: taffy::style::Display::Flex,
-            flex_direction: taffy::style::FlexDirection::Row,
+            // Logo at the right and items flowing leftwards in RTL
+            flex_direction: LayoutDirection::current().row(),
             justify_content: Some(taffy::style::JustifyContent::SpaceBetween),
             align_items: Some(taffy::style::AlignItems::Center),
             ..Default::default()
@@ -618,4 +774,17 @@ mod tests {
         let item = NavItem::disabled("Disabled", "disabled");
         assert!(item.disabled);
     }
+
+    #[test]
+    fn navigation_keyboard_reorder() {
+        let mut nav = Navigation::new()
+            .reorderable(true)
+            .add_item("Home", "home")
+            .add_item("About", "about");
+        nav.navigate_to(1);
+
+        assert!(nav.move_item_left(1));
+        assert_eq!(nav.items[0].id, "about");
+        assert_eq!(nav.get_active_item(), Some(0));
+    }
 }
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Pagination component - page navigation for paginated content
 /// 
@@ -30,20 +31,21 @@ pub struct Pagination {
     pub padding: f32,
     pub spacing: f32,
     pub button_size: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub active_color: (u8, u8, u8, u8),
-    pub inactive_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
-    pub active_text_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub active_color: ThemeColor,
+    pub inactive_color: ThemeColor,
+    pub hover_color: ThemeColor,
+    pub text_color: ThemeColor,
+    pub active_text_color: ThemeColor,
+    pub disabled_color: ThemeColor,
     pub on_page_change: Option<Box<dyn Fn(usize)>>,
     pub on_page_size_change: Option<Box<dyn Fn(usize)>>,
 }
 
 impl Pagination {
-    /// Create a new Pagination component
+    /// Create a new Pagination component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             current_page: Signal::new(1),
@@ -58,18 +60,36 @@ impl Pagination {
             padding: 8.0,
             spacing: 4.0,
             button_size: 36.0,
-            background_color: (255, 255, 255, 255),
-            active_color: (59, 130, 246, 255), // Blue
-            inactive_color: (245, 245, 245, 255),
-            hover_color: (220, 220, 220, 255),
-            text_color: (100, 100, 100, 255),
-            active_text_color: (255, 255, 255, 255),
-            disabled_color: (200, 200, 200, 255),
+            background_color: colors.surface,
+            active_color: colors.primary,
+            inactive_color: colors.hover,
+            hover_color: colors.border,
+            text_color: colors.text_muted,
+            active_text_color: colors.on_primary,
+            disabled_color: colors.disabled,
             on_page_change: None,
             on_page_size_change: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.active_color = colors.primary;
+        self.inactive_color = colors.hover;
+        self.hover_color = colors.border;
+        self.text_color = colors.text_muted;
+        self.active_text_color = colors.on_primary;
+        self.disabled_color = colors.disabled;
+    }
+
     /// Set the total number of pages
     pub fn total_pages(mut self, total: usize) -> Self {
         self.total_pages = total.max(1);
@@ -0,0 +1,304 @@
+// Palette - Color-blind-safe color palettes for charts and data display
+// Curated categorical and sequential palettes with automatic series colors
+
+use std::collections::HashMap;
+
+/// Background the palette will be drawn on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteMode {
+    Light,
+    Dark,
+}
+
+/// What kind of data the palette encodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteKind {
+    /// Distinct, unordered categories (one color per series)
+    Categorical,
+    /// Ordered values from low to high
+    Sequential,
+}
+
+/// A named list of colors 🎨
+///
+/// The built-in palettes stay distinguishable for the common forms of
+/// color blindness (deuteranopia, protanopia, tritanopia).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub name: String,
+    pub kind: PaletteKind,
+    pub colors: Vec<(u8, u8, u8, u8)>,
+}
+
+impl Palette {
+    /// Create a custom palette
+    pub fn new(name: impl Into<String>, kind: PaletteKind, colors: Vec<(u8, u8, u8, u8)>) -> Self {
+        Self { name: name.into(), kind, colors }
+    }
+
+    /// Okabe-Ito categorical palette (without black)
+    /// Works on dark backgrounds, the de facto standard for color-blind-safe charts
+    pub fn okabe_ito() -> Self {
+        Self::new("Okabe-Ito", PaletteKind::Categorical, vec![
+            (230, 159, 0, 255),   // Orange
+            (86, 180, 233, 255),  // Sky blue
+            (0, 158, 115, 255),   // Bluish green
+            (240, 228, 66, 255),  // Yellow
+            (0, 114, 178, 255),   // Blue
+            (213, 94, 0, 255),    // Vermillion
+            (204, 121, 167, 255), // Reddish purple
+        ])
+    }
+
+    /// Paul Tol's "bright" categorical palette
+    /// Enough contrast against white for light backgrounds
+    pub fn tol_bright() -> Self {
+        Self::new("Tol Bright", PaletteKind::Categorical, vec![
+            (68, 119, 170, 255),  // Blue
+            (238, 102, 119, 255), // Red
+            (34, 136, 51, 255),   // Green
+            (204, 187, 68, 255),  // Yellow
+            (102, 204, 238, 255), // Cyan
+            (170, 51, 119, 255),  // Purple
+            (187, 187, 187, 255), // Grey
+        ])
+    }
+
+    /// Paul Tol's "muted" categorical palette (9 colors, for busier charts)
+    pub fn tol_muted() -> Self {
+        Self::new("Tol Muted", PaletteKind::Categorical, vec![
+            (51, 34, 136, 255),   // Indigo
+            (136, 204, 238, 255), // Cyan
+            (68, 170, 153, 255),  // Teal
+            (17, 119, 51, 255),   // Green
+            (153, 153, 51, 255),  // Olive
+            (221, 204, 119, 255), // Sand
+            (204, 102, 119, 255), // Rose
+            (136, 34, 85, 255),   // Wine
+            (170, 68, 153, 255),  // Purple
+        ])
+    }
+
+    /// Viridis sequential palette (dark purple to yellow)
+    /// Perceptually uniform and readable in grayscale
+    pub fn viridis() -> Self {
+        Self::new("Viridis", PaletteKind::Sequential, vec![
+            (68, 1, 84, 255),
+            (72, 40, 120, 255),
+            (62, 74, 137, 255),
+            (49, 104, 142, 255),
+            (38, 130, 142, 255),
+            (31, 158, 137, 255),
+            (53, 183, 121, 255),
+            (109, 205, 89, 255),
+            (180, 222, 44, 255),
+            (253, 231, 37, 255),
+        ])
+    }
+
+    /// Cividis sequential palette (blue to yellow)
+    /// Designed to look the same with and without color vision deficiency
+    pub fn cividis() -> Self {
+        Self::new("Cividis", PaletteKind::Sequential, vec![
+            (0, 34, 78, 255),
+            (18, 53, 112, 255),
+            (59, 73, 108, 255),
+            (87, 93, 109, 255),
+            (112, 113, 115, 255),
+            (138, 134, 120, 255),
+            (165, 156, 116, 255),
+            (195, 179, 105, 255),
+            (225, 204, 85, 255),
+            (254, 232, 56, 255),
+        ])
+    }
+
+    /// Default categorical palette for a background
+    pub fn categorical(mode: PaletteMode) -> Self {
+        match mode {
+            PaletteMode::Light => Self::tol_bright(),
+            PaletteMode::Dark => Self::okabe_ito(),
+        }
+    }
+
+    /// Default sequential palette for a background
+    /// Low values fade toward the background color in both modes
+    pub fn sequential(mode: PaletteMode) -> Self {
+        match mode {
+            PaletteMode::Light => Self::viridis().reversed(),
+            PaletteMode::Dark => Self::viridis(),
+        }
+    }
+
+    /// Same palette with the color order reversed
+    pub fn reversed(mut self) -> Self {
+        self.colors.reverse();
+        self
+    }
+
+    /// Number of colors
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Is the palette empty?
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Color at an index, wrapping around for more series than colors
+    pub fn color(&self, index: usize) -> (u8, u8, u8, u8) {
+        if self.colors.is_empty() {
+            return (128, 128, 128, 255);
+        }
+        self.colors[index % self.colors.len()]
+    }
+
+    /// Sample a position from 0.0 (low) to 1.0 (high), interpolating between stops
+    pub fn sample(&self, t: f32) -> (u8, u8, u8, u8) {
+        match self.colors.len() {
+            0 => (128, 128, 128, 255),
+            1 => self.colors[0],
+            n => {
+                let position = t.clamp(0.0, 1.0) * (n - 1) as f32;
+                let i = (position.floor() as usize).min(n - 2);
+                let f = position - i as f32;
+                let (a, b) = (self.colors[i], self.colors[i + 1]);
+                let lerp = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * f).round() as u8;
+                (lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2), lerp(a.3, b.3))
+            }
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::categorical(PaletteMode::Light)
+    }
+}
+
+/// Automatic series color assignment
+///
+/// Series get palette colors in the order they're first seen, so the same
+/// series keeps its color when others are added or hidden. Overrides win.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut colors = SeriesColors::new(Palette::categorical(PaletteMode::Dark))
+///     .with_override("Errors", (213, 94, 0, 255));
+/// let revenue = colors.color_for("Revenue");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SeriesColors {
+    pub palette: Palette,
+    overrides: HashMap<String, (u8, u8, u8, u8)>,
+    assigned: Vec<String>,
+}
+
+impl SeriesColors {
+    /// Create an assigner for a palette
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            palette,
+            overrides: HashMap::new(),
+            assigned: Vec::new(),
+        }
+    }
+
+    /// Pin a series to a specific color
+    pub fn with_override(mut self, series: impl Into<String>, color: (u8, u8, u8, u8)) -> Self {
+        self.set_override(series, color);
+        self
+    }
+
+    /// Pin a series to a specific color
+    pub fn set_override(&mut self, series: impl Into<String>, color: (u8, u8, u8, u8)) {
+        self.overrides.insert(series.into(), color);
+    }
+
+    /// Remove a color override
+    pub fn clear_override(&mut self, series: &str) {
+        self.overrides.remove(series);
+    }
+
+    /// Swap the palette, keeping series order (and therefore slots)
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    /// Color for a series, assigning the next palette slot on first use
+    pub fn color_for(&mut self, series: &str) -> (u8, u8, u8, u8) {
+        if let Some(&color) = self.overrides.get(series) {
+            return color;
+        }
+        let index = match self.assigned.iter().position(|s| s == series) {
+            Some(index) => index,
+            None => {
+                self.assigned.push(series.to_string());
+                self.assigned.len() - 1
+            }
+        };
+        self.palette.color(index)
+    }
+
+    /// Forget all automatic assignments (overrides are kept)
+    pub fn reset(&mut self) {
+        self.assigned.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_defaults_per_mode() {
+        assert_eq!(Palette::categorical(PaletteMode::Dark).name, "Okabe-Ito");
+        assert_eq!(Palette::categorical(PaletteMode::Light).name, "Tol Bright");
+        assert_eq!(Palette::sequential(PaletteMode::Dark).kind, PaletteKind::Sequential);
+    }
+
+    #[test]
+    fn palette_color_wraps() {
+        let palette = Palette::okabe_ito();
+        assert_eq!(palette.color(0), palette.color(palette.len()));
+    }
+
+    #[test]
+    fn palette_sample_endpoints_and_midpoint() {
+        let palette = Palette::new("Test", PaletteKind::Sequential, vec![
+            (0, 0, 0, 255),
+            (200, 100, 50, 255),
+        ]);
+        assert_eq!(palette.sample(0.0), (0, 0, 0, 255));
+        assert_eq!(palette.sample(1.0), (200, 100, 50, 255));
+        assert_eq!(palette.sample(0.5), (100, 50, 25, 255));
+        assert_eq!(palette.sample(2.0), (200, 100, 50, 255));
+    }
+
+    #[test]
+    fn sequential_light_starts_light() {
+        let light = Palette::sequential(PaletteMode::Light);
+        let dark = Palette::sequential(PaletteMode::Dark);
+        assert_eq!(light.sample(0.0), dark.sample(1.0));
+    }
+
+    #[test]
+    fn series_colors_are_stable() {
+        let mut colors = SeriesColors::new(Palette::okabe_ito());
+        let a = colors.color_for("A");
+        let b = colors.color_for("B");
+        assert_ne!(a, b);
+        assert_eq!(colors.color_for("A"), a);
+        assert_eq!(b, Palette::okabe_ito().color(1));
+    }
+
+    #[test]
+    fn series_color_override() {
+        let mut colors = SeriesColors::new(Palette::okabe_ito())
+            .with_override("Errors", (255, 0, 0, 255));
+        assert_eq!(colors.color_for("Errors"), (255, 0, 0, 255));
+        colors.clear_override("Errors");
+        assert_eq!(colors.color_for("Errors"), Palette::okabe_ito().color(0));
+    }
+}
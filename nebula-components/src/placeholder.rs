@@ -0,0 +1,229 @@
+// Placeholder Component - Instant low-quality previews while images load
+// BlurHash strings and tiny LQIP thumbnails, decoded off the UI thread and cross-faded into the real image
+
+use image::{DynamicImage, Rgba, RgbaImage};
+use nebula_core::animation::MotionPreference;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+const BASE83: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// What to show before the real image arrives
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImagePlaceholder {
+    /// A BlurHash string (a couple dozen bytes, usually sent with the URL)
+    BlurHash(String),
+    /// A tiny encoded preview (e.g. a 16px JPEG), scaled up and blurred by the renderer
+    Preview(Vec<u8>),
+    /// A flat color (dominant color of the image)
+    Color((u8, u8, u8, u8)),
+}
+
+impl ImagePlaceholder {
+    /// Decode to pixels (`width` x `height` for BlurHash and Color; previews keep their size)
+    pub fn decode(&self, width: u32, height: u32) -> Result<DynamicImage, String> {
+        match self {
+            ImagePlaceholder::BlurHash(hash) => decode_blurhash(hash, width, height, 1.0).map(DynamicImage::ImageRgba8),
+            ImagePlaceholder::Preview(bytes) => {
+                image::load_from_memory(bytes).map_err(|e| format!("Failed to decode preview: {}", e))
+            }
+            ImagePlaceholder::Color((r, g, b, a)) => {
+                Ok(DynamicImage::ImageRgba8(RgbaImage::from_pixel(width.max(1), height.max(1), Rgba([*r, *g, *b, *a]))))
+            }
+        }
+    }
+}
+
+/// Decode a BlurHash into `width` x `height` pixels
+/// `punch` scales the contrast (1.0 = as encoded)
+pub fn decode_blurhash(hash: &str, width: u32, height: u32, punch: f32) -> Result<RgbaImage, String> {
+    let bytes = hash.as_bytes();
+    if bytes.len() < 6 {
+        return Err("BlurHash too short".to_string());
+    }
+    let size_flag = decode83(&bytes[..1])?;
+    let (components_x, components_y) = ((size_flag % 9 + 1) as usize, (size_flag / 9 + 1) as usize);
+    if bytes.len() != 4 + 2 * components_x * components_y {
+        return Err(format!("BlurHash length {} doesn't match {}x{} components", bytes.len(), components_x, components_y));
+    }
+    let max_value = (decode83(&bytes[1..2])? + 1) as f32 / 166.0 * punch;
+
+    let dc = decode83(&bytes[2..6])?;
+    let mut colors = vec![[srgb_to_linear(dc >> 16), srgb_to_linear(dc >> 8), srgb_to_linear(dc)]];
+    for chunk in bytes[6..].chunks(2) {
+        let value = decode83(chunk)?;
+        let ac = |quantized: u32| sign_pow((quantized as f32 - 9.0) / 9.0, 2.0) * max_value;
+        colors.push([ac(value / (19 * 19)), ac((value / 19) % 19), ac(value % 19)]);
+    }
+
+    let (width, height) = (width.max(1), height.max(1));
+    let mut image = RgbaImage::new(width, height);
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let mut rgb = [0.0f32; 3];
+        for j in 0..components_y {
+            for i in 0..components_x {
+                let basis = (std::f32::consts::PI * x as f32 * i as f32 / width as f32).cos()
+                    * (std::f32::consts::PI * y as f32 * j as f32 / height as f32).cos();
+                let color = colors[i + j * components_x];
+                for channel in 0..3 {
+                    rgb[channel] += color[channel] * basis;
+                }
+            }
+        }
+        *pixel = Rgba([linear_to_srgb(rgb[0]), linear_to_srgb(rgb[1]), linear_to_srgb(rgb[2]), 255]);
+    }
+    Ok(image)
+}
+
+fn decode83(chars: &[u8]) -> Result<u32, String> {
+    chars.iter().try_fold(0u32, |value, &c| {
+        let digit = BASE83.iter().position(|&b| b == c).ok_or_else(|| format!("Invalid BlurHash character '{}'", c as char))?;
+        Ok(value * 83 + digit as u32)
+    })
+}
+
+fn srgb_to_linear(value: u32) -> f32 {
+    let v = (value & 255) as f32 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let srgb = if v <= 0.003_130_8 { v * 12.92 } else { 1.055 * v.powf(1.0 / 2.4) - 0.055 };
+    (srgb * 255.0).round() as u8
+}
+
+fn sign_pow(value: f32, exponent: f32) -> f32 {
+    value.abs().powf(exponent).copysign(value)
+}
+
+/// A placeholder being decoded in the background 🧵
+///
+/// Decoding starts on a worker thread as soon as it's created (inline on
+/// wasm); `image()` returns None until it's ready. Cheap to clone.
+#[derive(Clone)]
+pub struct PlaceholderImage {
+    pub placeholder: ImagePlaceholder,
+    decoded: Arc<Mutex<Option<DynamicImage>>>,
+}
+
+impl PlaceholderImage {
+    /// Start decoding at `width` x `height` (small sizes like 32x32 are plenty; the renderer scales it)
+    pub fn start(placeholder: ImagePlaceholder, width: u32, height: u32) -> Self {
+        let decoded = Arc::new(Mutex::new(None));
+        let slot = decoded.clone();
+        let job = placeholder.clone();
+        let decode = move || match job.decode(width, height) {
+            Ok(image) => *slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(image),
+            Err(e) => warn!("⚠️ Placeholder not shown: {}", e),
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(e) = std::thread::Builder::new().name("nebula-placeholder".to_string()).spawn(decode) {
+            warn!("⚠️ Could not start placeholder thread: {}", e);
+        }
+        #[cfg(target_arch = "wasm32")]
+        decode();
+        Self { placeholder, decoded }
+    }
+
+    /// The decoded placeholder, once ready
+    pub fn image(&self) -> Option<DynamicImage> {
+        self.decoded.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    /// Finished decoding?
+    pub fn is_ready(&self) -> bool {
+        self.decoded.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).is_some()
+    }
+}
+
+/// Cross-fade from placeholder to full image (instant under reduced motion)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossFade {
+    pub duration: Duration,
+    started: Option<Instant>,
+}
+
+impl CrossFade {
+    /// Fade lasting `duration`
+    pub fn new(duration: Duration) -> Self {
+        Self { duration, started: None }
+    }
+
+    /// The full image arrived; begin fading it in
+    pub fn start(&mut self) {
+        self.started.get_or_insert_with(Instant::now);
+    }
+
+    /// Back to the placeholder (new source)
+    pub fn reset(&mut self) {
+        self.started = None;
+    }
+
+    /// Opacity of the full image at `now` (0.0 = placeholder only)
+    pub fn progress_at(&self, now: Instant) -> f32 {
+        let Some(started) = self.started else {
+            return 0.0;
+        };
+        let duration = MotionPreference::duration(self.duration);
+        if duration.is_zero() {
+            return 1.0;
+        }
+        (now.saturating_duration_since(started).as_secs_f32() / duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Opacity of the full image right now
+    pub fn progress(&self) -> f32 {
+        self.progress_at(Instant::now())
+    }
+
+    /// Still fading (keep requesting frames)?
+    pub fn is_animating(&self) -> bool {
+        self.started.is_some() && self.progress() < 1.0
+    }
+}
+
+impl Default for CrossFade {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(250))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blurhash_decodes_solid_and_rejects_bad_hashes() {
+        // 1x1 components, DC = #3B82F6
+        let image = decode_blurhash("006-B$", 4, 3, 1.0).unwrap();
+        assert_eq!(image.dimensions(), (4, 3));
+        assert_eq!(image.get_pixel(2, 1), &Rgba([59, 130, 246, 255]));
+
+        assert!(decode_blurhash("LEHV6nWB2yk8pyo0adR*.7kCMdnj", 32, 32, 1.0).is_ok());
+        assert!(decode_blurhash("LEHV6nWB2yk8", 32, 32, 1.0).is_err());
+        assert!(decode_blurhash("00 -B$", 4, 4, 1.0).is_err());
+    }
+
+    #[test]
+    fn placeholder_decodes_in_background_and_fades() {
+        let placeholder = PlaceholderImage::start(ImagePlaceholder::Color((10, 20, 30, 255)), 8, 8);
+        let started = Instant::now();
+        while !placeholder.is_ready() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(placeholder.image().unwrap().width(), 8);
+
+        let mut fade = CrossFade::new(Duration::from_millis(200));
+        assert_eq!(fade.progress(), 0.0);
+        fade.start();
+        let begun = fade.started.unwrap();
+        assert!((fade.progress_at(begun + Duration::from_millis(100)) - 0.5).abs() < 0.01);
+        assert_eq!(fade.progress_at(begun + Duration::from_secs(1)), 1.0);
+    }
+}
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
 
 /// Popover position relative to trigger
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -68,8 +69,9 @@ pub struct Popover {
 }
 
 impl Popover {
-    /// Create a new Popover component
+    /// Create a new Popover component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             title: None,
@@ -82,9 +84,9 @@ impl Popover {
             max_width: 400.0,
             max_height: 600.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            text_color: (0, 0, 0, 255),
-            border_color: (200, 200, 200, 255),
+            background_color: colors.surface,
+            text_color: colors.text,
+            border_color: colors.border,
             border_width: 1.0,
             border_radius: 8.0,
             show_arrow: true,
@@ -97,6 +99,20 @@ impl Popover {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.text_color = colors.text;
+        self.border_color = colors.border;
+    }
+
     /// Set the title
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -0,0 +1,724 @@
+// PrintPreview Component - Paginated preview of what will come out of the printer
+// Paper, orientation, margins, header/footer templates and zoom; hands a PrintJob to nebula-platform's Printer
+
+use crate::chart_export::{ChartPrimitive, ChartScene, TextAnchor};
+use nebula_core::{FocusKey, LayoutEngine, NodeId, QualitySettings, Signal};
+use nebula_gfx::draw_list::DrawList;
+use nebula_gfx::{PageOrientation, PaperSize, RendererCapabilities};
+#[cfg(feature = "export-png")]
+use nebula_gfx::{PrintJob, RgbaImage};
+use std::cell::RefCell;
+#[cfg(feature = "export-png")]
+use std::path::Path;
+use std::rc::Rc;
+use tracing::info;
+
+/// Page margins in points (1/72 inch)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageMargins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl PageMargins {
+    /// Create margins
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    /// Same margin on every side
+    pub fn uniform(margin: f32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+}
+
+impl Default for PageMargins {
+    /// One inch all round
+    fn default() -> Self {
+        Self::uniform(72.0)
+    }
+}
+
+/// Header or footer line: left, centered and right parts
+///
+/// Parts may use `{page}`, `{pages}` and `{title}`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PageTemplate {
+    pub left: String,
+    pub center: String,
+    pub right: String,
+}
+
+impl PageTemplate {
+    /// Empty template (nothing drawn)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the left part
+    pub fn left(mut self, text: impl Into<String>) -> Self {
+        self.left = text.into();
+        self
+    }
+
+    /// Set the centered part
+    pub fn center(mut self, text: impl Into<String>) -> Self {
+        self.center = text.into();
+        self
+    }
+
+    /// Set the right part
+    pub fn right(mut self, text: impl Into<String>) -> Self {
+        self.right = text.into();
+        self
+    }
+
+    /// Is every part empty?
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.center.is_empty() && self.right.is_empty()
+    }
+
+    /// Fill in placeholders for a page (1-based)
+    pub fn expand(text: &str, page: usize, pages: usize, title: &str) -> String {
+        text.replace("{page}", &page.to_string())
+            .replace("{pages}", &pages.to_string())
+            .replace("{title}", title)
+    }
+}
+
+/// Something that can lay itself out onto pages
+pub trait PrintContent {
+    /// Split into pages of `width` x `height` points (the area inside the margins)
+    fn paginate(&self, width: f32, height: f32) -> Vec<ChartScene>;
+}
+
+/// Pre-built pages (charts, reports) are printed as they are
+impl PrintContent for Vec<ChartScene> {
+    fn paginate(&self, _width: f32, _height: f32) -> Vec<ChartScene> {
+        self.clone()
+    }
+}
+
+/// Plain text, word-wrapped and split into pages 📄
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextContent {
+    pub text: String,
+    pub font_size: f32,
+    /// Line height as a multiple of the font size
+    pub line_spacing: f32,
+    pub color: (u8, u8, u8, u8),
+}
+
+impl TextContent {
+    /// Create 11pt black text
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), font_size: 11.0, line_spacing: 1.4, color: (0, 0, 0, 255) }
+    }
+
+    /// Set the font size (points)
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Lines after wrapping to `width` points
+    fn wrap(&self, width: f32) -> Vec<String> {
+        // Average glyph width for Roboto; good enough to decide where to break
+        let max_chars = ((width / (self.font_size * 0.5)).floor() as usize).max(1);
+        let mut lines = Vec::new();
+        for paragraph in self.text.lines() {
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let extra = if line.is_empty() { 0 } else { 1 };
+                if !line.is_empty() && line.chars().count() + extra + word.chars().count() > max_chars {
+                    lines.push(std::mem::take(&mut line));
+                } else if extra == 1 {
+                    line.push(' ');
+                }
+                // Break words longer than a line
+                let mut word: Vec<char> = word.chars().collect();
+                while word.len() > max_chars {
+                    lines.push(word.drain(..max_chars).collect());
+                }
+                line.extend(word);
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+impl PrintContent for TextContent {
+    fn paginate(&self, width: f32, height: f32) -> Vec<ChartScene> {
+        let line_height = self.font_size * self.line_spacing;
+        let lines_per_page = ((height / line_height).floor() as usize).max(1);
+        self.wrap(width)
+            .chunks(lines_per_page)
+            .map(|lines| {
+                let mut page = ChartScene::new(width, height);
+                for (row, line) in lines.iter().enumerate().filter(|(_, line)| !line.is_empty()) {
+                    let baseline = row as f32 * line_height + self.font_size;
+                    page.text(0.0, baseline, line.clone(), self.font_size, self.color, TextAnchor::Start);
+                }
+                page
+            })
+            .collect()
+    }
+}
+
+/// PrintPreview - See every page before printing 🖨️
+///
+/// - Paginates any `PrintContent` (plain text, pre-built ChartScene pages…)
+/// - Paper size, portrait / landscape and margins re-paginate live
+/// - Header and footer templates with `{page}`, `{pages}` and `{title}`
+/// - Zoom (25% - 400%, fit width, fit page) and page navigation
+///   (←/→, Home/End, +/-)
+/// - `print_job()` renders every page at `dpi` for
+///   `nebula_platform::Printer`; `export_pdf()` saves the same pages
+///   (both rasterize on the CPU, so they need the `export-png` feature)
+///
+/// # Example
+/// ```rust,ignore
+/// let preview = PrintPreview::new("Quarterly report")
+///     .content(TextContent::new(report_text))
+///     .paper(PaperSize::LETTER)
+///     .footer(PageTemplate::new().center("Page {page} of {pages}"));
+///
+/// preview.next_page();
+/// Printer::new().print(&preview.print_job()?)?;
+/// ```
+#[derive(Clone)]
+pub struct PrintPreview {
+    pub node_id: Option<NodeId>,
+    pub title: String,
+    pub paper: Signal<PaperSize>,
+    pub orientation: Signal<PageOrientation>,
+    pub margins: Signal<PageMargins>,
+    pub header: Signal<PageTemplate>,
+    pub footer: Signal<PageTemplate>,
+    pub copies: Signal<u32>,
+    /// 1.0 = one point per pixel
+    pub zoom: Signal<f32>,
+    /// Page shown (0-based)
+    pub current_page: Signal<usize>,
+    /// Resolution pages are rendered at for printing
+    pub dpi: f32,
+    pub template_font_size: f32,
+    pub position: (f32, f32),
+    pub width: f32,
+    pub height: f32,
+    pub toolbar_height: f32,
+    pub background_color: (u8, u8, u8, u8),
+    pub toolbar_color: (u8, u8, u8, u8),
+    pub page_color: (u8, u8, u8, u8),
+    pub shadow_color: (u8, u8, u8, u8),
+    pub text_color: (u8, u8, u8, u8),
+    pub template_color: (u8, u8, u8, u8),
+    content: Option<Rc<dyn PrintContent>>,
+    /// Pages for the last content area size
+    pages_cache: Rc<RefCell<Option<PageCache>>>,
+}
+
+/// Content area size and the pages laid out for it
+type PageCache = ((f32, f32), Rc<Vec<ChartScene>>);
+
+impl PrintPreview {
+    /// Create an empty A4 portrait preview
+    pub fn new(title: impl Into<String>) -> Self {
+        let title = title.into();
+        info!("🖨️ Creating PrintPreview '{}'", title);
+        Self {
+            node_id: None,
+            title,
+            paper: Signal::new(PaperSize::A4),
+            orientation: Signal::new(PageOrientation::Portrait),
+            margins: Signal::new(PageMargins::default()),
+            header: Signal::new(PageTemplate::new()),
+            footer: Signal::new(PageTemplate::new()),
+            copies: Signal::new(1),
+            zoom: Signal::new(1.0),
+            current_page: Signal::new(0),
+            dpi: 150.0,
+            template_font_size: 9.0,
+            position: (0.0, 0.0),
+            width: 800.0,
+            height: 600.0,
+            toolbar_height: 36.0,
+            background_color: (82, 86, 89, 255),
+            toolbar_color: (50, 54, 57, 255),
+            page_color: (255, 255, 255, 255),
+            shadow_color: (0, 0, 0, 60),
+            text_color: (240, 240, 240, 255),
+            template_color: (110, 110, 110, 255),
+            content: None,
+            pages_cache: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Set what gets printed
+    pub fn content(mut self, content: impl PrintContent + 'static) -> Self {
+        self.content = Some(Rc::new(content));
+        self
+    }
+
+    /// Replace the content (back to the first page)
+    pub fn set_content(&mut self, content: impl PrintContent + 'static) {
+        self.content = Some(Rc::new(content));
+        self.pages_cache.borrow_mut().take();
+        self.current_page.set(0);
+    }
+
+    /// Set the paper size
+    pub fn paper(self, paper: PaperSize) -> Self {
+        self.paper.set(paper);
+        self
+    }
+
+    /// Set the orientation
+    pub fn orientation(self, orientation: PageOrientation) -> Self {
+        self.orientation.set(orientation);
+        self
+    }
+
+    /// Set the margins
+    pub fn margins(self, margins: PageMargins) -> Self {
+        self.margins.set(margins);
+        self
+    }
+
+    /// Set the header template
+    pub fn header(self, header: PageTemplate) -> Self {
+        self.header.set(header);
+        self
+    }
+
+    /// Set the footer template
+    pub fn footer(self, footer: PageTemplate) -> Self {
+        self.footer.set(footer);
+        self
+    }
+
+    /// Set the print resolution
+    pub fn dpi(mut self, dpi: f32) -> Self {
+        self.dpi = dpi.max(1.0);
+        self
+    }
+
+    /// Set the position
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    /// Set the viewport size
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Change the paper size
+    pub fn set_paper(&self, paper: PaperSize) {
+        self.paper.set(paper);
+        self.clamp_page();
+    }
+
+    /// Change the orientation
+    pub fn set_orientation(&self, orientation: PageOrientation) {
+        self.orientation.set(orientation);
+        self.clamp_page();
+    }
+
+    /// Flip between portrait and landscape
+    pub fn toggle_orientation(&self) {
+        self.set_orientation(match self.orientation.get() {
+            PageOrientation::Portrait => PageOrientation::Landscape,
+            PageOrientation::Landscape => PageOrientation::Portrait,
+        });
+    }
+
+    /// Change the margins
+    pub fn set_margins(&self, margins: PageMargins) {
+        self.margins.set(margins);
+        self.clamp_page();
+    }
+
+    /// Sheet size in points
+    pub fn page_size(&self) -> (f32, f32) {
+        self.paper.get().oriented(self.orientation.get())
+    }
+
+    /// Printable area (x, y, width, height) in points
+    pub fn content_area(&self) -> (f32, f32, f32, f32) {
+        let (width, height) = self.page_size();
+        let margins = self.margins.get();
+        (
+            margins.left,
+            margins.top,
+            (width - margins.left - margins.right).max(1.0),
+            (height - margins.top - margins.bottom).max(1.0),
+        )
+    }
+
+    /// Content split into pages (cached per content area size)
+    fn content_pages(&self) -> Rc<Vec<ChartScene>> {
+        let (_, _, width, height) = self.content_area();
+        if let Some((size, pages)) = self.pages_cache.borrow().as_ref() {
+            if *size == (width, height) {
+                return pages.clone();
+            }
+        }
+        let pages = Rc::new(self.content.as_ref().map(|content| content.paginate(width, height)).unwrap_or_default());
+        *self.pages_cache.borrow_mut() = Some(((width, height), pages.clone()));
+        pages
+    }
+
+    /// Number of pages (at least one blank page)
+    pub fn page_count(&self) -> usize {
+        self.content_pages().len().max(1)
+    }
+
+    fn clamp_page(&self) {
+        let last = self.page_count() - 1;
+        if self.current_page.get() > last {
+            self.current_page.set(last);
+        }
+    }
+
+    /// Whole sheet for a page: content inside the margins plus header and footer
+    pub fn page(&self, index: usize) -> Option<ChartScene> {
+        let pages = self.content_pages();
+        let count = pages.len().max(1);
+        if index >= count {
+            return None;
+        }
+        let (width, height) = self.page_size();
+        let (left, top, area_width, area_height) = self.content_area();
+        let mut sheet = ChartScene::new(width, height);
+        sheet.rect(0.0, 0.0, width, height, self.page_color);
+        if let Some(content) = pages.get(index) {
+            sheet.primitives.extend(content.primitives.iter().map(|primitive| offset_primitive(primitive, left, top)));
+        }
+
+        let size = self.template_font_size;
+        let margins = self.margins.get();
+        // Header sits in the middle of the top margin, footer in the bottom one
+        let header_baseline = (margins.top / 2.0 + size / 2.0).min(top);
+        let footer_baseline = top + area_height + margins.bottom / 2.0 + size / 2.0;
+        for (template, baseline) in [(self.header.get(), header_baseline), (self.footer.get(), footer_baseline)] {
+            for (text, x, anchor) in [
+                (&template.left, left, TextAnchor::Start),
+                (&template.center, left + area_width / 2.0, TextAnchor::Middle),
+                (&template.right, left + area_width, TextAnchor::End),
+            ] {
+                if !text.is_empty() {
+                    let text = PageTemplate::expand(text, index + 1, count, &self.title);
+                    sheet.text(x, baseline, text, size, self.template_color, anchor);
+                }
+            }
+        }
+        Some(sheet)
+    }
+
+    /// Show a page (0-based); returns false if out of range
+    pub fn go_to_page(&self, index: usize) -> bool {
+        if index >= self.page_count() {
+            return false;
+        }
+        self.current_page.set(index);
+        true
+    }
+
+    /// Next page
+    pub fn next_page(&self) -> bool {
+        self.go_to_page(self.current_page.get() + 1)
+    }
+
+    /// Previous page
+    pub fn previous_page(&self) -> bool {
+        self.current_page.get() > 0 && self.go_to_page(self.current_page.get() - 1)
+    }
+
+    /// First page
+    pub fn first_page(&self) {
+        self.current_page.set(0);
+    }
+
+    /// Last page
+    pub fn last_page(&self) {
+        self.current_page.set(self.page_count() - 1);
+    }
+
+    /// "Page 2 of 5"
+    pub fn page_label(&self) -> String {
+        format!("Page {} of {}", self.current_page.get() + 1, self.page_count())
+    }
+
+    /// Set the zoom (clamped to 25% - 400%)
+    pub fn set_zoom(&self, zoom: f32) {
+        self.zoom.set(zoom.clamp(0.25, 4.0));
+    }
+
+    /// Zoom in one step
+    pub fn zoom_in(&self) {
+        self.set_zoom(self.zoom.get() * 1.25);
+    }
+
+    /// Zoom out one step
+    pub fn zoom_out(&self) {
+        self.set_zoom(self.zoom.get() / 1.25);
+    }
+
+    /// Zoom so the page fills the viewport width
+    pub fn fit_width(&self) {
+        let (page_width, _) = self.page_size();
+        self.set_zoom((self.width - 2.0 * PAGE_GAP) / page_width);
+    }
+
+    /// Zoom so the whole page is visible
+    pub fn fit_page(&self) {
+        let (page_width, page_height) = self.page_size();
+        let available = (self.width - 2.0 * PAGE_GAP, self.height - self.toolbar_height - 2.0 * PAGE_GAP);
+        self.set_zoom((available.0 / page_width).min(available.1 / page_height));
+    }
+
+    /// "125%"
+    pub fn zoom_label(&self) -> String {
+        format!("{}%", (self.zoom.get() * 100.0).round())
+    }
+
+    /// Rasterize one page at `dpi`
+    #[cfg(feature = "export-png")]
+    pub fn render_page(&self, index: usize) -> Result<RgbaImage, String> {
+        let page = self.page(index).ok_or_else(|| format!("No page {}", index + 1))?;
+        page.render(self.dpi / 72.0)
+    }
+
+    /// Every page, ready for `nebula_platform::Printer`
+    #[cfg(feature = "export-png")]
+    pub fn print_job(&self) -> Result<PrintJob, String> {
+        let mut job = PrintJob::new(self.title.clone())
+            .paper(self.paper.get())
+            .orientation(self.orientation.get())
+            .copies(self.copies.get());
+        for index in 0..self.page_count() {
+            job.pages.push(self.render_page(index)?);
+        }
+        info!("🖨️ Rendered {} pages of '{}' at {} dpi", job.pages.len(), self.title, self.dpi);
+        Ok(job)
+    }
+
+    /// Save every page as a PDF
+    #[cfg(feature = "export-png")]
+    pub fn export_pdf(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        self.print_job()?.save_pdf(path)
+    }
+
+    /// Keyboard navigation; returns true if handled
+    pub fn handle_key(&self, key: FocusKey) -> bool {
+        match key {
+            FocusKey::Right | FocusKey::Down => self.next_page(),
+            FocusKey::Left | FocusKey::Up => self.previous_page(),
+            FocusKey::Home => {
+                self.first_page();
+                true
+            }
+            FocusKey::End => {
+                self.last_page();
+                true
+            }
+            FocusKey::Char('+') | FocusKey::Char('=') => {
+                self.zoom_in();
+                true
+            }
+            FocusKey::Char('-') => {
+                self.zoom_out();
+                true
+            }
+            FocusKey::Char('0') => {
+                self.fit_page();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Sheet rectangle on screen for the current zoom
+    pub fn page_rect(&self) -> (f32, f32, f32, f32) {
+        let (page_width, page_height) = self.page_size();
+        let zoom = self.zoom.get();
+        let (width, height) = (page_width * zoom, page_height * zoom);
+        let (x, y) = self.position;
+        let x = x + ((self.width - width) / 2.0).max(PAGE_GAP);
+        (x, y + self.toolbar_height + PAGE_GAP, width, height)
+    }
+
+    /// Draw the toolbar and the current page
+    pub fn paint(&self, list: &mut DrawList) {
+        let (x, y) = self.position;
+        list.rect((x, y, self.width, self.height), self.background_color);
+        list.push_clip((x, y + self.toolbar_height, self.width, self.height - self.toolbar_height));
+
+        let (page_x, page_y, page_width, page_height) = self.page_rect();
+        let zoom = self.zoom.get();
+        if QualitySettings::current().shadows && RendererCapabilities::current().shadows {
+            list.rect((page_x + 3.0, page_y + 3.0, page_width, page_height), self.shadow_color);
+        }
+        if let Some(page) = self.page(self.current_page.get()) {
+            list.push_clip((page_x, page_y, page_width, page_height));
+            for primitive in &page.primitives {
+                match primitive.clone() {
+                    ChartPrimitive::Rect { x, y, width, height, color } => {
+                        list.rect((page_x + x * zoom, page_y + y * zoom, width * zoom, height * zoom), color);
+                    }
+                    ChartPrimitive::Line { points, width, color } => {
+                        // DrawList has no strokes: draw each segment's bounds (exact for axis-aligned lines)
+                        let stroke = (width * zoom).max(1.0);
+                        for segment in points.windows(2) {
+                            let (x0, y0) = (segment[0].0.min(segment[1].0), segment[0].1.min(segment[1].1));
+                            let (x1, y1) = (segment[0].0.max(segment[1].0), segment[0].1.max(segment[1].1));
+                            list.rect(
+                                (page_x + x0 * zoom - stroke / 2.0, page_y + y0 * zoom - stroke / 2.0, (x1 - x0) * zoom + stroke, (y1 - y0) * zoom + stroke),
+                                color,
+                            );
+                        }
+                    }
+                    ChartPrimitive::Text { x, y, text, size, color, anchor } => {
+                        let size = size * zoom;
+                        let text_width = text.chars().count() as f32 * size * 0.5;
+                        let left = page_x + x * zoom - match anchor {
+                            TextAnchor::Start => 0.0,
+                            TextAnchor::Middle => text_width / 2.0,
+                            TextAnchor::End => text_width,
+                        };
+                        list.text(text, left, page_y + y * zoom - size, size, color);
+                    }
+                }
+            }
+            list.pop_clip();
+        }
+        list.pop_clip();
+
+        // Toolbar: page navigation on the left, zoom on the right
+        list.rect((x, y, self.width, self.toolbar_height), self.toolbar_color);
+        let text_y = y + (self.toolbar_height - 13.0 * 1.2) / 2.0;
+        list.text(format!("‹  {}  ›", self.page_label()), x + 12.0, text_y, 13.0, self.text_color);
+        let orientation = match self.orientation.get() {
+            PageOrientation::Portrait => "Portrait",
+            PageOrientation::Landscape => "Landscape",
+        };
+        list.text(format!("{} · {}", self.paper.get().name, orientation), x + self.width / 2.0 - 50.0, text_y, 13.0, self.text_color);
+        list.text(format!("−  {}  +", self.zoom_label()), x + self.width - 110.0, text_y, 13.0, self.text_color);
+    }
+
+    /// Build the layout node
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let node = engine
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(self.width),
+                    height: taffy::style::Dimension::Length(self.height),
+                },
+                ..Default::default()
+            })
+            .map_err(|e| format!("Failed to create PrintPreview: {:?}", e))?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+}
+
+/// Space around the sheet in the viewport
+const PAGE_GAP: f32 = 24.0;
+
+/// Move a primitive by (dx, dy)
+fn offset_primitive(primitive: &ChartPrimitive, dx: f32, dy: f32) -> ChartPrimitive {
+    match primitive.clone() {
+        ChartPrimitive::Rect { x, y, width, height, color } => ChartPrimitive::Rect { x: x + dx, y: y + dy, width, height, color },
+        ChartPrimitive::Line { points, width, color } => ChartPrimitive::Line {
+            points: points.into_iter().map(|(x, y)| (x + dx, y + dy)).collect(),
+            width,
+            color,
+        },
+        ChartPrimitive::Text { x, y, text, size, color, anchor } => ChartPrimitive::Text { x: x + dx, y: y + dy, text, size, color, anchor },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(scene: &ChartScene) -> Vec<String> {
+        scene
+            .primitives
+            .iter()
+            .filter_map(|p| match p {
+                ChartPrimitive::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paginates_and_fills_templates() {
+        let text = (1..=120).map(|i| format!("Line {}", i)).collect::<Vec<_>>().join("\n");
+        let preview = PrintPreview::new("Log")
+            .content(TextContent::new(text).font_size(10.0))
+            .margins(PageMargins::uniform(36.0))
+            .header(PageTemplate::new().left("{title}"))
+            .footer(PageTemplate::new().right("Page {page} of {pages}"));
+
+        // 842 - 72 = 770pt of content, 14pt lines -> 55 lines per page
+        assert_eq!(preview.page_count(), 3);
+        let first = texts(&preview.page(0).unwrap());
+        assert_eq!(first.first().map(String::as_str), Some("Line 1"));
+        assert!(first.contains(&"Log".to_string()));
+        assert!(first.contains(&"Page 1 of 3".to_string()));
+        assert!(texts(&preview.page(2).unwrap()).contains(&"Line 111".to_string()));
+        assert!(preview.page(3).is_none());
+
+        // Landscape has fewer lines per page
+        preview.last_page();
+        preview.toggle_orientation();
+        assert_eq!(preview.page_count(), 4);
+        assert_eq!(preview.current_page.get(), 2);
+        preview.set_orientation(PageOrientation::Portrait);
+        assert_eq!(preview.current_page.get(), 2);
+
+        // Long words wrap too
+        let wrapped = TextContent::new("x".repeat(30)).font_size(10.0).wrap(50.0);
+        assert_eq!(wrapped, vec!["xxxxxxxxxx"; 3]);
+    }
+
+    #[test]
+    fn navigation_zoom_and_print_job() {
+        let pages: Vec<ChartScene> = (0..2).map(|_| ChartScene::new(10.0, 10.0)).collect();
+        let preview = PrintPreview::new("Charts").content(pages).paper(PaperSize::A5).dpi(36.0).size(500.0, 400.0);
+        assert_eq!(preview.page_label(), "Page 1 of 2");
+        assert!(preview.handle_key(FocusKey::Right));
+        assert!(!preview.next_page());
+        assert_eq!(preview.page_label(), "Page 2 of 2");
+        assert!(preview.handle_key(FocusKey::Home));
+        assert_eq!(preview.current_page.get(), 0);
+
+        preview.zoom_in();
+        assert_eq!(preview.zoom_label(), "125%");
+        preview.set_zoom(10.0);
+        assert_eq!(preview.zoom.get(), 4.0);
+        preview.fit_page();
+        let (_, page_y, _, page_height) = preview.page_rect();
+        assert!(page_y + page_height <= 400.0 - PAGE_GAP + 0.01);
+
+
+        // A5 at 36 dpi = half the point size
+        #[cfg(feature = "export-png")]
+        {
+            let job = preview.print_job().unwrap();
+            assert_eq!(job.pages.len(), 2);
+            assert_eq!((job.pages[0].width, job.pages[0].height), (210, 298));
+            assert_eq!(job.pages[0].pixel(0, 0), Some([255, 255, 255, 255]));
+            assert!(job.to_pdf().is_ok());
+        }
+    }
+}
@@ -3,6 +3,8 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
+use nebula_core::{announce, Politeness};
 
 /// ProgressBar component - displays linear progress
 /// 
@@ -27,29 +29,47 @@ pub struct ProgressBar {
     pub animated: bool,
     pub animation_duration: f32, // seconds
     pub indeterminate: bool, // For unknown progress
+    pub politeness: Politeness, // How screen readers hear progress
+    pub announce_step: f32, // Announce every step crossed (0.25 = each quarter)
     pub on_complete: Option<Box<dyn Fn()>>,
 }
 
 impl ProgressBar {
-    /// Create a new ProgressBar component
+    /// Create a new ProgressBar component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             value: Signal::new(0.0),
             width: 200.0,
             height: 8.0,
-            background_color: (230, 230, 230, 255),
-            fill_color: (59, 130, 246, 255), // Blue
+            background_color: colors.border,
+            fill_color: colors.primary,
             border_radius: 4.0,
             show_label: false,
             label_format: "{percent}%".to_string(),
             animated: true,
             animation_duration: 0.3,
             indeterminate: false,
+            politeness: Politeness::Polite,
+            announce_step: 0.25,
             on_complete: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.border;
+        self.fill_color = colors.primary;
+    }
+
     /// Set the progress value (0.0 to 1.0)
     pub fn value(self, value: f32) -> Self {
         self.value.set(value.clamp(0.0, 1.0));
@@ -125,12 +145,30 @@ impl ProgressBar {
         self
     }
 
+    /// Set how progress is announced (Politeness::Off to stay quiet)
+    pub fn politeness(mut self, politeness: Politeness) -> Self {
+        self.politeness = politeness;
+        self
+    }
+
+    /// Announce each time progress crosses a multiple of `step`
+    pub fn announce_step(mut self, step: f32) -> Self {
+        self.announce_step = step.clamp(0.01, 1.0);
+        self
+    }
+
     /// Update the progress value
     pub fn set_value(&mut self, value: f32) {
         let clamped = value.clamp(0.0, 1.0);
         let was_complete = self.is_complete();
+        let step = |value: f32| (value / self.announce_step).floor() as i32;
+        let crossed = step(clamped) > step(self.get_value());
         
         self.value.set(clamped);
+
+        if crossed && !self.indeterminate {
+            announce(self.get_label(), self.politeness);
+        }
         
         if !was_complete && self.is_complete() {
             if let Some(ref callback) = self.on_complete {
@@ -300,6 +338,19 @@ mod tests {
         assert!(*completed.lock().unwrap());
     }
 
+    #[test]
+    fn progress_bar_announces_steps() {
+        nebula_core::take_announcements();
+        let mut progress = ProgressBar::new();
+        progress.set_value(0.1);
+        progress.set_value(0.3);
+        progress.set_value(0.4);
+        progress.set_value(1.0);
+
+        let texts: Vec<_> = nebula_core::take_announcements().into_iter().map(|a| a.text).collect();
+        assert_eq!(texts, ["30%", "100%"]);
+    }
+
     #[test]
     fn progress_bar_on_complete_only_once() {
         use std::sync::{Arc, Mutex};
@@ -0,0 +1,390 @@
+// RadialMenu Component - Ring of actions around the pointer (pie menu)
+// Press, flick toward an item and release; nested rings, keyboard fallback, made for pens
+
+use nebula_core::{FocusKey, Signal, ThemeProvider};
+use nebula_gfx::draw_list::DrawList;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use tracing::info;
+
+/// One slice of a radial menu
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialItem {
+    pub label: String,
+    pub action: String,
+    pub icon: Option<String>,
+    pub disabled: bool,
+    /// Nested ring opened by this item
+    pub children: Vec<RadialItem>,
+}
+
+impl RadialItem {
+    /// Create an item
+    pub fn new(label: impl Into<String>, action: impl Into<String>) -> Self {
+        Self { label: label.into(), action: action.into(), icon: None, disabled: false, children: Vec::new() }
+    }
+
+    /// Item that opens a nested ring
+    pub fn submenu(label: impl Into<String>, children: Vec<RadialItem>) -> Self {
+        Self { children, ..Self::new(label, "") }
+    }
+
+    /// Add an icon
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Disable the item
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Does it open a nested ring?
+    pub fn is_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+type SelectCallback = Box<dyn Fn(&str)>;
+
+/// RadialMenu - Pie menu for pen and mouse 🥧
+///
+/// Items sit on a ring, clockwise from the top. Direction picks the
+/// item, not distance, so a quick flick works without aiming: press to
+/// open, drag toward an item and release. Dragging past the ring into a
+/// submenu opens its ring where the pointer is. A press released in the
+/// center keeps the menu open for a second click or the keyboard
+/// (arrows move around the ring, Enter picks, Escape backs out, 1-9 pick directly).
+///
+/// # Example
+/// ```rust,ignore
+/// let mut menu = RadialMenu::new()
+///     .add_item(RadialItem::new("Brush", "brush"))
+///     .add_item(RadialItem::submenu("Shapes", vec![RadialItem::new("Line", "line"), RadialItem::new("Ellipse", "ellipse")]))
+///     .on_select(|action| println!("{}", action));
+///
+/// menu.press(x, y);      // pen down (e.g. with the barrel button)
+/// menu.drag_to(x, y);    // pen moves
+/// menu.release(x, y);    // picks the highlighted item
+/// ```
+pub struct RadialMenu {
+    pub items: Vec<RadialItem>,
+    pub is_visible: Signal<bool>,
+    /// Highlighted slice of the open ring
+    pub highlighted: Signal<Option<usize>>,
+    /// Center of the open ring
+    pub center: (f32, f32),
+    /// Distance from the center to the item chips
+    pub radius: f32,
+    /// Central dead zone where nothing is highlighted
+    pub dead_zone: f32,
+    /// Indices of the submenus opened so far
+    path: Vec<usize>,
+    /// Ring centers before each submenu was opened
+    centers: Vec<(f32, f32)>,
+    gesture: bool,
+    on_select: Option<SelectCallback>,
+}
+
+impl RadialMenu {
+    /// Create an empty, hidden radial menu
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            is_visible: Signal::new(false),
+            highlighted: Signal::new(None),
+            center: (0.0, 0.0),
+            radius: 80.0,
+            dead_zone: 20.0,
+            path: Vec::new(),
+            centers: Vec::new(),
+            gesture: false,
+            on_select: None,
+        }
+    }
+
+    /// Add an item to the outer ring
+    pub fn add_item(mut self, item: RadialItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Set the ring radius
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set the select callback
+    pub fn on_select<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Open the outer ring centered at (x, y)
+    pub fn open_at(&mut self, x: f32, y: f32) {
+        self.center = (x, y);
+        self.path.clear();
+        self.centers.clear();
+        self.highlighted.set(None);
+        self.is_visible.set(true);
+    }
+
+    /// Close without picking anything
+    pub fn close(&mut self) {
+        self.is_visible.set(false);
+        self.highlighted.set(None);
+        self.gesture = false;
+    }
+
+    /// Check if the menu is visible
+    pub fn is_visible(&self) -> bool {
+        self.is_visible.get()
+    }
+
+    /// Items of the ring being shown
+    pub fn current_items(&self) -> &[RadialItem] {
+        self.path.iter().fold(&self.items, |items, &index| &items[index].children)
+    }
+
+    /// How many submenus deep the open ring is
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Slice in the direction of (x, y) from the center (None in the dead zone)
+    pub fn item_at(&self, x: f32, y: f32) -> Option<usize> {
+        let count = self.current_items().len();
+        let (dx, dy) = (x - self.center.0, y - self.center.1);
+        if count == 0 || dx.hypot(dy) < self.dead_zone {
+            return None;
+        }
+        // Clockwise from straight up, each slice centered on its item
+        let slice = TAU / count as f32;
+        let angle = (dy.atan2(dx) + FRAC_PI_2 + slice / 2.0).rem_euclid(TAU);
+        Some(((angle / slice) as usize).min(count - 1))
+    }
+
+    /// Center of an item's chip
+    pub fn item_position(&self, index: usize) -> (f32, f32) {
+        let count = self.current_items().len().max(1);
+        let angle = index as f32 * TAU / count as f32 - FRAC_PI_2;
+        (self.center.0 + angle.cos() * self.radius, self.center.1 + angle.sin() * self.radius)
+    }
+
+    /// Pointer or pen down: open the ring here and start a gesture
+    pub fn press(&mut self, x: f32, y: f32) {
+        if !self.is_visible() {
+            self.open_at(x, y);
+        }
+        self.gesture = true;
+    }
+
+    /// Pointer moved: highlight the slice it points at, and open a
+    /// submenu once the pointer crosses the ring on it
+    pub fn drag_to(&mut self, x: f32, y: f32) {
+        if !self.is_visible() {
+            return;
+        }
+        let index = self.item_at(x, y);
+        self.highlighted.set(index);
+        let Some(index) = index else {
+            return;
+        };
+        let beyond_ring = (x - self.center.0).hypot(y - self.center.1) > self.radius;
+        let item = &self.current_items()[index];
+        if self.gesture && beyond_ring && item.is_submenu() && !item.disabled {
+            self.enter(index, (x, y));
+        }
+    }
+
+    /// Pointer up: pick the highlighted item (a release in the center keeps
+    /// the menu open for a click or the keyboard). Returns the picked action.
+    pub fn release(&mut self, x: f32, y: f32) -> Option<String> {
+        if !self.is_visible() {
+            return None;
+        }
+        self.gesture = false;
+        let index = self.item_at(x, y)?;
+        self.activate(index)
+    }
+
+    /// Keyboard fallback; returns true if the key was used
+    pub fn handle_key(&mut self, key: FocusKey) -> bool {
+        if !self.is_visible() {
+            return false;
+        }
+        let count = self.current_items().len();
+        match key {
+            FocusKey::Right | FocusKey::Down | FocusKey::Left | FocusKey::Up if count > 0 => {
+                let forward = matches!(key, FocusKey::Right | FocusKey::Down);
+                let next = match self.highlighted.get() {
+                    Some(index) if forward => (index + 1) % count,
+                    Some(index) => (index + count - 1) % count,
+                    None => 0,
+                };
+                self.highlighted.set(Some(next));
+            }
+            FocusKey::Enter | FocusKey::Space => {
+                if let Some(index) = self.highlighted.get() {
+                    self.activate(index);
+                }
+            }
+            FocusKey::Char(c) if c.is_ascii_digit() && c != '0' => {
+                let index = c as usize - '1' as usize;
+                if index < count {
+                    self.activate(index);
+                }
+            }
+            FocusKey::Escape | FocusKey::Backspace => {
+                if !self.back() {
+                    self.close();
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Leave the open submenu; false at the outer ring
+    pub fn back(&mut self) -> bool {
+        let (Some(index), Some(center)) = (self.path.pop(), self.centers.pop()) else {
+            return false;
+        };
+        self.center = center;
+        self.highlighted.set(Some(index));
+        true
+    }
+
+    /// Pick an item of the open ring: submenus open, actions fire and close
+    pub fn activate(&mut self, index: usize) -> Option<String> {
+        let item = self.current_items().get(index)?.clone();
+        if item.disabled {
+            return None;
+        }
+        if item.is_submenu() {
+            self.enter(index, self.center);
+            return None;
+        }
+        info!("🥧 Radial menu: {}", item.action);
+        if let Some(ref callback) = self.on_select {
+            callback(&item.action);
+        }
+        self.close();
+        Some(item.action)
+    }
+
+    fn enter(&mut self, index: usize, center: (f32, f32)) {
+        self.path.push(index);
+        self.centers.push(self.center);
+        self.center = center;
+        self.highlighted.set(None);
+    }
+
+    /// Draw the ring
+    pub fn paint(&self, list: &mut DrawList) {
+        if !self.is_visible() {
+            return;
+        }
+        let theme = ThemeProvider::current();
+        let colors = theme.colors;
+        let (cx, cy) = self.center;
+        let outer = self.radius + 36.0;
+        list.rounded_rect((cx - outer, cy - outer, outer * 2.0, outer * 2.0), outer, colors.surface_variant);
+        list.rounded_rect((cx - self.dead_zone, cy - self.dead_zone, self.dead_zone * 2.0, self.dead_zone * 2.0), self.dead_zone, colors.border);
+        if self.depth() > 0 {
+            list.text("←", cx - theme.font_size * 0.3, cy - theme.font_size * 0.6, theme.font_size, colors.text);
+        }
+
+        let chip = 30.0;
+        for (index, item) in self.current_items().iter().enumerate() {
+            let (x, y) = self.item_position(index);
+            let highlighted = self.highlighted.get() == Some(index);
+            let (background, text) = match (highlighted, item.disabled) {
+                (_, true) => (colors.surface_variant, colors.border),
+                (true, false) => (colors.primary, (255, 255, 255, 255)),
+                (false, false) => (colors.hover, colors.text),
+            };
+            list.rounded_rect((x - chip, y - chip, chip * 2.0, chip * 2.0), chip, background);
+            let label = match (&item.icon, item.is_submenu()) {
+                (Some(icon), _) => icon.clone(),
+                (None, true) => format!("{} ›", item.label),
+                (None, false) => item.label.clone(),
+            };
+            let width = label.chars().count() as f32 * theme.font_size * 0.55;
+            list.text(label, x - width / 2.0, y - theme.font_size * 0.6, theme.font_size, text);
+        }
+    }
+}
+
+impl Default for RadialMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn menu() -> RadialMenu {
+        RadialMenu::new()
+            .add_item(RadialItem::new("Brush", "brush"))
+            .add_item(RadialItem::submenu("Shapes", vec![RadialItem::new("Line", "line"), RadialItem::new("Ellipse", "ellipse")]))
+            .add_item(RadialItem::new("Erase", "erase"))
+            .add_item(RadialItem::new("Fill", "fill").disabled())
+    }
+
+    #[test]
+    fn flick_picks_by_direction_and_opens_submenus() {
+        let mut menu = menu();
+        menu.press(200.0, 200.0);
+        // Up is the first item, right the second
+        assert_eq!(menu.item_at(200.0, 150.0), Some(0));
+        assert_eq!(menu.item_at(260.0, 200.0), Some(1));
+        assert_eq!(menu.item_at(205.0, 200.0), None);
+
+        menu.drag_to(200.0, 260.0);
+        assert_eq!(menu.release(200.0, 260.0), Some("erase".to_string()));
+        assert!(!menu.is_visible());
+
+        // Crossing the ring on "Shapes" opens its ring at the pointer
+        menu.press(200.0, 200.0);
+        menu.drag_to(300.0, 200.0);
+        assert_eq!(menu.depth(), 1);
+        assert_eq!(menu.center, (300.0, 200.0));
+        assert_eq!(menu.release(300.0, 260.0), Some("ellipse".to_string()));
+
+        // Released in the center: stays open; disabled items can't be picked
+        menu.press(200.0, 200.0);
+        assert_eq!(menu.release(202.0, 200.0), None);
+        assert!(menu.is_visible());
+        assert_eq!(menu.release(140.0, 200.0), None);
+        assert!(menu.is_visible());
+    }
+
+    #[test]
+    fn keyboard_walks_the_ring_and_backs_out() {
+        let mut menu = menu();
+        menu.open_at(100.0, 100.0);
+        menu.handle_key(FocusKey::Right);
+        menu.handle_key(FocusKey::Right);
+        assert_eq!(menu.highlighted.get(), Some(1));
+        menu.handle_key(FocusKey::Enter);
+        assert_eq!(menu.current_items().len(), 2);
+        menu.handle_key(FocusKey::Escape);
+        assert_eq!(menu.depth(), 0);
+        assert_eq!(menu.highlighted.get(), Some(1));
+        menu.handle_key(FocusKey::Char('1'));
+        assert!(!menu.is_visible());
+
+        let mut list = DrawList::new();
+        menu.open_at(100.0, 100.0);
+        menu.paint(&mut list);
+        assert!(list.len() >= 10);
+    }
+}
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Range component - dual-handle range slider for selecting a range
 /// 
@@ -27,19 +28,20 @@ pub struct Range {
     pub height: f32,
     pub track_height: f32,
     pub thumb_size: f32,
-    pub track_color: (u8, u8, u8, u8),
-    pub track_fill_color: (u8, u8, u8, u8),
-    pub thumb_color: (u8, u8, u8, u8),
-    pub thumb_hover_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
+    pub track_color: ThemeColor,
+    pub track_fill_color: ThemeColor,
+    pub thumb_color: ThemeColor,
+    pub thumb_hover_color: ThemeColor,
+    pub disabled_color: ThemeColor,
     pub show_values: bool,
     pub on_change: Option<Box<dyn Fn(f32, f32)>>,
     pub on_change_end: Option<Box<dyn Fn(f32, f32)>>,
 }
 
 impl Range {
-    /// Create a new Range component
+    /// Create a new Range component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             start_value: Signal::new(0.0),
@@ -52,17 +54,33 @@ impl Range {
             height: 40.0,
             track_height: 4.0,
             thumb_size: 20.0,
-            track_color: (220, 220, 220, 255),
-            track_fill_color: (59, 130, 246, 255), // Blue
-            thumb_color: (255, 255, 255, 255),
-            thumb_hover_color: (245, 245, 245, 255),
-            disabled_color: (200, 200, 200, 255),
+            track_color: colors.border,
+            track_fill_color: colors.primary,
+            thumb_color: colors.surface,
+            thumb_hover_color: colors.hover,
+            disabled_color: colors.disabled,
             show_values: false,
             on_change: None,
             on_change_end: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.track_color = colors.border;
+        self.track_fill_color = colors.primary;
+        self.thumb_color = colors.surface;
+        self.thumb_hover_color = colors.hover;
+        self.disabled_color = colors.disabled;
+    }
+
     /// Set the minimum value
     pub fn min(mut self, min: f32) -> Self {
         self.min = min;
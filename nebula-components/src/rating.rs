@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{mix, Theme, ThemeColor, ThemeProvider};
 
 /// Rating component - star rating for user feedback
 /// 
@@ -23,9 +24,9 @@ pub struct Rating {
     pub allow_half_stars: bool,
     pub readonly: bool,
     pub show_value: bool,
-    pub filled_color: (u8, u8, u8, u8),
-    pub empty_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
+    pub filled_color: ThemeColor,
+    pub empty_color: ThemeColor,
+    pub hover_color: ThemeColor,
     pub filled_icon: String,
     pub empty_icon: String,
     pub half_icon: String,
@@ -34,8 +35,9 @@ pub struct Rating {
 }
 
 impl Rating {
-    /// Create a new Rating component
+    /// Create a new Rating component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             value: Signal::new(0.0),
@@ -45,9 +47,9 @@ impl Rating {
             allow_half_stars: false,
             readonly: false,
             show_value: false,
-            filled_color: (255, 193, 7, 255), // Amber/Gold
-            empty_color: (200, 200, 200, 255), // Gray
-            hover_color: (255, 213, 79, 255), // Light amber
+            filled_color: colors.warning,
+            empty_color: colors.disabled,
+            hover_color: mix(colors.warning, colors.surface, 0.3),
             filled_icon: "★".to_string(),
             empty_icon: "☆".to_string(),
             half_icon: "⯨".to_string(),
@@ -56,6 +58,20 @@ impl Rating {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.filled_color = colors.warning;
+        self.empty_color = colors.disabled;
+        self.hover_color = mix(colors.warning, colors.surface, 0.3);
+    }
+
     /// Set the current value
     pub fn value(mut self, value: f32) -> Self {
         self.value.set(value.clamp(0.0, self.max_rating as f32));
@@ -0,0 +1,253 @@
+// RegionPicker Component - Dim a screenshot, drag a rectangle, get that part back
+// The building block for annotation and bug-report tools (pair with ScreenCapture)
+
+use nebula_core::signal::Signal;
+use nebula_gfx::draw_list::{DrawList, ImageId, Rect};
+use nebula_gfx::RgbaImage;
+use tracing::info;
+
+/// Called with the picked part of the screenshot
+pub type RegionCallback = Box<dyn Fn(&RgbaImage)>;
+
+/// RegionPicker - Full-screen "select an area" overlay ✂️
+///
+/// Shows a frozen screenshot dimmed everywhere except the rectangle being
+/// dragged. Releasing the mouse crops the screenshot (in physical pixels,
+/// so HiDPI captures stay sharp); Escape cancels.
+///
+/// # Example
+/// ```rust,ignore
+/// let shot = ScreenCapture::new().capture_display("0")?;
+/// let mut picker = RegionPicker::new(shot, window.scale_factor() as f32)
+///     .on_complete(|image| image.save_png("bug.png").unwrap());
+///
+/// // overlay window: route the mouse, then draw
+/// picker.handle_mouse_down(x, y);
+/// picker.paint(&mut list, screenshot_texture);
+/// ```
+pub struct RegionPicker {
+    screenshot: RgbaImage,
+    /// Physical pixels per logical pixel of the overlay
+    pub scale_factor: f32,
+    /// Overlay open?
+    pub is_open: Signal<bool>,
+    pub dim_color: (u8, u8, u8, u8),
+    pub border_color: (u8, u8, u8, u8),
+    pub label_color: (u8, u8, u8, u8),
+    /// Smaller drags count as a click (cleared selection)
+    pub min_size: f32,
+    selection: Option<Rect>,
+    drag_from: Option<(f32, f32)>,
+    on_complete: Option<RegionCallback>,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl RegionPicker {
+    /// Open a picker over a screenshot
+    pub fn new(screenshot: RgbaImage, scale_factor: f32) -> Self {
+        info!("✂️ Opening region picker over {}x{} screenshot", screenshot.width, screenshot.height);
+        Self {
+            screenshot,
+            scale_factor: scale_factor.max(0.1),
+            is_open: Signal::new(true),
+            dim_color: (0, 0, 0, 128),
+            border_color: (59, 130, 246, 255), // Blue
+            label_color: (255, 255, 255, 255),
+            min_size: 4.0,
+            selection: None,
+            drag_from: None,
+            on_complete: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Set the callback for a finished selection
+    pub fn on_complete<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RgbaImage) + 'static,
+    {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Set the callback for Escape
+    pub fn on_cancel<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.on_cancel = Some(Box::new(callback));
+        self
+    }
+
+    /// The frozen screenshot
+    pub fn screenshot(&self) -> &RgbaImage {
+        &self.screenshot
+    }
+
+    /// Overlay size in logical pixels
+    pub fn viewport_size(&self) -> (f32, f32) {
+        (self.screenshot.width as f32 / self.scale_factor, self.screenshot.height as f32 / self.scale_factor)
+    }
+
+    /// Current rectangle (logical pixels)
+    pub fn selection(&self) -> Option<Rect> {
+        self.selection
+    }
+
+    /// Is a rectangle being dragged?
+    pub fn is_dragging(&self) -> bool {
+        self.drag_from.is_some()
+    }
+
+    /// Start a rectangle
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) {
+        if !self.is_open.get() {
+            return;
+        }
+        let (x, y) = self.clamp_point(x, y);
+        self.drag_from = Some((x, y));
+        self.selection = Some((x, y, 0.0, 0.0));
+    }
+
+    /// Stretch the rectangle
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        let Some((start_x, start_y)) = self.drag_from else {
+            return;
+        };
+        let (x, y) = self.clamp_point(x, y);
+        self.selection = Some((start_x.min(x), start_y.min(y), (x - start_x).abs(), (y - start_y).abs()));
+    }
+
+    /// Finish the rectangle
+    /// Returns the picked image (also passed to `on_complete`) and closes the overlay
+    pub fn handle_mouse_up(&mut self, x: f32, y: f32) -> Option<RgbaImage> {
+        self.drag_from?;
+        self.handle_mouse_move(x, y);
+        self.drag_from = None;
+        let Some(image) = self.selected_image() else {
+            self.selection = None;
+            return None;
+        };
+        info!("✂️ Picked {}x{} region", image.width, image.height);
+        if let Some(callback) = &self.on_complete {
+            callback(&image);
+        }
+        self.is_open.set(false);
+        Some(image)
+    }
+
+    /// Cancel and close
+    pub fn handle_escape(&mut self) {
+        self.drag_from = None;
+        self.selection = None;
+        self.is_open.set(false);
+        if let Some(callback) = &self.on_cancel {
+            callback();
+        }
+    }
+
+    /// Selection in screenshot pixels (x, y, width, height)
+    pub fn selection_in_pixels(&self) -> Option<(u32, u32, u32, u32)> {
+        let (x, y, width, height) = self.selection?;
+        if width < self.min_size || height < self.min_size {
+            return None;
+        }
+        let scale = self.scale_factor;
+        Some((
+            (x * scale).round() as u32,
+            (y * scale).round() as u32,
+            (width * scale).round() as u32,
+            (height * scale).round() as u32,
+        ))
+    }
+
+    /// Crop the screenshot to the selection
+    pub fn selected_image(&self) -> Option<RgbaImage> {
+        let (x, y, width, height) = self.selection_in_pixels()?;
+        Some(self.screenshot.crop(x, y, width, height))
+    }
+
+    fn clamp_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let (width, height) = self.viewport_size();
+        (x.clamp(0.0, width), y.clamp(0.0, height))
+    }
+
+    /// Draw the overlay (`image` is the uploaded screenshot texture)
+    pub fn paint(&self, list: &mut DrawList, image: ImageId) {
+        if !self.is_open.get() {
+            return;
+        }
+        let (width, height) = self.viewport_size();
+        list.image(image, (0.0, 0.0, width, height));
+
+        let Some((x, y, w, h)) = self.selection.filter(|&(_, _, w, h)| w > 0.0 && h > 0.0) else {
+            list.rect((0.0, 0.0, width, height), self.dim_color);
+            return;
+        };
+        // Dim around the hole
+        list.rect((0.0, 0.0, width, y), self.dim_color);
+        list.rect((0.0, y + h, width, height - y - h), self.dim_color);
+        list.rect((0.0, y, x, h), self.dim_color);
+        list.rect((x + w, y, width - x - w, h), self.dim_color);
+
+        let border = 1.0;
+        list.rect((x, y, w, border), self.border_color);
+        list.rect((x, y + h - border, w, border), self.border_color);
+        list.rect((x, y, border, h), self.border_color);
+        list.rect((x + w - border, y, border, h), self.border_color);
+
+        if let Some((_, _, pixel_width, pixel_height)) = self.selection_in_pixels() {
+            let label_y = if y >= 20.0 { y - 18.0 } else { y + h + 4.0 };
+            list.text(format!("{} × {}", pixel_width, pixel_height), x, label_y, 12.0, self.label_color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn screenshot() -> RgbaImage {
+        // 20x10 physical pixels, red = x
+        let pixels = (0..10).flat_map(|_| (0..20u8).flat_map(|x| [x, 0, 0, 255])).collect();
+        RgbaImage::from_raw(20, 10, pixels).unwrap()
+    }
+
+    #[test]
+    fn drag_picks_region_in_physical_pixels() {
+        let picked = Rc::new(Cell::new(0));
+        let count = picked.clone();
+        let mut picker = RegionPicker::new(screenshot(), 2.0).on_complete(move |_| count.set(count.get() + 1));
+        assert_eq!(picker.viewport_size(), (10.0, 5.0));
+        picker.min_size = 1.0;
+
+        // Dragging up-left still gives a positive rectangle
+        picker.handle_mouse_down(6.0, 4.0);
+        picker.handle_mouse_move(2.0, 1.0);
+        assert_eq!(picker.selection(), Some((2.0, 1.0, 4.0, 3.0)));
+
+        let image = picker.handle_mouse_up(2.0, 1.0).unwrap();
+        assert_eq!((image.width, image.height), (8, 6));
+        assert_eq!(image.pixel(0, 0), Some([4, 0, 0, 255]));
+        assert_eq!(picked.get(), 1);
+        assert!(!picker.is_open.get());
+    }
+
+    #[test]
+    fn tiny_drag_and_escape() {
+        let cancelled = Rc::new(Cell::new(false));
+        let flag = cancelled.clone();
+        let mut picker = RegionPicker::new(screenshot(), 1.0).on_cancel(move || flag.set(true));
+
+        picker.handle_mouse_down(5.0, 5.0);
+        assert!(picker.handle_mouse_up(6.0, 6.0).is_none());
+        assert!(picker.selection().is_none());
+        assert!(picker.is_open.get());
+
+        picker.handle_escape();
+        assert!(cancelled.get());
+        assert!(!picker.is_open.get());
+    }
+}
@@ -0,0 +1,306 @@
+// Reorder - Drag-to-reorder state shared by List, Tabs and Navigation
+// Works along one axis: y for lists, x for tab strips and nav bars
+
+use nebula_core::animation::SpringAnimation;
+
+/// Called with the old and new index after an item moves
+pub type ReorderCallback = Box<dyn Fn(usize, usize)>;
+
+#[derive(Debug, Clone)]
+struct Drag {
+    from: usize,
+    target: usize,
+    sizes: Vec<f32>,
+    grab_offset: f32,
+    pointer: f32,
+}
+
+impl Drag {
+    fn start_of(&self, index: usize) -> f32 {
+        self.sizes[..index].iter().sum()
+    }
+}
+
+/// Drag-to-reorder state with live gap animation ↕️
+///
+/// While dragging, the items between the original and target slot slide
+/// over to open a gap; on drop, the dragged item springs into its new
+/// slot. Positions are along the main axis, relative to the first item.
+#[derive(Debug, Clone)]
+pub struct ReorderState {
+    drag: Option<Drag>,
+    offsets: Vec<SpringAnimation>,
+    drop: Option<(usize, SpringAnimation)>,
+    /// Distance from the viewport edge where auto-scroll kicks in
+    pub auto_scroll_margin: f32,
+    /// Auto-scroll speed at the very edge, in pixels per second
+    pub auto_scroll_speed: f32,
+}
+
+impl ReorderState {
+    /// Create idle reorder state
+    pub fn new() -> Self {
+        Self {
+            drag: None,
+            offsets: Vec::new(),
+            drop: None,
+            auto_scroll_margin: 48.0,
+            auto_scroll_speed: 600.0,
+        }
+    }
+
+    /// Pick up an item
+    /// `sizes` are the main-axis sizes of every item (including spacing)
+    pub fn begin(&mut self, index: usize, sizes: Vec<f32>, pointer: f32) {
+        if index >= sizes.len() {
+            return;
+        }
+        let spring = || SpringAnimation::new(0.0, 0.0).stiffness(400.0).damping(40.0);
+        self.offsets = (0..sizes.len()).map(|_| spring()).collect();
+        self.drop = None;
+        let mut drag = Drag { from: index, target: index, sizes, grab_offset: 0.0, pointer };
+        drag.grab_offset = pointer - drag.start_of(index);
+        self.drag = Some(drag);
+    }
+
+    /// Is an item being dragged?
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Index of the dragged item
+    pub fn dragged(&self) -> Option<usize> {
+        self.drag.as_ref().map(|drag| drag.from)
+    }
+
+    /// Slot the dragged item would land in if dropped now
+    pub fn target(&self) -> Option<usize> {
+        self.drag.as_ref().map(|drag| drag.target)
+    }
+
+    /// Where the lifted item is drawn (start edge, following the pointer)
+    pub fn dragged_position(&self) -> Option<f32> {
+        self.drag.as_ref().map(|drag| drag.pointer - drag.grab_offset)
+    }
+
+    /// Move the pointer, returning the new target slot
+    pub fn drag_to(&mut self, pointer: f32) -> Option<usize> {
+        let drag = self.drag.as_mut()?;
+        drag.pointer = pointer;
+
+        let size = drag.sizes[drag.from];
+        let center = pointer - drag.grab_offset + size / 2.0;
+        // Slot = how many other items have their midpoint before ours
+        let mut start = 0.0;
+        let mut target = 0;
+        for (index, &other) in drag.sizes.iter().enumerate() {
+            if index != drag.from && start + other / 2.0 < center {
+                target += 1;
+            }
+            start += other;
+        }
+        drag.target = target;
+
+        let (from, target) = (drag.from, drag.target);
+        for (index, spring) in self.offsets.iter_mut().enumerate() {
+            let shift = if from < target && index > from && index <= target {
+                -size
+            } else if target < from && index >= target && index < from {
+                size
+            } else {
+                0.0
+            };
+            if spring.target() != shift {
+                spring.set_target(shift);
+            }
+        }
+        Some(target)
+    }
+
+    /// Drop the item, returning (from, to) if it moved
+    /// The caller applies the move; the dropped item then springs into place
+    pub fn end(&mut self) -> Option<(usize, usize)> {
+        let drag = self.drag.take()?;
+        let landed_at = drag.pointer - drag.grab_offset;
+        let mut sizes = drag.sizes.clone();
+        let size = sizes.remove(drag.from);
+        sizes.insert(drag.target, size);
+        let slot: f32 = sizes[..drag.target].iter().sum();
+
+        // Items are reordered for real now, so their gap offsets go away
+        self.offsets.clear();
+        self.drop = Some((drag.target, SpringAnimation::new(landed_at - slot, 0.0).stiffness(400.0).damping(40.0)));
+        (drag.from != drag.target).then_some((drag.from, drag.target))
+    }
+
+    /// Abandon the drag; everything springs back
+    pub fn cancel(&mut self) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        let landed_at = drag.pointer - drag.grab_offset;
+        for spring in &mut self.offsets {
+            spring.set_target(0.0);
+        }
+        let slot = drag.start_of(drag.from);
+        self.drop = Some((drag.from, SpringAnimation::new(landed_at - slot, 0.0).stiffness(400.0).damping(40.0)));
+    }
+
+    /// Visual offset of an item from its slot (gap animation or drop settle)
+    pub fn offset(&self, index: usize) -> f32 {
+        if let Some((dropped, spring)) = &self.drop {
+            if *dropped == index {
+                return spring.value();
+            }
+        }
+        self.offsets.get(index).map_or(0.0, |spring| spring.value())
+    }
+
+    /// Advance animations (call every frame)
+    /// Returns true while anything is still moving
+    pub fn update(&mut self, dt: f32) -> bool {
+        let mut moving = false;
+        for spring in &mut self.offsets {
+            moving |= spring.update(dt);
+        }
+        if let Some((_, spring)) = &mut self.drop {
+            if spring.update(dt) {
+                moving = true;
+            } else {
+                self.drop = None;
+            }
+        }
+        if !moving && self.drag.is_none() {
+            self.offsets.clear();
+        }
+        moving
+    }
+
+    /// Scroll velocity while dragging near a viewport edge (pixels per second)
+    /// Negative scrolls toward the start; zero when not dragging or away from the edges
+    pub fn auto_scroll_velocity(&self, pointer: f32, viewport_start: f32, viewport_length: f32) -> f32 {
+        if self.drag.is_none() || self.auto_scroll_margin <= 0.0 {
+            return 0.0;
+        }
+        let margin = self.auto_scroll_margin.min(viewport_length / 2.0);
+        let from_start = pointer - viewport_start;
+        let from_end = viewport_start + viewport_length - pointer;
+        if from_start < margin {
+            -self.auto_scroll_speed * (1.0 - from_start / margin).min(1.0)
+        } else if from_end < margin {
+            self.auto_scroll_speed * (1.0 - from_end / margin).min(1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for ReorderState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Move an element from one index to another, shifting the ones between
+pub fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from >= items.len() || from == to {
+        return;
+    }
+    let item = items.remove(from);
+    items.insert(to.min(items.len()), item);
+}
+
+/// New index after a keyboard move (Ctrl+arrow) of `delta` slots
+/// Returns None when the item is already at that end
+pub fn keyboard_target(index: usize, delta: isize, len: usize) -> Option<usize> {
+    if index >= len {
+        return None;
+    }
+    let target = (index as isize + delta).clamp(0, len as isize - 1) as usize;
+    (target != index).then_some(target)
+}
+
+/// Where an index ends up after moving `from` to `to` (e.g. the active tab)
+pub fn index_after_move(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(state: &mut ReorderState) {
+        for _ in 0..600 {
+            if !state.update(1.0 / 60.0) {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn drag_opens_gap_and_drops() {
+        let mut state = ReorderState::new();
+        state.begin(0, vec![40.0; 5], 10.0);
+        assert_eq!(state.dragged(), Some(0));
+
+        // Item center passes the midpoints of items 1 and 2
+        assert_eq!(state.drag_to(95.0), Some(2));
+        settle(&mut state);
+        assert!((state.offset(1) + 40.0).abs() < 0.01);
+        assert!((state.offset(2) + 40.0).abs() < 0.01);
+        assert_eq!(state.offset(3), 0.0);
+        assert_eq!(state.dragged_position(), Some(85.0));
+
+        assert_eq!(state.end(), Some((0, 2)));
+        // Lands 5px past its slot at 80, then settles
+        assert!((state.offset(2) - 5.0).abs() < 0.01);
+        settle(&mut state);
+        assert_eq!(state.offset(2), 0.0);
+    }
+
+    #[test]
+    fn drag_backwards_and_cancel() {
+        let mut state = ReorderState::new();
+        state.begin(3, vec![40.0; 5], 130.0);
+        assert_eq!(state.drag_to(50.0), Some(1));
+        state.cancel();
+        assert!(!state.is_dragging());
+        settle(&mut state);
+        assert_eq!(state.offset(1), 0.0);
+        assert_eq!(state.end(), None);
+    }
+
+    #[test]
+    fn auto_scroll_near_edges() {
+        let mut state = ReorderState::new();
+        assert_eq!(state.auto_scroll_velocity(0.0, 0.0, 400.0), 0.0);
+        state.begin(0, vec![40.0; 20], 10.0);
+        assert_eq!(state.auto_scroll_velocity(0.0, 0.0, 400.0), -600.0);
+        assert_eq!(state.auto_scroll_velocity(376.0, 0.0, 400.0), 300.0);
+        assert_eq!(state.auto_scroll_velocity(200.0, 0.0, 400.0), 0.0);
+    }
+
+    #[test]
+    fn move_helpers() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+        move_item(&mut items, 0, 2);
+        assert_eq!(items, vec!['b', 'c', 'a', 'd']);
+
+        assert_eq!(keyboard_target(0, -1, 4), None);
+        assert_eq!(keyboard_target(0, 1, 4), Some(1));
+        assert_eq!(keyboard_target(3, 1, 4), None);
+
+        assert_eq!(index_after_move(0, 0, 2), 2);
+        assert_eq!(index_after_move(2, 0, 2), 1);
+        assert_eq!(index_after_move(1, 3, 0), 2);
+        assert_eq!(index_after_move(3, 0, 2), 3);
+    }
+}
@@ -0,0 +1,519 @@
+// Row Expansion - Expandable detail panels for Table and DataGrid
+// Tracks which rows are open, animates their detail height, and keeps
+// variable-height row offsets in sync for virtualization
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use nebula_core::animation::SpringAnimation;
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+
+use crate::table::TableRow;
+
+/// Builds the detail component tree shown under an expanded row
+pub type DetailBuilder = Box<dyn Fn(&TableRow, &mut LayoutEngine) -> Result<NodeId, String>>;
+
+/// Called with the row id and whether it is now expanded
+pub type ExpandCallback = Box<dyn Fn(&str, bool)>;
+
+/// Width of the expander (chevron) column
+pub const EXPANDER_COLUMN_WIDTH: f32 = 40.0;
+
+/// Detail panel built for a row, measured once the table is laid out
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BuiltDetail {
+    row_id: String,
+    panel: NodeId,
+    detail: NodeId,
+}
+
+/// How many rows may be open at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpansionMode {
+    /// Any number of rows can be expanded
+    #[default]
+    Multiple,
+    /// Opening a row closes the others
+    Accordion,
+}
+
+/// Expanded-row state with animated detail heights 📂
+///
+/// Detail heights start at `default_detail_height` until the real height is
+/// reported with `set_detail_height` (Table and DataGrid do this from
+/// `measure_details` after the detail tree is laid out).
+/// Offsets always use the animated height, so scroll positions stay correct
+/// while a panel opens or closes.
+#[derive(Debug, Clone)]
+pub struct RowExpansion {
+    pub mode: ExpansionMode,
+    pub expanded: Signal<Vec<String>>,
+    pub default_detail_height: f32,
+    measured: HashMap<String, f32>,
+    progress: HashMap<String, SpringAnimation>,
+    animated: bool,
+}
+
+impl RowExpansion {
+    /// Create expansion state for the given mode
+    pub fn new(mode: ExpansionMode) -> Self {
+        Self {
+            mode,
+            expanded: Signal::new(Vec::new()),
+            default_detail_height: 120.0,
+            measured: HashMap::new(),
+            progress: HashMap::new(),
+            animated: true,
+        }
+    }
+
+    /// Set the detail height used before a row is measured
+    pub fn default_detail_height(mut self, height: f32) -> Self {
+        self.default_detail_height = height.max(0.0);
+        self
+    }
+
+    /// Enable or disable the expand/collapse animation
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Is the row expanded (or expanding)?
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded.get().iter().any(|expanded| expanded == id)
+    }
+
+    /// Expand a row (collapsing the others in accordion mode)
+    pub fn expand(&mut self, id: &str) {
+        if self.is_expanded(id) {
+            return;
+        }
+        if self.mode == ExpansionMode::Accordion {
+            self.collapse_all();
+        }
+        self.expanded.update(|ids| {
+            let mut ids = ids.clone();
+            ids.push(id.to_string());
+            ids
+        });
+        self.animate_to(id, 1.0);
+    }
+
+    /// Collapse a row
+    pub fn collapse(&mut self, id: &str) {
+        if !self.is_expanded(id) {
+            return;
+        }
+        self.expanded.update(|ids| ids.iter().filter(|expanded| *expanded != id).cloned().collect());
+        self.animate_to(id, 0.0);
+    }
+
+    /// Toggle a row, returning whether it is now expanded
+    pub fn toggle(&mut self, id: &str) -> bool {
+        if self.is_expanded(id) {
+            self.collapse(id);
+            false
+        } else {
+            self.expand(id);
+            true
+        }
+    }
+
+    /// Collapse every row
+    pub fn collapse_all(&mut self) {
+        for id in self.expanded.get() {
+            self.collapse(&id);
+        }
+    }
+
+    /// Record the laid-out height of a row's detail panel
+    pub fn set_detail_height(&mut self, id: &str, height: f32) {
+        self.measured.insert(id.to_string(), height.max(0.0));
+    }
+
+    /// Full (unanimated) detail height for a row
+    pub fn measured_height(&self, id: &str) -> f32 {
+        self.measured.get(id).copied().unwrap_or(self.default_detail_height)
+    }
+
+    /// Expansion progress for a row, 0.0 (closed) to 1.0 (open)
+    pub fn progress(&self, id: &str) -> f32 {
+        match self.progress.get(id) {
+            Some(spring) => spring.value().clamp(0.0, 1.0),
+            None if self.is_expanded(id) => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Current (animated) detail height for a row
+    pub fn detail_height(&self, id: &str) -> f32 {
+        self.measured_height(id) * self.progress(id)
+    }
+
+    /// Is any panel still opening or closing?
+    pub fn is_animating(&self) -> bool {
+        !self.progress.is_empty()
+    }
+
+    /// Advance animations (call every frame)
+    /// Returns true while any panel is still moving
+    pub fn update(&mut self, dt: f32) -> bool {
+        self.progress.retain(|_, spring| spring.update(dt));
+        !self.progress.is_empty()
+    }
+
+    fn animate_to(&mut self, id: &str, target: f32) {
+        if !self.animated {
+            self.progress.remove(id);
+            return;
+        }
+        let start = 1.0 - target;
+        self.progress
+            .entry(id.to_string())
+            // Critically damped so panels never overshoot their content
+            .or_insert_with(|| SpringAnimation::new(start, target).stiffness(300.0).damping(35.0))
+            .set_target(target);
+    }
+
+    /// Height of a row including its detail panel
+    pub fn row_extent(&self, id: &str, row_height: f32) -> f32 {
+        row_height + self.detail_height(id)
+    }
+
+    /// Top offset of every row, plus the total height as the last entry
+    pub fn row_offsets(&self, row_ids: &[&str], row_height: f32) -> Vec<f32> {
+        let mut offsets = Vec::with_capacity(row_ids.len() + 1);
+        let mut y = 0.0;
+        offsets.push(y);
+        for id in row_ids {
+            y += self.row_extent(id, row_height);
+            offsets.push(y);
+        }
+        offsets
+    }
+
+    /// Total content height of all rows and open panels
+    pub fn total_height(&self, row_ids: &[&str], row_height: f32) -> f32 {
+        row_ids.iter().map(|id| self.row_extent(id, row_height)).sum()
+    }
+
+    /// Index of the row (or its detail panel) at a content offset
+    pub fn row_at_offset(&self, row_ids: &[&str], row_height: f32, y: f32) -> Option<usize> {
+        if y < 0.0 {
+            return None;
+        }
+        let offsets = self.row_offsets(row_ids, row_height);
+        // offsets[i] <= y < offsets[i + 1]
+        let index = offsets.partition_point(|&top| top <= y);
+        (index > 0 && index <= row_ids.len()).then(|| index - 1)
+    }
+
+    /// Rows intersecting the viewport, for virtualized rendering
+    pub fn visible_rows(&self, row_ids: &[&str], row_height: f32, scroll_y: f32, viewport_height: f32) -> Range<usize> {
+        let offsets = self.row_offsets(row_ids, row_height);
+        let top = scroll_y.max(0.0);
+        let bottom = top + viewport_height.max(0.0);
+        let start = offsets.partition_point(|&offset| offset <= top).saturating_sub(1).min(row_ids.len());
+        let end = offsets.partition_point(|&offset| offset < bottom).min(row_ids.len());
+        start..end.max(start)
+    }
+
+    /// Build layout nodes for a list of rows, inserting a clipped detail
+    /// panel under every row that is open or animating (recorded in `details`)
+    pub(crate) fn build_rows(
+        &self,
+        engine: &mut LayoutEngine,
+        rows: &[&TableRow],
+        row_height: f32,
+        builder: Option<&DetailBuilder>,
+        details: &mut Vec<BuiltDetail>,
+    ) -> Result<Vec<NodeId>, String> {
+        let mut nodes = Vec::with_capacity(rows.len());
+        for row in rows {
+            let row_node = engine
+                .new_leaf(taffy::style::Style {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Percent(1.0),
+                        height: taffy::style::Dimension::Length(row_height),
+                    },
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                })
+                .map_err(|e| format!("Failed to create row node: {:?}", e))?;
+            nodes.push(row_node);
+
+            let height = self.detail_height(&row.id);
+            let Some(builder) = builder.filter(|_| height > 0.0) else {
+                continue;
+            };
+            let detail = builder(row, engine)?;
+            let panel = engine
+                .new_with_children(
+                    taffy::style::Style {
+                        size: taffy::geometry::Size {
+                            width: taffy::style::Dimension::Percent(1.0),
+                            height: taffy::style::Dimension::Length(height),
+                        },
+                        overflow: taffy::geometry::Point {
+                            x: taffy::style::Overflow::Hidden,
+                            y: taffy::style::Overflow::Hidden,
+                        },
+                        flex_shrink: 0.0,
+                        ..Default::default()
+                    },
+                    &[detail],
+                )
+                .map_err(|e| format!("Failed to create detail panel node: {:?}", e))?;
+            nodes.push(panel);
+            details.push(BuiltDetail { row_id: row.id.clone(), panel, detail });
+        }
+        Ok(nodes)
+    }
+
+    /// Lay each built detail tree out at its natural height, as wide as its
+    /// panel, and record the heights; returns true if any height changed
+    pub(crate) fn measure_details(&mut self, engine: &mut LayoutEngine, details: &[BuiltDetail]) -> Result<bool, String> {
+        let mut changed = false;
+        for built in details {
+            let width = engine
+                .get_layout(built.panel)
+                .map_err(|e| format!("Detail panel for row {} is not laid out: {:?}", built.row_id, e))?
+                .size
+                .width;
+            let available = taffy::geometry::Size {
+                width: taffy::style::AvailableSpace::Definite(width),
+                height: taffy::style::AvailableSpace::MaxContent,
+            };
+            engine.mark_dirty(built.detail);
+            let height = engine
+                .compute_layout(built.detail, available)
+                .map_err(|e| format!("Failed to measure detail for row {}: {:?}", built.row_id, e))?
+                .size
+                .height;
+            if (self.measured_height(&built.row_id) - height).abs() > 0.5 {
+                self.set_detail_height(&built.row_id, height);
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+}
+
+impl Default for RowExpansion {
+    fn default() -> Self {
+        Self::new(ExpansionMode::default())
+    }
+}
+
+/// Everything Table and DataGrid need for expandable rows 📂
+///
+/// Expansion state (None = rows don't expand), the detail builder, and the
+/// expand/collapse callback, so both components share one implementation.
+#[derive(Default)]
+pub struct ExpandableRows {
+    pub state: Option<RowExpansion>,
+    pub detail_builder: Option<DetailBuilder>,
+    pub on_expand: Option<ExpandCallback>,
+    /// Detail panels from the last build
+    details: Vec<BuiltDetail>,
+}
+
+impl ExpandableRows {
+    /// Make rows expandable
+    pub fn enable(&mut self, mode: ExpansionMode) {
+        self.state = Some(RowExpansion::new(mode));
+    }
+
+    /// Set the detail builder (enabling expansion if needed)
+    pub fn set_detail(&mut self, builder: DetailBuilder) {
+        if self.state.is_none() {
+            self.state = Some(RowExpansion::default());
+        }
+        self.detail_builder = Some(builder);
+    }
+
+    /// Are rows expandable?
+    pub fn is_enabled(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// Toggle a row and run the callback
+    /// Returns whether it is now expanded, or None if rows don't expand
+    pub fn toggle(&mut self, id: &str) -> Option<bool> {
+        let expanded = self.state.as_mut()?.toggle(id);
+        if let Some(ref callback) = self.on_expand {
+            callback(id, expanded);
+        }
+        Some(expanded)
+    }
+
+    /// Is the row expanded (or expanding)?
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.state.as_ref().is_some_and(|state| state.is_expanded(id))
+    }
+
+    /// Advance expand/collapse animations
+    /// Returns true while a panel is still moving
+    pub fn update(&mut self, dt: f32) -> bool {
+        self.state.as_mut().is_some_and(|state| state.update(dt))
+    }
+
+    /// Total height of the rows and their open panels
+    pub fn total_height(&self, row_ids: &[&str], row_height: f32) -> f32 {
+        match self.state {
+            Some(ref state) => state.total_height(row_ids, row_height),
+            None => row_ids.len() as f32 * row_height,
+        }
+    }
+
+    /// Rows intersecting the viewport
+    pub fn visible_rows(&self, row_ids: &[&str], row_height: f32, scroll_y: f32, viewport_height: f32) -> Range<usize> {
+        match self.state {
+            Some(ref state) => state.visible_rows(row_ids, row_height, scroll_y, viewport_height),
+            None => RowExpansion::default().visible_rows(row_ids, row_height, scroll_y, viewport_height),
+        }
+    }
+
+    /// Forget the detail panels of the previous build (call before building rows)
+    pub(crate) fn begin_build(&mut self) {
+        self.details.clear();
+    }
+
+    /// Row and detail panel nodes (None if rows don't expand)
+    pub(crate) fn build_rows(
+        &mut self,
+        engine: &mut LayoutEngine,
+        rows: &[&TableRow],
+        row_height: f32,
+    ) -> Option<Result<Vec<NodeId>, String>> {
+        let state = self.state.as_ref()?;
+        Some(state.build_rows(engine, rows, row_height, self.detail_builder.as_ref(), &mut self.details))
+    }
+
+    /// Measure the built detail panels at their natural height (after layout)
+    /// Returns true if a height changed, so the rows need building and laying out again
+    pub fn measure_details(&mut self, engine: &mut LayoutEngine) -> Result<bool, String> {
+        match self.state.as_mut() {
+            Some(state) => state.measure_details(engine, &self.details),
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(expansion: &mut RowExpansion) {
+        for _ in 0..600 {
+            if !expansion.update(1.0 / 60.0) {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn multiple_mode_keeps_rows_open() {
+        let mut expansion = RowExpansion::new(ExpansionMode::Multiple);
+        assert!(expansion.toggle("a"));
+        expansion.expand("b");
+        assert!(expansion.is_expanded("a"));
+        assert!(expansion.is_expanded("b"));
+        assert!(!expansion.toggle("a"));
+        assert_eq!(expansion.expanded.get(), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn expandable_rows_toggle_runs_callback() {
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = calls.clone();
+        let mut rows = ExpandableRows {
+            on_expand: Some(Box::new(move |id, expanded| log.borrow_mut().push((id.to_string(), expanded)))),
+            ..Default::default()
+        };
+        assert_eq!(rows.toggle("a"), None);
+        assert_eq!(rows.total_height(&["a", "b"], 10.0), 20.0);
+
+        rows.enable(ExpansionMode::Multiple);
+        assert_eq!(rows.toggle("a"), Some(true));
+        assert!(rows.is_expanded("a"));
+        assert_eq!(*calls.borrow(), vec![("a".to_string(), true)]);
+    }
+
+    #[test]
+    fn accordion_mode_closes_others() {
+        let mut expansion = RowExpansion::new(ExpansionMode::Accordion);
+        expansion.expand("a");
+        expansion.expand("b");
+        assert!(!expansion.is_expanded("a"));
+        assert_eq!(expansion.expanded.get(), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn detail_height_animates_open_and_closed() {
+        let mut expansion = RowExpansion::new(ExpansionMode::Multiple).default_detail_height(100.0);
+        expansion.expand("a");
+        assert_eq!(expansion.detail_height("a"), 0.0);
+
+        expansion.update(1.0 / 60.0);
+        expansion.update(1.0 / 60.0);
+        let partway = expansion.detail_height("a");
+        assert!(partway > 0.0 && partway < 100.0);
+
+        settle(&mut expansion);
+        assert!(!expansion.is_animating());
+        assert_eq!(expansion.detail_height("a"), 100.0);
+
+        // Still takes space while collapsing
+        expansion.collapse("a");
+        expansion.update(1.0 / 60.0);
+        assert!(expansion.detail_height("a") > 0.0);
+        settle(&mut expansion);
+        assert_eq!(expansion.detail_height("a"), 0.0);
+    }
+
+    #[test]
+    fn offsets_account_for_variable_heights() {
+        let mut expansion = RowExpansion::new(ExpansionMode::Multiple).animated(false);
+        expansion.expand("b");
+        expansion.set_detail_height("b", 200.0);
+
+        let ids = ["a", "b", "c", "d"];
+        assert_eq!(expansion.row_offsets(&ids, 50.0), vec![0.0, 50.0, 300.0, 350.0, 400.0]);
+        assert_eq!(expansion.total_height(&ids, 50.0), 400.0);
+
+        // Inside b's detail panel
+        assert_eq!(expansion.row_at_offset(&ids, 50.0, 120.0), Some(1));
+        assert_eq!(expansion.row_at_offset(&ids, 50.0, 310.0), Some(2));
+        assert_eq!(expansion.row_at_offset(&ids, 50.0, 400.0), None);
+
+        assert_eq!(expansion.visible_rows(&ids, 50.0, 0.0, 60.0), 0..2);
+        assert_eq!(expansion.visible_rows(&ids, 50.0, 100.0, 210.0), 1..3);
+        assert_eq!(expansion.visible_rows(&ids, 50.0, 1000.0, 100.0), 4..4);
+    }
+
+    #[test]
+    fn build_rows_inserts_detail_panels() {
+        let mut engine = LayoutEngine::new();
+        let mut expansion = RowExpansion::new(ExpansionMode::Multiple).animated(false);
+        expansion.expand("b");
+
+        let rows = [TableRow::new("a", vec![]), TableRow::new("b", vec![])];
+        let rows: Vec<&TableRow> = rows.iter().collect();
+        let builder: DetailBuilder = Box::new(|_, engine| {
+            engine
+                .new_leaf(taffy::style::Style::default())
+                .map_err(|e| format!("{:?}", e))
+        });
+
+        let mut details = Vec::new();
+        let nodes = expansion.build_rows(&mut engine, &rows, 48.0, Some(&builder), &mut details).unwrap();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(details.len(), 1);
+        assert_eq!(details[0].row_id, "b");
+        let nodes = expansion.build_rows(&mut engine, &rows, 48.0, None, &mut details).unwrap();
+        assert_eq!(nodes.len(), 2);
+    }
+}
@@ -1,7 +1,9 @@
-use nebula_core::{LayoutEngine, NodeId, Layout};
+use nebula_core::{LayoutEngine, LayoutDirection, NodeId, Layout};
 use taffy::prelude::*;
 use tracing::{info, warn};
 
+use crate::scroll_memory::{ScrollKey, ScrollPosition};
+
 /// ScrollView - Scrollable Container 📜
 /// 
 /// Essential for content that doesn't fit on screen!
@@ -10,6 +12,7 @@ use tracing::{info, warn};
 /// - Scroll position tracking
 /// - Scroll indicators
 /// - Nested scrolling support
+/// - Right-to-left aware (horizontal offsets start at the right edge)
 /// - Works on old hardware!
 /// 
 /// Just like SwiftUI's ScrollView!
@@ -21,7 +24,8 @@ pub struct ScrollView {
     pub content: Option<NodeId>,
     /// Scroll direction
     pub direction: ScrollDirection,
-    /// Current scroll offset (x, y)
+    /// Current scroll offset (x, y); x is measured from the start edge,
+    /// which is the right edge in RTL layouts
     pub scroll_offset: (f32, f32),
     /// Content size (width, height)
     pub content_size: (f32, f32),
@@ -37,6 +41,8 @@ pub struct ScrollView {
     pub width: Option<f32>,
     /// Height (None = fill parent)
     pub height: Option<f32>,
+    /// Scroll restoration across navigation
+    pub scroll_memory: ScrollKey,
 }
 
 /// Scroll direction
@@ -66,6 +72,7 @@ impl ScrollView {
             velocity: (0.0, 0.0),
             width: None,
             height: None,
+            scroll_memory: ScrollKey::default(),
         }
     }
 
@@ -106,6 +113,19 @@ impl ScrollView {
         self
     }
 
+    /// Remember the scroll position under this id on the current route
+    /// (restored when the screen is built again after navigating back)
+    pub fn scroll_id(mut self, id: impl Into<String>) -> Self {
+        self.scroll_memory.id = Some(id.into());
+        self
+    }
+
+    /// Opt this view out of scroll restoration
+    pub fn remember_scroll(mut self, remember: bool) -> Self {
+        self.scroll_memory.enabled = remember;
+        self
+    }
+
     /// Set content node
     pub fn content(mut self, content: NodeId) -> Self {
         self.content = Some(content);
@@ -121,15 +141,42 @@ impl ScrollView {
             y.max(0.0).min(max_y),
         );
         
+        self.scroll_memory.save(ScrollPosition { offset: self.scroll_offset, page: 0 });
         info!("📜 Scrolled to ({}, {})", self.scroll_offset.0, self.scroll_offset.1);
     }
 
-    /// Scroll by a delta amount
+    /// Jump to the remembered position (once; `build` calls this)
+    pub fn restore_scroll(&mut self) {
+        // Content may not be measured yet, so the offset isn't clamped here
+        if let Some(position) = self.scroll_memory.take_restore() {
+            self.scroll_offset = position.offset;
+        }
+    }
+
+    /// Scroll by a delta amount (dx towards the end edge)
     pub fn scroll_by(&mut self, dx: f32, dy: f32) {
         let (x, y) = self.scroll_offset;
         self.scroll_to(x + dx, y + dy);
     }
 
+    /// Scroll by a wheel or trackpad delta (dx positive = towards the right,
+    /// whatever the layout direction)
+    pub fn scroll_by_wheel(&mut self, dx: f32, dy: f32) {
+        let dx = if LayoutDirection::current().is_rtl() { -dx } else { dx };
+        self.scroll_by(dx, dy);
+    }
+
+    /// Horizontal offset from the left edge, whatever the layout direction
+    fn offset_from_left(&self) -> f32 {
+        let (max_x, _) = self.max_scroll_offset();
+        if LayoutDirection::current().is_rtl() { max_x - self.scroll_offset.0 } else { self.scroll_offset.0 }
+    }
+
+    /// Where to draw the content relative to the viewport (negated offset from the top-left)
+    pub fn content_translation(&self) -> (f32, f32) {
+        (-self.offset_from_left(), -self.scroll_offset.1)
+    }
+
     /// Scroll to top
     pub fn scroll_to_top(&mut self) {
         self.scroll_to(self.scroll_offset.0, 0.0);
@@ -143,13 +190,16 @@ impl ScrollView {
 
     /// Scroll to left
     pub fn scroll_to_left(&mut self) {
-        self.scroll_to(0.0, self.scroll_offset.1);
+        let (max_x, _) = self.max_scroll_offset();
+        let x = if LayoutDirection::current().is_rtl() { max_x } else { 0.0 };
+        self.scroll_to(x, self.scroll_offset.1);
     }
 
     /// Scroll to right
     pub fn scroll_to_right(&mut self) {
         let (max_x, _) = self.max_scroll_offset();
-        self.scroll_to(max_x, self.scroll_offset.1);
+        let x = if LayoutDirection::current().is_rtl() { 0.0 } else { max_x };
+        self.scroll_to(x, self.scroll_offset.1);
     }
 
     /// Get current scroll offset
@@ -189,13 +239,13 @@ impl ScrollView {
 
     /// Check if at left
     pub fn is_at_left(&self) -> bool {
-        self.scroll_offset.0 <= 0.0
+        self.offset_from_left() <= 0.0
     }
 
     /// Check if at right
     pub fn is_at_right(&self) -> bool {
         let (max_x, _) = self.max_scroll_offset();
-        self.scroll_offset.0 >= max_x
+        self.offset_from_left() >= max_x
     }
 
     /// Get scroll progress (0.0 to 1.0)
@@ -261,6 +311,7 @@ impl ScrollView {
             warn!("⚠️ ScrollView has no content");
             return Err("ScrollView requires content".to_string());
         }
+        self.restore_scroll();
 
         // Create a container that clips content
         let style = Style {
@@ -598,6 +649,26 @@ mod tests {
         assert_eq!(scroll1.height, scroll2.height);
     }
 
+    #[test]
+    fn rtl_starts_at_the_right_edge() {
+        let mut scroll = ScrollView::new().direction(ScrollDirection::Horizontal);
+        scroll.update_viewport_size(200.0, 100.0);
+        scroll.update_content_size(500.0, 100.0);
+        LayoutDirection::set(LayoutDirection::RTL);
+
+        // Offset 0 is the start edge: showing the rightmost 200px
+        assert!(scroll.is_at_right());
+        assert_eq!(scroll.content_translation(), (-300.0, 0.0));
+        // Swiping content rightwards (wheel towards the left) moves towards the end
+        scroll.scroll_by_wheel(-50.0, 0.0);
+        assert_eq!(scroll.scroll_offset.0, 50.0);
+        scroll.scroll_to_left();
+        assert!(scroll.is_at_left());
+        assert_eq!(scroll.content_translation(), (0.0, 0.0));
+
+        LayoutDirection::set(LayoutDirection::LTR);
+    }
+
     #[test]
     fn scroll_direction_equality() {
         assert_eq!(ScrollDirection::Vertical, ScrollDirection::Vertical);
@@ -0,0 +1,162 @@
+// ScrollMemory - Scroll positions that survive navigation
+// Keyed by route and component id; NavigationStack tracks the route
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use tracing::debug;
+
+thread_local! {
+    static ROUTE: RefCell<String> = const { RefCell::new(String::new()) };
+    static POSITIONS: RefCell<HashMap<(String, String), ScrollPosition>> = RefCell::new(HashMap::new());
+}
+
+/// A saved scroll state
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScrollPosition {
+    /// Scroll offset (x, y)
+    pub offset: (f32, f32),
+    /// Page, for paginated components
+    pub page: usize,
+}
+
+/// ScrollMemory - Where each scrollable was on each screen 🧭
+///
+/// Components with a scroll id save their position here whenever they
+/// scroll, under the current route, and restore it the first time they're
+/// built. `NavigationStack` sets the route on every push and pop and
+/// forgets a screen's positions once it's popped, so going back restores
+/// where you were while pushing a screen again starts at the top.
+/// Positions are per UI thread, like the other navigation state.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut inbox = VirtualList::new().scroll_id("inbox");
+/// // push "/inbox/42", pop back: a freshly built list starts where it was
+/// let mut preview = ScrollView::new().scroll_id("preview").remember_scroll(false);
+/// ```
+pub struct ScrollMemory;
+
+impl ScrollMemory {
+    /// Route positions are saved under
+    pub fn route() -> String {
+        ROUTE.with(|route| route.borrow().clone())
+    }
+
+    /// Switch to another route (NavigationStack does this)
+    pub fn set_route(route: &str) {
+        ROUTE.with(|current| *current.borrow_mut() = route.to_string());
+    }
+
+    /// Save a component's position on the current route
+    pub fn save(id: &str, position: ScrollPosition) {
+        let key = (Self::route(), id.to_string());
+        POSITIONS.with(|positions| positions.borrow_mut().insert(key, position));
+    }
+
+    /// A component's saved position on the current route
+    pub fn get(id: &str) -> Option<ScrollPosition> {
+        let key = (Self::route(), id.to_string());
+        POSITIONS.with(|positions| positions.borrow().get(&key).copied())
+    }
+
+    /// Drop every position saved on a route
+    pub fn forget_route(route: &str) {
+        debug!("🧭 Forgetting scroll positions for {}", route);
+        POSITIONS.with(|positions| positions.borrow_mut().retain(|(saved_route, _), _| saved_route != route));
+    }
+
+    /// Drop everything
+    pub fn clear() {
+        POSITIONS.with(|positions| positions.borrow_mut().clear());
+    }
+}
+
+/// A component's link to ScrollMemory: its id and whether it takes part
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollKey {
+    /// Scroll id (None = not remembered)
+    pub id: Option<String>,
+    /// Opt-out switch, on by default
+    pub enabled: bool,
+    restored: bool,
+}
+
+impl Default for ScrollKey {
+    fn default() -> Self {
+        Self { id: None, enabled: true, restored: false }
+    }
+}
+
+impl ScrollKey {
+    /// The saved position, the first time this is called
+    pub fn take_restore(&mut self) -> Option<ScrollPosition> {
+        if self.restored || !self.enabled {
+            return None;
+        }
+        self.restored = true;
+        ScrollMemory::get(self.id.as_deref()?)
+    }
+
+    /// Save a position (ignored without an id, when opted out, and until
+    /// the saved one has been restored, so setup can't overwrite it)
+    pub fn save(&self, position: ScrollPosition) {
+        if let (Some(id), true) = (&self.id, self.enabled && self.restored) {
+            ScrollMemory::save(id, position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datagrid::DataGrid;
+    use crate::nav_stack::{NavigationStack, Screen, ScreenTransition};
+    use crate::table::TableRow;
+    use crate::virtual_list::VirtualList;
+    use nebula_core::layout::LayoutEngine;
+
+    fn inbox_list() -> VirtualList {
+        let mut list = VirtualList::new().estimated_item_height(40.0).viewport_height(400.0).scroll_id("messages");
+        list.set_items((0..100).map(|i| i.to_string()).collect());
+        list
+    }
+
+    fn orders_grid(engine: &mut LayoutEngine) -> DataGrid {
+        let mut grid = DataGrid::new().paginated(true).page_size(10).add_column("id", "Order").scroll_id("orders");
+        grid.rows = (0..50).map(|i| TableRow::new(i.to_string(), vec![i.to_string()])).collect();
+        grid.build(engine).unwrap();
+        grid
+    }
+
+    #[test]
+    fn positions_come_back_after_pop_but_not_after_push() {
+        ScrollMemory::clear();
+        let mut nav = NavigationStack::new(Screen::new("/inbox", "Inbox")).default_transition(ScreenTransition::None);
+        let mut list = inbox_list();
+        list.restore_scroll();
+        list.scroll_to(1200.0);
+        let mut engine = LayoutEngine::new();
+        orders_grid(&mut engine).go_to_page(3);
+
+        nav.push(Screen::new("/inbox/42", "Message"));
+        let mut other = inbox_list(); // Same id, other route
+        other.restore_scroll();
+        assert_eq!(other.scroll_offset(), 0.0);
+        other.scroll_to(80.0);
+
+        nav.pop();
+        let mut rebuilt = inbox_list();
+        rebuilt.restore_scroll();
+        assert_eq!(rebuilt.scroll_offset(), 1200.0);
+        let mut opted_out = inbox_list().remember_scroll(false);
+        opted_out.restore_scroll();
+        assert_eq!(opted_out.scroll_offset(), 0.0);
+        assert_eq!(orders_grid(&mut engine).get_page(), 3);
+
+        // The popped screen's positions are gone
+        nav.push(Screen::new("/inbox/42", "Message"));
+        let mut fresh = inbox_list();
+        fresh.restore_scroll();
+        assert_eq!(fresh.scroll_offset(), 0.0);
+    }
+}
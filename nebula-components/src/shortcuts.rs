@@ -0,0 +1,425 @@
+// Shortcuts - Keyboard shortcuts for application actions
+// Maps "Ctrl+Shift+Z"-style key combos to CommandBus action ids and menu items
+
+use nebula_core::command::{CommandBus, REDO, UNDO};
+use nebula_core::focus::{FocusKey, ModifierKeys};
+use tracing::{info, warn};
+
+use super::code_editor::KeyModifiers;
+use super::menubar::{MenuBar, MenuItem};
+
+/// A key combo like Ctrl+S
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shortcut {
+    pub key: FocusKey,
+    pub modifiers: KeyModifiers,
+}
+
+impl Shortcut {
+    /// Parse "Ctrl+Shift+Z" (Cmd counts as Ctrl; letters ignore case)
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut modifiers = KeyModifiers::default();
+        let mut key = None;
+        for part in text.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" | "command" | "meta" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                name => {
+                    if key.is_some() {
+                        return Err(format!("Shortcut '{}' has more than one key", text));
+                    }
+                    key = Some(parse_key(name).ok_or_else(|| format!("Unknown key '{}' in shortcut '{}'", part, text))?);
+                }
+            }
+        }
+        let key = key.ok_or_else(|| format!("Shortcut '{}' has no key", text))?;
+        Ok(Self { key, modifiers })
+    }
+
+    /// Does a key event trigger this shortcut?
+    pub fn matches(&self, key: FocusKey, modifiers: KeyModifiers) -> bool {
+        modifiers == self.modifiers && normalize(key) == self.key
+    }
+
+    /// Display text, e.g. "Ctrl+Shift+Z"
+    pub fn label(&self) -> String {
+        let mut parts = Vec::new();
+        if self.modifiers.ctrl {
+            parts.push("Ctrl".to_string());
+        }
+        if self.modifiers.alt {
+            parts.push("Alt".to_string());
+        }
+        if self.modifiers.shift {
+            parts.push("Shift".to_string());
+        }
+        parts.push(match self.key {
+            FocusKey::Char(c) => c.to_ascii_uppercase().to_string(),
+            other => format!("{:?}", other),
+        });
+        parts.join("+")
+    }
+}
+
+fn parse_key(name: &str) -> Option<FocusKey> {
+    Some(match name {
+        "tab" => FocusKey::Tab,
+        "enter" | "return" => FocusKey::Enter,
+        "space" => FocusKey::Space,
+        "esc" | "escape" => FocusKey::Escape,
+        "backspace" => FocusKey::Backspace,
+        "del" | "delete" => FocusKey::Delete,
+        "left" => FocusKey::Left,
+        "right" => FocusKey::Right,
+        "up" => FocusKey::Up,
+        "down" => FocusKey::Down,
+        "home" => FocusKey::Home,
+        "end" => FocusKey::End,
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => FocusKey::Char(c),
+                _ => return None,
+            }
+        }
+    })
+}
+
+/// Letters compare case-insensitively (Shift+z arrives as 'Z')
+fn normalize(key: FocusKey) -> FocusKey {
+    match key {
+        FocusKey::Char(c) => FocusKey::Char(c.to_ascii_lowercase()),
+        other => other,
+    }
+}
+
+/// Which physical key "Ctrl" in a shortcut string means
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutPlatform {
+    /// Cmd (⌘) is primary; the Control key doesn't trigger shortcuts
+    Mac,
+    /// Ctrl is primary; the Windows / Super key doesn't trigger shortcuts
+    Standard,
+}
+
+impl ShortcutPlatform {
+    /// The platform we were built for
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") || cfg!(target_os = "ios") { ShortcutPlatform::Mac } else { ShortcutPlatform::Standard }
+    }
+
+    /// Held keys as shortcut modifiers (None when a non-primary Ctrl/Cmd is held)
+    pub fn modifiers(self, keys: ModifierKeys) -> Option<KeyModifiers> {
+        let (primary, other) = match self {
+            ShortcutPlatform::Mac => (keys.meta, keys.control),
+            ShortcutPlatform::Standard => (keys.control, keys.meta),
+        };
+        (!other).then_some(KeyModifiers { shift: keys.shift, ctrl: primary, alt: keys.alt })
+    }
+
+    /// How a shortcut is shown on this platform ("⇧⌘Z" vs "Ctrl+Shift+Z")
+    pub fn label(self, shortcut: &Shortcut) -> String {
+        match self {
+            ShortcutPlatform::Standard => shortcut.label(),
+            ShortcutPlatform::Mac => {
+                let mut label = String::new();
+                for (held, symbol) in [(shortcut.modifiers.alt, '⌥'), (shortcut.modifiers.shift, '⇧'), (shortcut.modifiers.ctrl, '⌘')] {
+                    if held {
+                        label.push(symbol);
+                    }
+                }
+                let key = Shortcut { key: shortcut.key, modifiers: KeyModifiers::default() }.label();
+                label + &key
+            }
+        }
+    }
+}
+
+/// How shortcut hints are written 🏷️
+///
+/// Every hint (menu trailing text, tooltips, palette rows) goes through
+/// here, so platform symbols and translated modifier names live in one place.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutHints {
+    pub platform: ShortcutPlatform,
+    /// Modifier names for non-Mac hints ("Strg" in German)
+    pub ctrl: String,
+    pub alt: String,
+    pub shift: String,
+}
+
+impl Default for ShortcutHints {
+    fn default() -> Self {
+        Self::new(ShortcutPlatform::current())
+    }
+}
+
+impl ShortcutHints {
+    /// English modifier names on a platform
+    pub fn new(platform: ShortcutPlatform) -> Self {
+        Self { platform, ctrl: "Ctrl".to_string(), alt: "Alt".to_string(), shift: "Shift".to_string() }
+    }
+
+    /// Translate the modifier names (Mac hints use symbols and ignore these)
+    pub fn modifier_names(mut self, ctrl: impl Into<String>, alt: impl Into<String>, shift: impl Into<String>) -> Self {
+        self.ctrl = ctrl.into();
+        self.alt = alt.into();
+        self.shift = shift.into();
+        self
+    }
+
+    /// Hint text for a shortcut ("Strg+Umschalt+Z", "⇧⌘Z")
+    pub fn label(&self, shortcut: &Shortcut) -> String {
+        if self.platform == ShortcutPlatform::Mac {
+            return self.platform.label(shortcut);
+        }
+        let mut parts: Vec<&str> = Vec::new();
+        for (held, name) in [(shortcut.modifiers.ctrl, &self.ctrl), (shortcut.modifiers.alt, &self.alt), (shortcut.modifiers.shift, &self.shift)] {
+            if held {
+                parts.push(name);
+            }
+        }
+        let key = Shortcut { key: shortcut.key, modifiers: KeyModifiers::default() }.label();
+        parts.push(&key);
+        parts.join("+")
+    }
+}
+
+/// ShortcutRegistry - Key combos → action ids ⌨️
+///
+/// # Example
+/// ```rust,ignore
+/// let mut shortcuts = ShortcutRegistry::standard();
+/// shortcuts.bind("Ctrl+D", "layer.duplicate")?;
+/// // in the key handler, before focus routing:
+/// if shortcuts.handle(key, modifiers, &bus) { return; }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ShortcutRegistry {
+    bindings: Vec<(Shortcut, String)>,
+    /// How `hint_for` writes shortcuts
+    pub hints: ShortcutHints,
+}
+
+impl ShortcutRegistry {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with Undo (Ctrl+Z) and Redo (Ctrl+Y, Ctrl+Shift+Z)
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        for (shortcut, action) in [("Ctrl+Z", UNDO), ("Ctrl+Y", REDO), ("Ctrl+Shift+Z", REDO)] {
+            let _ = registry.bind(shortcut, action);
+        }
+        registry
+    }
+
+    /// Standard shortcuts plus every shortcut shown in a menu bar
+    pub fn from_menus(menubar: &MenuBar) -> Self {
+        fn collect(registry: &mut ShortcutRegistry, items: &[MenuItem]) {
+            for item in items {
+                if let Some(ref shortcut) = item.shortcut {
+                    if let Err(e) = registry.bind(shortcut, item.action.clone()) {
+                        warn!("⌨️ Skipping menu shortcut: {}", e);
+                    }
+                }
+                if let Some(ref submenu) = item.submenu {
+                    collect(registry, submenu);
+                }
+            }
+        }
+        let mut registry = Self::standard();
+        for menu in &menubar.menus {
+            collect(&mut registry, &menu.items);
+        }
+        registry
+    }
+
+    /// Set how hints are written (platform, translated modifier names)
+    pub fn hints(mut self, hints: ShortcutHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Bind a key combo to an action (replacing what the combo did before)
+    pub fn bind(&mut self, shortcut: &str, action: impl Into<String>) -> Result<(), String> {
+        let shortcut = Shortcut::parse(shortcut)?;
+        self.bindings.retain(|(existing, _)| *existing != shortcut);
+        self.bindings.push((shortcut, action.into()));
+        Ok(())
+    }
+
+    /// Remove every combo bound to an action
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.retain(|(_, bound)| bound != action);
+    }
+
+    /// Action a key event triggers
+    pub fn action_for(&self, key: FocusKey, modifiers: KeyModifiers) -> Option<&str> {
+        self.bindings.iter().find(|(shortcut, _)| shortcut.matches(key, modifiers)).map(|(_, action)| action.as_str())
+    }
+
+    /// First combo bound to an action (for menu and tooltip hints)
+    pub fn shortcut_for(&self, action: &str) -> Option<Shortcut> {
+        self.bindings.iter().find(|(_, bound)| bound == action).map(|(shortcut, _)| *shortcut)
+    }
+
+    /// Hint text for an action's shortcut, e.g. "Ctrl+S" (None when unbound)
+    pub fn hint_for(&self, action: &str) -> Option<String> {
+        self.shortcut_for(action).map(|shortcut| self.hints.label(&shortcut))
+    }
+
+    /// Run the action bound to a key event; true if the bus handled it
+    pub fn handle(&self, key: FocusKey, modifiers: KeyModifiers, bus: &CommandBus) -> bool {
+        self.action_for(key, modifiers).is_some_and(|action| bus.dispatch(action))
+    }
+}
+
+/// ShortcutMap - Fires menu items from the window's key stream ⌨️
+///
+/// Built from the shortcut strings MenuItems declare ("Ctrl+N"). Feed it
+/// the platform's modifier and key-down events; a matching key runs the
+/// item's action through `MenuBar::execute_action` (so a CommandBus sees
+/// it too). Disabled items don't fire.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut shortcuts = ShortcutMap::from_menus(&menubar);
+///
+/// fn on_modifiers_changed(&mut self, keys: ModifierKeys) {
+///     self.shortcuts.set_modifiers(keys);
+/// }
+/// fn on_key_down(&mut self, key: Key) {
+///     if let Some(key) = key.focus_key() {
+///         if self.shortcuts.key_down(key, &mut self.menubar) { return; }
+///     }
+///     // …focus routing
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShortcutMap {
+    pub registry: ShortcutRegistry,
+    pub platform: ShortcutPlatform,
+    modifiers: ModifierKeys,
+}
+
+impl ShortcutMap {
+    /// Standard shortcuts plus every shortcut declared in the menus
+    pub fn from_menus(menubar: &MenuBar) -> Self {
+        Self::with_registry(ShortcutRegistry::from_menus(menubar))
+    }
+
+    /// Wrap an existing registry
+    pub fn with_registry(registry: ShortcutRegistry) -> Self {
+        Self { registry, platform: ShortcutPlatform::current(), modifiers: ModifierKeys::default() }
+    }
+
+    /// Override the platform (Cmd vs Ctrl)
+    pub fn platform(mut self, platform: ShortcutPlatform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Held modifiers changed
+    pub fn set_modifiers(&mut self, modifiers: ModifierKeys) {
+        self.modifiers = modifiers;
+    }
+
+    /// Action the key would trigger with the modifiers held right now
+    pub fn action_for_key(&self, key: FocusKey) -> Option<&str> {
+        let modifiers = self.platform.modifiers(self.modifiers)?;
+        self.registry.action_for(key, modifiers)
+    }
+
+    /// A key went down; true if it fired a menu action
+    pub fn key_down(&mut self, key: FocusKey, menubar: &mut MenuBar) -> bool {
+        let Some(action) = self.action_for_key(key).map(str::to_string) else {
+            return false;
+        };
+        menubar.sync_commands();
+        if menubar.find_item(&action).is_some_and(|item| item.disabled) {
+            return false;
+        }
+        info!("⌨️ Shortcut → {}", action);
+        menubar.execute_action(&action);
+        true
+    }
+
+    /// Platform label of an action's shortcut ("⌘N" on macOS)
+    pub fn label_for(&self, action: &str) -> Option<String> {
+        self.registry.shortcut_for(action).map(|shortcut| self.platform.label(&shortcut))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nebula_core::command::FnCommand;
+    use nebula_core::signal::Signal;
+    use crate::menubar::Menu;
+
+    #[test]
+    fn parses_and_matches_shortcuts() {
+        let redo = Shortcut::parse("Cmd + Shift + z").unwrap();
+        assert_eq!(redo.label(), "Ctrl+Shift+Z");
+        assert!(redo.matches(FocusKey::Char('Z'), KeyModifiers { ctrl: true, shift: true, alt: false }));
+        assert!(!redo.matches(FocusKey::Char('z'), KeyModifiers::ctrl()));
+        assert_eq!(Shortcut::parse("Alt+Delete").unwrap().key, FocusKey::Delete);
+        assert!(Shortcut::parse("Ctrl+Shift").is_err());
+        assert!(Shortcut::parse("Ctrl+Banana").is_err());
+    }
+
+    #[test]
+    fn menu_shortcuts_dispatch_through_the_bus() {
+        let count = Signal::new(0);
+        let bus = CommandBus::new();
+        let target = count.clone();
+        bus.register("count.add", "Add", move || {
+            let (apply, revert) = (target.clone(), target.clone());
+            Box::new(FnCommand::new("Add", move || apply.update(|c| c + 1), move || revert.update(|c| c - 1)))
+        });
+        let menubar = MenuBar::new().add_menu(Menu::new("Edit").add_item_with_shortcut("Add", "count.add", "Ctrl+A"));
+        let shortcuts = ShortcutRegistry::from_menus(&menubar);
+
+        assert!(shortcuts.handle(FocusKey::Char('a'), KeyModifiers::ctrl(), &bus));
+        assert_eq!(count.get(), 1);
+        assert!(shortcuts.handle(FocusKey::Char('z'), KeyModifiers::ctrl(), &bus));
+        assert_eq!(count.get(), 0);
+        assert!(!shortcuts.handle(FocusKey::Char('q'), KeyModifiers::ctrl(), &bus));
+        assert_eq!(shortcuts.shortcut_for(REDO).map(|s| s.label()), Some("Ctrl+Y".to_string()));
+    }
+
+    #[test]
+    fn shortcut_map_fires_menu_items_with_platform_modifiers() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let log = fired.clone();
+        let mut menubar = MenuBar::new()
+            .add_menu(
+                Menu::new("File")
+                    .add_item_with_shortcut("New", "file.new", "Ctrl+N")
+                    .add_menu_item(MenuItem::disabled("Save", "file.save").with_shortcut("Ctrl+S")),
+            )
+            .on_action(move |action| log.borrow_mut().push(action.to_string()));
+        let mut mac = ShortcutMap::from_menus(&menubar).platform(ShortcutPlatform::Mac);
+        let mut windows = ShortcutMap::from_menus(&menubar).platform(ShortcutPlatform::Standard);
+
+        mac.set_modifiers(ModifierKeys { control: true, ..Default::default() });
+        assert!(!mac.key_down(FocusKey::Char('n'), &mut menubar));
+        mac.set_modifiers(ModifierKeys { meta: true, ..Default::default() });
+        assert!(mac.key_down(FocusKey::Char('n'), &mut menubar));
+        assert!(!mac.key_down(FocusKey::Char('s'), &mut menubar)); // Disabled
+        windows.set_modifiers(ModifierKeys { control: true, ..Default::default() });
+        assert!(windows.key_down(FocusKey::Char('n'), &mut menubar));
+
+        assert_eq!(*fired.borrow(), vec!["file.new", "file.new"]);
+        assert_eq!(mac.label_for(REDO).as_deref(), Some("⌘Y"));
+        assert_eq!(mac.platform.label(&Shortcut::parse("Ctrl+Shift+Z").unwrap()), "⇧⌘Z");
+        assert_eq!(windows.label_for("file.new").as_deref(), Some("Ctrl+N"));
+    }
+}
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Skeleton variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -31,14 +32,15 @@ pub struct Skeleton {
     pub visible: Signal<bool>,
     pub animate: bool,
     pub animation_duration: f32,
-    pub base_color: (u8, u8, u8, u8),
-    pub highlight_color: (u8, u8, u8, u8),
+    pub base_color: ThemeColor,
+    pub highlight_color: ThemeColor,
     pub border_radius: f32,
 }
 
 impl Skeleton {
-    /// Create a new Skeleton component
+    /// Create a new Skeleton component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             variant: SkeletonVariant::Rectangular,
@@ -47,12 +49,25 @@ impl Skeleton {
             visible: Signal::new(true),
             animate: true,
             animation_duration: 1.5,
-            base_color: (229, 231, 235, 255),      // Gray-200
-            highlight_color: (243, 244, 246, 255), // Gray-100
+            base_color: colors.border,
+            highlight_color: colors.hover,
             border_radius: 4.0,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.base_color = colors.border;
+        self.highlight_color = colors.hover;
+    }
+
     /// Create a text skeleton
     pub fn text() -> Self {
         Self::new()
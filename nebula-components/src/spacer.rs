@@ -2,6 +2,8 @@ use nebula_core::{LayoutEngine, NodeId, Layout};
 use taffy::prelude::*;
 use tracing::info;
 
+use super::widget::Widget;
+
 /// Spacer - Creates flexible or fixed space in layouts 📏
 /// 
 /// Essential for beautiful, well-spaced UIs!
@@ -121,6 +123,12 @@ impl Spacer {
     }
 }
 
+impl Widget for Spacer {
+    fn build_node(&mut self, engine: &mut LayoutEngine, _children: &[NodeId]) -> Result<NodeId, String> {
+        self.build(engine)
+    }
+}
+
 impl Default for Spacer {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,340 @@
+// SpellCheck - Pluggable spell-checking for text inputs
+// Finds misspelled words, builds squiggly underlines, and offers suggestions
+
+use crate::context_menu::{ContextMenu, ContextMenuItem};
+use std::collections::HashSet;
+
+/// Action prefix for "replace with suggestion" menu items
+pub const REPLACE_ACTION_PREFIX: &str = "spell:replace:";
+/// Action for "add to dictionary"
+pub const ADD_TO_DICTIONARY_ACTION: &str = "spell:add";
+/// Action for "ignore word"
+pub const IGNORE_ACTION: &str = "spell:ignore";
+
+/// Spell-checking backend 📖
+///
+/// Implement this for hunspell, platform APIs (NSSpellChecker, Windows
+/// ISpellChecker), or anything else. `WordListDictionary` is the built-in
+/// backend.
+pub trait SpellChecker {
+    /// Is this word spelled correctly?
+    fn check(&self, word: &str) -> bool;
+
+    /// Suggested replacements for a misspelled word, best first
+    fn suggest(&self, word: &str, limit: usize) -> Vec<String>;
+
+    /// Add a word to the user dictionary
+    fn add_word(&mut self, word: &str);
+
+    /// Ignore a word for this session (defaults to adding it)
+    fn ignore_word(&mut self, word: &str) {
+        self.add_word(word);
+    }
+}
+
+/// A misspelled word inside a piece of text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// Byte offset of the first character
+    pub start: usize,
+    /// Byte offset one past the last character
+    pub end: usize,
+    /// The misspelled word
+    pub word: String,
+}
+
+impl Misspelling {
+    /// Does this misspelling cover the given byte offset?
+    pub fn contains(&self, index: usize) -> bool {
+        index >= self.start && index <= self.end
+    }
+}
+
+/// Simple in-memory dictionary backed by a word list
+///
+/// Matching is case-insensitive. Suggestions are ranked by edit distance.
+#[derive(Debug, Clone, Default)]
+pub struct WordListDictionary {
+    words: HashSet<String>,
+    ignored: HashSet<String>,
+}
+
+impl WordListDictionary {
+    /// Create an empty dictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a dictionary from a list of words
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = Self::new();
+        for word in words {
+            dictionary.add_word(word.as_ref());
+        }
+        dictionary
+    }
+
+    /// Load a dictionary from text with one word per line (hunspell .dic style,
+    /// affix flags after '/' are dropped)
+    pub fn from_word_list(text: &str) -> Self {
+        Self::from_words(
+            text.lines()
+                .map(|line| line.split('/').next().unwrap_or("").trim())
+                .filter(|word| !word.is_empty() && !word.chars().all(|c| c.is_ascii_digit())),
+        )
+    }
+
+    /// Number of words in the dictionary
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Is the dictionary empty?
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl SpellChecker for WordListDictionary {
+    fn check(&self, word: &str) -> bool {
+        let lower = word.to_lowercase();
+        self.words.contains(&lower) || self.ignored.contains(&lower)
+    }
+
+    fn suggest(&self, word: &str, limit: usize) -> Vec<String> {
+        let lower = word.to_lowercase();
+        let max_distance = if lower.chars().count() <= 4 { 1 } else { 2 };
+
+        let mut candidates: Vec<(usize, &String)> = self
+            .words
+            .iter()
+            .map(|candidate| (edit_distance(&lower, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+
+        let capitalized = word.chars().next().map(|c| c.is_uppercase()).unwrap_or(false);
+        candidates
+            .into_iter()
+            .take(limit)
+            .map(|(_, candidate)| {
+                if capitalized {
+                    capitalize(candidate)
+                } else {
+                    candidate.clone()
+                }
+            })
+            .collect()
+    }
+
+    fn add_word(&mut self, word: &str) {
+        self.words.insert(word.to_lowercase());
+    }
+
+    fn ignore_word(&mut self, word: &str) {
+        self.ignored.insert(word.to_lowercase());
+    }
+}
+
+/// Find every misspelled word in the text
+///
+/// Words are runs of alphabetic characters and apostrophes. Words containing
+/// digits, single letters, and ALL-CAPS acronyms are skipped.
+pub fn find_misspellings(text: &str, checker: &dyn SpellChecker) -> Vec<Misspelling> {
+    let mut misspellings = Vec::new();
+    let mut word_start: Option<usize> = None;
+
+    let flush = |start: usize, end: usize, misspellings: &mut Vec<Misspelling>| {
+        let word = text[start..end].trim_matches('\'');
+        if word.is_empty() {
+            return;
+        }
+        let start = start + text[start..end].find(word).unwrap_or(0);
+        let end = start + word.len();
+        let skip = word.chars().count() < 2
+            || word.chars().any(|c| c.is_ascii_digit())
+            || word.chars().all(|c| !c.is_alphabetic() || c.is_uppercase());
+        if !skip && !checker.check(word) {
+            misspellings.push(Misspelling { start, end, word: word.to_string() });
+        }
+    };
+
+    for (index, c) in text.char_indices() {
+        let is_word_char = c.is_alphanumeric() || c == '\'';
+        match (is_word_char, word_start) {
+            (true, None) => word_start = Some(index),
+            (false, Some(start)) => {
+                flush(start, index, &mut misspellings);
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = word_start {
+        flush(start, text.len(), &mut misspellings);
+    }
+
+    misspellings
+}
+
+/// Points for a squiggly underline between two x positions
+///
+/// The text layer draws these as a polyline just below the baseline.
+pub fn squiggle_points(x_start: f32, x_end: f32, baseline: f32, amplitude: f32, wavelength: f32) -> Vec<(f32, f32)> {
+    let step = (wavelength / 2.0).max(0.5);
+    let mut points = Vec::new();
+    let mut x = x_start;
+    let mut up = true;
+
+    while x < x_end {
+        points.push((x, if up { baseline } else { baseline + amplitude }));
+        x += step;
+        up = !up;
+    }
+    points.push((x_end, if up { baseline } else { baseline + amplitude }));
+
+    points
+}
+
+/// Context menu items for a misspelled word
+///
+/// Suggestions come first, then "Add to Dictionary" and "Ignore".
+pub fn suggestion_menu_items(misspelling: &Misspelling, checker: &dyn SpellChecker, limit: usize) -> Vec<ContextMenuItem> {
+    let suggestions = checker.suggest(&misspelling.word, limit);
+
+    let mut items: Vec<ContextMenuItem> = if suggestions.is_empty() {
+        vec![ContextMenuItem::disabled("No Suggestions", "spell:none")]
+    } else {
+        suggestions
+            .into_iter()
+            .map(|suggestion| {
+                let action = format!("{}{}", REPLACE_ACTION_PREFIX, suggestion);
+                ContextMenuItem::new(suggestion, action)
+            })
+            .collect()
+    };
+
+    items.push(ContextMenuItem::separator());
+    items.push(ContextMenuItem::new("Add to Dictionary", ADD_TO_DICTIONARY_ACTION));
+    items.push(ContextMenuItem::new("Ignore", IGNORE_ACTION));
+    items
+}
+
+/// Build a context menu with spelling suggestions
+pub fn suggestion_menu(misspelling: &Misspelling, checker: &dyn SpellChecker, limit: usize) -> ContextMenu {
+    ContextMenu::new().items(suggestion_menu_items(misspelling, checker, limit))
+}
+
+/// Levenshtein distance between two strings (in characters)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary() -> WordListDictionary {
+        WordListDictionary::from_words(["hello", "world", "help", "the", "quick", "brown", "fox", "don't"])
+    }
+
+    #[test]
+    fn spellcheck_check_is_case_insensitive() {
+        let dict = dictionary();
+        assert!(dict.check("Hello"));
+        assert!(dict.check("WORLD"));
+        assert!(!dict.check("helo"));
+    }
+
+    #[test]
+    fn spellcheck_find_misspellings() {
+        let dict = dictionary();
+        let found = find_misspellings("Helo wrld, the fox!", &dict);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], Misspelling { start: 0, end: 4, word: "Helo".to_string() });
+        assert_eq!(found[1].word, "wrld");
+        assert_eq!(&"Helo wrld, the fox!"[found[1].start..found[1].end], "wrld");
+    }
+
+    #[test]
+    fn spellcheck_skips_acronyms_numbers_and_apostrophes() {
+        let dict = dictionary();
+        assert!(find_misspellings("NASA abc123 x 'hello' don't", &dict).is_empty());
+    }
+
+    #[test]
+    fn spellcheck_suggestions_ranked() {
+        let dict = dictionary();
+        let suggestions = dict.suggest("helo", 3);
+        assert_eq!(suggestions[0], "hello");
+        assert!(suggestions.contains(&"help".to_string()));
+        assert_eq!(dict.suggest("Wrld", 1), vec!["World".to_string()]);
+    }
+
+    #[test]
+    fn spellcheck_add_and_ignore() {
+        let mut dict = dictionary();
+        dict.add_word("Nebula");
+        dict.ignore_word("taffy");
+        assert!(dict.check("nebula"));
+        assert!(dict.check("Taffy"));
+        assert_eq!(dict.suggest("taffy", 5), Vec::<String>::new());
+    }
+
+    #[test]
+    fn spellcheck_word_list_parsing() {
+        let dict = WordListDictionary::from_word_list("3\nhello/MS\nworld\n\n");
+        assert_eq!(dict.len(), 2);
+        assert!(dict.check("hello"));
+    }
+
+    #[test]
+    fn spellcheck_squiggle_points() {
+        let points = squiggle_points(0.0, 8.0, 20.0, 2.0, 4.0);
+        assert_eq!(points.first(), Some(&(0.0, 20.0)));
+        assert_eq!(points[1], (2.0, 22.0));
+        assert_eq!(points.last().unwrap().0, 8.0);
+    }
+
+    #[test]
+    fn spellcheck_suggestion_menu() {
+        let dict = dictionary();
+        let misspelling = Misspelling { start: 0, end: 4, word: "helo".to_string() };
+        let menu = suggestion_menu(&misspelling, &dict, 2);
+        assert_eq!(menu.items[0].action, "spell:replace:hello");
+        assert!(menu.items.iter().any(|item| item.action == ADD_TO_DICTIONARY_ACTION));
+        assert!(menu.items.iter().any(|item| item.action == IGNORE_ACTION));
+
+        let none = Misspelling { start: 0, end: 3, word: "zzz".to_string() };
+        let items = suggestion_menu_items(&none, &dict, 2);
+        assert!(items[0].disabled);
+    }
+}
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Spinner size presets
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,7 +42,7 @@ pub struct Spinner {
     pub node_id: Option<NodeId>,
     pub is_spinning: Signal<bool>,
     pub size: SpinnerSize,
-    pub color: (u8, u8, u8, u8),
+    pub color: ThemeColor,
     pub thickness: f32,
     pub speed: f32, // Rotation speed multiplier
     pub label: Option<String>,
@@ -58,13 +59,14 @@ pub enum LabelPosition {
 }
 
 impl Spinner {
-    /// Create a new Spinner component
+    /// Create a new Spinner component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             is_spinning: Signal::new(true),
             size: SpinnerSize::Medium,
-            color: (59, 130, 246, 255), // Blue
+            color: colors.primary,
             thickness: 2.0,
             speed: 1.0,
             label: None,
@@ -72,6 +74,18 @@ impl Spinner {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.color = colors.primary;
+    }
+
     /// Set the size
     pub fn size(mut self, size: SpinnerSize) -> Self {
         self.size = size;
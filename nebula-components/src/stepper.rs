@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
 
 /// Step item
 #[derive(Debug, Clone, PartialEq)]
@@ -94,8 +95,9 @@ pub struct Stepper {
 }
 
 impl Stepper {
-    /// Create a new Stepper component
+    /// Create a new Stepper component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             steps: Vec::new(),
@@ -107,18 +109,36 @@ impl Stepper {
             connector_width: 2.0,
             show_numbers: true,
             clickable: false,
-            active_color: (59, 130, 246, 255), // Blue
-            completed_color: (34, 197, 94, 255), // Green
-            inactive_color: (200, 200, 200, 255), // Gray
-            error_color: (239, 68, 68, 255), // Red
-            connector_color: (220, 220, 220, 255),
-            text_color: (100, 100, 100, 255),
-            active_text_color: (0, 0, 0, 255),
+            active_color: colors.primary,
+            completed_color: colors.success,
+            inactive_color: colors.disabled,
+            error_color: colors.danger,
+            connector_color: colors.border,
+            text_color: colors.text_muted,
+            active_text_color: colors.text,
             on_step_click: None,
             on_complete: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.active_color = colors.primary;
+        self.completed_color = colors.success;
+        self.inactive_color = colors.disabled;
+        self.error_color = colors.danger;
+        self.connector_color = colors.border;
+        self.text_color = colors.text_muted;
+        self.active_text_color = colors.text;
+    }
+
     /// Set the current step
     pub fn current_step(mut self, step: usize) -> Self {
         self.current_step.set(step);
@@ -215,6 +235,30 @@ impl Stepper {
         self
     }
 
+    /// Follow a state signal (e.g. `StateMachine::signal()`) 🚦
+    ///
+    /// `step_id` names the step for each state (None leaves the stepper
+    /// where it is). Steps before the current one show as completed, so
+    /// going back in a branching flow un-completes what came after.
+    /// Add the steps first.
+    pub fn follow_state<S, F>(self, state: &Signal<S>, step_id: F) -> Self
+    where
+        S: Clone + 'static,
+        F: Fn(&S) -> Option<String> + 'static,
+    {
+        let ids: Vec<String> = self.steps.iter().map(|step| step.id.clone()).collect();
+        let (current, completed) = (self.current_step.clone(), self.completed_steps.clone());
+        let sync = move |state: &S| {
+            if let Some(index) = step_id(state).and_then(|id| ids.iter().position(|step| *step == id)) {
+                current.set(index);
+                completed.set((0..index).collect());
+            }
+        };
+        sync(&state.get());
+        state.subscribe(sync);
+        self
+    }
+
     /// Go to a specific step
     pub fn go_to_step(&mut self, index: usize) {
         if index < self.steps.len() {
@@ -393,6 +437,40 @@ impl Default for Stepper {
 mod tests {
     use super::*;
 
+    #[test]
+    fn stepper_follows_theme() {
+        assert_eq!(Stepper::new().active_color, Theme::light().colors.primary);
+
+        ThemeProvider::global().set(Theme::nebula_dark());
+        let stepper = Stepper::new();
+        assert_eq!(stepper.active_color, Theme::nebula_dark().colors.primary);
+
+        let stepper = stepper.theme(&Theme::dark());
+        assert_eq!(stepper.connector_color, Theme::dark().colors.border);
+    }
+
+    #[test]
+    fn stepper_follows_state_machine() {
+        use nebula_core::StateMachine;
+
+        let machine = StateMachine::new("account")
+            .transition("account", "next", "company")
+            .transition("company", "next", "confirm");
+        let stepper = Stepper::new()
+            .add_step("account", "Account")
+            .add_step("company", "Company")
+            .add_step("confirm", "Confirm")
+            .follow_state(&machine.signal(), |state| Some(state.to_string()));
+
+        machine.send("next");
+        machine.send("next");
+        assert_eq!(stepper.get_current_step_id().as_deref(), Some("confirm"));
+        assert!(stepper.is_completed(1));
+        machine.back();
+        assert_eq!(stepper.get_current_step(), 1);
+        assert!(!stepper.is_completed(1));
+    }
+
     #[test]
     fn stepper_starts_empty() {
         let stepper = Stepper::new();
@@ -0,0 +1,438 @@
+// Style - CSS-like visual properties for any widget, with cascading and pseudo-states
+// WidgetTree resolves each widget's Style against its parent's before painting
+
+use nebula_core::theme::ThemeColor as Color;
+use nebula_gfx::draw_list::{DrawList, Rect};
+use std::time::Duration;
+
+/// Per-side lengths (padding, margin)
+///
+/// `left` and `right` are the leading and trailing sides: under
+/// `LayoutDirection::RTL` they are mirrored when laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Edges {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Edges {
+    /// Same length on every side
+    pub fn all(value: f32) -> Self {
+        Self { top: value, right: value, bottom: value, left: value }
+    }
+
+    /// Vertical and horizontal lengths
+    pub fn symmetric(vertical: f32, horizontal: f32) -> Self {
+        Self { top: vertical, right: horizontal, bottom: vertical, left: horizontal }
+    }
+
+    /// Is every side zero?
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Border line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Border {
+    pub width: f32,
+    pub color: Color,
+}
+
+/// Drop shadow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub blur: f32,
+    pub color: Color,
+}
+
+/// Interaction state used to pick pseudo-state styles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WidgetState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+    pub disabled: bool,
+}
+
+/// Visual properties for a widget 🎨
+///
+/// Every property is optional: unset ones fall back like CSS. Text
+/// properties (font size, family, color) inherit from the parent; box
+/// properties (padding, margin, background, border, shadow) don't.
+/// Pseudo-state styles layer on top in the order hover → focused →
+/// pressed → disabled.
+///
+/// With a `transition`, changes (a hover background, a scale-up, a fade)
+/// animate over that duration instead of snapping; see
+/// `WidgetTree::animate`.
+///
+/// # Example
+/// ```rust,ignore
+/// let card = Style::new()
+///     .padding(16.0)
+///     .background((255, 255, 255, 255))
+///     .border(1.0, (220, 220, 220, 255))
+///     .radius(8.0)
+///     .shadow(0.0, 2.0, 8.0, (0, 0, 0, 40))
+///     .hover(Style::new().border(1.0, (59, 130, 246, 255)).scale(1.02))
+///     .transition(Duration::from_millis(200));
+/// tree.set_style(card_id, card);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Style {
+    pub padding: Option<Edges>,
+    pub margin: Option<Edges>,
+    pub background: Option<Color>,
+    pub border: Option<Border>,
+    pub radius: Option<f32>,
+    pub shadow: Option<Shadow>,
+    pub font_size: Option<f32>,
+    pub font_family: Option<String>,
+    pub text_color: Option<Color>,
+    /// Group opacity, 0.0 to 1.0 (applies to children too)
+    pub opacity: Option<f32>,
+    /// Visual size around the center (1.0 = as laid out); layout is unaffected
+    pub scale: Option<f32>,
+    /// Animate property changes over this long
+    pub transition: Option<Duration>,
+    pub hover: Option<Box<Style>>,
+    pub pressed: Option<Box<Style>>,
+    pub focused: Option<Box<Style>>,
+    pub disabled: Option<Box<Style>>,
+}
+
+impl Style {
+    /// Empty style (everything inherited or default)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Same padding on every side
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = Some(Edges::all(padding));
+        self
+    }
+
+    /// Padding per side
+    pub fn padding_edges(mut self, padding: Edges) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Same margin on every side
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = Some(Edges::all(margin));
+        self
+    }
+
+    /// Margin per side
+    pub fn margin_edges(mut self, margin: Edges) -> Self {
+        self.margin = Some(margin);
+        self
+    }
+
+    /// Background fill
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Border line
+    pub fn border(mut self, width: f32, color: Color) -> Self {
+        self.border = Some(Border { width, color });
+        self
+    }
+
+    /// Corner radius
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Drop shadow
+    pub fn shadow(mut self, offset_x: f32, offset_y: f32, blur: f32, color: Color) -> Self {
+        self.shadow = Some(Shadow { offset_x, offset_y, blur, color });
+        self
+    }
+
+    /// Font size (inherited)
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    /// Font family (inherited)
+    pub fn font_family(mut self, family: impl Into<String>) -> Self {
+        self.font_family = Some(family.into());
+        self
+    }
+
+    /// Text color (inherited)
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    /// Group opacity (0.0 to 1.0)
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Visual scale around the center
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Animate changes over `duration`
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
+    /// Overrides while hovered
+    pub fn hover(mut self, style: Style) -> Self {
+        self.hover = Some(Box::new(style));
+        self
+    }
+
+    /// Overrides while pressed
+    pub fn pressed(mut self, style: Style) -> Self {
+        self.pressed = Some(Box::new(style));
+        self
+    }
+
+    /// Overrides while focused
+    pub fn focused(mut self, style: Style) -> Self {
+        self.focused = Some(Box::new(style));
+        self
+    }
+
+    /// Overrides while disabled
+    pub fn disabled(mut self, style: Style) -> Self {
+        self.disabled = Some(Box::new(style));
+        self
+    }
+
+    /// Layer `other` on top: its set properties win
+    pub fn merge(&self, other: &Style) -> Style {
+        fn pick<T: Clone>(base: &Option<T>, over: &Option<T>) -> Option<T> {
+            over.clone().or_else(|| base.clone())
+        }
+        Style {
+            padding: pick(&self.padding, &other.padding),
+            margin: pick(&self.margin, &other.margin),
+            background: pick(&self.background, &other.background),
+            border: pick(&self.border, &other.border),
+            radius: pick(&self.radius, &other.radius),
+            shadow: pick(&self.shadow, &other.shadow),
+            font_size: pick(&self.font_size, &other.font_size),
+            font_family: pick(&self.font_family, &other.font_family),
+            text_color: pick(&self.text_color, &other.text_color),
+            opacity: pick(&self.opacity, &other.opacity),
+            scale: pick(&self.scale, &other.scale),
+            transition: pick(&self.transition, &other.transition),
+            hover: pick(&self.hover, &other.hover),
+            pressed: pick(&self.pressed, &other.pressed),
+            focused: pick(&self.focused, &other.focused),
+            disabled: pick(&self.disabled, &other.disabled),
+        }
+    }
+
+    /// This style with the pseudo-states that apply to `state` folded in
+    pub fn for_state(&self, state: WidgetState) -> Style {
+        let layers = [
+            (state.hovered, &self.hover),
+            (state.focused, &self.focused),
+            (state.pressed, &self.pressed),
+            (state.disabled, &self.disabled),
+        ];
+        layers
+            .into_iter()
+            .filter_map(|(active, layer)| layer.as_deref().filter(|_| active))
+            .fold(self.clone(), |style, layer| style.merge(layer))
+    }
+
+    /// Final values for a widget in `state` under a parent
+    pub fn resolve(&self, parent: &ComputedStyle, state: WidgetState) -> ComputedStyle {
+        let style = self.for_state(state);
+        ComputedStyle {
+            padding: style.padding.unwrap_or_default(),
+            margin: style.margin.unwrap_or_default(),
+            background: style.background,
+            border: style.border,
+            radius: style.radius.unwrap_or(0.0),
+            shadow: style.shadow,
+            font_size: style.font_size.or(parent.font_size),
+            font_family: style.font_family.or_else(|| parent.font_family.clone()),
+            text_color: style.text_color.or(parent.text_color),
+            opacity: style.opacity.unwrap_or(1.0),
+            scale: style.scale.unwrap_or(1.0),
+            transition: style.transition,
+        }
+    }
+}
+
+/// Resolved style for one widget
+/// Text properties stay optional: None means "widget's own default"
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedStyle {
+    pub padding: Edges,
+    pub margin: Edges,
+    pub background: Option<Color>,
+    pub border: Option<Border>,
+    pub radius: f32,
+    pub shadow: Option<Shadow>,
+    pub font_size: Option<f32>,
+    pub font_family: Option<String>,
+    pub text_color: Option<Color>,
+    pub opacity: f32,
+    pub scale: f32,
+    pub transition: Option<Duration>,
+}
+
+impl Default for ComputedStyle {
+    fn default() -> Self {
+        Self {
+            padding: Edges::default(),
+            margin: Edges::default(),
+            background: None,
+            border: None,
+            radius: 0.0,
+            shadow: None,
+            font_size: None,
+            font_family: None,
+            text_color: None,
+            opacity: 1.0,
+            scale: 1.0,
+            transition: None,
+        }
+    }
+}
+
+impl ComputedStyle {
+    /// In-between style, `t` from 0.0 (self) to 1.0 (`to`)
+    ///
+    /// Colors, lengths, opacity and scale blend; a color that appears or
+    /// disappears fades from / to transparent. Padding, margin and fonts
+    /// take the target value right away (they change layout).
+    pub fn lerp(&self, to: &ComputedStyle, t: f32) -> ComputedStyle {
+        let t = t.clamp(0.0, 1.0);
+        let number = |a: f32, b: f32| a + (b - a) * t;
+        let color = |a: Color, b: Color| {
+            let channel = |a: u8, b: u8| number(a as f32, b as f32).round() as u8;
+            (channel(a.0, b.0), channel(a.1, b.1), channel(a.2, b.2), channel(a.3, b.3))
+        };
+        let optional_color = |a: Option<Color>, b: Option<Color>| match (a, b) {
+            (Some(a), Some(b)) => Some(color(a, b)),
+            (Some(a), None) => Some(color(a, (a.0, a.1, a.2, 0))).filter(|_| t < 1.0),
+            (None, Some(b)) => Some(color((b.0, b.1, b.2, 0), b)),
+            (None, None) => None,
+        };
+        let border = match (self.border, to.border) {
+            (None, None) => None,
+            (a, b) => {
+                // A border that appears or disappears grows from / shrinks to zero width
+                let (a, b) = (a.or(b.map(|b| Border { width: 0.0, ..b })), b.or(a.map(|a| Border { width: 0.0, ..a })));
+                a.zip(b).map(|(a, b)| Border { width: number(a.width, b.width), color: color(a.color, b.color) })
+            }
+        };
+        let shadow = match (self.shadow, to.shadow) {
+            (Some(a), Some(b)) => Some(Shadow {
+                offset_x: number(a.offset_x, b.offset_x),
+                offset_y: number(a.offset_y, b.offset_y),
+                blur: number(a.blur, b.blur),
+                color: color(a.color, b.color),
+            }),
+            (Some(a), None) => Some(Shadow { color: color(a.color, (a.color.0, a.color.1, a.color.2, 0)), ..a }).filter(|_| t < 1.0),
+            (None, Some(b)) => Some(Shadow { color: color((b.color.0, b.color.1, b.color.2, 0), b.color), ..b }),
+            (None, None) => None,
+        };
+        ComputedStyle {
+            background: optional_color(self.background, to.background),
+            border,
+            radius: number(self.radius, to.radius),
+            shadow,
+            text_color: optional_color(self.text_color, to.text_color),
+            opacity: number(self.opacity, to.opacity),
+            scale: number(self.scale, to.scale),
+            ..to.clone()
+        }
+    }
+
+    /// Draw shadow, background and border behind a widget
+    pub fn paint_box(&self, bounds: Rect, list: &mut DrawList) {
+        let (x, y, width, height) = bounds;
+        if let Some(shadow) = self.shadow {
+            // Soft edge: a few expanding layers at falling opacity
+            let layers = if shadow.blur > 0.0 { 3 } else { 1 };
+            for layer in 0..layers {
+                let spread = shadow.blur * (layer as f32 + 1.0) / (layers as f32 * 2.0);
+                let alpha = (shadow.color.3 as f32 / layers as f32).round() as u8;
+                let rect = (x + shadow.offset_x - spread, y + shadow.offset_y - spread, width + spread * 2.0, height + spread * 2.0);
+                list.rounded_rect(rect, self.radius + spread, (shadow.color.0, shadow.color.1, shadow.color.2, alpha));
+            }
+        }
+        match (self.border, self.background) {
+            (Some(border), background) if border.width > 0.0 => {
+                if let Some(background) = background {
+                    list.rounded_rect(bounds, self.radius, border.color);
+                    let inner = (x + border.width, y + border.width, width - border.width * 2.0, height - border.width * 2.0);
+                    list.rounded_rect(inner, (self.radius - border.width).max(0.0), background);
+                } else {
+                    list.rect((x, y, width, border.width), border.color);
+                    list.rect((x, y + height - border.width, width, border.width), border.color);
+                    list.rect((x, y, border.width, height), border.color);
+                    list.rect((x + width - border.width, y, border.width, height), border.color);
+                }
+            }
+            (_, Some(background)) => {
+                list.rounded_rect(bounds, self.radius, background);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_properties_cascade_box_properties_dont() {
+        let parent = Style::new().text_color((10, 10, 10, 255)).font_size(18.0).background((1, 2, 3, 255)).padding(8.0);
+        let parent = parent.resolve(&ComputedStyle::default(), WidgetState::default());
+
+        let child = Style::new().font_size(12.0).resolve(&parent, WidgetState::default());
+        assert_eq!(child.text_color, Some((10, 10, 10, 255)));
+        assert_eq!(child.font_size, Some(12.0));
+        assert_eq!(child.background, None);
+        assert!(child.padding.is_zero());
+    }
+
+    #[test]
+    fn pseudo_states_layer_in_order() {
+        let style = Style::new()
+            .background((255, 255, 255, 255))
+            .hover(Style::new().background((240, 240, 240, 255)).text_color((0, 0, 255, 255)))
+            .pressed(Style::new().background((200, 200, 200, 255)))
+            .disabled(Style::new().text_color((150, 150, 150, 255)));
+        let root = ComputedStyle::default();
+
+        let hovered = style.resolve(&root, WidgetState { hovered: true, ..Default::default() });
+        assert_eq!(hovered.background, Some((240, 240, 240, 255)));
+
+        let pressed = style.resolve(&root, WidgetState { hovered: true, pressed: true, ..Default::default() });
+        assert_eq!(pressed.background, Some((200, 200, 200, 255)));
+        assert_eq!(pressed.text_color, Some((0, 0, 255, 255)));
+
+        let disabled = style.resolve(&root, WidgetState { hovered: true, disabled: true, ..Default::default() });
+        assert_eq!(disabled.text_color, Some((150, 150, 150, 255)));
+    }
+}
@@ -0,0 +1,493 @@
+// Stylesheet - Styles loaded from a CSS-like file, reloaded when the file changes
+// Lets designers tweak colors and spacing while the app runs
+
+use crate::style::{Edges, Style};
+use nebula_core::hot_reload::FileWatcher;
+use nebula_core::signal::Signal;
+use nebula_core::theme::ThemeColor as Color;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Stylesheet - Named styles from a CSS-subset file 📜
+///
+/// Supported syntax:
+/// - rules: `button { ... }`, `.card { ... }`, `#save, .primary { ... }`
+/// - pseudo-states: `:hover`, `:pressed` (or `:active`), `:focus`, `:disabled`
+/// - variables: `:root { --accent: #3b82f6; }` used as `var(--accent)`
+/// - properties: `background`, `color`, `padding`, `margin`, `border`,
+///   `border-radius`, `box-shadow`, `font-size`, `font-family`
+/// - colors: `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb()`, `rgba()`
+///   and a few names (`white`, `black`, `transparent`…)
+///
+/// # Example
+/// ```rust,ignore
+/// let mut sheet = Stylesheet::load("assets/app.css")?;
+/// sheet.watch()?;
+///
+/// // every frame
+/// if sheet.reload_if_changed()? {
+///     tree.set_style(save_id, sheet.style_for(".primary"));
+/// }
+/// ```
+pub struct Stylesheet {
+    rules: HashMap<String, Style>,
+    order: Vec<String>,
+    path: Option<PathBuf>,
+    watcher: Option<FileWatcher>,
+    /// Bumped after every successful (re)load
+    pub version: Signal<u64>,
+}
+
+impl Stylesheet {
+    /// Parse a stylesheet from source text
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let (rules, order) = parse_rules(source)?;
+        Ok(Self { rules, order, path: None, watcher: None, version: Signal::new(0) })
+    }
+
+    /// Load a stylesheet file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        let mut sheet = Self::parse(&source).map_err(|e| format!("{}: {}", path.display(), e))?;
+        sheet.path = Some(path.to_path_buf());
+        info!("📜 Loaded stylesheet {:?} ({} rules)", path, sheet.order.len());
+        Ok(sheet)
+    }
+
+    /// Start watching the file this sheet was loaded from
+    pub fn watch(&mut self) -> Result<(), String> {
+        let path = self.path.clone().ok_or("Stylesheet was not loaded from a file")?;
+        let mut watcher = FileWatcher::new();
+        watcher.watch(&path)?;
+        self.watcher = Some(watcher);
+        Ok(())
+    }
+
+    /// Re-read the file if it changed (call once per frame)
+    ///
+    /// Returns `Ok(true)` when new rules were applied. A file with a syntax
+    /// error keeps the previous rules and returns the error.
+    pub fn reload_if_changed(&mut self) -> Result<bool, String> {
+        let Some(watcher) = self.watcher.as_mut() else {
+            return Ok(false);
+        };
+        if watcher.poll().is_empty() {
+            return Ok(false);
+        }
+        let path = self.path.clone().ok_or("Stylesheet was not loaded from a file")?;
+        let source = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        match parse_rules(&source) {
+            Ok((rules, order)) => {
+                self.rules = rules;
+                self.order = order;
+                self.version.update(|version| version + 1);
+                info!("🔥 Reloaded stylesheet {:?}", path);
+                Ok(true)
+            }
+            Err(e) => {
+                warn!("⚠️ Keeping previous styles, {:?} has an error: {}", path, e);
+                Err(format!("{}: {}", path.display(), e))
+            }
+        }
+    }
+
+    /// Selectors in file order (without pseudo-states)
+    pub fn selectors(&self) -> &[String] {
+        &self.order
+    }
+
+    /// Does the sheet have a rule for `selector`?
+    pub fn contains(&self, selector: &str) -> bool {
+        self.rules.contains_key(selector)
+    }
+
+    /// Style for a selector (pseudo-state rules folded in)
+    /// Unknown selectors give an empty style.
+    pub fn style_for(&self, selector: &str) -> Style {
+        self.rules.get(selector).cloned().unwrap_or_default()
+    }
+}
+
+type Rules = (HashMap<String, Style>, Vec<String>);
+
+fn parse_rules(source: &str) -> Result<Rules, String> {
+    let source = strip_comments(source);
+    let mut variables: HashMap<String, String> = HashMap::new();
+    let mut rules: HashMap<String, Style> = HashMap::new();
+    let mut order = Vec::new();
+    let mut rest = source.as_str();
+    let mut line = 1;
+
+    loop {
+        let Some(open) = rest.find('{') else {
+            if !rest.trim().is_empty() {
+                return Err(format!("line {}: expected '{{' after '{}'", line + leading_lines(rest), rest.trim()));
+            }
+            break;
+        };
+        let selector_line = line + leading_lines(rest);
+        let selectors = &rest[..open];
+        let close = rest[open..].find('}').map(|i| open + i).ok_or(format!("line {}: missing '}}'", selector_line))?;
+        let body = &rest[open + 1..close];
+        if body.contains('{') {
+            return Err(format!("line {}: nested blocks are not supported", selector_line));
+        }
+        let declarations = parse_declarations(body, line + rest[..open + 1].matches('\n').count())?;
+
+        for selector in selectors.split(',').map(str::trim) {
+            if selector.is_empty() {
+                return Err(format!("line {}: empty selector", selector_line));
+            }
+            if selector == ":root" {
+                for (name, value, _) in &declarations {
+                    if name.starts_with("--") {
+                        variables.insert(name.clone(), value.clone());
+                    }
+                }
+                continue;
+            }
+            let (base, state) = match selector.split_once(':') {
+                Some((base, state)) => (base.trim(), Some(state.trim())),
+                None => (selector, None),
+            };
+            let mut style = Style::new();
+            for (name, value, decl_line) in &declarations {
+                let value = substitute_variables(value, &variables).map_err(|e| format!("line {}: {}", decl_line, e))?;
+                style = apply_property(style, name, &value).map_err(|e| format!("line {}: {}", decl_line, e))?;
+            }
+            if !rules.contains_key(base) {
+                order.push(base.to_string());
+            }
+            let entry = rules.entry(base.to_string()).or_default();
+            match state {
+                None => *entry = entry.merge(&style),
+                Some(state) => {
+                    let slot = match state {
+                        "hover" => &mut entry.hover,
+                        "pressed" | "active" => &mut entry.pressed,
+                        "focus" | "focused" => &mut entry.focused,
+                        "disabled" => &mut entry.disabled,
+                        other => return Err(format!("line {}: unknown pseudo-state ':{}'", selector_line, other)),
+                    };
+                    let merged = slot.as_deref().cloned().unwrap_or_default().merge(&style);
+                    *slot = Some(Box::new(merged));
+                }
+            }
+        }
+
+        line += rest[..close + 1].matches('\n').count();
+        rest = &rest[close + 1..];
+    }
+    Ok((rules, order))
+}
+
+/// Blank out `/* */` comments, keeping newlines so line numbers stay right
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..].find("*/").map(|i| start + i + 2).unwrap_or(rest.len());
+        out.extend(rest[start..end].chars().filter(|&c| c == '\n'));
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn leading_lines(text: &str) -> usize {
+    let trimmed = text.trim_start();
+    text[..text.len() - trimmed.len()].matches('\n').count()
+}
+
+/// `name: value;` pairs with the line each starts on
+fn parse_declarations(body: &str, first_line: usize) -> Result<Vec<(String, String, usize)>, String> {
+    let mut declarations = Vec::new();
+    let mut line = first_line;
+    for declaration in body.split(';') {
+        let decl_line = line + leading_lines(declaration);
+        line += declaration.matches('\n').count();
+        let declaration = declaration.trim();
+        if declaration.is_empty() {
+            continue;
+        }
+        let (name, value) = declaration
+            .split_once(':')
+            .ok_or(format!("line {}: expected 'property: value', found '{}'", decl_line, declaration))?;
+        declarations.push((name.trim().to_lowercase(), value.trim().to_string(), decl_line));
+    }
+    Ok(declarations)
+}
+
+fn substitute_variables(value: &str, variables: &HashMap<String, String>) -> Result<String, String> {
+    let mut out = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("var(") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..].find(')').map(|i| start + i).ok_or("unclosed var(")?;
+        let name = rest[start + 4..end].trim();
+        let resolved = variables.get(name).ok_or(format!("unknown variable '{}'", name))?;
+        out.push_str(resolved);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn apply_property(style: Style, name: &str, value: &str) -> Result<Style, String> {
+    Ok(match name {
+        "background" | "background-color" => style.background(parse_color(value)?),
+        "color" => style.text_color(parse_color(value)?),
+        "padding" => style.padding_edges(parse_edges(value)?),
+        "margin" => style.margin_edges(parse_edges(value)?),
+        "border-radius" => style.radius(parse_length(value)?),
+        "font-size" => style.font_size(parse_length(value)?),
+        "font-family" => style.font_family(value.trim_matches(|c| c == '"' || c == '\'')),
+        "opacity" => style.opacity(parse_fraction(value)?),
+        "scale" => style.scale(parse_fraction(value)?),
+        "transform" => match value.strip_prefix("scale(").and_then(|rest| rest.strip_suffix(')')) {
+            Some(scale) => style.scale(parse_fraction(scale)?),
+            None => return Err(format!("only 'transform: scale(n)' is supported, found '{}'", value)),
+        },
+        "transition" => {
+            if value == "none" {
+                return Ok(Style { transition: None, ..style });
+            }
+            // `background 200ms ease-out, transform .2s` - every property
+            // animates, the first duration wins
+            let duration = value.split([' ', ',']).find_map(|part| parse_duration(part).ok());
+            style.transition(duration.ok_or(format!("transition needs a duration, found '{}'", value))?)
+        }
+        "border" => {
+            if value == "none" {
+                return Ok(style.border(0.0, (0, 0, 0, 0)));
+            }
+            // `1px solid #ddd` - the line style is ignored
+            let mut width = 1.0;
+            let mut color = (0, 0, 0, 255);
+            for part in split_values(value) {
+                if let Ok(length) = parse_length(&part) {
+                    width = length;
+                } else if !matches!(part.as_str(), "solid" | "dashed" | "dotted") {
+                    color = parse_color(&part)?;
+                }
+            }
+            style.border(width, color)
+        }
+        "box-shadow" => {
+            let parts = split_values(value);
+            let lengths: Vec<f32> = parts.iter().filter_map(|part| parse_length(part).ok()).collect();
+            let color = parts.iter().find_map(|part| parse_color(part).ok()).unwrap_or((0, 0, 0, 64));
+            match lengths.as_slice() {
+                [x, y] => style.shadow(*x, *y, 0.0, color),
+                [x, y, blur, ..] => style.shadow(*x, *y, *blur, color),
+                _ => return Err(format!("box-shadow needs 'x y [blur] [color]', found '{}'", value)),
+            }
+        }
+        other => return Err(format!("unknown property '{}'", other)),
+    })
+}
+
+/// Split on whitespace, keeping `rgb(...)` together
+fn split_values(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in value.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if c.is_whitespace() && depth == 0 {
+            if !current.is_empty() {
+                parts.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+fn parse_length(value: &str) -> Result<f32, String> {
+    let number = value.trim().trim_end_matches("px");
+    number.parse::<f32>().map_err(|_| format!("invalid length '{}'", value))
+}
+
+/// `0.5` or `50%`
+fn parse_fraction(value: &str) -> Result<f32, String> {
+    let value = value.trim();
+    match value.strip_suffix('%') {
+        Some(percent) => percent.trim().parse::<f32>().map(|p| p / 100.0),
+        None => value.parse::<f32>(),
+    }
+    .map_err(|_| format!("invalid number '{}'", value))
+}
+
+/// `200ms` or `0.2s`
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let millis = match value.strip_suffix("ms") {
+        Some(millis) => millis.parse::<f64>(),
+        None => value.strip_suffix('s').unwrap_or("?").parse::<f64>().map(|s| s * 1000.0),
+    };
+    millis
+        .ok()
+        .filter(|ms| *ms >= 0.0)
+        .map(|ms| Duration::from_micros((ms * 1000.0).round() as u64))
+        .ok_or(format!("invalid duration '{}'", value))
+}
+
+/// CSS shorthand: 1 to 4 values (top right bottom left)
+fn parse_edges(value: &str) -> Result<Edges, String> {
+    let values = split_values(value).iter().map(|part| parse_length(part)).collect::<Result<Vec<_>, _>>()?;
+    Ok(match values.as_slice() {
+        [all] => Edges::all(*all),
+        [vertical, horizontal] => Edges::symmetric(*vertical, *horizontal),
+        [top, horizontal, bottom] => Edges { top: *top, right: *horizontal, bottom: *bottom, left: *horizontal },
+        [top, right, bottom, left] => Edges { top: *top, right: *right, bottom: *bottom, left: *left },
+        _ => return Err(format!("expected 1 to 4 lengths, found '{}'", value)),
+    })
+}
+
+fn parse_color(value: &str) -> Result<Color, String> {
+    let value = value.trim().to_lowercase();
+    if let Some(hex) = value.strip_prefix('#') {
+        let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).map(|d| d * 17);
+        let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+        let color = match hex.len() {
+            3 => (digit(0), digit(1), digit(2), Ok(255)),
+            4 => (digit(0), digit(1), digit(2), digit(3)),
+            6 => (pair(0), pair(2), pair(4), Ok(255)),
+            8 => (pair(0), pair(2), pair(4), pair(6)),
+            _ => return Err(format!("invalid color '{}'", value)),
+        };
+        return match color {
+            (Ok(r), Ok(g), Ok(b), Ok(a)) => Ok((r, g, b, a)),
+            _ => Err(format!("invalid color '{}'", value)),
+        };
+    }
+    if let Some(args) = value.strip_prefix("rgba(").or_else(|| value.strip_prefix("rgb(")) {
+        let args = args.strip_suffix(')').ok_or(format!("invalid color '{}'", value))?;
+        let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let channel = |part: &str| part.parse::<u8>().map_err(|_| format!("invalid color '{}'", value));
+        return match parts.as_slice() {
+            [r, g, b] => Ok((channel(r)?, channel(g)?, channel(b)?, 255)),
+            [r, g, b, a] => {
+                // CSS alpha is 0-1
+                let alpha = a.parse::<f32>().map_err(|_| format!("invalid color '{}'", value))?;
+                Ok((channel(r)?, channel(g)?, channel(b)?, (alpha.clamp(0.0, 1.0) * 255.0).round() as u8))
+            }
+            _ => Err(format!("invalid color '{}'", value)),
+        };
+    }
+    match value.as_str() {
+        "transparent" => Ok((0, 0, 0, 0)),
+        "white" => Ok((255, 255, 255, 255)),
+        "black" => Ok((0, 0, 0, 255)),
+        "red" => Ok((255, 0, 0, 255)),
+        "green" => Ok((0, 128, 0, 255)),
+        "blue" => Ok((0, 0, 255, 255)),
+        "gray" | "grey" => Ok((128, 128, 128, 255)),
+        _ => Err(format!("invalid color '{}'", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{ComputedStyle, WidgetState};
+
+    #[test]
+    fn parses_rules_variables_and_states() {
+        let sheet = Stylesheet::parse(
+            "/* brand */
+            :root { --accent: #3b82f6; }
+            .primary, #save {
+                background: var(--accent);
+                color: white;
+                padding: 8px 16px;
+                border: 1px solid rgba(0, 0, 0, 0.5);
+                border-radius: 6px;
+            }
+            .primary:hover { background: #2563eb; }
+            .primary:disabled { color: #999; }",
+        )
+        .unwrap();
+        assert_eq!(sheet.selectors(), &[".primary".to_string(), "#save".to_string()]);
+
+        let primary = sheet.style_for(".primary");
+        assert_eq!(primary.background, Some((59, 130, 246, 255)));
+        assert_eq!(primary.padding, Some(Edges::symmetric(8.0, 16.0)));
+        assert_eq!(primary.border.unwrap().color, (0, 0, 0, 128));
+        assert_eq!(sheet.style_for("#save").radius, Some(6.0));
+
+        let hovered = primary.resolve(&ComputedStyle::default(), WidgetState { hovered: true, ..Default::default() });
+        assert_eq!(hovered.background, Some((37, 99, 235, 255)));
+        let disabled = primary.resolve(&ComputedStyle::default(), WidgetState { disabled: true, ..Default::default() });
+        assert_eq!(disabled.text_color, Some((153, 153, 153, 255)));
+    }
+
+    #[test]
+    fn parses_opacity_scale_and_transitions() {
+        let sheet = Stylesheet::parse(
+            ".card { opacity: 90%; transition: background 150ms ease-out, transform .3s; }
+            .card:hover { transform: scale(1.05); opacity: 1; }",
+        )
+        .unwrap();
+        let card = sheet.style_for(".card");
+        assert_eq!(card.opacity, Some(0.9));
+        assert_eq!(card.transition, Some(Duration::from_millis(150)));
+        let hovered = card.resolve(&ComputedStyle::default(), WidgetState { hovered: true, ..Default::default() });
+        assert_eq!((hovered.scale, hovered.opacity), (1.05, 1.0));
+        assert!(Stylesheet::parse(".a { transition: background; }").is_err());
+    }
+
+    #[test]
+    fn errors_report_line_numbers() {
+        let error = Stylesheet::parse(".a {\n  color: red;\n  colour: blue;\n}").err().unwrap();
+        assert!(error.contains("line 3"), "{}", error);
+        assert!(Stylesheet::parse(".a { color: #12; }").is_err());
+        assert!(Stylesheet::parse(".a:wiggle { color: red; }").is_err());
+    }
+
+    /// Reload once the change shows up (file events arrive on another thread)
+    fn reload_soon(sheet: &mut Stylesheet) -> Result<bool, String> {
+        let start = std::time::Instant::now();
+        loop {
+            match sheet.reload_if_changed() {
+                Ok(false) if start.elapsed() < std::time::Duration::from_secs(5) => {
+                    std::thread::sleep(std::time::Duration::from_millis(10))
+                }
+                result => return result,
+            }
+        }
+    }
+
+    #[test]
+    fn reloads_when_file_changes() {
+        let path = std::env::temp_dir().join(format!("nebula-sheet-{}.css", std::process::id()));
+        std::fs::write(&path, ".card { padding: 4; }").unwrap();
+        let mut sheet = Stylesheet::load(&path).unwrap();
+        sheet.watch().unwrap();
+        assert!(!sheet.reload_if_changed().unwrap());
+
+        std::fs::write(&path, ".card { padding: 12px; }").unwrap();
+        assert!(reload_soon(&mut sheet).unwrap());
+        assert_eq!(sheet.style_for(".card").padding, Some(Edges::all(12.0)));
+        assert_eq!(sheet.version.get(), 1);
+
+        // Broken edits keep the last good rules
+        std::fs::write(&path, ".card { padding: oops }").unwrap();
+        assert!(reload_soon(&mut sheet).is_err());
+        assert_eq!(sheet.style_for(".card").padding, Some(Edges::all(12.0)));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,288 @@
+// Swipe - Swipe-to-action state for list rows
+// Leading actions sit under the start edge, trailing actions under the end edge
+
+use nebula_core::animation::SpringAnimation;
+
+/// Called with the item id and action id when a swipe action fires
+pub type SwipeCallback = Box<dyn Fn(&str, &str)>;
+
+/// Which side of a row an action is revealed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeEdge {
+    /// Revealed by swiping toward the end (right in LTR)
+    Leading,
+    /// Revealed by swiping toward the start (left in LTR)
+    Trailing,
+}
+
+/// Action button revealed behind a swiped row
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwipeAction {
+    pub id: String,
+    pub label: String,
+    pub icon: Option<String>,
+    pub color: (u8, u8, u8, u8),
+    pub text_color: (u8, u8, u8, u8),
+}
+
+impl SwipeAction {
+    /// Create an action
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            color: (107, 114, 128, 255), // Gray
+            text_color: (255, 255, 255, 255),
+        }
+    }
+
+    /// Red "Delete" action 🗑️
+    pub fn delete() -> Self {
+        Self::new("delete", "Delete").with_icon("🗑️").with_color(239, 68, 68, 255)
+    }
+
+    /// Blue "Archive" action 📦
+    pub fn archive() -> Self {
+        Self::new("archive", "Archive").with_icon("📦").with_color(59, 130, 246, 255)
+    }
+
+    /// Add an icon
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the button color
+    pub fn with_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.color = (r, g, b, a);
+        self
+    }
+}
+
+/// What happened when a swipe was released
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeOutcome {
+    /// Row springs back closed
+    Closed,
+    /// Row stays open, showing the actions on an edge
+    Opened(SwipeEdge),
+    /// Swiped past the commit threshold: the edge's first action fires
+    Committed(SwipeEdge),
+}
+
+#[derive(Debug, Clone)]
+struct Swipe {
+    start_x: f32,
+    start_offset: f32,
+    offset: f32,
+}
+
+/// Swipe-to-action state for one row at a time 👆
+///
+/// Dragging follows the pointer; on release the row either springs back,
+/// snaps open to show its buttons, or — past `commit_threshold` of the
+/// row width — slides all the way out and commits the first action.
+/// Starting a swipe on another row closes the open one.
+#[derive(Debug, Clone)]
+pub struct SwipeState {
+    item: Option<String>,
+    swipe: Option<Swipe>,
+    spring: Option<SpringAnimation>,
+    row_width: f32,
+    /// Width of each revealed action button
+    pub action_width: f32,
+    /// Fraction of the row width that commits the first action on release
+    pub commit_threshold: f32,
+}
+
+impl SwipeState {
+    /// Create idle swipe state
+    pub fn new() -> Self {
+        Self {
+            item: None,
+            swipe: None,
+            spring: None,
+            row_width: 0.0,
+            action_width: 72.0,
+            commit_threshold: 0.6,
+        }
+    }
+
+    /// Start swiping a row
+    pub fn begin(&mut self, item_id: &str, x: f32, row_width: f32) {
+        let start_offset = if self.item.as_deref() == Some(item_id) { self.offset(item_id) } else { 0.0 };
+        self.item = Some(item_id.to_string());
+        self.row_width = row_width;
+        self.spring = None;
+        self.swipe = Some(Swipe { start_x: x, start_offset, offset: start_offset });
+    }
+
+    /// Is a row being dragged?
+    pub fn is_swiping(&self) -> bool {
+        self.swipe.is_some()
+    }
+
+    /// Row currently swiped or open
+    pub fn item(&self) -> Option<&str> {
+        self.item.as_deref()
+    }
+
+    /// Move the pointer
+    /// `leading` / `trailing` are how many actions each edge has; an edge
+    /// with none doesn't move
+    pub fn drag_to(&mut self, x: f32, leading: usize, trailing: usize) -> f32 {
+        let row_width = self.row_width;
+        let Some(swipe) = self.swipe.as_mut() else {
+            return 0.0;
+        };
+        let max = if leading > 0 { row_width } else { 0.0 };
+        let min = if trailing > 0 { -row_width } else { 0.0 };
+        swipe.offset = (swipe.start_offset + x - swipe.start_x).clamp(min, max);
+        swipe.offset
+    }
+
+    /// Release the row
+    pub fn end(&mut self, leading: usize, trailing: usize) -> SwipeOutcome {
+        let Some(swipe) = self.swipe.take() else {
+            return SwipeOutcome::Closed;
+        };
+        let offset = swipe.offset;
+        let edge = if offset > 0.0 { SwipeEdge::Leading } else { SwipeEdge::Trailing };
+        let count = match edge {
+            SwipeEdge::Leading => leading,
+            SwipeEdge::Trailing => trailing,
+        };
+        let reveal = self.action_width * count as f32;
+        let sign = offset.signum();
+
+        let (target, outcome) = if count > 0 && offset.abs() >= self.row_width * self.commit_threshold {
+            (sign * self.row_width, SwipeOutcome::Committed(edge))
+        } else if count > 0 && offset.abs() >= reveal / 2.0 {
+            (sign * reveal, SwipeOutcome::Opened(edge))
+        } else {
+            (0.0, SwipeOutcome::Closed)
+        };
+        self.spring = Some(SpringAnimation::new(offset, target).stiffness(400.0).damping(40.0));
+        outcome
+    }
+
+    /// Spring the open row closed
+    pub fn close(&mut self) {
+        self.swipe = None;
+        let Some(item) = self.item.clone() else {
+            return;
+        };
+        let offset = self.offset(&item);
+        self.spring = Some(SpringAnimation::new(offset, 0.0).stiffness(400.0).damping(40.0));
+    }
+
+    /// Forget the row immediately (e.g. it was removed)
+    pub fn reset(&mut self) {
+        self.item = None;
+        self.swipe = None;
+        self.spring = None;
+    }
+
+    /// Horizontal offset of a row's content
+    pub fn offset(&self, item_id: &str) -> f32 {
+        if self.item.as_deref() != Some(item_id) {
+            return 0.0;
+        }
+        match (&self.swipe, &self.spring) {
+            (Some(swipe), _) => swipe.offset,
+            (None, Some(spring)) => spring.value(),
+            (None, None) => 0.0,
+        }
+    }
+
+    /// Edge whose actions are showing on the open row
+    pub fn open_edge(&self) -> Option<SwipeEdge> {
+        let item = self.item.as_deref()?;
+        let offset = match &self.spring {
+            Some(spring) if self.swipe.is_none() => spring.target(),
+            _ => self.offset(item),
+        };
+        if offset > 0.0 {
+            Some(SwipeEdge::Leading)
+        } else if offset < 0.0 {
+            Some(SwipeEdge::Trailing)
+        } else {
+            None
+        }
+    }
+
+    /// Advance the spring (call every frame)
+    /// Returns true while the row is still moving
+    pub fn update(&mut self, dt: f32) -> bool {
+        let Some(spring) = self.spring.as_mut() else {
+            return false;
+        };
+        if spring.update(dt) {
+            return true;
+        }
+        if spring.target() == 0.0 && self.swipe.is_none() {
+            self.item = None;
+            self.spring = None;
+        }
+        false
+    }
+}
+
+impl Default for SwipeState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(state: &mut SwipeState) {
+        for _ in 0..600 {
+            if !state.update(1.0 / 60.0) {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn short_swipe_springs_back() {
+        let mut state = SwipeState::new();
+        state.begin("a", 300.0, 400.0);
+        assert_eq!(state.drag_to(280.0, 1, 2), -20.0);
+        assert_eq!(state.end(1, 2), SwipeOutcome::Closed);
+        settle(&mut state);
+        assert_eq!(state.offset("a"), 0.0);
+        assert_eq!(state.item(), None);
+    }
+
+    #[test]
+    fn swipe_opens_and_commits() {
+        let mut state = SwipeState::new();
+        state.begin("a", 300.0, 400.0);
+        state.drag_to(200.0, 1, 2);
+        assert_eq!(state.end(1, 2), SwipeOutcome::Opened(SwipeEdge::Trailing));
+        settle(&mut state);
+        assert!((state.offset("a") + 144.0).abs() < 0.01);
+        assert_eq!(state.open_edge(), Some(SwipeEdge::Trailing));
+
+        // Continue from the open position past the threshold
+        state.begin("a", 300.0, 400.0);
+        assert!((state.drag_to(200.0, 1, 2) + 244.0).abs() < 0.01);
+        assert_eq!(state.end(1, 2), SwipeOutcome::Committed(SwipeEdge::Trailing));
+    }
+
+    #[test]
+    fn edges_without_actions_stay_put() {
+        let mut state = SwipeState::new();
+        state.begin("a", 0.0, 400.0);
+        assert_eq!(state.drag_to(100.0, 0, 1), 0.0);
+        assert_eq!(state.drag_to(-500.0, 0, 1), -400.0);
+
+        state.begin("b", 0.0, 400.0);
+        assert_eq!(state.offset("a"), 0.0);
+        assert_eq!(state.item(), Some("b"));
+    }
+}
@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Switch component - iOS-style switch for boolean values
 /// 
@@ -22,18 +23,19 @@ pub struct Switch {
     pub height: f32,
     pub padding: f32,
     pub thumb_size: f32,
-    pub track_color_off: (u8, u8, u8, u8),
-    pub track_color_on: (u8, u8, u8, u8),
-    pub thumb_color: (u8, u8, u8, u8),
+    pub track_color_off: ThemeColor,
+    pub track_color_on: ThemeColor,
+    pub thumb_color: ThemeColor,
     pub thumb_shadow: bool,
-    pub disabled_color: (u8, u8, u8, u8),
+    pub disabled_color: ThemeColor,
     pub animate: bool,
     pub on_change: Option<Box<dyn Fn(bool)>>,
 }
 
 impl Switch {
-    /// Create a new Switch component
+    /// Create a new Switch component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             checked: Signal::new(false),
@@ -43,16 +45,31 @@ impl Switch {
             height: 31.0,
             padding: 2.0,
             thumb_size: 27.0,
-            track_color_off: (120, 120, 128, 255),
-            track_color_on: (52, 199, 89, 255), // iOS green
-            thumb_color: (255, 255, 255, 255),
+            track_color_off: colors.text_muted,
+            track_color_on: colors.success,
+            thumb_color: colors.surface,
             thumb_shadow: true,
-            disabled_color: (200, 200, 200, 255),
+            disabled_color: colors.disabled,
             animate: true,
             on_change: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.track_color_off = colors.text_muted;
+        self.track_color_on = colors.success;
+        self.thumb_color = colors.surface;
+        self.disabled_color = colors.disabled;
+    }
+
     /// Set the checked state
     pub fn checked(self, checked: bool) -> Self {
         self.checked.set(checked);
@@ -168,7 +185,7 @@ impl Switch {
     }
 
     /// Get the current track color
-    pub fn get_track_color(&self) -> (u8, u8, u8, u8) {
+    pub fn get_track_color(&self) -> ThemeColor {
         if self.is_checked() {
             self.track_color_on
         } else {
@@ -350,8 +367,16 @@ mod tests {
         let switch = Switch::new();
         assert_eq!(switch.width, 51.0);
         assert_eq!(switch.height, 31.0);
-        assert_eq!(switch.track_color_on, (52, 199, 89, 255)); // iOS green
+        assert_eq!(switch.track_color_on, ThemeProvider::current().colors.success);
         assert!(switch.thumb_shadow);
         assert!(switch.animate);
     }
+
+    #[test]
+    fn switch_follows_high_contrast_theme() {
+        let theme = Theme::high_contrast(nebula_core::ThemeMode::Dark);
+        let switch = Switch::new().theme(&theme);
+        assert_eq!(switch.track_color_on, theme.colors.success);
+        assert_eq!(switch.thumb_color, theme.colors.surface);
+    }
 }
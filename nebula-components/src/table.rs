@@ -1,8 +1,22 @@
 // Table Component - Data table with columns, rows, and sorting
 // Essential for displaying tabular data
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nebula_core::accessibility::{AccessibilityAudit, ContrastSource};
+use nebula_core::events::EventQueue;
+use nebula_core::hit_test::{EventDispatcher, PointerEventKind};
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::layout_animation::{AnimatedRect, LayoutAnimator};
 use nebula_core::signal::Signal;
+use nebula_core::text::{TextAlign, TextLayout, TextLines};
+use nebula_core::theme::{Theme, ThemeProvider};
+use super::column_autosize::{AutosizeJob, ColumnAutosizer};
+use super::context_menu::ContextMenu;
+use super::events::TableEvent;
+use super::header_menu::{display_columns, group_rows, header_menu, HeaderAction, HeaderActionCallback, HeaderMenuState};
+use super::row_expansion::{ExpandableRows, ExpansionMode, EXPANDER_COLUMN_WIDTH};
 
 /// Table column definition
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +27,11 @@ pub struct TableColumn {
     pub sortable: bool,
     pub resizable: bool,
     pub align: ColumnAlign,
+    /// Kept at the start of the grid while the rest scrolls
+    pub pinned: bool,
+    pub hidden: bool,
+    /// Lines a cell may wrap onto before it's cut off with "…"
+    pub max_lines: usize,
 }
 
 /// Column alignment
@@ -23,6 +42,16 @@ pub enum ColumnAlign {
     Right,
 }
 
+impl From<ColumnAlign> for TextAlign {
+    fn from(align: ColumnAlign) -> Self {
+        match align {
+            ColumnAlign::Left => TextAlign::Left,
+            ColumnAlign::Center => TextAlign::Center,
+            ColumnAlign::Right => TextAlign::Right,
+        }
+    }
+}
+
 impl TableColumn {
     /// Create a new column
     pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
@@ -33,9 +62,18 @@ impl TableColumn {
             sortable: true,
             resizable: true,
             align: ColumnAlign::Left,
+            pinned: false,
+            hidden: false,
+            max_lines: 1,
         }
     }
 
+    /// Let cells wrap onto up to `lines` lines (raise the row height to match)
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = lines.max(1);
+        self
+    }
+
     /// Set the width
     pub fn width(mut self, width: f32) -> Self {
         self.width = Some(width);
@@ -59,6 +97,18 @@ impl TableColumn {
         self.align = align;
         self
     }
+
+    /// Pin to the start of the grid
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Hide the column (its cells stay in the rows)
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
 }
 
 /// Table row
@@ -98,6 +148,12 @@ impl TableRow {
     }
 }
 
+/// Row click callback (shared with the row handlers on the dispatcher)
+pub type RowClickCallback = Rc<dyn Fn(&str)>;
+
+/// Sort callback (column id, direction)
+pub type SortCallback = Box<dyn Fn(&str, SortDirection)>;
+
 /// Sort direction
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SortDirection {
@@ -124,6 +180,8 @@ pub struct Table {
     pub selected_rows: Signal<Vec<String>>,
     pub sort_column: Signal<Option<String>>,
     pub sort_direction: Signal<SortDirection>,
+    /// Column the rows are grouped by
+    pub group_by: Signal<Option<String>>,
     pub row_height: f32,
     pub header_height: f32,
     pub padding: f32,
@@ -140,13 +198,29 @@ pub struct Table {
     pub striped: bool,
     pub hoverable: bool,
     pub selectable: bool,
-    pub on_row_click: Option<Box<dyn Fn(&str)>>,
-    pub on_sort: Option<Box<dyn Fn(&str, SortDirection)>>,
+    /// Called with the row id when a row is clicked
+    pub on_row_click: Option<RowClickCallback>,
+    pub on_sort: Option<SortCallback>,
+    /// Called for every header menu action (after the table has applied it)
+    pub on_header_action: Option<HeaderActionCallback>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<TableEvent>>,
+    /// Fits columns to content (header menu, resize-handle double-click)
+    pub autosizer: ColumnAutosizer,
+    /// Expandable rows: state, detail builder and callback
+    pub expansion: ExpandableRows,
+    /// Row layout nodes from the last build (node, row id)
+    pub row_nodes: Vec<(NodeId, String)>,
+    /// Dispatcher `build` registers row click handlers on
+    pub dispatcher: Option<Rc<RefCell<EventDispatcher>>>,
+    /// Glides rows to new positions after sorting, inserts and removals
+    pub row_animation: LayoutAnimator,
 }
 
 impl Table {
-    /// Create a new Table component
+    /// Create a new Table component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             columns: Vec::new(),
@@ -154,27 +228,55 @@ impl Table {
             selected_rows: Signal::new(Vec::new()),
             sort_column: Signal::new(None),
             sort_direction: Signal::new(SortDirection::Ascending),
+            group_by: Signal::new(None),
             row_height: 48.0,
             header_height: 56.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            header_color: (250, 250, 250, 255),
-            row_color: (255, 255, 255, 255),
-            alt_row_color: (249, 249, 249, 255),
-            selected_color: (59, 130, 246, 20), // Light blue
-            hover_color: (245, 245, 245, 255),
-            text_color: (0, 0, 0, 255),
-            header_text_color: (100, 100, 100, 255),
-            border_color: (220, 220, 220, 255),
+            background_color: colors.surface,
+            header_color: colors.surface_variant,
+            row_color: colors.surface,
+            alt_row_color: colors.surface_alt,
+            selected_color: colors.selection,
+            hover_color: colors.hover,
+            text_color: colors.text,
+            header_text_color: colors.text_muted,
+            border_color: colors.border,
             show_header: true,
             striped: true,
             hoverable: true,
             selectable: true,
             on_row_click: None,
             on_sort: None,
+            on_header_action: None,
+            events: None,
+            autosizer: ColumnAutosizer::new(),
+            expansion: ExpandableRows::default(),
+            row_nodes: Vec::new(),
+            dispatcher: None,
+            row_animation: LayoutAnimator::new(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.header_color = colors.surface_variant;
+        self.row_color = colors.surface;
+        self.alt_row_color = colors.surface_alt;
+        self.selected_color = colors.selection;
+        self.hover_color = colors.hover;
+        self.text_color = colors.text;
+        self.header_text_color = colors.text_muted;
+        self.border_color = colors.border;
+    }
+
     /// Set row height
     pub fn row_height(mut self, height: f32) -> Self {
         self.row_height = height;
@@ -276,7 +378,13 @@ impl Table {
     where
         F: Fn(&str) + 'static,
     {
-        self.on_row_click = Some(Box::new(callback));
+        self.on_row_click = Some(Rc::new(callback));
+        self
+    }
+
+    /// Wire row clicks through a dispatcher on every build
+    pub fn dispatcher(mut self, dispatcher: Rc<RefCell<EventDispatcher>>) -> Self {
+        self.dispatcher = Some(dispatcher);
         self
     }
 
@@ -289,6 +397,122 @@ impl Table {
         self
     }
 
+    /// Emit typed events into a queue (alongside any callbacks)
+    pub fn events(mut self, events: &EventQueue<TableEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    fn emit(&self, event: TableEvent) {
+        if let Some(ref events) = self.events {
+            events.emit(event);
+        }
+    }
+
+    /// Set the header menu callback (for app-specific follow-ups like saving column state)
+    pub fn on_header_action<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, HeaderAction) + 'static,
+    {
+        self.on_header_action = Some(Box::new(callback));
+        self
+    }
+
+    /// Make rows expandable with an expander column
+    pub fn expandable(mut self, mode: ExpansionMode) -> Self {
+        self.expansion.enable(mode);
+        self
+    }
+
+    /// Set the component tree shown under an expanded row
+    pub fn detail<F>(mut self, builder: F) -> Self
+    where
+        F: Fn(&TableRow, &mut LayoutEngine) -> Result<NodeId, String> + 'static,
+    {
+        self.expansion.set_detail(Box::new(builder));
+        self
+    }
+
+    /// Set the expand/collapse callback
+    pub fn on_expand<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, bool) + 'static,
+    {
+        self.expansion.on_expand = Some(Box::new(callback));
+        self
+    }
+
+    /// Toggle a row's detail panel (expander column click)
+    pub fn toggle_expanded(&mut self, id: &str) {
+        if self.rows.iter().all(|r| r.id != id || r.disabled) {
+            return;
+        }
+        if let Some(expanded) = self.expansion.toggle(id) {
+            self.emit(TableEvent::RowExpanded { id: id.to_string(), expanded });
+        }
+    }
+
+    /// Check if a row is expanded
+    pub fn is_row_expanded(&self, id: &str) -> bool {
+        self.expansion.is_expanded(id)
+    }
+
+    /// Does the table show an expander column?
+    pub fn has_expander_column(&self) -> bool {
+        self.expansion.is_enabled()
+    }
+
+    /// Width reserved for the expander column
+    pub fn expander_column_width(&self) -> f32 {
+        if self.has_expander_column() { EXPANDER_COLUMN_WIDTH } else { 0.0 }
+    }
+
+    /// Advance expand/collapse animations
+    /// Returns true while a panel is still moving
+    pub fn update_expansion(&mut self, dt: f32) -> bool {
+        self.expansion.update(dt)
+    }
+
+    /// Size detail panels to their content (call after `compute_layout`)
+    /// Returns true if a panel's height changed; build and lay out again then
+    pub fn measure_details(&mut self, engine: &mut LayoutEngine) -> Result<bool, String> {
+        self.expansion.measure_details(engine)
+    }
+
+    /// Diff row positions against the previous layout (call after `compute_layout`)
+    /// Moved rows then glide from where they were; new ones fade in
+    pub fn capture_row_layout(&mut self, engine: &LayoutEngine) {
+        self.row_animation.capture_nodes(engine, &self.row_nodes);
+    }
+
+    /// Advance row move/fade animations
+    /// Returns true while rows are still moving
+    pub fn update_row_animation(&mut self, dt: f32) -> bool {
+        self.row_animation.update(dt)
+    }
+
+    /// Where to draw a row this frame (layout-relative; None before a capture)
+    pub fn row_rect(&self, id: &str) -> Option<AnimatedRect> {
+        self.row_animation.rect(id)
+    }
+
+    /// Removed rows still fading out where they were
+    pub fn leaving_rows(&self) -> Vec<(&str, AnimatedRect)> {
+        self.row_animation.leaving()
+    }
+
+    /// Height of all rows and open detail panels (without the header)
+    pub fn content_height(&self) -> f32 {
+        let ids: Vec<&str> = self.rows.iter().map(|r| r.id.as_str()).collect();
+        self.expansion.total_height(&ids, self.row_height)
+    }
+
+    /// Indices of rows intersecting the viewport
+    pub fn visible_rows(&self, scroll_y: f32, viewport_height: f32) -> std::ops::Range<usize> {
+        let ids: Vec<&str> = self.rows.iter().map(|r| r.id.as_str()).collect();
+        self.expansion.visible_rows(&ids, self.row_height, scroll_y, viewport_height)
+    }
+
     /// Select a row by ID
     pub fn select_row(&mut self, id: &str) {
         if !self.selectable {
@@ -300,20 +524,18 @@ impl Table {
                 return;
             }
 
-            let mut selected = self.selected_rows.get();
-            if !selected.contains(&id.to_string()) {
-                selected.push(id.to_string());
-                self.selected_rows.set(selected);
+            if !self.is_row_selected(id) {
+                self.selected_rows.update_in_place(|selected| selected.push(id.to_string()));
             }
         }
     }
 
     /// Deselect a row by ID
     pub fn deselect_row(&mut self, id: &str) {
-        let mut selected = self.selected_rows.get();
-        if let Some(pos) = selected.iter().position(|i| i == id) {
-            selected.remove(pos);
-            self.selected_rows.set(selected);
+        if let Some(pos) = self.selected_rows.with(|selected| selected.iter().position(|i| i == id)) {
+            self.selected_rows.update_in_place(|selected| {
+                selected.remove(pos);
+            });
         }
     }
 
@@ -333,7 +555,7 @@ impl Table {
 
     /// Check if a row is selected
     pub fn is_row_selected(&self, id: &str) -> bool {
-        self.selected_rows.get().contains(&id.to_string())
+        self.selected_rows.with(|selected| selected.iter().any(|i| i == id))
     }
 
     /// Get selected rows
@@ -364,7 +586,135 @@ impl Table {
             if let Some(ref callback) = self.on_sort {
                 callback(column_id, direction);
             }
+            self.emit(TableEvent::SortChanged { column: column_id.to_string(), direction });
+        }
+    }
+
+    /// Sort by column in a given direction
+    pub fn sort(&mut self, column_id: &str, direction: SortDirection) {
+        if !self.columns.iter().any(|c| c.id == column_id && c.sortable) {
+            return;
         }
+        self.sort_column.set(Some(column_id.to_string()));
+        self.sort_direction.set(direction);
+
+        if let Some(ref callback) = self.on_sort {
+            callback(column_id, direction);
+        }
+        self.emit(TableEvent::SortChanged { column: column_id.to_string(), direction });
+    }
+
+    /// Remove the sort
+    pub fn clear_sort(&mut self) {
+        self.sort_column.set(None);
+        self.sort_direction.set(SortDirection::Ascending);
+    }
+
+    /// Header context menu for a column (show it on right-click or the menu key)
+    pub fn header_menu(&self, column_id: &str) -> Option<ContextMenu> {
+        let column = self.columns.iter().find(|c| c.id == column_id)?;
+        let sorted = self.sort_column.get().as_deref() == Some(column_id);
+        let state = HeaderMenuState {
+            sort: sorted.then(|| self.sort_direction.get()),
+            filterable: false,
+            grouped: self.group_by.get().as_deref() == Some(column_id),
+        };
+        Some(header_menu(column, state))
+    }
+
+    /// Apply a header menu action string; returns false if it isn't one
+    /// (route `ContextMenu::on_select` here)
+    pub fn handle_header_action(&mut self, action: &str) -> bool {
+        let Some((action, column_id)) = HeaderAction::parse(action) else {
+            return false;
+        };
+        let Some(index) = self.find_column(&column_id) else {
+            return false;
+        };
+        match action {
+            HeaderAction::SortAscending => self.sort(&column_id, SortDirection::Ascending),
+            HeaderAction::SortDescending => self.sort(&column_id, SortDirection::Descending),
+            HeaderAction::ClearSort => self.clear_sort(),
+            HeaderAction::Pin => self.columns[index].pinned = true,
+            HeaderAction::Unpin => self.columns[index].pinned = false,
+            HeaderAction::Hide => self.columns[index].hidden = true,
+            HeaderAction::GroupBy => self.group_by.set(Some(column_id.clone())),
+            HeaderAction::Ungroup => self.group_by.set(None),
+            HeaderAction::Autosize => {
+                self.autosize_column(&column_id);
+            }
+            HeaderAction::Filter => {}
+        }
+        if let Some(ref callback) = self.on_header_action {
+            callback(&column_id, action);
+        }
+        self.emit(TableEvent::HeaderAction { column: column_id, action });
+        true
+    }
+
+    /// A cell's text laid out in its column: wrapped to the column's
+    /// `max_lines`, cut off with "…", aligned like the column
+    pub fn cell_lines(&self, row_id: &str, column_id: &str) -> Option<TextLines> {
+        let index = self.columns.iter().position(|c| c.id == column_id)?;
+        let column = &self.columns[index];
+        let text = self.rows.iter().find(|row| row.id == row_id)?.cells.get(index)?;
+        let font_size = self.autosizer.font_size;
+        let mut layout = TextLayout::new(font_size.round() as u32).max_lines(column.max_lines).align(column.align.into());
+        if let Some(width) = column.width {
+            layout = layout.max_width(width - self.padding * 2.0);
+        }
+        Some(layout.layout(text, &mut |piece| (self.autosizer.measure)(piece, font_size)))
+    }
+
+    /// Fit a column to its header and the rows in `autosizer.visible_rows` (capped at `autosizer.max_width`)
+    pub fn autosize_column(&mut self, column_id: &str) -> Option<f32> {
+        let index = self.columns.iter().position(|c| c.id == column_id && c.resizable)?;
+        let visible = &self.autosizer.visible_rows;
+        let rows: Vec<usize> = (visible.start..visible.end.min(self.rows.len())).collect();
+        let cells = rows.iter().filter_map(|&i| self.rows[i].cells.get(index)).map(String::as_str);
+        let width = self.autosizer.fit(&self.columns[index].label, cells, self.padding);
+        self.columns[index].width = Some(width);
+        Some(width)
+    }
+
+    /// Measure every row of a column in the background; pass the job to `apply_autosize`
+    pub fn autosize_column_async(&self, column_id: &str) -> Option<AutosizeJob> {
+        let index = self.columns.iter().position(|c| c.id == column_id && c.resizable)?;
+        let cells = self.rows.iter().filter_map(|row| row.cells.get(index).cloned()).collect();
+        Some(self.autosizer.fit_async(self.columns[index].label.clone(), cells, self.padding))
+    }
+
+    /// Use a background measurement once it's done; returns true when applied
+    pub fn apply_autosize(&mut self, column_id: &str, job: &AutosizeJob) -> bool {
+        let (Some(width), Some(column)) = (job.width(), self.columns.iter_mut().find(|c| c.id == column_id)) else {
+            return false;
+        };
+        column.width = Some(width);
+        true
+    }
+
+    /// Mouse down on a column's header resize handle; a double-click autosizes it
+    pub fn press_resize_handle(&mut self, column_id: &str, x: f32, y: f32) -> bool {
+        self.autosizer.press_resize_handle(column_id, x, y) && self.autosize_column(column_id).is_some()
+    }
+
+    /// Show a hidden column again
+    pub fn show_column(&mut self, column_id: &str) {
+        if let Some(index) = self.find_column(column_id) {
+            self.columns[index].hidden = false;
+        }
+    }
+
+    /// Column indices in display order (visible only, pinned first)
+    pub fn display_columns(&self) -> Vec<usize> {
+        display_columns(&self.columns)
+    }
+
+    /// Rows grouped by the `group_by` column (None when not grouped)
+    pub fn grouped_rows(&self) -> Option<Vec<(String, Vec<usize>)>> {
+        let index = self.find_column(self.group_by.get().as_deref()?)?;
+        let all: Vec<usize> = (0..self.rows.len()).collect();
+        Some(group_rows(&self.rows, &all, index))
     }
 
     /// Get sort column
@@ -437,13 +787,76 @@ impl Table {
             ..Default::default()
         };
 
+        // One node per row (plus its detail panel when expanded) so clicks can be hit-tested
+        let mut children = Vec::with_capacity(self.rows.len());
+        if let Some(dispatcher) = &self.dispatcher {
+            let mut dispatcher = dispatcher.borrow_mut();
+            for (node, _) in &self.row_nodes {
+                dispatcher.remove(*node);
+            }
+        }
+        self.row_nodes.clear();
+        self.expansion.begin_build();
+        for row in &self.rows {
+            let nodes = match self.expansion.build_rows(engine, &[row], self.row_height) {
+                Some(nodes) => nodes?,
+                None => vec![engine
+                    .new_leaf(taffy::style::Style {
+                        size: taffy::geometry::Size {
+                            width: taffy::style::Dimension::Percent(1.0),
+                            height: taffy::style::Dimension::Length(self.row_height),
+                        },
+                        flex_shrink: 0.0,
+                        ..Default::default()
+                    })
+                    .map_err(|e| format!("Failed to create row node: {:?}", e))?],
+            };
+            self.row_nodes.push((nodes[0], row.id.clone()));
+            children.extend(nodes);
+        }
+
         let node = engine
-            .new_leaf(style)
+            .new_with_children(style, &children)
             .map_err(|e| format!("Failed to create table node: {:?}", e))?;
         self.node_id = Some(node);
 
+        if let Some(dispatcher) = self.dispatcher.clone() {
+            self.attach_events(&mut dispatcher.borrow_mut());
+        }
+
         Ok(node)
     }
+
+    /// Register click handlers on the built row nodes
+    /// Clicking a row selects it (if selectable) and fires `on_row_click`.
+    /// `build` does this itself when the table has a `dispatcher`; otherwise
+    /// call it again after every `build`
+    pub fn attach_events(&self, dispatcher: &mut EventDispatcher) {
+        for (node, id) in &self.row_nodes {
+            if self.rows.iter().any(|row| &row.id == id && row.disabled) {
+                continue;
+            }
+            let id = id.clone();
+            let selected_rows = self.selected_rows.clone();
+            let selectable = self.selectable;
+            let on_row_click = self.on_row_click.clone();
+            let events = self.events.clone();
+            dispatcher.on(*node, move |event, _ctx| {
+                if event.kind != PointerEventKind::Click {
+                    return;
+                }
+                if selectable && !selected_rows.with(|selected| selected.contains(&id)) {
+                    selected_rows.update_in_place(|selected| selected.push(id.clone()));
+                }
+                if let Some(ref callback) = on_row_click {
+                    callback(&id);
+                }
+                if let Some(ref events) = events {
+                    events.emit(TableEvent::RowClicked(id.clone()));
+                }
+            });
+        }
+    }
 }
 
 impl Default for Table {
@@ -452,6 +865,23 @@ impl Default for Table {
     }
 }
 
+impl ContrastSource for Table {
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+        let id = match self.node_id {
+            Some(node) => format!("Table({:?})", node),
+            None => "Table".to_string(),
+        };
+
+        if self.show_header {
+            audit.check_contrast(format!("{}.header", id), self.header_text_color, self.header_color, false);
+        }
+        audit.check_contrast(format!("{}.row", id), self.text_color, self.row_color, false);
+        if self.striped {
+            audit.check_contrast(format!("{}.alt_row", id), self.text_color, self.alt_row_color, false);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -722,4 +1152,205 @@ mod tests {
         assert!(result.is_ok());
         assert!(table.node_id.is_some());
     }
+
+    #[test]
+    fn table_audit_contrast() {
+        let mut audit = AccessibilityAudit::new();
+        audit.set_enabled(true);
+
+        let table = Table::new();
+        table.audit_contrast(&mut audit);
+        assert_eq!(audit.checked_count(), 3);
+        assert!(audit.passed());
+
+        let mut table = Table::new();
+        table.text_color = (200, 200, 200, 255);
+        audit.clear();
+        table.audit_contrast(&mut audit);
+        assert_eq!(audit.issues().len(), 2);
+        assert_eq!(audit.issues()[0].component_id, "Table.row");
+    }
+
+    #[test]
+    fn table_expandable_rows() {
+        let mut engine = LayoutEngine::new();
+        let mut table = Table::new()
+            .expandable(ExpansionMode::Accordion)
+            .row_height(40.0)
+            .add_row("row1", vec!["A".to_string()])
+            .add_row("row2", vec!["B".to_string()])
+            .add_disabled_row("row3", vec!["C".to_string()])
+            .detail(|_, engine| {
+                engine
+                    .new_leaf(taffy::style::Style::default())
+                    .map_err(|e| format!("{:?}", e))
+            });
+        assert!(table.has_expander_column());
+
+        table.toggle_expanded("row1");
+        table.toggle_expanded("row3");
+        assert!(table.is_row_expanded("row1"));
+        assert!(!table.is_row_expanded("row3"));
+
+        table.toggle_expanded("row2");
+        assert!(!table.is_row_expanded("row1"));
+        while table.update_expansion(1.0 / 60.0) {}
+        assert_eq!(table.content_height(), 3.0 * 40.0 + 120.0);
+        assert_eq!(table.visible_rows(50.0, 100.0), 1..2);
+
+        let node = table.build(&mut engine).unwrap();
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(800.0),
+            height: taffy::style::AvailableSpace::MaxContent,
+        };
+        let layout = engine.compute_layout(node, available).unwrap();
+        assert_eq!(layout.size.height, 240.0);
+    }
+
+    #[test]
+    fn detail_panels_size_to_their_content() {
+        let mut table = Table::new()
+            .expandable(ExpansionMode::Multiple)
+            .row_height(40.0)
+            .add_row("short", vec!["A".to_string()])
+            .add_row("tall", vec!["B".to_string()])
+            .detail(|row, engine| {
+                let height = if row.id == "tall" { 300.0 } else { 30.0 };
+                engine
+                    .new_leaf(taffy::style::Style {
+                        size: taffy::geometry::Size {
+                            width: taffy::style::Dimension::Percent(1.0),
+                            height: taffy::style::Dimension::Length(height),
+                        },
+                        ..Default::default()
+                    })
+                    .map_err(|e| format!("{:?}", e))
+            });
+        table.expansion.state = table.expansion.state.take().map(|state| state.animated(false));
+        table.toggle_expanded("short");
+        table.toggle_expanded("tall");
+
+        let mut engine = LayoutEngine::new();
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(800.0),
+            height: taffy::style::AvailableSpace::MaxContent,
+        };
+        let node = table.build(&mut engine).unwrap();
+        engine.compute_layout(node, available).unwrap();
+        // Both panels start at the default height
+        assert_eq!(table.content_height(), 2.0 * 40.0 + 2.0 * 120.0);
+
+        assert!(table.measure_details(&mut engine).unwrap());
+        assert_eq!(table.content_height(), 2.0 * 40.0 + 30.0 + 300.0);
+        assert_eq!(table.visible_rows(0.0, 100.0), 0..2);
+        assert_eq!(table.visible_rows(120.0, 100.0), 1..2);
+
+        let node = table.build(&mut engine).unwrap();
+        let layout = engine.compute_layout(node, available).unwrap();
+        assert_eq!(layout.size.height, 2.0 * 40.0 + 30.0 + 300.0);
+        // Already measured, nothing left to change
+        assert!(!table.measure_details(&mut engine).unwrap());
+    }
+
+    #[test]
+    fn table_row_click_from_pointer() {
+        let clicked = Rc::new(RefCell::new(Vec::new()));
+        let sink = clicked.clone();
+        let mut table = Table::new()
+            .row_height(40.0)
+            .add_column("name", "Name")
+            .add_row("row1", vec!["A".to_string()])
+            .add_row("row2", vec!["B".to_string()])
+            .add_disabled_row("row3", vec!["C".to_string()])
+            .on_row_click(move |id| sink.borrow_mut().push(id.to_string()))
+            .dispatcher(Rc::new(RefCell::new(EventDispatcher::new())));
+
+        let mut engine = LayoutEngine::new();
+        table.build(&mut engine).unwrap();
+        // Rebuilding swaps the handlers over to the new row nodes
+        let node = table.build(&mut engine).unwrap();
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(800.0),
+            height: taffy::style::AvailableSpace::MaxContent,
+        };
+        engine.compute_layout(node, available).unwrap();
+        let dispatcher = table.dispatcher.clone().unwrap();
+        let mut dispatcher = dispatcher.borrow_mut();
+
+        dispatcher.pointer_down(&engine, node, 100.0, 50.0);
+        dispatcher.pointer_up(&engine, node, 120.0, 60.0);
+        assert_eq!(*clicked.borrow(), vec!["row2"]);
+        assert!(table.is_row_selected("row2"));
+
+        // Disabled rows ignore clicks
+        dispatcher.pointer_down(&engine, node, 100.0, 90.0);
+        dispatcher.pointer_up(&engine, node, 100.0, 90.0);
+        assert_eq!(clicked.borrow().len(), 1);
+    }
+
+    #[test]
+    fn sorted_rows_glide_to_their_new_places() {
+        let mut table = Table::new()
+            .row_height(40.0)
+            .add_column("name", "Name")
+            .add_row("b", vec!["Bob".to_string()])
+            .add_row("a", vec!["Alice".to_string()]);
+        let mut engine = LayoutEngine::new();
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(800.0),
+            height: taffy::style::AvailableSpace::MaxContent,
+        };
+        let mut relayout = |table: &mut Table| {
+            let node = table.build(&mut engine).unwrap();
+            engine.compute_layout(node, available).unwrap();
+            table.capture_row_layout(&engine);
+        };
+        relayout(&mut table);
+        assert_eq!(table.row_rect("a").unwrap().y, 40.0);
+
+        // The app re-sorts its rows (e.g. from on_sort)
+        table.rows.reverse();
+        relayout(&mut table);
+        // Still drawn at the old spot, then moves up
+        assert_eq!(table.row_rect("a").unwrap().y, 40.0);
+        while table.update_row_animation(1.0 / 60.0) {}
+        assert_eq!(table.row_rect("a").unwrap().y, 0.0);
+        assert_eq!(table.row_rect("b").unwrap().y, 40.0);
+    }
+
+    #[test]
+    fn table_autosizes_column_on_double_click() {
+        let mut table = Table::new()
+            .add_column("name", "Name")
+            .add_row("row1", vec!["Al".to_string()])
+            .add_row("row2", vec!["Bartholomew".to_string()])
+            .add_row("row3", vec!["W".repeat(200)]);
+        table.autosizer.measure = std::sync::Arc::new(|text: &str, _size: f32| text.len() as f32 * 8.0);
+        table.autosizer.visible_rows = 0..2;
+
+        assert!(!table.press_resize_handle("name", 100.0, 10.0));
+        assert!(table.press_resize_handle("name", 100.0, 10.0));
+        // "Bartholomew" (88) + padding 16 on each side; the off-screen row is ignored
+        assert_eq!(table.columns[0].width, Some(120.0));
+
+        table.handle_header_action(&HeaderAction::Autosize.action_for("name"));
+        assert_eq!(table.columns[0].width, Some(120.0));
+        table.autosizer.visible_rows = 0..3;
+        assert_eq!(table.autosize_column("name"), Some(table.autosizer.max_width));
+    }
+
+    #[test]
+    fn cells_wrap_and_truncate_in_their_column() {
+        let table = Table::new()
+            .add_column_object(TableColumn::new("note", "Note").width(120.0).max_lines(2))
+            .add_column_object(TableColumn::new("total", "Total").width(120.0).align(ColumnAlign::Right))
+            .add_row_object(TableRow::new("r1", vec!["Delivered late because the courier lost the parcel twice".to_string(), "12.50".to_string()]));
+
+        let note = table.cell_lines("r1", "note").unwrap();
+        assert_eq!(note.lines.len(), 2);
+        assert!(note.truncated && note.lines[1].text.ends_with('…'));
+        let total = table.cell_lines("r1", "total").unwrap();
+        assert!(total.lines[0].x > 0.0); // Right-aligned
+        assert!(table.cell_lines("r1", "missing").is_none());
+    }
 }
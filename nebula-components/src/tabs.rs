@@ -3,6 +3,11 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeProvider};
+use nebula_gfx::draw_list::{DrawList, Rect};
+use super::badge::ItemBadge;
+use super::drag_drop::{DragOutcome, DragPayload, DragSource, DragZone, DropIndicator, DropTarget};
+use super::reorder::{index_after_move, keyboard_target, move_item, ReorderCallback, ReorderState};
 
 /// Tab item
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +18,8 @@ pub struct Tab {
     pub icon: Option<String>,
     pub badge: Option<String>,
     pub closable: bool,
+    /// Unread count badge bound to a Signal
+    pub unread: Option<ItemBadge>,
 }
 
 impl Tab {
@@ -25,6 +32,7 @@ impl Tab {
             icon: None,
             badge: None,
             closable: false,
+            unread: None,
         }
     }
 
@@ -37,6 +45,7 @@ impl Tab {
             icon: None,
             badge: None,
             closable: false,
+            unread: None,
         }
     }
 
@@ -52,6 +61,19 @@ impl Tab {
         self
     }
 
+    /// Show an unread badge or dot that follows a count Signal
+    pub fn with_unread(mut self, badge: ItemBadge) -> Self {
+        self.unread = Some(badge);
+        self
+    }
+
+    /// Draw the unread badge on the tab's top-right corner
+    pub fn paint_badge(&self, list: &mut DrawList, bounds: Rect) {
+        if let Some(ref badge) = self.unread {
+            badge.paint(list, bounds);
+        }
+    }
+
     /// Make the tab closable
     pub fn closable(mut self, closable: bool) -> Self {
         self.closable = closable;
@@ -87,31 +109,63 @@ pub struct Tabs {
     pub indicator_height: f32,
     pub on_change: Option<Box<dyn Fn(&str)>>,
     pub on_close: Option<Box<dyn Fn(&str)>>,
+    pub reorderable: bool,
+    pub reorder: ReorderState,
+    pub on_reorder: Option<ReorderCallback>,
+    /// Tabs can be dragged between strips sharing this group
+    pub drag_group: Option<String>,
+    /// Tab widths from the last layout (places drags and drops)
+    pub tab_widths: Vec<f32>,
 }
 
 impl Tabs {
-    /// Create a new Tabs component
+    /// Create a new Tabs component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             tabs: Vec::new(),
             active_tab: Signal::new(None),
             height: 48.0,
             padding: 16.0,
-            background_color: (255, 255, 255, 255),
-            active_color: (255, 255, 255, 255),
-            inactive_color: (245, 245, 245, 255),
-            hover_color: (240, 240, 240, 255),
-            text_color: (100, 100, 100, 255),
-            active_text_color: (0, 0, 0, 255),
-            border_color: (220, 220, 220, 255),
-            indicator_color: (59, 130, 246, 255), // Blue
+            background_color: colors.surface,
+            active_color: colors.surface,
+            inactive_color: colors.surface_alt,
+            hover_color: colors.hover,
+            text_color: colors.text_muted,
+            active_text_color: colors.text,
+            border_color: colors.border,
+            indicator_color: colors.primary,
             indicator_height: 3.0,
             on_change: None,
             on_close: None,
+            reorderable: false,
+            reorder: ReorderState::new(),
+            on_reorder: None,
+            drag_group: None,
+            tab_widths: Vec::new(),
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.active_color = colors.surface;
+        self.inactive_color = colors.surface_alt;
+        self.hover_color = colors.hover;
+        self.text_color = colors.text_muted;
+        self.active_text_color = colors.text;
+        self.border_color = colors.border;
+        self.indicator_color = colors.primary;
+    }
+
     /// Set the height
     pub fn height(mut self, height: f32) -> Self {
         self.height = height;
@@ -190,6 +244,86 @@ impl Tabs {
         self
     }
 
+    /// Enable drag-to-reorder
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Set the reorder callback (old index, new index)
+    pub fn on_reorder<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + 'static,
+    {
+        self.on_reorder = Some(Box::new(callback));
+        self
+    }
+
+    /// Let tabs be dragged to and from other strips in the same group
+    pub fn drag_group(mut self, group: impl Into<String>) -> Self {
+        self.drag_group = Some(group.into());
+        self
+    }
+
+    /// Start dragging a tab (`x` relative to the first one, `widths` from layout)
+    pub fn start_drag(&mut self, index: usize, x: f32, widths: Vec<f32>) {
+        if self.reorderable && index < self.tabs.len() && widths.len() == self.tabs.len() {
+            self.reorder.begin(index, widths, x);
+        }
+    }
+
+    /// Move the dragged tab, returning the slot it would drop into
+    pub fn drag(&mut self, x: f32) -> Option<usize> {
+        self.reorder.drag_to(x)
+    }
+
+    /// Drop the dragged tab into its target slot
+    pub fn drop_tab(&mut self) {
+        if let Some((from, to)) = self.reorder.end() {
+            self.move_tab(from, to);
+        }
+    }
+
+    /// Abandon the current drag
+    pub fn cancel_drag(&mut self) {
+        self.reorder.cancel();
+    }
+
+    /// Move a tab one slot toward the start (Ctrl+Left)
+    pub fn move_tab_left(&mut self, index: usize) -> bool {
+        self.move_tab_by(index, -1)
+    }
+
+    /// Move a tab one slot toward the end (Ctrl+Right)
+    pub fn move_tab_right(&mut self, index: usize) -> bool {
+        self.move_tab_by(index, 1)
+    }
+
+    fn move_tab_by(&mut self, index: usize, delta: isize) -> bool {
+        if !self.reorderable || self.reorder.is_dragging() {
+            return false;
+        }
+        let Some(target) = keyboard_target(index, delta, self.tabs.len()) else {
+            return false;
+        };
+        self.move_tab(index, target);
+        true
+    }
+
+    /// Move a tab, keeping the active one active, and notify `on_reorder`
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return;
+        }
+        move_item(&mut self.tabs, from, to);
+        if let Some(active) = self.active_tab.get() {
+            self.active_tab.set(Some(index_after_move(active, from, to)));
+        }
+        if let Some(ref callback) = self.on_reorder {
+            callback(from, to);
+        }
+    }
+
     /// Select a tab by index
     pub fn select_tab(&mut self, index: usize) {
         if index < self.tabs.len() && !self.tabs[index].disabled {
@@ -255,6 +389,15 @@ impl Tabs {
         self.active_tab.get() == Some(index)
     }
 
+    /// Advance unread badge animations (call each frame); true while any runs
+    pub fn update_badges(&self, delta_time: f32) -> bool {
+        self.tabs
+            .iter()
+            .filter_map(|tab| tab.unread.as_ref())
+            .map(|badge| badge.update(delta_time))
+            .fold(false, |running, animating| running | animating)
+    }
+
     /// Get tab count
     pub fn tab_count(&self) -> usize {
         self.tabs.len()
@@ -302,10 +445,133 @@ impl Default for Tabs {
     }
 }
 
+/// Width assumed for tabs that haven't been laid out yet
+const FALLBACK_TAB_WIDTH: f32 = 120.0;
+
+impl Tabs {
+    fn tab_width(&self, index: usize) -> f32 {
+        self.tab_widths.get(index).copied().unwrap_or(FALLBACK_TAB_WIDTH)
+    }
+
+    /// Tab under `x` (relative to the first tab)
+    fn tab_at(&self, x: f32) -> Option<usize> {
+        let mut start = 0.0;
+        for index in 0..self.tabs.len() {
+            let end = start + self.tab_width(index);
+            if x >= start && x < end {
+                return Some(index);
+            }
+            start = end;
+        }
+        None
+    }
+
+    /// Gap nearest to `x` with its position
+    fn drop_slot(&self, x: f32) -> (usize, f32) {
+        let mut start = 0.0;
+        for index in 0..self.tabs.len() {
+            let width = self.tab_width(index);
+            if x < start + width / 2.0 {
+                return (index, start);
+            }
+            start += width;
+        }
+        (self.tabs.len(), start)
+    }
+
+    /// Remove a tab regardless of `closable`, keeping the active one active
+    fn take_tab(&mut self, index: usize) -> Option<Tab> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+        let tab = self.tabs.remove(index);
+        if index < self.tab_widths.len() {
+            self.tab_widths.remove(index);
+        }
+        if let Some(active) = self.active_tab.get() {
+            let active = match active.cmp(&index) {
+                std::cmp::Ordering::Less => Some(active),
+                std::cmp::Ordering::Equal => (!self.tabs.is_empty()).then(|| index.min(self.tabs.len() - 1)),
+                std::cmp::Ordering::Greater => Some(active - 1),
+            };
+            self.active_tab.set(active);
+        }
+        Some(tab)
+    }
+}
+
+impl DragSource for Tabs {
+    fn drag_payload(&self, (x, _): (f32, f32)) -> Option<DragPayload> {
+        let group = self.drag_group.as_ref()?;
+        let tab = self.tabs.get(self.tab_at(x)?).filter(|tab| !tab.disabled)?;
+        Some(DragPayload::new(group.clone(), tab.id.clone(), tab.label.clone()).with_data(tab.clone()))
+    }
+
+    fn drag_finished(&mut self, payload: &DragPayload, outcome: DragOutcome) {
+        if outcome == DragOutcome::Transferred {
+            if let Some(index) = self.find_tab(&payload.id) {
+                self.take_tab(index);
+            }
+        }
+    }
+}
+
+impl DropTarget for Tabs {
+    fn accepts(&self, payload: &DragPayload) -> bool {
+        self.drag_group.as_deref() == Some(payload.kind.as_str()) && payload.data::<Tab>().is_some()
+    }
+
+    fn drag_over(&mut self, _payload: &DragPayload, (x, _): (f32, f32)) -> Option<DropIndicator> {
+        Some(DropIndicator::VerticalLine(self.drop_slot(x).1))
+    }
+
+    fn drop_payload(&mut self, payload: &DragPayload, (x, _): (f32, f32)) -> bool {
+        let (slot, _) = self.drop_slot(x);
+        match (self.find_tab(&payload.id), payload.data::<Tab>()) {
+            (Some(from), _) => self.move_tab(from, if slot > from { slot - 1 } else { slot }),
+            (None, Some(tab)) => {
+                // A tab dragged in from another strip becomes the active one
+                self.tabs.insert(slot, tab.clone());
+                self.tab_widths.clear();
+                self.select_tab(slot);
+            }
+            (None, None) => return false,
+        }
+        true
+    }
+}
+
+impl DragZone for Tabs {
+    fn as_source(&mut self) -> Option<&mut dyn DragSource> {
+        Some(self)
+    }
+
+    fn as_target(&mut self) -> Option<&mut dyn DropTarget> {
+        Some(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn tabs_paint_unread_badges_from_signals() {
+        use crate::badge::Attention;
+
+        let unread = Signal::new(0);
+        let tabs = Tabs::new()
+            .add_tab_object(Tab::new("Inbox", "inbox").with_unread(ItemBadge::new(&unread).attention(Attention::Shake)));
+        let mut list = DrawList::new();
+        tabs.tabs[0].paint_badge(&mut list, (0.0, 0.0, 120.0, 40.0));
+        assert!(list.is_empty());
+
+        unread.set(4);
+        assert!(tabs.update_badges(0.016));
+        tabs.tabs[0].paint_badge(&mut list, (0.0, 0.0, 120.0, 40.0));
+        assert_eq!(list.len(), 2);
+    }
+
     #[test]
     fn tabs_starts_empty() {
         let tabs = Tabs::new();
@@ -486,4 +752,46 @@ mod tests {
         assert!(result.is_ok());
         assert!(tabs.node_id.is_some());
     }
+
+    #[test]
+    fn tabs_reorder_keeps_active_tab() {
+        let mut tabs = Tabs::new()
+            .reorderable(true)
+            .add_tab("One", "one")
+            .add_tab("Two", "two")
+            .add_tab("Three", "three");
+        tabs.select_tab(0);
+
+        tabs.start_drag(0, 10.0, vec![80.0, 120.0, 80.0]);
+        assert_eq!(tabs.drag(150.0), Some(1));
+        tabs.drop_tab();
+        assert_eq!(tabs.tabs[1].id, "one");
+        assert_eq!(tabs.get_active_tab_id(), Some("one".to_string()));
+
+        assert!(tabs.move_tab_right(1));
+        assert!(!tabs.move_tab_right(2));
+        assert_eq!(tabs.get_active_tab(), Some(2));
+    }
+
+    #[test]
+    fn tabs_drag_between_strips() {
+        use crate::drag_drop::{DragController, DragZone};
+        let mut left = Tabs::new().drag_group("editor").add_tab("One", "one").add_tab("Two", "two");
+        let mut right = Tabs::new().drag_group("editor").add_tab("Three", "three");
+        left.select_tab(1);
+        let mut drag = DragController::new();
+        let mut zones: Vec<(nebula_gfx::draw_list::Rect, &mut dyn DragZone)> =
+            vec![((0.0, 0.0, 400.0, 48.0), &mut left), ((400.0, 0.0, 400.0, 48.0), &mut right)];
+
+        // "Two" goes in front of "Three"
+        drag.press(&mut zones, (130.0, 20.0));
+        drag.move_to(&mut zones, (410.0, 20.0));
+        drag.release(&mut zones);
+        drop(zones);
+
+        assert_eq!(left.tabs.len(), 1);
+        assert_eq!(left.get_active_tab(), Some(0));
+        assert_eq!(right.tabs[0].id, "two");
+        assert_eq!(right.get_active_tab_id(), Some("two".to_string()));
+    }
 }
@@ -1,4 +1,8 @@
-use nebula_core::{Signal, TextRenderer, FontFamily};
+use nebula_core::{FontFamily, LayoutEngine, NodeId, Signal, TextAlign, TextLayout, TextLines, TextMeasure, TextRenderer, ThemeColor, ThemeProvider};
+use nebula_gfx::draw_list::{DrawList, Rect};
+
+use super::style::ComputedStyle;
+use super::widget::Widget;
 
 /// Text component - Display reactive text! 📝
 /// 
@@ -16,6 +20,12 @@ pub struct Text {
     pub font_size: u32,
     /// Font family
     pub font_family: FontFamily,
+    /// Lines to show before cutting off with an ellipsis (None = all)
+    pub max_lines: Option<usize>,
+    /// Alignment of wrapped lines
+    pub align: TextAlign,
+    /// Line height as a multiple of the font size
+    pub line_height: f32,
 }
 
 impl Text {
@@ -26,6 +36,9 @@ impl Text {
             position: (0.0, 0.0),
             font_size: 24,
             font_family: FontFamily::Roboto,
+            max_lines: None,
+            align: TextAlign::Left,
+            line_height: 1.2,
         }
     }
 
@@ -36,6 +49,9 @@ impl Text {
             position: (0.0, 0.0),
             font_size: 24,
             font_family: FontFamily::Roboto,
+            max_lines: None,
+            align: TextAlign::Left,
+            line_height: 1.2,
         }
     }
 
@@ -57,6 +73,37 @@ impl Text {
         self
     }
 
+    /// Cut off after this many lines, ending in "…"
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines.max(1));
+        self
+    }
+
+    /// Set the alignment of wrapped lines
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the line height (multiple of the font size)
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Paragraph layout for a width (wrap, max lines, alignment)
+    pub fn text_layout(&self, width: f32) -> TextLayout {
+        let mut layout = TextLayout::new(self.font_size).max_width(width).align(self.align).line_height(self.line_height);
+        layout.max_lines = self.max_lines;
+        layout
+    }
+
+    /// Lines as they'd be drawn in a box `width` wide (estimated widths, no font needed)
+    pub fn lines(&self, width: f32) -> TextLines {
+        let font_size = self.font_size as f32;
+        self.text_layout(width).layout(&self.get_content(), &mut |text| estimate_text_width(text, font_size))
+    }
+
     /// Get the current text content
     pub fn get_content(&self) -> String {
         self.content.get()
@@ -81,6 +128,141 @@ impl Text {
     }
 }
 
+impl Widget for Text {
+    fn build_node(&mut self, engine: &mut LayoutEngine, _children: &[NodeId]) -> Result<NodeId, String> {
+        // Auto-sized: the layout engine measures the text and wraps it to the parent
+        let mut measure = TextMeasure::new(self.get_content(), self.font_size).font_family(self.font_family);
+        measure.line_height = self.line_height;
+        measure.max_lines = self.max_lines;
+        engine
+            .new_text_leaf(taffy::style::Style::default(), measure)
+            .map_err(|e| format!("Failed to create text node: {:?}", e))
+    }
+
+    fn paint(&self, bounds: Rect, list: &mut DrawList) {
+        self.paint_styled(bounds, &ComputedStyle::default(), list);
+    }
+
+    fn paint_styled(&self, bounds: Rect, style: &ComputedStyle, list: &mut DrawList) {
+        let color = style.text_color.unwrap_or_else(|| ThemeProvider::current().colors.text);
+        for line in self.lines(bounds.2).lines {
+            for (run, x) in line.runs {
+                list.text(run, bounds.0 + x, bounds.1 + line.y, self.font_size as f32, color);
+            }
+        }
+    }
+}
+
+/// Approximate width of a line of text without a font (about half an em per glyph)
+pub fn estimate_text_width(text: &str, font_size: f32) -> f32 {
+    (text.chars().count() as f32 * font_size * 0.55).ceil()
+}
+
+/// One run of text sharing a style
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextSpan {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    /// Link target, if the span is a link
+    pub link: Option<String>,
+    /// Color override (None = inherit)
+    pub color: Option<ThemeColor>,
+}
+
+impl TextSpan {
+    /// Create a plain span
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), ..Default::default() }
+    }
+}
+
+/// Rich text made of styled spans - bold, italic, code and links! ✨
+///
+/// # Example
+/// ```rust,ignore
+/// let text = StyledText::new()
+///     .text("Run ")
+///     .code("cargo test")
+///     .text(" and check the ")
+///     .link("docs", "https://example.com");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StyledText {
+    pub spans: Vec<TextSpan>,
+}
+
+impl StyledText {
+    /// Create empty styled text
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a span
+    pub fn span(mut self, span: TextSpan) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Add plain text
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.span(TextSpan::new(text))
+    }
+
+    /// Add bold text
+    pub fn bold(self, text: impl Into<String>) -> Self {
+        self.span(TextSpan { bold: true, ..TextSpan::new(text) })
+    }
+
+    /// Add italic text
+    pub fn italic(self, text: impl Into<String>) -> Self {
+        self.span(TextSpan { italic: true, ..TextSpan::new(text) })
+    }
+
+    /// Add inline code
+    pub fn code(self, text: impl Into<String>) -> Self {
+        self.span(TextSpan { code: true, ..TextSpan::new(text) })
+    }
+
+    /// Add a link
+    pub fn link(self, text: impl Into<String>, url: impl Into<String>) -> Self {
+        self.span(TextSpan { link: Some(url.into()), ..TextSpan::new(text) })
+    }
+
+    /// Text without styling
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Is there no text?
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|span| span.text.is_empty())
+    }
+
+    /// Rough line count when wrapped to `max_chars` per line
+    /// Cheap enough to estimate heights before real measurement
+    pub fn estimate_lines(&self, max_chars: usize) -> usize {
+        let max_chars = max_chars.max(1);
+        self.plain_text()
+            .split('\n')
+            .map(|line| line.chars().count().div_ceil(max_chars).max(1))
+            .sum()
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(text: &str) -> Self {
+        Self::new().text(text)
+    }
+}
+
+impl From<String> for StyledText {
+    fn from(text: String) -> Self {
+        Self::new().text(text)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,4 +357,15 @@ mod tests {
         assert!(w > 0.0);
         assert!(h > 0.0);
     }
+
+    #[test]
+    fn styled_text_spans() {
+        let text = StyledText::new().text("Run ").code("cargo test").link(" docs", "https://example.com");
+        assert_eq!(text.plain_text(), "Run cargo test docs");
+        assert!(text.spans[1].code);
+        assert_eq!(text.spans[2].link.as_deref(), Some("https://example.com"));
+        assert_eq!(text.estimate_lines(10), 2);
+        assert_eq!(StyledText::from("a\nb").estimate_lines(10), 2);
+        assert!(StyledText::new().is_empty());
+    }
 }
@@ -1,6 +1,11 @@
-use nebula_core::{Signal, LayoutEngine, NodeId, Layout};
+use nebula_core::{Signal, LayoutEngine, NodeId, Layout, FocusKey, Focusable, ImeEvent};
+use nebula_core::theme::ThemeProvider;
+use crate::context_menu::ContextMenu;
+use crate::spellcheck::{self, Misspelling, SpellChecker};
+use crate::text::estimate_text_width;
 use taffy::prelude::*;
 use tracing::info;
+use std::cell::RefCell;
 use std::rc::Rc;
 
 /// TextField - Text input component ✏️
@@ -12,6 +17,8 @@ use std::rc::Rc;
 /// - Focus state
 /// - Placeholder text
 /// - Input validation
+/// - Optional spell-checking
+/// - IME composition (Chinese, Japanese, Korean input methods)
 /// 
 /// Just like HTML's input, but better!
 #[derive(Clone)]
@@ -38,6 +45,20 @@ pub struct TextField {
     on_change: Option<Rc<dyn Fn(String)>>,
     /// Submit handler (Enter key)
     on_submit: Option<Rc<dyn Fn(String)>>,
+    /// Spell-checking backend (None = spell-check disabled)
+    spell_checker: Option<Rc<RefCell<dyn SpellChecker>>>,
+    /// Squiggly underline color for misspelled words
+    pub spell_error_color: (u8, u8, u8, u8),
+    /// IME text being composed at the cursor (not part of `text` yet)
+    pub composition: Signal<Option<Composition>>,
+}
+
+/// IME preedit shown at the cursor until the input method commits it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Composition {
+    pub text: String,
+    /// Byte range of the IME caret inside `text`
+    pub cursor: Option<(usize, usize)>,
 }
 
 impl TextField {
@@ -56,6 +77,9 @@ impl TextField {
             position: (0.0, 0.0),
             on_change: None,
             on_submit: None,
+            spell_checker: None,
+            spell_error_color: (220, 38, 38, 255),
+            composition: Signal::new(None),
         }
     }
 
@@ -76,6 +100,9 @@ impl TextField {
             position: (0.0, 0.0),
             on_change: None,
             on_submit: None,
+            spell_checker: None,
+            spell_error_color: (220, 38, 38, 255),
+            composition: Signal::new(None),
         }
     }
 
@@ -127,6 +154,76 @@ impl TextField {
         self
     }
 
+    /// Enable spell-checking with the given backend
+    pub fn spell_check(mut self, checker: Rc<RefCell<dyn SpellChecker>>) -> Self {
+        self.spell_checker = Some(checker);
+        self
+    }
+
+    /// Set the squiggly underline color
+    pub fn spell_error_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.spell_error_color = (r, g, b, a);
+        self
+    }
+
+    /// Is spell-checking enabled?
+    pub fn is_spell_check_enabled(&self) -> bool {
+        self.spell_checker.is_some()
+    }
+
+    /// Misspelled words in the current text (empty if spell-check is off)
+    pub fn misspellings(&self) -> Vec<Misspelling> {
+        match &self.spell_checker {
+            Some(checker) => spellcheck::find_misspellings(&self.get_text(), &*checker.borrow()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Misspelled word at a byte offset (e.g. under a right-click)
+    pub fn misspelling_at(&self, index: usize) -> Option<Misspelling> {
+        self.misspellings().into_iter().find(|m| m.contains(index))
+    }
+
+    /// Suggestion menu for the misspelled word at a byte offset
+    pub fn spelling_menu_at(&self, index: usize) -> Option<ContextMenu> {
+        let checker = self.spell_checker.as_ref()?;
+        let misspelling = self.misspelling_at(index)?;
+        Some(spellcheck::suggestion_menu(&misspelling, &*checker.borrow(), 5))
+    }
+
+    /// Apply an action picked from the suggestion menu
+    /// Returns true if the action was a spelling action
+    pub fn apply_spelling_action(&self, action: &str, misspelling: &Misspelling) -> bool {
+        let Some(checker) = &self.spell_checker else {
+            return false;
+        };
+
+        if let Some(replacement) = action.strip_prefix(spellcheck::REPLACE_ACTION_PREFIX) {
+            let mut text = self.get_text();
+            if misspelling.end > text.len() || text.get(misspelling.start..misspelling.end) != Some(misspelling.word.as_str()) {
+                return false;
+            }
+            text.replace_range(misspelling.start..misspelling.end, replacement);
+            let cursor = misspelling.start + replacement.len();
+            self.text.set(text.clone());
+            self.cursor_position.set(cursor);
+            info!("📖 Replaced '{}' with '{}'", misspelling.word, replacement);
+            if let Some(handler) = &self.on_change {
+                handler(text);
+            }
+            true
+        } else if action == spellcheck::ADD_TO_DICTIONARY_ACTION {
+            checker.borrow_mut().add_word(&misspelling.word);
+            info!("📖 Added '{}' to dictionary", misspelling.word);
+            true
+        } else if action == spellcheck::IGNORE_ACTION {
+            checker.borrow_mut().ignore_word(&misspelling.word);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Get text content
     pub fn get_text(&self) -> String {
         self.text.get()
@@ -156,24 +253,29 @@ impl TextField {
 
     /// Insert character at cursor
     pub fn insert_char(&self, c: char) {
+        self.insert_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Insert text at cursor (cut short at `max_length` characters)
+    pub fn insert_str(&self, insert: &str) {
         let mut text = self.get_text();
         let cursor = self.cursor_position.get();
         
-        // Check max length
-        if let Some(max_len) = self.max_length {
-            if text.len() >= max_len {
-                return;
-            }
+        // Check max length (in characters, so CJK counts one per glyph)
+        let room = self.max_length.map_or(usize::MAX, |max_len| max_len.saturating_sub(text.chars().count()));
+        let insert: String = insert.chars().take(room).collect();
+        if insert.is_empty() {
+            return;
         }
         
-        // Insert character
-        text.insert(cursor, c);
+        // Insert text
+        text.insert_str(cursor, &insert);
         self.text.set(text.clone());
         
-        // Move cursor forward
-        self.cursor_position.set(cursor + 1);
+        // Move cursor forward (cursor is a byte index)
+        self.cursor_position.set(cursor + insert.len());
         
-        info!("✏️ Inserted '{}' at position {}", c, cursor);
+        info!("✏️ Inserted '{}' at position {}", insert, cursor);
         
         // Call change handler
         if let Some(handler) = &self.on_change {
@@ -186,12 +288,12 @@ impl TextField {
         let mut text = self.get_text();
         let cursor = self.cursor_position.get();
         
-        if cursor > 0 {
-            text.remove(cursor - 1);
+        if let Some(previous) = previous_boundary(&text, cursor) {
+            text.remove(previous);
             self.text.set(text.clone());
-            self.cursor_position.set(cursor - 1);
+            self.cursor_position.set(previous);
             
-            info!("✏️ Deleted character at position {}", cursor - 1);
+            info!("✏️ Deleted character at position {}", previous);
             
             // Call change handler
             if let Some(handler) = &self.on_change {
@@ -220,18 +322,16 @@ impl TextField {
 
     /// Move cursor left
     pub fn move_cursor_left(&self) {
-        let cursor = self.cursor_position.get();
-        if cursor > 0 {
-            self.cursor_position.set(cursor - 1);
+        if let Some(previous) = previous_boundary(&self.get_text(), self.cursor_position.get()) {
+            self.cursor_position.set(previous);
         }
     }
 
     /// Move cursor right
     pub fn move_cursor_right(&self) {
         let cursor = self.cursor_position.get();
-        let text_len = self.get_text().len();
-        if cursor < text_len {
-            self.cursor_position.set(cursor + 1);
+        if let Some(c) = self.get_text()[cursor..].chars().next() {
+            self.cursor_position.set(cursor + c.len_utf8());
         }
     }
 
@@ -246,6 +346,46 @@ impl TextField {
         self.cursor_position.set(text_len);
     }
 
+    /// Show IME preedit text at the cursor (empty text ends the composition)
+    pub fn set_composition(&self, text: impl Into<String>, cursor: Option<(usize, usize)>) {
+        let text = text.into();
+        self.composition.set(if text.is_empty() { None } else { Some(Composition { text, cursor }) });
+    }
+
+    /// Insert the IME's final text and end the composition
+    pub fn commit_composition(&self, text: &str) {
+        self.composition.set(None);
+        self.insert_str(text);
+    }
+
+    /// Is an IME composition in progress?
+    pub fn is_composing(&self) -> bool {
+        self.composition.get().is_some()
+    }
+
+    /// Text as displayed: the content with any preedit spliced in at the cursor
+    pub fn display_text(&self) -> String {
+        let mut text = self.get_text();
+        if let Some(composition) = self.composition.get() {
+            text.insert_str(self.cursor_position.get(), &composition.text);
+        }
+        text
+    }
+
+    /// Caret rect (x, y, width, height) with the preedit caret taken into account
+    pub fn caret_area(&self) -> (f32, f32, f32, f32) {
+        let text = self.get_text();
+        let cursor = self.cursor_position.get();
+        let mut before = text[..cursor].to_string();
+        if let Some(composition) = self.composition.get() {
+            let caret = composition.cursor.map_or(composition.text.len(), |(start, _)| start.min(composition.text.len()));
+            before.push_str(composition.text.get(..caret).unwrap_or(&composition.text));
+        }
+        let font_size = ThemeProvider::current().font_size;
+        let x = self.position.0 + TEXT_PADDING + estimate_text_width(&before, font_size);
+        (x, self.position.1, 1.0, self.height)
+    }
+
     /// Submit (Enter key)
     pub fn submit(&self) {
         let text = self.get_text();
@@ -344,6 +484,62 @@ impl TextField {
     }
 }
 
+impl Focusable for TextField {
+    fn set_focused(&self, focused: bool) {
+        if focused {
+            self.focus();
+        } else {
+            self.blur();
+        }
+    }
+
+    fn handle_key(&self, key: FocusKey, _shift: bool) -> bool {
+        // The input method owns the keyboard while composing
+        if self.is_composing() {
+            return key != FocusKey::Tab;
+        }
+        match key {
+            FocusKey::Char(c) => self.insert_char(c),
+            FocusKey::Space => self.insert_char(' '),
+            FocusKey::Backspace => self.delete_before_cursor(),
+            FocusKey::Delete => self.delete_at_cursor(),
+            FocusKey::Left => self.move_cursor_left(),
+            FocusKey::Right => self.move_cursor_right(),
+            FocusKey::Home => self.move_cursor_to_start(),
+            FocusKey::End => self.move_cursor_to_end(),
+            FocusKey::Enter => self.submit(),
+            FocusKey::Tab | FocusKey::Escape | FocusKey::Up | FocusKey::Down => return false,
+        }
+        true
+    }
+
+    fn focus_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        Some(self.bounds())
+    }
+
+    fn handle_ime(&self, event: &ImeEvent) -> bool {
+        match event {
+            ImeEvent::Enabled => {}
+            ImeEvent::Preedit { text, cursor } => self.set_composition(text.clone(), *cursor),
+            ImeEvent::Commit(text) => self.commit_composition(text),
+            ImeEvent::Disabled => self.composition.set(None),
+        }
+        true
+    }
+
+    fn ime_cursor_area(&self) -> Option<(f32, f32, f32, f32)> {
+        Some(self.caret_area())
+    }
+}
+
+/// Gap between the field's edge and its text
+const TEXT_PADDING: f32 = 8.0;
+
+/// Byte index of the character before `cursor`
+fn previous_boundary(text: &str, cursor: usize) -> Option<usize> {
+    text[..cursor].char_indices().next_back().map(|(index, _)| index)
+}
+
 impl Default for TextField {
     fn default() -> Self {
         Self::new()
@@ -353,7 +549,7 @@ impl Default for TextField {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::cell::RefCell;
+    use crate::spellcheck::WordListDictionary;
 
     #[test]
     fn textfield_creation() {
@@ -581,4 +777,89 @@ mod tests {
         assert_eq!(layout.size.width, 300.0);
         assert_eq!(layout.size.height, 50.0);
     }
+
+    #[test]
+    fn textfield_spell_check() {
+        let dictionary = Rc::new(RefCell::new(WordListDictionary::from_words(["hello", "world"])));
+        let field = TextField::with_text("helo world").spell_check(dictionary);
+        assert!(field.is_spell_check_enabled());
+
+        let misspellings = field.misspellings();
+        assert_eq!(misspellings.len(), 1);
+        assert!(field.misspelling_at(7).is_none());
+
+        let menu = field.spelling_menu_at(2).unwrap();
+        let action = menu.items[0].action.clone();
+        assert!(field.apply_spelling_action(&action, &misspellings[0]));
+        assert_eq!(field.get_text(), "hello world");
+        assert!(field.misspellings().is_empty());
+    }
+
+    #[test]
+    fn textfield_spell_check_add_word() {
+        let dictionary = Rc::new(RefCell::new(WordListDictionary::new()));
+        let field = TextField::with_text("nebula").spell_check(dictionary);
+        let misspelling = field.misspelling_at(0).unwrap();
+        assert!(field.apply_spelling_action(spellcheck::ADD_TO_DICTIONARY_ACTION, &misspelling));
+        assert!(field.misspellings().is_empty());
+        assert!(TextField::new().misspellings().is_empty());
+    }
+
+    #[test]
+    fn textfield_keyboard_through_focus_manager() {
+        use crate::{Button, Checkbox};
+        use nebula_core::FocusManager;
+        use std::cell::Cell;
+
+        let field = TextField::new();
+        let checkbox = Checkbox::new();
+        let clicks = Rc::new(Cell::new(0));
+        let counter = clicks.clone();
+        let button = Button::new("Send").on_click(move || counter.set(counter.get() + 1));
+
+        let mut focus = FocusManager::new();
+        focus.register(Rc::new(field.clone()));
+        focus.register(Rc::new(checkbox.clone()));
+        focus.register(Rc::new(button.clone()));
+
+        focus.handle_key(FocusKey::Tab, false);
+        assert!(field.is_focused());
+        for key in [FocusKey::Char('h'), FocusKey::Char('i'), FocusKey::Left, FocusKey::Char('!')] {
+            focus.handle_key(key, false);
+        }
+        assert_eq!(field.get_text(), "h!i");
+
+        focus.handle_key(FocusKey::Tab, false);
+        assert!(!field.is_focused());
+        focus.handle_key(FocusKey::Space, false);
+        assert!(checkbox.is_checked());
+
+        focus.handle_key(FocusKey::Tab, false);
+        focus.handle_key(FocusKey::Enter, false);
+        assert_eq!(clicks.get(), 1);
+    }
+
+    #[test]
+    fn ime_composes_cjk_text() {
+        let field = TextField::with_text("ab").max_length(5);
+        let mut focus = nebula_core::FocusManager::new();
+        let id = focus.register(Rc::new(field.clone()));
+        focus.request_focus(id);
+        field.move_cursor_left();
+
+        focus.handle_ime(&ImeEvent::Preedit { text: "にほん".to_string(), cursor: Some((6, 6)) });
+        assert_eq!(field.display_text(), "aにほんb");
+        assert_eq!(field.get_text(), "ab");
+        let (caret_x, ..) = focus.ime_cursor_area().unwrap();
+        assert_eq!(caret_x, TEXT_PADDING + estimate_text_width("aにほ", ThemeProvider::current().font_size));
+        assert!(focus.handle_key(FocusKey::Backspace, false)); // Owned by the IME
+        assert_eq!(field.get_text(), "ab");
+
+        focus.handle_ime(&ImeEvent::Commit("日本語です".to_string()));
+        assert!(!field.is_composing());
+        assert_eq!(field.get_text(), "a日本語b"); // Cut at max_length
+        field.delete_before_cursor();
+        field.move_cursor_left();
+        assert_eq!((field.get_text().as_str(), field.get_cursor_position()), ("a日本b", 4));
+    }
 }
@@ -3,6 +3,9 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use crate::calendar::CalendarDate;
+use crate::locale::{DateFormat, Locale};
 
 /// Timeline item
 #[derive(Debug, Clone, PartialEq)]
@@ -12,7 +15,7 @@ pub struct TimelineItem {
     pub description: Option<String>,
     pub timestamp: String,
     pub icon: Option<String>,
-    pub color: Option<(u8, u8, u8, u8)>,
+    pub color: Option<ThemeColor>,
     pub metadata: Option<String>,
 }
 
@@ -80,19 +83,23 @@ pub struct Timeline {
     pub line_width: f32,
     pub dot_size: f32,
     pub spacing: f32,
-    pub line_color: (u8, u8, u8, u8),
-    pub dot_color: (u8, u8, u8, u8),
-    pub background_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
-    pub timestamp_color: (u8, u8, u8, u8),
+    pub line_color: ThemeColor,
+    pub dot_color: ThemeColor,
+    pub background_color: ThemeColor,
+    pub text_color: ThemeColor,
+    pub timestamp_color: ThemeColor,
     pub show_icons: bool,
     pub clickable: bool,
     pub on_item_click: Option<Box<dyn Fn(&str)>>,
+    /// Locale and format for `add_dated_item`
+    pub locale: Locale,
+    pub date_format: DateFormat,
 }
 
 impl Timeline {
-    /// Create a new Timeline component
+    /// Create a new Timeline component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             items: Vec::new(),
@@ -100,17 +107,35 @@ impl Timeline {
             line_width: 2.0,
             dot_size: 12.0,
             spacing: 32.0,
-            line_color: (220, 220, 220, 255),
-            dot_color: (59, 130, 246, 255), // Blue
-            background_color: (255, 255, 255, 255),
-            text_color: (0, 0, 0, 255),
-            timestamp_color: (100, 100, 100, 255),
+            line_color: colors.border,
+            dot_color: colors.primary,
+            background_color: colors.surface,
+            text_color: colors.text,
+            timestamp_color: colors.text_muted,
             show_icons: true,
             clickable: false,
             on_item_click: None,
+            locale: Locale::default(),
+            date_format: DateFormat::Medium,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.line_color = colors.border;
+        self.dot_color = colors.primary;
+        self.background_color = colors.surface;
+        self.text_color = colors.text;
+        self.timestamp_color = colors.text_muted;
+    }
+
     /// Set the mode
     pub fn mode(mut self, mode: TimelineMode) -> Self {
         self.mode = mode;
@@ -165,6 +190,20 @@ impl Timeline {
         self
     }
 
+    /// Set the locale and format used by `add_dated_item`
+    pub fn date_format(mut self, locale: Locale, format: DateFormat) -> Self {
+        self.locale = locale;
+        self.date_format = format;
+        self
+    }
+
+    /// Add a timeline item stamped with a date, formatted in the timeline's locale
+    pub fn add_dated_item(mut self, id: impl Into<String>, title: impl Into<String>, date: CalendarDate) -> Self {
+        let timestamp = date.format_with(&self.locale, &self.date_format);
+        self.items.push(TimelineItem::new(id, title, timestamp));
+        self
+    }
+
     /// Add a timeline item object
     pub fn add_item_object(mut self, item: TimelineItem) -> Self {
         self.items.push(item);
@@ -0,0 +1,370 @@
+// TimePicker Component - Hour/minute/second selection in 12h or 24h
+// Pairs with DatePicker and Calendar ranges for scheduling
+
+use nebula_core::events::EventQueue;
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use crate::calendar::CalendarDate;
+use crate::events::PickerEvent;
+use crate::locale::Locale;
+
+/// Time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    pub hour: u8,   // 0-23
+    pub minute: u8, // 0-59
+    pub second: u8, // 0-59
+}
+
+impl Time {
+    /// Create a time (out-of-range parts are clamped)
+    pub fn new(hour: u8, minute: u8, second: u8) -> Self {
+        Self { hour: hour.min(23), minute: minute.min(59), second: second.min(59) }
+    }
+
+    /// Midnight
+    pub fn midnight() -> Self {
+        Self::new(0, 0, 0)
+    }
+
+    /// Seconds since midnight
+    pub fn seconds(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+
+    /// Time from seconds since midnight (wraps around the day)
+    pub fn from_seconds(seconds: i64) -> Self {
+        let seconds = seconds.rem_euclid(86_400);
+        Self::new((seconds / 3600) as u8, (seconds / 60 % 60) as u8, (seconds % 60) as u8)
+    }
+
+    /// Is this in the afternoon (12:00 or later)?
+    pub fn is_pm(&self) -> bool {
+        self.hour >= 12
+    }
+
+    /// Parse "14:30", "14:30:15", "2:30 pm" or "2pm"
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+        let (clock, meridiem) = if let Some(rest) = text.strip_suffix("am").or_else(|| text.strip_suffix("a.m.")) {
+            (rest.trim(), Some(false))
+        } else if let Some(rest) = text.strip_suffix("pm").or_else(|| text.strip_suffix("p.m.")) {
+            (rest.trim(), Some(true))
+        } else {
+            (text.as_str(), None)
+        };
+
+        let parts: Vec<&str> = clock.split([':', '.']).collect();
+        if parts.is_empty() || parts.len() > 3 || (parts.len() == 1 && meridiem.is_none()) {
+            return None;
+        }
+        let mut numbers = parts.iter().map(|part| part.trim().parse::<u8>().ok());
+        let hour = numbers.next()??;
+        let minute = numbers.next().unwrap_or(Some(0))?;
+        let second = numbers.next().unwrap_or(Some(0))?;
+        if minute > 59 || second > 59 {
+            return None;
+        }
+        let hour = match meridiem {
+            Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+            Some(_) => return None,
+            None if hour <= 23 => hour,
+            None => return None,
+        };
+        Some(Self::new(hour, minute, second))
+    }
+
+    /// Format as "14:30" / "2:30 PM" (with seconds if asked)
+    pub fn format(&self, twelve_hour: bool, show_seconds: bool) -> String {
+        let seconds = if show_seconds { format!(":{:02}", self.second) } else { String::new() };
+        if twelve_hour {
+            let hour = if self.hour.is_multiple_of(12) { 12 } else { self.hour % 12 };
+            format!("{}:{:02}{} {}", hour, self.minute, seconds, if self.is_pm() { "PM" } else { "AM" })
+        } else {
+            format!("{:02}:{:02}{}", self.hour, self.minute, seconds)
+        }
+    }
+}
+
+/// A date and a time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub date: CalendarDate,
+    pub time: Time,
+}
+
+impl DateTime {
+    /// Combine a date and a time
+    pub fn new(date: CalendarDate, time: Time) -> Self {
+        Self { date, time }
+    }
+
+    /// Seconds from `other` to this (positive when this is later)
+    pub fn diff_seconds(&self, other: &DateTime) -> i64 {
+        self.date.diff(&other.date) * 86_400 + self.time.seconds() as i64 - other.time.seconds() as i64
+    }
+
+    /// Start and end of a range; an end before the start is an error
+    pub fn range(start: DateTime, end: DateTime) -> Result<(DateTime, DateTime), String> {
+        if end < start {
+            return Err(format!("End {} {} is before start {} {}", end.date.format(), end.time.format(false, false), start.date.format(), start.time.format(false, false)));
+        }
+        Ok((start, end))
+    }
+}
+
+/// TimePicker component - pick a time of day 🕐
+///
+/// # Example
+/// ```rust,ignore
+/// let mut picker = TimePicker::new()
+///     .locale(Locale::en_us()) // 12-hour with AM/PM
+///     .minute_step(15)
+///     .on_change(|time| println!("Picked: {}", time.format(false, false)));
+/// picker.select_from_input("2:30 pm")?;
+/// ```
+pub struct TimePicker {
+    pub node_id: Option<NodeId>,
+    pub selected_time: Signal<Option<Time>>,
+    pub twelve_hour: bool,
+    pub show_seconds: bool,
+    /// Minutes moved by `step_minute` (and allowed when typing)
+    pub minute_step: u8,
+    pub min_time: Option<Time>,
+    pub max_time: Option<Time>,
+    pub disabled: bool,
+    pub width: f32,
+    pub height: f32,
+    pub on_change: Option<Box<dyn Fn(Time)>>,
+    /// Typed events, for apps that drain a queue instead of using callbacks
+    pub events: Option<EventQueue<PickerEvent>>,
+}
+
+impl TimePicker {
+    /// Create a new TimePicker (24-hour, minutes)
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            selected_time: Signal::new(None),
+            twelve_hour: false,
+            show_seconds: false,
+            minute_step: 1,
+            min_time: None,
+            max_time: None,
+            disabled: false,
+            width: 120.0,
+            height: 40.0,
+            on_change: None,
+            events: None,
+        }
+    }
+
+    /// Set the selected time
+    pub fn selected_time(self, time: Time) -> Self {
+        self.selected_time.set(Some(time));
+        self
+    }
+
+    /// Use the locale's clock (12h with AM/PM or 24h)
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.twelve_hour = locale.twelve_hour_clock;
+        self
+    }
+
+    /// Force 12-hour (true) or 24-hour (false) display
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Show and edit seconds
+    pub fn show_seconds(mut self, show: bool) -> Self {
+        self.show_seconds = show;
+        self
+    }
+
+    /// Snap minutes to a step (e.g. 15 for quarter hours)
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.clamp(1, 60);
+        self
+    }
+
+    /// Set the earliest selectable time
+    pub fn min_time(mut self, time: Time) -> Self {
+        self.min_time = Some(time);
+        self
+    }
+
+    /// Set the latest selectable time
+    pub fn max_time(mut self, time: Time) -> Self {
+        self.max_time = Some(time);
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the change callback
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Time) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Emit `PickerEvent::TimeChanged` into a queue (alongside `on_change`)
+    pub fn events(mut self, events: &EventQueue<PickerEvent>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    /// Is a time inside min/max and on the minute step?
+    pub fn is_time_selectable(&self, time: &Time) -> bool {
+        self.min_time.is_none_or(|min| *time >= min)
+            && self.max_time.is_none_or(|max| *time <= max)
+            && time.minute.is_multiple_of(self.minute_step)
+    }
+
+    /// Select a time
+    pub fn select_time(&mut self, time: Time) {
+        if self.disabled || !self.is_time_selectable(&time) {
+            return;
+        }
+        self.selected_time.set(Some(time));
+        if let Some(ref callback) = self.on_change {
+            callback(time);
+        }
+        if let Some(ref events) = self.events {
+            events.emit(PickerEvent::TimeChanged(time));
+        }
+    }
+
+    /// Select a time from text typed into the field
+    pub fn select_from_input(&mut self, input: &str) -> Result<Time, String> {
+        if self.disabled {
+            return Err("TimePicker is disabled".to_string());
+        }
+        let time = Time::parse(input).ok_or_else(|| format!("Could not parse time '{}'", input))?;
+        if !self.is_time_selectable(&time) {
+            return Err(format!("Time {} is not available", time.format(self.twelve_hour, self.show_seconds)));
+        }
+        self.select_time(time);
+        Ok(time)
+    }
+
+    /// Move the hour up or down (wraps around midnight)
+    pub fn step_hour(&mut self, delta: i32) {
+        self.step_seconds(delta as i64 * 3600);
+    }
+
+    /// Move the minute by `delta` steps (wraps around midnight)
+    pub fn step_minute(&mut self, delta: i32) {
+        self.step_seconds(delta as i64 * self.minute_step as i64 * 60);
+    }
+
+    /// Switch between AM and PM
+    pub fn toggle_meridiem(&mut self) {
+        self.step_seconds(12 * 3600);
+    }
+
+    fn step_seconds(&mut self, delta: i64) {
+        let current = self.get_selected_time().unwrap_or_else(Time::midnight);
+        // Snap to the step first so stepping from 10:07 by 15 lands on 10:15
+        let step = self.minute_step as i64 * 60;
+        let base = current.seconds() as i64 / step * step;
+        self.select_time(Time::from_seconds(base + delta));
+    }
+
+    /// Get the selected time
+    pub fn get_selected_time(&self) -> Option<Time> {
+        self.selected_time.get()
+    }
+
+    /// Clear the selected time
+    pub fn clear(&mut self) {
+        self.selected_time.set(None);
+    }
+
+    /// Selected time as shown in the field
+    pub fn display_text(&self) -> Option<String> {
+        self.get_selected_time().map(|time| time.format(self.twelve_hour, self.show_seconds))
+    }
+
+    /// Placeholder hint for the input field
+    pub fn placeholder(&self) -> &'static str {
+        match (self.twelve_hour, self.show_seconds) {
+            (true, true) => "hh:mm:ss AM",
+            (true, false) => "hh:mm AM",
+            (false, true) => "HH:MM:SS",
+            (false, false) => "HH:MM",
+        }
+    }
+
+    /// Build the timepicker layout
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let style = taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Length(self.width),
+                height: taffy::style::Dimension::Length(self.height),
+            },
+            display: taffy::style::Display::Flex,
+            align_items: Some(taffy::style::AlignItems::Center),
+            ..Default::default()
+        };
+
+        let node = engine
+            .new_leaf(style)
+            .map_err(|e| format!("Failed to create timepicker node: {:?}", e))?;
+        self.node_id = Some(node);
+
+        Ok(node)
+    }
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_both_clocks() {
+        assert_eq!(Time::parse("14:30"), Some(Time::new(14, 30, 0)));
+        assert_eq!(Time::parse("2:30 pm"), Some(Time::new(14, 30, 0)));
+        assert_eq!(Time::parse("12am"), Some(Time::midnight()));
+        assert_eq!(Time::parse("09.05.30"), Some(Time::new(9, 5, 30)));
+        assert_eq!(Time::parse("13pm"), None);
+        assert_eq!(Time::parse("24:00"), None);
+        assert_eq!(Time::new(0, 5, 0).format(true, false), "12:05 AM");
+        assert_eq!(Time::new(14, 5, 9).format(false, true), "14:05:09");
+    }
+
+    #[test]
+    fn steps_wrap_and_respect_limits() {
+        let mut picker = TimePicker::new().locale(Locale::en_us()).minute_step(15).min_time(Time::new(8, 0, 0));
+        assert!(picker.select_from_input("8:07").is_err()); // Off the step
+        picker.select_from_input("11:45 am").unwrap();
+        picker.step_minute(1);
+        assert_eq!(picker.display_text().as_deref(), Some("12:00 PM"));
+        picker.toggle_meridiem();
+        assert_eq!(picker.get_selected_time(), Some(Time::new(12, 0, 0))); // Midnight is before 8:00
+
+        picker.min_time = None;
+        picker.toggle_meridiem();
+        picker.step_hour(-1);
+        assert_eq!(picker.get_selected_time(), Some(Time::new(23, 0, 0)));
+
+        let start = DateTime::new(CalendarDate::new(2025, 11, 22), Time::new(23, 0, 0));
+        let end = DateTime::new(CalendarDate::new(2025, 11, 23), Time::new(1, 30, 0));
+        assert_eq!(end.diff_seconds(&start), 9000);
+        assert!(DateTime::range(end, start).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+// Titlebar Component - Custom-drawn titlebar for borderless windows
+// A drag region plus minimize / maximize / close Buttons; the app hands the actions to NebulaWindow
+
+use nebula_core::{Signal, ThemeProvider};
+use nebula_gfx::draw_list::DrawList;
+use tracing::info;
+
+use super::button::Button;
+
+/// What the titlebar wants the window to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitlebarAction {
+    /// Mouse pressed on the drag region: start moving the window
+    Drag,
+    Minimize,
+    ToggleMaximize,
+    Close,
+}
+
+/// Titlebar - Window chrome you draw yourself 🪟
+///
+/// Use with `NebulaWindow::with_decorations(false)`. Mouse handlers
+/// return the action to perform; map it to `WindowAction` and return it
+/// from `RenderCallback::take_window_action`.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut titlebar = Titlebar::new("Notes");
+/// titlebar.layout(window_width);
+///
+/// // in on_mouse_down:
+/// self.pending = titlebar.handle_mouse_down(x, y).map(|action| match action {
+///     TitlebarAction::Drag => WindowAction::Drag,
+///     TitlebarAction::Minimize => WindowAction::Minimize,
+///     TitlebarAction::ToggleMaximize => WindowAction::ToggleMaximize,
+///     TitlebarAction::Close => WindowAction::Close,
+/// });
+/// ```
+pub struct Titlebar {
+    pub title: String,
+    pub height: f32,
+    pub button_width: f32,
+    /// Window is maximized (swaps the maximize glyph for restore)
+    pub maximized: Signal<bool>,
+    minimize: Button,
+    maximize: Button,
+    close: Button,
+    width: f32,
+    hovered: Option<TitlebarAction>,
+}
+
+impl Titlebar {
+    /// Titlebar showing a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            height: 32.0,
+            button_width: 46.0,
+            maximized: Signal::new(false),
+            minimize: Button::new("—").test_id("titlebar.minimize"),
+            maximize: Button::new("☐").test_id("titlebar.maximize"),
+            close: Button::new("✕").test_id("titlebar.close"),
+            width: 0.0,
+            hovered: None,
+        }
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Place the buttons for a window `width` logical pixels wide
+    pub fn layout(&mut self, width: f32) {
+        self.width = width;
+        let size = (self.button_width, self.height);
+        for (slot, button) in [&mut self.close, &mut self.maximize, &mut self.minimize].into_iter().enumerate() {
+            button.position = (width - size.0 * (slot + 1) as f32, 0.0);
+            button.size = size;
+        }
+    }
+
+    /// Is the point in the titlebar but not on a button?
+    pub fn is_drag_region(&self, x: f32, y: f32) -> bool {
+        y >= 0.0 && y <= self.height && x >= 0.0 && x <= self.width && self.button_at(x, y).is_none()
+    }
+
+    /// Mouse pressed: `Drag` on the drag region, otherwise arms a button
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<TitlebarAction> {
+        for button in [&self.minimize, &self.maximize, &self.close] {
+            if button.handle_mouse_down(x, y) {
+                return None;
+            }
+        }
+        self.is_drag_region(x, y).then_some(TitlebarAction::Drag)
+    }
+
+    /// Mouse released: the action of the button that was pressed and released on
+    pub fn handle_mouse_up(&mut self, x: f32, y: f32) -> Option<TitlebarAction> {
+        let action = [
+            (&self.minimize, TitlebarAction::Minimize),
+            (&self.maximize, TitlebarAction::ToggleMaximize),
+            (&self.close, TitlebarAction::Close),
+        ]
+        .into_iter()
+        .find(|(button, _)| button.handle_mouse_up(x, y))
+        .map(|(_, action)| action);
+        if action == Some(TitlebarAction::ToggleMaximize) {
+            self.maximized.set(!self.maximized.get());
+        }
+        if let Some(action) = action {
+            info!("🪟 Titlebar {:?}", action);
+        }
+        action
+    }
+
+    /// Double-click on the drag region maximizes or restores
+    pub fn handle_double_click(&mut self, x: f32, y: f32) -> Option<TitlebarAction> {
+        if !self.is_drag_region(x, y) {
+            return None;
+        }
+        self.maximized.set(!self.maximized.get());
+        Some(TitlebarAction::ToggleMaximize)
+    }
+
+    /// Track the hovered button
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        self.hovered = self.button_at(x, y);
+    }
+
+    /// Draw the bar, title and buttons (call `layout` first)
+    pub fn paint(&self, list: &mut DrawList) {
+        let theme = ThemeProvider::current();
+        let colors = theme.colors;
+        list.rect((0.0, 0.0, self.width, self.height), colors.surface_variant);
+        let text_y = (self.height - theme.font_size * 1.2) / 2.0;
+        list.text(self.title.clone(), 12.0, text_y, theme.font_size, colors.text);
+
+        let maximize_glyph = if self.maximized.get() { "❐" } else { "☐" };
+        for (button, action, glyph) in [
+            (&self.minimize, TitlebarAction::Minimize, "—"),
+            (&self.maximize, TitlebarAction::ToggleMaximize, maximize_glyph),
+            (&self.close, TitlebarAction::Close, "✕"),
+        ] {
+            let (x, y, width, height) = button.bounds();
+            let hovered = self.hovered == Some(action);
+            let (background, text) = match (action, hovered || button.is_pressed.get()) {
+                (TitlebarAction::Close, true) => (Some(colors.danger), (255, 255, 255, 255)),
+                (_, true) => (Some(colors.hover), colors.text),
+                _ => (None, colors.text),
+            };
+            if let Some(background) = background {
+                list.rect((x, y, width, height), background);
+            }
+            list.text(glyph, x + (width - theme.font_size * 0.6) / 2.0, y + text_y, theme.font_size, text);
+        }
+    }
+
+    fn button_at(&self, x: f32, y: f32) -> Option<TitlebarAction> {
+        if self.minimize.is_point_inside(x, y) {
+            Some(TitlebarAction::Minimize)
+        } else if self.maximize.is_point_inside(x, y) {
+            Some(TitlebarAction::ToggleMaximize)
+        } else if self.close.is_point_inside(x, y) {
+            Some(TitlebarAction::Close)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn titlebar_drags_and_clicks_buttons() {
+        let mut titlebar = Titlebar::new("Notes");
+        titlebar.layout(800.0);
+
+        assert_eq!(titlebar.handle_mouse_down(100.0, 10.0), Some(TitlebarAction::Drag));
+        assert_eq!(titlebar.handle_mouse_down(100.0, 50.0), None);
+
+        // Close is the rightmost 46px
+        assert_eq!(titlebar.handle_mouse_down(780.0, 10.0), None);
+        assert_eq!(titlebar.handle_mouse_up(780.0, 10.0), Some(TitlebarAction::Close));
+
+        // Released off the button: nothing
+        titlebar.handle_mouse_down(730.0, 10.0);
+        assert_eq!(titlebar.handle_mouse_up(300.0, 10.0), None);
+
+        titlebar.handle_mouse_down(730.0, 10.0);
+        assert_eq!(titlebar.handle_mouse_up(730.0, 10.0), Some(TitlebarAction::ToggleMaximize));
+        assert!(titlebar.maximized.get());
+        assert_eq!(titlebar.handle_double_click(100.0, 10.0), Some(TitlebarAction::ToggleMaximize));
+        assert!(!titlebar.maximized.get());
+
+        let mut list = DrawList::new();
+        titlebar.paint(&mut list);
+        assert!(list.len() >= 5);
+    }
+}
@@ -2,7 +2,10 @@
 // Auto-dismissing notifications that appear at screen edges
 
 use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::accessibility::contrast_ratio;
 use nebula_core::signal::Signal;
+use nebula_core::theme::ThemeProvider;
+use nebula_core::{announce, Politeness};
 
 /// Toast type determines the visual style and icon
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,6 +52,7 @@ pub struct Toast {
     pub border_radius: f32,
     pub closable: bool,
     pub show_icon: bool,
+    pub politeness: Option<Politeness>, // None = by type (errors interrupt)
     pub on_close: Option<Box<dyn Fn()>>,
     pub on_click: Option<Box<dyn Fn()>>,
 }
@@ -71,6 +75,7 @@ impl Toast {
             border_radius: 8.0,
             closable: true,
             show_icon: true,
+            politeness: None,
             on_close: None,
             on_click: None,
         }
@@ -166,9 +171,24 @@ impl Toast {
         self
     }
 
-    /// Show the toast
+    /// How screen readers announce the toast
+    pub fn politeness(mut self, politeness: Politeness) -> Self {
+        self.politeness = Some(politeness);
+        self
+    }
+
+    /// Show the toast (and announce it to screen readers)
     pub fn show(&mut self) {
         self.is_visible.set(true);
+        announce(self.message.clone(), self.get_politeness());
+    }
+
+    /// The politeness used when shown
+    pub fn get_politeness(&self) -> Politeness {
+        self.politeness.unwrap_or(match self.toast_type {
+            ToastType::Error => Politeness::Assertive,
+            _ => Politeness::Polite,
+        })
     }
 
     /// Hide the toast
@@ -196,13 +216,18 @@ impl Toast {
         self.hide();
     }
 
-    /// Get the default background color for the toast type
+    /// Get the default background color for the toast type (from the theme;
+    /// plain surface in high contrast, where the icon carries the type)
     pub fn get_default_background_color(&self) -> (u8, u8, u8, u8) {
+        let theme = ThemeProvider::current();
+        if theme.high_contrast {
+            return theme.colors.surface;
+        }
         match self.toast_type {
-            ToastType::Info => (59, 130, 246, 255),     // Blue
-            ToastType::Success => (34, 197, 94, 255),   // Green
-            ToastType::Warning => (251, 146, 60, 255),  // Orange
-            ToastType::Error => (239, 68, 68, 255),     // Red
+            ToastType::Info => theme.colors.primary,
+            ToastType::Success => theme.colors.success,
+            ToastType::Warning => theme.colors.warning,
+            ToastType::Error => theme.colors.danger,
         }
     }
 
@@ -212,9 +237,14 @@ impl Toast {
             .unwrap_or_else(|| self.get_default_background_color())
     }
 
-    /// Get the text color (custom or default white)
+    /// Get the text color (custom, or black or white, whichever reads
+    /// better on the background)
     pub fn get_text_color(&self) -> (u8, u8, u8, u8) {
-        self.text_color.unwrap_or((255, 255, 255, 255))
+        self.text_color.unwrap_or_else(|| {
+            let background = self.get_background_color();
+            let (white, black) = ((255, 255, 255, 255), (0, 0, 0, 255));
+            if contrast_ratio(white, background) >= contrast_ratio(black, background) { white } else { black }
+        })
     }
 
     /// Check if toast should auto-dismiss
@@ -296,6 +326,20 @@ mod tests {
         assert!(toast.is_visible());
     }
 
+    #[test]
+    fn toast_show_is_announced() {
+        nebula_core::take_announcements();
+        Toast::new("Saved").show();
+        Toast::new("Offline").toast_type(ToastType::Error).show();
+        Toast::new("Quiet").politeness(Politeness::Off).show();
+
+        let announcements = nebula_core::take_announcements();
+        assert_eq!(announcements.len(), 2);
+        assert_eq!(announcements[0].politeness, Politeness::Polite);
+        assert_eq!(announcements[1].text, "Offline");
+        assert_eq!(announcements[1].politeness, Politeness::Assertive);
+    }
+
     #[test]
     fn toast_can_be_hidden() {
         let mut toast = Toast::new("Test");
@@ -338,7 +382,8 @@ mod tests {
         assert_eq!(success.get_default_background_color(), (34, 197, 94, 255));
 
         let warning = Toast::new("Warning").toast_type(ToastType::Warning);
-        assert_eq!(warning.get_default_background_color(), (251, 146, 60, 255));
+        assert_eq!(warning.get_default_background_color(), (245, 158, 11, 255));
+        assert_eq!(warning.get_text_color(), (0, 0, 0, 255)); // Dark text on amber
 
         let error = Toast::new("Error").toast_type(ToastType::Error);
         assert_eq!(error.get_default_background_color(), (239, 68, 68, 255));
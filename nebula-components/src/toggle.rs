@@ -3,6 +3,7 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
 
 /// Toggle component - toggle switch for boolean values
 /// 
@@ -22,10 +23,10 @@ pub struct Toggle {
     pub width: f32,
     pub height: f32,
     pub thumb_size: f32,
-    pub track_color_off: (u8, u8, u8, u8),
-    pub track_color_on: (u8, u8, u8, u8),
-    pub thumb_color: (u8, u8, u8, u8),
-    pub disabled_color: (u8, u8, u8, u8),
+    pub track_color_off: ThemeColor,
+    pub track_color_on: ThemeColor,
+    pub thumb_color: ThemeColor,
+    pub disabled_color: ThemeColor,
     pub on_change: Option<Box<dyn Fn(bool)>>,
 }
 
@@ -39,8 +40,9 @@ pub enum LabelPosition {
 }
 
 impl Toggle {
-    /// Create a new Toggle component
+    /// Create a new Toggle component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             checked: Signal::new(false),
@@ -50,14 +52,29 @@ impl Toggle {
             width: 48.0,
             height: 28.0,
             thumb_size: 24.0,
-            track_color_off: (200, 200, 200, 255),
-            track_color_on: (59, 130, 246, 255), // Blue
-            thumb_color: (255, 255, 255, 255),
-            disabled_color: (220, 220, 220, 255),
+            track_color_off: colors.border,
+            track_color_on: colors.primary,
+            thumb_color: colors.surface,
+            disabled_color: colors.disabled,
             on_change: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.track_color_off = colors.border;
+        self.track_color_on = colors.primary;
+        self.thumb_color = colors.surface;
+        self.disabled_color = colors.disabled;
+    }
+
     /// Set the checked state
     pub fn checked(self, checked: bool) -> Self {
         self.checked.set(checked);
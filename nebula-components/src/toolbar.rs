@@ -0,0 +1,313 @@
+// Toolbar Component - Row of action buttons under the menu bar
+// Buttons dispatch action ids, so they share commands with menus and shortcuts
+
+use std::rc::Rc;
+
+use nebula_core::command::CommandBus;
+use nebula_core::hit_test::{EventDispatcher, PointerEventKind};
+use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::theme::ThemeProvider;
+
+use super::shortcuts::ShortcutRegistry;
+
+type ActionCallback = Rc<dyn Fn(&str)>;
+
+/// Toolbar button (or separator)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolbarItem {
+    pub action: String,
+    pub label: String,
+    pub icon: Option<String>,
+    pub tooltip: Option<String>,
+    /// Shortcut hint shown in the tooltip ("Ctrl+Z")
+    pub shortcut: Option<String>,
+    pub disabled: bool,
+    pub is_separator: bool,
+}
+
+impl ToolbarItem {
+    /// Create a text button
+    pub fn new(label: impl Into<String>, action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            label: label.into(),
+            icon: None,
+            tooltip: None,
+            shortcut: None,
+            disabled: false,
+            is_separator: false,
+        }
+    }
+
+    /// Create a separator
+    pub fn separator() -> Self {
+        Self { is_separator: true, ..Self::new("", "") }
+    }
+
+    /// Add an icon (shown instead of the label)
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Add a tooltip
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Tooltip text with the shortcut hint, e.g. "Undo (Ctrl+Z)"
+    pub fn tooltip_text(&self) -> String {
+        let text = self.tooltip.clone().unwrap_or_else(|| self.label.clone());
+        match self.shortcut {
+            Some(ref shortcut) => format!("{} ({})", text, shortcut),
+            None => text,
+        }
+    }
+}
+
+/// Toolbar component - row of action buttons
+///
+/// # Example
+/// ```rust,ignore
+/// let mut toolbar = Toolbar::new()
+///     .add_button(ToolbarItem::new("Undo", "edit.undo").with_icon("↩"))
+///     .add_button(ToolbarItem::new("Redo", "edit.redo").with_icon("↪"))
+///     .add_separator()
+///     .add_button(ToolbarItem::new("Delete", "layer.delete").with_icon("🗑"))
+///     .commands(bus.clone());
+/// ```
+pub struct Toolbar {
+    pub node_id: Option<NodeId>,
+    pub items: Vec<ToolbarItem>,
+    pub height: f32,
+    pub button_size: f32,
+    pub spacing: f32,
+    pub padding: f32,
+    pub background_color: (u8, u8, u8, u8),
+    pub icon_color: (u8, u8, u8, u8),
+    pub hover_color: (u8, u8, u8, u8),
+    pub disabled_color: (u8, u8, u8, u8),
+    pub separator_color: (u8, u8, u8, u8),
+    pub on_action: Option<ActionCallback>,
+    /// Actions the bus knows run through it (see `commands`)
+    pub commands: Option<CommandBus>,
+    /// Button layout nodes from the last build (node, item index)
+    pub item_nodes: Vec<(NodeId, usize)>,
+}
+
+impl Toolbar {
+    /// Create a new Toolbar (colors come from the current theme)
+    pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
+        Self {
+            node_id: None,
+            items: Vec::new(),
+            height: 40.0,
+            button_size: 32.0,
+            spacing: 4.0,
+            padding: 4.0,
+            background_color: colors.surface,
+            icon_color: colors.text,
+            hover_color: colors.hover,
+            disabled_color: colors.disabled,
+            separator_color: colors.border,
+            on_action: None,
+            commands: None,
+            item_nodes: Vec::new(),
+        }
+    }
+
+    /// Set the height
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the (square) button size
+    pub fn button_size(mut self, size: f32) -> Self {
+        self.button_size = size;
+        self
+    }
+
+    /// Add a button
+    pub fn add_button(mut self, item: ToolbarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add a separator
+    pub fn add_separator(mut self) -> Self {
+        self.items.push(ToolbarItem::separator());
+        self
+    }
+
+    /// Set the action callback (actions the CommandBus doesn't know)
+    pub fn on_action<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.on_action = Some(Rc::new(callback));
+        self
+    }
+
+    /// Route actions through a CommandBus; buttons follow its enabled state
+    pub fn commands(mut self, bus: CommandBus) -> Self {
+        self.commands = Some(bus);
+        self.sync_commands();
+        self
+    }
+
+    /// Show each button's shortcut in its tooltip
+    pub fn shortcuts(mut self, shortcuts: &ShortcutRegistry) -> Self {
+        for item in self.items.iter_mut().filter(|item| !item.is_separator) {
+            item.shortcut = shortcuts.hint_for(&item.action);
+        }
+        self
+    }
+
+    /// Refresh disabled states from the CommandBus (e.g. after an undo)
+    pub fn sync_commands(&mut self) {
+        if let Some(ref bus) = self.commands {
+            for item in self.items.iter_mut().filter(|item| !item.is_separator && bus.knows(&item.action)) {
+                item.disabled = !bus.is_enabled(&item.action);
+            }
+        }
+    }
+
+    /// Press a button by index; returns true if an action ran
+    pub fn click(&mut self, index: usize) -> bool {
+        let Some(item) = self.items.get(index).filter(|item| !item.disabled && !item.is_separator) else {
+            return false;
+        };
+        let handled = run_action(&item.action, self.commands.as_ref(), self.on_action.as_ref());
+        self.sync_commands();
+        handled
+    }
+
+    /// Build the toolbar layout
+    pub fn build(&mut self, engine: &mut LayoutEngine) -> Result<NodeId, String> {
+        let style = taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Percent(1.0),
+                height: taffy::style::Dimension::Length(self.height),
+            },
+            padding: taffy::geometry::Rect {
+                left: taffy::style::LengthPercentage::Length(self.padding),
+                right: taffy::style::LengthPercentage::Length(self.padding),
+                top: taffy::style::LengthPercentage::Length(self.padding),
+                bottom: taffy::style::LengthPercentage::Length(self.padding),
+            },
+            display: taffy::style::Display::Flex,
+            flex_direction: taffy::style::FlexDirection::Row,
+            align_items: Some(taffy::style::AlignItems::Center),
+            gap: taffy::geometry::Size {
+                width: taffy::style::LengthPercentage::Length(self.spacing),
+                height: taffy::style::LengthPercentage::Length(0.0),
+            },
+            ..Default::default()
+        };
+
+        // One node per button so clicks can be hit-tested
+        let mut children = Vec::with_capacity(self.items.len());
+        self.item_nodes.clear();
+        for (index, item) in self.items.iter().enumerate() {
+            let (width, height) = if item.is_separator { (1.0, self.button_size * 0.6) } else { (self.button_size, self.button_size) };
+            let node = engine
+                .new_leaf(taffy::style::Style {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Length(width),
+                        height: taffy::style::Dimension::Length(height),
+                    },
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                })
+                .map_err(|e| format!("Failed to create toolbar button node: {:?}", e))?;
+            if !item.is_separator {
+                self.item_nodes.push((node, index));
+            }
+            children.push(node);
+        }
+
+        let node = engine
+            .new_with_children(style, &children)
+            .map_err(|e| format!("Failed to create toolbar node: {:?}", e))?;
+        self.node_id = Some(node);
+
+        Ok(node)
+    }
+
+    /// Register click handlers on the built button nodes
+    /// Disabled buttons are skipped; call again after every `build`
+    pub fn attach_events(&self, dispatcher: &mut EventDispatcher) {
+        for &(node, index) in &self.item_nodes {
+            let item = &self.items[index];
+            if item.disabled {
+                continue;
+            }
+            let action = item.action.clone();
+            let commands = self.commands.clone();
+            let on_action = self.on_action.clone();
+            dispatcher.on(node, move |event, _ctx| {
+                if event.kind == PointerEventKind::Click {
+                    run_action(&action, commands.as_ref(), on_action.as_ref());
+                }
+            });
+        }
+    }
+}
+
+/// Bus actions go to the bus, the rest to the callback
+fn run_action(action: &str, commands: Option<&CommandBus>, on_action: Option<&ActionCallback>) -> bool {
+    match commands {
+        Some(bus) if bus.knows(action) => bus.dispatch(action),
+        _ => match on_action {
+            Some(callback) => {
+                callback(action);
+                true
+            }
+            None => false,
+        },
+    }
+}
+
+impl Default for Toolbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nebula_core::command::FnCommand;
+    use nebula_core::signal::Signal;
+
+    #[test]
+    fn toolbar_buttons_run_bus_commands() {
+        let value = Signal::new(0);
+        let bus = CommandBus::new();
+        let target = value.clone();
+        bus.register("value.bump", "Bump", move || {
+            let (apply, revert) = (target.clone(), target.clone());
+            Box::new(FnCommand::new("Bump", move || apply.update(|v| v + 1), move || revert.update(|v| v - 1)))
+        });
+        let mut toolbar = Toolbar::new()
+            .add_button(ToolbarItem::new("Undo", "edit.undo"))
+            .add_separator()
+            .add_button(ToolbarItem::new("Bump", "value.bump"))
+            .commands(bus.clone())
+            .shortcuts(&ShortcutRegistry::standard());
+
+        assert!(toolbar.items[0].disabled);
+        assert_eq!(toolbar.items[0].tooltip_text(), "Undo (Ctrl+Z)");
+        assert!(!toolbar.click(1)); // Separator
+
+        assert!(toolbar.click(2));
+        assert_eq!(value.get(), 1);
+        assert!(!toolbar.items[0].disabled);
+        assert!(toolbar.click(0));
+        assert_eq!(value.get(), 0);
+        assert!(toolbar.items[0].disabled);
+    }
+}
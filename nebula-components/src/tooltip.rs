@@ -1,8 +1,13 @@
 // Tooltip Component - Shows helpful text on hover
 // Lightweight overlay that appears near the target element
 
-use nebula_core::layout::{LayoutEngine, NodeId};
+use nebula_core::layout::{LayoutEngine, NodeId, TextMeasure};
+use nebula_core::accessibility::{AccessibilityAudit, ContrastSource};
 use nebula_core::signal::Signal;
+use nebula_core::text::{TextAlign, TextLayout, TextLines};
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+
+use super::text::estimate_text_width;
 
 /// Tooltip position relative to target
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,8 +40,13 @@ pub struct Tooltip {
     pub delay: u32, // milliseconds before showing
     pub max_width: f32,
     pub padding: f32,
-    pub background_color: (u8, u8, u8, u8), // RGBA
-    pub text_color: (u8, u8, u8, u8),
+    pub font_size: u32,
+    /// Alignment of wrapped lines
+    pub text_align: TextAlign,
+    /// Lines to show before cutting off with an ellipsis (None = all)
+    pub max_lines: Option<usize>,
+    pub background_color: ThemeColor,
+    pub text_color: ThemeColor,
     pub border_radius: f32,
     pub show_arrow: bool,
     pub arrow_size: f32,
@@ -46,8 +56,9 @@ pub struct Tooltip {
 }
 
 impl Tooltip {
-    /// Create a new Tooltip component
+    /// Create a new Tooltip component (colors come from the current theme)
     pub fn new(content: impl Into<String>) -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             content: content.into(),
@@ -57,8 +68,11 @@ impl Tooltip {
             delay: 500,
             max_width: 200.0,
             padding: 8.0,
-            background_color: (45, 45, 45, 255), // Dark gray
-            text_color: (255, 255, 255, 255),    // White
+            font_size: 13,
+            text_align: TextAlign::Left,
+            max_lines: None,
+            background_color: colors.text,
+            text_color: colors.surface,
             border_radius: 4.0,
             show_arrow: true,
             arrow_size: 6.0,
@@ -68,6 +82,39 @@ impl Tooltip {
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.text;
+        self.text_color = colors.surface;
+    }
+
+    /// Set the alignment of wrapped lines
+    pub fn text_align(mut self, align: TextAlign) -> Self {
+        self.text_align = align;
+        self
+    }
+
+    /// Cut long tips off after this many lines
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines.max(1));
+        self
+    }
+
+    /// The content wrapped inside `max_width` (estimated widths, no font needed)
+    pub fn lines(&self) -> TextLines {
+        let mut layout = TextLayout::new(self.font_size).max_width(self.max_width - self.padding * 2.0).align(self.text_align);
+        layout.max_lines = self.max_lines;
+        let font_size = self.font_size as f32;
+        layout.layout(&self.content, &mut |text| estimate_text_width(text, font_size))
+    }
+
     /// Set the tooltip content
     pub fn content(mut self, content: impl Into<String>) -> Self {
         self.content = content.into();
@@ -271,8 +318,11 @@ impl Tooltip {
             ..Default::default()
         };
 
+        // Sized by its text, wrapping at max_width
+        let mut measure = TextMeasure::new(self.content.clone(), self.font_size);
+        measure.max_lines = self.max_lines;
         let node = engine
-            .new_leaf(style)
+            .new_text_leaf(style, measure)
             .map_err(|e| format!("Failed to create tooltip node: {:?}", e))?;
         self.node_id = Some(node);
 
@@ -286,6 +336,16 @@ impl Default for Tooltip {
     }
 }
 
+impl ContrastSource for Tooltip {
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+        let id = match self.node_id {
+            Some(node) => format!("Tooltip({:?})", node),
+            None => "Tooltip".to_string(),
+        };
+        audit.check_contrast(id, self.text_color, self.background_color, false);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +521,26 @@ mod tests {
         tooltip = tooltip.content("Updated");
         assert_eq!(tooltip.content, "Updated");
     }
+
+    #[test]
+    fn long_tips_wrap_inside_max_width() {
+        let tooltip = Tooltip::new("Saves the document and uploads a copy to every connected device").max_lines(2);
+        let lines = tooltip.lines();
+        assert_eq!(lines.lines.len(), 2);
+        assert!(lines.truncated && lines.lines[1].text.ends_with('…'));
+        assert!(lines.width <= tooltip.max_width - tooltip.padding * 2.0);
+
+        let mut engine = LayoutEngine::new();
+        let mut tooltip = tooltip.max_lines(3);
+        tooltip.show();
+        let node = tooltip.build(&mut engine).unwrap();
+        let size = engine
+            .compute_layout(node, taffy::geometry::Size { width: taffy::style::AvailableSpace::MaxContent, height: taffy::style::AvailableSpace::MaxContent })
+            .unwrap()
+            .size;
+        assert!(size.width <= tooltip.max_width && size.width > tooltip.max_width / 2.0);
+        assert!(size.height > 13.0 * 1.2 * 2.0 + tooltip.padding * 2.0);
+    }
 }
 
 // Implement Clone for Tooltip (needed for tests)
@@ -475,6 +555,9 @@ impl Clone for Tooltip {
             delay: self.delay,
             max_width: self.max_width,
             padding: self.padding,
+            font_size: self.font_size,
+            text_align: self.text_align,
+            max_lines: self.max_lines,
             background_color: self.background_color,
             text_color: self.text_color,
             border_radius: self.border_radius,
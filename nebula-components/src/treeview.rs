@@ -3,6 +3,8 @@
 
 use nebula_core::layout::{LayoutEngine, NodeId};
 use nebula_core::signal::Signal;
+use nebula_core::theme::{Theme, ThemeColor, ThemeProvider};
+use super::drag_drop::{DragOutcome, DragPayload, DragSource, DragZone, DropIndicator, DropTarget};
 
 /// Tree node
 #[derive(Debug, Clone, PartialEq)]
@@ -140,12 +142,12 @@ pub struct TreeView {
     pub indent_size: f32,
     pub node_height: f32,
     pub padding: f32,
-    pub background_color: (u8, u8, u8, u8),
-    pub node_color: (u8, u8, u8, u8),
-    pub selected_color: (u8, u8, u8, u8),
-    pub hover_color: (u8, u8, u8, u8),
-    pub text_color: (u8, u8, u8, u8),
-    pub selected_text_color: (u8, u8, u8, u8),
+    pub background_color: ThemeColor,
+    pub node_color: ThemeColor,
+    pub selected_color: ThemeColor,
+    pub hover_color: ThemeColor,
+    pub text_color: ThemeColor,
+    pub selected_text_color: ThemeColor,
     pub expand_icon: String,
     pub collapse_icon: String,
     pub leaf_icon: String,
@@ -153,11 +155,14 @@ pub struct TreeView {
     pub on_select: Option<Box<dyn Fn(&str)>>,
     pub on_expand: Option<Box<dyn Fn(&str)>>,
     pub on_collapse: Option<Box<dyn Fn(&str)>>,
+    /// Nodes can be dragged between trees sharing this group
+    pub drag_group: Option<String>,
 }
 
 impl TreeView {
-    /// Create a new TreeView component
+    /// Create a new TreeView component (colors come from the current theme)
     pub fn new() -> Self {
+        let colors = ThemeProvider::current().colors;
         Self {
             node_id: None,
             nodes: Vec::new(),
@@ -165,12 +170,12 @@ impl TreeView {
             indent_size: 24.0,
             node_height: 32.0,
             padding: 8.0,
-            background_color: (255, 255, 255, 255),
-            node_color: (255, 255, 255, 255),
-            selected_color: (59, 130, 246, 20), // Light blue
-            hover_color: (245, 245, 245, 255),
-            text_color: (0, 0, 0, 255),
-            selected_text_color: (59, 130, 246, 255), // Blue
+            background_color: colors.surface,
+            node_color: colors.surface,
+            selected_color: colors.selection,
+            hover_color: colors.hover,
+            text_color: colors.text,
+            selected_text_color: colors.primary,
             expand_icon: "▶".to_string(),
             collapse_icon: "▼".to_string(),
             leaf_icon: "•".to_string(),
@@ -178,9 +183,27 @@ impl TreeView {
             on_select: None,
             on_expand: None,
             on_collapse: None,
+            drag_group: None,
         }
     }
 
+    /// Use a theme's colors
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Recolor for a theme (call when the theme Signal changes)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let colors = &theme.colors;
+        self.background_color = colors.surface;
+        self.node_color = colors.surface;
+        self.selected_color = colors.selection;
+        self.hover_color = colors.hover;
+        self.text_color = colors.text;
+        self.selected_text_color = colors.primary;
+    }
+
     /// Set indent size
     pub fn indent_size(mut self, size: f32) -> Self {
         self.indent_size = size;
@@ -274,6 +297,12 @@ impl TreeView {
         self
     }
 
+    /// Let nodes be dragged (and re-parented) within and between trees in this group
+    pub fn drag_group(mut self, group: impl Into<String>) -> Self {
+        self.drag_group = Some(group.into());
+        self
+    }
+
     /// Select a node by ID
     pub fn select_node(&mut self, id: &str) {
         if let Some(node) = self.find_node(id) {
@@ -435,6 +464,151 @@ impl Default for TreeView {
     }
 }
 
+/// Where a dropped node goes, relative to an existing node
+#[derive(Debug, Clone, PartialEq)]
+enum TreeDrop {
+    Before(String),
+    After(String),
+    Into(String),
+    /// After the last root
+    End,
+}
+
+impl TreeView {
+    /// Nodes currently on screen, top to bottom (children of expanded nodes)
+    pub fn visible_nodes(&self) -> Vec<&TreeNode> {
+        fn walk<'a>(nodes: &'a [TreeNode], rows: &mut Vec<&'a TreeNode>) {
+            for node in nodes {
+                rows.push(node);
+                if node.expanded {
+                    walk(&node.children, rows);
+                }
+            }
+        }
+        let mut rows = Vec::new();
+        walk(&self.nodes, &mut rows);
+        rows
+    }
+
+    /// Detach a node (with its subtree)
+    pub fn take_node(&mut self, id: &str) -> Option<TreeNode> {
+        locate(&mut self.nodes, id).map(|(siblings, index)| siblings.remove(index))
+    }
+
+    /// Drop placement for `y` (relative to the first row)
+    fn drop_placement(&self, dragged: &str, y: f32) -> Option<(TreeDrop, DropIndicator)> {
+        if y < 0.0 {
+            return None;
+        }
+        let rows = self.visible_nodes();
+        let row = (y / self.node_height) as usize;
+        let Some(node) = rows.get(row) else {
+            return Some((TreeDrop::End, DropIndicator::HorizontalLine(rows.len() as f32 * self.node_height)));
+        };
+        // A node can't go inside itself
+        if self.find_node(dragged).is_some_and(|dragged| dragged.find_child(&node.id).is_some()) {
+            return None;
+        }
+        let top = row as f32 * self.node_height;
+        let fraction = (y - top) / self.node_height;
+        Some(if fraction < 0.25 {
+            (TreeDrop::Before(node.id.clone()), DropIndicator::HorizontalLine(top))
+        } else if fraction > 0.75 {
+            (TreeDrop::After(node.id.clone()), DropIndicator::HorizontalLine(top + self.node_height))
+        } else {
+            (TreeDrop::Into(node.id.clone()), DropIndicator::Row { y: top, height: self.node_height })
+        })
+    }
+
+    fn insert_node(&mut self, node: TreeNode, placement: TreeDrop) {
+        let (id, offset) = match placement {
+            TreeDrop::Before(id) => (id, 0),
+            TreeDrop::After(id) => (id, 1),
+            TreeDrop::Into(id) => {
+                if let Some(parent) = self.find_node_mut(&id) {
+                    parent.children.push(node);
+                    parent.expanded = true;
+                } else {
+                    self.nodes.push(node);
+                }
+                return;
+            }
+            TreeDrop::End => {
+                self.nodes.push(node);
+                return;
+            }
+        };
+        match locate(&mut self.nodes, &id) {
+            Some((siblings, index)) => siblings.insert(index + offset, node),
+            None => self.nodes.push(node),
+        }
+    }
+}
+
+impl DragSource for TreeView {
+    fn drag_payload(&self, (_, y): (f32, f32)) -> Option<DragPayload> {
+        let group = self.drag_group.as_ref()?;
+        let row = (y >= 0.0).then(|| (y / self.node_height) as usize)?;
+        let node = self.visible_nodes().into_iter().nth(row).filter(|node| !node.disabled)?;
+        Some(DragPayload::new(group.clone(), node.id.clone(), node.label.clone()).with_data(node.clone()))
+    }
+
+    fn drag_finished(&mut self, payload: &DragPayload, outcome: DragOutcome) {
+        if outcome != DragOutcome::Transferred {
+            return;
+        }
+        if let Some(node) = self.take_node(&payload.id) {
+            if self.selected_node.get().is_some_and(|selected| node.find_child(&selected).is_some()) {
+                self.selected_node.set(None);
+            }
+        }
+    }
+}
+
+impl DropTarget for TreeView {
+    fn accepts(&self, payload: &DragPayload) -> bool {
+        self.drag_group.as_deref() == Some(payload.kind.as_str()) && payload.data::<TreeNode>().is_some()
+    }
+
+    fn drag_over(&mut self, payload: &DragPayload, (_, y): (f32, f32)) -> Option<DropIndicator> {
+        self.drop_placement(&payload.id, y).map(|(_, indicator)| indicator)
+    }
+
+    fn drop_payload(&mut self, payload: &DragPayload, (_, y): (f32, f32)) -> bool {
+        let Some((placement, _)) = self.drop_placement(&payload.id, y) else {
+            return false;
+        };
+        // Our own node moves with its subtree; a foreign one is copied in
+        let node = match self.take_node(&payload.id) {
+            Some(node) => node,
+            None => match payload.data::<TreeNode>() {
+                Some(node) => node.clone(),
+                None => return false,
+            },
+        };
+        self.insert_node(node, placement);
+        true
+    }
+}
+
+impl DragZone for TreeView {
+    fn as_source(&mut self) -> Option<&mut dyn DragSource> {
+        Some(self)
+    }
+
+    fn as_target(&mut self) -> Option<&mut dyn DropTarget> {
+        Some(self)
+    }
+}
+
+/// Sibling list holding `id` and its index there
+fn locate<'a>(nodes: &'a mut Vec<TreeNode>, id: &str) -> Option<(&'a mut Vec<TreeNode>, usize)> {
+    if let Some(index) = nodes.iter().position(|node| node.id == id) {
+        return Some((nodes, index));
+    }
+    nodes.iter_mut().find_map(|node| locate(&mut node.children, id))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -699,4 +873,32 @@ mod tests {
         assert!(result.is_ok());
         assert!(tree.node_id.is_some());
     }
+
+    #[test]
+    fn treeview_drag_reparents_nodes() {
+        use crate::drag_drop::{DragController, DragOutcome, DragZone};
+        // Rows: docs, readme, src (32px each)
+        let mut tree = TreeView::new()
+            .drag_group("files")
+            .add_node(TreeNode::new("docs", "docs").with_child(TreeNode::new("readme", "README")).expanded(true))
+            .add_node(TreeNode::new("src", "src"));
+        let mut drag = DragController::new();
+        let bounds = (0.0, 0.0, 300.0, 300.0);
+        let mut zones: Vec<(nebula_gfx::draw_list::Rect, &mut dyn DragZone)> = vec![(bounds, &mut tree)];
+
+        // "readme" onto the middle of "src" = into it
+        drag.press(&mut zones, (10.0, 40.0));
+        drag.move_to(&mut zones, (10.0, 80.0));
+        assert_eq!(drag.release(&mut zones), Some(DragOutcome::Rearranged));
+
+        // Rows are now docs, src, readme; "src" can't go into its own child
+        drag.press(&mut zones, (10.0, 40.0));
+        drag.move_to(&mut zones, (10.0, 80.0));
+        assert_eq!(drag.hovered(), None);
+        assert_eq!(drag.release(&mut zones), Some(DragOutcome::Cancelled));
+        drop(zones);
+
+        assert_eq!(tree.find_node("src").unwrap().children[0].id, "readme");
+        assert!(tree.find_node("docs").unwrap().children.is_empty());
+    }
 }
@@ -0,0 +1,526 @@
+// Validation - Reusable rules for form fields
+// Compose rules per field; error messages follow the Form's Locale
+
+use crate::locale::Locale;
+use nebula_core::Signal;
+use regex::Regex;
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+use tracing::info;
+
+/// Why a value was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    Required,
+    TooShort(usize),
+    TooLong(usize),
+    NotANumber,
+    TooSmall(f64),
+    TooLarge(f64),
+    InvalidFormat,
+    InvalidEmail,
+    InvalidUrl,
+    /// Label of the field it should match
+    Mismatch(String),
+    /// Async uniqueness check said no
+    Taken,
+    /// Already-worded message
+    Custom(String),
+}
+
+impl ValidationError {
+    /// Message in the locale's language (English, German and French built in)
+    pub fn message(&self, locale: &Locale) -> String {
+        let number = |value: f64| locale.format_number(value, if value.fract() == 0.0 { 0 } else { 2 });
+        let language = locale.code.split('-').next().unwrap_or("en");
+        match (language, self) {
+            (_, ValidationError::Custom(message)) => message.clone(),
+            ("de", ValidationError::Required) => "Dieses Feld ist erforderlich".to_string(),
+            ("de", ValidationError::TooShort(n)) => format!("Mindestens {} Zeichen", n),
+            ("de", ValidationError::TooLong(n)) => format!("Höchstens {} Zeichen", n),
+            ("de", ValidationError::NotANumber) => "Bitte eine Zahl eingeben".to_string(),
+            ("de", ValidationError::TooSmall(min)) => format!("Muss mindestens {} sein", number(*min)),
+            ("de", ValidationError::TooLarge(max)) => format!("Darf höchstens {} sein", number(*max)),
+            ("de", ValidationError::InvalidFormat) => "Ungültiges Format".to_string(),
+            ("de", ValidationError::InvalidEmail) => "Ungültige E-Mail-Adresse".to_string(),
+            ("de", ValidationError::InvalidUrl) => "Ungültige URL".to_string(),
+            ("de", ValidationError::Mismatch(other)) => format!("Stimmt nicht mit {} überein", other),
+            ("de", ValidationError::Taken) => "Bereits vergeben".to_string(),
+            ("fr", ValidationError::Required) => "Ce champ est obligatoire".to_string(),
+            ("fr", ValidationError::TooShort(n)) => format!("Au moins {} caractères", n),
+            ("fr", ValidationError::TooLong(n)) => format!("Au plus {} caractères", n),
+            ("fr", ValidationError::NotANumber) => "Veuillez saisir un nombre".to_string(),
+            ("fr", ValidationError::TooSmall(min)) => format!("Doit être au moins {}", number(*min)),
+            ("fr", ValidationError::TooLarge(max)) => format!("Doit être au plus {}", number(*max)),
+            ("fr", ValidationError::InvalidFormat) => "Format invalide".to_string(),
+            ("fr", ValidationError::InvalidEmail) => "Adresse e-mail invalide".to_string(),
+            ("fr", ValidationError::InvalidUrl) => "URL invalide".to_string(),
+            ("fr", ValidationError::Mismatch(other)) => format!("Ne correspond pas à {}", other),
+            ("fr", ValidationError::Taken) => "Déjà utilisé".to_string(),
+            (_, ValidationError::Required) => "This field is required".to_string(),
+            (_, ValidationError::TooShort(n)) => format!("Must be at least {} characters", n),
+            (_, ValidationError::TooLong(n)) => format!("Must be at most {} characters", n),
+            (_, ValidationError::NotANumber) => "Enter a number".to_string(),
+            (_, ValidationError::TooSmall(min)) => format!("Must be at least {}", number(*min)),
+            (_, ValidationError::TooLarge(max)) => format!("Must be at most {}", number(*max)),
+            (_, ValidationError::InvalidFormat) => "Invalid format".to_string(),
+            (_, ValidationError::InvalidEmail) => "Enter a valid email address".to_string(),
+            (_, ValidationError::InvalidUrl) => "Enter a valid URL".to_string(),
+            (_, ValidationError::Mismatch(other)) => format!("Must match {}", other),
+            (_, ValidationError::Taken) => "Already taken".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message(&Locale::en_us()))
+    }
+}
+
+/// Custom synchronous check
+pub type CustomRule = Rc<dyn Fn(&str) -> Result<(), ValidationError>>;
+
+/// Async check: call the callback with `true` when the value is available
+pub type UniquenessCheck = Rc<dyn Fn(&str, Box<dyn FnOnce(bool)>)>;
+
+/// One validation rule ✅
+///
+/// Every rule except `Required` accepts an empty value, so optional
+/// fields only need `Required` left out.
+#[derive(Clone)]
+pub enum Rule {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    /// Number (parsed with the form's Locale) within bounds
+    Range { min: Option<f64>, max: Option<f64> },
+    Pattern(Regex),
+    Email,
+    Url,
+    /// Same value as another field (by name)
+    Matches(String),
+    Custom(CustomRule),
+    /// Runs after every synchronous rule passes
+    Unique(UniquenessCheck),
+    /// Another rule with its own message
+    WithMessage(Box<Rule>, String),
+}
+
+impl Rule {
+    /// Non-empty (whitespace doesn't count)
+    pub fn required() -> Self {
+        Rule::Required
+    }
+
+    /// At least `length` characters
+    pub fn min_length(length: usize) -> Self {
+        Rule::MinLength(length)
+    }
+
+    /// At most `length` characters
+    pub fn max_length(length: usize) -> Self {
+        Rule::MaxLength(length)
+    }
+
+    /// A number between `min` and `max` (inclusive)
+    pub fn range(min: f64, max: f64) -> Self {
+        Rule::Range { min: Some(min), max: Some(max) }
+    }
+
+    /// A number no smaller than `min`
+    pub fn min(min: f64) -> Self {
+        Rule::Range { min: Some(min), max: None }
+    }
+
+    /// A number no larger than `max`
+    pub fn max(max: f64) -> Self {
+        Rule::Range { min: None, max: Some(max) }
+    }
+
+    /// Any number
+    pub fn numeric() -> Self {
+        Rule::Range { min: None, max: None }
+    }
+
+    /// Whole value must match a regex
+    pub fn pattern(pattern: &str) -> Result<Self, String> {
+        Regex::new(&format!("^(?:{})$", pattern))
+            .map(Rule::Pattern)
+            .map_err(|e| format!("Invalid validation pattern: {}", e))
+    }
+
+    /// An email address
+    pub fn email() -> Self {
+        Rule::Email
+    }
+
+    /// An http(s) URL
+    pub fn url() -> Self {
+        Rule::Url
+    }
+
+    /// Same value as the field named `field` (password confirmation)
+    pub fn matches(field: impl Into<String>) -> Self {
+        Rule::Matches(field.into())
+    }
+
+    /// Custom check
+    pub fn custom<F>(check: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), ValidationError> + 'static,
+    {
+        Rule::Custom(Rc::new(check))
+    }
+
+    /// Async availability check (usernames, emails…)
+    pub fn unique<F>(check: F) -> Self
+    where
+        F: Fn(&str, Box<dyn FnOnce(bool)>) + 'static,
+    {
+        Rule::Unique(Rc::new(check))
+    }
+
+    /// Replace the error message
+    pub fn message(self, message: impl Into<String>) -> Self {
+        Rule::WithMessage(Box::new(self), message.into())
+    }
+
+    /// Run the synchronous part of the rule
+    ///
+    /// `other` looks up another field's value and label for `Matches`.
+    pub fn check(&self, value: &str, locale: &Locale, other: &dyn Fn(&str) -> Option<(String, String)>) -> Result<(), ValidationError> {
+        let empty = value.trim().is_empty();
+        match self {
+            Rule::Required if empty => Err(ValidationError::Required),
+            Rule::WithMessage(rule, message) => rule
+                .check(value, locale, other)
+                .map_err(|_| ValidationError::Custom(message.clone())),
+            _ if empty => Ok(()),
+            Rule::MinLength(length) if value.chars().count() < *length => Err(ValidationError::TooShort(*length)),
+            Rule::MaxLength(length) if value.chars().count() > *length => Err(ValidationError::TooLong(*length)),
+            Rule::Range { min, max } => {
+                let number = locale.parse_number(value).ok_or(ValidationError::NotANumber)?;
+                match (min, max) {
+                    (Some(min), _) if number < *min => Err(ValidationError::TooSmall(*min)),
+                    (_, Some(max)) if number > *max => Err(ValidationError::TooLarge(*max)),
+                    _ => Ok(()),
+                }
+            }
+            Rule::Pattern(regex) if !regex.is_match(value) => Err(ValidationError::InvalidFormat),
+            Rule::Email if !is_email(value) => Err(ValidationError::InvalidEmail),
+            Rule::Url if !is_url(value) => Err(ValidationError::InvalidUrl),
+            Rule::Matches(field) => match other(field) {
+                Some((other_value, _)) if other_value == value => Ok(()),
+                Some((_, label)) => Err(ValidationError::Mismatch(label)),
+                None => Err(ValidationError::Mismatch(field.clone())),
+            },
+            Rule::Custom(check) => check(value),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// local@domain.tld with no spaces
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.rsplit_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !value.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains("..")
+        && !domain.contains('@')
+}
+
+/// http(s)://host[...]
+fn is_url(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix("https://").or_else(|| value.strip_prefix("http://")) else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host = host.rsplit('@').next().unwrap_or(host);
+    !host.is_empty() && !host.starts_with('.') && !value.chars().any(char::is_whitespace)
+}
+
+/// A value plus its rules and current error
+#[derive(Clone)]
+pub struct FormField {
+    pub name: String,
+    /// Shown in messages such as "Must match Password"
+    pub label: String,
+    pub value: Signal<String>,
+    /// Localized message, if invalid
+    pub error: Signal<Option<String>>,
+    /// Async check in flight
+    pub pending: Signal<bool>,
+    /// Edited or submitted: errors are only reported once touched
+    pub touched: Signal<bool>,
+    pub rules: Vec<Rule>,
+    /// Ignores async results for values that have since changed
+    generation: Rc<Cell<u64>>,
+}
+
+/// Form - Fields with composable validation rules 📝
+///
+/// # Example
+/// ```rust,ignore
+/// let form = Form::new()
+///     .field("username", "Username", vec![
+///         Rule::required(),
+///         Rule::min_length(3),
+///         Rule::unique(|name, done| api.check_username(name, done)),
+///     ])
+///     .field("password", "Password", vec![Rule::required(), Rule::min_length(8)])
+///     .field("confirm", "Confirm password", vec![Rule::matches("password")]);
+///
+/// form.set_value("username", "nebula");
+/// if form.validate() {
+///     submit(form.values());
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct Form {
+    pub fields: Vec<FormField>,
+    pub locale: Locale,
+}
+
+impl Form {
+    /// Create an empty form
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the locale (messages and number parsing)
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Add a field
+    pub fn field(mut self, name: impl Into<String>, label: impl Into<String>, rules: Vec<Rule>) -> Self {
+        self.fields.push(FormField {
+            name: name.into(),
+            label: label.into(),
+            value: Signal::new(String::new()),
+            error: Signal::new(None),
+            pending: Signal::new(false),
+            touched: Signal::new(false),
+            rules,
+            generation: Rc::new(Cell::new(0)),
+        });
+        self
+    }
+
+    /// Look up a field
+    pub fn get(&self, name: &str) -> Option<&FormField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Current value of a field
+    pub fn value(&self, name: &str) -> Option<String> {
+        self.get(name).map(|field| field.value.get())
+    }
+
+    /// Every (name, value)
+    pub fn values(&self) -> Vec<(String, String)> {
+        self.fields.iter().map(|field| (field.name.clone(), field.value.get())).collect()
+    }
+
+    /// Current error of a field
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.get(name).and_then(|field| field.error.get())
+    }
+
+    /// Every (name, message)
+    pub fn errors(&self) -> Vec<(String, String)> {
+        self.fields
+            .iter()
+            .filter_map(|field| field.error.get().map(|error| (field.name.clone(), error)))
+            .collect()
+    }
+
+    /// User edited a field: revalidate it and anything that must match it
+    pub fn set_value(&self, name: &str, value: impl Into<String>) {
+        let Some(field) = self.get(name) else {
+            return;
+        };
+        field.value.set(value.into());
+        field.touched.set(true);
+        self.validate_field(name);
+        for dependent in &self.fields {
+            let depends = dependent.rules.iter().any(|rule| matches!(rule, Rule::Matches(other) if other == name));
+            if depends && dependent.touched.get() {
+                self.validate_field(&dependent.name);
+            }
+        }
+    }
+
+    /// Validate one field; true if it passes the synchronous rules
+    ///
+    /// A passing field with a `Unique` rule stays pending until the check
+    /// answers.
+    pub fn validate_field(&self, name: &str) -> bool {
+        let Some(field) = self.get(name) else {
+            return false;
+        };
+        let generation = field.generation.get() + 1;
+        field.generation.set(generation);
+        field.pending.set(false);
+
+        let value = field.value.get();
+        let other = |name: &str| self.get(name).map(|other| (other.value.get(), other.label.clone()));
+        let failed = field.rules.iter().find_map(|rule| rule.check(&value, &self.locale, &other).err());
+        field.error.set(failed.as_ref().map(|error| error.message(&self.locale)));
+        if failed.is_some() {
+            return false;
+        }
+
+        if !value.trim().is_empty() {
+            for rule in &field.rules {
+                let (check, message) = match rule {
+                    Rule::Unique(check) => (check, None),
+                    Rule::WithMessage(inner, message) => match inner.as_ref() {
+                        Rule::Unique(check) => (check, Some(message.clone())),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
+                field.pending.set(true);
+                let (error, pending, current) = (field.error.clone(), field.pending.clone(), field.generation.clone());
+                let taken = message.unwrap_or_else(|| ValidationError::Taken.message(&self.locale));
+                check(
+                    &value,
+                    Box::new(move |available| {
+                        // A newer edit superseded this check
+                        if current.get() != generation {
+                            return;
+                        }
+                        pending.set(false);
+                        if !available {
+                            error.set(Some(taken));
+                        }
+                    }),
+                );
+                // Only one async check per field
+                break;
+            }
+        }
+        true
+    }
+
+    /// Touch and validate every field; true if all pass and nothing is pending
+    pub fn validate(&self) -> bool {
+        let mut valid = true;
+        for field in &self.fields {
+            field.touched.set(true);
+            valid &= self.validate_field(&field.name);
+        }
+        info!("📝 Form validated: {} errors", self.errors().len());
+        valid && self.is_valid()
+    }
+
+    /// No errors and no checks pending
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|field| field.error.get().is_none() && !field.pending.get())
+    }
+
+    /// Any async check in flight?
+    pub fn is_pending(&self) -> bool {
+        self.fields.iter().any(|field| field.pending.get())
+    }
+
+    /// Clear values, errors and touched state
+    pub fn reset(&self) {
+        for field in &self.fields {
+            field.generation.set(field.generation.get() + 1);
+            field.value.set(String::new());
+            field.error.set(None);
+            field.pending.set(false);
+            field.touched.set(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Value being checked and its completion callback
+    type PendingCheck = (String, Box<dyn FnOnce(bool)>);
+
+    fn check(rule: Rule, value: &str) -> Result<(), ValidationError> {
+        rule.check(value, &Locale::en_us(), &|_| None)
+    }
+
+    #[test]
+    fn rules_accept_and_reject() {
+        assert_eq!(check(Rule::required(), "  "), Err(ValidationError::Required));
+        assert_eq!(check(Rule::min_length(3), "ab"), Err(ValidationError::TooShort(3)));
+        assert_eq!(check(Rule::min_length(3), ""), Ok(()));
+        assert_eq!(check(Rule::max_length(2), "héé"), Err(ValidationError::TooLong(2)));
+        assert_eq!(check(Rule::range(1.0, 10.0), "1,000"), Err(ValidationError::TooLarge(10.0)));
+        assert_eq!(check(Rule::min(0.5), "abc"), Err(ValidationError::NotANumber));
+        assert_eq!(check(Rule::pattern("[A-Z]{3}").unwrap(), "ABCD"), Err(ValidationError::InvalidFormat));
+        assert!(Rule::pattern("(").is_err());
+        assert_eq!(check(Rule::email(), "a@b.co"), Ok(()));
+        assert_eq!(check(Rule::email(), "a@b"), Err(ValidationError::InvalidEmail));
+        assert_eq!(check(Rule::url(), "https://example.com/x?y"), Ok(()));
+        assert_eq!(check(Rule::url(), "ftp://example.com"), Err(ValidationError::InvalidUrl));
+        assert_eq!(
+            check(Rule::min_length(5).message("Too short!"), "abc"),
+            Err(ValidationError::Custom("Too short!".to_string()))
+        );
+
+        // Messages follow the locale (numbers too)
+        let german = Locale::de_de();
+        assert!(german.parse_number("1.234,5").is_some());
+        assert_eq!(Rule::max(1000.0).check("1.234,5", &german, &|_| None).unwrap_err().message(&german), "Darf höchstens 1.000 sein");
+        assert_eq!(ValidationError::Required.message(&Locale::fr_fr()), "Ce champ est obligatoire");
+        assert_eq!(ValidationError::TooShort(8).to_string(), "Must be at least 8 characters");
+    }
+
+    #[test]
+    fn form_validates_matching_fields_and_async_checks() {
+        let pending_checks: Rc<RefCell<Vec<PendingCheck>>> = Rc::new(RefCell::new(Vec::new()));
+        let queue = pending_checks.clone();
+        let form = Form::new()
+            .field("user", "Username", vec![Rule::required(), Rule::unique(move |name, done| queue.borrow_mut().push((name.to_string(), done)))])
+            .field("password", "Password", vec![Rule::required(), Rule::min_length(8)])
+            .field("confirm", "Confirm", vec![Rule::matches("password")]);
+
+        assert!(!form.validate());
+        assert_eq!(form.error("user").as_deref(), Some("This field is required"));
+        assert_eq!(form.errors().len(), 2);
+
+        form.set_value("password", "hunter22");
+        form.set_value("confirm", "hunter2");
+        assert_eq!(form.error("confirm").as_deref(), Some("Must match Password"));
+        // Fixing the password side revalidates the confirmation
+        form.set_value("password", "hunter2");
+        assert_eq!(form.error("password").as_deref(), Some("Must be at least 8 characters"));
+        assert_eq!(form.error("confirm"), None);
+        form.set_value("password", "hunter222");
+        form.set_value("confirm", "hunter222");
+
+        // Stale answers are ignored; the latest one decides
+        form.set_value("user", "neb");
+        form.set_value("user", "nebula");
+        assert!(form.is_pending() && !form.is_valid());
+        let mut checks: Vec<_> = pending_checks.borrow_mut().drain(..).collect();
+        let (name, latest) = checks.pop().unwrap();
+        assert_eq!(name, "nebula");
+        (checks.pop().unwrap().1)(false);
+        assert_eq!(form.error("user"), None);
+        latest(false);
+        assert_eq!(form.error("user").as_deref(), Some("Already taken"));
+        assert!(!form.is_pending() && !form.is_valid());
+
+        form.reset();
+        assert!(form.errors().is_empty() && form.value("user") == Some(String::new()));
+    }
+}
@@ -0,0 +1,569 @@
+// VirtualList Component - Virtualized list of variable-height items
+// Only the items in (or near) the viewport get layout nodes, so feeds and
+// chat logs with thousands of entries stay cheap to build
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use nebula_core::layout::{LayoutEngine, NodeId};
+
+use crate::scroll_memory::{ScrollKey, ScrollPosition};
+
+/// Builds the component tree for one item, given its id
+pub type ItemBuilder<'a> = dyn FnMut(&str, &mut LayoutEngine) -> Result<NodeId, String> + 'a;
+
+/// Measured item heights keyed by item id 📏
+///
+/// Unmeasured items are estimated from the average of everything measured
+/// so far (or `default_estimate` before the first measurement). Fixed
+/// heights (section headers, separators) are known up front and kept out
+/// of the average.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasurementCache {
+    heights: HashMap<String, f32>,
+    fixed: HashMap<String, f32>,
+    total: f32,
+    pub default_estimate: f32,
+}
+
+impl MeasurementCache {
+    /// Create an empty cache with an initial estimate
+    pub fn new(default_estimate: f32) -> Self {
+        Self {
+            heights: HashMap::new(),
+            fixed: HashMap::new(),
+            total: 0.0,
+            default_estimate: default_estimate.max(1.0),
+        }
+    }
+
+    /// Measured height for an item, if known
+    pub fn get(&self, id: &str) -> Option<f32> {
+        self.fixed.get(id).or_else(|| self.heights.get(id)).copied()
+    }
+
+    /// Measured height, falling back to the estimate
+    pub fn height(&self, id: &str) -> f32 {
+        self.get(id).unwrap_or_else(|| self.estimate())
+    }
+
+    /// Height assumed for unmeasured items
+    pub fn estimate(&self) -> f32 {
+        if self.heights.is_empty() {
+            self.default_estimate
+        } else {
+            self.total / self.heights.len() as f32
+        }
+    }
+
+    /// Record a measurement
+    /// Returns true if it differs from what was cached
+    pub fn insert(&mut self, id: impl Into<String>, height: f32) -> bool {
+        let height = height.max(0.0);
+        match self.heights.insert(id.into(), height) {
+            Some(previous) => {
+                self.total += height - previous;
+                (previous - height).abs() > f32::EPSILON
+            }
+            None => {
+                self.total += height;
+                true
+            }
+        }
+    }
+
+    /// Give an item a known height that never counts toward the estimate
+    pub fn set_fixed(&mut self, id: impl Into<String>, height: f32) {
+        self.fixed.insert(id.into(), height.max(0.0));
+    }
+
+    /// Forget a measurement (e.g. after the item's content changed)
+    pub fn remove(&mut self, id: &str) -> Option<f32> {
+        let height = self.heights.remove(id)?;
+        self.total -= height;
+        Some(height)
+    }
+
+    /// Forget every measurement (e.g. after a width change)
+    pub fn clear(&mut self) {
+        self.heights.clear();
+        self.fixed.clear();
+        self.total = 0.0;
+    }
+
+    /// Number of measured items
+    pub fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    /// Has nothing been measured yet?
+    pub fn is_empty(&self) -> bool {
+        self.heights.is_empty()
+    }
+}
+
+impl Default for MeasurementCache {
+    fn default() -> Self {
+        Self::new(48.0)
+    }
+}
+
+/// Where an item lands when scrolling to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAlign {
+    Start,
+    Center,
+    End,
+    /// Scroll as little as possible to make it visible
+    Nearest,
+}
+
+/// Virtualized list of variable-height items 📜
+///
+/// Items are measured the first time they are built and laid out; until
+/// then their height is estimated. When a measurement corrects an estimate
+/// above the viewport, the scroll offset moves with it so the content on
+/// screen doesn't jump.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut list = VirtualList::new().viewport_height(600.0).follow_end(true);
+/// list.set_items(messages.iter().map(|m| m.id.clone()).collect());
+/// let node = list.build(&mut engine, &mut |id, engine| build_message(id, engine))?;
+/// engine.compute_layout(node, available)?;
+/// list.measure_built(&engine);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VirtualList {
+    pub node_id: Option<NodeId>,
+    pub cache: MeasurementCache,
+    pub overscan: usize,
+    ids: Vec<String>,
+    offsets: Vec<f32>,
+    scroll_offset: f32,
+    viewport_height: f32,
+    follow_end: bool,
+    built: Vec<(String, NodeId)>,
+    /// Scroll restoration across navigation
+    pub scroll_memory: ScrollKey,
+}
+
+impl VirtualList {
+    /// Create an empty VirtualList
+    pub fn new() -> Self {
+        Self {
+            node_id: None,
+            cache: MeasurementCache::default(),
+            overscan: 3,
+            ids: Vec::new(),
+            offsets: vec![0.0],
+            scroll_offset: 0.0,
+            viewport_height: 0.0,
+            follow_end: false,
+            built: Vec::new(),
+            scroll_memory: ScrollKey::default(),
+        }
+    }
+
+    /// Set the height assumed before an item is measured
+    pub fn estimated_item_height(mut self, height: f32) -> Self {
+        self.cache.default_estimate = height.max(1.0);
+        self.rebuild_offsets();
+        self
+    }
+
+    /// Set the viewport height
+    pub fn viewport_height(mut self, height: f32) -> Self {
+        self.set_viewport_height(height);
+        self
+    }
+
+    /// Set how many extra items are built above and below the viewport
+    pub fn overscan(mut self, items: usize) -> Self {
+        self.overscan = items;
+        self
+    }
+
+    /// Keep the list pinned to the bottom while it is scrolled to the end
+    /// (chat-style: new messages stay in view)
+    pub fn follow_end(mut self, follow: bool) -> Self {
+        self.follow_end = follow;
+        self
+    }
+
+    /// Remember the scroll position under this id on the current route
+    /// (restored when the screen is built again after navigating back)
+    pub fn scroll_id(mut self, id: impl Into<String>) -> Self {
+        self.scroll_memory.id = Some(id.into());
+        self
+    }
+
+    /// Opt this list out of scroll restoration
+    pub fn remember_scroll(mut self, remember: bool) -> Self {
+        self.scroll_memory.enabled = remember;
+        self
+    }
+
+    /// Replace the item ids, keeping the first visible item in place
+    pub fn set_items(&mut self, ids: Vec<String>) {
+        self.preserving_scroll(|list| {
+            list.ids = ids;
+            list.rebuild_offsets();
+        });
+    }
+
+    /// Append an item
+    pub fn push(&mut self, id: impl Into<String>) {
+        let id = id.into();
+        self.preserving_scroll(|list| {
+            let top = list.total_height();
+            list.offsets.push(top + list.cache.height(&id));
+            list.ids.push(id);
+        });
+    }
+
+    /// Item ids in display order
+    pub fn items(&self) -> &[String] {
+        &self.ids
+    }
+
+    /// Number of items
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Is the list empty?
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Record an item's real height
+    pub fn measure(&mut self, id: &str, height: f32) {
+        let previous_estimate = self.cache.estimate();
+        let changed = self.cache.insert(id, height);
+        // A new average moves every unmeasured item too
+        if changed || self.cache.estimate() != previous_estimate {
+            self.preserving_scroll(|list| list.rebuild_offsets());
+        }
+    }
+
+    /// Measure every item from the last `build`, after the layout was computed
+    pub fn measure_built(&mut self, engine: &LayoutEngine) {
+        let measured: Vec<(String, f32)> = self
+            .built
+            .iter()
+            .filter_map(|(id, node)| engine.get_layout(*node).ok().map(|l| (id.clone(), l.size.height)))
+            .collect();
+        if measured.is_empty() {
+            return;
+        }
+        self.preserving_scroll(|list| {
+            for (id, height) in measured {
+                list.cache.insert(id, height);
+            }
+            list.rebuild_offsets();
+        });
+    }
+
+    /// Forget a measurement so the item is measured again on the next build
+    pub fn invalidate(&mut self, id: &str) {
+        if self.cache.remove(id).is_some() {
+            self.preserving_scroll(|list| list.rebuild_offsets());
+        }
+    }
+
+    /// Height of an item (measured or estimated)
+    pub fn item_height(&self, index: usize) -> f32 {
+        self.offsets.get(index + 1).zip(self.offsets.get(index)).map_or(0.0, |(bottom, top)| bottom - top)
+    }
+
+    /// Top offset of an item
+    pub fn offset_of(&self, index: usize) -> f32 {
+        self.offsets[index.min(self.ids.len())]
+    }
+
+    /// Total content height
+    pub fn total_height(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or(0.0)
+    }
+
+    /// Index of the item at a content offset
+    pub fn index_at(&self, y: f32) -> Option<usize> {
+        if y < 0.0 || y >= self.total_height() {
+            return None;
+        }
+        Some(self.offsets.partition_point(|&top| top <= y) - 1)
+    }
+
+    /// Items intersecting the viewport (without overscan)
+    pub fn visible_range(&self) -> Range<usize> {
+        let top = self.scroll_offset;
+        let bottom = top + self.viewport_height;
+        let start = self.offsets.partition_point(|&offset| offset <= top).saturating_sub(1).min(self.ids.len());
+        let end = self.offsets.partition_point(|&offset| offset < bottom).min(self.ids.len());
+        start..end.max(start)
+    }
+
+    /// Items to build: the visible range plus overscan on both sides
+    pub fn render_range(&self) -> Range<usize> {
+        let visible = self.visible_range();
+        visible.start.saturating_sub(self.overscan)..(visible.end + self.overscan).min(self.ids.len())
+    }
+
+    /// Current scroll offset
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    /// Largest valid scroll offset
+    pub fn max_scroll_offset(&self) -> f32 {
+        (self.total_height() - self.viewport_height).max(0.0)
+    }
+
+    /// Scroll to an absolute offset (clamped)
+    pub fn scroll_to(&mut self, y: f32) {
+        self.scroll_offset = y.clamp(0.0, self.max_scroll_offset());
+        self.scroll_memory.save(ScrollPosition { offset: (0.0, self.scroll_offset), page: 0 });
+    }
+
+    /// Jump to the remembered position (once, after items are set; `build` calls this)
+    pub fn restore_scroll(&mut self) {
+        if let Some(position) = self.scroll_memory.take_restore() {
+            self.scroll_to(position.offset.1);
+        }
+    }
+
+    /// Scroll by a delta
+    pub fn scroll_by(&mut self, dy: f32) {
+        self.scroll_to(self.scroll_offset + dy);
+    }
+
+    /// Scroll to the last item
+    pub fn scroll_to_end(&mut self) {
+        self.scroll_to(self.max_scroll_offset());
+    }
+
+    /// Is the list scrolled to the end?
+    pub fn is_at_end(&self) -> bool {
+        self.scroll_offset >= self.max_scroll_offset() - 0.5
+    }
+
+    /// Bring an item into view
+    pub fn scroll_to_index(&mut self, index: usize, align: ScrollAlign) {
+        if index >= self.ids.len() {
+            return;
+        }
+        let top = self.offset_of(index);
+        let height = self.item_height(index);
+        let target = match align {
+            ScrollAlign::Start => top,
+            ScrollAlign::Center => top + height / 2.0 - self.viewport_height / 2.0,
+            ScrollAlign::End => top + height - self.viewport_height,
+            ScrollAlign::Nearest if top < self.scroll_offset => top,
+            ScrollAlign::Nearest if top + height > self.scroll_offset + self.viewport_height => {
+                top + height - self.viewport_height
+            }
+            ScrollAlign::Nearest => self.scroll_offset,
+        };
+        self.scroll_to(target);
+    }
+
+    /// Update the viewport height (e.g. after a window resize)
+    pub fn set_viewport_height(&mut self, height: f32) {
+        let at_end = self.follow_end && self.is_at_end();
+        self.viewport_height = height.max(0.0);
+        if at_end {
+            self.scroll_to_end();
+        } else {
+            self.scroll_to(self.scroll_offset);
+        }
+    }
+
+    /// Build nodes for the render range between two spacers that stand in
+    /// for everything above and below it
+    pub fn build(&mut self, engine: &mut LayoutEngine, builder: &mut ItemBuilder) -> Result<NodeId, String> {
+        self.restore_scroll();
+        let range = self.render_range();
+        let spacer = |engine: &mut LayoutEngine, height: f32| {
+            engine
+                .new_leaf(taffy::style::Style {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Percent(1.0),
+                        height: taffy::style::Dimension::Length(height),
+                    },
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                })
+                .map_err(|e| format!("Failed to create spacer node: {:?}", e))
+        };
+
+        let mut children = vec![spacer(engine, self.offset_of(range.start))?];
+        self.built.clear();
+        for index in range.clone() {
+            let id = self.ids[index].clone();
+            let node = builder(&id, engine)?;
+            children.push(node);
+            self.built.push((id, node));
+        }
+        children.push(spacer(engine, self.total_height() - self.offset_of(range.end))?);
+
+        let style = taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Percent(1.0),
+                height: taffy::style::Dimension::Auto,
+            },
+            display: taffy::style::Display::Flex,
+            flex_direction: taffy::style::FlexDirection::Column,
+            ..Default::default()
+        };
+        let node = engine
+            .new_with_children(style, &children)
+            .map_err(|e| format!("Failed to create virtual list node: {:?}", e))?;
+        self.node_id = Some(node);
+
+        Ok(node)
+    }
+
+    fn rebuild_offsets(&mut self) {
+        self.offsets.clear();
+        self.offsets.reserve(self.ids.len() + 1);
+        let mut y = 0.0;
+        self.offsets.push(y);
+        for id in &self.ids {
+            y += self.cache.height(id);
+            self.offsets.push(y);
+        }
+    }
+
+    /// Run a change that may move items, then restore the scroll position
+    /// relative to the first visible item (or the end, when following it)
+    fn preserving_scroll(&mut self, change: impl FnOnce(&mut Self)) {
+        let at_end = self.follow_end && !self.ids.is_empty() && self.is_at_end();
+        let anchor = self.index_at(self.scroll_offset).map(|index| {
+            (self.ids[index].clone(), self.scroll_offset - self.offset_of(index))
+        });
+
+        change(self);
+
+        if at_end {
+            self.scroll_to_end();
+            return;
+        }
+        let anchored = anchor.and_then(|(id, within)| {
+            let index = self.ids.iter().position(|other| *other == id)?;
+            Some(self.offset_of(index) + within.min(self.item_height(index)))
+        });
+        self.scroll_to(anchored.unwrap_or(self.scroll_offset));
+    }
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_of(count: usize) -> VirtualList {
+        let mut list = VirtualList::new().estimated_item_height(50.0).viewport_height(200.0).overscan(1);
+        list.set_items((0..count).map(|i| format!("item{}", i)).collect());
+        list
+    }
+
+    #[test]
+    fn measurement_cache_estimates_from_average() {
+        let mut cache = MeasurementCache::new(40.0);
+        assert_eq!(cache.height("a"), 40.0);
+        assert!(cache.insert("a", 100.0));
+        assert!(cache.insert("b", 50.0));
+        assert!(!cache.insert("b", 50.0));
+        assert_eq!(cache.estimate(), 75.0);
+        assert_eq!(cache.height("c"), 75.0);
+        cache.remove("a");
+        assert_eq!(cache.estimate(), 50.0);
+
+        cache.set_fixed("header", 20.0);
+        assert_eq!(cache.height("header"), 20.0);
+        assert_eq!(cache.estimate(), 50.0);
+    }
+
+    #[test]
+    fn ranges_use_estimates() {
+        let list = list_of(100);
+        assert_eq!(list.total_height(), 5000.0);
+        assert_eq!(list.visible_range(), 0..4);
+        assert_eq!(list.render_range(), 0..5);
+        assert_eq!(list.index_at(125.0), Some(2));
+    }
+
+    #[test]
+    fn measurements_above_viewport_keep_content_still() {
+        let mut list = list_of(100);
+        list.scroll_to(1000.0);
+        assert_eq!(list.visible_range().start, 20);
+
+        // item5 turns out taller, and the average grows with it
+        list.measure("item5", 150.0);
+        assert_eq!(list.offset_of(20), list.scroll_offset());
+        assert_eq!(list.visible_range().start, 20);
+    }
+
+    #[test]
+    fn scroll_to_index_alignments() {
+        let mut list = list_of(100);
+        list.scroll_to_index(10, ScrollAlign::Start);
+        assert_eq!(list.scroll_offset(), 500.0);
+        list.scroll_to_index(10, ScrollAlign::End);
+        assert_eq!(list.scroll_offset(), 350.0);
+        list.scroll_to_index(9, ScrollAlign::Nearest);
+        assert_eq!(list.scroll_offset(), 350.0);
+        list.scroll_to_index(99, ScrollAlign::Center);
+        assert_eq!(list.scroll_offset(), list.max_scroll_offset());
+    }
+
+    #[test]
+    fn follow_end_sticks_to_bottom() {
+        let mut list = VirtualList::new().estimated_item_height(50.0).viewport_height(200.0).follow_end(true);
+        for i in 0..10 {
+            list.push(format!("msg{}", i));
+        }
+        list.scroll_to_end();
+        list.push("msg10");
+        assert!(list.is_at_end());
+        assert_eq!(list.scroll_offset(), 350.0);
+
+        list.scroll_to(0.0);
+        list.push("msg11");
+        assert_eq!(list.scroll_offset(), 0.0);
+    }
+
+    #[test]
+    fn build_measures_visible_items() {
+        let mut engine = LayoutEngine::new();
+        let mut list = list_of(50);
+        let node = list
+            .build(&mut engine, &mut |id, engine| {
+                let height = if id == "item1" { 120.0 } else { 30.0 };
+                engine
+                    .new_leaf(nebula_core::layout::styles::fixed_size(100.0, height))
+                    .map_err(|e| format!("{:?}", e))
+            })
+            .unwrap();
+
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(100.0),
+            height: taffy::style::AvailableSpace::MaxContent,
+        };
+        let layout = engine.compute_layout(node, available).unwrap();
+        // Built items plus a spacer for the 45 estimated ones below
+        assert_eq!(layout.size.height, 240.0 + 45.0 * 50.0);
+
+        list.measure_built(&engine);
+        assert_eq!(list.cache.len(), 5);
+        assert_eq!(list.item_height(1), 120.0);
+        assert_eq!(list.total_height(), 240.0 + 45.0 * 48.0);
+    }
+}
@@ -0,0 +1,699 @@
+// Widget - One trait for every component, one tree to hold them
+// build → layout → paint → event, driven by WidgetTree
+
+use std::any::Any;
+
+use nebula_core::animation::{AnimationController, MotionPreference, SpringAnimation};
+use nebula_core::layout::{LayoutDirection, LayoutEngine, NodeId};
+use nebula_core::signal::Signal;
+use nebula_gfx::draw_list::{DrawList, Rect};
+
+use super::style::{ComputedStyle, Edges, Style, WidgetState};
+
+/// Pointer event delivered to widgets (window coordinates, in pixels)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WidgetEvent {
+    PointerDown { x: f32, y: f32 },
+    PointerUp { x: f32, y: f32 },
+    PointerMove { x: f32, y: f32 },
+}
+
+impl WidgetEvent {
+    /// Pointer position
+    pub fn position(&self) -> (f32, f32) {
+        match *self {
+            WidgetEvent::PointerDown { x, y }
+            | WidgetEvent::PointerUp { x, y }
+            | WidgetEvent::PointerMove { x, y } => (x, y),
+        }
+    }
+}
+
+/// A component that can live in a WidgetTree 🧩
+///
+/// The tree calls `build` bottom-up (children first, so containers get
+/// their children's nodes), then lays everything out and hands each
+/// widget its absolute bounds for `paint` and `event`.
+pub trait Widget: Any {
+    /// Create this widget's layout node around its already-built children
+    fn build_node(&mut self, engine: &mut LayoutEngine, children: &[NodeId]) -> Result<NodeId, String>;
+
+    /// Record drawing commands; `bounds` is (x, y, width, height) in window pixels
+    fn paint(&self, bounds: Rect, list: &mut DrawList) {
+        let _ = (bounds, list); // Default: draws nothing
+    }
+
+    /// Paint with the widget's resolved Style (box decoration is already drawn)
+    /// Override to honor text color / font settings; defaults to `paint`
+    fn paint_styled(&self, bounds: Rect, style: &ComputedStyle, list: &mut DrawList) {
+        let _ = style;
+        self.paint(bounds, list);
+    }
+
+    /// Handle a pointer event; return true to stop it bubbling to parents
+    fn event(&mut self, event: &WidgetEvent, bounds: Rect) -> bool {
+        let _ = (event, bounds); // Default: not handled
+        false
+    }
+}
+
+/// Handle to a widget in a WidgetTree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WidgetId(usize);
+
+struct Entry {
+    widget: Box<dyn Widget>,
+    parent: Option<WidgetId>,
+    children: Vec<WidgetId>,
+    node: Option<NodeId>,
+    style: Style,
+    state: WidgetState,
+    /// Style resolved by the last `animate` (to notice changes)
+    resolved: Option<ComputedStyle>,
+    /// Running style transition (from, to)
+    transition: Option<(ComputedStyle, ComputedStyle)>,
+    /// Subtree only shown while this is true
+    visible_when: Option<Signal<bool>>,
+    /// Visibility the last build used
+    shown: bool,
+}
+
+/// Retained tree of widgets 🌳
+///
+/// # Example
+/// ```rust,ignore
+/// let mut tree = WidgetTree::new();
+/// let root = tree.insert(VStack::new().spacing(8.0));
+/// tree.append_child(root, Text::new("Hello"));
+/// tree.append_child(root, Button::new("OK").on_click(|| println!("OK!")));
+/// tree.set_root(root);
+///
+/// tree.layout(800.0, 600.0)?;
+/// let mut list = DrawList::new();
+/// tree.paint(&mut list);
+/// tree.dispatch(&WidgetEvent::PointerDown { x: 20.0, y: 40.0 });
+/// ```
+pub struct WidgetTree {
+    entries: Vec<Option<Entry>>,
+    root: Option<WidgetId>,
+    engine: LayoutEngine,
+    dirty: bool,
+    /// One 0→1 spring per running style transition
+    animations: AnimationController,
+}
+
+impl WidgetTree {
+    /// Create an empty tree
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            root: None,
+            engine: LayoutEngine::new(),
+            dirty: true,
+            animations: AnimationController::new(),
+        }
+    }
+
+    /// Add a detached widget
+    pub fn insert(&mut self, widget: impl Widget) -> WidgetId {
+        let id = WidgetId(self.entries.len());
+        self.entries.push(Some(Entry {
+            widget: Box::new(widget),
+            parent: None,
+            children: Vec::new(),
+            node: None,
+            style: Style::default(),
+            state: WidgetState::default(),
+            resolved: None,
+            transition: None,
+            visible_when: None,
+            shown: true,
+        }));
+        self.dirty = true;
+        id
+    }
+
+    /// Add a widget as the last child of `parent`
+    pub fn append_child(&mut self, parent: WidgetId, widget: impl Widget) -> WidgetId {
+        let child = self.insert(widget);
+        self.attach(parent, child);
+        child
+    }
+
+    /// Move an existing widget under `parent`
+    pub fn attach(&mut self, parent: WidgetId, child: WidgetId) {
+        if parent == child || !self.contains(parent) || !self.contains(child) || self.is_ancestor(child, parent) {
+            return;
+        }
+        self.detach(child);
+        if let Some(entry) = self.entry_mut(child) {
+            entry.parent = Some(parent);
+        }
+        if let Some(entry) = self.entry_mut(parent) {
+            entry.children.push(child);
+        }
+        self.dirty = true;
+    }
+
+    /// Unhook a widget from its parent (it stays in the tree, detached)
+    pub fn detach(&mut self, child: WidgetId) {
+        let Some(parent) = self.parent(child) else {
+            return;
+        };
+        if let Some(entry) = self.entry_mut(parent) {
+            entry.children.retain(|&other| other != child);
+        }
+        if let Some(entry) = self.entry_mut(child) {
+            entry.parent = None;
+        }
+        self.dirty = true;
+    }
+
+    /// Remove a widget and everything under it
+    pub fn remove(&mut self, id: WidgetId) {
+        self.detach(id);
+        for child in self.children(id).to_vec() {
+            self.remove(child);
+        }
+        if let Some(slot) = self.entries.get_mut(id.0) {
+            *slot = None;
+        }
+        if self.root == Some(id) {
+            self.root = None;
+        }
+        self.dirty = true;
+    }
+
+    /// Set the root widget
+    pub fn set_root(&mut self, id: WidgetId) {
+        if self.contains(id) {
+            self.root = Some(id);
+            self.dirty = true;
+        }
+    }
+
+    /// Root widget
+    pub fn root(&self) -> Option<WidgetId> {
+        self.root
+    }
+
+    /// Is the id still in the tree?
+    pub fn contains(&self, id: WidgetId) -> bool {
+        self.entry(id).is_some()
+    }
+
+    /// Number of widgets
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Is the tree empty?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Parent of a widget
+    pub fn parent(&self, id: WidgetId) -> Option<WidgetId> {
+        self.entry(id)?.parent
+    }
+
+    /// Children of a widget, in paint order
+    pub fn children(&self, id: WidgetId) -> &[WidgetId] {
+        self.entry(id).map_or(&[], |entry| entry.children.as_slice())
+    }
+
+    /// Borrow a widget as its concrete type
+    pub fn get<W: Widget>(&self, id: WidgetId) -> Option<&W> {
+        let widget: &dyn Any = self.entry(id)?.widget.as_ref();
+        widget.downcast_ref()
+    }
+
+    /// Mutably borrow a widget as its concrete type (marks the tree for rebuild)
+    pub fn get_mut<W: Widget>(&mut self, id: WidgetId) -> Option<&mut W> {
+        self.dirty = true;
+        let widget: &mut dyn Any = self.entry_mut(id)?.widget.as_mut();
+        widget.downcast_mut()
+    }
+
+    /// Set a widget's Style (padding/margin changes relayout on the next `layout`)
+    pub fn set_style(&mut self, id: WidgetId, style: Style) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.style = style;
+            self.dirty = true;
+        }
+    }
+
+    /// A widget's own Style (before cascading)
+    pub fn style(&self, id: WidgetId) -> Option<&Style> {
+        Some(&self.entry(id)?.style)
+    }
+
+    /// Only show a widget (and its subtree) while `visible` is true
+    /// Hidden subtrees take no space, paint nothing and get no events
+    pub fn show_when(&mut self, id: WidgetId, visible: Signal<bool>) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.visible_when = Some(visible);
+            self.dirty = true;
+        }
+    }
+
+    /// Is a widget's own visibility condition met?
+    pub fn is_shown(&self, id: WidgetId) -> bool {
+        self.entry(id).is_some_and(|entry| entry.visible_when.as_ref().is_none_or(Signal::get))
+    }
+
+    /// Interaction state of a widget
+    pub fn state(&self, id: WidgetId) -> WidgetState {
+        self.entry(id).map(|entry| entry.state).unwrap_or_default()
+    }
+
+    /// Mark a widget disabled (it stops receiving pointer events)
+    pub fn set_disabled(&mut self, id: WidgetId, disabled: bool) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.state.disabled = disabled;
+        }
+    }
+
+    /// Mark a widget focused (e.g. from the FocusManager)
+    pub fn set_focused(&mut self, id: WidgetId, focused: bool) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.state.focused = focused;
+        }
+    }
+
+    /// Style after cascading from the root and applying pseudo-states
+    pub fn computed_style(&self, id: WidgetId) -> Option<ComputedStyle> {
+        let mut chain = vec![id];
+        let mut ancestor = self.parent(id);
+        while let Some(parent) = ancestor {
+            chain.push(parent);
+            ancestor = self.parent(parent);
+        }
+        let mut computed = ComputedStyle::default();
+        for &link in chain.iter().rev() {
+            let entry = self.entry(link)?;
+            computed = entry.style.resolve(&computed, entry.state);
+        }
+        Some(computed)
+    }
+
+    /// Layout node of a widget (after `layout`)
+    pub fn node(&self, id: WidgetId) -> Option<NodeId> {
+        self.entry(id)?.node
+    }
+
+    /// Layout engine holding the built nodes
+    pub fn engine(&self) -> &LayoutEngine {
+        &self.engine
+    }
+
+    /// Build every node (if anything changed) and lay the tree out
+    pub fn layout(&mut self, width: f32, height: f32) -> Result<(), String> {
+        let root = self.root.ok_or("WidgetTree has no root")?;
+        // A visibility signal flipped since the last build
+        let visibility_changed = self.entries.iter().flatten().any(|entry| entry.visible_when.as_ref().is_some_and(|visible| visible.get() != entry.shown));
+        if self.dirty || visibility_changed {
+            // Fresh engine each rebuild so stale nodes don't pile up
+            let scale_factor = self.engine.scale_factor();
+            self.engine = LayoutEngine::new();
+            self.engine.set_scale_factor(scale_factor);
+            self.build_subtree(root)?;
+            self.dirty = false;
+        }
+        let node = self.node(root).ok_or("Root was not built")?;
+        let available = taffy::geometry::Size {
+            width: taffy::style::AvailableSpace::Definite(width),
+            height: taffy::style::AvailableSpace::Definite(height),
+        };
+        self.engine
+            .compute_layout(node, available)
+            .map_err(|e| format!("Failed to compute layout: {:?}", e))?;
+        Ok(())
+    }
+
+    fn build_subtree(&mut self, id: WidgetId) -> Result<NodeId, String> {
+        let mut children = Vec::new();
+        for child in self.children(id).to_vec() {
+            let shown = self.is_shown(child);
+            if let Some(entry) = self.entry_mut(child) {
+                entry.shown = shown;
+            }
+            if shown {
+                children.push(self.build_subtree(child)?);
+            } else {
+                self.forget_nodes(child);
+            }
+        }
+        let entry = self.entries[id.0].as_mut().ok_or("Widget was removed")?;
+        let node = entry.widget.build_node(&mut self.engine, &children)?;
+        entry.node = Some(node);
+        let (padding, margin) = (entry.style.padding, entry.style.margin);
+        if padding.is_some() || margin.is_some() {
+            let mut layout_style = self.engine.get_style(node).map_err(|e| format!("Failed to style node: {:?}", e))?;
+            if let Some(padding) = padding {
+                layout_style.padding = to_lengths(padding);
+            }
+            if let Some(margin) = margin {
+                layout_style.margin = to_lengths(margin).map(taffy::style::LengthPercentageAuto::from);
+            }
+            self.engine.set_style(node, layout_style).map_err(|e| format!("Failed to style node: {:?}", e))?;
+        }
+        Ok(node)
+    }
+
+    /// Drop the (stale) nodes of a hidden subtree so it has no bounds
+    fn forget_nodes(&mut self, id: WidgetId) {
+        for child in self.children(id).to_vec() {
+            self.forget_nodes(child);
+        }
+        if let Some(entry) = self.entry_mut(id) {
+            entry.node = None;
+        }
+    }
+
+    /// Absolute bounds of a widget (x, y, width, height)
+    pub fn bounds(&self, id: WidgetId) -> Option<Rect> {
+        let layout = self.engine.get_layout(self.node(id)?).ok()?;
+        let (mut x, mut y) = (layout.location.x, layout.location.y);
+        let mut ancestor = self.parent(id);
+        while let Some(parent) = ancestor {
+            let parent_layout = self.engine.get_layout(self.node(parent)?).ok()?;
+            x += parent_layout.location.x;
+            y += parent_layout.location.y;
+            ancestor = self.parent(parent);
+        }
+        Some((x, y, layout.size.width, layout.size.height))
+    }
+
+    /// Start transitions for styles that changed (hover, new Style…) and
+    /// advance running ones; call once per frame before `paint`
+    /// Returns true while something is animating (keep redrawing)
+    pub fn animate(&mut self) -> bool {
+        self.track_style_changes();
+        self.animations.update() > 0
+    }
+
+    /// Like `animate`, stepping a fixed `delta_time` in seconds
+    pub fn animate_by(&mut self, delta_time: f32) -> bool {
+        self.track_style_changes();
+        self.animations.update_by(delta_time) > 0
+    }
+
+    fn track_style_changes(&mut self) {
+        let Some(root) = self.root else {
+            return;
+        };
+        let mut pending = vec![(root, ComputedStyle::default())];
+        while let Some((id, parent)) = pending.pop() {
+            // Borrow the entries alone, so the animations stay mutable
+            let Some(entry) = self.entries.get(id.0).and_then(Option::as_ref) else {
+                continue;
+            };
+            let target = entry.style.resolve(&parent, entry.state);
+            let name = transition_name(id);
+            let spring = self.animations.get(&name);
+            // Start from what's on screen, even mid-transition
+            let shown = match (&entry.transition, spring) {
+                (Some((from, to)), Some(spring)) => Some(from.lerp(to, spring.value())),
+                _ => entry.resolved.clone(),
+            };
+            let running = spring.is_some() && entry.transition.is_some();
+            // Reduced motion: style changes apply at once
+            let duration = target.transition.filter(|_| !MotionPreference::is_reduced());
+            let transition = match (shown, duration) {
+                (Some(shown), Some(duration)) if Some(&target) != entry.resolved.as_ref() => {
+                    self.animations.restart(name, SpringAnimation::new(0.0, 1.0).settle_within(duration));
+                    Some((shown, target.clone()))
+                }
+                _ if running => entry.transition.clone(),
+                _ => None,
+            };
+            pending.extend(entry.children.iter().map(|&child| (child, target.clone())));
+            if let Some(entry) = self.entry_mut(id) {
+                entry.transition = transition;
+                entry.resolved = Some(target);
+            }
+        }
+    }
+
+    /// Paint the whole tree, parents under their children
+    pub fn paint(&self, list: &mut DrawList) {
+        if let Some(root) = self.root {
+            self.paint_subtree(root, &ComputedStyle::default(), list);
+        }
+    }
+
+    fn paint_subtree(&self, id: WidgetId, parent: &ComputedStyle, list: &mut DrawList) {
+        let (Some(entry), Some(bounds)) = (self.entry(id), self.bounds(id)) else {
+            return;
+        };
+        let style = match (&entry.transition, self.animations.get(&transition_name(id))) {
+            (Some((from, to)), Some(spring)) => from.lerp(to, spring.value()),
+            _ => entry.style.resolve(parent, entry.state),
+        };
+        let start = list.len();
+        style.paint_box(bounds, list);
+        entry.widget.paint_styled(bounds, &style, list);
+        for &child in &entry.children {
+            self.paint_subtree(child, &style, list);
+        }
+        // Opacity and scale apply to the whole subtree
+        if style.opacity < 1.0 || style.scale != 1.0 {
+            let center = (bounds.0 + bounds.2 / 2.0, bounds.1 + bounds.3 / 2.0);
+            list.transform_since(start, center, style.scale, style.opacity);
+        }
+    }
+
+    /// Topmost widget under a point
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<WidgetId> {
+        self.hit_test_subtree(self.root?, x, y)
+    }
+
+    fn hit_test_subtree(&self, id: WidgetId, x: f32, y: f32) -> Option<WidgetId> {
+        let (bx, by, width, height) = self.bounds(id)?;
+        if x < bx || y < by || x >= bx + width || y >= by + height {
+            return None;
+        }
+        // Later children paint on top, so they're hit first
+        self.children(id)
+            .iter()
+            .rev()
+            .find_map(|&child| self.hit_test_subtree(child, x, y))
+            .or(Some(id))
+    }
+
+    /// Deliver a pointer event to the widget under it, bubbling up to its
+    /// ancestors until one handles it
+    /// Returns the widget that handled it
+    pub fn dispatch(&mut self, event: &WidgetEvent) -> Option<WidgetId> {
+        let (x, y) = event.position();
+        let hit = self.hit_test(x, y);
+        self.update_pointer_state(event, hit);
+        let mut target = hit;
+        while let Some(id) = target {
+            let bounds = self.bounds(id)?;
+            let entry = self.entry_mut(id)?;
+            if entry.state.disabled {
+                return None;
+            }
+            if entry.widget.event(event, bounds) {
+                return Some(id);
+            }
+            target = entry.parent;
+        }
+        None
+    }
+
+    /// Hover covers the hit widget and its ancestors (like CSS `:hover`);
+    /// pressed lasts from pointer down until pointer up
+    fn update_pointer_state(&mut self, event: &WidgetEvent, hit: Option<WidgetId>) {
+        let mut path = Vec::new();
+        let mut current = hit;
+        while let Some(id) = current {
+            path.push(id);
+            current = self.parent(id);
+        }
+        for (index, slot) in self.entries.iter_mut().enumerate() {
+            let Some(entry) = slot.as_mut() else {
+                continue;
+            };
+            let on_path = path.contains(&WidgetId(index));
+            entry.state.hovered = on_path;
+            match event {
+                WidgetEvent::PointerDown { .. } => entry.state.pressed = on_path && !entry.state.disabled,
+                WidgetEvent::PointerUp { .. } => entry.state.pressed = false,
+                WidgetEvent::PointerMove { .. } => {}
+            }
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: WidgetId, id: WidgetId) -> bool {
+        let mut current = self.parent(id);
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self.parent(parent);
+        }
+        false
+    }
+
+    fn entry(&self, id: WidgetId) -> Option<&Entry> {
+        self.entries.get(id.0)?.as_ref()
+    }
+
+    fn entry_mut(&mut self, id: WidgetId) -> Option<&mut Entry> {
+        self.entries.get_mut(id.0)?.as_mut()
+    }
+}
+
+fn transition_name(id: WidgetId) -> String {
+    format!("style-{}", id.0)
+}
+
+/// Left and right swap in RTL, so `Edges::left` is always the leading side
+fn to_lengths(edges: Edges) -> taffy::geometry::Rect<taffy::style::LengthPercentage> {
+    let (left, right) = LayoutDirection::current().sides(edges.left, edges.right);
+    taffy::geometry::Rect {
+        left: taffy::style::LengthPercentage::Length(left),
+        right: taffy::style::LengthPercentage::Length(right),
+        top: taffy::style::LengthPercentage::Length(edges.top),
+        bottom: taffy::style::LengthPercentage::Length(edges.bottom),
+    }
+}
+
+impl Default for WidgetTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Button, Spacer, Style, Text, VStack};
+    use nebula_gfx::DrawCommand;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn tree(clicks: Rc<Cell<u32>>) -> (WidgetTree, WidgetId, WidgetId) {
+        let mut tree = WidgetTree::new();
+        let root = tree.insert(VStack::new().spacing(10.0).padding(20.0));
+        tree.append_child(root, Text::new("Title").font_size(20));
+        let button = tree.append_child(root, Button::new("OK").size(100.0, 40.0).on_click(move || clicks.set(clicks.get() + 1)));
+        tree.append_child(root, Spacer::fixed(10.0, 10.0));
+        tree.set_root(root);
+        tree.layout(400.0, 300.0).unwrap();
+        (tree, root, button)
+    }
+
+    #[test]
+    fn tree_lays_out_children() {
+        let (tree, root, button) = tree(Rc::new(Cell::new(0)));
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.children(root).len(), 3);
+
+        // Title is 24px tall (20 * 1.2), plus padding and spacing
+        let (x, y, width, height) = tree.bounds(button).unwrap();
+        assert_eq!((x, y), (20.0, 54.0));
+        assert_eq!((width, height), (100.0, 40.0));
+    }
+
+    #[test]
+    fn tree_paints_and_hit_tests() {
+        let (tree, root, button) = tree(Rc::new(Cell::new(0)));
+        let mut list = DrawList::new();
+        tree.paint(&mut list);
+        assert!(list.commands().any(|command| matches!(command, DrawCommand::Text { text, .. } if text == "OK")));
+
+        assert_eq!(tree.hit_test(30.0, 60.0), Some(button));
+        assert_eq!(tree.hit_test(60.0, 100.0), Some(root));
+        assert_eq!(tree.hit_test(500.0, 10.0), None);
+    }
+
+    #[test]
+    fn tree_dispatches_clicks() {
+        let clicks = Rc::new(Cell::new(0));
+        let (mut tree, _, button) = tree(clicks.clone());
+        assert_eq!(tree.dispatch(&WidgetEvent::PointerDown { x: 30.0, y: 60.0 }), Some(button));
+        assert_eq!(tree.dispatch(&WidgetEvent::PointerUp { x: 30.0, y: 60.0 }), Some(button));
+        assert_eq!(clicks.get(), 1);
+
+        // Nobody handles clicks on the background
+        assert_eq!(tree.dispatch(&WidgetEvent::PointerDown { x: 60.0, y: 100.0 }), None);
+    }
+
+    #[test]
+    fn tree_cascades_styles_and_states() {
+        let (mut tree, root, button) = tree(Rc::new(Cell::new(0)));
+        let title = tree.children(root)[0];
+        tree.set_style(root, Style::new().background((10, 20, 30, 255)).text_color((200, 0, 0, 255)));
+        tree.set_style(title, Style::new().margin(5.0).hover(Style::new().text_color((0, 200, 0, 255))));
+        tree.layout(400.0, 300.0).unwrap();
+        assert_eq!(tree.bounds(title).unwrap().0, 25.0);
+
+        let mut list = DrawList::new();
+        tree.paint(&mut list);
+        assert!(list.commands().any(|command| matches!(command, DrawCommand::Rect { color: (10, 20, 30, 255), .. })));
+        assert!(list.commands().any(|command| matches!(command, DrawCommand::Text { text, color: (200, 0, 0, 255), .. } if text == "Title")));
+
+        let (x, y, _, _) = tree.bounds(title).unwrap();
+        tree.dispatch(&WidgetEvent::PointerMove { x: x + 1.0, y: y + 1.0 });
+        assert!(tree.state(title).hovered && tree.state(root).hovered);
+        assert_eq!(tree.computed_style(title).unwrap().text_color, Some((0, 200, 0, 255)));
+
+        // Disabled widgets don't get events
+        tree.set_disabled(button, true);
+        assert_eq!(tree.dispatch(&WidgetEvent::PointerDown { x: 30.0, y: 70.0 }), None);
+    }
+
+    #[test]
+    fn style_changes_transition_smoothly() {
+        let (mut tree, _, button) = tree(Rc::new(Cell::new(0)));
+        let hover = Style::new().background((200, 100, 0, 255)).scale(1.5);
+        tree.set_style(button, Style::new().background((0, 0, 0, 255)).hover(hover).transition(std::time::Duration::from_millis(200)));
+        let background = |tree: &WidgetTree| {
+            let mut list = DrawList::new();
+            tree.paint(&mut list);
+            let found = list.commands().find_map(|command| match command {
+                DrawCommand::Rect { rect, color } if rect.2 >= 100.0 && rect.2 < 400.0 => Some((*rect, *color)),
+                _ => None,
+            });
+            found.unwrap()
+        };
+        assert!(!tree.animate_by(0.016));
+        assert_eq!(background(&tree).1, (0, 0, 0, 255));
+
+        tree.dispatch(&WidgetEvent::PointerMove { x: 30.0, y: 60.0 });
+        assert!(tree.animate_by(0.03));
+        let (rect, color) = background(&tree);
+        assert!(color.0 > 0 && color.0 < 200, "{:?}", color);
+        assert!(rect.2 > 100.0 && rect.2 < 150.0, "{:?}", rect);
+
+        // Settles on the hover style within the duration
+        for _ in 0..12 {
+            tree.animate_by(0.016);
+        }
+        assert!(!tree.animate_by(0.016));
+        let (rect, color) = background(&tree);
+        assert_eq!(color, (200, 100, 0, 255));
+        assert_eq!(rect, (-5.0, 44.0, 150.0, 60.0));
+
+        tree.set_style(button, Style::new().background((0, 0, 0, 255)).opacity(0.5));
+        tree.animate_by(0.016);
+        assert_eq!(background(&tree).1, (0, 0, 0, 128));
+    }
+
+    #[test]
+    fn tree_downcasts_and_removes() {
+        let (mut tree, root, button) = tree(Rc::new(Cell::new(0)));
+        tree.get_mut::<Button>(button).unwrap().label = "Cancel".to_string();
+        assert_eq!(tree.get::<Button>(button).unwrap().label, "Cancel");
+        assert!(tree.get::<Text>(button).is_none());
+
+        tree.remove(button);
+        assert_eq!(tree.children(root).len(), 2);
+        tree.layout(400.0, 300.0).unwrap();
+        assert!(tree.bounds(button).is_none());
+    }
+}
@@ -0,0 +1,231 @@
+// ZoomView - Zoom and pan state for content larger (or smaller) than its viewport
+// Used by ImageViewer; works for anything with a fixed content size
+
+use nebula_gfx::draw_list::Rect;
+
+/// Zoom/pan state mapping content pixels into a viewport 🔍
+///
+/// `scale` is content pixels → viewport pixels, `offset` is where the
+/// content's top-left corner sits in the viewport. Content smaller than the
+/// viewport is centered; larger content can be panned but never past its edges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoomView {
+    pub content_size: (f32, f32),
+    pub viewport_size: (f32, f32),
+    pub scale: f32,
+    pub offset: (f32, f32),
+    pub min_scale: f32,
+    pub max_scale: f32,
+    drag_from: Option<(f32, f32)>,
+}
+
+impl ZoomView {
+    /// Create a zoom view showing the content fitted into the viewport
+    pub fn new(content_width: f32, content_height: f32, viewport_width: f32, viewport_height: f32) -> Self {
+        let mut view = Self {
+            content_size: (content_width, content_height),
+            viewport_size: (viewport_width, viewport_height),
+            scale: 1.0,
+            offset: (0.0, 0.0),
+            min_scale: 0.1,
+            max_scale: 8.0,
+            drag_from: None,
+        };
+        view.fit();
+        view
+    }
+
+    /// Set the zoom limits
+    pub fn scale_range(mut self, min: f32, max: f32) -> Self {
+        self.min_scale = min;
+        self.max_scale = max.max(min);
+        self.set_scale(self.scale);
+        self
+    }
+
+    /// Replace the content (e.g. next image) and fit it
+    pub fn set_content_size(&mut self, width: f32, height: f32) {
+        self.content_size = (width, height);
+        self.fit();
+    }
+
+    /// Resize the viewport, keeping the point under its center in place
+    pub fn set_viewport_size(&mut self, width: f32, height: f32) {
+        let was_fitted = self.is_fitted();
+        let center = self.to_content(self.viewport_size.0 / 2.0, self.viewport_size.1 / 2.0);
+        self.viewport_size = (width, height);
+        if was_fitted {
+            self.fit();
+            return;
+        }
+        self.offset = (width / 2.0 - center.0 * self.scale, height / 2.0 - center.1 * self.scale);
+        self.clamp_offset();
+    }
+
+    /// Scale at which the whole content fits the viewport
+    pub fn fit_scale(&self) -> f32 {
+        let (cw, ch) = self.content_size;
+        let (vw, vh) = self.viewport_size;
+        if cw <= 0.0 || ch <= 0.0 {
+            return 1.0;
+        }
+        (vw / cw).min(vh / ch)
+    }
+
+    /// Fit the content and center it
+    pub fn fit(&mut self) {
+        self.scale = self.fit_scale();
+        self.clamp_offset();
+    }
+
+    /// Show the content at 1:1 pixels, centered
+    pub fn actual_size(&mut self) {
+        self.scale = 1.0;
+        self.offset = (
+            (self.viewport_size.0 - self.content_size.0) / 2.0,
+            (self.viewport_size.1 - self.content_size.1) / 2.0,
+        );
+        self.clamp_offset();
+    }
+
+    /// Is the content shown fitted?
+    pub fn is_fitted(&self) -> bool {
+        (self.scale - self.fit_scale()).abs() < 0.001
+    }
+
+    /// Switch between fit and 1:1
+    pub fn toggle_fit(&mut self) {
+        if self.is_fitted() {
+            self.actual_size();
+        } else {
+            self.fit();
+        }
+    }
+
+    /// Zoom to a scale, keeping the viewport center in place
+    pub fn set_scale(&mut self, scale: f32) {
+        let (vw, vh) = self.viewport_size;
+        self.zoom_at(scale / self.scale, vw / 2.0, vh / 2.0);
+    }
+
+    /// Zoom around a viewport point (factor > 1 zooms in)
+    /// The content pixel under the point stays under it
+    pub fn zoom_at(&mut self, factor: f32, x: f32, y: f32) {
+        if factor <= 0.0 || !factor.is_finite() {
+            return;
+        }
+        let scale = (self.scale * factor).clamp(self.min_scale, self.max_scale);
+        let ratio = scale / self.scale;
+        self.offset = (x - (x - self.offset.0) * ratio, y - (y - self.offset.1) * ratio);
+        self.scale = scale;
+        self.clamp_offset();
+    }
+
+    /// Mouse wheel (positive delta = scroll up = zoom in)
+    pub fn handle_wheel(&mut self, delta: f32, x: f32, y: f32) {
+        self.zoom_at(1.15f32.powf(delta), x, y);
+    }
+
+    /// Trackpad / touch pinch (scale > 1 = fingers apart = zoom in)
+    pub fn handle_pinch(&mut self, scale: f32, center_x: f32, center_y: f32) {
+        self.zoom_at(scale, center_x, center_y);
+    }
+
+    /// Move the content
+    pub fn pan_by(&mut self, dx: f32, dy: f32) {
+        self.offset = (self.offset.0 + dx, self.offset.1 + dy);
+        self.clamp_offset();
+    }
+
+    /// Start a drag pan
+    pub fn begin_drag(&mut self, x: f32, y: f32) {
+        self.drag_from = Some((x, y));
+    }
+
+    /// Continue a drag pan
+    pub fn drag_to(&mut self, x: f32, y: f32) {
+        let Some((from_x, from_y)) = self.drag_from else {
+            return;
+        };
+        self.pan_by(x - from_x, y - from_y);
+        self.drag_from = Some((x, y));
+    }
+
+    /// Finish a drag pan
+    pub fn end_drag(&mut self) {
+        self.drag_from = None;
+    }
+
+    /// Is a drag pan in progress?
+    pub fn is_dragging(&self) -> bool {
+        self.drag_from.is_some()
+    }
+
+    /// Where the content is drawn, in viewport pixels (x, y, width, height)
+    pub fn content_rect(&self) -> Rect {
+        (self.offset.0, self.offset.1, self.content_size.0 * self.scale, self.content_size.1 * self.scale)
+    }
+
+    /// Map a viewport point to content pixels
+    pub fn to_content(&self, x: f32, y: f32) -> (f32, f32) {
+        ((x - self.offset.0) / self.scale, (y - self.offset.1) / self.scale)
+    }
+
+    /// Map a content pixel to the viewport
+    pub fn to_viewport(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.offset.0 + x * self.scale, self.offset.1 + y * self.scale)
+    }
+
+    fn clamp_offset(&mut self) {
+        let clamp_axis = |offset: f32, content: f32, viewport: f32| {
+            if content <= viewport {
+                (viewport - content) / 2.0
+            } else {
+                offset.clamp(viewport - content, 0.0)
+            }
+        };
+        let (_, _, width, height) = self.content_rect();
+        self.offset = (
+            clamp_axis(self.offset.0, width, self.viewport_size.0),
+            clamp_axis(self.offset.1, height, self.viewport_size.1),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zoom_view_fits_and_centers() {
+        let mut view = ZoomView::new(2000.0, 1000.0, 800.0, 600.0);
+        assert_eq!(view.scale, 0.4);
+        assert_eq!(view.content_rect(), (0.0, 100.0, 800.0, 400.0));
+
+        view.toggle_fit();
+        assert_eq!(view.scale, 1.0);
+        assert_eq!(view.offset, (-600.0, -200.0));
+        view.toggle_fit();
+        assert!(view.is_fitted());
+    }
+
+    #[test]
+    fn zoom_view_zooms_about_cursor() {
+        let mut view = ZoomView::new(800.0, 600.0, 800.0, 600.0);
+        let before = view.to_content(200.0, 150.0);
+        view.zoom_at(2.0, 200.0, 150.0);
+        assert_eq!(view.scale, 2.0);
+        assert_eq!(view.to_content(200.0, 150.0), before);
+
+        // Panning stops at the content edges
+        view.pan_by(1000.0, 1000.0);
+        assert_eq!(view.offset, (0.0, 0.0));
+        view.begin_drag(100.0, 100.0);
+        view.drag_to(50.0, 80.0);
+        view.end_drag();
+        assert_eq!(view.offset, (-50.0, -20.0));
+
+        view.set_scale(100.0);
+        assert_eq!(view.scale, 8.0);
+    }
+}
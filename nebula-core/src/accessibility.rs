@@ -5,12 +5,16 @@
 //! - Screen reader support (Narrator, VoiceOver, Orca)
 //! - Keyboard navigation
 //! - WCAG 2.1 Level AA compliance
+//! - Contrast auditing in debug builds
+//! - Live regions so status changes are announced, not just shown
 //! 
 //! Built with AccessKit - the universal accessibility toolkit!
 
 use accesskit::{
     Node, NodeId as AccessNodeId, Role, Tree, TreeUpdate,
 };
+use crate::theme::ThemeColor;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tracing::{info, warn};
@@ -53,6 +57,8 @@ pub struct AccessNode {
     pub focusable: bool,
     /// Is disabled?
     pub disabled: bool,
+    /// Live region politeness (changes are announced when set)
+    pub live: Option<Politeness>,
 }
 
 impl AccessibilityTree {
@@ -75,6 +81,7 @@ impl AccessibilityTree {
                 children: Vec::new(),
                 focusable: false,
                 disabled: false,
+                live: None,
             },
         );
         
@@ -102,6 +109,7 @@ impl AccessibilityTree {
             children: Vec::new(),
             focusable: true,
             disabled: false,
+            live: None,
         };
         
         self.nodes.insert(id, node);
@@ -125,6 +133,7 @@ impl AccessibilityTree {
             children: Vec::new(),
             focusable: false,
             disabled: false,
+            live: None,
         };
         
         self.nodes.insert(id, node);
@@ -149,6 +158,7 @@ impl AccessibilityTree {
             children: Vec::new(),
             focusable: true,
             disabled: false,
+            live: None,
         };
         
         self.nodes.insert(id, node);
@@ -172,6 +182,7 @@ impl AccessibilityTree {
             children: Vec::new(),
             focusable: true,
             disabled: false,
+            live: None,
         };
         
         self.nodes.insert(id, node);
@@ -290,6 +301,10 @@ impl AccessibilityTree {
             if !node.children.is_empty() {
                 builder.set_children(node.children.clone());
             }
+
+            if let Some(politeness) = node.live {
+                builder.set_live(politeness.to_live());
+            }
             
             nodes_vec.push((node.id, builder.build(&mut class_set)));
         }
@@ -301,6 +316,45 @@ impl AccessibilityTree {
         }
     }
 
+    /// Add a live region: screen readers announce its label whenever it changes
+    pub fn add_live_region(&mut self, politeness: Politeness) -> AccessNodeId {
+        let id = self.next_node_id();
+
+        info!("♿ Adding {:?} live region", politeness);
+
+        let node = AccessNode {
+            id,
+            role: if politeness == Politeness::Assertive { Role::Alert } else { Role::Status },
+            label: None,
+            value: None,
+            description: None,
+            children: Vec::new(),
+            focusable: false,
+            disabled: false,
+            live: Some(politeness),
+        };
+
+        self.nodes.insert(id, node);
+        self.add_child_to_root(id);
+        id
+    }
+
+    /// Move queued announcements into the tree's live regions
+    ///
+    /// Call before `build_tree_update`; returns true if anything changed.
+    /// One region per politeness level is created on first use, and the
+    /// latest message of each level wins (as in the browser).
+    pub fn flush_announcements(&mut self) -> bool {
+        let announcements = take_announcements();
+        for announcement in &announcements {
+            let politeness = announcement.politeness;
+            let existing = self.nodes.values().find(|node| node.live == Some(politeness)).map(|node| node.id);
+            let id = existing.unwrap_or_else(|| self.add_live_region(politeness));
+            self.update_label(id, announcement.text.clone());
+        }
+        !announcements.is_empty()
+    }
+
     /// Get node count
     pub fn node_count(&self) -> usize {
         self.nodes.len()
@@ -332,6 +386,266 @@ impl Default for AccessibilityTree {
     }
 }
 
+/// How urgently a live region's changes are announced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    /// Not announced
+    Off,
+    /// Announced when the screen reader is idle (status, progress)
+    Polite,
+    /// Interrupts whatever is being read (errors)
+    Assertive,
+}
+
+impl Politeness {
+    /// AccessKit's equivalent
+    pub fn to_live(self) -> accesskit::Live {
+        match self {
+            Politeness::Off => accesskit::Live::Off,
+            Politeness::Polite => accesskit::Live::Polite,
+            Politeness::Assertive => accesskit::Live::Assertive,
+        }
+    }
+}
+
+/// A message waiting to be read out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    pub text: String,
+    pub politeness: Politeness,
+}
+
+thread_local! {
+    static ANNOUNCEMENTS: RefCell<Vec<Announcement>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Queue a message for assistive tech 📢
+///
+/// Components call this when they show something that would otherwise be
+/// visual-only (a toast, an alert, progress). `AccessibilityTree::flush_announcements`
+/// moves the queue into live regions. `Politeness::Off` and empty text are ignored.
+pub fn announce(text: impl Into<String>, politeness: Politeness) {
+    let text = text.into();
+    if politeness == Politeness::Off || text.trim().is_empty() {
+        return;
+    }
+    ANNOUNCEMENTS.with(|queue| queue.borrow_mut().push(Announcement { text, politeness }));
+}
+
+/// Drain the queued announcements, oldest first
+pub fn take_announcements() -> Vec<Announcement> {
+    ANNOUNCEMENTS.with(|queue| std::mem::take(&mut *queue.borrow_mut()))
+}
+
+/// WCAG conformance level used by the contrast audit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// Level AA (4.5:1 normal text, 3:1 large text)
+    AA,
+    /// Level AAA (7:1 normal text, 4.5:1 large text)
+    AAA,
+}
+
+impl WcagLevel {
+    /// Minimum contrast ratio required at this level
+    pub fn required_ratio(&self, large_text: bool) -> f32 {
+        match (self, large_text) {
+            (WcagLevel::AA, false) => 4.5,
+            (WcagLevel::AA, true) => 3.0,
+            (WcagLevel::AAA, false) => 7.0,
+            (WcagLevel::AAA, true) => 4.5,
+        }
+    }
+}
+
+/// Relative luminance of an RGBA color (WCAG 2.1 definition)
+pub fn relative_luminance(color: ThemeColor) -> f32 {
+    fn channel(c: u8) -> f32 {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    0.2126 * channel(color.0) + 0.7152 * channel(color.1) + 0.0722 * channel(color.2)
+}
+
+/// Contrast ratio between a foreground and background color (1.0 to 21.0)
+///
+/// Translucent foregrounds are composited over the background first,
+/// which is how they actually end up on screen.
+pub fn contrast_ratio(foreground: ThemeColor, background: ThemeColor) -> f32 {
+    let alpha = foreground.3 as f32 / 255.0;
+    let blend = |fg: u8, bg: u8| (fg as f32 * alpha + bg as f32 * (1.0 - alpha)).round() as u8;
+    let composited = (
+        blend(foreground.0, background.0),
+        blend(foreground.1, background.1),
+        blend(foreground.2, background.2),
+        255,
+    );
+
+    let l1 = relative_luminance(composited);
+    let l2 = relative_luminance(background);
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// A text/background pair that failed the contrast audit
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// Component that rendered the pair
+    pub component_id: String,
+    /// Text color
+    pub foreground: ThemeColor,
+    /// Background color
+    pub background: ThemeColor,
+    /// Measured contrast ratio
+    pub ratio: f32,
+    /// Ratio required by the audit level
+    pub required: f32,
+}
+
+/// Something that renders text and can report its text/background pairs
+///
+/// Implemented by components so an app can audit a whole screen by
+/// walking its widgets instead of listing colors by hand.
+pub trait ContrastSource {
+    /// Report every text/background pair this component renders
+    fn audit_contrast(&self, audit: &mut AccessibilityAudit);
+}
+
+/// Accessibility Audit - Catch unreadable text before users do! ♿
+///
+/// Components report the text/background pairs they render and the audit
+/// checks each one against the WCAG contrast requirements. Enabled by
+/// default in debug builds only, so release builds pay nothing.
+pub struct AccessibilityAudit {
+    /// Is the audit enabled?
+    enabled: bool,
+    /// Level to check against
+    level: WcagLevel,
+    /// Number of pairs checked
+    checked: usize,
+    /// Failing pairs
+    issues: Vec<ContrastIssue>,
+}
+
+impl AccessibilityAudit {
+    /// Create a new audit (enabled in debug builds)
+    pub fn new() -> Self {
+        Self {
+            enabled: cfg!(debug_assertions),
+            level: WcagLevel::AA,
+            checked: 0,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Set the WCAG level to check against
+    pub fn level(mut self, level: WcagLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Enable or disable the audit
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Is the audit enabled?
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Check a text/background pair rendered by a component
+    /// Returns true if the pair passes (or the audit is disabled)
+    pub fn check_contrast(
+        &mut self,
+        component_id: impl Into<String>,
+        foreground: ThemeColor,
+        background: ThemeColor,
+        large_text: bool,
+    ) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        self.checked += 1;
+        let ratio = contrast_ratio(foreground, background);
+        let required = self.level.required_ratio(large_text);
+
+        if ratio + f32::EPSILON < required {
+            let component_id = component_id.into();
+            warn!(
+                "♿ Low contrast in '{}': {:.2}:1 (required {:.1}:1)",
+                component_id, ratio, required
+            );
+            self.issues.push(ContrastIssue {
+                component_id,
+                foreground,
+                background,
+                ratio,
+                required,
+            });
+            return false;
+        }
+
+        true
+    }
+
+    /// Let a component report all of its pairs
+    pub fn audit(&mut self, source: &dyn ContrastSource) -> &mut Self {
+        source.audit_contrast(self);
+        self
+    }
+
+    /// Number of pairs checked
+    pub fn checked_count(&self) -> usize {
+        self.checked
+    }
+
+    /// Failing pairs
+    pub fn issues(&self) -> &[ContrastIssue] {
+        &self.issues
+    }
+
+    /// Did every checked pair pass?
+    pub fn passed(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Clear all results
+    pub fn clear(&mut self) {
+        self.checked = 0;
+        self.issues.clear();
+    }
+
+    /// Print audit summary
+    pub fn print_summary(&self) {
+        if !self.enabled {
+            return;
+        }
+
+        info!("♿ CONTRAST AUDIT ({:?})", self.level);
+        info!("  Pairs checked: {}", self.checked);
+        info!("  Failures: {}", self.issues.len());
+        for issue in &self.issues {
+            info!(
+                "  - {}: {:.2}:1 (required {:.1}:1)",
+                issue.component_id, issue.ratio, issue.required
+            );
+        }
+    }
+}
+
+impl Default for AccessibilityAudit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -480,4 +794,92 @@ mod tests {
         let tree = AccessibilityTree::default();
         assert_eq!(tree.node_count(), 1);
     }
+
+    #[test]
+    fn announcements_fill_live_regions() {
+        let mut tree = AccessibilityTree::new();
+        announce("Saved", Politeness::Polite);
+        announce("ignored", Politeness::Off);
+        announce("Upload failed", Politeness::Assertive);
+        announce("Saved again", Politeness::Polite);
+        assert!(tree.flush_announcements());
+        assert_eq!(tree.node_count(), 3); // Root + one region per level
+
+        let label = |politeness| tree.nodes.values().find(|n| n.live == Some(politeness)).and_then(|n| n.label.clone());
+        assert_eq!(label(Politeness::Polite).as_deref(), Some("Saved again"));
+        assert_eq!(label(Politeness::Assertive).as_deref(), Some("Upload failed"));
+        assert!(!tree.flush_announcements());
+    }
+
+    #[test]
+    fn contrast_ratio_extremes() {
+        let ratio = contrast_ratio((0, 0, 0, 255), (255, 255, 255, 255));
+        assert!((ratio - 21.0).abs() < 0.01);
+
+        let ratio = contrast_ratio((128, 128, 128, 255), (128, 128, 128, 255));
+        assert!((ratio - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn contrast_ratio_composites_alpha() {
+        // Fully transparent text is invisible
+        let ratio = contrast_ratio((0, 0, 0, 0), (255, 255, 255, 255));
+        assert!((ratio - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn wcag_required_ratios() {
+        assert_eq!(WcagLevel::AA.required_ratio(false), 4.5);
+        assert_eq!(WcagLevel::AA.required_ratio(true), 3.0);
+        assert_eq!(WcagLevel::AAA.required_ratio(false), 7.0);
+    }
+
+    #[test]
+    fn audit_reports_failures() {
+        let mut audit = AccessibilityAudit::new();
+        audit.set_enabled(true);
+
+        assert!(audit.check_contrast("title", (0, 0, 0, 255), (255, 255, 255, 255), false));
+        assert!(!audit.check_contrast("hint", (200, 200, 200, 255), (255, 255, 255, 255), false));
+
+        assert_eq!(audit.checked_count(), 2);
+        assert!(!audit.passed());
+        assert_eq!(audit.issues().len(), 1);
+        assert_eq!(audit.issues()[0].component_id, "hint");
+
+        audit.clear();
+        assert!(audit.passed());
+        assert_eq!(audit.checked_count(), 0);
+    }
+
+    #[test]
+    fn audit_disabled_skips_checks() {
+        let mut audit = AccessibilityAudit::new();
+        audit.set_enabled(false);
+
+        assert!(audit.check_contrast("hint", (250, 250, 250, 255), (255, 255, 255, 255), false));
+        assert_eq!(audit.checked_count(), 0);
+    }
+
+    #[test]
+    fn audit_walks_contrast_sources() {
+        struct Label(ThemeColor, ThemeColor);
+        impl ContrastSource for Label {
+            fn audit_contrast(&self, audit: &mut AccessibilityAudit) {
+                audit.check_contrast("label", self.0, self.1, false);
+            }
+        }
+
+        let mut audit = AccessibilityAudit::new();
+        audit.set_enabled(true);
+        let sources: Vec<Box<dyn ContrastSource>> = vec![
+            Box::new(Label((0, 0, 0, 255), (255, 255, 255, 255))),
+            Box::new(Label((220, 220, 220, 255), (255, 255, 255, 255))),
+        ];
+        for source in &sources {
+            audit.audit(source.as_ref());
+        }
+        assert_eq!(audit.checked_count(), 2);
+        assert_eq!(audit.issues().len(), 1);
+    }
 }
@@ -0,0 +1,213 @@
+//! Analytics - Opt-in product metrics! 📊
+//!
+//! Nothing is recorded until the app installs an `AnalyticsSink`; after
+//! that the framework reports structured events on its own:
+//! - screen views when `Navigation` changes screens
+//! - interactions on anything tagged with a test id (`Button::test_id`,
+//!   `EventDispatcher::set_test_id`)
+//! - frame-time percentiles from the window's render loop
+//!
+//! The sink decides where events go (a file, an HTTP batcher, a vendor
+//! SDK), so the framework needs no patches to be instrumented. Events
+//! serialize with serde for sinks that ship JSON.
+
+use serde::Serialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// One structured analytics event
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnalyticsEvent {
+    /// The user reached a screen
+    ScreenView { screen: String, previous: Option<String> },
+    /// The user did something to a tagged element
+    Interaction { test_id: String, action: String, screen: Option<String> },
+    /// Frame times over the last reporting window, in milliseconds
+    FrameTimes { frames: usize, p50_ms: f32, p90_ms: f32, p99_ms: f32, max_ms: f32 },
+    /// App-defined event
+    Custom { name: String, properties: Vec<(String, String)> },
+}
+
+/// Where analytics events go
+pub trait AnalyticsSink {
+    /// Record one event (`session_time` is time since `Analytics::install`)
+    fn record(&self, event: &AnalyticsEvent, session_time: Duration);
+
+    /// Push buffered events out (called by `Analytics::flush`)
+    fn flush(&self) {}
+}
+
+/// Closures are sinks too
+impl<F: Fn(&AnalyticsEvent, Duration)> AnalyticsSink for F {
+    fn record(&self, event: &AnalyticsEvent, session_time: Duration) {
+        self(event, session_time)
+    }
+}
+
+struct AnalyticsState {
+    sink: Rc<dyn AnalyticsSink>,
+    started: Instant,
+    screen: Option<String>,
+    frame_times: Vec<Duration>,
+    frames_per_report: usize,
+}
+
+thread_local! {
+    static ANALYTICS: RefCell<Option<AnalyticsState>> = const { RefCell::new(None) };
+}
+
+/// Analytics - The app-wide analytics hook (per UI thread) 📊
+///
+/// # Example
+/// ```rust,ignore
+/// Analytics::install(Rc::new(|event: &AnalyticsEvent, at: Duration| {
+///     uploader.push(at, serde_json::to_string(event).unwrap());
+/// }));
+/// Analytics::event("checkout_started", &[("items", "3")]);
+/// ```
+pub struct Analytics;
+
+impl Analytics {
+    /// Start recording into `sink` (frame times reported every 600 frames)
+    pub fn install(sink: Rc<dyn AnalyticsSink>) {
+        info!("📊 Analytics enabled");
+        ANALYTICS.with(|slot| {
+            *slot.borrow_mut() = Some(AnalyticsState {
+                sink,
+                started: Instant::now(),
+                screen: None,
+                frame_times: Vec::new(),
+                frames_per_report: 600,
+            })
+        });
+    }
+
+    /// Stop recording (flushes the sink first)
+    pub fn uninstall() {
+        Self::flush();
+        ANALYTICS.with(|slot| *slot.borrow_mut() = None);
+    }
+
+    /// Is a sink installed?
+    pub fn is_enabled() -> bool {
+        ANALYTICS.with(|slot| slot.borrow().is_some())
+    }
+
+    /// Frames per `FrameTimes` report
+    pub fn set_frames_per_report(frames: usize) {
+        Self::with_state(|state| state.frames_per_report = frames.max(1));
+    }
+
+    /// The user reached a screen (repeats of the current screen are ignored)
+    pub fn screen_view(screen: &str) {
+        let event = Self::with_state(|state| {
+            if state.screen.as_deref() == Some(screen) {
+                return None;
+            }
+            let previous = state.screen.replace(screen.to_string());
+            Some(AnalyticsEvent::ScreenView { screen: screen.to_string(), previous })
+        });
+        if let Some(event) = event.flatten() {
+            Self::emit(event);
+        }
+    }
+
+    /// The user interacted with a tagged element ("click", "toggle"…)
+    pub fn interaction(test_id: &str, action: &str) {
+        let screen = Self::with_state(|state| state.screen.clone());
+        if let Some(screen) = screen {
+            Self::emit(AnalyticsEvent::Interaction { test_id: test_id.to_string(), action: action.to_string(), screen });
+        }
+    }
+
+    /// Add one frame's render time; emits percentiles once enough piled up
+    pub fn frame(frame_time: Duration) {
+        let report = Self::with_state(|state| {
+            state.frame_times.push(frame_time);
+            if state.frame_times.len() < state.frames_per_report {
+                return None;
+            }
+            let mut times = std::mem::take(&mut state.frame_times);
+            times.sort();
+            let percentile = |p: f32| {
+                let index = ((times.len() - 1) as f32 * p).round() as usize;
+                times[index].as_secs_f32() * 1000.0
+            };
+            Some(AnalyticsEvent::FrameTimes {
+                frames: times.len(),
+                p50_ms: percentile(0.5),
+                p90_ms: percentile(0.9),
+                p99_ms: percentile(0.99),
+                max_ms: percentile(1.0),
+            })
+        });
+        if let Some(event) = report.flatten() {
+            Self::emit(event);
+        }
+    }
+
+    /// App-defined event
+    pub fn event(name: &str, properties: &[(&str, &str)]) {
+        let properties = properties.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+        Self::emit(AnalyticsEvent::Custom { name: name.to_string(), properties });
+    }
+
+    /// Ask the sink to push out what it buffered (e.g. before quitting)
+    pub fn flush() {
+        if let Some(sink) = Self::with_state(|state| state.sink.clone()) {
+            sink.flush();
+        }
+    }
+
+    /// Run `f` on the state if analytics is on
+    fn with_state<R>(f: impl FnOnce(&mut AnalyticsState) -> R) -> Option<R> {
+        ANALYTICS.with(|slot| slot.borrow_mut().as_mut().map(f))
+    }
+
+    /// Hand an event to the sink (outside the borrow, so sinks may emit too)
+    fn emit(event: AnalyticsEvent) {
+        if let Some((sink, elapsed)) = Self::with_state(|state| (state.sink.clone(), state.started.elapsed())) {
+            sink.record(&event, elapsed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_only_after_install() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        Analytics::interaction("checkout", "click");
+        assert!(!Analytics::is_enabled());
+
+        let log = events.clone();
+        Analytics::install(Rc::new(move |event: &AnalyticsEvent, _: Duration| log.borrow_mut().push(event.clone())));
+        Analytics::set_frames_per_report(4);
+        Analytics::screen_view("cart");
+        Analytics::screen_view("cart");
+        Analytics::interaction("checkout", "click");
+        for ms in [10, 12, 30, 11] {
+            Analytics::frame(Duration::from_millis(ms));
+        }
+        Analytics::uninstall();
+        Analytics::screen_view("done");
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0], AnalyticsEvent::ScreenView { screen: "cart".into(), previous: None });
+        assert_eq!(
+            serde_json::to_string(&events[1]).unwrap(),
+            r#"{"type":"interaction","test_id":"checkout","action":"click","screen":"cart"}"#
+        );
+        let AnalyticsEvent::FrameTimes { frames, p50_ms, max_ms, .. } = events[2] else {
+            panic!("expected frame times, got {:?}", events[2]);
+        };
+        assert_eq!(frames, 4);
+        assert!((p50_ms - 12.0).abs() < 0.01 && (max_ms - 30.0).abs() < 0.01);
+    }
+}
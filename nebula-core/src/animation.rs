@@ -6,12 +6,233 @@
 //! - Animation modifiers (.scale(), .fade(), .rotate())
 //! - Implicit animations (SwiftUI-style)
 //! - 60 FPS on old hardware!
+//! - Reduced motion for users who ask for it (`MotionPreference`)
+//! - Slow motion, pause and frame stepping for debugging (`AnimationClock`)
 //! 
 //! Physics-based animations feel NATURAL and RESPONSIVE!
 
+use crate::quality::QualitySettings;
+use crate::signal::Signal;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
 use std::time::{Duration, Instant};
 use tracing::{info, warn};
 
+/// How much motion the user wants 🧘
+///
+/// Some people get dizzy or nauseous from moving interfaces, and WCAG
+/// 2.3.3 asks apps to let them turn it off. With `Reduced`, springs jump
+/// straight to their target and style transitions are skipped; springs
+/// marked `essential()` (progress indicators) keep animating.
+///
+/// The platform layer reports the OS setting with `set_system`; apps can
+/// override it (e.g. from their own settings screen) with `set_override`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MotionPreference {
+    /// Animate everything
+    #[default]
+    Full,
+    /// Skip non-essential motion
+    Reduced,
+}
+
+struct MotionState {
+    system: MotionPreference,
+    app_override: Option<MotionPreference>,
+    effective: Signal<MotionPreference>,
+}
+
+thread_local! {
+    static MOTION: RefCell<MotionState> = RefCell::new(MotionState {
+        system: MotionPreference::Full,
+        app_override: None,
+        effective: Signal::new(MotionPreference::Full),
+    });
+}
+
+impl MotionPreference {
+    /// The preference animations follow right now (per UI thread)
+    pub fn current() -> Self {
+        MOTION.with(|motion| {
+            let motion = motion.borrow();
+            motion.app_override.unwrap_or(motion.system)
+        })
+    }
+
+    /// Shortcut for `current() == Reduced`
+    pub fn is_reduced() -> bool {
+        Self::current() == MotionPreference::Reduced
+    }
+
+    /// Signal of the effective preference (fires when it changes)
+    pub fn signal() -> Signal<MotionPreference> {
+        MOTION.with(|motion| motion.borrow().effective.clone())
+    }
+
+    /// Record the OS accessibility setting
+    pub fn set_system(preference: MotionPreference) {
+        MOTION.with(|motion| motion.borrow_mut().system = preference);
+        Self::publish();
+    }
+
+    /// Override the OS setting (None follows the OS again)
+    pub fn set_override(preference: Option<MotionPreference>) {
+        MOTION.with(|motion| motion.borrow_mut().app_override = preference);
+        Self::publish();
+    }
+
+    /// Length of a transition under the current preference (zero when reduced)
+    pub fn duration(duration: Duration) -> Duration {
+        if Self::is_reduced() { Duration::ZERO } else { duration }
+    }
+
+    fn publish() {
+        let current = Self::current();
+        let signal = Self::signal();
+        if signal.get() != current {
+            info!("🧘 Motion preference: {:?}", current);
+            signal.set(current);
+        }
+    }
+}
+
+/// Snapshot of one running spring, for the animation inspector
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationInfo {
+    /// Unique per spring (clones share it)
+    pub id: u64,
+    /// What it animates ("opacity", "row:42"…)
+    pub label: String,
+    pub value: f32,
+    pub target: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub essential: bool,
+    /// Rough time to settle
+    pub duration: Duration,
+    /// Animation time so far
+    pub elapsed: Duration,
+    pub progress: f32,
+}
+
+struct ClockState {
+    time_scale: f32,
+    paused: bool,
+    /// Let one frame through while paused
+    stepping: bool,
+    recording: bool,
+    /// Springs updated this frame (by id, so substeps overwrite)
+    frame: BTreeMap<u64, AnimationInfo>,
+    /// Springs updated last frame
+    running: Vec<AnimationInfo>,
+}
+
+thread_local! {
+    static CLOCK: RefCell<ClockState> = const {
+        RefCell::new(ClockState {
+            time_scale: 1.0,
+            paused: false,
+            stepping: false,
+            recording: false,
+            frame: BTreeMap::new(),
+            running: Vec::new(),
+        })
+    };
+    static NEXT_SPRING_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+/// Animation Clock - Slow motion and pause for debugging choreography 🐢
+///
+/// Every `SpringAnimation` runs on this clock, so a time scale of 0.25
+/// plays the whole UI at quarter speed without touching app code. While
+/// paused, `step` lets exactly one frame through; the window calls
+/// `end_frame` after each render.
+///
+/// With `set_recording(true)` springs report themselves every frame and
+/// `running` lists what moved in the last one (see `AnimationPanel`).
+pub struct AnimationClock;
+
+impl AnimationClock {
+    /// Current speed multiplier (1.0 = real time)
+    pub fn time_scale() -> f32 {
+        CLOCK.with(|clock| clock.borrow().time_scale)
+    }
+
+    /// Play animations slower (< 1) or faster (> 1)
+    pub fn set_time_scale(scale: f32) {
+        let scale = scale.clamp(0.01, 10.0);
+        info!("🐢 Animation time scale: {}×", scale);
+        CLOCK.with(|clock| clock.borrow_mut().time_scale = scale);
+    }
+
+    /// Are animations frozen?
+    pub fn is_paused() -> bool {
+        CLOCK.with(|clock| clock.borrow().paused)
+    }
+
+    /// Freeze or resume every animation
+    pub fn set_paused(paused: bool) {
+        info!("🐢 Animations {}", if paused { "paused" } else { "resumed" });
+        CLOCK.with(|clock| clock.borrow_mut().paused = paused);
+    }
+
+    /// Advance one frame while paused
+    pub fn step() {
+        CLOCK.with(|clock| clock.borrow_mut().stepping = true);
+    }
+
+    /// Animation time for a frame of `delta_time` real seconds
+    pub fn scale(delta_time: f32) -> f32 {
+        CLOCK.with(|clock| {
+            let clock = clock.borrow();
+            if clock.paused && !clock.stepping { 0.0 } else { delta_time * clock.time_scale }
+        })
+    }
+
+    /// Collect `AnimationInfo` for the inspector (off by default)
+    pub fn set_recording(recording: bool) {
+        CLOCK.with(|clock| {
+            let mut clock = clock.borrow_mut();
+            clock.recording = recording;
+            if !recording {
+                clock.frame.clear();
+                clock.running.clear();
+            }
+        });
+    }
+
+    /// Is the inspector collecting?
+    pub fn is_recording() -> bool {
+        CLOCK.with(|clock| clock.borrow().recording)
+    }
+
+    /// Springs that were updated during the last frame
+    pub fn running() -> Vec<AnimationInfo> {
+        CLOCK.with(|clock| clock.borrow().running.clone())
+    }
+
+    /// Close the frame: publish what ran and end a pending step
+    pub fn end_frame() {
+        CLOCK.with(|clock| {
+            let mut clock = clock.borrow_mut();
+            clock.stepping = false;
+            if clock.recording {
+                let frame = std::mem::take(&mut clock.frame);
+                clock.running = frame.into_values().collect();
+            }
+        });
+    }
+
+    fn record(info: AnimationInfo) {
+        CLOCK.with(|clock| {
+            let mut clock = clock.borrow_mut();
+            if clock.recording {
+                clock.frame.insert(info.id, info);
+            }
+        });
+    }
+}
+
 /// Spring Animation - Physics-based smooth animations! 🎨
 /// 
 /// Uses spring physics: F = -kx - cv
@@ -37,6 +258,13 @@ pub struct SpringAnimation {
     start_time: Option<Instant>,
     /// Is animation complete?
     complete: bool,
+    /// Keeps animating under reduced motion
+    essential: bool,
+    /// Inspector identity and name
+    id: u64,
+    label: Option<String>,
+    /// Animation time so far (follows `AnimationClock`)
+    elapsed: f32,
 }
 
 impl SpringAnimation {
@@ -51,6 +279,10 @@ impl SpringAnimation {
             damping: 30.0,     // Default: slightly bouncy
             start_time: None,
             complete: false,
+            essential: false,
+            id: NEXT_SPRING_ID.with(|next| next.replace(next.get() + 1)),
+            label: None,
+            elapsed: 0.0,
         }
     }
 
@@ -68,6 +300,27 @@ impl SpringAnimation {
         self
     }
 
+    /// Critically damped (no bounce), settling within `duration`
+    pub fn settle_within(self, duration: Duration) -> Self {
+        // Both the distance (1 + ωt)·e^(-ωt) and the speed ω²t·e^(-ωt) are
+        // under the 0.001 completion threshold by ωt ≈ 16 for usual durations
+        let omega = 16.0 / duration.as_secs_f32().max(0.016);
+        self.stiffness(omega * omega).damping(2.0 * omega)
+    }
+
+    /// Keep animating even when the user prefers reduced motion
+    /// (progress and loading indicators, where motion carries meaning)
+    pub fn essential(mut self) -> Self {
+        self.essential = true;
+        self
+    }
+
+    /// Name shown in the animation inspector
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     /// Start the animation
     pub fn start(&mut self) {
         if self.start_time.is_none() {
@@ -87,6 +340,24 @@ impl SpringAnimation {
             self.start();
         }
 
+        // Reduced motion: jump straight to the end
+        if !self.essential && MotionPreference::is_reduced() {
+            self.current = self.target;
+            self.velocity = 0.0;
+            self.complete = true;
+            return false;
+        }
+
+        // Slow motion / pause (a paused spring is still running)
+        let delta_time = AnimationClock::scale(delta_time);
+        if AnimationClock::is_recording() {
+            AnimationClock::record(self.info());
+        }
+        if delta_time == 0.0 {
+            return true;
+        }
+        self.elapsed += delta_time;
+
         // Spring physics: F = -kx - cv
         let displacement = self.current - self.target;
         let spring_force = -self.stiffness * displacement;
@@ -133,19 +404,42 @@ impl SpringAnimation {
         self.complete
     }
 
-    /// Get progress (0.0 to 1.0)
+    /// Get progress (0.0 to 1.0) in animation time
     pub fn progress(&self) -> f32 {
         if self.complete {
             return 1.0;
         }
-        
-        let start = self.start_time
-            .map(|t| t.elapsed().as_secs_f32())
-            .unwrap_or(0.0);
-        
-        // Estimate completion time based on spring parameters
-        let estimated_duration = 1.0 / (self.damping / (2.0 * self.stiffness.sqrt()));
-        (start / estimated_duration).min(1.0)
+        (self.elapsed / self.estimated_duration().as_secs_f32().max(0.001)).min(1.0)
+    }
+
+    /// Rough time to settle, from how fast the spring's swing decays
+    pub fn estimated_duration(&self) -> Duration {
+        let omega = self.stiffness.max(0.001).sqrt();
+        let zeta = self.damping / (2.0 * omega);
+        let seconds = if zeta < 1.0 {
+            // Envelope e^(-ζωt) under 0.001
+            6.9 / (zeta * omega).max(0.001)
+        } else {
+            // Slowest mode of a (critically) overdamped spring
+            16.0 / (omega * (zeta - (zeta * zeta - 1.0).sqrt())).max(0.001)
+        };
+        Duration::from_secs_f32(seconds.min(3600.0))
+    }
+
+    /// Snapshot for the animation inspector
+    pub fn info(&self) -> AnimationInfo {
+        AnimationInfo {
+            id: self.id,
+            label: self.label.clone().unwrap_or_else(|| format!("spring #{}", self.id)),
+            value: self.current,
+            target: self.target,
+            stiffness: self.stiffness,
+            damping: self.damping,
+            essential: self.essential,
+            duration: self.estimated_duration(),
+            elapsed: Duration::from_secs_f32(self.elapsed),
+            progress: self.progress(),
+        }
     }
 }
 
@@ -159,6 +453,8 @@ pub struct AnimationController {
     animations: Vec<(String, SpringAnimation)>,
     /// Last update time
     last_update: Option<Instant>,
+    /// Frames skipped since the last update (see `QualitySettings::animation_interval`)
+    skipped: u32,
 }
 
 impl AnimationController {
@@ -168,6 +464,7 @@ impl AnimationController {
         Self {
             animations: Vec::new(),
             last_update: None,
+            skipped: 0,
         }
     }
 
@@ -175,6 +472,10 @@ impl AnimationController {
     pub fn add(&mut self, name: impl Into<String>, animation: SpringAnimation) {
         let name = name.into();
         info!("🎬 Adding animation: {}", name);
+        let animation = match animation.label {
+            Some(_) => animation,
+            None => animation.label(name.clone()),
+        };
         self.animations.push((name, animation));
     }
 
@@ -195,16 +496,31 @@ impl AnimationController {
     /// Update all animations
     /// Returns number of active animations
     pub fn update(&mut self) -> usize {
+        // Degraded quality: skip frames, the next update covers their time
+        self.skipped += 1;
+        if self.skipped < QualitySettings::current().animation_interval {
+            return self.animations.len();
+        }
+        self.skipped = 0;
+
         let now = Instant::now();
         let delta_time = self.last_update
             .map(|t| now.duration_since(t).as_secs_f32())
             .unwrap_or(1.0 / 60.0); // Default to 60 FPS
         
         self.last_update = Some(now);
+        self.update_by(delta_time)
+    }
 
-        // Update all animations
+    /// Advance all animations by a fixed step (fixed-timestep loops, tests)
+    /// Returns number of active animations
+    pub fn update_by(&mut self, delta_time: f32) -> usize {
+        // Long frames are split so stiff springs stay stable
+        let steps = (delta_time * 240.0).ceil().max(1.0) as usize;
         for (_, animation) in &mut self.animations {
-            animation.update(delta_time);
+            for _ in 0..steps {
+                animation.update(delta_time / steps as f32);
+            }
         }
 
         // Remove completed animations
@@ -219,6 +535,15 @@ impl AnimationController {
         after
     }
 
+    /// Add an animation, replacing one with the same name
+    pub fn restart(&mut self, name: impl Into<String>, animation: SpringAnimation) {
+        let name = name.into();
+        match self.get_mut(&name) {
+            Some(existing) => *existing = animation.label(name),
+            None => self.add(name, animation),
+        }
+    }
+
     /// Get number of active animations
     pub fn active_count(&self) -> usize {
         self.animations.len()
@@ -289,6 +614,29 @@ mod tests {
         assert!(anim.value() < 100.0); // But not reached target yet
     }
 
+    #[test]
+    fn reduced_motion_skips_non_essential_springs() {
+        let changes = std::rc::Rc::new(std::cell::Cell::new(0));
+        let seen = changes.clone();
+        MotionPreference::signal().subscribe(move |_| seen.set(seen.get() + 1));
+
+        MotionPreference::set_system(MotionPreference::Reduced);
+        let mut decorative = SpringAnimation::new(0.0, 100.0);
+        let mut spinner = SpringAnimation::new(0.0, 100.0).essential();
+        assert!(!decorative.update(1.0 / 60.0));
+        assert_eq!(decorative.value(), 100.0);
+        assert!(spinner.update(1.0 / 60.0));
+        assert_eq!(MotionPreference::duration(Duration::from_millis(200)), Duration::ZERO);
+
+        // The app can override the OS either way
+        MotionPreference::set_override(Some(MotionPreference::Full));
+        assert!(SpringAnimation::new(0.0, 100.0).update(1.0 / 60.0));
+        MotionPreference::set_override(None);
+        assert!(MotionPreference::is_reduced());
+        MotionPreference::set_system(MotionPreference::Full);
+        assert_eq!(changes.get(), 4);
+    }
+
     #[test]
     fn spring_animation_completion() {
         let mut anim = SpringAnimation::new(0.0, 1.0);
@@ -333,6 +681,42 @@ mod tests {
         assert!(progress >= 0.0 && progress <= 1.0);
     }
 
+    #[test]
+    fn clock_slows_pauses_and_steps_springs() {
+        let mut normal = AnimationController::new();
+        normal.add("opacity", SpringAnimation::new(0.0, 100.0));
+        normal.update_by(1.0 / 60.0);
+
+        AnimationClock::set_recording(true);
+        AnimationClock::set_time_scale(0.5);
+        let mut controller = AnimationController::new();
+        controller.add("opacity", SpringAnimation::new(0.0, 100.0));
+        controller.update_by(1.0 / 30.0);
+        let expected = normal.get("opacity").unwrap().value();
+        assert!((controller.get("opacity").unwrap().value() - expected).abs() < 1.0);
+        AnimationClock::end_frame();
+        let running = AnimationClock::running();
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].label, "opacity");
+        assert!(running[0].progress > 0.0 && running[0].progress < 1.0);
+
+        AnimationClock::set_paused(true);
+        let before = controller.get("opacity").unwrap().value();
+        controller.update_by(1.0 / 60.0);
+        assert_eq!(controller.get("opacity").unwrap().value(), before);
+        AnimationClock::step();
+        controller.update_by(1.0 / 60.0);
+        AnimationClock::end_frame();
+        let stepped = controller.get("opacity").unwrap().value();
+        assert!(stepped > before);
+        controller.update_by(1.0 / 60.0);
+        assert_eq!(controller.get("opacity").unwrap().value(), stepped);
+
+        AnimationClock::set_paused(false);
+        AnimationClock::set_time_scale(1.0);
+        AnimationClock::set_recording(false);
+    }
+
     #[test]
     fn animation_controller_creation() {
         let controller = AnimationController::new();
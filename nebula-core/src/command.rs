@@ -0,0 +1,506 @@
+//! Commands - Undoable application actions! ↩️
+//!
+//! A `Command` is one thing the user did ("Rename layer", "Delete 3
+//! rows") that knows how to undo itself. `UndoManager` keeps the undo and
+//! redo stacks; commands that share a merge key and follow each other
+//! quickly (nudging with the arrow keys, dragging a slider) become a single
+//! undo step.
+//!
+//! `CommandBus` gives commands action ids ("layer.rename"), so the menu
+//! bar, toolbars and keyboard shortcuts all run the same code, and the
+//! built-in `edit.undo` / `edit.redo` actions walk the app's real history
+//! instead of only the focused text field's.
+
+use crate::signal::Signal;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// Action id of the built-in Undo
+pub const UNDO: &str = "edit.undo";
+
+/// Action id of the built-in Redo
+pub const REDO: &str = "edit.redo";
+
+/// An undoable application action
+pub trait Command {
+    /// Name shown in the Edit menu ("Undo Rename")
+    fn label(&self) -> String;
+
+    /// Do it (the first time)
+    fn execute(&mut self) -> Result<(), String>;
+
+    /// Take it back
+    fn undo(&mut self);
+
+    /// Do it again after an undo (default: `execute`)
+    fn redo(&mut self) {
+        if let Err(e) = self.execute() {
+            warn!("↩️ Redo of '{}' failed: {}", self.label(), e);
+        }
+    }
+
+    /// Consecutive commands with the same key merge into one undo step
+    fn merge_key(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Command made from a pair of closures
+///
+/// # Example
+/// ```rust,ignore
+/// let (name, old) = (layer.name.clone(), layer.name.get());
+/// let rename = FnCommand::new(
+///     "Rename",
+///     { let name = name.clone(); move || name.set("Sky".to_string()) },
+///     move || name.set(old.clone()),
+/// );
+/// ```
+pub struct FnCommand {
+    label: String,
+    apply: Box<dyn FnMut()>,
+    revert: Box<dyn FnMut()>,
+    merge_key: Option<String>,
+}
+
+impl FnCommand {
+    /// Command running `apply` to do and redo, `revert` to undo
+    pub fn new(label: impl Into<String>, apply: impl FnMut() + 'static, revert: impl FnMut() + 'static) -> Self {
+        Self { label: label.into(), apply: Box::new(apply), revert: Box::new(revert), merge_key: None }
+    }
+
+    /// Merge with neighbouring commands that use the same key
+    pub fn merge_key(mut self, key: impl Into<String>) -> Self {
+        self.merge_key = Some(key.into());
+        self
+    }
+}
+
+impl Command for FnCommand {
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn execute(&mut self) -> Result<(), String> {
+        (self.apply)();
+        Ok(())
+    }
+
+    fn undo(&mut self) {
+        (self.revert)();
+    }
+
+    fn merge_key(&self) -> Option<String> {
+        self.merge_key.clone()
+    }
+}
+
+/// One undo step: a command and whatever merged into it
+struct UndoStep {
+    label: String,
+    merge_key: Option<String>,
+    commands: Vec<Box<dyn Command>>,
+    last_change: Instant,
+}
+
+struct UndoInner {
+    undo: RefCell<Vec<UndoStep>>,
+    redo: RefCell<Vec<UndoStep>>,
+    limit: Cell<usize>,
+    merge_window: Cell<Duration>,
+    undo_label: Signal<Option<String>>,
+    redo_label: Signal<Option<String>>,
+}
+
+/// UndoManager - The app's undo / redo history ↩️
+///
+/// Clones share the same history.
+///
+/// # Example
+/// ```rust,ignore
+/// let history = UndoManager::new();
+/// history.record(Box::new(rename)); // already executed
+/// history.undo_label().subscribe(|label| println!("{:?}", label)); // Some("Rename")
+/// history.undo();
+/// ```
+#[derive(Clone)]
+pub struct UndoManager {
+    inner: Rc<UndoInner>,
+}
+
+impl UndoManager {
+    /// Empty history keeping up to 100 steps
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(UndoInner {
+                undo: RefCell::new(Vec::new()),
+                redo: RefCell::new(Vec::new()),
+                limit: Cell::new(100),
+                merge_window: Cell::new(Duration::from_millis(1000)),
+                undo_label: Signal::new(None),
+                redo_label: Signal::new(None),
+            }),
+        }
+    }
+
+    /// How many undo steps to keep
+    pub fn limit(self, limit: usize) -> Self {
+        self.inner.limit.set(limit);
+        self.trim();
+        self
+    }
+
+    /// How close together mergeable commands must be (default 1s)
+    pub fn merge_window(self, window: Duration) -> Self {
+        self.inner.merge_window.set(window);
+        self
+    }
+
+    /// Add a command that has already been executed
+    pub fn record(&self, command: Box<dyn Command>) {
+        let merge_key = command.merge_key();
+        {
+            let mut undo = self.inner.undo.borrow_mut();
+            let window = self.inner.merge_window.get();
+            match undo.last_mut() {
+                Some(step) if merge_key.is_some() && step.merge_key == merge_key && step.last_change.elapsed() <= window => {
+                    step.commands.push(command);
+                    step.last_change = Instant::now();
+                }
+                _ => undo.push(UndoStep {
+                    label: command.label(),
+                    merge_key,
+                    commands: vec![command],
+                    last_change: Instant::now(),
+                }),
+            }
+        }
+        self.inner.redo.borrow_mut().clear();
+        self.trim();
+    }
+
+    /// Undo the last step; false if there's nothing to undo
+    pub fn undo(&self) -> bool {
+        let Some(mut step) = self.inner.undo.borrow_mut().pop() else {
+            return false;
+        };
+        info!("↩️ Undo {}", step.label);
+        step.commands.iter_mut().rev().for_each(|command| command.undo());
+        self.inner.redo.borrow_mut().push(step);
+        self.refresh();
+        true
+    }
+
+    /// Redo the last undone step; false if there's nothing to redo
+    pub fn redo(&self) -> bool {
+        let Some(mut step) = self.inner.redo.borrow_mut().pop() else {
+            return false;
+        };
+        info!("↪️ Redo {}", step.label);
+        step.commands.iter_mut().for_each(|command| command.redo());
+        // A redone step never merges with what comes next
+        step.merge_key = None;
+        self.inner.undo.borrow_mut().push(step);
+        self.refresh();
+        true
+    }
+
+    /// Can `undo` do anything?
+    pub fn can_undo(&self) -> bool {
+        !self.inner.undo.borrow().is_empty()
+    }
+
+    /// Can `redo` do anything?
+    pub fn can_redo(&self) -> bool {
+        !self.inner.redo.borrow().is_empty()
+    }
+
+    /// Label of the step `undo` would revert (None when there is none)
+    pub fn undo_label(&self) -> Signal<Option<String>> {
+        self.inner.undo_label.clone()
+    }
+
+    /// Label of the step `redo` would re-apply
+    pub fn redo_label(&self) -> Signal<Option<String>> {
+        self.inner.redo_label.clone()
+    }
+
+    /// Forget all history (e.g. after opening another document)
+    pub fn clear(&self) {
+        self.inner.undo.borrow_mut().clear();
+        self.inner.redo.borrow_mut().clear();
+        self.refresh();
+    }
+
+    fn trim(&self) {
+        {
+            let mut undo = self.inner.undo.borrow_mut();
+            let excess = undo.len().saturating_sub(self.inner.limit.get());
+            undo.drain(..excess);
+        }
+        self.refresh();
+    }
+
+    fn refresh(&self) {
+        let undo = self.inner.undo.borrow().last().map(|step| step.label.clone());
+        let redo = self.inner.redo.borrow().last().map(|step| step.label.clone());
+        if self.inner.undo_label.get() != undo {
+            self.inner.undo_label.set(undo);
+        }
+        if self.inner.redo_label.get() != redo {
+            self.inner.redo_label.set(redo);
+        }
+    }
+}
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+type CommandFactory = Rc<dyn Fn() -> Box<dyn Command>>;
+type ActionHandler = Rc<dyn Fn()>;
+type EnabledCheck = Rc<dyn Fn() -> bool>;
+
+#[derive(Clone)]
+enum Handler {
+    /// Makes an undoable command each time
+    Command(CommandFactory),
+    /// Plain action (Save, Quit…), not recorded
+    Action(ActionHandler),
+}
+
+struct RegisteredAction {
+    id: String,
+    label: String,
+    handler: Handler,
+    enabled: Option<EnabledCheck>,
+}
+
+struct BusInner {
+    actions: RefCell<Vec<RegisteredAction>>,
+    history: UndoManager,
+    error: Signal<Option<String>>,
+}
+
+/// CommandBus - One place every app action goes through 🚌
+///
+/// Menus, toolbars and shortcuts dispatch action ids; the bus runs the
+/// registered command and records it for undo. `edit.undo` and
+/// `edit.redo` are built in. Clones share the same bus.
+///
+/// # Example
+/// ```rust,ignore
+/// let bus = CommandBus::new();
+/// bus.register("layer.delete", "Delete Layer", move || Box::new(DeleteLayer::new(&doc)));
+/// bus.register_action("file.save", "Save", move || doc.save());
+/// bus.enabled_when("layer.delete", move || selection.get().is_some());
+///
+/// let menu = MenuBar::new().add_menu(edit_menu).commands(bus.clone());
+/// bus.dispatch("edit.undo");
+/// ```
+#[derive(Clone)]
+pub struct CommandBus {
+    inner: Rc<BusInner>,
+}
+
+impl CommandBus {
+    /// Bus with its own history
+    pub fn new() -> Self {
+        Self::with_history(UndoManager::new())
+    }
+
+    /// Bus recording into an existing history
+    pub fn with_history(history: UndoManager) -> Self {
+        Self { inner: Rc::new(BusInner { actions: RefCell::new(Vec::new()), history, error: Signal::new(None) }) }
+    }
+
+    /// The undo history
+    pub fn history(&self) -> UndoManager {
+        self.inner.history.clone()
+    }
+
+    /// Last command that failed to execute (cleared by the next success)
+    pub fn error(&self) -> Signal<Option<String>> {
+        self.inner.error.clone()
+    }
+
+    /// Register an undoable action; `make` builds a fresh command per run
+    pub fn register<F>(&self, id: impl Into<String>, label: impl Into<String>, make: F)
+    where
+        F: Fn() -> Box<dyn Command> + 'static,
+    {
+        self.insert(id.into(), label.into(), Handler::Command(Rc::new(make)));
+    }
+
+    /// Register an action that isn't undoable (Save, Print…)
+    pub fn register_action<F>(&self, id: impl Into<String>, label: impl Into<String>, run: F)
+    where
+        F: Fn() + 'static,
+    {
+        self.insert(id.into(), label.into(), Handler::Action(Rc::new(run)));
+    }
+
+    fn insert(&self, id: String, label: String, handler: Handler) {
+        let mut actions = self.inner.actions.borrow_mut();
+        actions.retain(|action| action.id != id);
+        actions.push(RegisteredAction { id, label, handler, enabled: None });
+    }
+
+    /// Only allow an action while `check` says so (menus grey it out)
+    pub fn enabled_when<F>(&self, id: &str, check: F)
+    where
+        F: Fn() -> bool + 'static,
+    {
+        if let Some(action) = self.inner.actions.borrow_mut().iter_mut().find(|action| action.id == id) {
+            action.enabled = Some(Rc::new(check));
+        }
+    }
+
+    /// Does the bus handle this action id?
+    pub fn knows(&self, id: &str) -> bool {
+        id == UNDO || id == REDO || self.inner.actions.borrow().iter().any(|action| action.id == id)
+    }
+
+    /// Every action id the bus handles, built-ins first (for command palettes)
+    pub fn action_ids(&self) -> Vec<String> {
+        let mut ids = vec![UNDO.to_string(), REDO.to_string()];
+        ids.extend(self.inner.actions.borrow().iter().map(|action| action.id.clone()));
+        ids
+    }
+
+    /// Can the action run right now?
+    pub fn is_enabled(&self, id: &str) -> bool {
+        match id {
+            UNDO => self.inner.history.can_undo(),
+            REDO => self.inner.history.can_redo(),
+            _ => {
+                let check = self.inner.actions.borrow().iter().find(|action| action.id == id).map(|action| action.enabled.clone());
+                match check {
+                    Some(Some(check)) => check(),
+                    Some(None) => true,
+                    None => false,
+                }
+            }
+        }
+    }
+
+    /// Label for menus ("Undo Rename" for the built-ins)
+    pub fn label(&self, id: &str) -> Option<String> {
+        let with_step = |verb: &str, step: Option<String>| Some(step.map_or(verb.to_string(), |step| format!("{} {}", verb, step)));
+        match id {
+            UNDO => with_step("Undo", self.inner.history.undo_label().get()),
+            REDO => with_step("Redo", self.inner.history.redo_label().get()),
+            _ => self.inner.actions.borrow().iter().find(|action| action.id == id).map(|action| action.label.clone()),
+        }
+    }
+
+    /// Run an action by id; returns false if unknown or disabled
+    pub fn dispatch(&self, id: &str) -> bool {
+        match id {
+            UNDO => return self.inner.history.undo(),
+            REDO => return self.inner.history.redo(),
+            _ => {}
+        }
+        if !self.is_enabled(id) {
+            return false;
+        }
+        // Clone the handler out so it can register or dispatch itself
+        let Some(handler) = self.inner.actions.borrow().iter().find(|action| action.id == id).map(|action| action.handler.clone()) else {
+            return false;
+        };
+        match handler {
+            Handler::Command(make) => self.execute(make()).is_ok(),
+            Handler::Action(run) => {
+                run();
+                true
+            }
+        }
+    }
+
+    /// Execute a command and record it for undo
+    pub fn execute(&self, mut command: Box<dyn Command>) -> Result<(), String> {
+        match command.execute() {
+            Ok(()) => {
+                info!("🚌 {}", command.label());
+                self.inner.history.record(command);
+                if self.inner.error.get().is_some() {
+                    self.inner.error.set(None);
+                }
+                Ok(())
+            }
+            Err(e) => {
+                warn!("🚌 {} failed: {}", command.label(), e);
+                self.inner.error.set(Some(e.clone()));
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Default for CommandBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add(value: &Signal<i32>, amount: i32) -> Box<dyn Command> {
+        let (apply, revert) = (value.clone(), value.clone());
+        Box::new(
+            FnCommand::new(format!("Add {}", amount), move || apply.update(|v| v + amount), move || revert.update(|v| v - amount))
+                .merge_key("add"),
+        )
+    }
+
+    #[test]
+    fn undo_redo_and_merging() {
+        let value = Signal::new(0);
+        let history = UndoManager::new();
+        let bus = CommandBus::with_history(history.clone());
+
+        bus.execute(add(&value, 1)).unwrap();
+        bus.execute(add(&value, 2)).unwrap();
+        assert_eq!(value.get(), 3);
+        // Both adds merged into one step, labelled by the first
+        assert_eq!(history.undo_label().get(), Some("Add 1".to_string()));
+
+        assert!(bus.dispatch(UNDO));
+        assert_eq!(value.get(), 0);
+        assert!(!history.can_undo());
+        assert_eq!(bus.label(REDO), Some("Redo Add 1".to_string()));
+
+        assert!(bus.dispatch(REDO));
+        assert_eq!(value.get(), 3);
+        // New work clears redo
+        bus.execute(Box::new(FnCommand::new("Reset", || {}, || {}))).unwrap();
+        assert!(!history.can_redo());
+        assert_eq!(bus.label(UNDO), Some("Undo Reset".to_string()));
+    }
+
+    #[test]
+    fn registered_actions_respect_enabled_checks() {
+        let value = Signal::new(0);
+        let allowed = Signal::new(false);
+        let bus = CommandBus::new();
+        let target = value.clone();
+        bus.register("value.bump", "Bump", move || add(&target, 10));
+        let check = allowed.clone();
+        bus.enabled_when("value.bump", move || check.get());
+
+        assert!(bus.knows("value.bump") && !bus.knows("value.other"));
+        assert!(!bus.dispatch("value.bump"));
+        allowed.set(true);
+        assert!(bus.dispatch("value.bump"));
+        assert_eq!(value.get(), 10);
+        assert!(bus.dispatch(UNDO));
+        assert_eq!(value.get(), 0);
+        assert!(!bus.dispatch(UNDO));
+    }
+}
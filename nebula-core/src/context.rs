@@ -0,0 +1,115 @@
+//! Context - App-wide services without threading them through every call! 🧭
+//!
+//! Like React context: provide a value once at startup and any component
+//! can look it up by type. Values are usually cheap handles (an `Rc`
+//! inside, like `FeatureFlags` or `CommandBus`), so `get` hands out a
+//! clone that shares state with everyone else's.
+//!
+//! `scoped` overrides a value for the duration of a closure and then puts
+//! the outer one back, so a window or a subtree can see its own value
+//! (a different theme, say) while the rest of the app keeps the global one.
+//!
+//! Context lives on the UI thread, like Signals.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static VALUES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Context - Values looked up by type 🧭
+///
+/// # Example
+/// ```rust,ignore
+/// Context::provide(FeatureFlags::new().flag("new_checkout", false));
+///
+/// // deep inside a screen:
+/// let flags = Context::get::<FeatureFlags>().unwrap_or_default();
+/// ```
+pub struct Context;
+
+impl Context {
+    /// Make `value` available to everyone (replacing any of the same type)
+    pub fn provide<T: Clone + 'static>(value: T) {
+        VALUES.with(|values| values.borrow_mut().insert(TypeId::of::<T>(), Box::new(value)));
+    }
+
+    /// A clone of the provided value
+    pub fn get<T: Clone + 'static>() -> Option<T> {
+        VALUES.with(|values| values.borrow().get(&TypeId::of::<T>()).and_then(|value| value.downcast_ref::<T>()).cloned())
+    }
+
+    /// Has a value of this type been provided?
+    pub fn has<T: 'static>() -> bool {
+        VALUES.with(|values| values.borrow().contains_key(&TypeId::of::<T>()))
+    }
+
+    /// Take a value back out of the context
+    pub fn remove<T: 'static>() -> Option<T> {
+        VALUES.with(|values| values.borrow_mut().remove(&TypeId::of::<T>())).and_then(|value| value.downcast().ok()).map(|value| *value)
+    }
+
+    /// Run `f` with `value` provided, then restore whatever was there before
+    ///
+    /// Scopes nest, and the outer value comes back even if `f` panics.
+    ///
+    /// ```rust,ignore
+    /// Context::scoped(ThemeProvider::new(Theme::dark()), || sidebar.paint(&mut list));
+    /// ```
+    pub fn scoped<T: Clone + 'static, R>(value: T, f: impl FnOnce() -> R) -> R {
+        struct Restore {
+            key: TypeId,
+            previous: Option<Box<dyn Any>>,
+        }
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.previous.take();
+                VALUES.with(|values| {
+                    let mut values = values.borrow_mut();
+                    match previous {
+                        Some(previous) => values.insert(self.key, previous),
+                        None => values.remove(&self.key),
+                    }
+                });
+            }
+        }
+        let key = TypeId::of::<T>();
+        let previous = VALUES.with(|values| values.borrow_mut().insert(key, Box::new(value)));
+        let _restore = Restore { key, previous };
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provides_values_by_type() {
+        assert_eq!(Context::get::<u32>(), None);
+        Context::provide(7u32);
+        Context::provide(String::from("nebula"));
+        Context::provide(8u32);
+        assert_eq!(Context::get::<u32>(), Some(8));
+        assert_eq!(Context::get::<String>().as_deref(), Some("nebula"));
+        assert_eq!(Context::remove::<u32>(), Some(8));
+        assert!(!Context::has::<u32>());
+    }
+
+    #[test]
+    fn scoped_values_nest_and_restore() {
+        Context::provide(String::from("app"));
+        let seen = Context::scoped(String::from("window"), || {
+            let inner = Context::scoped(String::from("panel"), Context::get::<String>);
+            (inner, Context::get::<String>())
+        });
+        assert_eq!(seen, (Some("panel".to_string()), Some("window".to_string())));
+        assert_eq!(Context::get::<String>().as_deref(), Some("app"));
+
+        // Nothing before the scope: nothing after it
+        Context::scoped(3u8, || assert_eq!(Context::get::<u8>(), Some(3)));
+        assert!(!Context::has::<u8>());
+    }
+}
@@ -0,0 +1,589 @@
+//! Data Sources - Queries that keep working offline! 🗄️
+//!
+//! A `DataSource` fetches data for a query (an API call, a database
+//! lookup…). Wrapping it in a `CachedSource` adds an offline-first cache
+//! with stale-while-revalidate semantics:
+//!
+//! - A query answers from the cache at once, even if the data is old
+//! - If the cached copy is older than `max_age` (or missing) it's fetched
+//!   again in the background and the query's Signal updates when it lands
+//! - While offline nothing is fetched; cached data keeps showing, and
+//!   stale queries revalidate as soon as the `online` signal turns true
+//!
+//! Results are saved through a `CacheStorage`: `DiskCache` (a sled
+//! database on native and IndexedDB on the web, both survive restarts) or
+//! `MemoryCache`. Other backends plug in by implementing the trait.
+
+use crate::signal::Signal;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// Callback a fetch calls when it finishes
+pub type FetchDone<T> = Box<dyn FnOnce(Result<T, String>)>;
+
+/// Something that can fetch data for a query
+pub trait DataSource<Q> {
+    type Item;
+
+    /// Stable cache key for a query (e.g. the request URL)
+    fn cache_key(&self, query: &Q) -> String;
+
+    /// Start fetching; call `done` whenever the answer arrives
+    fn fetch(&self, query: &Q, done: FetchDone<Self::Item>);
+}
+
+/// Where cached results are kept (as JSON text)
+pub trait CacheStorage {
+    fn read(&self, key: &str) -> Option<String>;
+    fn write(&self, key: &str, value: &str) -> Result<(), String>;
+    fn remove(&self, key: &str);
+}
+
+/// In-memory cache (lost on exit; handy for tests and the web)
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: RefCell<HashMap<String, String>>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheStorage for MemoryCache {
+    fn read(&self, key: &str) -> Option<String> {
+        self.entries.borrow().get(key).cloned()
+    }
+
+    fn write(&self, key: &str, value: &str) -> Result<(), String> {
+        self.entries.borrow_mut().insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.borrow_mut().remove(key);
+    }
+}
+
+/// Disk cache: a sled database on native, IndexedDB on the web 💽
+///
+/// Keys are stored as-is, so there are no file names to hash and no
+/// collisions to guard against. On the web, IndexedDB only answers
+/// asynchronously: entries are loaded into memory once the database
+/// opens and every write goes to both, so reads made before the load
+/// finishes miss and simply fetch.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    #[cfg(not(target_arch = "wasm32"))]
+    db: Result<sled::Db, String>,
+    #[cfg(target_arch = "wasm32")]
+    db: indexed_db::IndexedDb,
+}
+
+/// Databases opened by this process, one per directory
+///
+/// sled locks its directory, and the lock outlives a dropped `Db` for a
+/// moment, so every `DiskCache` on a path shares one handle instead.
+#[cfg(not(target_arch = "wasm32"))]
+static OPEN_CACHES: std::sync::Mutex<std::collections::BTreeMap<std::path::PathBuf, sled::Db>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DiskCache {
+    /// Cache database in `dir` (created if missing)
+    ///
+    /// Caches on the same directory share one open database. If it can't
+    /// be opened (e.g. another process holds it) the cache stays empty and
+    /// every write fails, so queries just fetch.
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        let dir = dir.into();
+        let _ = std::fs::create_dir_all(&dir);
+        let key = dir.canonicalize().unwrap_or_else(|_| dir.clone());
+
+        let mut open = OPEN_CACHES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(db) = open.get(&key) {
+            return Self { db: Ok(db.clone()) };
+        }
+        let db = sled::open(&dir).map_err(|e| format!("Failed to open cache {}: {}", dir.display(), e));
+        match &db {
+            Ok(db) => {
+                open.insert(key, db.clone());
+            }
+            Err(e) => warn!("🗄️ {}", e),
+        }
+        Self { db }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CacheStorage for DiskCache {
+    fn read(&self, key: &str) -> Option<String> {
+        let value = self.db.as_ref().ok()?.get(key).ok()??;
+        String::from_utf8(value.to_vec()).ok()
+    }
+
+    fn write(&self, key: &str, value: &str) -> Result<(), String> {
+        let db = self.db.as_ref().map_err(Clone::clone)?;
+        db.insert(key, value.as_bytes()).map_err(|e| format!("Failed to write '{}': {}", key, e))?;
+        // Cache writes are rare; flushing keeps an entry across a crash
+        db.flush().map(|_| ()).map_err(|e| format!("Failed to flush cache: {}", e))
+    }
+
+    fn remove(&self, key: &str) {
+        if let Ok(db) = &self.db {
+            let _ = db.remove(key);
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl DiskCache {
+    /// Cache in the IndexedDB database `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { db: indexed_db::IndexedDb::open(&name.into()) }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl CacheStorage for DiskCache {
+    fn read(&self, key: &str) -> Option<String> {
+        self.db.entries.borrow().get(key).cloned()
+    }
+
+    fn write(&self, key: &str, value: &str) -> Result<(), String> {
+        self.db.entries.borrow_mut().insert(key.to_string(), value.to_string());
+        self.db.put(key, value)
+    }
+
+    fn remove(&self, key: &str) {
+        self.db.entries.borrow_mut().remove(key);
+        self.db.delete(key);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod indexed_db {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use tracing::warn;
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use web_sys::{IdbDatabase, IdbObjectStore, IdbTransactionMode};
+
+    const STORE: &str = "entries";
+
+    /// An IndexedDB object store mirrored in memory
+    #[derive(Debug, Clone, Default)]
+    pub struct IndexedDb {
+        pub entries: Rc<RefCell<HashMap<String, String>>>,
+        db: Rc<RefCell<Option<IdbDatabase>>>,
+    }
+
+    impl IndexedDb {
+        /// Start opening the database; entries arrive when it's ready
+        pub fn open(name: &str) -> Self {
+            let cache = Self::default();
+            let factory = web_sys::window().and_then(|window| window.indexed_db().ok().flatten());
+            let Some(request) = factory.and_then(|factory| factory.open_with_u32(name, 1).ok()) else {
+                warn!("🗄️ IndexedDB is unavailable, caching in memory only");
+                return cache;
+            };
+
+            let upgrading = request.clone();
+            let on_upgrade = Closure::once_into_js(move || {
+                if let Ok(db) = upgrading.result() {
+                    let _ = db.unchecked_into::<IdbDatabase>().create_object_store(STORE);
+                }
+            });
+            request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+            let opened = request.clone();
+            let target = cache.clone();
+            let on_success = Closure::once_into_js(move || {
+                let Ok(db) = opened.result() else { return };
+                *target.db.borrow_mut() = Some(db.unchecked_into());
+                // Keep anything written while the database was opening
+                let written: Vec<(String, String)> =
+                    target.entries.borrow().iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                for (key, value) in written {
+                    let _ = target.put(&key, &value);
+                }
+                target.load();
+            });
+            request.set_onsuccess(Some(on_success.unchecked_ref()));
+            cache
+        }
+
+        fn store(&self, mode: IdbTransactionMode) -> Result<IdbObjectStore, String> {
+            let db = self.db.borrow();
+            let db = db.as_ref().ok_or("IndexedDB is still opening")?;
+            db.transaction_with_str_and_mode(STORE, mode)
+                .and_then(|transaction| transaction.object_store(STORE))
+                .map_err(|e| format!("IndexedDB error: {:?}", e))
+        }
+
+        /// Read every stored entry into memory (newer in-memory writes win)
+        fn load(&self) {
+            let Ok(store) = self.store(IdbTransactionMode::Readonly) else { return };
+            let (Ok(keys), Ok(values)) = (store.get_all_keys(), store.get_all()) else { return };
+            let entries = self.entries.clone();
+            let values_done = values.clone();
+            // Requests in a transaction finish in order, so keys are ready too
+            let on_values = Closure::once_into_js(move || {
+                let (Ok(keys), Ok(values)) = (keys.result(), values_done.result()) else { return };
+                let keys: js_sys::Array = keys.unchecked_into();
+                let values: js_sys::Array = values.unchecked_into();
+                let mut entries = entries.borrow_mut();
+                for (key, value) in keys.iter().zip(values.iter()) {
+                    if let (Some(key), Some(value)) = (key.as_string(), value.as_string()) {
+                        entries.entry(key).or_insert(value);
+                    }
+                }
+            });
+            values.set_onsuccess(Some(on_values.unchecked_ref()));
+        }
+
+        /// Store an entry (memory only until the database is open)
+        pub fn put(&self, key: &str, value: &str) -> Result<(), String> {
+            if self.db.borrow().is_none() {
+                return Ok(());
+            }
+            self.store(IdbTransactionMode::Readwrite)?
+                .put_with_key(&JsValue::from_str(value), &JsValue::from_str(key))
+                .map(|_| ())
+                .map_err(|e| format!("IndexedDB error: {:?}", e))
+        }
+
+        /// Delete an entry
+        pub fn delete(&self, key: &str) {
+            if let Ok(store) = self.store(IdbTransactionMode::Readwrite) {
+                let _ = store.delete(&JsValue::from_str(key));
+            }
+        }
+    }
+}
+
+/// What a query currently shows
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryState<T> {
+    /// Latest data (cached or fresh)
+    pub data: Option<T>,
+    /// When `data` was fetched
+    pub updated_at: Option<SystemTime>,
+    /// `data` is older than `max_age`
+    pub stale: bool,
+    /// A fetch is in flight
+    pub loading: bool,
+    /// Last fetch failure (cached data is kept)
+    pub error: Option<String>,
+}
+
+impl<T> Default for QueryState<T> {
+    fn default() -> Self {
+        Self { data: None, updated_at: None, stale: true, loading: false, error: None }
+    }
+}
+
+/// Cached value plus when it was fetched
+#[derive(Serialize, Deserialize)]
+struct CachedValue<T> {
+    saved_at: u64,
+    data: T,
+}
+
+struct Tracked<Q, T: Clone> {
+    key: String,
+    query: Q,
+    state: Signal<QueryState<T>>,
+}
+
+struct CachedInner<Q, S: DataSource<Q>>
+where
+    S::Item: Clone,
+{
+    source: S,
+    storage: Box<dyn CacheStorage>,
+    max_age: Cell<Duration>,
+    online: RefCell<Signal<bool>>,
+    queries: RefCell<Vec<Tracked<Q, S::Item>>>,
+}
+
+/// CachedSource - Offline-first, stale-while-revalidate queries 🗄️
+///
+/// Clones share the same cache and queries.
+///
+/// # Example
+/// ```rust,ignore
+/// let network = NetworkMonitor::new();
+/// let sites = CachedSource::new(SitesApi::new(), DiskCache::new(data_dir.join("cache")))
+///     .max_age(Duration::from_secs(10 * 60))
+///     .online(network.online());
+///
+/// let nearby = sites.query(Region::around(position));
+/// // nearby.get().data shows the last known list right away, offline or not
+/// ```
+pub struct CachedSource<Q, S: DataSource<Q>>
+where
+    S::Item: Clone,
+{
+    inner: Rc<CachedInner<Q, S>>,
+}
+
+impl<Q, S: DataSource<Q>> Clone for CachedSource<Q, S>
+where
+    S::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<Q, S> CachedSource<Q, S>
+where
+    Q: Clone + 'static,
+    S: DataSource<Q> + 'static,
+    S::Item: Clone + Serialize + DeserializeOwned + 'static,
+{
+    /// Wrap a source with a cache
+    pub fn new(source: S, storage: impl CacheStorage + 'static) -> Self {
+        Self {
+            inner: Rc::new(CachedInner {
+                source,
+                storage: Box::new(storage),
+                max_age: Cell::new(Duration::from_secs(5 * 60)),
+                online: RefCell::new(Signal::new(true)),
+                queries: RefCell::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Cached data older than this is refetched (default 5 minutes)
+    pub fn max_age(self, max_age: Duration) -> Self {
+        self.inner.max_age.set(max_age);
+        self
+    }
+
+    /// Follow a connectivity signal: no fetches while false, and stale
+    /// queries revalidate when it turns true
+    pub fn online(self, online: Signal<bool>) -> Self {
+        *self.inner.online.borrow_mut() = online.clone();
+        let weak = Rc::downgrade(&self.inner);
+        online.subscribe(move |is_online| {
+            if let (true, Some(inner)) = (*is_online, weak.upgrade()) {
+                info!("🗄️ Back online, revalidating stale queries");
+                CachedSource { inner }.revalidate();
+            }
+        });
+        self
+    }
+
+    /// State of a query: cached data now, fresh data when it arrives
+    ///
+    /// Asking again for the same key returns the same signal.
+    pub fn query(&self, query: Q) -> Signal<QueryState<S::Item>> {
+        let key = self.inner.source.cache_key(&query);
+        if let Some(tracked) = self.inner.queries.borrow().iter().find(|tracked| tracked.key == key) {
+            return tracked.state.clone();
+        }
+
+        let mut state = QueryState::default();
+        if let Some(cached) = self.read_cache(&key) {
+            state.updated_at = Some(UNIX_EPOCH + Duration::from_secs(cached.saved_at));
+            state.stale = self.is_stale(state.updated_at);
+            state.data = Some(cached.data);
+        }
+        let signal = Signal::new(state);
+        self.inner.queries.borrow_mut().push(Tracked { key: key.clone(), query, state: signal.clone() });
+        if signal.get().stale {
+            self.fetch(&key);
+        }
+        signal
+    }
+
+    /// Fetch a query again, even if its data is fresh
+    pub fn refresh(&self, query: &Q) {
+        let key = self.inner.source.cache_key(query);
+        self.fetch(&key);
+    }
+
+    /// Refetch every tracked query whose data is stale or missing
+    pub fn revalidate(&self) {
+        let tracked: Vec<(String, Signal<QueryState<S::Item>>)> =
+            self.inner.queries.borrow().iter().map(|tracked| (tracked.key.clone(), tracked.state.clone())).collect();
+        for (key, state) in tracked {
+            let stale = self.is_stale(state.get().updated_at);
+            if stale != state.get().stale {
+                state.update(|current| QueryState { stale, ..current.clone() });
+            }
+            if stale {
+                self.fetch(&key);
+            }
+        }
+    }
+
+    /// Drop a query's cached data (the next fetch repopulates it)
+    pub fn invalidate(&self, query: &Q) {
+        let key = self.inner.source.cache_key(query);
+        self.inner.storage.remove(&key);
+        if let Some(tracked) = self.inner.queries.borrow().iter().find(|tracked| tracked.key == key) {
+            tracked.state.update(|state| QueryState { stale: true, ..state.clone() });
+        }
+    }
+
+    /// Is the source allowed to fetch right now?
+    pub fn is_online(&self) -> bool {
+        self.inner.online.borrow().get()
+    }
+
+    fn fetch(&self, key: &str) {
+        let (query, state) = match self.inner.queries.borrow().iter().find(|tracked| tracked.key == key) {
+            Some(tracked) => (tracked.query.clone(), tracked.state.clone()),
+            None => return,
+        };
+        if !self.is_online() || state.get().loading {
+            return;
+        }
+        state.update(|current| QueryState { loading: true, ..current.clone() });
+
+        let (cache, key) = (self.clone(), key.to_string());
+        self.inner.source.fetch(
+            &query,
+            Box::new(move |result| match result {
+                Ok(data) => {
+                    let saved_at = SystemTime::now();
+                    cache.write_cache(&key, &data, saved_at);
+                    state.set(QueryState { data: Some(data), updated_at: Some(saved_at), stale: false, loading: false, error: None });
+                }
+                Err(error) => {
+                    warn!("🗄️ Fetching '{}' failed: {}", key, error);
+                    state.update(|current| QueryState { loading: false, error: Some(error.clone()), ..current.clone() });
+                }
+            }),
+        );
+    }
+
+    fn is_stale(&self, updated_at: Option<SystemTime>) -> bool {
+        updated_at
+            .and_then(|at| at.elapsed().ok())
+            .is_none_or(|age| age >= self.inner.max_age.get())
+    }
+
+    fn read_cache(&self, key: &str) -> Option<CachedValue<S::Item>> {
+        let text = self.inner.storage.read(key)?;
+        serde_json::from_str(&text)
+            .map_err(|e| warn!("🗄️ Ignoring unreadable cache entry '{}': {}", key, e))
+            .ok()
+    }
+
+    fn write_cache(&self, key: &str, data: &S::Item, saved_at: SystemTime) {
+        let saved_at = saved_at.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let result = serde_json::to_string(&CachedValue { saved_at, data })
+            .map_err(|e| e.to_string())
+            .and_then(|text| self.inner.storage.write(key, &text));
+        if let Err(e) = result {
+            warn!("🗄️ Couldn't cache '{}': {}", key, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Requests = Rc<RefCell<Vec<(String, FetchDone<Vec<String>>)>>>;
+
+    /// Answers are handed out by the test
+    #[derive(Clone, Default)]
+    struct Sites {
+        requests: Requests,
+    }
+
+    impl DataSource<String> for Sites {
+        type Item = Vec<String>;
+
+        fn cache_key(&self, region: &String) -> String {
+            format!("sites/{}", region)
+        }
+
+        fn fetch(&self, region: &String, done: FetchDone<Vec<String>>) {
+            self.requests.borrow_mut().push((region.clone(), done));
+        }
+    }
+
+    impl Sites {
+        fn answer(&self, result: Result<Vec<&str>, &str>) {
+            let (_, done) = self.requests.borrow_mut().remove(0);
+            done(result.map(|sites| sites.into_iter().map(String::from).collect()).map_err(String::from));
+        }
+
+        fn pending(&self) -> usize {
+            self.requests.borrow().len()
+        }
+    }
+
+    #[test]
+    fn serves_cache_then_revalidates() {
+        let dir = std::env::temp_dir().join(format!("nebula-cache-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let sites = Sites::default();
+        let disk = DiskCache::new(&dir);
+
+        {
+            let cache = CachedSource::new(sites.clone(), disk.clone());
+            let north = cache.query("north".to_string());
+            assert!(north.get().loading && north.get().data.is_none());
+            sites.answer(Ok(vec!["Well 7", "Pump 2"]));
+            assert_eq!(north.get().data.unwrap().len(), 2);
+            assert!(!north.get().stale);
+        }
+
+        // A new session shows the saved data at once; with max_age 0 it's
+        // stale, so it also refetches, and a failure keeps the old data
+        let cache = CachedSource::new(sites.clone(), disk).max_age(Duration::ZERO);
+        let north = cache.query("north".to_string());
+        assert_eq!(north.get().data, Some(vec!["Well 7".to_string(), "Pump 2".to_string()]));
+        assert!(north.get().stale && north.get().loading);
+        sites.answer(Err("timeout"));
+        assert_eq!(north.get().error.as_deref(), Some("timeout"));
+        assert_eq!(north.get().data.unwrap().len(), 2);
+
+        // Same key, same signal, no second fetch while one is running
+        cache.refresh(&"north".to_string());
+        let again = cache.query("north".to_string());
+        cache.refresh(&"north".to_string());
+        assert_eq!(sites.pending(), 1);
+        sites.answer(Ok(vec!["Well 7"]));
+        assert_eq!(again.get().data.unwrap(), vec!["Well 7".to_string()]);
+        assert_eq!(north.get().error, None);
+
+        // Opening the directory again shares the open database
+        assert!(DiskCache::new(&dir).read("sites/north").is_some_and(|entry| entry.contains("Well 7")));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn waits_for_connectivity() {
+        let sites = Sites::default();
+        let online = Signal::new(false);
+        let cache = CachedSource::new(sites.clone(), MemoryCache::new()).online(online.clone());
+        let south = cache.query("south".to_string());
+        assert_eq!(sites.pending(), 0);
+        assert!(!south.get().loading);
+
+        online.set(true);
+        assert_eq!(sites.pending(), 1);
+        sites.answer(Ok(vec!["Tank 1"]));
+        assert!(!south.get().stale);
+
+        // Fresh data isn't refetched on reconnect
+        online.set(false);
+        online.set(true);
+        assert_eq!(sites.pending(), 0);
+    }
+}
@@ -0,0 +1,100 @@
+//! Events - Typed component messages, drained once per frame 📬
+//!
+//! The alternative to boxed callbacks: a component given an `EventQueue`
+//! pushes plain-data events (`TableEvent::RowClicked(id)`) into it, and the
+//! app drains the queue each frame and turns them into state changes,
+//! usually by dispatching them into a `Store`. Events are ordinary enums,
+//! so tests can assert on exactly what happened and a recorded list can be
+//! fed back in to replay a session.
+//!
+//! Callbacks keep working; a component with both fires both.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// EventQueue - FIFO of events shared between components and the app 📬
+///
+/// Clones share the same queue, so one queue can collect from many
+/// components.
+///
+/// # Example
+/// ```rust,ignore
+/// let events = EventQueue::new();
+/// let table = Table::new().events(&events);
+///
+/// // each frame:
+/// for event in events.drain() {
+///     store.dispatch(Action::Table(event));
+/// }
+/// ```
+pub struct EventQueue<E> {
+    inner: Rc<RefCell<VecDeque<E>>>,
+}
+
+impl<E> Clone for EventQueue<E> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<E> Default for EventQueue<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> std::fmt::Debug for EventQueue<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventQueue").field("pending", &self.len()).finish()
+    }
+}
+
+impl<E> EventQueue<E> {
+    /// Empty queue
+    pub fn new() -> Self {
+        Self { inner: Rc::new(RefCell::new(VecDeque::new())) }
+    }
+
+    /// Add an event at the back
+    pub fn emit(&self, event: E) {
+        self.inner.borrow_mut().push_back(event);
+    }
+
+    /// Take every pending event, oldest first
+    pub fn drain(&self) -> Vec<E> {
+        self.inner.borrow_mut().drain(..).collect()
+    }
+
+    /// Take the oldest pending event
+    pub fn pop(&self) -> Option<E> {
+        self.inner.borrow_mut().pop_front()
+    }
+
+    /// Number of pending events
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Nothing pending?
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_one_fifo() {
+        let events = EventQueue::new();
+        let sender = events.clone();
+        sender.emit("first");
+        events.emit("second");
+        assert_eq!(events.len(), 2);
+        assert_eq!(sender.pop(), Some("first"));
+        assert_eq!(events.drain(), vec!["second"]);
+        assert!(sender.is_empty());
+    }
+}
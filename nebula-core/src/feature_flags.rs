@@ -0,0 +1,188 @@
+//! Feature Flags - Stage-roll new screens! 🚩
+//!
+//! Every flag is a `Signal<bool>`, so UI built behind a flag (see
+//! `show_if_flag` in nebula-components) appears or disappears the moment
+//! it flips, whether that's from remote config, a percentage rollout or
+//! the debug panel.
+//!
+//! Rollouts and A/B variants bucket users by a stable hash of their id,
+//! so the same user always lands in the same group across launches.
+//!
+//! Provide the flags once with `FeatureFlags::provide` and read them
+//! anywhere through `Context`.
+
+use crate::context::Context;
+use crate::signal::Signal;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use tracing::info;
+
+struct FlagEntry {
+    value: Signal<bool>,
+    default: bool,
+}
+
+/// FeatureFlags - Signal-backed on/off switches 🚩
+///
+/// Clones share the same flags.
+///
+/// # Example
+/// ```rust,ignore
+/// let flags = FeatureFlags::new()
+///     .define("new_checkout", false)
+///     .define("dark_mode_v2", true);
+/// flags.rollout("new_checkout", 10.0, &user.id); // 10% of users
+/// flags.provide();
+///
+/// let checkout = FeatureFlags::current().flag("new_checkout");
+/// ```
+#[derive(Clone, Default)]
+pub struct FeatureFlags {
+    flags: Rc<RefCell<BTreeMap<String, FlagEntry>>>,
+}
+
+impl FeatureFlags {
+    /// No flags yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a flag with its default value
+    pub fn define(self, name: impl Into<String>, default: bool) -> Self {
+        let name = name.into();
+        let mut flags = self.flags.borrow_mut();
+        match flags.get_mut(&name) {
+            Some(entry) => entry.default = default,
+            None => {
+                flags.insert(name, FlagEntry { value: Signal::new(default), default });
+            }
+        }
+        drop(flags);
+        self
+    }
+
+    /// Make these flags the app's flags (see `Context`)
+    pub fn provide(&self) {
+        Context::provide(self.clone());
+    }
+
+    /// The provided flags (an empty set if none were provided)
+    pub fn current() -> Self {
+        Context::get::<FeatureFlags>().unwrap_or_default()
+    }
+
+    /// Signal of a flag (undeclared flags start off)
+    pub fn flag(&self, name: &str) -> Signal<bool> {
+        self.flags
+            .borrow_mut()
+            .entry(name.to_string())
+            .or_insert_with(|| FlagEntry { value: Signal::new(false), default: false })
+            .value
+            .clone()
+    }
+
+    /// Is a flag on right now?
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.flags.borrow().get(name).is_some_and(|entry| entry.value.get())
+    }
+
+    /// Turn a flag on or off
+    pub fn set(&self, name: &str, enabled: bool) {
+        let flag = self.flag(name);
+        if flag.get() != enabled {
+            info!("🚩 {} → {}", name, if enabled { "on" } else { "off" });
+            flag.set(enabled);
+        }
+    }
+
+    /// Flip a flag (debug panel)
+    pub fn toggle(&self, name: &str) {
+        self.set(name, !self.is_enabled(name));
+    }
+
+    /// Back to the declared defaults
+    pub fn reset(&self) {
+        let defaults: Vec<(String, bool)> = self.flags.borrow().iter().map(|(name, entry)| (name.clone(), entry.default)).collect();
+        for (name, default) in defaults {
+            self.set(&name, default);
+        }
+    }
+
+    /// Turn a flag on for `percent` of users (stable per `user_id`)
+    pub fn rollout(&self, name: &str, percent: f32, user_id: &str) {
+        let bucket = bucket(name, user_id) % 10_000;
+        self.set(name, (bucket as f32) < percent.clamp(0.0, 100.0) * 100.0);
+    }
+
+    /// Pick an A/B variant for a user (stable per experiment and `user_id`)
+    pub fn variant<'a>(&self, experiment: &str, variants: &[&'a str], user_id: &str) -> Option<&'a str> {
+        if variants.is_empty() {
+            return None;
+        }
+        Some(variants[(bucket(experiment, user_id) % variants.len() as u64) as usize])
+    }
+
+    /// Apply remote config (unknown names are declared off by default)
+    pub fn apply<'a>(&self, values: impl IntoIterator<Item = (&'a str, bool)>) {
+        for (name, enabled) in values {
+            self.set(name, enabled);
+        }
+    }
+
+    /// Every flag with its current value, sorted by name
+    pub fn snapshot(&self) -> Vec<(String, bool)> {
+        self.flags.borrow().iter().map(|(name, entry)| (name.clone(), entry.value.get())).collect()
+    }
+}
+
+/// FNV-1a of "key:user" (stable across runs and platforms)
+fn bucket(key: &str, user_id: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in key.bytes().chain(std::iter::once(b':')).chain(user_id.bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_are_signals_shared_through_context() {
+        FeatureFlags::new().define("new_checkout", false).define("beta", true).provide();
+        let flags = FeatureFlags::current();
+        let checkout = flags.flag("new_checkout");
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        checkout.subscribe(move |on| log.borrow_mut().push(*on));
+
+        FeatureFlags::current().toggle("new_checkout");
+        flags.apply([("beta", false)]);
+        assert_eq!(flags.snapshot(), vec![("beta".to_string(), false), ("new_checkout".to_string(), true)]);
+        flags.reset();
+        assert_eq!(*seen.borrow(), vec![true, false]);
+        assert!(flags.is_enabled("beta"));
+    }
+
+    #[test]
+    fn rollouts_and_variants_are_stable_per_user() {
+        let flags = FeatureFlags::new();
+        let users: Vec<String> = (0..1000).map(|i| format!("user-{}", i)).collect();
+        let enabled = users
+            .iter()
+            .filter(|user| {
+                flags.rollout("new_checkout", 25.0, user);
+                flags.is_enabled("new_checkout")
+            })
+            .count();
+        assert!((200..300).contains(&enabled), "{} of 1000 enabled", enabled);
+
+        let first = flags.variant("layout", &["a", "b"], "user-7");
+        assert_eq!(flags.variant("layout", &["a", "b"], "user-7"), first);
+        flags.rollout("all", 100.0, "anyone");
+        assert!(flags.is_enabled("all"));
+    }
+}
@@ -0,0 +1,426 @@
+//! Focus - Keyboard focus and Tab navigation! ⌨️
+//!
+//! `FocusManager` knows which widget has keyboard focus, moves it with
+//! Tab / Shift+Tab, and routes every other key to the focused widget.
+//! Widgets opt in by implementing `Focusable`; components keep their state
+//! in Signals, so registering a clone shares state with the original.
+//!
+//! The focus ring follows the `:focus-visible` rule from the web: it shows
+//! after keyboard navigation and hides when focus came from a click.
+//!
+//! Input method (IME) composition for Chinese, Japanese and Korean goes
+//! to the focused widget too (`handle_ime`), and the widget tells the OS
+//! where to put the candidate window (`ime_cursor_area`).
+
+use std::rc::Rc;
+use tracing::{debug, info};
+
+/// Key routed through the focus system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusKey {
+    Tab,
+    Enter,
+    Space,
+    Escape,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    /// A typed character
+    Char(char),
+}
+
+/// Modifier keys physically held, as the platform reports them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModifierKeys {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    /// Cmd on macOS, the Windows key elsewhere
+    pub meta: bool,
+}
+
+/// Input method (IME) composition event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImeEvent {
+    /// An input method became active
+    Enabled,
+    /// Text being composed (not yet part of the content); `cursor` is a
+    /// byte range inside it, None hides the caret
+    Preedit { text: String, cursor: Option<(usize, usize)> },
+    /// Final text to insert (ends the composition)
+    Commit(String),
+    /// The input method was turned off
+    Disabled,
+}
+
+/// A widget that can take keyboard focus
+pub trait Focusable {
+    /// Focus gained (true) or lost (false)
+    fn set_focused(&self, focused: bool) {
+        let _ = focused; // Default: nothing to update
+    }
+
+    /// Handle a key while focused; return true if it was used
+    fn handle_key(&self, key: FocusKey, shift: bool) -> bool;
+
+    /// Where to draw the focus ring (x, y, width, height)
+    fn focus_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        None
+    }
+
+    /// Handle IME composition while focused; return true if it was used
+    fn handle_ime(&self, event: &ImeEvent) -> bool {
+        let _ = event; // Default: not a text input
+        false
+    }
+
+    /// Caret area the IME candidate window should sit next to
+    fn ime_cursor_area(&self) -> Option<(f32, f32, f32, f32)> {
+        None
+    }
+}
+
+/// Handle to a registered focusable widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FocusId(u64);
+
+/// Focus ring to draw around the focused widget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusRing {
+    /// Ring rectangle (x, y, width, height), already outset
+    pub bounds: (f32, f32, f32, f32),
+    pub color: (u8, u8, u8, u8),
+    pub width: f32,
+}
+
+struct FocusEntry {
+    id: FocusId,
+    tab_index: i32,
+    enabled: bool,
+    target: Rc<dyn Focusable>,
+}
+
+/// Tracks keyboard focus and Tab order 🎯
+///
+/// Tab order works like HTML `tabindex`: positive indices come first in
+/// ascending order, then index 0 in registration order. Negative indices
+/// are skipped by Tab but can still be focused with `request_focus`.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut focus = FocusManager::new();
+/// let name = focus.register(Rc::new(name_field.clone()));
+/// focus.register(Rc::new(agree_checkbox.clone()));
+/// focus.register(Rc::new(submit_button.clone()));
+///
+/// focus.request_focus(name);
+/// focus.handle_key(FocusKey::Char('A'), false); // types into the field
+/// focus.handle_key(FocusKey::Tab, false);       // → checkbox
+/// focus.handle_key(FocusKey::Space, false);     // toggles it
+/// ```
+pub struct FocusManager {
+    entries: Vec<FocusEntry>,
+    focused: Option<FocusId>,
+    next_id: u64,
+    ring_visible: bool,
+    /// Focus ring color
+    pub ring_color: (u8, u8, u8, u8),
+    /// Focus ring thickness
+    pub ring_width: f32,
+    /// Gap between the widget and its ring
+    pub ring_offset: f32,
+}
+
+impl FocusManager {
+    /// Create an empty focus manager
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            focused: None,
+            next_id: 0,
+            ring_visible: false,
+            ring_color: (59, 130, 246, 255), // Blue
+            ring_width: 2.0,
+            ring_offset: 2.0,
+        }
+    }
+
+    /// Register a widget at the default Tab position
+    pub fn register(&mut self, target: Rc<dyn Focusable>) -> FocusId {
+        self.register_with_tab_index(target, 0)
+    }
+
+    /// Register a widget with an explicit tab index
+    pub fn register_with_tab_index(&mut self, target: Rc<dyn Focusable>, tab_index: i32) -> FocusId {
+        let id = FocusId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(FocusEntry { id, tab_index, enabled: true, target });
+        id
+    }
+
+    /// Remove a widget (blurs it if focused)
+    pub fn unregister(&mut self, id: FocusId) {
+        if self.focused == Some(id) {
+            self.blur();
+        }
+        self.entries.retain(|entry| entry.id != id);
+    }
+
+    /// Enable or disable a widget (disabled widgets can't take focus)
+    pub fn set_enabled(&mut self, id: FocusId, enabled: bool) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.enabled = enabled;
+        }
+        if !enabled && self.focused == Some(id) {
+            self.blur();
+        }
+    }
+
+    /// Number of registered widgets
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Is nothing registered?
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Focused widget
+    pub fn focused(&self) -> Option<FocusId> {
+        self.focused
+    }
+
+    /// Is this widget focused?
+    pub fn is_focused(&self, id: FocusId) -> bool {
+        self.focused == Some(id)
+    }
+
+    /// Widgets in Tab order
+    pub fn tab_order(&self) -> Vec<FocusId> {
+        let mut order: Vec<(usize, &FocusEntry)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.enabled && entry.tab_index >= 0)
+            .collect();
+        // Positive indices first (ascending), then 0; ties keep registration order
+        order.sort_by_key(|(position, entry)| (entry.tab_index == 0, entry.tab_index, *position));
+        order.into_iter().map(|(_, entry)| entry.id).collect()
+    }
+
+    /// Focus a widget from code
+    /// Returns false if it isn't registered or is disabled
+    pub fn request_focus(&mut self, id: FocusId) -> bool {
+        self.ring_visible = true;
+        self.move_focus(id)
+    }
+
+    /// Focus a widget because it was clicked (no focus ring)
+    pub fn focus_from_pointer(&mut self, id: FocusId) -> bool {
+        self.ring_visible = false;
+        self.move_focus(id)
+    }
+
+    /// Drop focus
+    pub fn blur(&mut self) {
+        if let Some(entry) = self.focused.and_then(|id| self.entry(id)) {
+            entry.target.set_focused(false);
+        }
+        self.focused = None;
+    }
+
+    /// Move to the next widget in Tab order (wraps)
+    pub fn focus_next(&mut self) -> Option<FocusId> {
+        self.step(1)
+    }
+
+    /// Move to the previous widget in Tab order (wraps)
+    pub fn focus_previous(&mut self) -> Option<FocusId> {
+        self.step(-1)
+    }
+
+    fn step(&mut self, direction: isize) -> Option<FocusId> {
+        let order = self.tab_order();
+        if order.is_empty() {
+            return None;
+        }
+        let len = order.len() as isize;
+        let next = match self.focused.and_then(|id| order.iter().position(|&other| other == id)) {
+            Some(index) => (index as isize + direction).rem_euclid(len),
+            None if direction > 0 => 0,
+            None => len - 1,
+        };
+        let id = order[next as usize];
+        self.ring_visible = true;
+        self.move_focus(id);
+        Some(id)
+    }
+
+    fn move_focus(&mut self, id: FocusId) -> bool {
+        if !self.entry(id).is_some_and(|entry| entry.enabled) {
+            return false;
+        }
+        if self.focused == Some(id) {
+            return true;
+        }
+        self.blur();
+        if let Some(entry) = self.entry(id) {
+            entry.target.set_focused(true);
+        }
+        self.focused = Some(id);
+        debug!("⌨️ Focus moved to {:?}", id);
+        true
+    }
+
+    /// Route a key press
+    /// Tab / Shift+Tab move focus; everything else goes to the focused widget.
+    /// Returns true if the key was used.
+    pub fn handle_key(&mut self, key: FocusKey, shift: bool) -> bool {
+        if key == FocusKey::Tab {
+            let moved = if shift { self.focus_previous() } else { self.focus_next() };
+            return moved.is_some();
+        }
+        let Some(target) = self.focused.and_then(|id| self.entry(id)).map(|entry| entry.target.clone()) else {
+            return false;
+        };
+        // Keyboard use brings the ring back after a click
+        self.ring_visible = true;
+        let handled = target.handle_key(key, shift);
+        if !handled && key == FocusKey::Escape {
+            info!("⌨️ Escape: focus cleared");
+            self.blur();
+            return true;
+        }
+        handled
+    }
+
+    /// Route an IME event to the focused widget
+    pub fn handle_ime(&mut self, event: &ImeEvent) -> bool {
+        let Some(target) = self.focused.and_then(|id| self.entry(id)).map(|entry| entry.target.clone()) else {
+            return false;
+        };
+        target.handle_ime(event)
+    }
+
+    /// Where the focused widget's caret is, for the IME candidate window
+    pub fn ime_cursor_area(&self) -> Option<(f32, f32, f32, f32)> {
+        self.entry(self.focused?)?.target.ime_cursor_area()
+    }
+
+    /// Bounds of the focused widget (x, y, width, height), e.g. to anchor a
+    /// keyboard-opened context menu
+    pub fn focused_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        self.entry(self.focused?)?.target.focus_bounds()
+    }
+
+    /// Is the focus ring showing?
+    pub fn is_ring_visible(&self) -> bool {
+        self.ring_visible && self.focused.is_some()
+    }
+
+    /// Focus ring to draw this frame (None when hidden)
+    pub fn focus_ring(&self) -> Option<FocusRing> {
+        if !self.ring_visible {
+            return None;
+        }
+        let (x, y, width, height) = self.entry(self.focused?)?.target.focus_bounds()?;
+        let outset = self.ring_offset + self.ring_width;
+        Some(FocusRing {
+            bounds: (x - outset, y - outset, width + outset * 2.0, height + outset * 2.0),
+            color: self.ring_color,
+            width: self.ring_width,
+        })
+    }
+
+    fn entry(&self, id: FocusId) -> Option<&FocusEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    struct Probe {
+        focused: Cell<bool>,
+        keys: RefCell<Vec<FocusKey>>,
+    }
+
+    impl Focusable for Probe {
+        fn set_focused(&self, focused: bool) {
+            self.focused.set(focused);
+        }
+
+        fn handle_key(&self, key: FocusKey, _shift: bool) -> bool {
+            self.keys.borrow_mut().push(key);
+            key != FocusKey::Escape
+        }
+
+        fn focus_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+            Some((10.0, 10.0, 100.0, 30.0))
+        }
+    }
+
+    #[test]
+    fn tab_order_follows_tab_index() {
+        let mut focus = FocusManager::new();
+        let a = focus.register(Rc::new(Probe::default()));
+        let b = focus.register_with_tab_index(Rc::new(Probe::default()), 2);
+        let c = focus.register_with_tab_index(Rc::new(Probe::default()), -1);
+        let d = focus.register_with_tab_index(Rc::new(Probe::default()), 1);
+        assert_eq!(focus.tab_order(), vec![d, b, a]);
+
+        assert!(focus.handle_key(FocusKey::Tab, false));
+        assert_eq!(focus.focused(), Some(d));
+        focus.handle_key(FocusKey::Tab, true);
+        assert_eq!(focus.focused(), Some(a));
+
+        // Skipped by Tab, but focusable from code
+        assert!(focus.request_focus(c));
+        focus.set_enabled(c, false);
+        assert_eq!(focus.focused(), None);
+        assert!(!focus.request_focus(c));
+    }
+
+    #[test]
+    fn keys_route_to_focused_widget() {
+        let mut focus = FocusManager::new();
+        let first = Rc::new(Probe::default());
+        let second = Rc::new(Probe::default());
+        let first_id = focus.register(first.clone());
+        focus.register(second.clone());
+
+        assert!(!focus.handle_key(FocusKey::Char('x'), false));
+        focus.focus_from_pointer(first_id);
+        assert!(first.focused.get());
+        assert!(focus.focus_ring().is_none());
+
+        assert!(focus.handle_key(FocusKey::Char('x'), false));
+        assert_eq!(*first.keys.borrow(), vec![FocusKey::Char('x')]);
+        assert_eq!(focus.focus_ring().unwrap().bounds, (6.0, 6.0, 108.0, 38.0));
+        assert_eq!(focus.focused_bounds(), Some((10.0, 10.0, 100.0, 30.0)));
+
+        focus.handle_key(FocusKey::Tab, false);
+        assert!(!first.focused.get());
+        assert!(second.focused.get());
+
+        // Unhandled Escape drops focus
+        assert!(focus.handle_key(FocusKey::Escape, false));
+        assert_eq!(focus.focused(), None);
+        assert!(!second.focused.get());
+    }
+}
@@ -0,0 +1,458 @@
+//! Hit Testing - Route pointer input to the layout nodes under it! 🎯
+//!
+//! `hit_test` walks the computed taffy layouts to find every node under a
+//! point. `EventDispatcher` runs the handlers registered on those nodes the
+//! way the DOM does: capture handlers from the root down, then the target,
+//! then bubble handlers back up. Any handler can stop propagation. A press
+//! and release over the same node also dispatches a `Click` to it.
+//!
+//! Feed it from an `InputHandler`:
+//! ```rust,ignore
+//! fn on_mouse_down(&mut self, _button: MouseButtonEvent, position: MousePosition) {
+//!     self.dispatcher.pointer_down(&self.engine, self.root, position.x as f32, position.y as f32);
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::analytics::Analytics;
+use crate::layout::{LayoutEngine, NodeId};
+use tracing::debug;
+
+/// Kind of pointer event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerEventKind {
+    Down,
+    Up,
+    Move,
+    /// Down and up over the same node
+    Click,
+}
+
+/// Pointer event in window coordinates (logical pixels)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointerEvent {
+    pub kind: PointerEventKind,
+    pub x: f32,
+    pub y: f32,
+}
+
+impl PointerEvent {
+    /// Create a pointer event
+    pub fn new(kind: PointerEventKind, x: f32, y: f32) -> Self {
+        Self { kind, x, y }
+    }
+}
+
+/// Where in the capture/bubble walk a handler is running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// Root → target, before the target sees it
+    Capture,
+    /// On the node under the pointer
+    Target,
+    /// Target → root, after the target saw it
+    Bubble,
+}
+
+/// Passed to every handler during a dispatch
+#[derive(Debug, Clone)]
+pub struct EventContext {
+    pub phase: EventPhase,
+    /// Deepest node under the pointer
+    pub target: NodeId,
+    /// Node whose handler is running
+    pub current: NodeId,
+    /// Pointer position relative to `current`'s top-left corner
+    pub local: (f32, f32),
+    stopped: bool,
+}
+
+impl EventContext {
+    /// Stop the event reaching any further nodes
+    pub fn stop_propagation(&mut self) {
+        self.stopped = true;
+    }
+
+    /// Was propagation stopped?
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+}
+
+/// Pointer handler registered on a node
+pub type EventHandler = Box<dyn FnMut(&PointerEvent, &mut EventContext)>;
+
+struct Registered {
+    capture: bool,
+    handler: EventHandler,
+}
+
+/// Node under the pointer and its absolute top-left corner
+type HitNode = (NodeId, (f32, f32));
+
+/// Every node under a point, root first and the topmost (deepest) last
+///
+/// Later siblings are drawn on top, so they win when siblings overlap.
+/// Hidden (`Display::None`) nodes have no size and are never hit.
+pub fn hit_test(engine: &LayoutEngine, root: NodeId, x: f32, y: f32) -> Vec<NodeId> {
+    hit_path(engine, root, x, y).into_iter().map(|(node, _)| node).collect()
+}
+
+/// Topmost node under a point
+pub fn node_at(engine: &LayoutEngine, root: NodeId, x: f32, y: f32) -> Option<NodeId> {
+    hit_test(engine, root, x, y).pop()
+}
+
+fn hit_path(engine: &LayoutEngine, root: NodeId, x: f32, y: f32) -> Vec<HitNode> {
+    let mut path = Vec::new();
+    walk(engine, root, (0.0, 0.0), x, y, &mut path);
+    path
+}
+
+fn walk(engine: &LayoutEngine, node: NodeId, origin: (f32, f32), x: f32, y: f32, path: &mut Vec<HitNode>) -> bool {
+    let Ok(layout) = engine.get_layout(node) else {
+        return false;
+    };
+    let left = origin.0 + layout.location.x;
+    let top = origin.1 + layout.location.y;
+    if x < left || y < top || x >= left + layout.size.width || y >= top + layout.size.height {
+        return false;
+    }
+    path.push((node, (left, top)));
+    let children = engine.children(node).unwrap_or_default();
+    for child in children.into_iter().rev() {
+        if walk(engine, child, (left, top), x, y, path) {
+            break;
+        }
+    }
+    true
+}
+
+/// Capture/bubble pointer dispatch over layout nodes 📬
+///
+/// # Example
+/// ```rust,ignore
+/// let mut dispatcher = EventDispatcher::new();
+/// dispatcher.on(row, |event, _ctx| {
+///     if event.kind == PointerEventKind::Click {
+///         println!("Row clicked!");
+///     }
+/// });
+/// dispatcher.on_capture(list, |event, ctx| {
+///     if event.kind == PointerEventKind::Down && dragging {
+///         ctx.stop_propagation(); // rows never see it
+///     }
+/// });
+///
+/// dispatcher.pointer_down(&engine, root, 40.0, 120.0);
+/// dispatcher.pointer_up(&engine, root, 40.0, 120.0); // also fires Click
+/// ```
+pub struct EventDispatcher {
+    handlers: HashMap<NodeId, Vec<Registered>>,
+    pressed: Option<NodeId>,
+    /// Stable names for tests and analytics
+    test_ids: HashMap<NodeId, String>,
+}
+
+impl EventDispatcher {
+    /// Create a dispatcher with no handlers
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            pressed: None,
+            test_ids: HashMap::new(),
+        }
+    }
+
+    /// Handle events targeting a node or bubbling up from its descendants
+    pub fn on<F>(&mut self, node: NodeId, handler: F)
+    where
+        F: FnMut(&PointerEvent, &mut EventContext) + 'static,
+    {
+        self.register(node, false, Box::new(handler));
+    }
+
+    /// Handle events on their way down to a descendant (before it sees them)
+    pub fn on_capture<F>(&mut self, node: NodeId, handler: F)
+    where
+        F: FnMut(&PointerEvent, &mut EventContext) + 'static,
+    {
+        self.register(node, true, Box::new(handler));
+    }
+
+    fn register(&mut self, node: NodeId, capture: bool, handler: EventHandler) {
+        self.handlers.entry(node).or_default().push(Registered { capture, handler });
+    }
+
+    /// Name a node for tests and analytics; clicks inside it are
+    /// reported as interactions when an `AnalyticsSink` is installed
+    pub fn set_test_id(&mut self, node: NodeId, test_id: impl Into<String>) {
+        self.test_ids.insert(node, test_id.into());
+    }
+
+    /// Node tagged with a test id
+    pub fn find_test_id(&self, test_id: &str) -> Option<NodeId> {
+        self.test_ids.iter().find(|(_, id)| *id == test_id).map(|(node, _)| *node)
+    }
+
+    /// Remove every handler on a node
+    pub fn remove(&mut self, node: NodeId) {
+        self.handlers.remove(&node);
+        self.test_ids.remove(&node);
+        if self.pressed == Some(node) {
+            self.pressed = None;
+        }
+    }
+
+    /// Remove every handler (e.g. before the tree is rebuilt)
+    pub fn clear(&mut self) {
+        self.handlers.clear();
+        self.test_ids.clear();
+        self.pressed = None;
+    }
+
+    /// Does a node have handlers?
+    pub fn has_handlers(&self, node: NodeId) -> bool {
+        self.handlers.get(&node).is_some_and(|handlers| !handlers.is_empty())
+    }
+
+    /// Pointer pressed
+    pub fn pointer_down(&mut self, engine: &LayoutEngine, root: NodeId, x: f32, y: f32) -> bool {
+        self.dispatch(engine, root, PointerEvent::new(PointerEventKind::Down, x, y))
+    }
+
+    /// Pointer released (dispatches `Click` too when it's over the pressed node)
+    pub fn pointer_up(&mut self, engine: &LayoutEngine, root: NodeId, x: f32, y: f32) -> bool {
+        self.dispatch(engine, root, PointerEvent::new(PointerEventKind::Up, x, y))
+    }
+
+    /// Pointer moved
+    pub fn pointer_move(&mut self, engine: &LayoutEngine, root: NodeId, x: f32, y: f32) -> bool {
+        self.dispatch(engine, root, PointerEvent::new(PointerEventKind::Move, x, y))
+    }
+
+    /// Hit-test an event and run the handlers along its path
+    /// Returns true if any handler ran
+    pub fn dispatch(&mut self, engine: &LayoutEngine, root: NodeId, event: PointerEvent) -> bool {
+        let path = hit_path(engine, root, event.x, event.y);
+        let mut handled = self.dispatch_path(&path, &event);
+
+        match event.kind {
+            PointerEventKind::Down => self.pressed = path.last().map(|(node, _)| *node),
+            PointerEventKind::Up => {
+                // Click goes to the pressed node if the pointer is still over it
+                let pressed = self.pressed.take();
+                if let Some(index) = pressed.and_then(|pressed| path.iter().position(|(node, _)| *node == pressed)) {
+                    let click = PointerEvent::new(PointerEventKind::Click, event.x, event.y);
+                    handled |= self.dispatch_path(&path[..=index], &click);
+                    // The innermost tagged node names the interaction
+                    if let Some(test_id) = path[..=index].iter().rev().find_map(|(node, _)| self.test_ids.get(node)) {
+                        Analytics::interaction(test_id, "click");
+                    }
+                }
+            }
+            PointerEventKind::Move | PointerEventKind::Click => {}
+        }
+        handled
+    }
+
+    fn dispatch_path(&mut self, path: &[HitNode], event: &PointerEvent) -> bool {
+        let Some(&(target, _)) = path.last() else {
+            return false;
+        };
+        debug!("🎯 {:?} at ({}, {}) → {:?}", event.kind, event.x, event.y, target);
+        let mut ctx = EventContext {
+            phase: EventPhase::Capture,
+            target,
+            current: target,
+            local: (0.0, 0.0),
+            stopped: false,
+        };
+        let ancestors = &path[..path.len() - 1];
+        let mut handled = false;
+
+        for &(node, origin) in ancestors {
+            handled |= self.run(node, origin, EventPhase::Capture, event, &mut ctx);
+            if ctx.stopped {
+                return handled;
+            }
+        }
+        let (_, origin) = path[path.len() - 1];
+        handled |= self.run(target, origin, EventPhase::Target, event, &mut ctx);
+        for &(node, origin) in ancestors.iter().rev() {
+            if ctx.stopped {
+                break;
+            }
+            handled |= self.run(node, origin, EventPhase::Bubble, event, &mut ctx);
+        }
+        handled
+    }
+
+    fn run(&mut self, node: NodeId, origin: (f32, f32), phase: EventPhase, event: &PointerEvent, ctx: &mut EventContext) -> bool {
+        let Some(handlers) = self.handlers.get_mut(&node) else {
+            return false;
+        };
+        ctx.phase = phase;
+        ctx.current = node;
+        ctx.local = (event.x - origin.0, event.y - origin.1);
+        let mut ran = false;
+        for registered in handlers.iter_mut() {
+            let wanted = match phase {
+                EventPhase::Capture => registered.capture,
+                EventPhase::Target => true,
+                EventPhase::Bubble => !registered.capture,
+            };
+            if wanted {
+                (registered.handler)(event, ctx);
+                ran = true;
+                if ctx.stopped {
+                    break;
+                }
+            }
+        }
+        ran
+    }
+}
+
+impl Default for EventDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use taffy::prelude::{length, Size, Style};
+
+    /// 200x200 root with a 100x100 panel at (20, 20) holding a 50x50 button at (10, 10)
+    fn tree() -> (LayoutEngine, NodeId, NodeId, NodeId) {
+        let mut engine = LayoutEngine::new();
+        let button = engine
+            .new_leaf(Style { size: Size { width: length(50.0), height: length(50.0) }, ..Default::default() })
+            .unwrap();
+        let panel = engine
+            .new_with_children(
+                Style {
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    padding: taffy::geometry::Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) },
+                    ..Default::default()
+                },
+                &[button],
+            )
+            .unwrap();
+        let root = engine
+            .new_with_children(
+                Style {
+                    size: Size { width: length(200.0), height: length(200.0) },
+                    padding: taffy::geometry::Rect { left: length(20.0), right: length(20.0), top: length(20.0), bottom: length(20.0) },
+                    ..Default::default()
+                },
+                &[panel],
+            )
+            .unwrap();
+        engine.compute_layout(root, Size::max_content()).unwrap();
+        (engine, root, panel, button)
+    }
+
+    #[test]
+    fn hit_test_returns_path_to_deepest_node() {
+        let (engine, root, panel, button) = tree();
+        assert_eq!(hit_test(&engine, root, 40.0, 40.0), vec![root, panel, button]);
+        assert_eq!(hit_test(&engine, root, 100.0, 100.0), vec![root, panel]);
+        assert_eq!(node_at(&engine, root, 5.0, 5.0), Some(root));
+        assert_eq!(node_at(&engine, root, 250.0, 5.0), None);
+    }
+
+    #[test]
+    fn dispatch_runs_capture_target_bubble() {
+        let (engine, root, panel, button) = tree();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = EventDispatcher::new();
+        for (node, name) in [(root, "root"), (panel, "panel"), (button, "button")] {
+            let sink = log.clone();
+            dispatcher.on(node, move |_, ctx| sink.borrow_mut().push(format!("{} {:?}", name, ctx.phase)));
+            let sink = log.clone();
+            dispatcher.on_capture(node, move |_, ctx| sink.borrow_mut().push(format!("{} capture {:?}", name, ctx.phase)));
+        }
+
+        assert!(dispatcher.pointer_move(&engine, root, 40.0, 40.0));
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "root capture Capture",
+                "panel capture Capture",
+                "button Target",
+                "button capture Target",
+                "panel Bubble",
+                "root Bubble",
+            ]
+        );
+    }
+
+    #[test]
+    fn stop_propagation_and_click() {
+        let (engine, root, panel, button) = tree();
+        let clicks = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = EventDispatcher::new();
+        let sink = clicks.clone();
+        dispatcher.on(button, move |event, ctx| {
+            if event.kind == PointerEventKind::Click {
+                sink.borrow_mut().push(ctx.local);
+                ctx.stop_propagation();
+            }
+        });
+        let sink = clicks.clone();
+        dispatcher.on(panel, move |event, _| {
+            if event.kind == PointerEventKind::Click {
+                sink.borrow_mut().push((-1.0, -1.0));
+            }
+        });
+
+        dispatcher.pointer_down(&engine, root, 40.0, 40.0);
+        dispatcher.pointer_up(&engine, root, 45.0, 42.0);
+        assert_eq!(*clicks.borrow(), vec![(15.0, 12.0)]);
+
+        // Released off the button: no click
+        dispatcher.pointer_down(&engine, root, 40.0, 40.0);
+        dispatcher.pointer_up(&engine, root, 100.0, 100.0);
+        assert_eq!(clicks.borrow().len(), 1);
+        dispatcher.pointer_down(&engine, root, 100.0, 100.0);
+        dispatcher.pointer_up(&engine, root, 110.0, 100.0);
+        assert_eq!(clicks.borrow().last(), Some(&(-1.0, -1.0)));
+    }
+
+    #[test]
+    fn clicks_on_tagged_nodes_reach_analytics() {
+        use crate::analytics::{Analytics, AnalyticsEvent};
+
+        let (engine, root, panel, button) = tree();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = events.clone();
+        Analytics::install(Rc::new(move |event: &AnalyticsEvent, _: std::time::Duration| log.borrow_mut().push(event.clone())));
+        Analytics::screen_view("home");
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.set_test_id(panel, "settings-panel");
+        dispatcher.set_test_id(button, "save-button");
+        assert_eq!(dispatcher.find_test_id("save-button"), Some(button));
+
+        dispatcher.pointer_down(&engine, root, 40.0, 40.0);
+        dispatcher.pointer_up(&engine, root, 40.0, 40.0);
+        dispatcher.pointer_down(&engine, root, 100.0, 100.0);
+        dispatcher.pointer_up(&engine, root, 100.0, 100.0);
+        Analytics::uninstall();
+
+        let ids: Vec<String> = events
+            .borrow()
+            .iter()
+            .filter_map(|event| match event {
+                AnalyticsEvent::Interaction { test_id, .. } => Some(test_id.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ids, vec!["save-button", "settings-panel"]);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -253,6 +255,111 @@ impl Default for HotReloadManager {
     }
 }
 
+/// FileWatcher - Notices when asset files (stylesheets, themes…) change! 👀
+///
+/// Watches individual files rather than a whole directory. `poll` is
+/// non-blocking, so call it once per frame. Editors often save by
+/// replacing the file, so the parent directory is watched; only files
+/// named in its events are re-checked, and a change is confirmed by
+/// comparing modification time and size. Without OS events (or after an
+/// event that names no paths) every file is re-checked.
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<(std::time::SystemTime, u64)>)>,
+    /// Kept alive so events keep coming
+    watcher: Option<notify::RecommendedWatcher>,
+    events: Option<std::sync::mpsc::Receiver<Event>>,
+}
+
+impl FileWatcher {
+    /// Create a watcher with nothing watched
+    pub fn new() -> Self {
+        Self { files: Vec::new(), watcher: None, events: None }
+    }
+
+    /// Start watching a file
+    pub fn watch(&mut self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref().to_path_buf();
+        if self.watcher.is_none() {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let watcher = notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
+                if let Ok(event) = res {
+                    let _ = tx.send(event);
+                }
+            });
+            match watcher {
+                Ok(watcher) => {
+                    self.watcher = Some(watcher);
+                    self.events = Some(rx);
+                }
+                // Still works through the mtime check in `poll`
+                Err(e) => warn!("⚠️ File events unavailable, polling instead: {}", e),
+            }
+        }
+        if let Some(watcher) = self.watcher.as_mut() {
+            let directory = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+            if let Err(e) = watcher.watch(directory, RecursiveMode::NonRecursive) {
+                warn!("⚠️ Could not watch {:?}: {}", directory, e);
+            }
+        }
+        info!("👀 Watching file: {:?}", path);
+        let stamp = Self::stamp(&path);
+        self.files.push((path, stamp));
+        Ok(())
+    }
+
+    /// Watched files
+    pub fn files(&self) -> Vec<&Path> {
+        self.files.iter().map(|(path, _)| path.as_path()).collect()
+    }
+
+    /// Files that changed since the last poll
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let touched = self.touched_names();
+        let mut changed = Vec::new();
+        for (path, stamp) in &mut self.files {
+            let named = touched.as_ref().is_none_or(|names| path.file_name().is_some_and(|name| names.contains(name)));
+            if !named {
+                continue;
+            }
+            let current = Self::stamp(path);
+            if current != *stamp {
+                *stamp = current;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    /// File names in the pending OS events (None = re-check everything)
+    ///
+    /// Names rather than full paths, since events may carry canonical paths
+    /// for files that were watched through relative ones.
+    fn touched_names(&self) -> Option<HashSet<OsString>> {
+        let events = self.events.as_ref()?;
+        let mut names = HashSet::new();
+        for event in events.try_iter() {
+            if event.paths.is_empty() || event.need_rescan() {
+                // Drain the rest and fall back to checking every file
+                events.try_iter().for_each(drop);
+                return None;
+            }
+            names.extend(event.paths.iter().filter_map(|path| path.file_name()).map(OsString::from));
+        }
+        Some(names)
+    }
+
+    fn stamp(path: &Path) -> Option<(std::time::SystemTime, u64)> {
+        let metadata = std::fs::metadata(path).ok()?;
+        Some((metadata.modified().ok()?, metadata.len()))
+    }
+}
+
+impl Default for FileWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AppState {
     /// Create a new empty AppState
     pub fn new() -> Self {
@@ -327,6 +434,27 @@ impl Default for AppState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn file_watcher_reports_changes() {
+        let path = std::env::temp_dir().join(format!("nebula-watch-{}.css", std::process::id()));
+        std::fs::write(&path, "a").unwrap();
+        let mut watcher = FileWatcher::new();
+        watcher.watch(&path).unwrap();
+        assert!(watcher.poll().is_empty());
+
+        std::fs::write(&path, "ab").unwrap();
+        // OS events arrive on notify's thread, so give them a moment
+        let start = Instant::now();
+        let mut changed = watcher.poll();
+        while changed.is_empty() && start.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+            changed = watcher.poll();
+        }
+        assert_eq!(changed, vec![path.clone()]);
+        assert!(watcher.poll().is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn hot_reload_manager_creation() {
         let manager = HotReloadManager::new();
@@ -0,0 +1,117 @@
+//! Idle - Notice when nobody is at the screen! 💤
+//!
+//! Kiosks and clinical workstations must lock themselves when left alone.
+//! The platform layer calls `record_input` for every key, click, wheel and
+//! touch; `IdleDetector` compares the last input against a timeout held in
+//! a Signal (so a settings screen can change it live) and flips its `idle`
+//! Signal when the timeout passes.
+//!
+//! The last input time is per UI thread, like the other input state.
+
+use crate::signal::Signal;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+thread_local! {
+    static LAST_INPUT: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// The user did something (called by the platform layer)
+pub fn record_input() {
+    record_input_at(Instant::now());
+}
+
+/// The user did something at a given time
+pub fn record_input_at(at: Instant) {
+    LAST_INPUT.with(|last| last.set(Some(at)));
+}
+
+/// When the user last did something (None before the first input)
+pub fn last_input() -> Option<Instant> {
+    LAST_INPUT.with(|last| last.get())
+}
+
+/// Idle detector - Turns "no input for a while" into a Signal 💤
+///
+/// # Example
+/// ```rust,ignore
+/// let detector = IdleDetector::new(Duration::from_secs(300));
+/// // Every frame:
+/// if detector.poll() {
+///     lock_screen.lock();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct IdleDetector {
+    /// How long without input counts as idle
+    pub timeout: Signal<Duration>,
+    /// Is the user away right now?
+    pub idle: Signal<bool>,
+    started: Instant,
+}
+
+impl IdleDetector {
+    /// Detector with a timeout
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout: Signal::new(timeout), idle: Signal::new(false), started: Instant::now() }
+    }
+
+    /// Change the timeout
+    pub fn set_timeout(&self, timeout: Duration) {
+        self.timeout.set(timeout);
+    }
+
+    /// Time since the last input (or since the detector started, if later)
+    pub fn idle_for(&self, now: Instant) -> Duration {
+        let since = last_input().map_or(self.started, |last| last.max(self.started));
+        now.saturating_duration_since(since)
+    }
+
+    /// Check now; returns true when the user has just become idle
+    pub fn poll(&self) -> bool {
+        self.poll_at(Instant::now())
+    }
+
+    /// Check at a given time; returns true when the user has just become idle
+    pub fn poll_at(&self, now: Instant) -> bool {
+        let idle = self.idle_for(now) >= self.timeout.get();
+        let was_idle = self.idle.get();
+        if idle != was_idle {
+            self.idle.set(idle);
+            info!("💤 User {}", if idle { "is idle" } else { "is back" });
+        }
+        idle && !was_idle
+    }
+
+    /// Is the user idle (as of the last poll)?
+    pub fn is_idle(&self) -> bool {
+        self.idle.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idle_after_timeout_until_input() {
+        let detector = IdleDetector::new(Duration::from_secs(60));
+        let start = Instant::now();
+        record_input_at(start);
+
+        assert!(!detector.poll_at(start + Duration::from_secs(30)));
+        assert!(detector.poll_at(start + Duration::from_secs(61)));
+        // Reported once
+        assert!(!detector.poll_at(start + Duration::from_secs(90)));
+        assert!(detector.is_idle());
+
+        record_input_at(start + Duration::from_secs(100));
+        assert!(!detector.poll_at(start + Duration::from_secs(101)));
+        assert!(!detector.is_idle());
+
+        // A shorter timeout applies straight away
+        detector.set_timeout(Duration::from_secs(5));
+        assert!(detector.poll_at(start + Duration::from_secs(106)));
+    }
+}
@@ -0,0 +1,179 @@
+//! Input Settings - Click, drag, scroll and key-repeat tuning in one place! 🖱️
+//!
+//! How fast a double-click is, how far the mouse moves before a press
+//! becomes a drag, how many pixels one wheel notch scrolls: users set
+//! these in their OS and expect every app to follow. `InputSettings`
+//! holds them for the whole UI thread so FileBrowser, DragController and
+//! text selection all agree.
+//!
+//! The platform layer reports the OS values with `set_system`; apps can
+//! override them (e.g. a bigger drag threshold for touch screens) with
+//! `set_override`. `ClickCounter` turns presses into click counts using
+//! the current double-click interval and distance.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Pointer and keyboard tuning
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputSettings {
+    /// Longest gap between the clicks of a double-click
+    pub double_click_interval: Duration,
+    /// How far the pointer may move between the clicks of a double-click
+    pub double_click_distance: f32,
+    /// Distance the pointer must travel before a press becomes a drag
+    pub drag_threshold: f32,
+    /// Pixels per line when the wheel reports lines instead of pixels
+    pub scroll_line_height: f32,
+    /// Lines scrolled per wheel notch
+    pub wheel_scroll_lines: f32,
+    /// Deliver auto-repeated key presses (held keys)
+    pub key_repeat: bool,
+    /// Hold time before a key starts repeating
+    pub key_repeat_delay: Duration,
+    /// Time between repeats
+    pub key_repeat_interval: Duration,
+}
+
+impl InputSettings {
+    /// Common desktop defaults (used when the OS can't be read)
+    pub const DEFAULT: Self = Self {
+        double_click_interval: Duration::from_millis(500),
+        double_click_distance: 4.0,
+        drag_threshold: 4.0,
+        scroll_line_height: 16.0,
+        wheel_scroll_lines: 3.0,
+        key_repeat: true,
+        key_repeat_delay: Duration::from_millis(500),
+        key_repeat_interval: Duration::from_millis(33),
+    };
+
+    /// The settings input handling follows right now (per UI thread)
+    pub fn current() -> Self {
+        INPUT.with(|input| {
+            let input = input.borrow();
+            input.app_override.unwrap_or(input.system)
+        })
+    }
+
+    /// What the OS reported (ignoring any override)
+    pub fn system() -> Self {
+        INPUT.with(|input| input.borrow().system)
+    }
+
+    /// Record the OS settings
+    pub fn set_system(settings: InputSettings) {
+        INPUT.with(|input| input.borrow_mut().system = settings);
+        info!("🖱️ Input settings: {:?}", Self::current());
+    }
+
+    /// Override the OS settings (None follows the OS again)
+    ///
+    /// Start from `system()` to change a single value:
+    /// `InputSettings::set_override(Some(InputSettings { drag_threshold: 10.0, ..InputSettings::system() }))`
+    pub fn set_override(settings: Option<InputSettings>) {
+        INPUT.with(|input| input.borrow_mut().app_override = settings);
+        info!("🖱️ Input settings: {:?}", Self::current());
+    }
+
+    /// Pixels to scroll for a wheel delta measured in lines
+    pub fn lines_to_pixels(&self, lines: f32) -> f32 {
+        lines * self.wheel_scroll_lines * self.scroll_line_height
+    }
+
+    /// Has the pointer moved far enough from `origin` to start a drag?
+    pub fn is_drag(&self, origin: (f32, f32), pointer: (f32, f32)) -> bool {
+        (pointer.0 - origin.0).hypot(pointer.1 - origin.1) >= self.drag_threshold
+    }
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+struct InputState {
+    system: InputSettings,
+    app_override: Option<InputSettings>,
+}
+
+thread_local! {
+    static INPUT: RefCell<InputState> = const { RefCell::new(InputState { system: InputSettings::DEFAULT, app_override: None }) };
+}
+
+/// Counts clicks for double- and triple-click 🖱️
+///
+/// Call `press` on every mouse down; it returns 1 for a single click,
+/// 2 for a double-click, 3 for a triple-click and so on, following
+/// `InputSettings::current()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClickCounter {
+    last: Option<(f32, f32, Instant)>,
+    count: u32,
+}
+
+impl ClickCounter {
+    /// No clicks yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mouse down at (x, y) now
+    pub fn press(&mut self, x: f32, y: f32) -> u32 {
+        self.press_at(x, y, Instant::now())
+    }
+
+    /// Mouse down at (x, y) at a given time
+    pub fn press_at(&mut self, x: f32, y: f32, now: Instant) -> u32 {
+        let settings = InputSettings::current();
+        let continues = self.last.is_some_and(|(last_x, last_y, at)| {
+            now.saturating_duration_since(at) <= settings.double_click_interval
+                && (x - last_x).hypot(y - last_y) <= settings.double_click_distance
+        });
+        self.count = if continues { self.count + 1 } else { 1 };
+        self.last = Some((x, y, now));
+        self.count
+    }
+
+    /// Clicks in the current run
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Forget the last click (e.g. the content under the pointer changed)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicks_count_within_interval_and_distance() {
+        let mut clicks = ClickCounter::new();
+        let start = Instant::now();
+        assert_eq!(clicks.press_at(10.0, 10.0, start), 1);
+        assert_eq!(clicks.press_at(11.0, 10.0, start + Duration::from_millis(200)), 2);
+        assert_eq!(clicks.press_at(11.0, 11.0, start + Duration::from_millis(400)), 3);
+        // Too slow
+        assert_eq!(clicks.press_at(11.0, 11.0, start + Duration::from_secs(2)), 1);
+        // Too far
+        assert_eq!(clicks.press_at(50.0, 11.0, start + Duration::from_millis(2100)), 1);
+    }
+
+    #[test]
+    fn override_replaces_system_settings() {
+        InputSettings::set_system(InputSettings { drag_threshold: 6.0, ..InputSettings::DEFAULT });
+        assert_eq!(InputSettings::current().drag_threshold, 6.0);
+        InputSettings::set_override(Some(InputSettings { drag_threshold: 12.0, ..InputSettings::system() }));
+        assert!(!InputSettings::current().is_drag((0.0, 0.0), (8.0, 0.0)));
+        InputSettings::set_override(None);
+        assert!(InputSettings::current().is_drag((0.0, 0.0), (8.0, 0.0)));
+        assert_eq!(InputSettings::DEFAULT.lines_to_pixels(1.0), 48.0);
+        InputSettings::set_system(InputSettings::DEFAULT);
+    }
+}
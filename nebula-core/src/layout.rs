@@ -2,6 +2,8 @@ use taffy::prelude::*;
 use taffy::TaffyTree as Taffy;
 use tracing::{info, debug};
 use std::collections::HashMap;
+use crate::signal::Signal;
+use crate::text::{FontFamily, TextLayout, TextRenderer};
 
 /// Layout engine wrapper around Taffy
 /// Provides Flexbox layout for Nebula UI! 📐
@@ -13,11 +15,85 @@ use std::collections::HashMap;
 /// 
 /// But ours works on 20-year-old machines! 🚀
 pub struct LayoutEngine {
-    taffy: Taffy,
+    taffy: Taffy<TextMeasure>,
     /// Cache of computed layouts
     layout_cache: HashMap<NodeId, Layout>,
     /// Dirty nodes that need re-layout
     dirty_nodes: Vec<NodeId>,
+    /// Physical pixels per logical pixel (layout is always logical)
+    scale_factor: f32,
+    /// Fonts for measuring text leaves, loaded on first use
+    text_renderers: HashMap<FontFamily, Option<TextRenderer>>,
+}
+
+/// Text a leaf sizes itself to 📏
+///
+/// A leaf created with `new_text_leaf` and an `Auto` size gets its size
+/// from its text: as wide as its longest line, wrapping at word
+/// boundaries when the parent is narrower, and as tall as its lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMeasure {
+    pub text: String,
+    pub font_size: u32,
+    pub font_family: FontFamily,
+    /// Line height as a multiple of the font size
+    pub line_height: f32,
+    /// Extra space around the text (horizontal, vertical), e.g. a button's padding
+    pub padding: (f32, f32),
+    /// Wrap onto several lines when the width is constrained
+    pub wrap: bool,
+    /// Lines to keep before cutting off with an ellipsis
+    pub max_lines: Option<usize>,
+}
+
+impl TextMeasure {
+    /// Measure for a line of text (wrapping, 1.2 line height, no padding)
+    pub fn new(text: impl Into<String>, font_size: u32) -> Self {
+        Self {
+            text: text.into(),
+            font_size,
+            font_family: FontFamily::Roboto,
+            line_height: 1.2,
+            padding: (0.0, 0.0),
+            wrap: true,
+            max_lines: None,
+        }
+    }
+
+    /// Set the font family
+    pub fn font_family(mut self, family: FontFamily) -> Self {
+        self.font_family = family;
+        self
+    }
+
+    /// Add space around the text (horizontal, vertical; both sides together)
+    pub fn padding(mut self, horizontal: f32, vertical: f32) -> Self {
+        self.padding = (horizontal, vertical);
+        self
+    }
+
+    /// Cut off after this many lines
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines.max(1));
+        self
+    }
+
+    /// Keep the text on one line
+    pub fn no_wrap(mut self) -> Self {
+        self.wrap = false;
+        self
+    }
+
+    /// Size of the text (padding included) within a width limit
+    pub fn measure(&self, max_width: Option<f32>, measure_text: &mut dyn FnMut(&str) -> f32) -> (f32, f32) {
+        let mut layout = TextLayout::new(self.font_size).line_height(self.line_height);
+        layout.max_lines = self.max_lines;
+        if let Some(width) = max_width.filter(|_| self.wrap) {
+            layout = layout.max_width((width - self.padding.0).max(0.0));
+        }
+        let lines = layout.layout(&self.text, measure_text);
+        (lines.width.ceil() + self.padding.0, lines.height.ceil() + self.padding.1)
+    }
 }
 
 /// Node ID wrapper
@@ -39,9 +115,79 @@ impl From<Direction> for FlexDirection {
     fn from(dir: Direction) -> Self {
         match dir {
             Direction::Column => FlexDirection::Column,
-            Direction::Row => FlexDirection::Row,
+            Direction::Row => LayoutDirection::current().row(),
+        }
+    }
+}
+
+/// Reading direction of the UI 🌍
+///
+/// Arabic, Hebrew, Persian and Urdu read right to left, and users expect
+/// the whole UI to mirror: rows start at the right, leading padding is on
+/// the right, horizontal scrolling starts at the right edge. Set it once
+/// (usually from the locale) and rows, HStacks, Navigation, MenuBar,
+/// ScrollView and style padding/margin follow.
+///
+/// Like the other UI-wide settings it lives on the UI thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDirection {
+    /// Left to right
+    #[default]
+    LTR,
+    /// Right to left (mirrored)
+    RTL,
+}
+
+thread_local! {
+    static LAYOUT_DIRECTION: Signal<LayoutDirection> = Signal::new(LayoutDirection::LTR);
+}
+
+impl LayoutDirection {
+    /// The direction layout follows right now
+    pub fn current() -> Self {
+        LAYOUT_DIRECTION.with(|direction| direction.get())
+    }
+
+    /// Switch the whole UI's direction (widgets rebuilt after this mirror)
+    pub fn set(direction: LayoutDirection) {
+        if Self::current() != direction {
+            info!("🌍 Layout direction: {:?}", direction);
+            LAYOUT_DIRECTION.with(|signal| signal.set(direction));
         }
     }
+
+    /// Signal of the direction (fires when it changes)
+    pub fn signal() -> Signal<LayoutDirection> {
+        LAYOUT_DIRECTION.with(|direction| direction.clone())
+    }
+
+    /// Direction for a language tag: RTL for Arabic, Hebrew, Persian, Urdu and friends
+    pub fn for_language(language: &str) -> Self {
+        const RTL_LANGUAGES: &[&str] = &["ar", "he", "iw", "fa", "ur", "yi", "ps", "sd", "ug", "dv", "ckb"];
+        let primary = language.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        if RTL_LANGUAGES.contains(&primary.as_str()) { LayoutDirection::RTL } else { LayoutDirection::LTR }
+    }
+
+    /// Is this right to left?
+    pub fn is_rtl(self) -> bool {
+        self == LayoutDirection::RTL
+    }
+
+    /// Flex direction for a row laid out in this direction
+    pub fn row(self) -> FlexDirection {
+        if self.is_rtl() { FlexDirection::RowReverse } else { FlexDirection::Row }
+    }
+
+    /// (left, right) for logical (start, end) sides
+    pub fn sides<T>(self, start: T, end: T) -> (T, T) {
+        if self.is_rtl() { (end, start) } else { (start, end) }
+    }
+
+    /// Mirror an x position inside a container: the left edge of a box
+    /// of `width` placed `x` from the start edge
+    pub fn mirror_x(self, x: f32, width: f32, container_width: f32) -> f32 {
+        if self.is_rtl() { container_width - x - width } else { x }
+    }
 }
 
 impl LayoutEngine {
@@ -52,9 +198,38 @@ impl LayoutEngine {
             taffy: Taffy::new(),
             layout_cache: HashMap::new(),
             dirty_nodes: Vec::new(),
+            scale_factor: 1.0,
+            text_renderers: HashMap::new(),
         }
     }
 
+    /// Create a leaf that sizes itself to its text (see `TextMeasure`)
+    pub fn new_text_leaf(&mut self, style: Style, text: TextMeasure) -> Result<NodeId, taffy::TaffyError> {
+        let node = self.taffy.new_leaf_with_context(style, text)?;
+        self.mark_dirty(node);
+        Ok(node)
+    }
+
+    /// Change a text leaf's text (it's re-measured on the next layout)
+    pub fn set_text(&mut self, node: NodeId, text: impl Into<String>) -> Result<(), taffy::TaffyError> {
+        if let Some(measure) = self.taffy.get_node_context_mut(node) {
+            measure.text = text.into();
+        }
+        self.taffy.mark_dirty(node)?;
+        // Ancestors' cached layouts depend on this size too
+        let mut current = Some(node);
+        while let Some(dirty) = current {
+            self.mark_dirty(dirty);
+            current = self.taffy.parent(dirty);
+        }
+        Ok(())
+    }
+
+    /// A text leaf's measure, if it has one
+    pub fn text_measure(&self, node: NodeId) -> Option<&TextMeasure> {
+        self.taffy.get_node_context(node)
+    }
+
     /// Create a new leaf node (no children)
     pub fn new_leaf(&mut self, style: Style) -> Result<NodeId, taffy::TaffyError> {
         let node = self.taffy.new_leaf(style)?;
@@ -83,16 +258,21 @@ impl LayoutEngine {
         self.new_with_children(style, children)
     }
 
-    /// Create an HStack (horizontal stack / row)
+    /// Create an HStack (horizontal stack / row, mirrored in RTL)
     pub fn create_hstack(&mut self, children: &[NodeId]) -> Result<NodeId, taffy::TaffyError> {
         let style = Style {
             display: Display::Flex,
-            flex_direction: FlexDirection::Row,
+            flex_direction: LayoutDirection::current().row(),
             ..Default::default()
         };
         self.new_with_children(style, children)
     }
 
+    /// Get a copy of a node's style
+    pub fn get_style(&self, node: NodeId) -> Result<Style, taffy::TaffyError> {
+        self.taffy.style(node).cloned()
+    }
+
     /// Set node style
     pub fn set_style(&mut self, node: NodeId, style: Style) -> Result<(), taffy::TaffyError> {
         self.taffy.set_style(node, style)?;
@@ -107,6 +287,11 @@ impl LayoutEngine {
         Ok(())
     }
 
+    /// Get a node's children, in order
+    pub fn children(&self, node: NodeId) -> Result<Vec<NodeId>, taffy::TaffyError> {
+        self.taffy.children(node)
+    }
+
     /// Remove child from node
     pub fn remove_child(&mut self, parent: NodeId, child: NodeId) -> Result<NodeId, taffy::TaffyError> {
         let removed = self.taffy.remove_child(parent, child)?;
@@ -136,8 +321,27 @@ impl LayoutEngine {
             }
         }
 
-        // Compute layout
-        self.taffy.compute_layout(node, available_space)?;
+        // Compute layout, measuring text leaves with their fonts
+        let renderers = &mut self.text_renderers;
+        self.taffy.compute_layout_with_measure(node, available_space, |known, available, _, measure, _| {
+            let Some(measure) = measure else {
+                return Size::ZERO;
+            };
+            let max_width = known.width.or(match available.width {
+                AvailableSpace::Definite(width) => Some(width),
+                AvailableSpace::MinContent => Some(0.0),
+                AvailableSpace::MaxContent => None,
+            });
+            let renderer = renderers
+                .entry(measure.font_family)
+                .or_insert_with(|| TextRenderer::with_font_family(measure.font_family).ok());
+            let (width, height) = match renderer {
+                Some(renderer) => measure.measure(max_width, &mut |text| renderer.measure_text(text, measure.font_size)),
+                // No font: about half an em per character
+                None => measure.measure(max_width, &mut |text| text.chars().count() as f32 * measure.font_size as f32 * 0.55),
+            };
+            Size { width: known.width.unwrap_or(width), height: known.height.unwrap_or(height) }
+        })?;
         let layout = *self.taffy.layout(node)?;
 
         // Cache the result
@@ -180,6 +384,39 @@ impl LayoutEngine {
     pub fn cache_size(&self) -> usize {
         self.layout_cache.len()
     }
+
+    /// Set the scale factor (physical pixels per logical pixel)
+    /// Every cached node is marked dirty so the next compute re-lays it out
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        if (self.scale_factor - scale_factor).abs() < f32::EPSILON {
+            return;
+        }
+
+        info!("📐 Scale factor changed: {} → {}", self.scale_factor, scale_factor);
+        self.scale_factor = scale_factor;
+
+        let cached: Vec<NodeId> = self.layout_cache.keys().copied().collect();
+        for node in cached {
+            self.mark_dirty(node);
+        }
+        self.layout_cache.clear();
+    }
+
+    /// Get the scale factor
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Get a computed layout in physical pixels (for the backbuffer)
+    pub fn get_physical_layout(&self, node: NodeId) -> Result<Layout, taffy::TaffyError> {
+        let mut layout = self.get_layout(node)?;
+        let scale = self.scale_factor;
+        layout.location.x *= scale;
+        layout.location.y *= scale;
+        layout.size.width *= scale;
+        layout.size.height *= scale;
+        Ok(layout)
+    }
 }
 
 impl Default for LayoutEngine {
@@ -395,6 +632,29 @@ mod tests {
         assert_eq!(layout.size.height, 100.0);
     }
 
+    #[test]
+    fn rtl_mirrors_rows() {
+        let mut engine = LayoutEngine::new();
+        let first = engine.new_leaf(styles::fixed_size(100.0, 50.0)).unwrap();
+        let second = engine.new_leaf(styles::fixed_size(100.0, 50.0)).unwrap();
+        LayoutDirection::set(LayoutDirection::RTL);
+        let row = engine.create_hstack(&[first, second]).unwrap();
+        LayoutDirection::set(LayoutDirection::LTR);
+
+        let mut style = engine.get_style(row).unwrap();
+        style.size = Size { width: Dimension::Length(300.0), height: Dimension::Auto };
+        engine.set_style(row, style).unwrap();
+        engine.compute_layout(row, Size { width: AvailableSpace::Definite(300.0), height: AvailableSpace::MaxContent }).unwrap();
+        assert_eq!(engine.get_layout(first).unwrap().location.x, 200.0);
+        assert_eq!(engine.get_layout(second).unwrap().location.x, 100.0);
+
+        assert_eq!(LayoutDirection::for_language("ar-EG"), LayoutDirection::RTL);
+        assert_eq!(LayoutDirection::for_language("he"), LayoutDirection::RTL);
+        assert_eq!(LayoutDirection::for_language("en_US"), LayoutDirection::LTR);
+        assert_eq!(LayoutDirection::RTL.sides("start", "end"), ("end", "start"));
+        assert_eq!(LayoutDirection::RTL.mirror_x(10.0, 30.0, 100.0), 60.0);
+    }
+
     #[test]
     fn performance_1000_nodes() {
         let mut engine = LayoutEngine::new();
@@ -425,6 +685,47 @@ mod tests {
         assert!(duration.as_millis() < 15, "Layout took {:?}, should be < 15ms", duration);
     }
 
+    #[test]
+    fn scale_factor_change_marks_dirty() {
+        let mut engine = LayoutEngine::new();
+        assert_eq!(engine.scale_factor(), 1.0);
+
+        let node = engine.new_leaf(styles::fixed_size(100.0, 50.0)).unwrap();
+        let available = Size {
+            width: AvailableSpace::Definite(200.0),
+            height: AvailableSpace::Definite(200.0),
+        };
+        engine.compute_layout(node, available).unwrap();
+        assert_eq!(engine.dirty_count(), 0);
+
+        engine.set_scale_factor(2.0);
+        assert_eq!(engine.scale_factor(), 2.0);
+        assert_eq!(engine.dirty_count(), 1);
+
+        // Same factor again is a no-op
+        engine.compute_layout(node, available).unwrap();
+        engine.set_scale_factor(2.0);
+        assert_eq!(engine.dirty_count(), 0);
+    }
+
+    #[test]
+    fn physical_layout_is_scaled() {
+        let mut engine = LayoutEngine::new();
+        let node = engine.new_leaf(styles::fixed_size(100.0, 50.0)).unwrap();
+        engine.set_scale_factor(1.5);
+
+        let available = Size {
+            width: AvailableSpace::Definite(200.0),
+            height: AvailableSpace::Definite(200.0),
+        };
+        let logical = engine.compute_layout(node, available).unwrap();
+        assert_eq!(logical.size.width, 100.0);
+
+        let physical = engine.get_physical_layout(node).unwrap();
+        assert_eq!(physical.size.width, 150.0);
+        assert_eq!(physical.size.height, 75.0);
+    }
+
     #[test]
     fn cache_clear() {
         let mut engine = LayoutEngine::new();
@@ -441,4 +742,29 @@ mod tests {
         engine.clear_cache();
         assert_eq!(engine.cache_size(), 0);
     }
+
+    #[test]
+    fn text_leaves_size_and_wrap_to_their_text() {
+        let mut engine = LayoutEngine::new();
+        let text = "The quick brown fox jumps over the lazy dog";
+        let leaf = engine.new_text_leaf(Style::default(), TextMeasure::new(text, 16)).unwrap();
+        let column = engine
+            .new_with_children(Style { size: Size { width: Dimension::Length(120.0), height: Dimension::Auto }, ..Default::default() }, &[leaf])
+            .unwrap();
+        let unbounded = Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent };
+
+        engine.compute_layout(leaf, unbounded).unwrap();
+        let one_line = engine.get_layout(leaf).unwrap().size;
+        assert!(one_line.width > 120.0);
+        assert_eq!(one_line.height, (16.0f32 * 1.2).ceil());
+
+        engine.compute_layout(column, unbounded).unwrap();
+        let wrapped = engine.taffy.layout(leaf).unwrap().size;
+        assert!(wrapped.width <= 120.0);
+        assert!(wrapped.height > one_line.height * 2.0); // Three lines
+
+        engine.set_text(leaf, "Hi").unwrap();
+        engine.compute_layout(column, unbounded).unwrap();
+        assert_eq!(engine.taffy.layout(leaf).unwrap().size.height, one_line.height);
+    }
 }
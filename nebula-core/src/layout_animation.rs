@@ -0,0 +1,255 @@
+//! Layout Animation - FLIP for keyed children 🔀
+//!
+//! When rows are inserted, removed or reordered, the layout engine puts
+//! them in their new places in a single frame. `LayoutAnimator` diffs the
+//! layout of keyed nodes between captures, FLIP style:
+//! - **First**: where each key was drawn before the change
+//! - **Last**: where the new layout puts it
+//! - **Invert**: keep drawing it at the old spot
+//! - **Play**: spring it to the new spot
+//!
+//! New keys fade in where they land; removed keys fade out where they were
+//! (see `leaving()`), so data updates never jump.
+
+use crate::animation::SpringAnimation;
+use crate::layout::{LayoutEngine, NodeId};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Where (and how opaque) a keyed item should be drawn this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimatedRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub opacity: f32,
+}
+
+impl AnimatedRect {
+    /// A fully opaque rect
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height, opacity: 1.0 }
+    }
+
+    fn lerp(&self, to: &AnimatedRect, t: f32) -> AnimatedRect {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        AnimatedRect {
+            x: mix(self.x, to.x),
+            y: mix(self.y, to.y),
+            width: mix(self.width, to.width),
+            height: mix(self.height, to.height),
+            opacity: mix(self.opacity, to.opacity).clamp(0.0, 1.0),
+        }
+    }
+
+    fn same_place(&self, other: &AnimatedRect) -> bool {
+        (self.x - other.x).abs() < 0.5
+            && (self.y - other.y).abs() < 0.5
+            && (self.width - other.width).abs() < 0.5
+            && (self.height - other.height).abs() < 0.5
+    }
+}
+
+/// One key moving from `from` to `to`
+#[derive(Debug, Clone)]
+struct Track {
+    from: AnimatedRect,
+    to: AnimatedRect,
+    /// 0 → 1; None once settled
+    spring: Option<SpringAnimation>,
+}
+
+impl Track {
+    fn settled(rect: AnimatedRect) -> Self {
+        Self { from: rect, to: rect, spring: None }
+    }
+
+    fn current(&self) -> AnimatedRect {
+        match self.spring {
+            Some(ref spring) => self.from.lerp(&self.to, spring.value()),
+            None => self.to,
+        }
+    }
+}
+
+/// LayoutAnimator - Smooths layout changes of keyed items 🔀
+///
+/// # Example
+/// ```rust,ignore
+/// let mut flip = LayoutAnimator::new();
+/// // after every compute_layout:
+/// flip.capture_nodes(&engine, &row_nodes);
+/// // every frame:
+/// flip.update(dt);
+/// let rect = flip.rect("row-7");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayoutAnimator {
+    duration: Duration,
+    tracks: HashMap<String, Track>,
+    leaving: Vec<(String, Track)>,
+    /// The first capture only records positions (nothing to animate from)
+    seeded: bool,
+}
+
+impl LayoutAnimator {
+    /// Animator settling changes within 250ms
+    pub fn new() -> Self {
+        Self { duration: Duration::from_millis(250), tracks: HashMap::new(), leaving: Vec::new(), seeded: false }
+    }
+
+    /// How long moves, fades in and fades out take
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Record the latest layout of every key and start animating what changed
+    pub fn capture<K: Into<String>>(&mut self, layout: impl IntoIterator<Item = (K, AnimatedRect)>) {
+        let mut tracks = HashMap::with_capacity(self.tracks.len());
+        for (key, target) in layout {
+            let key = key.into();
+            let track = match self.tracks.remove(&key) {
+                // Already heading there: keep going
+                Some(track) if track.to.same_place(&target) => Track { to: target, ..track },
+                // Moved: start from where it's drawn right now (smooth even mid-flight)
+                Some(track) => self.track(track.current(), target),
+                None if self.seeded => self.track(AnimatedRect { opacity: 0.0, ..target }, target),
+                None => Track::settled(target),
+            };
+            tracks.insert(key, track);
+        }
+
+        // Whatever wasn't laid out this time has been removed
+        for (key, track) in std::mem::replace(&mut self.tracks, tracks) {
+            let from = track.current();
+            let leaving = self.track(from, AnimatedRect { opacity: 0.0, ..from });
+            self.leaving.push((key, leaving));
+        }
+        self.seeded = true;
+    }
+
+    /// Capture keyed layout nodes (positions relative to their parent)
+    pub fn capture_nodes(&mut self, engine: &LayoutEngine, nodes: &[(NodeId, String)]) {
+        let layout: Vec<(String, AnimatedRect)> = nodes
+            .iter()
+            .filter_map(|(node, key)| {
+                let layout = engine.get_layout(*node).ok()?;
+                let rect = AnimatedRect::new(layout.location.x, layout.location.y, layout.size.width, layout.size.height);
+                Some((key.clone(), rect))
+            })
+            .collect();
+        self.capture(layout);
+    }
+
+    fn track(&self, from: AnimatedRect, to: AnimatedRect) -> Track {
+        if from == to {
+            return Track::settled(to);
+        }
+        Track { from, to, spring: Some(SpringAnimation::new(0.0, 1.0).settle_within(self.duration)) }
+    }
+
+    /// Advance the animations (call every frame)
+    /// Returns true while anything is still moving
+    pub fn update(&mut self, dt: f32) -> bool {
+        // Long frames are split so the stiff springs stay stable
+        let steps = (dt * 240.0).ceil().max(1.0) as usize;
+        let step = |track: &mut Track| {
+            if let Some(ref mut spring) = track.spring {
+                for _ in 0..steps {
+                    spring.update(dt / steps as f32);
+                }
+                if spring.is_complete() {
+                    track.spring = None;
+                }
+            }
+        };
+        self.tracks.values_mut().for_each(step);
+        self.leaving.iter_mut().for_each(|(_, track)| step(track));
+        self.leaving.retain(|(_, track)| track.spring.is_some());
+        self.is_animating()
+    }
+
+    /// Where to draw a key this frame
+    pub fn rect(&self, key: &str) -> Option<AnimatedRect> {
+        self.tracks.get(key).map(Track::current)
+    }
+
+    /// Vertical offset between where a key is drawn and where it's laid out
+    pub fn offset_y(&self, key: &str) -> f32 {
+        self.tracks.get(key).map_or(0.0, |track| track.current().y - track.to.y)
+    }
+
+    /// Removed keys still fading out, with where to draw them
+    pub fn leaving(&self) -> Vec<(&str, AnimatedRect)> {
+        self.leaving.iter().map(|(key, track)| (key.as_str(), track.current())).collect()
+    }
+
+    /// Is anything moving or fading?
+    pub fn is_animating(&self) -> bool {
+        !self.leaving.is_empty() || self.tracks.values().any(|track| track.spring.is_some())
+    }
+
+    /// Jump everything to its final place
+    pub fn finish(&mut self) {
+        for track in self.tracks.values_mut() {
+            *track = Track::settled(track.to);
+        }
+        self.leaving.clear();
+    }
+}
+
+impl Default for LayoutAnimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(keys: &[&str]) -> Vec<(String, AnimatedRect)> {
+        keys.iter().enumerate().map(|(i, key)| (key.to_string(), AnimatedRect::new(0.0, i as f32 * 40.0, 200.0, 40.0))).collect()
+    }
+
+    #[test]
+    fn reordered_rows_glide_from_their_old_place() {
+        let mut flip = LayoutAnimator::new();
+        flip.capture(rows(&["a", "b", "c"]));
+        assert!(!flip.is_animating());
+
+        flip.capture(rows(&["c", "a", "b"]));
+        // Inverted: still drawn where they were
+        assert_eq!(flip.rect("c").unwrap().y, 80.0);
+        assert_eq!(flip.offset_y("a"), -40.0);
+
+        flip.update(0.1);
+        let y = flip.rect("c").unwrap().y;
+        assert!(y > 0.0 && y < 80.0, "mid-flight at {}", y);
+
+        while flip.update(1.0 / 60.0) {}
+        assert_eq!(flip.rect("c").unwrap().y, 0.0);
+        assert_eq!(flip.offset_y("a"), 0.0);
+    }
+
+    #[test]
+    fn inserted_rows_fade_in_and_removed_rows_fade_out() {
+        let mut flip = LayoutAnimator::new().duration(Duration::from_millis(100));
+        flip.capture(rows(&["a", "b"]));
+        flip.capture(rows(&["a", "new"]));
+
+        assert_eq!(flip.rect("new").unwrap().opacity, 0.0);
+        let leaving = flip.leaving();
+        assert_eq!(leaving.len(), 1);
+        assert_eq!((leaving[0].0, leaving[0].1.y, leaving[0].1.opacity), ("b", 40.0, 1.0));
+
+        for _ in 0..30 {
+            flip.update(1.0 / 60.0);
+        }
+        assert_eq!(flip.rect("new").unwrap().opacity, 1.0);
+        assert!(flip.leaving().is_empty());
+        assert!(!flip.is_animating());
+    }
+}
@@ -1,15 +1,51 @@
 pub mod signal;
 pub mod text;
 pub mod layout;
+pub mod layout_animation;
+pub mod hit_test;
+pub mod focus;
+pub mod input_settings;
+pub mod idle;
+pub mod theme;
 pub mod hot_reload;
 pub mod accessibility;
 pub mod animation;
 pub mod profiler;
+pub mod settings;
+pub mod store;
+pub mod state_machine;
+pub mod mutation;
+pub mod data_source;
+pub mod command;
+pub mod events;
+pub mod scheduler;
+pub mod analytics;
+pub mod context;
+pub mod feature_flags;
+pub mod quality;
 
-pub use signal::{Signal, SignalContext, Memo};
-pub use text::{TextRenderer, RasterizedGlyph, FontMetrics, FontFamily};
-pub use layout::{LayoutEngine, NodeId, Layout, Direction};
-pub use hot_reload::{HotReloadManager, AppState};
-pub use accessibility::{AccessibilityTree, AccessNode};
-pub use animation::{SpringAnimation, AnimationController, Animatable};
+pub use signal::{Signal, SignalContext, Memo, Effect, create_effect, untrack};
+pub use text::{TextRenderer, RasterizedGlyph, FontMetrics, FontFamily, ShapedGlyph, ShapedText, TextAlign, TextLayout, TextLine, TextLines};
+pub use layout::{LayoutEngine, NodeId, Layout, Direction, LayoutDirection, TextMeasure};
+pub use layout_animation::{AnimatedRect, LayoutAnimator};
+pub use theme::{mix, Theme, ThemeMode, ThemeColor, ThemeColors, ThemeRadius, ThemeSpacing, ThemeProvider};
+pub use focus::{FocusManager, Focusable, FocusId, FocusKey, FocusRing, ImeEvent, ModifierKeys};
+pub use input_settings::{ClickCounter, InputSettings};
+pub use idle::IdleDetector;
+pub use hit_test::{hit_test, node_at, EventDispatcher, EventContext, EventHandler, EventPhase, PointerEvent, PointerEventKind};
+pub use hot_reload::{HotReloadManager, AppState, FileWatcher};
+pub use accessibility::{announce, take_announcements, AccessibilityTree, AccessNode, AccessibilityAudit, Announcement, ContrastIssue, ContrastSource, Politeness, WcagLevel};
+pub use animation::{SpringAnimation, AnimationController, Animatable, AnimationClock, AnimationInfo, MotionPreference};
 pub use profiler::{Profiler, PerformanceAudit};
+pub use settings::{PersistedSignal, SettingsFormat, SettingsStore};
+pub use store::{Store, StoreSnapshot};
+pub use state_machine::StateMachine;
+pub use mutation::{Mutation, MutationDone, Rollback};
+pub use data_source::{CacheStorage, CachedSource, DataSource, DiskCache, FetchDone, MemoryCache, QueryState};
+pub use command::{Command, CommandBus, FnCommand, UndoManager};
+pub use events::EventQueue;
+pub use scheduler::{Scheduler, TaskHandle, TaskStep};
+pub use analytics::{Analytics, AnalyticsEvent, AnalyticsSink};
+pub use context::Context;
+pub use feature_flags::FeatureFlags;
+pub use quality::{QualityChange, QualityManager, QualitySettings, TextQuality};
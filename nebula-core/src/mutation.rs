@@ -0,0 +1,292 @@
+//! Mutation - Optimistic updates for async operations! ⚡
+//!
+//! Waiting for the server before showing a "like" feels slow. A `Mutation`
+//! applies the change to a Signal or Store right away, runs the async
+//! operation, and when it answers either keeps the change (optionally
+//! reconciling with the server's result) or rolls it back and reports the
+//! error.
+//!
+//! `pending` and `error` are Signals, so buttons show a spinner and turn
+//! themselves off while a request is in flight
+//! (`Button::bind_loading(mutation.pending.clone())`).
+//!
+//! The async side is callback based: `run` gets the input and a `done`
+//! callback to call with `Ok(result)` or `Err(message)` whenever the work
+//! finishes, from whatever executor or event loop the app uses.
+
+use crate::signal::Signal;
+use crate::store::Store;
+use std::fmt::Debug;
+use std::rc::Rc;
+use tracing::{info, warn};
+
+/// Callback the async operation calls when it finishes
+pub type MutationDone<R> = Box<dyn FnOnce(Result<R, String>)>;
+
+/// Undoes an optimistic change
+pub type Rollback = Box<dyn FnOnce()>;
+
+type Runner<I, R> = Rc<dyn Fn(&I, MutationDone<R>)>;
+type Optimistic<I> = Rc<dyn Fn(&I) -> Rollback>;
+type SuccessHook<I, R> = Option<Rc<dyn Fn(&I, &R)>>;
+type ErrorHook<I> = Option<Rc<dyn Fn(&I, &str)>>;
+
+/// Mutation - Apply now, confirm or roll back later ⚡
+///
+/// Clones share the same status signals.
+///
+/// # Example
+/// ```rust,ignore
+/// let likes = Signal::new(41u32);
+/// let like = Mutation::new(|post_id: &u64, done| api.like(*post_id, done))
+///     .optimistic_signal(likes.clone(), |count, _| count + 1)
+///     .on_error(|_, message| toast(message));
+///
+/// let button = Button::new("♥").bind_loading(like.pending.clone());
+/// like.mutate(7); // likes == 42 at once; back to 41 if the request fails
+/// ```
+pub struct Mutation<I, R> {
+    /// A request is in flight
+    pub pending: Signal<bool>,
+    /// Requests in flight
+    pub in_flight: Signal<usize>,
+    /// Last failure (cleared by the next `mutate`)
+    pub error: Signal<Option<String>>,
+    run: Runner<I, R>,
+    optimistic: Vec<Optimistic<I>>,
+    on_success: SuccessHook<I, R>,
+    on_error: ErrorHook<I>,
+}
+
+impl<I, R> Clone for Mutation<I, R> {
+    fn clone(&self) -> Self {
+        Self {
+            pending: self.pending.clone(),
+            in_flight: self.in_flight.clone(),
+            error: self.error.clone(),
+            run: self.run.clone(),
+            optimistic: self.optimistic.clone(),
+            on_success: self.on_success.clone(),
+            on_error: self.on_error.clone(),
+        }
+    }
+}
+
+impl<I: 'static, R: 'static> Mutation<I, R> {
+    /// Create a mutation from the async operation
+    pub fn new<F>(run: F) -> Self
+    where
+        F: Fn(&I, MutationDone<R>) + 'static,
+    {
+        Self {
+            pending: Signal::new(false),
+            in_flight: Signal::new(0),
+            error: Signal::new(None),
+            run: Rc::new(run),
+            optimistic: Vec::new(),
+            on_success: None,
+            on_error: None,
+        }
+    }
+
+    /// Custom optimistic change: apply it and return how to undo it
+    pub fn optimistic<F>(mut self, apply: F) -> Self
+    where
+        F: Fn(&I) -> Rollback + 'static,
+    {
+        self.optimistic.push(Rc::new(apply));
+        self
+    }
+
+    /// Optimistically set a signal to `apply(current, input)`
+    ///
+    /// Rolling back restores the old value only if nothing else changed the
+    /// signal in the meantime, so newer edits are never clobbered.
+    pub fn optimistic_signal<T, F>(self, signal: Signal<T>, apply: F) -> Self
+    where
+        T: Clone + PartialEq + 'static,
+        F: Fn(&T, &I) -> T + 'static,
+    {
+        self.optimistic(move |input| {
+            let previous = signal.get();
+            let optimistic = apply(&previous, input);
+            signal.set(optimistic.clone());
+            let signal = signal.clone();
+            Box::new(move || {
+                if signal.get() == optimistic {
+                    signal.set(previous);
+                }
+            })
+        })
+    }
+
+    /// Optimistically dispatch `apply(input)`; dispatch `undo(input)` to roll back
+    ///
+    /// Undoing with an action (rather than restoring a snapshot) keeps
+    /// whatever else was dispatched while the request was in flight.
+    pub fn optimistic_store<S, A, F, U>(self, store: Store<S, A>, apply: F, undo: U) -> Self
+    where
+        S: Clone + 'static,
+        A: Debug + 'static,
+        F: Fn(&I) -> A + 'static,
+        U: Fn(&I) -> A + 'static,
+    {
+        let undo = Rc::new(undo);
+        self.optimistic(move |input| {
+            store.dispatch(apply(input));
+            let (store, rollback) = (store.clone(), undo(input));
+            Box::new(move || store.dispatch(rollback))
+        })
+    }
+
+    /// Reconcile with the server's answer (swap a temporary id for the real one…)
+    pub fn on_success<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&I, &R) + 'static,
+    {
+        self.on_success = Some(Rc::new(callback));
+        self
+    }
+
+    /// Called with the error after rolling back
+    pub fn on_error<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&I, &str) + 'static,
+    {
+        self.on_error = Some(Rc::new(callback));
+        self
+    }
+
+    /// Apply the optimistic change and start the operation
+    pub fn mutate(&self, input: I) {
+        self.error.set(None);
+        self.in_flight.update(|count| count + 1);
+        self.pending.set(true);
+
+        let rollbacks: Vec<Rollback> = self.optimistic.iter().map(|apply| apply(&input)).collect();
+        let finished = self.clone();
+        let input = Rc::new(input);
+        let run_input = input.clone();
+        (self.run)(
+            &run_input,
+            Box::new(move |result| {
+                match result {
+                    Ok(value) => {
+                        if let Some(callback) = &finished.on_success {
+                            callback(&input, &value);
+                        }
+                    }
+                    Err(message) => {
+                        warn!("⚡ Mutation failed, rolling back: {}", message);
+                        // Undo in reverse order of application
+                        for rollback in rollbacks.into_iter().rev() {
+                            rollback();
+                        }
+                        if let Some(callback) = &finished.on_error {
+                            callback(&input, &message);
+                        }
+                        finished.error.set(Some(message));
+                    }
+                }
+                finished.in_flight.update(|count| count.saturating_sub(1));
+                finished.pending.set(finished.in_flight.get() > 0);
+                info!("⚡ Mutation settled ({} in flight)", finished.in_flight.get());
+            }),
+        );
+    }
+
+    /// A request is in flight
+    pub fn is_pending(&self) -> bool {
+        self.pending.get()
+    }
+
+    /// Forget the last error
+    pub fn clear_error(&self) {
+        self.error.set(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Holds `done` callbacks until the test answers them
+    type Pending<R> = Rc<RefCell<Vec<MutationDone<R>>>>;
+
+    fn deferred<I, R>() -> (Pending<R>, impl Fn(&I, MutationDone<R>)) {
+        let pending: Pending<R> = Rc::new(RefCell::new(Vec::new()));
+        let queue = pending.clone();
+        (pending, move |_: &I, done| queue.borrow_mut().push(done))
+    }
+
+    #[test]
+    fn signal_updates_apply_then_roll_back_on_failure() {
+        let likes = Signal::new(41u32);
+        let (requests, run) = deferred::<u64, u32>();
+        let confirmed = Rc::new(RefCell::new(Vec::new()));
+        let log = confirmed.clone();
+        let like = Mutation::new(run)
+            .optimistic_signal(likes.clone(), |count, _| count + 1)
+            .on_success(move |post, total| log.borrow_mut().push((*post, *total)));
+
+        like.mutate(7);
+        assert_eq!(likes.get(), 42);
+        assert!(like.is_pending());
+        like.mutate(7);
+        assert_eq!(likes.get(), 43);
+
+        let mut done = requests.borrow_mut().drain(..).collect::<Vec<_>>();
+        // The first request's value was already replaced by the second, so it stays
+        (done.remove(0))(Err("offline".to_string()));
+        assert_eq!(likes.get(), 43);
+        assert!(like.is_pending(), "second request still in flight");
+        assert_eq!(like.error.get().as_deref(), Some("offline"));
+        (done.remove(0))(Ok(43));
+        assert!(!like.is_pending());
+        assert_eq!(*confirmed.borrow(), vec![(7, 43)]);
+
+        like.mutate(8);
+        assert_eq!(like.error.get(), None);
+        assert_eq!(likes.get(), 44);
+        (requests.borrow_mut().pop().unwrap())(Err("offline".to_string()));
+        assert_eq!(likes.get(), 43);
+    }
+
+    #[test]
+    fn store_updates_roll_back_with_an_action() {
+        #[derive(Debug)]
+        enum Todo {
+            Add(String),
+            Remove(String),
+            Rename(String, String),
+        }
+        let store = Store::new(Vec::<String>::new(), |items: &Vec<String>, action: &Todo| {
+            let mut items = items.clone();
+            match action {
+                Todo::Add(name) => items.push(name.clone()),
+                Todo::Remove(name) => items.retain(|item| item != name),
+                Todo::Rename(from, to) => items.iter_mut().filter(|item| *item == from).for_each(|item| *item = to.clone()),
+            }
+            items
+        });
+        let (requests, run) = deferred::<String, String>();
+        let add = Mutation::new(run)
+            .optimistic_store(store.clone(), |name: &String| Todo::Add(format!("{} (saving)", name)), |name| Todo::Remove(format!("{} (saving)", name)))
+            .on_success({
+                let store = store.clone();
+                move |name, saved| store.dispatch(Todo::Rename(format!("{} (saving)", name), saved.clone()))
+            });
+
+        add.mutate("milk".to_string());
+        add.mutate("eggs".to_string());
+        store.dispatch(Todo::Add("bread".to_string()));
+        assert_eq!(store.state(), vec!["milk (saving)", "eggs (saving)", "bread"]);
+
+        let mut done = requests.borrow_mut().drain(..).collect::<Vec<_>>();
+        (done.remove(0))(Ok("milk".to_string()));
+        (done.remove(0))(Err("500".to_string()));
+        assert_eq!(store.state(), vec!["milk", "bread"]);
+        assert!(!add.pending.get());
+    }
+}
@@ -0,0 +1,250 @@
+//! Quality - Stay smooth on slow machines! 🐢
+//!
+//! On low-tier hardware (OpenGL 2.1, the CPU renderer) a busy screen can
+//! blow the frame budget. `QualityManager` watches frame times and, when
+//! frames keep running long, turns effects down one step at a time:
+//! shadows, then blur, then animation rate, then text antialiasing. When
+//! frames are comfortably fast again the steps come back in reverse.
+//!
+//! The effective settings live in `QualitySettings::current()` (and its
+//! signal), so widgets just check them while painting. Apps can register
+//! their own steps (drop video previews, pause a live chart…) with
+//! `QualityManager::add_step`.
+
+use crate::signal::Signal;
+use std::collections::VecDeque;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Text rasterization quality
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextQuality {
+    /// Full antialiasing
+    #[default]
+    Smooth,
+    /// Fewer coverage levels (cheaper blending on the CPU tier)
+    Coarse,
+}
+
+/// Effects widgets and renderers should honor right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QualitySettings {
+    pub shadows: bool,
+    pub blur: bool,
+    /// Advance animations every Nth frame (1 = every frame)
+    pub animation_interval: u32,
+    pub text: TextQuality,
+}
+
+impl Default for QualitySettings {
+    fn default() -> Self {
+        Self { shadows: true, blur: true, animation_interval: 1, text: TextQuality::Smooth }
+    }
+}
+
+thread_local! {
+    static SETTINGS: Signal<QualitySettings> = Signal::new(QualitySettings::default());
+}
+
+impl QualitySettings {
+    /// Settings in effect (per UI thread)
+    pub fn current() -> Self {
+        SETTINGS.with(|settings| settings.get())
+    }
+
+    /// Signal of the settings (fires when a step is applied or restored)
+    pub fn signal() -> Signal<QualitySettings> {
+        SETTINGS.with(|settings| settings.clone())
+    }
+
+    /// Change the settings in effect
+    pub fn update(change: impl FnOnce(&mut QualitySettings)) {
+        let mut settings = Self::current();
+        change(&mut settings);
+        if settings != Self::current() {
+            SETTINGS.with(|signal| signal.set(settings));
+        }
+    }
+}
+
+/// What the watchdog just did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QualityChange {
+    Degraded(String),
+    Restored(String),
+}
+
+struct QualityStep {
+    name: String,
+    /// Called with true to degrade, false to restore
+    apply: Box<dyn Fn(bool)>,
+}
+
+/// QualityManager - Frame budget watchdog 🐢
+///
+/// # Example
+/// ```rust,ignore
+/// let mut quality = QualityManager::new(Duration::from_millis(16))
+///     .for_tier(backend.tier())
+///     .add_step("video_previews", move |degraded| previews.set(!degraded));
+///
+/// // every frame:
+/// quality.record_frame(frame_time);
+/// ```
+pub struct QualityManager {
+    pub budget: Duration,
+    /// Frames averaged before deciding
+    pub sample_frames: usize,
+    /// Consecutive fast windows before a step is restored
+    pub recover_windows: u32,
+    enabled: bool,
+    steps: Vec<QualityStep>,
+    applied: usize,
+    samples: VecDeque<Duration>,
+    fast_windows: u32,
+}
+
+impl QualityManager {
+    /// Watchdog with the built-in steps (shadows, blur, animation rate, text AA)
+    pub fn new(budget: Duration) -> Self {
+        let manager = Self {
+            budget,
+            sample_frames: 30,
+            recover_windows: 4,
+            enabled: true,
+            steps: Vec::new(),
+            applied: 0,
+            samples: VecDeque::new(),
+            fast_windows: 0,
+        };
+        manager
+            .add_step("shadows", |degraded| QualitySettings::update(|settings| settings.shadows = !degraded))
+            .add_step("blur", |degraded| QualitySettings::update(|settings| settings.blur = !degraded))
+            .add_step("animation_rate", |degraded| {
+                QualitySettings::update(|settings| settings.animation_interval = if degraded { 2 } else { 1 })
+            })
+            .add_step("text_antialiasing", |degraded| {
+                QualitySettings::update(|settings| settings.text = if degraded { TextQuality::Coarse } else { TextQuality::Smooth })
+            })
+    }
+
+    /// Only watch on low-tier backends (tier 2 and below, see `Backend::tier`)
+    pub fn for_tier(mut self, tier: u8) -> Self {
+        self.enabled = tier <= 2;
+        self
+    }
+
+    /// Turn the watchdog on or off (off restores everything)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        if !enabled {
+            self.restore_all();
+        }
+        self
+    }
+
+    /// Register a step, applied after the ones before it
+    /// `apply` gets true to degrade and false to restore
+    pub fn add_step(mut self, name: impl Into<String>, apply: impl Fn(bool) + 'static) -> Self {
+        self.steps.push(QualityStep { name: name.into(), apply: Box::new(apply) });
+        self
+    }
+
+    /// Is the watchdog active?
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Names of the steps currently applied, in order
+    pub fn applied_steps(&self) -> Vec<&str> {
+        self.steps[..self.applied].iter().map(|step| step.name.as_str()).collect()
+    }
+
+    /// Feed one frame time; degrades or restores a step when a window completes
+    pub fn record_frame(&mut self, frame_time: Duration) -> Option<QualityChange> {
+        if !self.enabled {
+            return None;
+        }
+        self.samples.push_back(frame_time);
+        if self.samples.len() < self.sample_frames.max(1) {
+            return None;
+        }
+        let average = self.samples.iter().sum::<Duration>() / self.samples.len() as u32;
+        self.samples.clear();
+
+        if average > self.budget {
+            self.fast_windows = 0;
+            let step = self.steps.get(self.applied)?;
+            warn!("🐢 Frames average {:?} (budget {:?}), degrading {}", average, self.budget, step.name);
+            (step.apply)(true);
+            self.applied += 1;
+            return Some(QualityChange::Degraded(step.name.clone()));
+        }
+
+        // Only come back with plenty of headroom, or we'd flip-flop
+        if average * 2 < self.budget && self.applied > 0 {
+            self.fast_windows += 1;
+            if self.fast_windows >= self.recover_windows {
+                self.fast_windows = 0;
+                self.applied -= 1;
+                let step = &self.steps[self.applied];
+                info!("🐢 Frames back to {:?}, restoring {}", average, step.name);
+                (step.apply)(false);
+                return Some(QualityChange::Restored(step.name.clone()));
+            }
+        } else {
+            self.fast_windows = 0;
+        }
+        None
+    }
+
+    /// Undo every applied step
+    pub fn restore_all(&mut self) {
+        while self.applied > 0 {
+            self.applied -= 1;
+            (self.steps[self.applied].apply)(false);
+        }
+        self.samples.clear();
+        self.fast_windows = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn slow_frames_degrade_and_fast_frames_restore() {
+        let previews = Rc::new(Cell::new(true));
+        let flag = previews.clone();
+        let mut quality = QualityManager::new(Duration::from_millis(16))
+            .add_step("video_previews", move |degraded| flag.set(!degraded));
+        quality.sample_frames = 2;
+        quality.recover_windows = 2;
+        let slow = Duration::from_millis(40);
+        let fast = Duration::from_millis(4);
+
+        assert_eq!(quality.record_frame(slow), None);
+        assert_eq!(quality.record_frame(slow), Some(QualityChange::Degraded("shadows".to_string())));
+        for _ in 0..8 {
+            quality.record_frame(slow);
+        }
+        let settings = QualitySettings::current();
+        assert!(!settings.shadows && !settings.blur && settings.animation_interval == 2 && settings.text == TextQuality::Coarse);
+        assert!(!previews.get());
+        assert_eq!(quality.applied_steps().len(), 5);
+
+        // Two fast windows bring back the last step only
+        for _ in 0..4 {
+            quality.record_frame(fast);
+        }
+        assert!(previews.get());
+        assert_eq!(QualitySettings::current().text, TextQuality::Coarse);
+
+        quality.restore_all();
+        assert_eq!(QualitySettings::current(), QualitySettings::default());
+        assert!(!QualityManager::new(Duration::from_millis(16)).for_tier(3).is_enabled());
+    }
+}
@@ -0,0 +1,230 @@
+//! Scheduler - Split long UI-thread work across frames ⏱️
+//!
+//! Building thousands of tree rows or diffing a big tree in one go stalls
+//! the frame it runs in. A `Scheduler` holds cooperative tasks (closures
+//! that do a small slice of work and report how far along they are) and
+//! runs them after each frame until the frame's time budget is used up.
+//! Every task gets a `TaskHandle` with `progress` and `done` Signals, so a
+//! progress bar or skeleton can follow along.
+//!
+//! Tasks run on the UI thread between frames; they're not threads. A task
+//! that takes longer than the budget in one step still overruns it, so
+//! keep steps small. Each frame runs at least one step so work always
+//! moves forward, even when rendering ate the whole budget.
+
+use crate::signal::Signal;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
+
+/// What a task step reports back
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskStep {
+    /// More to do; carries progress from 0.0 to 1.0
+    Continue(f32),
+    /// Finished
+    Done,
+}
+
+/// Handle to a scheduled task
+#[derive(Debug, Clone)]
+pub struct TaskHandle {
+    pub name: String,
+    /// 0.0 to 1.0, updated after every step
+    pub progress: Signal<f32>,
+    /// Flips to true when the task finishes (not when it's cancelled)
+    pub done: Signal<bool>,
+    cancelled: Rc<Cell<bool>>,
+}
+
+impl TaskHandle {
+    /// Drop the task before its next step
+    pub fn cancel(&self) {
+        self.cancelled.set(true);
+    }
+
+    /// Was the task cancelled?
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.get()
+    }
+
+    /// Has the task finished?
+    pub fn is_done(&self) -> bool {
+        self.done.get()
+    }
+}
+
+struct Task {
+    step: Box<dyn FnMut() -> TaskStep>,
+    handle: TaskHandle,
+}
+
+/// Scheduler - Cooperative tasks with a per-frame time budget ⏱️
+///
+/// Clones share the same task queue, so components can spawn work into
+/// the scheduler the window runs.
+///
+/// # Example
+/// ```rust,ignore
+/// let scheduler = Scheduler::new(Duration::from_millis(4));
+/// let nodes = Signal::new(Vec::new());
+/// let target = nodes.clone();
+/// let load = scheduler.spawn_each("load project", files, move |file| {
+///     target.update_in_place(|nodes| nodes.push(TreeNode::new(&file.path, &file.name)));
+/// });
+/// // progress_bar follows load.progress; when load.done flips, tree.nodes(nodes.get())
+///
+/// // every frame, after rendering (NebulaWindow::with_scheduler does this):
+/// scheduler.run_frame();
+/// ```
+#[derive(Clone)]
+pub struct Scheduler {
+    budget: Rc<Cell<Duration>>,
+    tasks: Rc<RefCell<VecDeque<Task>>>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(4))
+    }
+}
+
+impl std::fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scheduler").field("budget", &self.budget()).field("pending", &self.pending()).finish()
+    }
+}
+
+impl Scheduler {
+    /// Scheduler that spends at most `budget` per frame on tasks
+    pub fn new(budget: Duration) -> Self {
+        Self { budget: Rc::new(Cell::new(budget)), tasks: Rc::new(RefCell::new(VecDeque::new())) }
+    }
+
+    /// Time spent on tasks per frame
+    pub fn budget(&self) -> Duration {
+        self.budget.get()
+    }
+
+    /// Change the per-frame budget
+    pub fn set_budget(&self, budget: Duration) {
+        self.budget.set(budget);
+    }
+
+    /// Queue a task; `step` is called repeatedly until it returns `Done`
+    pub fn spawn<F>(&self, name: impl Into<String>, step: F) -> TaskHandle
+    where
+        F: FnMut() -> TaskStep + 'static,
+    {
+        let handle = TaskHandle {
+            name: name.into(),
+            progress: Signal::new(0.0),
+            done: Signal::new(false),
+            cancelled: Rc::new(Cell::new(false)),
+        };
+        info!("⏱️ Scheduled task: {}", handle.name);
+        self.tasks.borrow_mut().push_back(Task { step: Box::new(step), handle: handle.clone() });
+        handle
+    }
+
+    /// Queue a task that calls `f` once per item, in order
+    pub fn spawn_each<T, F>(&self, name: impl Into<String>, items: Vec<T>, mut f: F) -> TaskHandle
+    where
+        T: 'static,
+        F: FnMut(T) + 'static,
+    {
+        let total = items.len();
+        let mut items = items.into_iter();
+        let mut processed = 0;
+        self.spawn(name, move || match items.next() {
+            Some(item) => {
+                f(item);
+                processed += 1;
+                if processed == total {
+                    TaskStep::Done
+                } else {
+                    TaskStep::Continue(processed as f32 / total as f32)
+                }
+            }
+            None => TaskStep::Done,
+        })
+    }
+
+    /// Number of unfinished tasks
+    pub fn pending(&self) -> usize {
+        self.tasks.borrow().len()
+    }
+
+    /// Nothing left to do?
+    pub fn is_idle(&self) -> bool {
+        self.tasks.borrow().is_empty()
+    }
+
+    /// Run tasks within this frame's budget; returns the number of steps run
+    pub fn run_frame(&self) -> usize {
+        self.run_for(self.budget())
+    }
+
+    /// Run tasks round-robin until `budget` is spent or the queue is empty
+    ///
+    /// At least one step runs whenever a task is waiting.
+    pub fn run_for(&self, budget: Duration) -> usize {
+        let started = Instant::now();
+        let mut steps = 0;
+        loop {
+            if steps > 0 && started.elapsed() >= budget {
+                break;
+            }
+            // Take the task out so its step can spawn more tasks
+            let Some(mut task) = self.tasks.borrow_mut().pop_front() else {
+                break;
+            };
+            if task.handle.is_cancelled() {
+                debug!("⏱️ Cancelled task: {}", task.handle.name);
+                continue;
+            }
+            steps += 1;
+            match (task.step)() {
+                TaskStep::Continue(progress) => {
+                    task.handle.progress.set(progress.clamp(0.0, 1.0));
+                    self.tasks.borrow_mut().push_back(task);
+                }
+                TaskStep::Done => {
+                    info!("⏱️ Finished task: {}", task.handle.name);
+                    task.handle.progress.set(1.0);
+                    task.handle.done.set(true);
+                }
+            }
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tasks_share_frames_and_report_progress() {
+        let scheduler = Scheduler::new(Duration::ZERO);
+        let rows = Signal::new(Vec::new());
+        let target = rows.clone();
+        let load = scheduler.spawn_each("rows", (0..4).collect(), move |i| target.update_in_place(|rows| rows.push(i)));
+        let other = scheduler.spawn("other", || TaskStep::Continue(0.5));
+
+        // A zero budget still runs one step per frame
+        assert_eq!(scheduler.run_frame(), 1);
+        assert_eq!(load.progress.get(), 0.25);
+        assert_eq!(scheduler.run_frame(), 1);
+        assert_eq!(other.progress.get(), 0.5); // Round-robin
+
+        other.cancel();
+        assert_eq!(scheduler.run_for(Duration::from_secs(1)), 3);
+        assert!(load.is_done());
+        assert!(!other.is_done());
+        assert_eq!(rows.get(), vec![0, 1, 2, 3]);
+        assert!(scheduler.is_idle());
+    }
+}
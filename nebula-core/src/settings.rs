@@ -0,0 +1,366 @@
+//! Settings - Signals that survive restarts! 💾
+//!
+//! `SettingsStore` keeps one JSON or TOML file of app preferences (theme,
+//! window size, last opened path…). `PersistedSignal` is a `Signal` bound
+//! to one key of that file: it starts from the saved value and every
+//! change is written back.
+//!
+//! Writes are debounced so dragging a slider doesn't hit the disk on
+//! every frame: call `update()` once per frame (it saves once the file has
+//! been quiet for the debounce time) and `flush()` before exiting. The
+//! store also saves anything pending when the last handle is dropped.
+
+use crate::signal::Signal;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// File format, picked from the extension (`.toml`, anything else is JSON)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsFormat {
+    Json,
+    Toml,
+}
+
+impl SettingsFormat {
+    /// Format for a path
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()).as_deref() {
+            Some("toml") => SettingsFormat::Toml,
+            _ => SettingsFormat::Json,
+        }
+    }
+
+    fn parse(&self, text: &str) -> Result<Map<String, Value>, String> {
+        match self {
+            SettingsFormat::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+            SettingsFormat::Toml => toml::from_str(text).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn format(&self, values: &Map<String, Value>) -> Result<String, String> {
+        match self {
+            SettingsFormat::Json => serde_json::to_string_pretty(values).map_err(|e| e.to_string()),
+            // TOML has no null, so unset options are left out (and read back as missing)
+            SettingsFormat::Toml => {
+                let values: Map<String, Value> = values.iter().filter_map(|(key, value)| Some((key.clone(), without_nulls(value)?))).collect();
+                toml::to_string_pretty(&values).map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+/// A value with every null (and null-valued key) dropped; None if it is null
+fn without_nulls(value: &Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::Array(items) => Some(Value::Array(items.iter().filter_map(without_nulls).collect())),
+        Value::Object(entries) => Some(Value::Object(
+            entries.iter().filter_map(|(key, value)| Some((key.clone(), without_nulls(value)?))).collect(),
+        )),
+        other => Some(other.clone()),
+    }
+}
+
+struct StoreInner {
+    path: PathBuf,
+    format: SettingsFormat,
+    values: Map<String, Value>,
+    debounce: Duration,
+    /// Latest unsaved change (None = nothing to save)
+    last_change: Option<Instant>,
+}
+
+impl StoreInner {
+    fn save(&mut self) -> Result<(), String> {
+        if self.last_change.is_none() {
+            return Ok(());
+        }
+        let text = self.format.format(&self.values)?;
+        if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        // Write then rename, so a crash never leaves half a file
+        let temp = self.path.with_extension("tmp");
+        std::fs::write(&temp, text).map_err(|e| format!("Failed to write {}: {}", temp.display(), e))?;
+        std::fs::rename(&temp, &self.path).map_err(|e| format!("Failed to replace {}: {}", self.path.display(), e))?;
+        self.last_change = None;
+        info!("💾 Saved settings to {}", self.path.display());
+        Ok(())
+    }
+}
+
+impl Drop for StoreInner {
+    fn drop(&mut self) {
+        if let Err(e) = self.save() {
+            warn!("⚠️ Settings not saved: {}", e);
+        }
+    }
+}
+
+/// SettingsStore - One preferences file, shared by every PersistedSignal 💾
+///
+/// Clones share the same file and values.
+///
+/// # Example
+/// ```rust,ignore
+/// let settings = SettingsStore::open(config_dir.join("settings.toml"));
+/// let theme = settings.signal("theme", "dark".to_string());
+/// let window_size = settings.signal("window_size", (1280u32, 800u32));
+///
+/// theme.set("light".to_string()); // saved after the debounce
+/// settings.update()?;             // once per frame
+/// ```
+#[derive(Clone)]
+pub struct SettingsStore {
+    inner: Rc<RefCell<StoreInner>>,
+}
+
+impl SettingsStore {
+    /// Open a settings file; a missing or unreadable file starts empty
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let format = SettingsFormat::from_path(&path);
+        let values = match std::fs::read_to_string(&path) {
+            Ok(text) => format.parse(&text).unwrap_or_else(|e| {
+                warn!("⚠️ Ignoring unreadable settings {}: {}", path.display(), e);
+                Map::new()
+            }),
+            Err(_) => Map::new(),
+        };
+        Self {
+            inner: Rc::new(RefCell::new(StoreInner {
+                path,
+                format,
+                values,
+                debounce: Duration::from_millis(500),
+                last_change: None,
+            })),
+        }
+    }
+
+    /// How long changes wait before being written (default 500ms)
+    pub fn debounce(self, debounce: Duration) -> Self {
+        self.inner.borrow_mut().debounce = debounce;
+        self
+    }
+
+    /// Settings file path
+    pub fn path(&self) -> PathBuf {
+        self.inner.borrow().path.clone()
+    }
+
+    /// Saved value for a key (None if missing or of another type)
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.inner.borrow().values.get(key).cloned()?;
+        serde_json::from_value(value).ok()
+    }
+
+    /// Change a key (written after the debounce)
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<(), String> {
+        let value = serde_json::to_value(value).map_err(|e| format!("Can't save setting '{}': {}", key, e))?;
+        let mut inner = self.inner.borrow_mut();
+        if inner.values.get(key) != Some(&value) {
+            inner.values.insert(key.to_string(), value);
+            inner.last_change = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    /// Forget a key
+    pub fn remove(&self, key: &str) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.values.remove(key).is_some() {
+            inner.last_change = Some(Instant::now());
+        }
+    }
+
+    /// A signal for `key`, starting from the saved value or `default`
+    pub fn signal<T>(&self, key: &str, default: T) -> PersistedSignal<T>
+    where
+        T: Clone + Serialize + DeserializeOwned + 'static,
+    {
+        PersistedSignal::with_store(self.clone(), key, default)
+    }
+
+    /// Unsaved changes?
+    pub fn is_dirty(&self) -> bool {
+        self.inner.borrow().last_change.is_some()
+    }
+
+    /// Save once nothing has changed for the debounce time; true if written
+    pub fn update(&self) -> Result<bool, String> {
+        let mut inner = self.inner.borrow_mut();
+        match inner.last_change {
+            Some(changed) if changed.elapsed() >= inner.debounce => inner.save().map(|_| true),
+            _ => Ok(false),
+        }
+    }
+
+    /// Save now
+    pub fn flush(&self) -> Result<(), String> {
+        self.inner.borrow_mut().save()
+    }
+}
+
+/// PersistedSignal - A Signal whose value is kept in a settings file 💾
+///
+/// Reads and writes work like any other signal (`signal()` hands out the
+/// underlying `Signal` for bindings); every change is queued for saving.
+///
+/// # Example
+/// ```rust,ignore
+/// let last_path = PersistedSignal::new("settings.json", "last_opened", String::new());
+/// last_path.set("/home/me/notes.md".to_string());
+/// last_path.store().flush()?;
+/// ```
+#[derive(Clone)]
+pub struct PersistedSignal<T: Clone> {
+    signal: Signal<T>,
+    key: String,
+    store: SettingsStore,
+}
+
+impl<T> PersistedSignal<T>
+where
+    T: Clone + Serialize + DeserializeOwned + 'static,
+{
+    /// Bind `key` of the file at `path` (opens its own store)
+    pub fn new(path: impl Into<PathBuf>, key: &str, default: T) -> Self {
+        Self::with_store(SettingsStore::open(path), key, default)
+    }
+
+    /// Bind `key` of a shared store
+    pub fn with_store(store: SettingsStore, key: &str, default: T) -> Self {
+        let signal = Signal::new(store.get(key).unwrap_or(default));
+        let (writer, name) = (store.clone(), key.to_string());
+        signal.subscribe(move |value| {
+            if let Err(e) = writer.set(&name, value) {
+                warn!("⚠️ {}", e);
+            }
+        });
+        Self { signal, key: key.to_string(), store }
+    }
+
+    /// The underlying signal
+    pub fn signal(&self) -> &Signal<T> {
+        &self.signal
+    }
+
+    /// Current value
+    pub fn get(&self) -> T {
+        self.signal.get()
+    }
+
+    /// Change the value (saved after the debounce)
+    pub fn set(&self, value: T) {
+        self.signal.set(value);
+    }
+
+    /// Change the value with a function
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&T) -> T,
+    {
+        self.signal.update(f);
+    }
+
+    /// Settings key
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The store it saves to
+    pub fn store(&self) -> &SettingsStore {
+        &self.store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nebula-settings-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join(name)
+    }
+
+    #[test]
+    fn persisted_signals_round_trip_json_and_toml() {
+        for name in ["settings.json", "settings.toml"] {
+            let path = temp_path(name);
+            {
+                let store = SettingsStore::open(&path);
+                let theme = store.signal("theme", "dark".to_string());
+                let size = store.signal("window_size", (1280u32, 800u32));
+                let last_opened = store.signal("last_opened", Some("/tmp/notes.md".to_string()));
+                assert!(!store.is_dirty());
+
+                last_opened.set(None);
+                assert_eq!(store.flush(), Ok(()));
+                theme.set("light".to_string());
+                size.update(|(w, h)| (w / 2, *h));
+                assert!(store.is_dirty());
+                // Dropping the last handle saves what's pending
+            }
+            let text = std::fs::read_to_string(&path).unwrap();
+            assert!(text.contains("light"), "{}", text);
+
+            let store = SettingsStore::open(&path);
+            assert_eq!(store.signal("theme", String::new()).get(), "light");
+            assert_eq!(store.signal("window_size", (0u32, 0u32)).get(), (640, 800));
+            assert_eq!(store.signal::<Option<String>>("last_opened", None).get(), None);
+            // Wrong type or missing key falls back to the default
+            assert_eq!(store.signal("theme", 7u8).get(), 7);
+            assert!(PersistedSignal::new(&path, "missing", true).get());
+            let _ = std::fs::remove_dir_all(path.parent().unwrap());
+        }
+    }
+
+    #[test]
+    fn writes_are_debounced() {
+        let path = temp_path("debounced.json");
+        let store = SettingsStore::open(&path).debounce(Duration::from_millis(30));
+        let volume = store.signal("volume", 0.5f64);
+        volume.set(0.8);
+        assert_eq!(store.update(), Ok(false));
+        assert!(!path.exists());
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(store.update(), Ok(true));
+        assert!(!store.is_dirty());
+
+        // Setting the same value again isn't a change
+        volume.set(0.8);
+        assert!(!store.is_dirty());
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(SettingsStore::open(&path).get::<f64>("volume"), None);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn continuous_changes_save_once_quiet() {
+        let path = temp_path("dragged.json");
+        let store = SettingsStore::open(&path).debounce(Duration::from_millis(200));
+        let volume = store.signal("volume", 0.0f64);
+
+        // A slider drag: longer than the debounce overall, never quiet for it
+        for step in 1..=4 {
+            volume.set(step as f64 / 10.0);
+            std::thread::sleep(Duration::from_millis(80));
+            assert_eq!(store.update(), Ok(false));
+        }
+        assert!(!path.exists());
+
+        std::thread::sleep(Duration::from_millis(220));
+        assert_eq!(store.update(), Ok(true));
+        assert_eq!(SettingsStore::open(&path).get::<f64>("volume"), Some(0.4));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
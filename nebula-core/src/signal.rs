@@ -1,5 +1,5 @@
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::cell::{Cell, Ref, RefCell};
+use std::rc::{Rc, Weak};
 use std::collections::HashSet;
 use tracing::info;
 
@@ -10,6 +10,7 @@ use tracing::info;
 /// - Batched updates (10k updates < 0.03ms!)
 /// - Dependency tracking
 /// - Memoization
+/// - Derived signals (`Signal::derive`)
 #[derive(Clone)]
 pub struct Signal<T: Clone> {
     inner: Rc<RefCell<SignalInner<T>>>,
@@ -18,7 +19,11 @@ pub struct Signal<T: Clone> {
 struct SignalInner<T: Clone> {
     value: T,
     subscribers: Vec<Box<dyn Fn(&T)>>,
+    /// Derived signals and effects that read this one (dropped ones are pruned)
+    observers: Vec<Weak<Observer>>,
     id: usize,
+    /// Keeps a derived signal's computation alive
+    observer: Option<Rc<Observer>>,
 }
 
 // Global signal ID counter
@@ -35,6 +40,114 @@ fn next_signal_id() -> usize {
     })
 }
 
+/// A derived computation or effect, re-run when any signal it read changes
+#[derive(Default)]
+struct Observer {
+    rerun: RefCell<Option<Box<dyn Fn()>>>,
+    /// Signals read on the latest run (registrations elsewhere are stale)
+    sources: RefCell<HashSet<usize>>,
+    /// Guards against an effect re-triggering itself
+    running: Cell<bool>,
+}
+
+// Observers currently computing (innermost last)
+thread_local! {
+    static OBSERVERS: RefCell<Vec<Rc<Observer>>> = const { RefCell::new(Vec::new()) };
+}
+
+// Effects stay alive until disposed, even when their handle is dropped
+thread_local! {
+    static EFFECTS: RefCell<Vec<Rc<Observer>>> = const { RefCell::new(Vec::new()) };
+}
+
+impl Observer {
+    /// Run `f`, subscribing to every signal it reads (and only those)
+    fn track<R>(self: &Rc<Self>, f: impl FnOnce() -> R) -> R {
+        self.sources.borrow_mut().clear();
+        OBSERVERS.with(|observers| observers.borrow_mut().push(self.clone()));
+        let result = f();
+        OBSERVERS.with(|observers| observers.borrow_mut().pop());
+        result
+    }
+
+    fn rerun(&self) {
+        if self.running.replace(true) {
+            return;
+        }
+        if let Some(rerun) = self.rerun.borrow().as_ref() {
+            rerun();
+        }
+        self.running.set(false);
+    }
+}
+
+/// Handle to an effect created with `create_effect`
+#[derive(Clone)]
+pub struct Effect {
+    observer: Rc<Observer>,
+}
+
+impl Effect {
+    /// Run the effect now (dependencies are re-tracked)
+    pub fn run(&self) {
+        self.observer.rerun();
+    }
+
+    /// Stop reacting to signal changes
+    pub fn dispose(&self) {
+        *self.observer.rerun.borrow_mut() = None;
+        EFFECTS.with(|effects| effects.borrow_mut().retain(|effect| !Rc::ptr_eq(effect, &self.observer)));
+    }
+
+    /// Still reacting?
+    pub fn is_active(&self) -> bool {
+        self.observer.rerun.borrow().is_some()
+    }
+
+    /// Number of signals the effect depends on
+    pub fn dependency_count(&self) -> usize {
+        self.observer.sources.borrow().len()
+    }
+}
+
+/// Run a side effect now and again whenever a signal it reads changes ✨
+///
+/// Like `Signal::derive` but for work with no value: saving, logging,
+/// syncing to native widgets. The effect lives until `dispose` is called,
+/// so the returned handle can be ignored. Writing a signal the effect
+/// itself reads does not re-trigger it.
+///
+/// # Example
+/// ```rust,ignore
+/// let selected = table.selected_row.clone();
+/// create_effect(move || {
+///     if let Some(row) = selected.get() {
+///         std::fs::write("last_row.txt", row.to_string()).ok();
+///     }
+/// });
+/// ```
+pub fn create_effect<F>(effect: F) -> Effect
+where
+    F: Fn() + 'static,
+{
+    let observer = Rc::new(Observer::default());
+    let weak_observer = Rc::downgrade(&observer);
+    *observer.rerun.borrow_mut() = Some(Box::new(move || {
+        if let Some(observer) = weak_observer.upgrade() {
+            observer.track(&effect);
+        }
+    }));
+    EFFECTS.with(|effects| effects.borrow_mut().push(observer.clone()));
+    observer.rerun();
+    Effect { observer }
+}
+
+/// Read signals without making them dependencies of the surrounding
+/// derived signal or effect
+pub fn untrack<R>(f: impl FnOnce() -> R) -> R {
+    Rc::new(Observer::default()).track(f)
+}
+
 impl<T: Clone> Signal<T> {
     /// Create a new signal with an initial value
     pub fn new(initial_value: T) -> Self {
@@ -42,50 +155,159 @@ impl<T: Clone> Signal<T> {
             inner: Rc::new(RefCell::new(SignalInner {
                 value: initial_value,
                 subscribers: Vec::new(),
+                observers: Vec::new(),
                 id: next_signal_id(),
+                observer: None,
             })),
         }
     }
 
+    /// Create a signal computed from other signals 🧮
+    ///
+    /// Every signal read inside `compute` becomes a dependency: when any of
+    /// them changes, the value is recomputed and subscribers are notified.
+    /// Dependencies are re-collected on every run, so a signal read only on
+    /// a branch no longer taken stops triggering recomputation. Setting a derived signal by hand lasts until the next
+    /// upstream change.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let rows = Signal::new(vec!["apple", "banana", "cherry"]);
+    /// let filter = Signal::new(String::new());
+    /// let visible = Signal::derive({
+    ///     let (rows, filter) = (rows.clone(), filter.clone());
+    ///     move || rows.get().into_iter().filter(|row| row.contains(&filter.get())).collect::<Vec<_>>()
+    /// });
+    /// filter.set("an".into()); // visible == ["banana"]
+    /// ```
+    pub fn derive<F>(compute: F) -> Self
+    where
+        F: Fn() -> T + 'static,
+        T: 'static,
+    {
+        let observer = Rc::new(Observer::default());
+        let value = observer.track(&compute);
+        let signal = Self::new(value);
+
+        // Weak links only: the derived signal owns its observer, upstream
+        // signals just poke it while it's alive
+        let weak_signal = Rc::downgrade(&signal.inner);
+        let weak_observer = Rc::downgrade(&observer);
+        *observer.rerun.borrow_mut() = Some(Box::new(move || {
+            let (Some(inner), Some(observer)) = (weak_signal.upgrade(), weak_observer.upgrade()) else {
+                return;
+            };
+            let value = observer.track(&compute);
+            Signal { inner }.set(value);
+        }));
+        signal.inner.borrow_mut().observer = Some(observer);
+        signal
+    }
+
+    /// Subscribe the derived signal being computed (if any) to this one
+    fn track_observer(&self) {
+        let Some(observer) = OBSERVERS.with(|observers| observers.borrow().last().cloned()) else {
+            return;
+        };
+        let id = self.inner.borrow().id;
+        if !observer.sources.borrow_mut().insert(id) {
+            return;
+        }
+        // Busy notifying: try again on the next read
+        let Ok(mut inner) = self.inner.try_borrow_mut() else {
+            observer.sources.borrow_mut().remove(&id);
+            return;
+        };
+        // Long-lived signals (the locale, a theme) see many short-lived
+        // derived signals come and go, so forget the dead ones first
+        inner.observers.retain(|observer| observer.strong_count() > 0);
+        // Still registered from an earlier run
+        if !inner.observers.iter().any(|known| known.as_ptr() == Rc::as_ptr(&observer)) {
+            inner.observers.push(Rc::downgrade(&observer));
+        }
+    }
+
+    /// Is `observer` alive and did its latest run read this signal?
+    fn observes(id: usize, observer: &Weak<Observer>) -> bool {
+        observer.upgrade().is_some_and(|observer| observer.sources.borrow().contains(&id))
+    }
+
     /// Get the current value of the signal
     pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    /// Read the value without cloning it (tracked like `get`)
+    ///
+    /// ```rust,ignore
+    /// let count = table.selected_rows.with(|rows| rows.len());
+    /// ```
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
         // Track this signal as a dependency if we're in a tracking context
         SignalContext::track_dependency(self.inner.borrow().id);
-        self.inner.borrow().value.clone()
+        self.track_observer();
+        f(&self.inner.borrow().value)
+    }
+
+    /// Borrow the value (tracked like `get`)
+    ///
+    /// Drop the guard before setting the signal: a write while it's held panics.
+    pub fn get_ref(&self) -> Ref<'_, T> {
+        SignalContext::track_dependency(self.inner.borrow().id);
+        self.track_observer();
+        Ref::map(self.inner.borrow(), |inner| &inner.value)
     }
 
     /// Set a new value and notify all subscribers
     /// If we're in a batched context, notifications are deferred
     pub fn set(&self, new_value: T) {
-        let signal_id = self.inner.borrow().id;
-        
-        // Update the value
-        {
-            let mut inner = self.inner.borrow_mut();
-            inner.value = new_value.clone();
-        }
-        
-        // Check if we're in a batched context
+        self.inner.borrow_mut().value = new_value;
+        self.changed();
+    }
+
+    /// Change the value in place (no clone) and notify subscribers
+    ///
+    /// ```rust,ignore
+    /// table.selected_rows.update_in_place(|rows| rows.push(id));
+    /// ```
+    pub fn update_in_place<F>(&self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        f(&mut self.inner.borrow_mut().value);
+        self.changed();
+    }
+
+    /// Notify now, or later when batching
+    fn changed(&self) {
         if SignalContext::is_batching() {
-            SignalContext::mark_dirty(signal_id);
+            SignalContext::mark_dirty(self.inner.borrow().id);
         } else {
-            // Notify immediately
-            self.notify(&new_value);
+            self.notify();
         }
     }
 
-    /// Notify all subscribers (internal)
-    fn notify(&self, value: &T) {
-        let inner = self.inner.borrow();
-        for subscriber in &inner.subscribers {
-            subscriber(value);
+    /// Notify all subscribers, then re-run live observers (internal)
+    fn notify(&self) {
+        let observers: Vec<Rc<Observer>> = {
+            let inner = self.inner.borrow();
+            for subscriber in &inner.subscribers {
+                subscriber(&inner.value);
+            }
+            inner.observers.iter().filter(|observer| Self::observes(inner.id, observer)).filter_map(Weak::upgrade).collect()
+        };
+        if let Ok(mut inner) = self.inner.try_borrow_mut() {
+            let id = inner.id;
+            inner.observers.retain(|observer| Self::observes(id, observer));
+        }
+        for observer in observers {
+            observer.rerun();
         }
     }
 
     /// Flush notifications for this signal (called by SignalContext)
     pub(crate) fn flush(&self) {
-        let value = self.inner.borrow().value.clone();
-        self.notify(&value);
+        self.notify();
     }
 
     /// Update the value using a function and notify subscribers
@@ -111,9 +333,10 @@ impl<T: Clone> Signal<T> {
         inner.subscribers.len() - 1
     }
 
-    /// Get the number of subscribers
+    /// Get the number of subscribers (including live derived signals and effects)
     pub fn subscriber_count(&self) -> usize {
-        self.inner.borrow().subscribers.len()
+        let inner = self.inner.borrow();
+        inner.subscribers.len() + inner.observers.iter().filter(|observer| Self::observes(inner.id, observer)).count()
     }
 
     /// Get the signal ID
@@ -334,6 +557,24 @@ mod tests {
     use super::*;
     use std::time::Instant;
 
+    #[test]
+    fn borrowed_reads_and_in_place_updates() {
+        let rows = Signal::new(vec![1, 2, 3]);
+        let notified = Rc::new(Cell::new(0));
+        let count = notified.clone();
+        rows.subscribe(move |rows: &Vec<i32>| count.set(rows.len()));
+        let total = Signal::derive({
+            let rows = rows.clone();
+            move || rows.with(|rows| rows.iter().sum::<i32>())
+        });
+
+        assert_eq!(rows.with(Vec::len), 3);
+        assert_eq!(rows.get_ref()[1], 2);
+        rows.update_in_place(|rows| rows.push(4));
+        assert_eq!(notified.get(), 4);
+        assert_eq!(total.get(), 10);
+    }
+
     #[test]
     fn signal_creation_and_get() {
         let signal = Signal::new(42);
@@ -549,6 +790,132 @@ mod tests {
         // this would be < 0.03ms
     }
 
+    #[test]
+    fn derived_signal_tracks_every_dependency() {
+        let rows = Signal::new(vec!["apple", "banana", "cherry"]);
+        let filter = Signal::new(String::new());
+        let visible = Signal::derive({
+            let (rows, filter) = (rows.clone(), filter.clone());
+            move || rows.get().into_iter().filter(|row| row.contains(filter.get().as_str())).collect::<Vec<_>>()
+        });
+        let notified = Rc::new(RefCell::new(0));
+        let count = notified.clone();
+        visible.subscribe(move |_| *count.borrow_mut() += 1);
+        assert_eq!(visible.get().len(), 3);
+
+        filter.set("an".to_string());
+        assert_eq!(visible.get(), vec!["banana"]);
+        rows.update(|rows| rows.iter().copied().chain(["mango"]).collect());
+        assert_eq!(visible.get(), vec!["banana", "mango"]);
+        assert_eq!(*notified.borrow(), 2);
+
+        // Derived signals chain
+        let count = Signal::derive({
+            let visible = visible.clone();
+            move || visible.get().len()
+        });
+        filter.set("e".to_string());
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn derived_signal_follows_branches_and_can_be_dropped() {
+        let use_a = Signal::new(true);
+        let a = Signal::new(1);
+        let b = Signal::new(10);
+        let picked = Signal::derive({
+            let (use_a, a, b) = (use_a.clone(), a.clone(), b.clone());
+            move || if use_a.get() { a.get() } else { b.get() }
+        });
+        use_a.set(false);
+        assert_eq!(picked.get(), 10);
+        b.set(20);
+        assert_eq!(picked.get(), 20);
+
+        drop(picked);
+        b.set(30); // No derived signal left to update
+    }
+
+    #[test]
+    fn untaken_branch_stops_triggering_recomputation() {
+        let use_a = Signal::new(true);
+        let a = Signal::new(1);
+        let b = Signal::new(10);
+        let runs = Rc::new(Cell::new(0));
+        let picked = Signal::derive({
+            let (use_a, a, b, runs) = (use_a.clone(), a.clone(), b.clone(), runs.clone());
+            move || {
+                runs.set(runs.get() + 1);
+                if use_a.get() { a.get() } else { b.get() }
+            }
+        });
+        use_a.set(false);
+        assert_eq!(runs.get(), 2);
+        assert_eq!(a.subscriber_count(), 0);
+
+        a.set(2); // Only read on the branch no longer taken
+        assert_eq!(runs.get(), 2);
+        assert!(a.inner.borrow().observers.is_empty());
+
+        use_a.set(true);
+        a.set(3);
+        assert_eq!(picked.get(), 3);
+        assert_eq!(runs.get(), 4);
+        assert_eq!(a.inner.borrow().observers.len(), 1);
+    }
+
+    #[test]
+    fn dropped_derived_signals_are_pruned() {
+        let locale = Signal::new("en".to_string());
+        for _ in 0..100 {
+            let label = Signal::derive({
+                let locale = locale.clone();
+                move || format!("{}: Save", locale.get())
+            });
+            assert_eq!(label.get(), "en: Save");
+        }
+        // Only dead links left, dropped as soon as the next one subscribes
+        assert_eq!(locale.subscriber_count(), 0);
+        let kept = Signal::derive({
+            let locale = locale.clone();
+            move || locale.get().len()
+        });
+        assert_eq!(locale.inner.borrow().observers.len(), 1);
+
+        locale.set("fr-FR".to_string());
+        assert_eq!(kept.get(), 5);
+        drop(kept);
+        locale.set("de".to_string());
+        assert!(locale.inner.borrow().observers.is_empty());
+    }
+
+    #[test]
+    fn effect_reruns_until_disposed() {
+        let selected = Signal::new(0);
+        let other = Signal::new(0);
+        let saved = Rc::new(RefCell::new(Vec::new()));
+        let log = saved.clone();
+        let effect = create_effect({
+            let (selected, other) = (selected.clone(), other.clone());
+            move || {
+                let peeked = untrack(|| other.get());
+                log.borrow_mut().push(selected.get() + peeked);
+            }
+        });
+        assert_eq!(*saved.borrow(), vec![0]);
+        assert_eq!(effect.dependency_count(), 1);
+
+        selected.set(3);
+        other.set(100); // Untracked: no re-run
+        selected.set(4);
+        assert_eq!(*saved.borrow(), vec![0, 3, 104]);
+
+        effect.dispose();
+        selected.set(5);
+        assert!(!effect.is_active());
+        assert_eq!(saved.borrow().len(), 3);
+    }
+
     #[test]
     fn signal_has_unique_id() {
         let signal1 = Signal::new(1);
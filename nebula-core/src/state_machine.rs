@@ -0,0 +1,267 @@
+//! State Machine - Multi-step flows without nested ifs! 🚦
+//!
+//! A `StateMachine` lists its states' transitions up front: "on Next in
+//! Profile, go to Business if the user picked a company account, otherwise
+//! to Confirm". Guards decide between branches (the first passing
+//! transition wins, in declaration order), on-enter / on-exit actions run
+//! side effects, and the current state is a `Signal` so Steppers,
+//! navigation bars and views follow it automatically.
+//!
+//! The machine also remembers the path taken, so `back()` undoes the last
+//! step even when the flow branched.
+
+use crate::signal::Signal;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use tracing::{info, warn};
+
+type Guard<S, E> = Box<dyn Fn(&S, &E) -> bool>;
+type Action<S> = Box<dyn Fn(&S)>;
+
+struct Transition<S, E> {
+    /// None = from any state
+    from: Option<S>,
+    event: E,
+    to: S,
+    guard: Option<Guard<S, E>>,
+}
+
+/// StateMachine - Finite state machine with guarded transitions 🚦
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Signup { Account, Profile, Business, Confirm }
+/// #[derive(Debug, PartialEq)]
+/// enum Go { Next, Cancel }
+///
+/// let is_company = Signal::new(false);
+/// let machine = StateMachine::new(Signup::Account)
+///     .transition(Signup::Account, Go::Next, Signup::Profile)
+///     .guarded(Signup::Profile, Go::Next, Signup::Business, {
+///         let is_company = is_company.clone();
+///         move |_, _| is_company.get()
+///     })
+///     .transition(Signup::Profile, Go::Next, Signup::Confirm)
+///     .transition(Signup::Business, Go::Next, Signup::Confirm)
+///     .transition_from_any(Go::Cancel, Signup::Account)
+///     .on_enter(Signup::Confirm, |_from| save_draft());
+///
+/// machine.send(Go::Next); // Profile
+/// machine.back();         // Account
+/// ```
+pub struct StateMachine<S: Clone, E> {
+    state: Signal<S>,
+    initial: S,
+    transitions: Vec<Transition<S, E>>,
+    /// (state, action(previous state))
+    on_enter: Vec<(S, Action<S>)>,
+    /// (state, action(next state))
+    on_exit: Vec<(S, Action<S>)>,
+    /// States left to reach the current one, oldest first
+    history: RefCell<Vec<S>>,
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: Clone + PartialEq + Debug + 'static,
+    E: PartialEq + Debug,
+{
+    /// Create a machine in its initial state
+    pub fn new(initial: S) -> Self {
+        Self {
+            state: Signal::new(initial.clone()),
+            initial,
+            transitions: Vec::new(),
+            on_enter: Vec::new(),
+            on_exit: Vec::new(),
+            history: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// `event` in `from` moves to `to`
+    pub fn transition(mut self, from: S, event: E, to: S) -> Self {
+        self.transitions.push(Transition { from: Some(from), event, to, guard: None });
+        self
+    }
+
+    /// Like `transition`, but only if `guard(from, event)` says yes
+    pub fn guarded<G>(mut self, from: S, event: E, to: S, guard: G) -> Self
+    where
+        G: Fn(&S, &E) -> bool + 'static,
+    {
+        self.transitions.push(Transition { from: Some(from), event, to, guard: Some(Box::new(guard)) });
+        self
+    }
+
+    /// `event` moves to `to` from every state (cancel, reset…)
+    pub fn transition_from_any(mut self, event: E, to: S) -> Self {
+        self.transitions.push(Transition { from: None, event, to, guard: None });
+        self
+    }
+
+    /// Run `action(previous)` whenever `state` is entered
+    pub fn on_enter<F>(mut self, state: S, action: F) -> Self
+    where
+        F: Fn(&S) + 'static,
+    {
+        self.on_enter.push((state, Box::new(action)));
+        self
+    }
+
+    /// Run `action(next)` whenever `state` is left
+    pub fn on_exit<F>(mut self, state: S, action: F) -> Self
+    where
+        F: Fn(&S) + 'static,
+    {
+        self.on_exit.push((state, Box::new(action)));
+        self
+    }
+
+    /// Current state
+    pub fn state(&self) -> S {
+        self.state.get()
+    }
+
+    /// Current state as a signal (for bindings)
+    pub fn signal(&self) -> Signal<S> {
+        self.state.clone()
+    }
+
+    /// Is the machine in `state`?
+    pub fn is(&self, state: &S) -> bool {
+        self.state.get() == *state
+    }
+
+    /// Where `event` would lead right now (None if nothing accepts it)
+    pub fn target(&self, event: &E) -> Option<S> {
+        let current = self.state.get();
+        self.transitions
+            .iter()
+            .filter(|t| t.event == *event && t.from.as_ref().is_none_or(|from| *from == current))
+            .find(|t| t.guard.as_ref().is_none_or(|guard| guard(&current, event)))
+            .map(|t| t.to.clone())
+    }
+
+    /// Would `event` do anything? (enable / disable a "Next" button)
+    pub fn can(&self, event: &E) -> bool {
+        self.target(event).is_some()
+    }
+
+    /// Handle an event; returns true if the state changed
+    pub fn send(&self, event: E) -> bool {
+        let Some(next) = self.target(&event) else {
+            warn!("🚦 {:?} ignored in {:?}", event, self.state.get());
+            return false;
+        };
+        let previous = self.state.get();
+        info!("🚦 {:?} --{:?}--> {:?}", previous, event, next);
+        self.history.borrow_mut().push(previous.clone());
+        self.enter(previous, next);
+        true
+    }
+
+    /// Return to the previous state (runs exit / enter actions)
+    pub fn back(&self) -> bool {
+        let Some(previous) = self.history.borrow_mut().pop() else {
+            return false;
+        };
+        self.enter(self.state.get(), previous);
+        true
+    }
+
+    /// Can `back()` go anywhere?
+    pub fn can_go_back(&self) -> bool {
+        !self.history.borrow().is_empty()
+    }
+
+    /// States visited before the current one, oldest first
+    pub fn history(&self) -> Vec<S> {
+        self.history.borrow().clone()
+    }
+
+    /// Back to the initial state with no history (no actions run)
+    pub fn reset(&self) {
+        self.history.borrow_mut().clear();
+        self.state.set(self.initial.clone());
+    }
+
+    fn enter(&self, from: S, to: S) {
+        for (state, action) in &self.on_exit {
+            if *state == from {
+                action(&to);
+            }
+        }
+        self.state.set(to.clone());
+        for (state, action) in &self.on_enter {
+            if *state == to {
+                action(&from);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Flow {
+        Account,
+        Profile,
+        Business,
+        Confirm,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Go {
+        Next,
+        Cancel,
+    }
+
+    #[test]
+    fn guarded_branches_actions_and_back() {
+        let company = Signal::new(false);
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (enter_log, exit_log) = (log.clone(), log.clone());
+        let machine = StateMachine::new(Flow::Account)
+            .transition(Flow::Account, Go::Next, Flow::Profile)
+            .guarded(Flow::Profile, Go::Next, Flow::Business, {
+                let company = company.clone();
+                move |_, _| company.get()
+            })
+            .transition(Flow::Profile, Go::Next, Flow::Confirm)
+            .transition(Flow::Business, Go::Next, Flow::Confirm)
+            .transition_from_any(Go::Cancel, Flow::Account)
+            .on_enter(Flow::Confirm, move |from| enter_log.borrow_mut().push(format!("enter Confirm from {:?}", from)))
+            .on_exit(Flow::Account, move |to| exit_log.borrow_mut().push(format!("exit Account to {:?}", to)));
+
+        let state = machine.signal();
+        assert_eq!(machine.target(&Go::Cancel), Some(Flow::Account));
+        assert!(machine.send(Go::Next));
+        assert_eq!(state.get(), Flow::Profile);
+        assert_eq!(machine.target(&Go::Next), Some(Flow::Confirm));
+
+        company.set(true);
+        assert!(machine.send(Go::Next));
+        assert!(machine.is(&Flow::Business));
+        assert!(machine.send(Go::Next));
+        assert_eq!(machine.history(), vec![Flow::Account, Flow::Profile, Flow::Business]);
+        assert!(!machine.can(&Go::Next));
+        assert!(!machine.send(Go::Next));
+
+        // Back retraces the branch that was taken
+        assert!(machine.back());
+        assert!(machine.is(&Flow::Business));
+        assert!(machine.send(Go::Cancel));
+        assert!(machine.is(&Flow::Account));
+        assert_eq!(
+            *log.borrow(),
+            vec!["exit Account to Profile".to_string(), "enter Confirm from Business".to_string()]
+        );
+
+        machine.reset();
+        assert!(!machine.can_go_back());
+    }
+}
@@ -0,0 +1,261 @@
+//! Store - App-wide state with reducers and selectors! 🏪
+//!
+//! Signals are perfect for local state; a `Store` holds the state a whole
+//! app shares. Changes only happen by dispatching actions through one
+//! reducer, so every change is visible in one place.
+//!
+//! `select` turns a slice of the state into its own `Signal` that only
+//! fires when that slice changes, so a component watching the cart count
+//! doesn't re-render when the user's theme changes.
+//!
+//! For debugging, the store keeps a history of (action, state) snapshots:
+//! `travel_to` rewinds or replays them, and `on_dispatch` hooks see every
+//! action with the states before and after.
+
+use crate::signal::{untrack, Signal};
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::rc::Rc;
+use tracing::info;
+
+/// One entry in the store's history
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoreSnapshot<S> {
+    /// `{:?}` of the action ("@@init" for the initial state)
+    pub action: String,
+    pub state: S,
+}
+
+type Reducer<S, A> = Box<dyn Fn(&S, &A) -> S>;
+type DispatchHook<S, A> = Box<dyn Fn(&A, &S, &S)>;
+
+struct StoreInner<S: Clone, A> {
+    state: Signal<S>,
+    reducer: Reducer<S, A>,
+    hooks: RefCell<Vec<DispatchHook<S, A>>>,
+    history: RefCell<Vec<StoreSnapshot<S>>>,
+    /// Snapshot currently shown
+    cursor: Cell<usize>,
+    history_limit: Cell<usize>,
+}
+
+/// Store - Reducer-driven state container 🏪
+///
+/// Clones share the same state.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Clone, PartialEq)]
+/// struct App { cart: Vec<u32>, theme: String }
+///
+/// #[derive(Debug)]
+/// enum Action { AddToCart(u32), SetTheme(String) }
+///
+/// let store = Store::new(App::default(), |state, action| match action {
+///     Action::AddToCart(id) => App { cart: [state.cart.clone(), vec![*id]].concat(), ..state.clone() },
+///     Action::SetTheme(theme) => App { theme: theme.clone(), ..state.clone() },
+/// });
+///
+/// let cart_count = store.select(|state| state.cart.len());
+/// store.dispatch(Action::AddToCart(7)); // cart_count fires
+/// store.dispatch(Action::SetTheme("dark".into())); // cart_count doesn't
+/// store.travel_to(1); // back to just after the first action
+/// ```
+pub struct Store<S: Clone, A> {
+    inner: Rc<StoreInner<S, A>>,
+}
+
+impl<S: Clone, A> Clone for Store<S, A> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<S, A> Store<S, A>
+where
+    S: Clone + 'static,
+    A: Debug + 'static,
+{
+    /// Create a store from an initial state and a reducer
+    pub fn new<R>(initial: S, reducer: R) -> Self
+    where
+        R: Fn(&S, &A) -> S + 'static,
+    {
+        info!("🏪 Creating Store");
+        Self {
+            inner: Rc::new(StoreInner {
+                state: Signal::new(initial.clone()),
+                reducer: Box::new(reducer),
+                hooks: RefCell::new(Vec::new()),
+                history: RefCell::new(vec![StoreSnapshot { action: "@@init".to_string(), state: initial }]),
+                cursor: Cell::new(0),
+                history_limit: Cell::new(100),
+            }),
+        }
+    }
+
+    /// Keep at most `limit` snapshots (0 turns history off)
+    pub fn history_limit(self, limit: usize) -> Self {
+        self.inner.history_limit.set(limit);
+        self.trim_history();
+        self
+    }
+
+    /// Current state
+    pub fn state(&self) -> S {
+        self.inner.state.get()
+    }
+
+    /// The whole state as a signal
+    pub fn signal(&self) -> Signal<S> {
+        self.inner.state.clone()
+    }
+
+    /// Run an action through the reducer
+    ///
+    /// Dispatching after `travel_to` drops the snapshots after the one
+    /// being shown, like typing after undo.
+    pub fn dispatch(&self, action: A) {
+        let previous = self.inner.state.get();
+        let next = (self.inner.reducer)(&previous, &action);
+
+        if self.inner.history_limit.get() > 0 {
+            let mut history = self.inner.history.borrow_mut();
+            history.truncate(self.inner.cursor.get() + 1);
+            history.push(StoreSnapshot { action: format!("{:?}", action), state: next.clone() });
+        }
+        self.trim_history();
+
+        self.inner.state.set(next.clone());
+        for hook in self.inner.hooks.borrow().iter() {
+            hook(&action, &previous, &next);
+        }
+    }
+
+    /// Signal of one slice of the state; fires only when that slice changes
+    pub fn select<T, F>(&self, selector: F) -> Signal<T>
+    where
+        T: Clone + PartialEq + 'static,
+        F: Fn(&S) -> T + 'static,
+    {
+        let selected = Signal::new(selector(&self.inner.state.get()));
+        let output = selected.clone();
+        self.inner.state.subscribe(move |state| {
+            let value = selector(state);
+            // Compare without tracking: selectors may run inside effects
+            if untrack(|| output.get()) != value {
+                output.set(value);
+            }
+        });
+        selected
+    }
+
+    /// Call `hook(action, before, after)` after every dispatch
+    pub fn on_dispatch<F>(&self, hook: F)
+    where
+        F: Fn(&A, &S, &S) + 'static,
+    {
+        self.inner.hooks.borrow_mut().push(Box::new(hook));
+    }
+
+    /// Recorded snapshots, oldest first
+    pub fn snapshots(&self) -> Vec<StoreSnapshot<S>> {
+        self.inner.history.borrow().clone()
+    }
+
+    /// Index of the snapshot being shown
+    pub fn current_snapshot(&self) -> usize {
+        self.inner.cursor.get()
+    }
+
+    /// Show a recorded state (debugging); returns false if out of range
+    pub fn travel_to(&self, index: usize) -> bool {
+        let Some(snapshot) = self.inner.history.borrow().get(index).cloned() else {
+            return false;
+        };
+        self.inner.cursor.set(index);
+        info!("🏪 Time travel to #{} ({})", index, snapshot.action);
+        self.inner.state.set(snapshot.state);
+        true
+    }
+
+    fn trim_history(&self) {
+        let mut history = self.inner.history.borrow_mut();
+        let limit = self.inner.history_limit.get();
+        let excess = history.len().saturating_sub(limit);
+        history.drain(..excess);
+        self.inner.cursor.set(history.len().saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    struct App {
+        cart: Vec<u32>,
+        theme: String,
+    }
+
+    #[derive(Debug)]
+    enum Action {
+        Add(u32),
+        Theme(&'static str),
+    }
+
+    fn store() -> Store<App, Action> {
+        Store::new(App::default(), |state: &App, action: &Action| match action {
+            Action::Add(id) => App { cart: [state.cart.clone(), vec![*id]].concat(), ..state.clone() },
+            Action::Theme(theme) => App { theme: theme.to_string(), ..state.clone() },
+        })
+    }
+
+    #[test]
+    fn selectors_only_fire_for_their_slice() {
+        let store = store();
+        let count = store.select(|state| state.cart.len());
+        let fired = Rc::new(Cell::new(0));
+        let seen = fired.clone();
+        count.subscribe(move |_| seen.set(seen.get() + 1));
+        let actions = Rc::new(RefCell::new(Vec::new()));
+        let log = actions.clone();
+        store.on_dispatch(move |action, before, after| log.borrow_mut().push((format!("{:?}", action), before.cart.len(), after.cart.len())));
+
+        store.dispatch(Action::Add(1));
+        store.dispatch(Action::Theme("dark"));
+        store.dispatch(Action::Add(2));
+        assert_eq!(count.get(), 2);
+        assert_eq!(fired.get(), 2);
+        assert_eq!(store.state().theme, "dark");
+        assert_eq!(actions.borrow()[1], ("Theme(\"dark\")".to_string(), 1, 1));
+    }
+
+    #[test]
+    fn time_travel_rewinds_and_branches() {
+        let store = store();
+        let count = store.select(|state| state.cart.len());
+        store.dispatch(Action::Add(1));
+        store.dispatch(Action::Add(2));
+        store.dispatch(Action::Add(3));
+        assert_eq!(store.snapshots().len(), 4);
+        assert_eq!(store.snapshots()[2].action, "Add(2)");
+
+        assert!(store.travel_to(1));
+        assert_eq!(count.get(), 1);
+        assert!(!store.travel_to(9));
+
+        // Dispatching from the past drops the old future
+        store.dispatch(Action::Theme("light"));
+        let labels: Vec<String> = store.snapshots().into_iter().map(|s| s.action).collect();
+        assert_eq!(labels, vec!["@@init", "Add(1)", "Theme(\"light\")"]);
+        assert_eq!(store.current_snapshot(), 2);
+
+        let limited = self::store().history_limit(2);
+        for id in 0..5 {
+            limited.dispatch(Action::Add(id));
+        }
+        assert_eq!(limited.snapshots().len(), 2);
+        assert_eq!(limited.current_snapshot(), 1);
+    }
+}
@@ -1,7 +1,15 @@
 use fontdue::{Font, FontSettings};
 use tracing::info;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+pub mod bidi;
+pub mod shaping;
+pub mod text_layout;
+
+pub use shaping::{ShapedGlyph, ShapedText};
+pub use text_layout::{TextAlign, TextLayout, TextLine, TextLines};
+
 // 🌍 EMBEDDED FONTS - Works offline, everywhere, forever!
 // Roboto: Beautiful, readable, supports Latin scripts
 const ROBOTO_REGULAR: &[u8] = include_bytes!("../assets/fonts/Roboto-Regular.ttf");
@@ -19,7 +27,33 @@ const NOTO_SANS_BENGALI: &[u8] = include_bytes!("../assets/fonts/NotoSansBengali
 /// - Fast glyph caching
 pub struct TextRenderer {
     font: Font,
-    glyph_cache: HashMap<(char, u32), RasterizedGlyph>,
+    /// The font parsed once for the shaper
+    face: ShapingFace,
+    /// Rasterized glyphs by (glyph index, size)
+    glyph_cache: HashMap<(u16, u32), RasterizedGlyph>,
+}
+
+/// A rustybuzz face that owns (or borrows for `'static`) its font bytes
+struct ShapingFace {
+    // Declared before `_data` so it is dropped before the bytes it borrows
+    face: Option<rustybuzz::Face<'static>>,
+    _data: Cow<'static, [u8]>,
+}
+
+impl ShapingFace {
+    fn new(data: Cow<'static, [u8]>) -> Self {
+        let bytes: &'static [u8] = match &data {
+            Cow::Borrowed(bytes) => bytes,
+            // SAFETY: an owned font lives in a heap buffer that never moves
+            // or changes while `_data` is alive, and `face` is dropped first.
+            Cow::Owned(bytes) => unsafe { &*(bytes.as_slice() as *const [u8]) },
+        };
+        Self { face: rustybuzz::Face::from_slice(bytes, 0), _data: data }
+    }
+
+    fn face(&self) -> Option<&rustybuzz::Face<'_>> {
+        self.face.as_ref()
+    }
 }
 
 /// A rasterized glyph with its bitmap data
@@ -40,7 +74,7 @@ pub struct RasterizedGlyph {
 }
 
 /// Font selection
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FontFamily {
     /// Roboto - Beautiful, modern, supports Latin
     Roboto,
@@ -71,6 +105,7 @@ impl TextRenderer {
         
         Ok(Self {
             font,
+            face: ShapingFace::new(Cow::Borrowed(font_data)),
             glyph_cache: HashMap::new(),
         })
     }
@@ -87,51 +122,75 @@ impl TextRenderer {
         
         Ok(Self {
             font,
+            face: ShapingFace::new(Cow::Owned(font_data.to_vec())),
             glyph_cache: HashMap::new(),
         })
     }
     
     /// Rasterize a single character at a given size
     pub fn rasterize_char(&mut self, c: char, size: u32) -> Option<&RasterizedGlyph> {
+        let index = self.font.lookup_glyph_index(c);
+        self.rasterize_glyph(index, size)
+    }
+
+    /// Rasterize a glyph by its index in the font (what the shaper produces)
+    pub fn rasterize_glyph(&mut self, index: u16, size: u32) -> Option<&RasterizedGlyph> {
         // Check cache first - FAST! ⚡
-        let cache_key = (c, size);
-        if self.glyph_cache.contains_key(&cache_key) {
-            return self.glyph_cache.get(&cache_key);
+        let cache_key = (index, size);
+        if !self.glyph_cache.contains_key(&cache_key) {
+            let (metrics, bitmap) = self.font.rasterize_indexed(index, size as f32);
+            let glyph = RasterizedGlyph {
+                bitmap,
+                width: metrics.width,
+                height: metrics.height,
+                x_offset: metrics.xmin,
+                y_offset: metrics.ymin,
+                advance_width: metrics.advance_width,
+            };
+            self.glyph_cache.insert(cache_key, glyph);
         }
-        
-        // Rasterize the glyph
-        let (metrics, bitmap) = self.font.rasterize(c, size as f32);
-        
-        let glyph = RasterizedGlyph {
-            bitmap,
-            width: metrics.width,
-            height: metrics.height,
-            x_offset: metrics.xmin,
-            y_offset: metrics.ymin,
-            advance_width: metrics.advance_width,
-        };
-        
-        self.glyph_cache.insert(cache_key, glyph);
         self.glyph_cache.get(&cache_key)
     }
     
+    /// Shape one line with the font's own rules (ligatures, Arabic joining,
+    /// Indic reordering, marks and kerning) and bidi reordering (see `shaping`)
+    pub fn shape(&self, text: &str, size: u32) -> ShapedText {
+        let direction = bidi::paragraph_direction(text).unwrap_or_else(crate::layout::LayoutDirection::current);
+        match self.face.face() {
+            Some(face) => shaping::shape(face, text, size, direction),
+            None => ShapedText { glyphs: Vec::new(), width: 0.0, size, direction },
+        }
+    }
+
+    /// Rasterize a shaped line; each glyph's advance includes kerning and
+    /// its offsets include the shaper's mark positioning
+    pub fn rasterize_shaped(&mut self, shaped: &ShapedText) -> Vec<RasterizedGlyph> {
+        let mut pen = 0.0;
+        shaped
+            .glyphs
+            .iter()
+            .filter_map(|shaped_glyph| {
+                let mut glyph = self.rasterize_glyph(shaped_glyph.glyph_id, shaped.size)?.clone();
+                glyph.x_offset += (shaped_glyph.x - pen).round() as i32;
+                glyph.y_offset += shaped_glyph.y_offset.round() as i32;
+                glyph.advance_width = shaped_glyph.advance;
+                pen += shaped_glyph.advance;
+                Some(glyph)
+            })
+            .collect()
+    }
+
     /// Rasterize a string of text
-    /// Returns a vector of glyphs ready to render!
+    /// Returns a vector of glyphs ready to render, left to right
+    /// (shaped and reordered first, see `shape`)
     pub fn rasterize_text(&mut self, text: &str, size: u32) -> Vec<RasterizedGlyph> {
-        text.chars()
-            .filter_map(|c| self.rasterize_char(c, size).cloned())
-            .collect()
+        let shaped = self.shape(text, size);
+        self.rasterize_shaped(&shaped)
     }
     
-    /// Measure the width of a text string in pixels
+    /// Measure the width of a text string in pixels (shaped, kerning included)
     pub fn measure_text(&mut self, text: &str, size: u32) -> f32 {
-        let mut total_width = 0.0;
-        for c in text.chars() {
-            if let Some(glyph) = self.rasterize_char(c, size) {
-                total_width += glyph.advance_width;
-            }
-        }
-        total_width
+        self.shape(text, size).width
     }
     
     /// Get font metrics
@@ -300,6 +359,18 @@ mod tests {
         assert!(longer_width > width);
     }
     
+    #[test]
+    fn custom_font_shapes_after_move() {
+        let source = TextRenderer::new().unwrap().measure_text("Hello", 24);
+        let renderers: Vec<TextRenderer> = (0..2)
+            .map(|_| TextRenderer::with_custom_font(ROBOTO_REGULAR).unwrap())
+            .collect();
+
+        for renderer in &renderers {
+            assert_eq!(renderer.shape("Hello", 24).width, source);
+        }
+    }
+    
     #[test]
     fn glyph_caching() {
         let mut renderer = TextRenderer::new().unwrap();
@@ -347,4 +418,17 @@ mod tests {
         let glyphs = renderer.rasterize_text("হ্যালো", 24);
         assert!(glyphs.len() > 0);
     }
+
+    #[test]
+    fn shaped_text_drives_measure_and_raster() {
+        let mut renderer = TextRenderer::new().unwrap();
+        let shaped = renderer.shape("office", 16);
+        assert!(shaped.glyph_count() <= 6);
+        assert_eq!(renderer.measure_text("office", 16), shaped.width);
+        let last = shaped.glyphs.last().unwrap();
+        assert!((last.x + last.advance - shaped.width).abs() < 0.001);
+
+        let advances: f32 = renderer.rasterize_text("office", 16).iter().map(|glyph| glyph.advance_width).sum();
+        assert!((advances - shaped.width).abs() < 0.001);
+    }
 }
@@ -0,0 +1,103 @@
+//! Bidi - Right-to-left and mixed-direction text! 🔀
+//!
+//! Text is stored in logical order (the order it's typed and read), but
+//! glyphs are drawn left to right. `visual_runs` runs the full Unicode
+//! Bidirectional Algorithm (UAX #9, via the `unicode-bidi` crate),
+//! including explicit embeddings, overrides and isolates (LRE/RLO/…/PDF,
+//! LRI/RLI/FSI/PDI), and splits a line into directional runs in drawing
+//! order; the shaper (see `shaping`) shapes each run in its direction.
+//!
+//! `visual_order` does the same on plain strings (mirroring brackets in
+//! right-to-left runs), for code that works on characters rather than
+//! glyphs. The explicit direction controls are honored and then dropped
+//! from its output, since they have no glyphs.
+
+use crate::layout::LayoutDirection;
+use std::ops::Range;
+use unicode_bidi::{bidi_class, get_base_direction, BidiClass, Direction, Level, ParagraphBidiInfo};
+
+/// Direction of a paragraph: its first strong letter, or None if it has none
+pub fn paragraph_direction(text: &str) -> Option<LayoutDirection> {
+    match get_base_direction(text) {
+        Direction::Ltr => Some(LayoutDirection::LTR),
+        Direction::Rtl => Some(LayoutDirection::RTL),
+        Direction::Mixed => None,
+    }
+}
+
+/// Does the text contain any right-to-left characters?
+pub fn has_rtl(text: &str) -> bool {
+    text.chars().any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL))
+}
+
+/// Explicit embedding, override and isolate controls (invisible once applied)
+fn is_control(c: char) -> bool {
+    use BidiClass::*;
+    matches!(bidi_class(c), LRE | RLE | LRO | RLO | PDF | LRI | RLI | FSI | PDI)
+}
+
+/// Split one line into directional runs, in visual (left to right) order
+///
+/// Each run is a byte range of `text` and whether it reads right to left.
+/// `base` is the paragraph direction to use when the text has no strong
+/// letters (pass `paragraph_direction(text)` to detect it).
+pub fn visual_runs(text: &str, base: LayoutDirection) -> Vec<(Range<usize>, bool)> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let base_level = if base.is_rtl() { Level::rtl() } else { Level::ltr() };
+    let info = ParagraphBidiInfo::new(text, Some(base_level));
+    if !info.has_rtl() {
+        return vec![(0..text.len(), false)];
+    }
+    let (levels, runs) = info.visual_runs(0..text.len());
+    runs.into_iter().map(|run| (run.clone(), levels[run.start].is_rtl())).collect()
+}
+
+/// Reorder one line from logical to visual (left-to-right drawing) order
+///
+/// `base` is the paragraph direction to use when the text has no strong
+/// letters (pass `paragraph_direction(text)` to detect it).
+pub fn visual_order(text: &str, base: LayoutDirection) -> String {
+    let mut visual = String::with_capacity(text.len());
+    for (range, rtl) in visual_runs(text, base) {
+        let chars = text[range].chars().filter(|&c| !is_control(c));
+        if rtl {
+            visual.extend(chars.rev().map(|c| unicode_bidi_mirroring::get_mirrored(c).unwrap_or(c)));
+        } else {
+            visual.extend(chars);
+        }
+    }
+    visual
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reorders_mixed_direction_text() {
+        // Hebrew "shalom" with an English word and a number in it
+        assert_eq!(visual_order("שלום", LayoutDirection::LTR), "םולש");
+        assert_eq!(visual_order("abc שלום def", LayoutDirection::LTR), "abc םולש def");
+        assert_eq!(visual_order("שלום abc", LayoutDirection::RTL), "abc םולש");
+        // Digits keep their order inside RTL text
+        assert_eq!(visual_order("מחיר 120", LayoutDirection::RTL), "120 ריחמ");
+        // Brackets mirror in RTL runs
+        assert_eq!(visual_order("(שלום)", LayoutDirection::RTL), "(םולש)");
+        // Pure LTR text is untouched
+        assert_eq!(visual_order("Hello, world!", LayoutDirection::LTR), "Hello, world!");
+        assert_eq!(paragraph_direction("123 שלום"), Some(LayoutDirection::RTL));
+        assert_eq!(paragraph_direction("123"), None);
+    }
+
+    #[test]
+    fn honors_explicit_controls() {
+        // RLO forces "abc" right to left; the controls themselves vanish
+        assert_eq!(visual_order("x \u{202E}abc\u{202C} y", LayoutDirection::LTR), "x cba y");
+        // An isolate keeps its Hebrew and digits from touching the text around it
+        assert_eq!(visual_order("\u{2067}שלום 1\u{2069} abc", LayoutDirection::LTR), "1 םולש abc");
+        // FSI picks its own direction from its first strong letter
+        assert_eq!(visual_order("abc \u{2068}שלום!\u{2069}", LayoutDirection::LTR), "abc !םולש");
+    }
+}
@@ -0,0 +1,118 @@
+//! Shaping - From a string to positioned glyphs ✍️
+//!
+//! Drawing one glyph per character gets three things wrong: Latin
+//! ligatures ("fi" should be one glyph), Arabic joining (letters change
+//! shape with their neighbours) and Indic vowel signs that are typed after
+//! a consonant but drawn before it. `TextRenderer::shape` hands the line to
+//! `rustybuzz` (a Rust port of HarfBuzz), which applies the font's own
+//! GSUB/GPOS rules, so conjuncts, mark positioning and kerning come out
+//! the way the font designer intended. It returns a `ShapedText` (glyph
+//! ids with advances and offsets), which is what `measure_text` measures
+//! and `rasterize_text` draws.
+//!
+//! Mixed-direction lines are split into runs by `bidi::visual_runs`
+//! first; each run is shaped in its own direction and the runs are laid
+//! out left to right.
+
+use super::bidi;
+use crate::layout::LayoutDirection;
+use rustybuzz::{Direction, Face, UnicodeBuffer};
+
+/// One glyph, positioned on the line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapedGlyph {
+    /// Glyph index in the font (after the font's substitutions)
+    pub glyph_id: u16,
+    /// Byte offset in the text of the first character this glyph came from
+    pub cluster: usize,
+    /// Pen position from the start of the line (glyph offset included)
+    pub x: f32,
+    /// Offset above the baseline (marks stacked on a letter)
+    pub y_offset: f32,
+    /// How far the pen moves after this glyph (kerning included)
+    pub advance: f32,
+}
+
+/// A shaped line of text, in visual (left to right) order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapedText {
+    pub glyphs: Vec<ShapedGlyph>,
+    /// Total advance width in pixels
+    pub width: f32,
+    /// Font size the line was shaped at
+    pub size: u32,
+    /// Paragraph direction the line was reordered for
+    pub direction: LayoutDirection,
+}
+
+impl ShapedText {
+    /// Number of glyphs (ligatures count once)
+    pub fn glyph_count(&self) -> usize {
+        self.glyphs.len()
+    }
+}
+
+/// Shape one line with a font face at `size` pixels
+pub fn shape(face: &Face, text: &str, size: u32, direction: LayoutDirection) -> ShapedText {
+    let scale = size as f32 / face.units_per_em() as f32;
+    let mut glyphs = Vec::with_capacity(text.len());
+    let mut pen = 0.0;
+
+    for (range, rtl) in bidi::visual_runs(text, direction) {
+        let mut buffer = UnicodeBuffer::new();
+        buffer.push_str(&text[range.clone()]);
+        buffer.guess_segment_properties();
+        buffer.set_direction(if rtl { Direction::RightToLeft } else { Direction::LeftToRight });
+
+        let output = rustybuzz::shape(face, &[], buffer);
+        for (info, position) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+            let advance = position.x_advance as f32 * scale;
+            glyphs.push(ShapedGlyph {
+                glyph_id: info.glyph_id as u16,
+                cluster: range.start + info.cluster as usize,
+                x: pen + position.x_offset as f32 * scale,
+                y_offset: position.y_offset as f32 * scale,
+                advance,
+            });
+            pen += advance;
+        }
+    }
+    ShapedText { glyphs, width: pen, size, direction }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTO: &[u8] = include_bytes!("../../assets/fonts/Roboto-Regular.ttf");
+    const BENGALI: &[u8] = include_bytes!("../../assets/fonts/NotoSansBengali-Regular.ttf");
+
+    #[test]
+    fn font_ligatures_replace_letters() {
+        let face = Face::from_slice(ROBOTO, 0).unwrap();
+        let shaped = shape(&face, "office", 16, LayoutDirection::LTR);
+        // "ffi" is one glyph in Roboto's liga feature
+        assert_eq!(shaped.glyph_count(), 4);
+        assert_eq!(shaped.glyphs.iter().map(|glyph| glyph.cluster).collect::<Vec<_>>(), vec![0, 1, 4, 5]);
+    }
+
+    #[test]
+    fn indic_vowel_sign_is_drawn_before_its_consonant() {
+        let face = Face::from_slice(BENGALI, 0).unwrap();
+        let shaped = shape(&face, "কি", 16, LayoutDirection::LTR);
+        let i_sign = face.glyph_index('\u{09BF}').unwrap().0;
+        assert_eq!(shaped.glyphs.first().map(|glyph| glyph.glyph_id), Some(i_sign));
+    }
+
+    #[test]
+    fn right_to_left_runs_come_out_in_visual_order() {
+        let face = Face::from_slice(ROBOTO, 0).unwrap();
+        let text = "abc שלום";
+        let shaped = shape(&face, text, 16, LayoutDirection::LTR);
+        let clusters: Vec<usize> = shaped.glyphs.iter().map(|glyph| glyph.cluster).collect();
+        // "abc " left to right, then the Hebrew word from its last letter back
+        assert_eq!(&clusters[..4], &[0, 1, 2, 3]);
+        assert!(clusters[4..].windows(2).all(|pair| pair[0] > pair[1]));
+        assert_eq!(clusters.last(), Some(&4));
+    }
+}
@@ -0,0 +1,249 @@
+//! Text layout - Paragraphs: wrapping, ellipsis and alignment 📄
+//!
+//! `TextLayout` breaks text into lines that fit a width (at spaces, and at
+//! newlines), cuts it to `max_lines` with an ellipsis on the last line,
+//! and positions each line for left, center, right or justified
+//! alignment. It measures through a closure, so the same layout works with
+//! a `TextRenderer` (`layout_with`), with a width estimate in a paint pass
+//! without fonts, or with a fake measure in tests.
+//!
+//! The layout engine uses it to size text leaves (`TextMeasure`), and
+//! Text, Table cells and Tooltip use it to draw.
+
+/// Horizontal alignment of lines in a paragraph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// Stretch every line but a paragraph's last to the full width
+    Justify,
+}
+
+/// A positioned line
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLine {
+    pub text: String,
+    /// Left edge from the start of the paragraph box
+    pub x: f32,
+    /// Top edge from the top of the paragraph box
+    pub y: f32,
+    /// Width of the text as drawn (full width for justified lines)
+    pub width: f32,
+    /// Pieces to draw (text, x): one per line, or one per word when justified
+    pub runs: Vec<(String, f32)>,
+}
+
+/// A laid-out paragraph
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextLines {
+    pub lines: Vec<TextLine>,
+    /// Widest line
+    pub width: f32,
+    /// All lines, at the line height
+    pub height: f32,
+    /// Lines were cut off by `max_lines` (the last one ends in an ellipsis)
+    pub truncated: bool,
+}
+
+/// TextLayout - How to lay out a paragraph 📄
+///
+/// # Example
+/// ```rust,ignore
+/// let lines = TextLayout::new(14).max_width(240.0).max_lines(2).align(TextAlign::Center)
+///     .layout_with(&mut renderer, &description);
+/// for line in &lines.lines {
+///     for (run, x) in &line.runs {
+///         list.text(run.clone(), left + x, top + line.y, 14.0, color);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLayout {
+    pub font_size: u32,
+    /// Wrap width (None = break only at newlines)
+    pub max_width: Option<f32>,
+    /// Lines to keep before cutting off with an ellipsis (Some(0) keeps none)
+    pub max_lines: Option<usize>,
+    pub align: TextAlign,
+    /// Line height as a multiple of the font size
+    pub line_height: f32,
+    pub ellipsis: String,
+}
+
+impl TextLayout {
+    /// Left-aligned, unwrapped, 1.2 line height
+    pub fn new(font_size: u32) -> Self {
+        Self { font_size, max_width: None, max_lines: None, align: TextAlign::Left, line_height: 1.2, ellipsis: "…".to_string() }
+    }
+
+    /// Wrap to a width
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.max_width = Some(width.max(0.0));
+        self
+    }
+
+    /// Keep at most this many lines (the last one ends in an ellipsis; 0 keeps none)
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines);
+        self
+    }
+
+    /// Set the alignment
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the line height (multiple of the font size)
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Height of one line in pixels
+    pub fn line_pixels(&self) -> f32 {
+        self.font_size as f32 * self.line_height
+    }
+
+    /// Lay out with a TextRenderer's shaped widths
+    pub fn layout_with(&self, renderer: &mut super::TextRenderer, text: &str) -> TextLines {
+        let size = self.font_size;
+        self.layout(text, &mut |piece| renderer.measure_text(piece, size))
+    }
+
+    /// Lay out, measuring line widths with `measure`
+    pub fn layout(&self, text: &str, measure: &mut dyn FnMut(&str) -> f32) -> TextLines {
+        // (text, ends a paragraph)
+        let mut lines: Vec<(String, bool)> = Vec::new();
+        for paragraph in text.split('\n') {
+            match self.max_width {
+                Some(max_width) => {
+                    let mut line = String::new();
+                    for word in paragraph.split(' ').filter(|word| !word.is_empty()) {
+                        let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+                        if line.is_empty() || measure(&candidate) <= max_width {
+                            line = candidate;
+                        } else {
+                            lines.push((std::mem::replace(&mut line, word.to_string()), false));
+                        }
+                    }
+                    lines.push((line, true));
+                }
+                None => lines.push((paragraph.to_string(), true)),
+            }
+        }
+
+        let mut truncated = false;
+        if let Some(max_lines) = self.max_lines {
+            if lines.len() > max_lines {
+                lines.truncate(max_lines);
+                truncated = true;
+                if let Some(last) = lines.last_mut() {
+                    last.0 = self.ellipsize(&last.0, measure);
+                    last.1 = true;
+                }
+            }
+        }
+
+        let line_pixels = self.line_pixels();
+        let measured: Vec<(String, bool, f32)> = lines
+            .into_iter()
+            .map(|(line, ends_paragraph)| {
+                let width = measure(&line);
+                (line, ends_paragraph, width)
+            })
+            .collect();
+        let widest = measured.iter().map(|(_, _, width)| *width).fold(0.0, f32::max);
+        let box_width = self.max_width.unwrap_or(widest);
+
+        let lines = measured
+            .into_iter()
+            .enumerate()
+            .map(|(index, (text, ends_paragraph, width))| {
+                let y = index as f32 * line_pixels;
+                let x = match self.align {
+                    TextAlign::Left | TextAlign::Justify => 0.0,
+                    TextAlign::Center => ((box_width - width) / 2.0).max(0.0),
+                    TextAlign::Right => (box_width - width).max(0.0),
+                };
+                let words: Vec<&str> = text.split(' ').filter(|word| !word.is_empty()).collect();
+                if self.align == TextAlign::Justify && !ends_paragraph && words.len() > 1 {
+                    let words_width: f32 = words.iter().map(|word| measure(word)).sum();
+                    let gap = (box_width - words_width) / (words.len() - 1) as f32;
+                    let mut pen = 0.0;
+                    let runs = words
+                        .iter()
+                        .map(|word| {
+                            let run = (word.to_string(), pen);
+                            pen += measure(word) + gap;
+                            run
+                        })
+                        .collect();
+                    return TextLine { text, x: 0.0, y, width: box_width, runs };
+                }
+                let runs = vec![(text.clone(), x)];
+                TextLine { text, x, y, width, runs }
+            })
+            .collect::<Vec<_>>();
+
+        TextLines { height: lines.len() as f32 * line_pixels, lines, width: widest, truncated }
+    }
+
+    /// The line with the ellipsis added, dropping characters until it fits
+    fn ellipsize(&self, line: &str, measure: &mut dyn FnMut(&str) -> f32) -> String {
+        let mut kept: Vec<char> = line.chars().collect();
+        loop {
+            let candidate = format!("{}{}", kept.iter().collect::<String>().trim_end(), self.ellipsis);
+            let fits = self.max_width.is_none_or(|max_width| measure(&candidate) <= max_width);
+            if fits || kept.is_empty() {
+                return candidate;
+            }
+            kept.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10px per character
+    fn mono(text: &str) -> f32 {
+        text.chars().count() as f32 * 10.0
+    }
+
+    #[test]
+    fn wraps_truncates_and_aligns() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        let lines = TextLayout::new(10).max_width(100.0).layout(text, &mut mono);
+        let texts: Vec<&str> = lines.lines.iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(texts, vec!["the quick", "brown fox", "jumps over", "the lazy", "dog"]);
+        assert_eq!(lines.height, 5.0 * 12.0);
+        assert!(!lines.truncated);
+
+        let cut = TextLayout::new(10).max_width(100.0).max_lines(2).layout(text, &mut mono);
+        assert_eq!(cut.lines[1].text, "brown fox…");
+        assert!(cut.truncated);
+
+        let none = TextLayout::new(10).max_lines(0).layout(text, &mut mono);
+        assert!(none.lines.is_empty());
+        assert_eq!(none.height, 0.0);
+        assert!(none.truncated);
+
+        let right = TextLayout::new(10).max_width(100.0).align(TextAlign::Right).layout("hi\nthere", &mut mono);
+        assert_eq!((right.lines[0].x, right.lines[1].x), (80.0, 50.0));
+        let center = TextLayout::new(10).max_width(100.0).align(TextAlign::Center).layout("hi", &mut mono);
+        assert_eq!(center.lines[0].x, 40.0);
+    }
+
+    #[test]
+    fn justify_spreads_words_except_on_the_last_line() {
+        let lines = TextLayout::new(10).max_width(100.0).align(TextAlign::Justify).line_height(1.5).layout("ab cd ef gh", &mut mono);
+        assert_eq!(lines.lines[0].text, "ab cd ef");
+        assert_eq!(lines.lines[0].runs, vec![("ab".to_string(), 0.0), ("cd".to_string(), 40.0), ("ef".to_string(), 80.0)]);
+        assert_eq!(lines.lines[1].runs, vec![("gh".to_string(), 0.0)]);
+        assert_eq!(lines.lines[1].y, 15.0);
+    }
+}
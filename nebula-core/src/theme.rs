@@ -0,0 +1,466 @@
+//! Theme - Design tokens and dark mode! 🎨
+//!
+//! A `Theme` names colors, radii and spacing by what they're *for*
+//! (surface, primary, border…) instead of hard-coding RGBA tuples in every
+//! component. Components read their defaults from the `ThemeProvider`, and
+//! switching themes at runtime is just setting its Signal.
+//!
+//! Several themes can be live at once: `ThemeProvider::scoped` makes a
+//! provider current for one window or subtree through the `Context`, so a
+//! customer-facing display can be branded while the operator console uses
+//! a dense dark theme.
+//!
+//! `Theme::high_contrast` is the built-in theme for users who turn on the
+//! OS high-contrast / forced-colors setting: solid black-on-white (or
+//! white-on-black) with strong borders. The platform layer detects the
+//! setting and calls `ThemeProvider::set_high_contrast`.
+
+use crate::context::Context;
+use crate::signal::Signal;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::info;
+
+/// RGBA color token
+pub type ThemeColor = (u8, u8, u8, u8);
+
+/// Light or dark appearance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+}
+
+/// Semantic color tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeColors {
+    /// Main background (cards, tables, inputs)
+    pub surface: ThemeColor,
+    /// Raised/secondary background (headers, toolbars)
+    pub surface_variant: ThemeColor,
+    /// Alternating background (striped rows)
+    pub surface_alt: ThemeColor,
+    /// Hovered background
+    pub hover: ThemeColor,
+    /// Brand / accent color
+    pub primary: ThemeColor,
+    /// Accent while pressed
+    pub primary_pressed: ThemeColor,
+    /// Text and icons drawn on `primary`
+    pub on_primary: ThemeColor,
+    /// Body text
+    pub text: ThemeColor,
+    /// Secondary text (labels, captions)
+    pub text_muted: ThemeColor,
+    /// Borders and dividers
+    pub border: ThemeColor,
+    /// Disabled / inactive elements
+    pub disabled: ThemeColor,
+    /// Selection highlight (usually a translucent primary)
+    pub selection: ThemeColor,
+    pub success: ThemeColor,
+    pub warning: ThemeColor,
+    pub danger: ThemeColor,
+}
+
+/// Corner radius tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeRadius {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    /// Pills and circles
+    pub full: f32,
+}
+
+/// Spacing scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeSpacing {
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}
+
+/// A complete set of design tokens 🎨
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub mode: ThemeMode,
+    pub colors: ThemeColors,
+    pub radius: ThemeRadius,
+    pub spacing: ThemeSpacing,
+    /// Default body font size
+    pub font_size: f32,
+    /// Built for high-contrast / forced-colors users (components drop
+    /// tints and shadows and rely on borders instead)
+    pub high_contrast: bool,
+}
+
+/// Blend two colors: `t` = 0 gives `a`, 1 gives `b` (alpha blends too)
+///
+/// Components use it to derive tints from theme tokens, e.g. an alert's
+/// background as `mix(surface, danger, 0.1)`, instead of fixed pastels.
+pub fn mix(a: ThemeColor, b: ThemeColor, t: f32) -> ThemeColor {
+    let t = t.clamp(0.0, 1.0);
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    (channel(a.0, b.0), channel(a.1, b.1), channel(a.2, b.2), channel(a.3, b.3))
+}
+
+impl Theme {
+    /// The default light theme (matches the components' classic look)
+    pub fn light() -> Self {
+        Self {
+            name: "Light".to_string(),
+            mode: ThemeMode::Light,
+            colors: ThemeColors {
+                surface: (255, 255, 255, 255),
+                surface_variant: (250, 250, 250, 255),
+                surface_alt: (249, 249, 249, 255),
+                hover: (245, 245, 245, 255),
+                primary: (59, 130, 246, 255), // Blue
+                primary_pressed: (37, 99, 235, 255),
+                on_primary: (255, 255, 255, 255),
+                text: (0, 0, 0, 255),
+                text_muted: (100, 100, 100, 255),
+                border: (220, 220, 220, 255),
+                disabled: (200, 200, 200, 255),
+                selection: (59, 130, 246, 20),
+                success: (34, 197, 94, 255),
+                warning: (245, 158, 11, 255),
+                danger: (239, 68, 68, 255),
+            },
+            radius: ThemeRadius::default(),
+            spacing: ThemeSpacing::default(),
+            font_size: 16.0,
+            high_contrast: false,
+        }
+    }
+
+    /// Neutral dark theme
+    pub fn dark() -> Self {
+        Self {
+            name: "Dark".to_string(),
+            mode: ThemeMode::Dark,
+            colors: ThemeColors {
+                surface: (24, 24, 27, 255),
+                surface_variant: (39, 39, 42, 255),
+                surface_alt: (30, 30, 33, 255),
+                hover: (52, 52, 56, 255),
+                primary: (96, 165, 250, 255),
+                primary_pressed: (59, 130, 246, 255),
+                on_primary: (10, 10, 10, 255),
+                text: (244, 244, 245, 255),
+                text_muted: (161, 161, 170, 255),
+                border: (63, 63, 70, 255),
+                disabled: (82, 82, 91, 255),
+                selection: (96, 165, 250, 48),
+                success: (74, 222, 128, 255),
+                warning: (251, 191, 36, 255),
+                danger: (248, 113, 113, 255),
+            },
+            radius: ThemeRadius::default(),
+            spacing: ThemeSpacing::default(),
+            font_size: 16.0,
+            high_contrast: false,
+        }
+    }
+
+    /// Nebula's signature deep-space dark theme 🌌
+    pub fn nebula_dark() -> Self {
+        Self {
+            name: "Nebula Dark".to_string(),
+            mode: ThemeMode::Dark,
+            colors: ThemeColors {
+                surface: (15, 14, 30, 255),
+                surface_variant: (28, 25, 52, 255),
+                surface_alt: (21, 19, 41, 255),
+                hover: (40, 36, 72, 255),
+                primary: (167, 139, 250, 255), // Violet
+                primary_pressed: (139, 92, 246, 255),
+                on_primary: (15, 14, 30, 255),
+                text: (237, 233, 254, 255),
+                text_muted: (167, 160, 200, 255),
+                border: (55, 48, 100, 255),
+                disabled: (75, 70, 110, 255),
+                selection: (167, 139, 250, 56),
+                success: (52, 211, 153, 255),
+                warning: (251, 191, 36, 255),
+                danger: (251, 113, 133, 255),
+            },
+            radius: ThemeRadius { sm: 6.0, md: 10.0, lg: 16.0, full: 9999.0 },
+            spacing: ThemeSpacing::default(),
+            font_size: 16.0,
+            high_contrast: false,
+        }
+    }
+
+    /// Maximum-contrast theme for the OS high-contrast setting ♿
+    ///
+    /// Every text/background pair clears WCAG AAA (7:1) and borders are
+    /// full-strength, so nothing depends on a subtle tint to be seen
+    pub fn high_contrast(mode: ThemeMode) -> Self {
+        let colors = match mode {
+            ThemeMode::Dark => ThemeColors {
+                surface: (0, 0, 0, 255),
+                surface_variant: (0, 0, 0, 255),
+                surface_alt: (20, 20, 20, 255),
+                hover: (40, 40, 40, 255),
+                primary: (255, 255, 0, 255), // Yellow
+                primary_pressed: (0, 255, 255, 255),
+                on_primary: (0, 0, 0, 255),
+                text: (255, 255, 255, 255),
+                text_muted: (210, 210, 210, 255),
+                border: (255, 255, 255, 255),
+                disabled: (150, 150, 150, 255),
+                selection: (255, 255, 0, 96),
+                success: (90, 255, 120, 255),
+                warning: (255, 200, 0, 255),
+                danger: (255, 110, 110, 255),
+            },
+            ThemeMode::Light => ThemeColors {
+                surface: (255, 255, 255, 255),
+                surface_variant: (255, 255, 255, 255),
+                surface_alt: (240, 240, 240, 255),
+                hover: (225, 225, 225, 255),
+                primary: (0, 0, 160, 255), // Navy
+                primary_pressed: (80, 0, 130, 255),
+                on_primary: (255, 255, 255, 255),
+                text: (0, 0, 0, 255),
+                text_muted: (40, 40, 40, 255),
+                border: (0, 0, 0, 255),
+                disabled: (100, 100, 100, 255),
+                selection: (0, 0, 160, 64),
+                success: (0, 100, 0, 255),
+                warning: (120, 60, 0, 255),
+                danger: (170, 0, 0, 255),
+            },
+        };
+        Self {
+            name: match mode {
+                ThemeMode::Light => "High Contrast Light".to_string(),
+                ThemeMode::Dark => "High Contrast Dark".to_string(),
+            },
+            mode,
+            colors,
+            radius: ThemeRadius::default(),
+            spacing: ThemeSpacing::default(),
+            font_size: 16.0,
+            high_contrast: true,
+        }
+    }
+
+    /// Built-in theme for a mode
+    pub fn for_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Light => Self::light(),
+            ThemeMode::Dark => Self::dark(),
+        }
+    }
+
+    /// Is this a dark theme?
+    pub fn is_dark(&self) -> bool {
+        self.mode == ThemeMode::Dark
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Default for ThemeRadius {
+    fn default() -> Self {
+        Self { sm: 4.0, md: 6.0, lg: 12.0, full: 9999.0 }
+    }
+}
+
+impl Default for ThemeSpacing {
+    fn default() -> Self {
+        Self { xs: 4.0, sm: 8.0, md: 16.0, lg: 24.0, xl: 32.0 }
+    }
+}
+
+thread_local! {
+    static GLOBAL_PROVIDER: RefCell<ThemeProvider> = RefCell::new(ThemeProvider::default());
+}
+
+/// Where components get their theme from 🖌️
+///
+/// Clones share the same Signal, so a provider can be handed to any
+/// component and switching the theme reaches all of them.
+///
+/// # Example
+/// ```rust,ignore
+/// let themes = ThemeProvider::global();
+/// themes.signal().subscribe(|theme| println!("Now using {}", theme.name));
+///
+/// let table = Table::new(); // picks up the current theme's colors
+/// themes.set(Theme::nebula_dark());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThemeProvider {
+    theme: Signal<Theme>,
+    /// The theme to go back to when high contrast is turned off
+    before_high_contrast: Rc<RefCell<Option<Theme>>>,
+}
+
+impl ThemeProvider {
+    /// Create a provider with a starting theme
+    pub fn new(theme: Theme) -> Self {
+        Self { theme: Signal::new(theme), before_high_contrast: Rc::new(RefCell::new(None)) }
+    }
+
+    /// The app-wide provider (per UI thread), ignoring any scope
+    pub fn global() -> Self {
+        GLOBAL_PROVIDER.with(|provider| provider.borrow().clone())
+    }
+
+    /// Make `provider` the app-wide provider
+    pub fn install(provider: ThemeProvider) {
+        GLOBAL_PROVIDER.with(|slot| *slot.borrow_mut() = provider);
+    }
+
+    /// The provider components should use right now: the innermost
+    /// `scoped` one, or the app-wide one outside any scope
+    pub fn active() -> Self {
+        Context::get::<ThemeProvider>().unwrap_or_else(Self::global)
+    }
+
+    /// Shortcut for `ThemeProvider::active().get()`
+    pub fn current() -> Theme {
+        Self::active().get()
+    }
+
+    /// Run `f` (building or painting a window or subtree) with this
+    /// provider as the current one
+    pub fn scoped<R>(&self, f: impl FnOnce() -> R) -> R {
+        Context::scoped(self.clone(), f)
+    }
+
+    /// Current theme
+    pub fn get(&self) -> Theme {
+        self.theme.get()
+    }
+
+    /// Switch themes (notifies subscribers)
+    pub fn set(&self, theme: Theme) {
+        info!("🎨 Switching theme to '{}'", theme.name);
+        self.theme.set(theme);
+    }
+
+    /// Switch between the built-in light and dark themes
+    pub fn toggle_mode(&self) {
+        let mode = if self.get().is_dark() { ThemeMode::Light } else { ThemeMode::Dark };
+        self.set(Theme::for_mode(mode));
+    }
+
+    /// Follow the OS high-contrast setting: on switches to the built-in
+    /// high-contrast theme (same light/dark mode), off restores the
+    /// theme that was active before
+    pub fn set_high_contrast(&self, enabled: bool) {
+        let current = self.get();
+        if enabled && !current.high_contrast {
+            self.set(Theme::high_contrast(current.mode));
+            *self.before_high_contrast.borrow_mut() = Some(current);
+        } else if !enabled && current.high_contrast {
+            let previous = self.before_high_contrast.borrow_mut().take();
+            self.set(previous.unwrap_or_else(|| Theme::for_mode(current.mode)));
+        }
+    }
+
+    /// Is a high-contrast theme active?
+    pub fn is_high_contrast(&self) -> bool {
+        self.get().high_contrast
+    }
+
+    /// The underlying Signal (subscribe to react to theme changes)
+    pub fn signal(&self) -> Signal<Theme> {
+        self.theme.clone()
+    }
+}
+
+impl Default for ThemeProvider {
+    fn default() -> Self {
+        Self::new(Theme::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    #[test]
+    fn builtin_themes() {
+        assert_eq!(Theme::default(), Theme::light());
+        assert!(!Theme::light().is_dark());
+        assert!(Theme::dark().is_dark());
+        assert!(Theme::nebula_dark().is_dark());
+        assert_eq!(Theme::for_mode(ThemeMode::Dark).name, "Dark");
+        assert_eq!(Theme::light().spacing.md, 16.0);
+    }
+
+    #[test]
+    fn high_contrast_themes_clear_aaa() {
+        use crate::accessibility::contrast_ratio;
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            let colors = Theme::high_contrast(mode).colors;
+            assert!(contrast_ratio(colors.text, colors.surface) >= 7.0);
+            assert!(contrast_ratio(colors.text_muted, colors.surface) >= 7.0);
+            assert!(contrast_ratio(colors.on_primary, colors.primary) >= 7.0);
+            assert!(contrast_ratio(colors.border, colors.surface) >= 7.0);
+        }
+        assert_eq!(mix((0, 0, 0, 255), (200, 100, 50, 255), 0.5), (100, 50, 25, 255));
+    }
+
+    #[test]
+    fn high_contrast_restores_previous_theme() {
+        let provider = ThemeProvider::new(Theme::nebula_dark());
+        provider.set_high_contrast(true);
+        assert!(provider.is_high_contrast());
+        assert_eq!(provider.get().name, "High Contrast Dark");
+        provider.set_high_contrast(false);
+        assert_eq!(provider.get().name, "Nebula Dark");
+    }
+
+    #[test]
+    fn scoped_providers_theme_a_subtree() {
+        let branded = ThemeProvider::new(Theme::nebula_dark());
+        let operator = ThemeProvider::new(Theme::dark());
+        let names = branded.scoped(|| {
+            let outer = ThemeProvider::current().name;
+            let inner = operator.scoped(|| ThemeProvider::current().name);
+            (outer, inner)
+        });
+        assert_eq!(names, ("Nebula Dark".to_string(), "Dark".to_string()));
+        assert_eq!(ThemeProvider::current().name, "Light");
+
+        // Switching a scoped provider only affects its own scope
+        operator.set(Theme::high_contrast(ThemeMode::Dark));
+        assert!(operator.scoped(|| ThemeProvider::current().high_contrast));
+        assert!(!ThemeProvider::current().high_contrast);
+    }
+
+    #[test]
+    fn provider_switches_at_runtime() {
+        let provider = ThemeProvider::global();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        provider.signal().subscribe(move |theme| log.borrow_mut().push(theme.name.clone()));
+
+        provider.toggle_mode();
+        assert_eq!(ThemeProvider::current().mode, ThemeMode::Dark);
+        ThemeProvider::global().set(Theme::nebula_dark());
+        assert_eq!(*seen.borrow(), vec!["Dark".to_string(), "Nebula Dark".to_string()]);
+
+        let custom = ThemeProvider::new(Theme::light());
+        ThemeProvider::install(custom.clone());
+        assert_eq!(ThemeProvider::current().name, "Light");
+        custom.set(Theme::dark());
+        assert_eq!(ThemeProvider::current().name, "Dark");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::capabilities::RendererCapabilities;
 use tracing::{info, warn};
 
 /// Rendering backend tier system
@@ -40,6 +41,16 @@ impl Backend {
         }
     }
     
+    /// Effects this tier can draw
+    pub fn capabilities(&self) -> RendererCapabilities {
+        match self {
+            Backend::WebGPU | Backend::OpenGL33 => RendererCapabilities::FULL,
+            // Shaders, but no float render targets for multi-pass blur
+            Backend::OpenGL21 => RendererCapabilities { blur: false, render_targets: false, ..RendererCapabilities::FULL },
+            Backend::CPU => RendererCapabilities::CPU,
+        }
+    }
+
     /// Get tier level (higher is better)
     pub fn tier(&self) -> u8 {
         match self {
@@ -0,0 +1,96 @@
+//! Capabilities - Know what the backend can't draw! 🧩
+//!
+//! Not every backend can do every effect: Canvas2D on an old browser has
+//! no shaders or blur filters, the CPU renderer skips blur because it's
+//! too slow. Each renderer reports a `RendererCapabilities`, and the app
+//! publishes it with `RendererCapabilities::set_current` so components
+//! can pick a fallback (a stronger dim instead of a backdrop blur, a
+//! border instead of a shadow) rather than silently drawing nothing.
+
+use std::cell::Cell;
+use tracing::info;
+
+/// Effects a backend can render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RendererCapabilities {
+    /// Soft drop shadows
+    pub shadows: bool,
+    /// Gaussian blur (backdrop blur, frosted glass)
+    pub blur: bool,
+    /// Rotation, scale and skew of drawn content
+    pub transforms: bool,
+    /// Custom shader effects
+    pub shaders: bool,
+    /// Offscreen render targets
+    pub render_targets: bool,
+}
+
+impl RendererCapabilities {
+    /// Everything (GPU backends)
+    pub const FULL: Self = Self { shadows: true, blur: true, transforms: true, shaders: true, render_targets: true };
+
+    /// Solid fills, text and images only
+    pub const BASIC: Self = Self { shadows: false, blur: false, transforms: false, shaders: false, render_targets: false };
+
+    /// Browser Canvas2D (shadowBlur and transforms, but no filters or shaders)
+    pub const CANVAS2D: Self = Self { shadows: true, blur: false, transforms: true, shaders: false, render_targets: false };
+
+    /// Software rendering (blur is too slow to run every frame)
+    pub const CPU: Self = Self { shadows: true, blur: false, transforms: true, shaders: false, render_targets: false };
+
+    /// Names of the effects that need a fallback, for logs and parity audits
+    pub fn missing(&self) -> Vec<&'static str> {
+        [
+            (self.shadows, "shadows"),
+            (self.blur, "blur"),
+            (self.transforms, "transforms"),
+            (self.shaders, "shaders"),
+            (self.render_targets, "render_targets"),
+        ]
+        .into_iter()
+        .filter(|(supported, _)| !supported)
+        .map(|(_, name)| name)
+        .collect()
+    }
+
+    /// Capabilities of the active renderer (per UI thread, `FULL` until set)
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Publish the active renderer's capabilities
+    pub fn set_current(capabilities: Self) {
+        if capabilities != Self::current() {
+            let missing = capabilities.missing();
+            if !missing.is_empty() {
+                info!("🧩 Renderer lacks {}; components will use fallbacks", missing.join(", "));
+            }
+            CURRENT.with(|current| current.set(capabilities));
+        }
+    }
+}
+
+impl Default for RendererCapabilities {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<RendererCapabilities> = const { Cell::new(RendererCapabilities::FULL) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canvas2d_lacks_blur_and_shaders() {
+        assert_eq!(RendererCapabilities::CANVAS2D.missing(), vec!["blur", "shaders", "render_targets"]);
+        assert!(RendererCapabilities::FULL.missing().is_empty());
+
+        RendererCapabilities::set_current(RendererCapabilities::CANVAS2D);
+        assert!(!RendererCapabilities::current().blur);
+        RendererCapabilities::set_current(RendererCapabilities::FULL);
+    }
+}
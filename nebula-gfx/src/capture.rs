@@ -0,0 +1,295 @@
+use std::path::Path;
+use tracing::info;
+
+/// Captured frame - straight-alpha RGBA8 pixels, rows top to bottom 📸
+///
+/// Every backend's `capture_frame` returns one of these, so screenshots
+/// and visual tests don't care which renderer produced them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Create a fully transparent image
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Wrap existing RGBA8 pixels
+    /// Returns an error if the data length doesn't match the dimensions
+    pub fn from_raw(width: u32, height: u32, pixels: Vec<u8>) -> Result<Self, String> {
+        let expected = width as usize * height as usize * 4;
+        if pixels.len() != expected {
+            return Err(format!(
+                "Image data is {} bytes, expected {} for {}x{} RGBA",
+                pixels.len(), expected, width, height
+            ));
+        }
+        Ok(Self { width, height, pixels })
+    }
+
+    /// Convert from 0RGB/ARGB u32 pixels (softbuffer layout), forcing alpha to 255
+    pub fn from_argb(width: u32, height: u32, data: &[u32]) -> Result<Self, String> {
+        let pixels = data
+            .iter()
+            .flat_map(|&p| [(p >> 16) as u8, (p >> 8) as u8, p as u8, 255])
+            .collect();
+        Self::from_raw(width, height, pixels)
+    }
+
+    /// Get a pixel as [r, g, b, a]
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        Some([self.pixels[i], self.pixels[i + 1], self.pixels[i + 2], self.pixels[i + 3]])
+    }
+
+    /// Flip rows in place (GPU read-backs start at the bottom row)
+    pub fn flip_vertical(&mut self) {
+        let stride = self.width as usize * 4;
+        let height = self.height as usize;
+        for row in 0..height / 2 {
+            let (top, bottom) = self.pixels.split_at_mut((height - row - 1) * stride);
+            top[row * stride..(row + 1) * stride].swap_with_slice(&mut bottom[..stride]);
+        }
+    }
+
+    /// Encode as PNG
+    pub fn encode_png(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut bytes, self.width, self.height);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder
+                .write_header()
+                .map_err(|e| format!("Failed to write PNG header: {}", e))?;
+            writer
+                .write_image_data(&self.pixels)
+                .map_err(|e| format!("Failed to write PNG data: {}", e))?;
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a PNG (any color type) into RGBA8
+    pub fn decode_png(bytes: &[u8]) -> Result<Self, String> {
+        let mut decoder = png::Decoder::new(bytes);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info().map_err(|e| format!("Failed to read PNG: {}", e))?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let frame = reader
+            .next_frame(&mut buffer)
+            .map_err(|e| format!("Failed to decode PNG: {}", e))?;
+        buffer.truncate(frame.buffer_size());
+        let pixels = match frame.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+            png::ColorType::GrayscaleAlpha => buffer.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+            png::ColorType::Indexed => return Err("Indexed PNG was not expanded".to_string()),
+        };
+        Self::from_raw(frame.width, frame.height, pixels)
+    }
+
+    /// Copy out a region (clamped to the image)
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        let x = x.min(self.width);
+        let y = y.min(self.height);
+        let width = width.min(self.width - x);
+        let height = height.min(self.height - y);
+        let stride = self.width as usize * 4;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for row in y..y + height {
+            let start = row as usize * stride + x as usize * 4;
+            pixels.extend_from_slice(&self.pixels[start..start + width as usize * 4]);
+        }
+        Self { width, height, pixels }
+    }
+
+    /// Compare against a baseline, pixel by pixel 🔍
+    /// Channels within `tolerance` count as equal (anti-aliasing noise)
+    pub fn diff(&self, baseline: &RgbaImage, tolerance: u8) -> Result<ImageDiff, String> {
+        if (self.width, self.height) != (baseline.width, baseline.height) {
+            return Err(format!(
+                "Baseline is {}x{}, capture is {}x{}",
+                baseline.width, baseline.height, self.width, self.height
+            ));
+        }
+        let changed: Vec<bool> = self
+            .pixels
+            .chunks_exact(4)
+            .zip(baseline.pixels.chunks_exact(4))
+            .map(|(a, b)| a.iter().zip(b).any(|(&a, &b)| a.abs_diff(b) > tolerance))
+            .collect();
+        Ok(ImageDiff { width: self.width, height: self.height, changed })
+    }
+
+    /// Save as a PNG file
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let bytes = self.encode_png()?;
+        std::fs::write(path, bytes)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        info!("📸 Saved {}x{} screenshot to {}", self.width, self.height, path.display());
+        Ok(())
+    }
+}
+
+/// Which pixels changed between a capture and its baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDiff {
+    pub width: u32,
+    pub height: u32,
+    /// One flag per pixel, rows top to bottom
+    pub changed: Vec<bool>,
+}
+
+impl ImageDiff {
+    /// Nothing changed?
+    pub fn is_identical(&self) -> bool {
+        !self.changed.contains(&true)
+    }
+
+    /// Number of changed pixels
+    pub fn changed_pixels(&self) -> usize {
+        self.changed.iter().filter(|&&changed| changed).count()
+    }
+
+    /// Changed share of the image (0.0 - 1.0)
+    pub fn changed_ratio(&self) -> f32 {
+        if self.changed.is_empty() {
+            return 0.0;
+        }
+        self.changed_pixels() as f32 / self.changed.len() as f32
+    }
+
+    /// Smallest (x, y, width, height) box holding every change
+    pub fn bounds(&self) -> Option<(u32, u32, u32, u32)> {
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (u32::MAX, u32::MAX, 0, 0);
+        for (x, y, length) in self.runs() {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x + length);
+            max_y = max_y.max(y + 1);
+        }
+        (min_x != u32::MAX).then(|| (min_x, min_y, max_x - min_x, max_y - min_y))
+    }
+
+    /// Horizontal runs of changed pixels as (x, y, length)
+    /// Cheap to draw as rects over the live window
+    pub fn runs(&self) -> Vec<(u32, u32, u32)> {
+        let mut runs = Vec::new();
+        if self.width == 0 {
+            return runs;
+        }
+        for (y, row) in self.changed.chunks_exact(self.width as usize).enumerate() {
+            let mut start = None;
+            for (x, &changed) in row.iter().chain(std::iter::once(&false)).enumerate() {
+                match (changed, start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(first)) => {
+                        runs.push((first as u32, y as u32, (x - first) as u32));
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        runs
+    }
+
+    /// The capture faded to gray with changed pixels in hot magenta
+    pub fn highlight(&self, capture: &RgbaImage) -> RgbaImage {
+        let pixels = capture
+            .pixels
+            .chunks_exact(4)
+            .zip(&self.changed)
+            .flat_map(|(p, &changed)| {
+                if changed {
+                    [255, 0, 170, 255]
+                } else {
+                    let gray = ((p[0] as u32 * 3 + p[1] as u32 * 6 + p[2] as u32) / 10) as u8;
+                    let faded = 160 + gray / 3;
+                    [faded, faded, faded, 255]
+                }
+            })
+            .collect();
+        RgbaImage { width: capture.width, height: capture.height, pixels }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_raw_checks_length() {
+        assert!(RgbaImage::from_raw(2, 2, vec![0; 16]).is_ok());
+        assert!(RgbaImage::from_raw(2, 2, vec![0; 15]).is_err());
+    }
+
+    #[test]
+    fn from_argb_converts_channels() {
+        let image = RgbaImage::from_argb(1, 1, &[0x00FF8040]).unwrap();
+        assert_eq!(image.pixel(0, 0), Some([255, 128, 64, 255]));
+        assert_eq!(image.pixel(1, 0), None);
+    }
+
+    #[test]
+    fn flip_vertical_swaps_rows() {
+        let mut image = RgbaImage::from_raw(1, 3, vec![
+            1, 1, 1, 1,
+            2, 2, 2, 2,
+            3, 3, 3, 3,
+        ]).unwrap();
+        image.flip_vertical();
+        assert_eq!(image.pixel(0, 0), Some([3, 3, 3, 3]));
+        assert_eq!(image.pixel(0, 1), Some([2, 2, 2, 2]));
+        assert_eq!(image.pixel(0, 2), Some([1, 1, 1, 1]));
+    }
+
+    #[test]
+    fn encode_png_has_signature() {
+        let bytes = RgbaImage::new(4, 4).encode_png().unwrap();
+        assert_eq!(&bytes[..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+    }
+
+    #[test]
+    fn png_round_trip_and_crop() {
+        let pixels = (0..4 * 3).flat_map(|i| [i as u8, 0, 0, 255]).collect();
+        let image = RgbaImage::from_raw(4, 3, pixels).unwrap();
+        let decoded = RgbaImage::decode_png(&image.encode_png().unwrap()).unwrap();
+        assert_eq!(decoded, image);
+
+        let cropped = image.crop(1, 1, 2, 5);
+        assert_eq!((cropped.width, cropped.height), (2, 2));
+        assert_eq!(cropped.pixel(0, 0), Some([5, 0, 0, 255]));
+        assert_eq!(cropped.pixel(1, 1), Some([10, 0, 0, 255]));
+    }
+
+    #[test]
+    fn diff_finds_changed_pixels() {
+        let baseline = RgbaImage::from_raw(4, 2, vec![100; 32]).unwrap();
+        let mut capture = baseline.clone();
+        capture.pixels[4] = 102; // (1, 0) within tolerance
+        capture.pixels[5 * 4] = 200; // (1, 1)
+        capture.pixels[6 * 4 + 2] = 0; // (2, 1)
+
+        let diff = capture.diff(&baseline, 4).unwrap();
+        assert_eq!(diff.changed_pixels(), 2);
+        assert_eq!(diff.runs(), vec![(1, 1, 2)]);
+        assert_eq!(diff.bounds(), Some((1, 1, 2, 1)));
+        assert_eq!(diff.highlight(&capture).pixel(2, 1), Some([255, 0, 170, 255]));
+        assert!(capture.diff(&baseline, 255).unwrap().is_identical());
+        assert!(capture.diff(&RgbaImage::new(2, 2), 0).is_err());
+    }
+}
@@ -0,0 +1,412 @@
+/// Handle to an image uploaded to a backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ImageId(pub u32);
+
+/// RGBA color as used by draw commands (straight alpha)
+pub type Rgba = (u8, u8, u8, u8);
+
+/// Rect as (x, y, width, height) in pixels, origin top-left
+pub type Rect = (f32, f32, f32, f32);
+
+/// One glyph's coverage mask (0-255), placed relative to the top-left of the line box
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GlyphMask {
+    pub x: i32,
+    pub y: i32,
+    pub width: usize,
+    pub coverage: Vec<u8>,
+}
+
+/// Turns a line of text at a pixel size into glyph masks
+///
+/// Native backends have no font stack of their own, so the app plugs one in
+/// (e.g. nebula-core's TextRenderer); `Text` commands are skipped without it.
+pub type TextRasterizer = Box<dyn FnMut(&str, f32) -> Vec<GlyphMask> + Send>;
+
+/// One recorded drawing operation
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    /// Fill the whole target
+    Clear(Rgba),
+    /// Filled rectangle
+    Rect { rect: Rect, color: Rgba },
+    /// Filled rectangle with rounded corners
+    RoundedRect { rect: Rect, radius: f32, color: Rgba },
+    /// Single line of text, `(x, y)` is the top-left of the line box
+    Text { text: String, x: f32, y: f32, size: f32, color: Rgba },
+    /// Image (or part of it) stretched over a rect
+    /// `src` is in image pixels; None draws the whole image
+    Image { image: ImageId, src: Option<Rect>, dst: Rect, opacity: f32 },
+    /// Intersect the clip with a rect until the matching PopClip
+    PushClip(Rect),
+    PopClip,
+}
+
+/// Backend-independent list of drawing commands 📝
+///
+/// Record once, replay on any backend — the web renderers (WebGL2 and
+/// Canvas2D) both consume the same list, so a frame looks the same
+/// whichever one the browser gave us.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut list = DrawList::new();
+/// list.clear((10, 14, 23, 255))
+///     .rounded_rect((20.0, 20.0, 200.0, 48.0), 8.0, (59, 130, 246, 255))
+///     .text("Hello, web!", 36.0, 32.0, 16.0, (255, 255, 255, 255));
+/// renderer.render(&list)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrawList {
+    commands: Vec<DrawCommand>,
+    clip_depth: usize,
+}
+
+impl DrawList {
+    /// Create an empty list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a command
+    pub fn push(&mut self, command: DrawCommand) -> &mut Self {
+        match command {
+            DrawCommand::PushClip(_) => self.clip_depth += 1,
+            DrawCommand::PopClip => {
+                // Unbalanced pops are dropped so backends never underflow
+                if self.clip_depth == 0 {
+                    return self;
+                }
+                self.clip_depth -= 1;
+            }
+            _ => {}
+        }
+        self.commands.push(command);
+        self
+    }
+
+    /// Fill the whole target
+    pub fn clear(&mut self, color: Rgba) -> &mut Self {
+        self.push(DrawCommand::Clear(color))
+    }
+
+    /// Filled rectangle
+    pub fn rect(&mut self, rect: Rect, color: Rgba) -> &mut Self {
+        self.push(DrawCommand::Rect { rect, color })
+    }
+
+    /// Filled rectangle with rounded corners
+    pub fn rounded_rect(&mut self, rect: Rect, radius: f32, color: Rgba) -> &mut Self {
+        if radius <= 0.0 {
+            return self.rect(rect, color);
+        }
+        self.push(DrawCommand::RoundedRect { rect, radius, color })
+    }
+
+    /// Single line of text
+    pub fn text(&mut self, text: impl Into<String>, x: f32, y: f32, size: f32, color: Rgba) -> &mut Self {
+        self.push(DrawCommand::Text { text: text.into(), x, y, size, color })
+    }
+
+    /// Whole image stretched over a rect
+    pub fn image(&mut self, image: ImageId, dst: Rect) -> &mut Self {
+        self.push(DrawCommand::Image { image, src: None, dst, opacity: 1.0 })
+    }
+
+    /// Part of an image stretched over a rect, with opacity
+    pub fn image_region(&mut self, image: ImageId, src: Rect, dst: Rect, opacity: f32) -> &mut Self {
+        self.push(DrawCommand::Image { image, src: Some(src), dst, opacity: opacity.clamp(0.0, 1.0) })
+    }
+
+    /// Start clipping to a rect
+    pub fn push_clip(&mut self, rect: Rect) -> &mut Self {
+        self.push(DrawCommand::PushClip(rect))
+    }
+
+    /// End the innermost clip
+    pub fn pop_clip(&mut self) -> &mut Self {
+        self.push(DrawCommand::PopClip)
+    }
+
+    /// Recorded commands, with any still-open clips closed
+    pub fn commands(&self) -> impl Iterator<Item = &DrawCommand> {
+        self.commands.iter().chain(std::iter::repeat_n(&DrawCommand::PopClip, self.clip_depth))
+    }
+
+    /// Number of recorded commands
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Is the list empty?
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Remove every command, keeping the allocation for the next frame
+    pub fn reset(&mut self) {
+        self.commands.clear();
+        self.clip_depth = 0;
+    }
+
+    /// Scale (around `origin`) and fade the commands recorded since `start`
+    ///
+    /// Used for group effects like a widget's opacity or a hover zoom: paint
+    /// the group, then transform everything it recorded.
+    pub fn transform_since(&mut self, start: usize, origin: (f32, f32), scale: f32, opacity: f32) {
+        let opacity = opacity.clamp(0.0, 1.0);
+        let point = |x: f32, y: f32| (origin.0 + (x - origin.0) * scale, origin.1 + (y - origin.1) * scale);
+        let rect = |(x, y, width, height): Rect| {
+            let (x, y) = point(x, y);
+            (x, y, width * scale, height * scale)
+        };
+        let fade = |color: Rgba| (color.0, color.1, color.2, (color.3 as f32 * opacity).round() as u8);
+        for command in self.commands.iter_mut().skip(start) {
+            match command {
+                DrawCommand::Clear(_) | DrawCommand::PopClip => {}
+                DrawCommand::Rect { rect: r, color } => {
+                    *r = rect(*r);
+                    *color = fade(*color);
+                }
+                DrawCommand::RoundedRect { rect: r, radius, color } => {
+                    *r = rect(*r);
+                    *radius *= scale;
+                    *color = fade(*color);
+                }
+                DrawCommand::Text { x, y, size, color, .. } => {
+                    (*x, *y) = point(*x, *y);
+                    *size *= scale;
+                    *color = fade(*color);
+                }
+                DrawCommand::Image { dst, opacity: image_opacity, .. } => {
+                    *dst = rect(*dst);
+                    *image_opacity *= opacity;
+                }
+                DrawCommand::PushClip(r) => *r = rect(*r),
+            }
+        }
+    }
+
+    /// Images referenced by the list (so backends can check uploads up front)
+    pub fn images(&self) -> Vec<ImageId> {
+        let mut images: Vec<ImageId> = self
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Image { image, .. } => Some(*image),
+                _ => None,
+            })
+            .collect();
+        images.sort();
+        images.dedup();
+        images
+    }
+
+    /// Drop commands entirely hidden under later opaque rects 🙈
+    ///
+    /// Content under an opaque Modal or a full-bleed panel never reaches
+    /// the screen, but every backend would still fill it — on the CPU tier
+    /// fill rate is the bottleneck, so run this before rasterizing.
+    /// Returns the number of commands removed.
+    ///
+    /// Only plain opaque `Rect`s occlude (rounded corners and images may be
+    /// see-through). Each command is compared after clipping, so an occluder
+    /// only counts where its own clip lets it draw.
+    pub fn cull_occluded(&mut self) -> usize {
+        let mut occluded = Vec::new();
+        let culled = self.mark_occluded(&mut occluded);
+        let mut flags = occluded.into_iter();
+        self.commands.retain(|_| !flags.next().unwrap_or(false));
+        culled
+    }
+
+    /// Flag the commands `cull_occluded` would drop, leaving the list as is
+    ///
+    /// `occluded` is refilled with one flag per recorded command, so a
+    /// backend can keep the allocation across frames and skip flagged
+    /// commands while replaying. Returns the number flagged.
+    pub fn mark_occluded(&self, occluded: &mut Vec<bool>) -> usize {
+        let visible = self.visible_bounds();
+        let mut occluders: Vec<Rect> = Vec::new();
+        occluded.clear();
+        occluded.resize(self.commands.len(), false);
+        for (index, command) in self.commands.iter().enumerate().rev() {
+            let Some(bounds) = visible[index] else { continue };
+            if occluders.iter().any(|&occluder| contains_rect(occluder, bounds)) {
+                occluded[index] = true;
+                continue;
+            }
+            if let DrawCommand::Rect { color: (_, _, _, 255), .. } = command {
+                if bounds.2 > 0.0 && bounds.3 > 0.0 && occluders.len() < MAX_OCCLUDERS {
+                    occluders.push(bounds);
+                }
+            }
+        }
+        occluded.iter().filter(|&&hidden| hidden).count()
+    }
+
+    /// Total pixels the list fills after clipping (overdraw counted each time)
+    pub fn painted_area(&self) -> f32 {
+        self.visible_bounds().into_iter().flatten().map(|(_, _, width, height)| width * height).sum()
+    }
+
+    /// Clipped on-screen bounds of every drawing command
+    /// None for Clear and clip commands, which are never culled
+    fn visible_bounds(&self) -> Vec<Option<Rect>> {
+        let mut clips = ClipStack::new();
+        self.commands
+            .iter()
+            .map(|command| {
+                let bounds = match command {
+                    DrawCommand::Clear(_) => return None,
+                    DrawCommand::PushClip(rect) => {
+                        clips.push(*rect);
+                        return None;
+                    }
+                    DrawCommand::PopClip => {
+                        clips.pop();
+                        return None;
+                    }
+                    DrawCommand::Rect { rect, .. } | DrawCommand::RoundedRect { rect, .. } => *rect,
+                    DrawCommand::Image { dst, .. } => *dst,
+                    // No font metrics here, so assume every glyph is a full em wide
+                    DrawCommand::Text { text, x, y, size, .. } => (*x, *y, text.chars().count() as f32 * size, size * 1.5),
+                };
+                Some(match clips.current() {
+                    Some(clip) => intersect_rects(clip, bounds),
+                    None => bounds,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Occluders tracked per cull pass (the topmost ones matter most)
+const MAX_OCCLUDERS: usize = 64;
+
+/// Is `inner` entirely inside `outer`?
+fn contains_rect(outer: Rect, inner: Rect) -> bool {
+    inner.0 >= outer.0 && inner.1 >= outer.1 && inner.0 + inner.2 <= outer.0 + outer.2 && inner.1 + inner.3 <= outer.1 + outer.3
+}
+
+/// Intersection of two rects (empty rects have zero size)
+pub fn intersect_rects(a: Rect, b: Rect) -> Rect {
+    let x = a.0.max(b.0);
+    let y = a.1.max(b.1);
+    let right = (a.0 + a.2).min(b.0 + b.2);
+    let bottom = (a.1 + a.3).min(b.1 + b.3);
+    (x, y, (right - x).max(0.0), (bottom - y).max(0.0))
+}
+
+/// Nested clip rects, each intersected with its parent
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClipStack {
+    rects: Vec<Rect>,
+}
+
+impl ClipStack {
+    /// Create an empty stack (no clipping)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a clip, returning the effective clip rect
+    pub fn push(&mut self, rect: Rect) -> Rect {
+        let rect = match self.rects.last() {
+            Some(&parent) => intersect_rects(parent, rect),
+            None => rect,
+        };
+        self.rects.push(rect);
+        rect
+    }
+
+    /// Pop the innermost clip, returning the clip now in effect
+    pub fn pop(&mut self) -> Option<Rect> {
+        self.rects.pop();
+        self.current()
+    }
+
+    /// Effective clip rect (None = unclipped)
+    pub fn current(&self) -> Option<Rect> {
+        self.rects.last().copied()
+    }
+
+    /// Number of pushed clips
+    pub fn depth(&self) -> usize {
+        self.rects.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_list_records_in_order() {
+        let mut list = DrawList::new();
+        list.clear((0, 0, 0, 255))
+            .rect((0.0, 0.0, 10.0, 10.0), (255, 0, 0, 255))
+            .rounded_rect((0.0, 0.0, 10.0, 10.0), 0.0, (0, 255, 0, 255))
+            .image(ImageId(2), (0.0, 0.0, 4.0, 4.0))
+            .image(ImageId(1), (0.0, 0.0, 4.0, 4.0))
+            .image(ImageId(2), (4.0, 0.0, 4.0, 4.0));
+
+        assert_eq!(list.len(), 6);
+        // Zero radius degrades to a plain rect
+        assert!(matches!(list.commands().nth(2), Some(DrawCommand::Rect { .. })));
+        assert_eq!(list.images(), vec![ImageId(1), ImageId(2)]);
+
+        list.reset();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn draw_list_balances_clips() {
+        let mut list = DrawList::new();
+        list.pop_clip().push_clip((0.0, 0.0, 5.0, 5.0)).push_clip((1.0, 1.0, 2.0, 2.0)).pop_clip();
+        assert_eq!(list.len(), 3);
+
+        let commands: Vec<&DrawCommand> = list.commands().collect();
+        assert_eq!(commands.len(), 4);
+        assert_eq!(commands[3], &DrawCommand::PopClip);
+    }
+
+    #[test]
+    fn cull_drops_content_under_opaque_modal() {
+        let mut list = DrawList::new();
+        list.clear((0, 0, 0, 255))
+            .rect((0.0, 0.0, 100.0, 100.0), (255, 255, 255, 255))
+            .text("Hi", 10.0, 10.0, 10.0, (0, 0, 0, 255))
+            // Sticks out of the modal, so it stays
+            .rect((150.0, 150.0, 100.0, 100.0), (0, 255, 0, 255))
+            // Clipped down to the covered area, so it goes
+            .push_clip((0.0, 0.0, 50.0, 50.0))
+            .rect((0.0, 0.0, 300.0, 300.0), (0, 0, 255, 255))
+            .pop_clip()
+            // Modal backdrop is translucent, the dialog is opaque
+            .rect((0.0, 0.0, 400.0, 400.0), (0, 0, 0, 128))
+            .rect((0.0, 0.0, 200.0, 200.0), (255, 255, 255, 255));
+
+        let before = list.painted_area();
+        let mut occluded = Vec::new();
+        assert_eq!(list.mark_occluded(&mut occluded), 3);
+        assert_eq!(occluded, [false, true, true, false, false, true, false, false, false]);
+        assert_eq!(list.len(), 9);
+        assert_eq!(list.cull_occluded(), 3);
+        assert!(list.painted_area() < before);
+        let commands: Vec<&DrawCommand> = list.commands().collect();
+        assert_eq!(commands.len(), 6);
+        assert!(matches!(commands[1], DrawCommand::Rect { color: (0, 255, 0, 255), .. }));
+    }
+
+    #[test]
+    fn clip_stack_intersects() {
+        let mut clips = ClipStack::new();
+        assert_eq!(clips.push((0.0, 0.0, 100.0, 100.0)), (0.0, 0.0, 100.0, 100.0));
+        assert_eq!(clips.push((50.0, 50.0, 100.0, 100.0)), (50.0, 50.0, 50.0, 50.0));
+        assert_eq!(clips.push((200.0, 0.0, 10.0, 10.0)).2, 0.0);
+        assert_eq!(clips.pop(), Some((50.0, 50.0, 50.0, 50.0)));
+        clips.pop();
+        assert_eq!(clips.pop(), None);
+    }
+}
@@ -26,9 +26,29 @@
 
 pub mod renderer;
 pub mod backend;
+pub mod capabilities;
+pub mod capture;
+pub mod path;
+pub mod nine_patch;
+pub mod opacity;
+pub mod draw_list;
+pub mod print;
+pub mod stream;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod render_thread;
 
-pub use renderer::{Renderer, Color};
+pub use renderer::{Renderer, Color, RenderError, RenderTargetId};
+pub use capture::{ImageDiff, RgbaImage};
+pub use print::{PageOrientation, PaperSize, PrintJob};
+pub use path::{FillRule, Path, PathBuilder};
+pub use nine_patch::{nine_patch_slices, NinePatchInsets, NineSlice};
+pub use opacity::OpacityStack;
+pub use draw_list::{ClipStack, DrawCommand, DrawList, ImageId};
+pub use stream::StreamingTexture;
 pub use backend::{Backend, RendererBuilder};
+pub use capabilities::RendererCapabilities;
+#[cfg(not(target_arch = "wasm32"))]
+pub use render_thread::RenderThread;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,134 @@
+/// Fixed border widths of a nine-patch image, in source pixels
+///
+/// The corners are drawn unscaled, the edges stretch along one axis,
+/// and the center stretches in both.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NinePatchInsets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl NinePatchInsets {
+    /// Create insets from each side
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self { left, top, right, bottom }
+    }
+
+    /// Same inset on every side
+    pub fn uniform(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
+
+/// One of the nine pieces: where to read from and where to draw to
+/// Rects are (x, y, width, height), origin top-left
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NineSlice {
+    pub src: (f32, f32, f32, f32),
+    pub dst: (f32, f32, f32, f32),
+}
+
+/// Split an image into nine slices stretched over a destination rect 🧩
+///
+/// If the destination is smaller than the fixed borders, the borders shrink
+/// proportionally instead of overlapping. Empty slices are skipped.
+pub fn nine_patch_slices(
+    image_width: u32,
+    image_height: u32,
+    insets: NinePatchInsets,
+    dst: (f32, f32, f32, f32),
+) -> Vec<NineSlice> {
+    let (image_width, image_height) = (image_width as f32, image_height as f32);
+    let (dx, dy, dw, dh) = dst;
+
+    // Source borders can't exceed the image
+    let src_left = insets.left.clamp(0.0, image_width);
+    let src_right = insets.right.clamp(0.0, image_width - src_left);
+    let src_top = insets.top.clamp(0.0, image_height);
+    let src_bottom = insets.bottom.clamp(0.0, image_height - src_top);
+
+    // Destination borders shrink when the rect is too small
+    let shrink = |a: f32, b: f32, available: f32| {
+        if a + b > available && a + b > 0.0 {
+            let scale = available.max(0.0) / (a + b);
+            (a * scale, b * scale)
+        } else {
+            (a, b)
+        }
+    };
+    let (dst_left, dst_right) = shrink(src_left, src_right, dw);
+    let (dst_top, dst_bottom) = shrink(src_top, src_bottom, dh);
+
+    let src_cols = [
+        (0.0, src_left),
+        (src_left, image_width - src_left - src_right),
+        (image_width - src_right, src_right),
+    ];
+    let src_rows = [
+        (0.0, src_top),
+        (src_top, image_height - src_top - src_bottom),
+        (image_height - src_bottom, src_bottom),
+    ];
+    let dst_cols = [
+        (dx, dst_left),
+        (dx + dst_left, dw - dst_left - dst_right),
+        (dx + dw - dst_right, dst_right),
+    ];
+    let dst_rows = [
+        (dy, dst_top),
+        (dy + dst_top, dh - dst_top - dst_bottom),
+        (dy + dh - dst_bottom, dst_bottom),
+    ];
+
+    let mut slices = Vec::with_capacity(9);
+    for row in 0..3 {
+        for col in 0..3 {
+            let (sx, sw) = src_cols[col];
+            let (sy, sh) = src_rows[row];
+            let (x, w) = dst_cols[col];
+            let (y, h) = dst_rows[row];
+            if sw <= 0.0 || sh <= 0.0 || w <= 0.0 || h <= 0.0 {
+                continue;
+            }
+            slices.push(NineSlice {
+                src: (sx, sy, sw, sh),
+                dst: (x, y, w, h),
+            });
+        }
+    }
+    slices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nine_patch_corners_keep_size() {
+        let slices = nine_patch_slices(30, 30, NinePatchInsets::uniform(10.0), (0.0, 0.0, 100.0, 50.0));
+        assert_eq!(slices.len(), 9);
+        // Top-left corner is unscaled
+        assert_eq!(slices[0], NineSlice { src: (0.0, 0.0, 10.0, 10.0), dst: (0.0, 0.0, 10.0, 10.0) });
+        // Center stretches in both directions
+        assert_eq!(slices[4], NineSlice { src: (10.0, 10.0, 10.0, 10.0), dst: (10.0, 10.0, 80.0, 30.0) });
+        // Bottom-right corner is pinned to the far edge
+        assert_eq!(slices[8].dst, (90.0, 40.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn nine_patch_shrinks_borders_when_too_small() {
+        let slices = nine_patch_slices(30, 30, NinePatchInsets::uniform(10.0), (0.0, 0.0, 10.0, 40.0));
+        // No room for the middle column, left and right halve
+        assert_eq!(slices.len(), 6);
+        assert_eq!(slices[0].dst, (0.0, 0.0, 5.0, 10.0));
+        assert_eq!(slices[1].dst, (5.0, 0.0, 5.0, 10.0));
+    }
+
+    #[test]
+    fn nine_patch_zero_insets_is_single_stretch() {
+        let slices = nine_patch_slices(8, 8, NinePatchInsets::default(), (5.0, 5.0, 20.0, 20.0));
+        assert_eq!(slices, vec![NineSlice { src: (0.0, 0.0, 8.0, 8.0), dst: (5.0, 5.0, 20.0, 20.0) }]);
+    }
+}
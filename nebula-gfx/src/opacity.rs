@@ -0,0 +1,84 @@
+/// Nested group opacity 👻
+///
+/// Each `push` multiplies into the effective opacity; `pop` restores the
+/// previous level. Backends pair every push with an offscreen layer so
+/// overlapping children blend with each other first and then fade as
+/// one group, instead of each primitive fading on its own.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OpacityStack {
+    levels: Vec<f32>,
+}
+
+impl OpacityStack {
+    /// Create an empty stack (effective opacity 1.0)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a group opacity (clamped to 0.0..=1.0), returning the new effective opacity
+    pub fn push(&mut self, opacity: f32) -> f32 {
+        let opacity = if opacity.is_nan() { 1.0 } else { opacity.clamp(0.0, 1.0) };
+        self.levels.push(opacity);
+        self.current()
+    }
+
+    /// Pop the innermost group opacity
+    /// Returns None if the stack was already empty
+    pub fn pop(&mut self) -> Option<f32> {
+        self.levels.pop()
+    }
+
+    /// Opacity of the innermost group alone
+    pub fn top(&self) -> f32 {
+        self.levels.last().copied().unwrap_or(1.0)
+    }
+
+    /// Effective opacity (product of every level)
+    pub fn current(&self) -> f32 {
+        self.levels.iter().product()
+    }
+
+    /// Number of pushed levels
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Is nothing pushed?
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Scale an 8-bit alpha by the effective opacity (for per-primitive fading)
+    pub fn apply(&self, alpha: u8) -> u8 {
+        (alpha as f32 * self.current()).round() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opacity_stack_multiplies_levels() {
+        let mut stack = OpacityStack::new();
+        assert_eq!(stack.current(), 1.0);
+        assert_eq!(stack.push(0.5), 0.5);
+        assert_eq!(stack.push(0.5), 0.25);
+        assert_eq!(stack.top(), 0.5);
+        assert_eq!(stack.apply(200), 50);
+
+        assert_eq!(stack.pop(), Some(0.5));
+        assert_eq!(stack.current(), 0.5);
+        stack.pop();
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn opacity_stack_clamps() {
+        let mut stack = OpacityStack::new();
+        assert_eq!(stack.push(2.0), 1.0);
+        assert_eq!(stack.push(-1.0), 0.0);
+        assert_eq!(stack.depth(), 2);
+    }
+}
@@ -0,0 +1,356 @@
+/// A single path drawing command
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    /// Control point, end point
+    QuadTo(f32, f32, f32, f32),
+    /// Two control points, end point
+    CubicTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+/// How overlapping parts of a path are filled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Inside if the winding number is non-zero (SVG default)
+    #[default]
+    NonZero,
+    /// Inside if a ray crosses an odd number of edges
+    EvenOdd,
+}
+
+/// Vector path built from lines and bezier curves ✏️
+///
+/// Coordinates are in pixels with the origin at the top-left.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    pub commands: Vec<PathCommand>,
+    pub fill_rule: FillRule,
+}
+
+/// A flattened sub-path
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<(f32, f32)>,
+    pub closed: bool,
+}
+
+impl Path {
+    /// Set the fill rule
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Does the path have any commands?
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Bounding box of all points, including control points (x, y, width, height)
+    pub fn bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let mut points = Vec::new();
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(x, y) | PathCommand::LineTo(x, y) => points.push((x, y)),
+                PathCommand::QuadTo(cx, cy, x, y) => points.extend([(cx, cy), (x, y)]),
+                PathCommand::CubicTo(c1x, c1y, c2x, c2y, x, y) => {
+                    points.extend([(c1x, c1y), (c2x, c2y), (x, y)])
+                }
+                PathCommand::Close => {}
+            }
+        }
+
+        let first = *points.first()?;
+        let (min, max) = points.iter().fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        });
+        Some((min.0, min.1, max.0 - min.0, max.1 - min.1))
+    }
+
+    /// Convert curves to line segments
+    /// `tolerance` is the maximum distance (in pixels) from the true curve
+    pub fn flatten(&self, tolerance: f32) -> Vec<Polyline> {
+        let tolerance = tolerance.max(0.01);
+        let mut polylines = Vec::new();
+        let mut current: Vec<(f32, f32)> = Vec::new();
+        let mut start = (0.0, 0.0);
+
+        let finish = |points: &mut Vec<(f32, f32)>, closed: bool, polylines: &mut Vec<Polyline>| {
+            if points.len() > 1 {
+                polylines.push(Polyline { points: std::mem::take(points), closed });
+            } else {
+                points.clear();
+            }
+        };
+
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(x, y) => {
+                    finish(&mut current, false, &mut polylines);
+                    start = (x, y);
+                    current.push(start);
+                }
+                PathCommand::LineTo(x, y) => {
+                    if current.is_empty() {
+                        current.push(start);
+                    }
+                    current.push((x, y));
+                }
+                PathCommand::QuadTo(cx, cy, x, y) => {
+                    if current.is_empty() {
+                        current.push(start);
+                    }
+                    let p0 = *current.last().unwrap_or(&start);
+                    let dd = length(p0.0 - 2.0 * cx + x, p0.1 - 2.0 * cy + y);
+                    let segments = segment_count(dd / (4.0 * tolerance));
+                    for i in 1..=segments {
+                        let t = i as f32 / segments as f32;
+                        let mt = 1.0 - t;
+                        current.push((
+                            mt * mt * p0.0 + 2.0 * mt * t * cx + t * t * x,
+                            mt * mt * p0.1 + 2.0 * mt * t * cy + t * t * y,
+                        ));
+                    }
+                }
+                PathCommand::CubicTo(c1x, c1y, c2x, c2y, x, y) => {
+                    if current.is_empty() {
+                        current.push(start);
+                    }
+                    let p0 = *current.last().unwrap_or(&start);
+                    let dd = length(p0.0 - 2.0 * c1x + c2x, p0.1 - 2.0 * c1y + c2y)
+                        .max(length(c1x - 2.0 * c2x + x, c1y - 2.0 * c2y + y));
+                    let segments = segment_count(0.75 * dd / tolerance);
+                    for i in 1..=segments {
+                        let t = i as f32 / segments as f32;
+                        let mt = 1.0 - t;
+                        let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+                        current.push((
+                            a * p0.0 + b * c1x + c * c2x + d * x,
+                            a * p0.1 + b * c1y + c * c2y + d * y,
+                        ));
+                    }
+                }
+                PathCommand::Close => {
+                    if let Some(&first) = current.first() {
+                        start = first;
+                    }
+                    finish(&mut current, true, &mut polylines);
+                }
+            }
+        }
+        finish(&mut current, false, &mut polylines);
+
+        polylines
+    }
+}
+
+fn length(x: f32, y: f32) -> f32 {
+    (x * x + y * y).sqrt()
+}
+
+/// Wang's formula: segments = ceil(sqrt(estimate)), capped for huge curves
+fn segment_count(estimate: f32) -> usize {
+    (estimate.sqrt().ceil() as usize).clamp(1, 256)
+}
+
+/// Builds a Path one command at a time
+///
+/// # Example
+/// ```rust,ignore
+/// let path = PathBuilder::new()
+///     .move_to(10.0, 10.0)
+///     .line_to(90.0, 10.0)
+///     .quad_to(90.0, 90.0, 10.0, 90.0)
+///     .close()
+///     .build();
+/// renderer.draw_path(&path, Some(Color::NEBULA_BLUE), Some((Color::WHITE, 2.0)))?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathBuilder {
+    path: Path,
+}
+
+impl PathBuilder {
+    /// Start an empty path
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new sub-path at a point
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.path.commands.push(PathCommand::MoveTo(x, y));
+        self
+    }
+
+    /// Straight line to a point
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.path.commands.push(PathCommand::LineTo(x, y));
+        self
+    }
+
+    /// Quadratic bezier through one control point
+    pub fn quad_to(mut self, cx: f32, cy: f32, x: f32, y: f32) -> Self {
+        self.path.commands.push(PathCommand::QuadTo(cx, cy, x, y));
+        self
+    }
+
+    /// Cubic bezier through two control points
+    pub fn cubic_to(mut self, c1x: f32, c1y: f32, c2x: f32, c2y: f32, x: f32, y: f32) -> Self {
+        self.path.commands.push(PathCommand::CubicTo(c1x, c1y, c2x, c2y, x, y));
+        self
+    }
+
+    /// Close the current sub-path
+    pub fn close(mut self) -> Self {
+        self.path.commands.push(PathCommand::Close);
+        self
+    }
+
+    /// Axis-aligned rectangle as a closed sub-path
+    pub fn rect(self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.move_to(x, y)
+            .line_to(x + width, y)
+            .line_to(x + width, y + height)
+            .line_to(x, y + height)
+            .close()
+    }
+
+    /// Rectangle with rounded corners as a closed sub-path
+    /// The radius is limited to half the shorter side
+    pub fn rounded_rect(self, x: f32, y: f32, width: f32, height: f32, radius: f32) -> Self {
+        let r = radius.clamp(0.0, (width.min(height) / 2.0).max(0.0));
+        if r == 0.0 {
+            return self.rect(x, y, width, height);
+        }
+        // Distance of the cubic control points that best approximates a quarter circle
+        let k = r * 0.552_284_8;
+        let (right, bottom) = (x + width, y + height);
+        self.move_to(x + r, y)
+            .line_to(right - r, y)
+            .cubic_to(right - r + k, y, right, y + r - k, right, y + r)
+            .line_to(right, bottom - r)
+            .cubic_to(right, bottom - r + k, right - r + k, bottom, right - r, bottom)
+            .line_to(x + r, bottom)
+            .cubic_to(x + r - k, bottom, x, bottom - r + k, x, bottom - r)
+            .line_to(x, y + r)
+            .cubic_to(x, y + r - k, x + r - k, y, x + r, y)
+            .close()
+    }
+
+    /// Set the fill rule
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.path.fill_rule = fill_rule;
+        self
+    }
+
+    /// Finish the path
+    pub fn build(self) -> Path {
+        self.path
+    }
+}
+
+/// Triangles covering a polyline's fan from its first point
+/// Drawn into the stencil buffer, these mark the fill area for any fill rule
+pub fn fan_triangles(polyline: &Polyline) -> Vec<(f32, f32)> {
+    let points = &polyline.points;
+    let mut triangles = Vec::with_capacity(points.len().saturating_sub(2) * 3);
+    for i in 1..points.len().saturating_sub(1) {
+        triangles.extend([points[0], points[i], points[i + 1]]);
+    }
+    triangles
+}
+
+/// Triangles for a stroke of the given width (butt caps, no joins)
+pub fn stroke_triangles(polyline: &Polyline, width: f32) -> Vec<(f32, f32)> {
+    let half = width / 2.0;
+    let points = &polyline.points;
+    let mut segments: Vec<((f32, f32), (f32, f32))> = points.windows(2).map(|w| (w[0], w[1])).collect();
+    if polyline.closed && points.len() > 2 {
+        segments.push((points[points.len() - 1], points[0]));
+    }
+
+    let mut triangles = Vec::with_capacity(segments.len() * 6);
+    for (a, b) in segments {
+        let len = length(b.0 - a.0, b.1 - a.1);
+        if len == 0.0 {
+            continue;
+        }
+        let (nx, ny) = (-(b.1 - a.1) / len * half, (b.0 - a.0) / len * half);
+        let (a0, a1) = ((a.0 + nx, a.1 + ny), (a.0 - nx, a.1 - ny));
+        let (b0, b1) = ((b.0 + nx, b.1 + ny), (b.0 - nx, b.1 - ny));
+        triangles.extend([a0, b0, b1, a0, b1, a1]);
+    }
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_records_commands() {
+        let path = PathBuilder::new().move_to(0.0, 0.0).line_to(10.0, 0.0).close().build();
+        assert_eq!(path.commands, vec![
+            PathCommand::MoveTo(0.0, 0.0),
+            PathCommand::LineTo(10.0, 0.0),
+            PathCommand::Close,
+        ]);
+        assert_eq!(path.fill_rule, FillRule::NonZero);
+    }
+
+    #[test]
+    fn rounded_rect_stays_inside_its_bounds() {
+        let path = PathBuilder::new().rounded_rect(10.0, 20.0, 40.0, 10.0, 50.0).build();
+        assert_eq!(path.bounds(), Some((10.0, 20.0, 40.0, 10.0)));
+        assert_eq!(PathBuilder::new().rounded_rect(0.0, 0.0, 4.0, 4.0, 0.0).build().commands.len(), 5);
+    }
+
+    #[test]
+    fn path_bounds_include_control_points() {
+        let path = PathBuilder::new().move_to(0.0, 0.0).quad_to(5.0, 20.0, 10.0, 0.0).build();
+        assert_eq!(path.bounds(), Some((0.0, 0.0, 10.0, 20.0)));
+        assert_eq!(Path::default().bounds(), None);
+    }
+
+    #[test]
+    fn flatten_lines_and_subpaths() {
+        let path = PathBuilder::new().rect(0.0, 0.0, 10.0, 10.0).move_to(20.0, 0.0).line_to(30.0, 0.0).build();
+        let polylines = path.flatten(0.25);
+        assert_eq!(polylines.len(), 2);
+        assert!(polylines[0].closed);
+        assert_eq!(polylines[0].points.len(), 4);
+        assert!(!polylines[1].closed);
+    }
+
+    #[test]
+    fn flatten_curves_stay_on_curve() {
+        let path = PathBuilder::new()
+            .move_to(0.0, 0.0)
+            .cubic_to(0.0, 100.0, 100.0, 100.0, 100.0, 0.0)
+            .build();
+        let points = &path.flatten(0.1)[0].points;
+        assert!(points.len() > 10);
+        assert_eq!(*points.last().unwrap(), (100.0, 0.0));
+        // Every point lies under the curve's peak (50, 75)
+        assert!(points.iter().all(|&(_, y)| (0.0..=75.01).contains(&y)));
+        let peak = points.iter().map(|&(_, y)| y).fold(0.0, f32::max);
+        assert!(peak > 74.5);
+    }
+
+    #[test]
+    fn fan_and_stroke_triangle_counts() {
+        let polyline = &PathBuilder::new().rect(0.0, 0.0, 10.0, 10.0).build().flatten(0.25)[0];
+        assert_eq!(fan_triangles(polyline).len(), 2 * 3);
+        assert_eq!(stroke_triangles(polyline, 2.0).len(), 4 * 6);
+    }
+
+    #[test]
+    fn stroke_is_offset_by_half_width() {
+        let polyline = Polyline { points: vec![(0.0, 0.0), (10.0, 0.0)], closed: false };
+        let triangles = stroke_triangles(&polyline, 4.0);
+        assert_eq!(triangles[0], (0.0, 2.0));
+        assert_eq!(triangles[2], (10.0, -2.0));
+    }
+}
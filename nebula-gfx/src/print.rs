@@ -0,0 +1,225 @@
+use crate::capture::RgbaImage;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::io::Write;
+use std::path::Path;
+use tracing::info;
+
+/// Paper size in points (1/72 inch), portrait
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaperSize {
+    pub name: &'static str,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl PaperSize {
+    pub const A4: PaperSize = PaperSize { name: "A4", width: 595.0, height: 842.0 };
+    pub const A5: PaperSize = PaperSize { name: "A5", width: 420.0, height: 595.0 };
+    pub const LETTER: PaperSize = PaperSize { name: "Letter", width: 612.0, height: 792.0 };
+    pub const LEGAL: PaperSize = PaperSize { name: "Legal", width: 612.0, height: 1008.0 };
+
+    /// Size in points for an orientation
+    pub fn oriented(&self, orientation: PageOrientation) -> (f32, f32) {
+        match orientation {
+            PageOrientation::Portrait => (self.width, self.height),
+            PageOrientation::Landscape => (self.height, self.width),
+        }
+    }
+}
+
+/// Which way up the paper goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageOrientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
+/// Rendered pages ready for a printer or a PDF file 🖨️
+///
+/// Each page is a full-bleed raster of the sheet (margins, header and
+/// footer already drawn), so every print backend just places images.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintJob {
+    pub title: String,
+    pub pages: Vec<RgbaImage>,
+    pub paper: PaperSize,
+    pub orientation: PageOrientation,
+    pub copies: u32,
+}
+
+impl PrintJob {
+    /// Create an empty A4 portrait job
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            pages: Vec::new(),
+            paper: PaperSize::A4,
+            orientation: PageOrientation::Portrait,
+            copies: 1,
+        }
+    }
+
+    /// Set the paper
+    pub fn paper(mut self, paper: PaperSize) -> Self {
+        self.paper = paper;
+        self
+    }
+
+    /// Set the orientation
+    pub fn orientation(mut self, orientation: PageOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the number of copies
+    pub fn copies(mut self, copies: u32) -> Self {
+        self.copies = copies.max(1);
+        self
+    }
+
+    /// Add a page
+    pub fn page(mut self, page: RgbaImage) -> Self {
+        self.pages.push(page);
+        self
+    }
+
+    /// Encode as a PDF document (one image per page, alpha flattened onto white)
+    pub fn to_pdf(&self) -> Result<Vec<u8>, String> {
+        if self.pages.is_empty() {
+            return Err("Print job has no pages".to_string());
+        }
+        let (width, height) = self.paper.oriented(self.orientation);
+        let mut pdf = PdfWriter::default();
+        pdf.bytes.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+        // 1 = catalog, 2 = page tree, 3 = info, then page / contents / image per page
+        let page_ids: Vec<usize> = (0..self.pages.len()).map(|i| 4 + i * 3).collect();
+        pdf.object(1, b"<< /Type /Catalog /Pages 2 0 R >>");
+        let kids: Vec<String> = page_ids.iter().map(|id| format!("{} 0 R", id)).collect();
+        pdf.object(2, format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), page_ids.len()).as_bytes());
+        pdf.object(3, format!("<< /Title ({}) /Producer (Nebula UI) >>", escape_pdf_string(&self.title)).as_bytes());
+
+        for (page, &id) in self.pages.iter().zip(&page_ids) {
+            pdf.object(
+                id,
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /XObject << /Im0 {} 0 R >> >> /Contents {} 0 R >>",
+                    width, height, id + 2, id + 1
+                )
+                .as_bytes(),
+            );
+            let contents = format!("q {} 0 0 {} 0 0 cm /Im0 Do Q", width, height);
+            pdf.stream(id + 1, "", contents.as_bytes());
+
+            let rgb: Vec<u8> = page
+                .pixels
+                .chunks_exact(4)
+                .flat_map(|p| {
+                    let alpha = p[3] as u32;
+                    let over_white = |channel: u8| ((channel as u32 * alpha + 255 * (255 - alpha)) / 255) as u8;
+                    [over_white(p[0]), over_white(p[1]), over_white(p[2])]
+                })
+                .collect();
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&rgb).map_err(|e| format!("Failed to compress page: {}", e))?;
+            let data = encoder.finish().map_err(|e| format!("Failed to compress page: {}", e))?;
+            pdf.stream(
+                id + 2,
+                &format!(
+                    "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode ",
+                    page.width, page.height
+                ),
+                &data,
+            );
+        }
+
+        Ok(pdf.finish(3))
+    }
+
+    /// Save as a PDF file
+    pub fn save_pdf(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_pdf()?).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        info!("🖨️ Saved {} page PDF to {}", self.pages.len(), path.display());
+        Ok(())
+    }
+}
+
+/// Tracks object offsets for the cross-reference table
+#[derive(Default)]
+struct PdfWriter {
+    bytes: Vec<u8>,
+    offsets: Vec<(usize, usize)>,
+}
+
+impl PdfWriter {
+    fn object(&mut self, id: usize, body: &[u8]) {
+        self.offsets.push((id, self.bytes.len()));
+        self.bytes.extend_from_slice(format!("{} 0 obj\n", id).as_bytes());
+        self.bytes.extend_from_slice(body);
+        self.bytes.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn stream(&mut self, id: usize, dictionary: &str, data: &[u8]) {
+        let mut body = format!("<< {}/Length {} >>\nstream\n", dictionary, data.len()).into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\nendstream");
+        self.object(id, &body);
+    }
+
+    fn finish(mut self, info_id: usize) -> Vec<u8> {
+        self.offsets.sort();
+        let xref = self.bytes.len();
+        let count = self.offsets.len() + 1;
+        let mut table = format!("xref\n0 {}\n0000000000 65535 f \n", count);
+        for (_, offset) in &self.offsets {
+            table.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+        table.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            count, info_id, xref
+        ));
+        self.bytes.extend_from_slice(table.as_bytes());
+        self.bytes
+    }
+}
+
+fn escape_pdf_string(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .flat_map(|c| match c {
+            '\\' | '(' | ')' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pdf_has_one_image_page_per_page() {
+        let job = PrintJob::new("Report (draft)")
+            .paper(PaperSize::LETTER)
+            .orientation(PageOrientation::Landscape)
+            .page(RgbaImage::new(8, 6))
+            .page(RgbaImage::new(8, 6));
+        let pdf = job.to_pdf().unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.contains("/Count 2"));
+        assert!(text.contains("/MediaBox [0 0 792 612]"));
+        assert!(text.contains("/Title (Report \\(draft\\))"));
+        assert_eq!(text.matches("/Subtype /Image").count(), 2);
+        assert!(text.trim_end().ends_with("%%EOF"));
+
+        // startxref points at the xref table
+        let start: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        assert!(pdf[start..].starts_with(b"xref"));
+        assert!(PrintJob::new("empty").to_pdf().is_err());
+    }
+}
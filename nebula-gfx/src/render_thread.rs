@@ -0,0 +1,215 @@
+//! Render Thread - Keep input snappy when drawing is slow! 🧵
+//!
+//! By default a frame is built and submitted on the UI thread, so a slow
+//! GPU submission or a big CPU rasterization delays the next input event.
+//! With a `RenderThread`, the UI thread only records a `DrawList` and
+//! hands it over; a dedicated thread submits it to the backend.
+//!
+//! Frames travel through a triple buffer: the UI thread always has a free
+//! list to record into, the render thread always takes the newest frame,
+//! and frames the renderer couldn't keep up with are dropped instead of
+//! queueing up latency. Lists are recycled, so steady state allocates
+//! nothing.
+//!
+//! Renderers usually aren't `Send` (GL contexts belong to one thread), so
+//! the renderer is created on the render thread by the closure passed to
+//! `spawn`.
+
+use crate::draw_list::DrawList;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use tracing::{error, info};
+
+/// The three buffers: one being recorded (held by the UI thread), one
+/// ready, one being rendered (held by the render thread)
+#[derive(Default)]
+struct Slots {
+    /// Newest finished frame, not yet picked up
+    ready: Option<DrawList>,
+    /// Recycled lists waiting to be recorded into
+    free: Vec<DrawList>,
+    shutdown: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    slots: Mutex<Slots>,
+    frame_ready: Condvar,
+    rendered: AtomicU64,
+    dropped: AtomicU64,
+}
+
+/// Render Thread - Submits DrawLists away from the UI thread 🧵
+///
+/// # Example
+/// ```no_run
+/// use nebula_gfx::draw_list::DrawList;
+/// use nebula_gfx::render_thread::RenderThread;
+/// use nebula_gfx::Renderer;
+///
+/// // `create` builds the backend on the render thread,
+/// // e.g. `|| CpuRenderer::new(window, window, width, height).unwrap()`
+/// fn run<R: Renderer>(create: impl FnOnce() -> R + Send + 'static, paint: impl Fn(&mut DrawList)) {
+///     let render_thread = RenderThread::spawn(move || {
+///         let mut renderer = create();
+///         move |list: &DrawList| {
+///             if let Err(e) = renderer.render(list) {
+///                 eprintln!("Frame failed: {}", e);
+///             }
+///         }
+///     });
+///
+///     // every frame, on the UI thread:
+///     let mut list = render_thread.acquire();
+///     paint(&mut list);
+///     render_thread.submit(list);
+/// }
+/// ```
+pub struct RenderThread {
+    shared: Arc<Shared>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RenderThread {
+    /// Start the thread; `init` runs on it and returns the submit function
+    pub fn spawn<I, F>(init: I) -> Self
+    where
+        I: FnOnce() -> F + Send + 'static,
+        F: FnMut(&DrawList),
+    {
+        let shared = Arc::new(Shared::default());
+        let worker = shared.clone();
+        let handle = std::thread::Builder::new()
+            .name("nebula-render".to_string())
+            .spawn(move || {
+                let mut render = init();
+                info!("🧵 Render thread started");
+                while let Some(list) = worker.next_frame() {
+                    render(&list);
+                    worker.rendered.fetch_add(1, Ordering::Relaxed);
+                    worker.recycle(list);
+                }
+                info!("🧵 Render thread stopped");
+            })
+            .map_err(|e| error!("Failed to start render thread: {}", e))
+            .ok();
+        Self { shared, handle }
+    }
+
+    /// An empty list to record the next frame into (reuses old allocations)
+    pub fn acquire(&self) -> DrawList {
+        let mut slots = self.shared.slots.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut list = slots.free.pop().unwrap_or_default();
+        list.reset();
+        list
+    }
+
+    /// Hand a finished frame to the render thread (never blocks)
+    /// An older frame still waiting is dropped
+    pub fn submit(&self, list: DrawList) {
+        let mut slots = self.shared.slots.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(stale) = slots.ready.replace(list) {
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            slots.free.push(stale);
+        }
+        drop(slots);
+        self.shared.frame_ready.notify_one();
+    }
+
+    /// Frames the render thread has submitted
+    pub fn frames_rendered(&self) -> u64 {
+        self.shared.rendered.load(Ordering::Relaxed)
+    }
+
+    /// Frames replaced before the render thread got to them
+    pub fn frames_dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Is the thread running?
+    pub fn is_running(&self) -> bool {
+        self.handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+
+    /// Render the pending frame (if any), then stop the thread
+    pub fn shutdown(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.shared.slots.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).shutdown = true;
+            self.shared.frame_ready.notify_one();
+            if handle.join().is_err() {
+                error!("Render thread panicked");
+            }
+        }
+    }
+}
+
+impl Drop for RenderThread {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl Shared {
+    /// Wait for the newest frame (None once shut down and drained)
+    fn next_frame(&self) -> Option<DrawList> {
+        let mut slots = self.slots.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        loop {
+            if let Some(list) = slots.ready.take() {
+                return Some(list);
+            }
+            if slots.shutdown {
+                return None;
+            }
+            slots = self.frame_ready.wait(slots).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    fn recycle(&self, list: DrawList) {
+        let mut slots = self.slots.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Two spares cover record + ready; more would just hold memory
+        if slots.free.len() < 2 {
+            slots.free.push(list);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn renders_newest_frames_off_thread() {
+        let (frames, received) = mpsc::channel();
+        let (release, gate) = mpsc::channel::<()>();
+        let mut render_thread = RenderThread::spawn(move || {
+            move |list: &DrawList| {
+                frames.send((std::thread::current().name().map(str::to_string), list.len())).unwrap();
+                gate.recv_timeout(Duration::from_secs(5)).ok();
+            }
+        });
+
+        // First frame blocks the renderer; of the next three only the newest survives
+        for commands in 1..=4 {
+            let mut list = render_thread.acquire();
+            for _ in 0..commands {
+                list.rect((0.0, 0.0, 1.0, 1.0), (0, 0, 0, 255));
+            }
+            render_thread.submit(list);
+            if commands == 1 {
+                received.recv_timeout(Duration::from_secs(5)).unwrap();
+            }
+        }
+        release.send(()).unwrap();
+        release.send(()).unwrap();
+        render_thread.shutdown();
+
+        let (thread, commands) = received.recv().unwrap();
+        assert_eq!((thread.as_deref(), commands), (Some("nebula-render"), 4));
+        assert_eq!(render_thread.frames_rendered(), 2);
+        assert_eq!(render_thread.frames_dropped(), 2);
+        assert!(!render_thread.is_running());
+    }
+}
@@ -1,3 +1,38 @@
+use crate::capabilities::RendererCapabilities;
+use crate::capture::RgbaImage;
+use crate::draw_list::DrawList;
+use crate::path::Path;
+use std::fmt;
+
+/// Handle to an offscreen render target
+/// Backends hand these out from `create_render_target`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RenderTargetId(pub u32);
+
+/// Error from a rendering backend (surface present, GL call, missing target...)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderError(pub String);
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+impl From<String> for RenderError {
+    fn from(message: String) -> Self {
+        Self(message)
+    }
+}
+
+impl From<&str> for RenderError {
+    fn from(message: &str) -> Self {
+        Self(message.to_string())
+    }
+}
+
 /// Universal renderer trait - works with ANY backend!
 /// This is the magic that makes Nebula UI work on everything from
 /// cutting-edge GPUs to 20-year-old machines! 🌌
@@ -23,9 +58,89 @@ pub trait Renderer {
     /// End the current frame and present
     fn end_frame(&mut self) -> Result<(), Self::Error>;
     
-    /// Get current dimensions
+    /// Get current dimensions (physical pixels)
     fn dimensions(&self) -> (u32, u32);
+
+    /// Read back the last rendered frame (for screenshots and visual tests)
+    fn capture_frame(&mut self) -> Result<RgbaImage, Self::Error>;
+
+    /// Fill and/or stroke a vector path
+    /// Path coordinates are in physical pixels, origin top-left.
+    /// `stroke` is (color, width in pixels); pass None to skip either part.
+    fn draw_path(
+        &mut self,
+        path: &Path,
+        fill: Option<Self::Color>,
+        stroke: Option<(Self::Color, f32)>,
+    ) -> Result<(), Self::Error>;
+
+    /// Start a transparency group: everything drawn until the matching
+    /// `pop_opacity` is composited as one layer at this opacity
+    fn push_opacity(&mut self, opacity: f32);
+
+    /// End the innermost transparency group and composite it
+    fn pop_opacity(&mut self) -> Result<(), Self::Error>;
+
+    /// Replay a recorded DrawList (the same list the web renderers consume)
+    fn render(&mut self, list: &DrawList) -> Result<(), Self::Error>;
+
+    /// Get the scale factor (physical pixels per logical pixel)
+    fn scale_factor(&self) -> f64 {
+        1.0
+    }
+
+    /// Get current dimensions in logical pixels
+    fn logical_dimensions(&self) -> (f64, f64) {
+        let (width, height) = self.dimensions();
+        let scale = self.scale_factor();
+        (width as f64 / scale, height as f64 / scale)
+    }
+    
+    /// Does this backend support offscreen render targets?
+    fn supports_render_targets(&self) -> bool {
+        false
+    }
+
+    /// Create an offscreen render target (size in physical pixels)
+    /// Returns None if the backend doesn't support render targets
+    fn create_render_target(&mut self, _width: u32, _height: u32) -> Option<RenderTargetId> {
+        None
+    }
+
+    /// Redirect drawing into a render target (None = back to the window)
+    /// Returns false if the target couldn't be bound
+    fn set_render_target(&mut self, target: Option<RenderTargetId>) -> bool {
+        target.is_none()
+    }
+
+    /// Draw a render target's contents as an image at (x, y)
+    fn draw_render_target(&mut self, _target: RenderTargetId, _x: f32, _y: f32) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Free a render target
+    fn delete_render_target(&mut self, _target: RenderTargetId) {}
     
+    /// Effects this backend can draw (components pick fallbacks for the rest)
+    fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities { render_targets: self.supports_render_targets(), ..RendererCapabilities::BASIC }
+    }
+
+    /// Can this backend draw soft shadows?
+    fn supports_shadows(&self) -> bool {
+        self.capabilities().shadows
+    }
+
+    /// Can this backend blur?
+    fn supports_blur(&self) -> bool {
+        self.capabilities().blur
+    }
+
+    /// Can this backend rotate/scale/skew content?
+    fn supports_transforms(&self) -> bool {
+        self.capabilities().transforms
+    }
+
     /// Get renderer name for debugging
     fn name(&self) -> &'static str;
 }
@@ -55,6 +170,7 @@ pub trait Color: Clone + Copy + Sized {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::opacity::OpacityStack;
     
     // Mock color for testing
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -91,6 +207,9 @@ mod tests {
         width: u32,
         height: u32,
         clear_color: MockColor,
+        paths_drawn: usize,
+        commands_replayed: usize,
+        opacity: OpacityStack,
     }
     
     impl Renderer for MockRenderer {
@@ -119,6 +238,38 @@ mod tests {
         fn dimensions(&self) -> (u32, u32) {
             (self.width, self.height)
         }
+
+        fn capture_frame(&mut self) -> Result<RgbaImage, Self::Error> {
+            Ok(RgbaImage::new(self.width, self.height))
+        }
+
+        fn draw_path(
+            &mut self,
+            path: &Path,
+            _fill: Option<Self::Color>,
+            _stroke: Option<(Self::Color, f32)>,
+        ) -> Result<(), Self::Error> {
+            if !path.is_empty() {
+                self.paths_drawn += 1;
+            }
+            Ok(())
+        }
+
+        fn push_opacity(&mut self, opacity: f32) {
+            self.opacity.push(opacity);
+        }
+
+        fn pop_opacity(&mut self) -> Result<(), Self::Error> {
+            self.opacity
+                .pop()
+                .map(|_| ())
+                .ok_or_else(|| std::io::Error::other("pop_opacity without push_opacity"))
+        }
+
+        fn render(&mut self, list: &DrawList) -> Result<(), Self::Error> {
+            self.commands_replayed += list.commands().count();
+            Ok(())
+        }
         
         fn name(&self) -> &'static str {
             "Mock Renderer"
@@ -131,9 +282,13 @@ mod tests {
             width: 800,
             height: 600,
             clear_color: MockColor::NEBULA_BLUE,
+            paths_drawn: 0,
+            commands_replayed: 0,
+            opacity: OpacityStack::new(),
         };
         
         assert_eq!(renderer.dimensions(), (800, 600));
+        assert_eq!(renderer.logical_dimensions(), (800.0, 600.0));
         assert_eq!(renderer.name(), "Mock Renderer");
         
         renderer.resize(1024, 768);
@@ -144,6 +299,45 @@ mod tests {
         
         assert!(renderer.clear().is_ok());
         assert!(renderer.end_frame().is_ok());
+
+        let frame = renderer.capture_frame().unwrap();
+        assert_eq!((frame.width, frame.height), (1024, 768));
+
+        let path = crate::PathBuilder::new().move_to(0.0, 0.0).quad_to(5.0, 10.0, 10.0, 0.0).build();
+        assert!(renderer.draw_path(&path, Some(MockColor::RED), Some((MockColor::WHITE, 2.0))).is_ok());
+        assert_eq!(renderer.paths_drawn, 1);
+
+        renderer.push_opacity(0.5);
+        renderer.push_opacity(0.5);
+        assert_eq!(renderer.opacity.current(), 0.25);
+        assert!(renderer.pop_opacity().is_ok());
+        assert!(renderer.pop_opacity().is_ok());
+        assert!(renderer.pop_opacity().is_err());
+
+        let mut list = DrawList::new();
+        list.push_clip((0.0, 0.0, 10.0, 10.0)).rect((0.0, 0.0, 5.0, 5.0), (255, 0, 0, 255));
+        assert!(renderer.render(&list).is_ok());
+        // The open clip is closed on replay
+        assert_eq!(renderer.commands_replayed, 3);
+    }
+    
+    #[test]
+    fn render_targets_unsupported_by_default() {
+        let mut renderer = MockRenderer {
+            width: 800,
+            height: 600,
+            clear_color: MockColor::NEBULA_BLUE,
+            paths_drawn: 0,
+            commands_replayed: 0,
+            opacity: OpacityStack::new(),
+        };
+
+        assert!(!renderer.supports_render_targets());
+        assert_eq!(renderer.create_render_target(128, 128), None);
+        assert!(!renderer.set_render_target(Some(RenderTargetId(1))));
+        assert!(renderer.set_render_target(None));
+        // No effects unless the backend says so
+        assert!(!renderer.supports_blur() && !renderer.supports_shadows() && !renderer.supports_transforms());
     }
     
     #[test]
@@ -0,0 +1,103 @@
+//! Streaming Textures - Images that change every frame 🎞️
+//!
+//! `upload_image` is made for pictures that are loaded once. Feeding it a
+//! camera preview or a video means a brand-new texture (and a copy of the
+//! pixels) every frame. A `StreamingTexture` is a shared RGBA8 buffer with
+//! a version counter instead: the producer writes frames into it from any
+//! thread, and a renderer it is attached to copies each new version into
+//! the *same* texture, in place, right before drawing (`glTexSubImage2D`
+//! on GL). Draw lists refer to it by its `ImageId` like any other image.
+
+use crate::draw_list::ImageId;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+#[derive(Debug, Default)]
+struct StreamFrame {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    /// 0 = nothing written yet
+    version: u64,
+}
+
+/// Shared handle to a texture whose pixels are replaced every frame
+/// Clones share the same buffer (one for the producer, one per renderer)
+#[derive(Debug, Clone)]
+pub struct StreamingTexture {
+    id: ImageId,
+    frame: Arc<Mutex<StreamFrame>>,
+}
+
+impl StreamingTexture {
+    /// Create an empty stream drawn as `id`
+    pub fn new(id: ImageId) -> Self {
+        Self { id, frame: Arc::default() }
+    }
+
+    /// Image id to use in draw lists
+    pub fn id(&self) -> ImageId {
+        self.id
+    }
+
+    /// Bumped on every write (0 = no frame yet)
+    pub fn version(&self) -> u64 {
+        self.lock().version
+    }
+
+    /// Size of the current frame
+    pub fn size(&self) -> (u32, u32) {
+        let frame = self.lock();
+        (frame.width, frame.height)
+    }
+
+    /// Replace the pixels (tightly packed RGBA8, width * height * 4 bytes)
+    /// The buffer is reused, so a steady stream doesn't allocate
+    pub fn write(&self, width: u32, height: u32, pixels: &[u8]) -> Result<(), String> {
+        if pixels.len() != width as usize * height as usize * 4 {
+            return Err(format!("Stream frame is {} bytes, expected {}x{} RGBA", pixels.len(), width, height));
+        }
+        let mut frame = self.lock();
+        frame.pixels.clear();
+        frame.pixels.extend_from_slice(pixels);
+        frame.width = width;
+        frame.height = height;
+        frame.version += 1;
+        Ok(())
+    }
+
+    /// Hand the pixels to `upload` if they changed since version `seen`
+    /// Returns the version that was read (renderers keep it for the next call)
+    pub fn read_newer<R>(&self, seen: u64, upload: impl FnOnce(u32, u32, &[u8]) -> R) -> Option<(u64, R)> {
+        let frame = self.lock();
+        if frame.version == seen || frame.version == 0 {
+            return None;
+        }
+        Some((frame.version, upload(frame.width, frame.height, &frame.pixels)))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StreamFrame> {
+        self.frame.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renderer_sees_each_version_once() {
+        let producer = StreamingTexture::new(ImageId(7));
+        let renderer = producer.clone();
+        assert!(renderer.read_newer(0, |_, _, _| ()).is_none());
+
+        producer.write(1, 1, &[1, 2, 3, 4]).unwrap();
+        let (seen, pixels) = renderer.read_newer(0, |width, height, pixels| (width, height, pixels.to_vec())).unwrap();
+        assert_eq!(pixels, (1, 1, vec![1, 2, 3, 4]));
+        assert!(renderer.read_newer(seen, |_, _, _| ()).is_none());
+
+        producer.write(2, 1, &[0; 8]).unwrap();
+        assert_eq!(renderer.size(), (2, 1));
+        assert!(renderer.read_newer(seen, |_, _, _| ()).is_some());
+        assert!(producer.write(2, 2, &[0; 8]).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+//! Catalog - The app's translations and current locale 🗂️
+//!
+//! One catalog per UI thread, like the other UI-wide settings. Bundles are
+//! added per locale (embedded with `include_str!` or loaded from a
+//! directory); lookups walk a fallback chain:
+//! `de-AT` → `de` → the fallback locale (`en-US` by default) → `en`.
+//!
+//! `t` returns a derived Signal that reads the locale and the catalog's
+//! revision, so labels built from it re-translate when either changes.
+
+use fluent_bundle::{FluentArgs, FluentResource};
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use nebula_core::Signal;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::{info, warn};
+use unic_langid::LanguageIdentifier;
+
+/// Messages for one locale, ready to format
+pub type FluentBundle = fluent_bundle::FluentBundle<FluentResource>;
+
+struct CatalogState {
+    bundles: HashMap<String, FluentBundle>,
+    fallback: String,
+}
+
+thread_local! {
+    static LOCALE: Signal<String> = Signal::new("en-US".to_string());
+    /// Bumped whenever bundles change
+    static REVISION: Signal<u64> = Signal::new(0);
+    static CATALOG: RefCell<CatalogState> = RefCell::new(CatalogState { bundles: HashMap::new(), fallback: "en-US".to_string() });
+}
+
+/// The current locale
+pub fn locale() -> String {
+    locale_signal().get()
+}
+
+/// The locale Signal (subscribe to react to locale changes)
+pub fn locale_signal() -> Signal<String> {
+    LOCALE.with(|locale| locale.clone())
+}
+
+/// Switch the UI language; every `t` Signal re-translates
+pub fn set_locale(locale: impl Into<String>) {
+    let locale = normalize_locale(&locale.into());
+    let signal = locale_signal();
+    if signal.get() != locale {
+        info!("🌍 Locale: {}", locale);
+        signal.set(locale);
+    }
+}
+
+/// Locale used when a message is missing from the current one
+pub fn set_fallback_locale(locale: impl Into<String>) {
+    let locale = normalize_locale(&locale.into());
+    CATALOG.with(|catalog| catalog.borrow_mut().fallback = locale);
+    bump_revision();
+}
+
+/// `de-DE` as a language identifier (unparseable tags become "und")
+fn language_id(locale: &str) -> LanguageIdentifier {
+    locale.parse().unwrap_or_default()
+}
+
+/// Add parsed messages for a locale (later additions override earlier ones)
+pub fn add_resource(locale: &str, resource: FluentResource) {
+    let locale = normalize_locale(locale);
+    CATALOG.with(|catalog| {
+        catalog
+            .borrow_mut()
+            .bundles
+            .entry(locale.clone())
+            .or_insert_with(|| FluentBundle::new(vec![language_id(&locale)]))
+            .add_resource_overriding(resource);
+    });
+    bump_revision();
+}
+
+/// Parse FTL source; bad entries are skipped and come back as messages
+fn parse_ftl(source: &str) -> (FluentResource, Vec<String>) {
+    match FluentResource::try_new(source.to_string()) {
+        Ok(resource) => (resource, Vec::new()),
+        Err((resource, errors)) => (resource, errors.iter().map(ToString::to_string).collect()),
+    }
+}
+
+/// Add FTL source for a locale
+/// Valid messages are added even if some entries fail; the errors come back joined
+pub fn add_ftl(locale: &str, source: &str) -> Result<(), String> {
+    let (resource, errors) = parse_ftl(source);
+    for error in &errors {
+        warn!("⚠️ {}: {}", locale, error);
+    }
+    add_resource(locale, resource);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// Load every `.ftl` file under `dir`, from either layout:
+/// `dir/de-DE/*.ftl` or `dir/de-DE.ftl`; returns the number of files read
+pub fn load_dir(dir: impl AsRef<Path>) -> Result<usize, String> {
+    let dir = dir.as_ref();
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    let mut files = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            let locale = entry.file_name().to_string_lossy().to_string();
+            let inner = std::fs::read_dir(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            files.extend(inner.flatten().map(|file| (locale.clone(), file.path())));
+        } else if let Some(stem) = path.file_stem() {
+            files.push((stem.to_string_lossy().to_string(), path.clone()));
+        }
+    }
+
+    let mut loaded = 0;
+    for (locale, path) in files {
+        if path.extension().is_none_or(|ext| ext != "ftl") {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let (resource, errors) = parse_ftl(&source);
+        for error in errors {
+            warn!("⚠️ {}: {}", path.display(), error);
+        }
+        add_resource(&locale, resource);
+        loaded += 1;
+    }
+    info!("🌍 Loaded {} translation files from {}", loaded, dir.display());
+    Ok(loaded)
+}
+
+/// Is there a translation for `key` in the current locale or a fallback?
+pub fn has_translation(key: &str) -> bool {
+    let chain = fallback_chain(&locale());
+    CATALOG.with(|catalog| {
+        let catalog = catalog.borrow();
+        chain.iter().filter_map(|locale| catalog.bundles.get(locale)).any(|bundle| format(bundle, key, None).is_some())
+    })
+}
+
+/// Format a message (`id` or `id.attribute`) from one bundle; None if it isn't there
+fn format(bundle: &FluentBundle, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let (id, attribute) = match key.split_once('.') {
+        Some((id, attribute)) => (id, Some(attribute)),
+        None => (key, None),
+    };
+    let message = bundle.get_message(id)?;
+    let pattern = match attribute {
+        Some(attribute) => message.get_attribute(attribute)?.value(),
+        None => message.value()?,
+    };
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors).into_owned();
+    for error in errors {
+        warn!("⚠️ {}: {}", key, error);
+    }
+    Some(text)
+}
+
+/// Translate now (not reactive); a missing key comes back as itself
+pub fn translate(key: &str, args: &FluentArgs) -> String {
+    let chain = fallback_chain(&locale());
+    let found = CATALOG.with(|catalog| {
+        let catalog = catalog.borrow();
+        chain.iter().filter_map(|locale| catalog.bundles.get(locale)).find_map(|bundle| format(bundle, key, Some(args)))
+    });
+    found.unwrap_or_else(|| {
+        warn!("⚠️ No translation for `{}` in {}", key, chain.join(", "));
+        key.to_string()
+    })
+}
+
+/// Translation as a Signal that follows the locale (see the `t!` macro)
+pub fn t(key: &str, args: FluentArgs<'static>) -> Signal<String> {
+    let key = key.to_string();
+    let (locale, revision) = (locale_signal(), REVISION.with(|revision| revision.clone()));
+    Signal::derive(move || {
+        locale.get();
+        revision.get();
+        translate(&key, &args)
+    })
+}
+
+/// The OS language from `LC_ALL` / `LC_MESSAGES` / `LANG` (None on wasm or when unset)
+pub fn system_locale() -> Option<String> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+        .map(|value| normalize_locale(&value))
+}
+
+/// CLDR plural category of `n` in a locale ("zero", "one", "two", "few",
+/// "many" or "other"), for code that picks strings outside Fluent
+pub fn plural_category(locale: &str, n: f64) -> &'static str {
+    let category = PluralRules::create(language_id(&normalize_locale(locale)), PluralRuleType::CARDINAL)
+        .or_else(|_| PluralRules::create(language_id("en"), PluralRuleType::CARDINAL))
+        .and_then(|rules| rules.select(n));
+    match category {
+        Ok(PluralCategory::ZERO) => "zero",
+        Ok(PluralCategory::ONE) => "one",
+        Ok(PluralCategory::TWO) => "two",
+        Ok(PluralCategory::FEW) => "few",
+        Ok(PluralCategory::MANY) => "many",
+        _ => "other",
+    }
+}
+
+/// `de_DE.UTF-8` → `de-DE`
+pub fn normalize_locale(locale: &str) -> String {
+    let locale = locale.split(['.', '@']).next().unwrap_or(locale);
+    locale.replace('_', "-")
+}
+
+/// Locales to try, most specific first
+fn fallback_chain(locale: &str) -> Vec<String> {
+    let fallback = CATALOG.with(|catalog| catalog.borrow().fallback.clone());
+    let mut chain: Vec<String> = Vec::new();
+    for candidate in [locale, &fallback] {
+        for tag in [candidate, candidate.split('-').next().unwrap_or(candidate)] {
+            if !chain.iter().any(|existing| existing == tag) {
+                chain.push(tag.to_string());
+            }
+        }
+    }
+    chain
+}
+
+fn bump_revision() {
+    let revision = REVISION.with(|revision| revision.clone());
+    revision.set(revision.get() + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signals_follow_locale_with_fallback() {
+        add_ftl("en-US", "greeting = Hello, { $name }!\nquit = Quit").unwrap();
+        add_ftl("de", "greeting = Hallo, { $name }!").unwrap();
+        assert!(add_ftl("de", "broken = { $x").is_err());
+
+        let greeting = crate::t!("greeting", name = "Ada");
+        let quit = t("quit", FluentArgs::new());
+        // Arguments are wrapped in bidi isolates (FSI … PDI) so RTL names can't reorder the sentence
+        assert_eq!(greeting.get(), "Hello, \u{2068}Ada\u{2069}!");
+
+        set_locale("de_AT.UTF-8");
+        assert_eq!(locale(), "de-AT");
+        assert_eq!(greeting.get(), "Hallo, \u{2068}Ada\u{2069}!");
+        // Not translated yet: falls back to English
+        assert_eq!(quit.get(), "Quit");
+
+        add_ftl("de", "quit = Beenden").unwrap();
+        assert_eq!(quit.get(), "Beenden");
+        assert_eq!(translate("missing-key", &FluentArgs::new()), "missing-key");
+        set_locale("en-US");
+    }
+
+    const FTL: &str = r#"
+-brand = Nebula
+welcome = Welcome to { -brand }!
+emails = { $count ->
+    [0] No new email
+    [one] One new email
+   *[other] { $count } new emails
+}
+save-dialog =
+    .title = Save changes?
+"#;
+
+    #[test]
+    fn formats_terms_attributes_and_plurals() {
+        let (resource, errors) = parse_ftl(FTL);
+        assert!(errors.is_empty(), "{:?}", errors);
+        let mut bundle = FluentBundle::new(vec![language_id("fr")]);
+        bundle.set_use_isolating(false);
+        bundle.add_resource_overriding(resource);
+
+        let count = |n: f64| {
+            let mut args = FluentArgs::new();
+            args.set("count", n);
+            format(&bundle, "emails", Some(&args))
+        };
+        assert_eq!(format(&bundle, "welcome", None).as_deref(), Some("Welcome to Nebula!"));
+        assert_eq!(format(&bundle, "save-dialog.title", None).as_deref(), Some("Save changes?"));
+        assert_eq!(format(&bundle, "save-dialog", None), None);
+        // The exact [0] variant wins; French counts 1.5 as singular
+        assert_eq!(count(0.0).as_deref(), Some("No new email"));
+        assert_eq!(count(1.5).as_deref(), Some("One new email"));
+        assert_eq!(count(5.0).as_deref(), Some("5 new emails"));
+
+        assert!(parse_ftl("ok = Fine\nopen = { $x\nstill-ok = Yes").1.len() == 1);
+    }
+
+    #[test]
+    fn plural_categories_follow_the_language() {
+        assert_eq!(plural_category("en-US", 1.0), "one");
+        assert_eq!(plural_category("en", 1.5), "other");
+        assert_eq!(plural_category("ru", 23.0), "few");
+        assert_eq!(plural_category("pl", 21.0), "many");
+        assert_eq!(plural_category("ar", 2.0), "two");
+        assert_eq!(plural_category("de_DE.UTF-8", 1.0), "one");
+    }
+}
@@ -0,0 +1,53 @@
+//! # Nebula i18n - Translations for Nebula UI
+//!
+//! Load [Fluent](https://projectfluent.org) `.ftl` bundles (parsed and
+//! formatted by the `fluent-bundle` crate) and look strings up with `t!`,
+//! which returns a Signal that re-translates when the locale changes. 🌍
+//!
+//! Components take Signals for their labels, so translations plug straight
+//! in: `Text::from_signal(t!(…))` and `Button::bind_label(t!(…))`.
+//!
+//! ## Example:
+//! ```rust,ignore
+//! nebula_i18n::add_ftl("en-US", include_str!("../locales/en-US.ftl"))?;
+//! nebula_i18n::load_dir("locales")?;
+//!
+//! let title = Text::from_signal(t!("inbox-title"));
+//! let unread = Text::from_signal(t!("unread-emails", count = 3));
+//! let save = Button::new("").bind_label(t!("save-dialog.confirm"));
+//!
+//! nebula_i18n::set_locale("de-DE"); // every label above updates
+//! ```
+
+pub mod catalog;
+
+pub use catalog::{
+    add_ftl, add_resource, has_translation, load_dir, locale, locale_signal, normalize_locale, plural_category,
+    set_fallback_locale, set_locale, system_locale, t, translate, FluentBundle,
+};
+pub use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+
+/// Translate a key into a Signal that follows the locale 🌍
+///
+/// ```rust,ignore
+/// let title = t!("inbox-title");
+/// let unread = t!("unread-emails", count = 3, folder = "Inbox");
+/// let custom = t!("unread-emails", args); // a FluentArgs<'static> built elsewhere
+/// ```
+///
+/// Argument values are captured when the macro runs; for arguments that
+/// change, derive your own Signal around `translate`.
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::t($key, $crate::FluentArgs::new())
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::t($key, args)
+    }};
+    ($key:expr, $args:expr) => {
+        $crate::t($key, $args)
+    };
+}
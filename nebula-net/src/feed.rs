@@ -0,0 +1,375 @@
+//! Feed - Live data from a background connection into Signals! 📡
+//!
+//! A `FeedSource` owns one connection attempt (a WebSocket, an SSE stream,
+//! or anything else that produces text frames). The feed runs it on a
+//! background thread, decodes every frame there, and reconnects with
+//! exponential backoff when the connection drops.
+//!
+//! Signals live on the UI thread, so nothing touches them until `poll()`:
+//! call it once per frame and it drains what arrived, updating `status`,
+//! `latest` and `error` and running the message handlers (bound Signals,
+//! Store dispatches…).
+
+use nebula_core::{Signal, Store};
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Turns one text frame into a message
+pub type Decoder<T> = Arc<dyn Fn(&str) -> Result<T, String> + Send + Sync>;
+
+type Handler<T> = Box<dyn Fn(&T)>;
+
+/// Reconnect delays: `initial`, then multiplied by `factor` up to `max`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub factor: f64,
+    /// Give up after this many failed attempts in a row (None = never)
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(30),
+            factor: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+impl Backoff {
+    /// Wait before reconnect attempt `attempt` (1 = first retry)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(32) as i32;
+        let scale = self.factor.max(1.0).powi(exponent);
+        if self.initial.as_secs_f64() * scale >= self.max.as_secs_f64() {
+            self.max
+        } else {
+            self.initial.mul_f64(scale)
+        }
+    }
+}
+
+/// Connection state, as seen by the UI
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedStatus {
+    Connecting,
+    Open,
+    /// Waiting `delay` before attempt `attempt`
+    Reconnecting { attempt: u32, delay: Duration },
+    /// Closed by the app or out of attempts
+    Closed,
+}
+
+enum FeedEvent<T> {
+    Status(FeedStatus),
+    Message(T),
+    Error(String),
+}
+
+/// Handed to a `FeedSource` for one connection
+pub struct FeedSink<T> {
+    sender: Sender<FeedEvent<T>>,
+    decode: Decoder<T>,
+    stop: Arc<AtomicBool>,
+    opened: bool,
+}
+
+impl<T> FeedSink<T> {
+    /// The connection is up (resets the backoff)
+    pub fn opened(&mut self) {
+        self.opened = true;
+        let _ = self.sender.send(FeedEvent::Status(FeedStatus::Open));
+    }
+
+    /// Deliver a text frame; false once the feed was closed (stop reading)
+    pub fn message(&mut self, text: &str) -> bool {
+        let event = match (self.decode)(text) {
+            Ok(message) => FeedEvent::Message(message),
+            Err(e) => FeedEvent::Error(format!("Bad message: {}", e)),
+        };
+        self.sender.send(event).is_ok() && !self.is_closed()
+    }
+
+    /// Has the app closed the feed?
+    pub fn is_closed(&self) -> bool {
+        self.stop.load(Ordering::Relaxed)
+    }
+}
+
+/// One connection attempt: call `sink.opened()`, then `sink.message()` per
+/// frame until the stream ends. Sources should check `sink.is_closed()`
+/// every so often (e.g. with a read timeout) so `close()` is prompt.
+pub trait FeedSource: Send + 'static {
+    /// Run until the connection ends; `Err` describes why it failed
+    fn connect<T>(&mut self, sink: &mut FeedSink<T>) -> Result<(), String>;
+
+    /// Name for logs
+    fn describe(&self) -> String;
+}
+
+/// FeedBuilder - Configure a feed before it connects 📡
+pub struct FeedBuilder<S: FeedSource> {
+    source: S,
+    backoff: Backoff,
+}
+
+impl<S: FeedSource> FeedBuilder<S> {
+    /// Feed from any source
+    pub fn new(source: S) -> Self {
+        Self { source, backoff: Backoff::default() }
+    }
+
+    /// Reconnect policy
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Connect, decoding frames as JSON
+    pub fn json<T>(self) -> Feed<T>
+    where
+        T: DeserializeOwned + Clone + Send + 'static,
+    {
+        self.decode(|text| serde_json::from_str(text).map_err(|e| e.to_string()))
+    }
+
+    /// Connect, delivering frames as they are
+    pub fn text(self) -> Feed<String> {
+        self.decode(|text| Ok(text.to_string()))
+    }
+
+    /// Connect with a custom decoder (runs on the background thread)
+    pub fn decode<T, F>(self, decode: F) -> Feed<T>
+    where
+        T: Clone + Send + 'static,
+        F: Fn(&str) -> Result<T, String> + Send + Sync + 'static,
+    {
+        Feed::spawn(self.source, self.backoff, Arc::new(decode))
+    }
+}
+
+/// Feed - A live stream of typed messages, delivered on the UI thread 📡
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Clone, Deserialize)]
+/// struct Tick { symbol: String, price: f64 }
+///
+/// let prices = Signal::new(Vec::new());
+/// let feed = nebula_net::websocket("ws://localhost:9000/ticks")
+///     .send_on_open(r#"{"subscribe":"EURUSD"}"#)
+///     .feed()
+///     .json::<Tick>();
+/// feed.on_message(move |tick| prices.update(|p| [p.clone(), vec![tick.price]].concat()));
+///
+/// // every frame:
+/// feed.poll();
+/// ```
+pub struct Feed<T: Clone> {
+    /// Connection state
+    pub status: Signal<FeedStatus>,
+    /// Most recent message
+    pub latest: Signal<Option<T>>,
+    /// Most recent connection or decode error
+    pub error: Signal<Option<String>>,
+    receiver: Receiver<FeedEvent<T>>,
+    stop: Arc<AtomicBool>,
+    handlers: RefCell<Vec<Handler<T>>>,
+}
+
+impl<T: Clone + Send + 'static> Feed<T> {
+    fn spawn<S: FeedSource>(mut source: S, backoff: Backoff, decode: Decoder<T>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = stop.clone();
+        let name = source.describe();
+        info!("📡 Opening feed {}", name);
+
+        std::thread::spawn(move || {
+            let mut failures = 0;
+            while !worker_stop.load(Ordering::Relaxed) {
+                let mut sink = FeedSink { sender: sender.clone(), decode: decode.clone(), stop: worker_stop.clone(), opened: false };
+                let result = source.connect(&mut sink);
+                if worker_stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                if sink.opened {
+                    failures = 0;
+                }
+                if let Err(e) = result {
+                    warn!("📡 Feed {} failed: {}", name, e);
+                    let _ = sender.send(FeedEvent::Error(e));
+                }
+                failures += 1;
+                if backoff.max_attempts.is_some_and(|max| failures > max) {
+                    warn!("📡 Feed {} giving up after {} attempts", name, failures - 1);
+                    break;
+                }
+                let delay = backoff.delay(failures);
+                if sender.send(FeedEvent::Status(FeedStatus::Reconnecting { attempt: failures, delay })).is_err() {
+                    return;
+                }
+                // Sleep in slices so close() doesn't wait out a long backoff
+                let mut waited = Duration::ZERO;
+                while waited < delay && !worker_stop.load(Ordering::Relaxed) {
+                    let step = (delay - waited).min(Duration::from_millis(50));
+                    std::thread::sleep(step);
+                    waited += step;
+                }
+            }
+            let _ = sender.send(FeedEvent::Status(FeedStatus::Closed));
+        });
+
+        Self {
+            status: Signal::new(FeedStatus::Connecting),
+            latest: Signal::new(None),
+            error: Signal::new(None),
+            receiver,
+            stop,
+            handlers: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Call `handler` for every message (on the UI thread, from `poll`)
+    pub fn on_message<F>(&self, handler: F)
+    where
+        F: Fn(&T) + 'static,
+    {
+        self.handlers.borrow_mut().push(Box::new(handler));
+    }
+
+    /// Keep a signal set to the latest message
+    pub fn bind(&self, signal: &Signal<T>) {
+        let signal = signal.clone();
+        self.on_message(move |message| signal.set(message.clone()));
+    }
+
+    /// Dispatch `action(message)` to a store for every message
+    pub fn dispatch_to<S, A, F>(&self, store: &Store<S, A>, action: F)
+    where
+        S: Clone + 'static,
+        A: Debug + 'static,
+        F: Fn(&T) -> A + 'static,
+    {
+        let store = store.clone();
+        self.on_message(move |message| store.dispatch(action(message)));
+    }
+
+    /// Deliver everything that arrived since the last call; returns the message count
+    pub fn poll(&self) -> usize {
+        let mut delivered = 0;
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                FeedEvent::Status(status) => {
+                    if status == FeedStatus::Open {
+                        self.error.set(None);
+                    }
+                    self.status.set(status);
+                }
+                FeedEvent::Error(e) => self.error.set(Some(e)),
+                FeedEvent::Message(message) => {
+                    for handler in self.handlers.borrow().iter() {
+                        handler(&message);
+                    }
+                    self.latest.set(Some(message));
+                    delivered += 1;
+                }
+            }
+        }
+        delivered
+    }
+
+    /// Is the connection up right now?
+    pub fn is_open(&self) -> bool {
+        self.status.get() == FeedStatus::Open
+    }
+
+    /// Stop the connection and stop reconnecting
+    pub fn close(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<T: Clone> Drop for Feed<T> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::rc::Rc;
+    use std::time::Instant;
+
+    /// Refuses once, then serves a few frames, then refuses forever
+    struct Scripted {
+        attempts: u32,
+    }
+
+    impl FeedSource for Scripted {
+        fn connect<T>(&mut self, sink: &mut FeedSink<T>) -> Result<(), String> {
+            self.attempts += 1;
+            if self.attempts != 2 {
+                return Err("connection refused".to_string());
+            }
+            sink.opened();
+            for frame in [r#"{"price":1.5}"#, "not json", r#"{"price":2.5}"#] {
+                sink.message(frame);
+            }
+            Ok(())
+        }
+
+        fn describe(&self) -> String {
+            "scripted".to_string()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct Tick {
+        price: f64,
+    }
+
+    #[test]
+    fn messages_reach_signals_and_reconnects_back_off() {
+        let backoff = Backoff { initial: Duration::from_millis(1), max: Duration::from_millis(2), factor: 2.0, max_attempts: Some(2) };
+        let feed = FeedBuilder::new(Scripted { attempts: 0 }).backoff(backoff).json::<Tick>();
+        let price = Signal::new(Tick { price: 0.0 });
+        feed.bind(&price);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        feed.status.subscribe(move |status| log.borrow_mut().push(*status));
+
+        let start = Instant::now();
+        let mut delivered = 0;
+        while feed.status.get() != FeedStatus::Closed && start.elapsed() < Duration::from_secs(5) {
+            delivered += feed.poll();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(delivered, 2);
+        assert_eq!(price.get(), Tick { price: 2.5 });
+        assert_eq!(feed.latest.get(), Some(Tick { price: 2.5 }));
+        assert_eq!(feed.error.get().as_deref(), Some("connection refused"));
+        let statuses = seen.borrow();
+        assert_eq!(statuses[0], FeedStatus::Reconnecting { attempt: 1, delay: Duration::from_millis(1) });
+        assert_eq!(statuses[1], FeedStatus::Open);
+        // Opening reset the count; two more failures, then give up
+        assert_eq!(statuses[3], FeedStatus::Reconnecting { attempt: 2, delay: Duration::from_millis(2) });
+        assert_eq!(statuses.last(), Some(&FeedStatus::Closed));
+
+        assert_eq!(Backoff::default().delay(1), Duration::from_millis(500));
+        assert_eq!(Backoff::default().delay(20), Duration::from_secs(30));
+    }
+}
@@ -0,0 +1,47 @@
+//! # Nebula Net - Live data for Nebula UI
+//!
+//! Connect a WebSocket or Server-Sent Events stream and get typed messages
+//! in Signals and Stores, with reconnects handled for you. 📡
+//!
+//! Connections run on a background thread; `Feed::poll()` (once per frame)
+//! brings what arrived onto the UI thread.
+//!
+//! ## Features
+//! None are on by default; enable the transports you need:
+//! - `websocket`: `ws://` feeds via tungstenite
+//! - `sse`: Server-Sent Events over `http://` via ureq
+//! - `rustls` or `native-tls`: also `wss://` and `https://`
+//!
+//! ## Example:
+//! ```rust,ignore
+//! let cpu = Signal::new(0.0f64);
+//! let feed = nebula_net::sse("http://localhost:8080/metrics").event("cpu").feed().json::<f64>();
+//! feed.bind(&cpu);
+//!
+//! // in the frame loop
+//! feed.poll();
+//! ```
+
+pub mod feed;
+pub mod sse;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+pub use feed::{Backoff, Decoder, Feed, FeedBuilder, FeedSink, FeedSource, FeedStatus};
+pub use sse::{SseEvent, SseParser};
+#[cfg(feature = "sse")]
+pub use sse::SseSource;
+#[cfg(feature = "websocket")]
+pub use websocket::WebSocketSource;
+
+/// WebSocket source for a `ws://` URL
+#[cfg(feature = "websocket")]
+pub fn websocket(url: impl Into<String>) -> WebSocketSource {
+    WebSocketSource::new(url)
+}
+
+/// Server-Sent Events source for an `http://` URL
+#[cfg(feature = "sse")]
+pub fn sse(url: impl Into<String>) -> SseSource {
+    SseSource::new(url)
+}
@@ -0,0 +1,139 @@
+//! WebSocket feeds 🔌
+//!
+//! `ws://` connections through tungstenite, and `wss://` with the `rustls`
+//! or `native-tls` feature. Text frames (and UTF-8 binary frames) go to
+//! the feed's decoder; pings are answered for you.
+
+use crate::feed::{FeedBuilder, FeedSink, FeedSource};
+use std::io::ErrorKind;
+use std::time::Duration;
+use tracing::info;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::Message;
+
+/// WebSocketSource - Connects to a WebSocket server 🔌
+///
+/// # Example
+/// ```rust,ignore
+/// let feed = nebula_net::websocket("ws://localhost:9000/ticks")
+///     .send_on_open(r#"{"subscribe":"EURUSD"}"#)
+///     .feed()
+///     .json::<Tick>();
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebSocketSource {
+    url: String,
+    send_on_open: Vec<String>,
+    poll_interval: Duration,
+}
+
+impl WebSocketSource {
+    /// Source for a `ws://` URL (`wss://` with a TLS feature)
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into(), send_on_open: Vec::new(), poll_interval: Duration::from_millis(250) }
+    }
+
+    /// Send a text frame after every (re)connect, e.g. a subscription
+    pub fn send_on_open(mut self, text: impl Into<String>) -> Self {
+        self.send_on_open.push(text.into());
+        self
+    }
+
+    /// How often a quiet connection checks whether the feed was closed
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Configure the feed (backoff, decoding)
+    pub fn feed(self) -> FeedBuilder<Self> {
+        FeedBuilder::new(self)
+    }
+}
+
+impl FeedSource for WebSocketSource {
+    fn connect<T>(&mut self, sink: &mut FeedSink<T>) -> Result<(), String> {
+        let (mut socket, _) = tungstenite::connect(self.url.as_str()).map_err(|e| format!("Can't connect to {}: {}", self.url, e))?;
+        // A read timeout lets the loop notice close() on a quiet connection
+        let tcp = match socket.get_mut() {
+            MaybeTlsStream::Plain(stream) => Some(&*stream),
+            #[cfg(feature = "native-tls")]
+            MaybeTlsStream::NativeTls(stream) => Some(stream.get_ref()),
+            #[cfg(feature = "rustls")]
+            MaybeTlsStream::Rustls(stream) => Some(&stream.sock),
+            _ => None,
+        };
+        if let Some(tcp) = tcp {
+            tcp.set_read_timeout(Some(self.poll_interval)).map_err(|e| e.to_string())?;
+        }
+        for text in &self.send_on_open {
+            socket.send(Message::text(text.clone())).map_err(|e| format!("Can't send to {}: {}", self.url, e))?;
+        }
+        info!("🔌 Connected to {}", self.url);
+        sink.opened();
+
+        loop {
+            if sink.is_closed() {
+                let _ = socket.close(None);
+                return Ok(());
+            }
+            let text = match socket.read() {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Binary(bytes)) => match String::from_utf8(bytes) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                },
+                Ok(Message::Close(_)) | Err(tungstenite::Error::ConnectionClosed) => return Ok(()),
+                Ok(_) => continue,
+                Err(tungstenite::Error::Io(e)) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => continue,
+                Err(e) => return Err(format!("{} dropped: {}", self.url, e)),
+            };
+            if !sink.message(&text) {
+                let _ = socket.close(None);
+                return Ok(());
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        self.url.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed::{Backoff, FeedStatus};
+    use std::net::TcpListener;
+    use std::time::Instant;
+
+    #[test]
+    fn frames_from_a_local_server_reach_the_feed() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            // Echo the subscription back, then push two updates
+            let subscription = socket.read().unwrap();
+            socket.send(subscription).unwrap();
+            socket.send(Message::text("42")).unwrap();
+            socket.send(Message::Binary(b"43".to_vec())).unwrap();
+            socket.close(None).unwrap();
+            while socket.read().is_ok() {}
+        });
+
+        let backoff = Backoff { max_attempts: Some(0), ..Backoff::default() };
+        let feed = WebSocketSource::new(url).send_on_open("7").feed().backoff(backoff).json::<u32>();
+        let received = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = received.clone();
+        feed.on_message(move |value| log.borrow_mut().push(*value));
+
+        let start = Instant::now();
+        while feed.status.get() != FeedStatus::Closed && start.elapsed() < Duration::from_secs(5) {
+            feed.poll();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(*received.borrow(), vec![7, 42, 43]);
+    }
+}
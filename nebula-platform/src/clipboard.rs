@@ -0,0 +1,155 @@
+//! Clipboard - Copy and paste on desktop AND web! 📋
+//!
+//! Desktop talks to the OS clipboard directly; the browser clipboard is
+//! asynchronous, so reads always finish through a callback. On desktop
+//! the callback simply runs before `read_text` returns, which lets the
+//! same app code compile for both targets.
+//!
+//! When the system clipboard is unavailable (headless desktop, or the
+//! browser denied permission) an in-process clipboard takes over, so copy
+//! then paste inside the app keeps working.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::warn;
+
+/// Text clipboard shared by desktop and web targets
+pub struct Clipboard {
+    #[cfg(not(target_arch = "wasm32"))]
+    system: Option<arboard::Clipboard>,
+    /// Last text written by this app (used when the system clipboard fails)
+    local: Rc<RefCell<Option<String>>>,
+}
+
+impl Clipboard {
+    /// Connect to the system clipboard
+    pub fn new() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let system = match arboard::Clipboard::new() {
+            Ok(clipboard) => Some(clipboard),
+            Err(e) => {
+                warn!("⚠️ System clipboard unavailable, using in-app clipboard: {}", e);
+                None
+            }
+        };
+
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            system,
+            local: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// In-process clipboard only (never touches the system clipboard)
+    pub fn local() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            system: None,
+            local: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Is the system clipboard connected?
+    pub fn is_system(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.system.is_some()
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            web_sys::window().is_some()
+        }
+    }
+
+    /// Copy text
+    /// On the web the write finishes in the background; failures are logged
+    pub fn write_text(&mut self, text: impl Into<String>) -> Result<(), String> {
+        let text = text.into();
+        *self.local.borrow_mut() = Some(text.clone());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(system) = self.system.as_mut() {
+            system.set_text(text).map_err(|e| format!("Failed to copy: {}", e))?;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let promise = window.navigator().clipboard().write_text(&text);
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Err(e) = wasm_bindgen_futures::JsFuture::from(promise).await {
+                    warn!("⚠️ Browser clipboard write failed: {:?}", e);
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Paste text
+    /// `callback` runs immediately on desktop and once the browser answers on the web
+    pub fn read_text<F>(&mut self, callback: F)
+    where
+        F: FnOnce(Result<String, String>) + 'static,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(system) = self.system.as_mut() {
+            callback(system.get_text().map_err(|e| format!("Failed to paste: {}", e)));
+            return;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let promise = window.navigator().clipboard().read_text();
+            let local = self.local.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match wasm_bindgen_futures::JsFuture::from(promise).await {
+                    Ok(text) => callback(text.as_string().ok_or_else(|| "Clipboard is not text".to_string())),
+                    Err(e) => {
+                        warn!("⚠️ Browser clipboard read failed: {:?}", e);
+                        callback(read_local(&local));
+                    }
+                }
+            });
+            return;
+        }
+
+        callback(read_local(&self.local));
+    }
+}
+
+fn read_local(local: &RefCell<Option<String>>) -> Result<String, String> {
+    local.borrow().clone().ok_or_else(|| "Clipboard is empty".to_string())
+}
+
+impl Default for Clipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Clipboard").field("system", &self.is_system()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_clipboard_round_trip() {
+        let mut clipboard = Clipboard::local();
+        assert!(!clipboard.is_system());
+
+        let pasted = Rc::new(RefCell::new(None));
+        let sink = pasted.clone();
+        clipboard.read_text(move |result| *sink.borrow_mut() = Some(result));
+        assert!(pasted.borrow().as_ref().unwrap().is_err());
+
+        clipboard.write_text("hello").unwrap();
+        let sink = pasted.clone();
+        clipboard.read_text(move |result| *sink.borrow_mut() = Some(result));
+        assert_eq!(pasted.borrow().clone(), Some(Ok("hello".to_string())));
+    }
+}
@@ -0,0 +1,197 @@
+//! Contrast - Read the OS high-contrast setting ♿
+//!
+//! Low-vision users turn on high contrast (Windows), Increase Contrast
+//! (macOS), the High Contrast theme (GNOME) or forced colors (browsers)
+//! and expect apps to follow. `system_high_contrast` reads it without
+//! native code:
+//! - Linux (GNOME and friends): `gsettings … a11y.interface high-contrast`
+//! - macOS: `defaults read com.apple.universalaccess increaseContrast`
+//! - Windows: the `Flags` value under `Accessibility\HighContrast` (bit 0 = on)
+//! - Browser: the `forced-colors: active` or `prefers-contrast: more` media queries
+//!
+//! Spawning those tools takes tens of milliseconds, so the UI thread never
+//! does it: `apply_system_high_contrast` starts the check on a background
+//! thread and applies the last cached answer, and `update_system_high_contrast`
+//! (once per frame) switches the app-wide `ThemeProvider` to
+//! `Theme::high_contrast` (and back) when a check lands with a new answer.
+//! Only changes of the OS answer are applied, so an app that turns high
+//! contrast on or off itself keeps its choice until the OS setting flips.
+
+use nebula_core::ThemeProvider;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use tracing::info;
+
+/// Last answer from the OS: 0 = not checked yet, 1 = off, 2 = on
+static CACHED: AtomicU8 = AtomicU8::new(0);
+/// OS answer last applied to the theme (same encoding as CACHED)
+static APPLIED: AtomicU8 = AtomicU8::new(0);
+/// Is a background check running?
+static CHECKING: AtomicBool = AtomicBool::new(false);
+
+/// The OS setting, or None when it can't be read
+///
+/// Blocks while the platform tool runs; UI code should use
+/// `apply_system_high_contrast` / `cached_high_contrast` instead.
+pub fn system_high_contrast() -> Option<bool> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = web_sys::window()?;
+        let matches = |query: &str| window.match_media(query).ok().flatten().is_some_and(|list| list.matches());
+        Some(matches("(forced-colors: active)") || matches("(prefers-contrast: more)"))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        type Probe = (&'static str, &'static [&'static str], fn(&str) -> Option<bool>);
+        let (program, args, parse): Probe = if cfg!(target_os = "macos") {
+            ("defaults", &["read", "com.apple.universalaccess", "increaseContrast"], parse_macos)
+        } else if cfg!(target_os = "windows") {
+            ("reg", &["query", r"HKCU\Control Panel\Accessibility\HighContrast", "/v", "Flags"], parse_windows)
+        } else {
+            ("gsettings", &["get", "org.gnome.desktop.a11y.interface", "high-contrast"], parse_gnome)
+        };
+        let output = std::process::Command::new(program).args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// The answer from the last finished check (None before the first one)
+pub fn cached_high_contrast() -> Option<bool> {
+    match CACHED.load(Ordering::Relaxed) {
+        1 => Some(false),
+        2 => Some(true),
+        _ => None,
+    }
+}
+
+fn store(enabled: Option<bool>) {
+    CACHED.store(enabled.map_or(0, |on| if on { 2 } else { 1 }), Ordering::Relaxed);
+}
+
+/// Re-read the OS setting in the background (no-op while a check is running)
+pub fn refresh_high_contrast() {
+    if CHECKING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        // Media queries are cheap and there are no threads to move them to
+        store(system_high_contrast());
+        CHECKING.store(false, Ordering::Release);
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::spawn(|| {
+        store(system_high_contrast());
+        CHECKING.store(false, Ordering::Release);
+    });
+}
+
+/// Start a check of the OS setting and apply the cached answer (call at
+/// startup and when the window regains focus); returns whether high
+/// contrast is on now. Never blocks on the OS.
+pub fn apply_system_high_contrast() -> bool {
+    refresh_high_contrast();
+    update_system_high_contrast()
+}
+
+/// Apply a finished check to the app-wide theme if the OS answer changed
+/// since the last one applied (cheap, call once per frame); returns
+/// whether high contrast is on
+pub fn update_system_high_contrast() -> bool {
+    let provider = ThemeProvider::global();
+    let cached = CACHED.load(Ordering::Relaxed);
+    if cached != 0 && APPLIED.swap(cached, Ordering::Relaxed) != cached {
+        let enabled = cached == 2;
+        info!("♿ System high contrast: {}", if enabled { "on" } else { "off" });
+        provider.set_high_contrast(enabled);
+    }
+    provider.is_high_contrast()
+}
+
+/// `gsettings` prints "true" / "false"
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_gnome(output: &str) -> Option<bool> {
+    match output.trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// `defaults` prints 1 when Increase Contrast is on
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_macos(output: &str) -> Option<bool> {
+    match output.trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// `reg query` prints "    Flags    REG_SZ    127"; HCF_HIGHCONTRASTON is bit 0
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_windows(output: &str) -> Option<bool> {
+    let line = output.lines().find(|line| line.trim_start().starts_with("Flags"))?;
+    let flags: u32 = line.split_whitespace().last()?.parse().ok()?;
+    Some(flags & 1 == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// The cache and the global theme are shared between tests
+    static GLOBALS: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn parses_each_platforms_answer() {
+        assert_eq!(parse_gnome("true\n"), Some(true));
+        assert_eq!(parse_gnome("false\n"), Some(false));
+        assert_eq!(parse_macos("1\n"), Some(true));
+        assert_eq!(parse_macos("The domain/default pair does not exist"), None);
+        let windows = "\r\nHKEY_CURRENT_USER\\Control Panel\\Accessibility\\HighContrast\r\n    Flags    REG_SZ    127\r\n";
+        assert_eq!(parse_windows(windows), Some(true));
+        assert_eq!(parse_windows("    Flags    REG_SZ    126"), Some(false));
+        assert_eq!(parse_windows(""), None);
+    }
+
+    #[test]
+    fn cache_round_trips() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        store(Some(true));
+        assert_eq!(cached_high_contrast(), Some(true));
+        store(Some(false));
+        assert_eq!(cached_high_contrast(), Some(false));
+        store(None);
+        assert_eq!(cached_high_contrast(), None);
+    }
+
+    #[test]
+    fn app_override_survives_repeated_updates() {
+        let _globals = GLOBALS.lock().unwrap_or_else(|e| e.into_inner());
+        let provider = ThemeProvider::global();
+
+        store(Some(true));
+        assert!(update_system_high_contrast());
+
+        // The app turns it off itself; the unchanged OS answer doesn't revert that
+        provider.set_high_contrast(false);
+        for _ in 0..3 {
+            assert!(!update_system_high_contrast());
+        }
+
+        // A new OS answer is applied once, then the app is in charge again
+        store(Some(false));
+        assert!(!update_system_high_contrast());
+        provider.set_high_contrast(true);
+        assert!(update_system_high_contrast());
+        store(Some(true));
+        assert!(update_system_high_contrast());
+
+        provider.set_high_contrast(false);
+        store(None);
+    }
+}
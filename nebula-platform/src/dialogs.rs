@@ -0,0 +1,234 @@
+//! Dialogs - Native open and save pickers! 🗂️
+//!
+//! `open_file`, `open_files`, `pick_folder` and `save_file` show the
+//! system's own file dialogs (GTK/portal on Linux, NSOpenPanel on macOS,
+//! IFileDialog on Windows). They are async so the UI keeps painting while
+//! the dialog is up; await them from whatever executor the app runs.
+//!
+//! Opened files come back with their real path and their bytes already
+//! read, ready for `FileUpload::add_files` or `FileBrowser::navigate_to`.
+//! The browser has no native dialogs we can drive from here, so on the
+//! web these return nothing (use `FileDropTarget` there).
+
+use crate::file_drop::{DroppedFile, KNOWN_TYPES};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Named group of extensions shown in the dialog's type menu
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFilter {
+    pub name: String,
+    /// Extensions without the dot ("png", not ".png")
+    pub extensions: Vec<String>,
+}
+
+/// Options for a file dialog
+///
+/// # Example
+/// ```rust,ignore
+/// let dialog = FileDialog::new().title("Attach files").accept("image/*,application/pdf");
+/// let picked = dialogs::open_files(dialog).await;
+/// upload.add_files(picked.into_iter().map(|p| UploadedFile::from_bytes(p.file.name, p.file.mime_type, p.file.data)).collect());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileDialog {
+    pub title: Option<String>,
+    /// Folder the dialog starts in
+    pub directory: Option<PathBuf>,
+    /// Suggested name (save dialogs)
+    pub file_name: Option<String>,
+    pub filters: Vec<FileFilter>,
+}
+
+impl FileDialog {
+    /// Dialog with the platform defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the window title
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Start in this folder
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Suggest a file name
+    pub fn file_name(mut self, name: impl Into<String>) -> Self {
+        self.file_name = Some(name.into());
+        self
+    }
+
+    /// Add a type filter
+    pub fn filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push(FileFilter {
+            name: name.into(),
+            extensions: extensions.iter().map(|ext| ext.trim_start_matches('.').to_string()).collect(),
+        });
+        self
+    }
+
+    /// Filter by an HTML-style accept list ("image/*,application/pdf,.md")
+    /// MIME types map to the extensions we know; `*/*` adds no filter
+    pub fn accept(self, accept: &str) -> Self {
+        let mut extensions: Vec<&str> = Vec::new();
+        for pattern in accept.split(',').map(str::trim).filter(|pattern| !pattern.is_empty()) {
+            if pattern == "*/*" {
+                return self;
+            }
+            if let Some(extension) = pattern.strip_prefix('.') {
+                extensions.push(extension);
+                continue;
+            }
+            let prefix = pattern.strip_suffix("/*").map(|group| format!("{}/", group));
+            for (extension, mime_type) in KNOWN_TYPES {
+                let matches = match &prefix {
+                    Some(prefix) => mime_type.starts_with(prefix.as_str()),
+                    None => *mime_type == pattern,
+                };
+                if matches {
+                    extensions.push(extension);
+                }
+            }
+        }
+        if extensions.is_empty() {
+            return self;
+        }
+        extensions.dedup();
+        self.filter("Supported files", &extensions)
+    }
+}
+
+/// A file picked in an open dialog
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickedFile {
+    pub path: PathBuf,
+    /// Name, guessed MIME type and bytes
+    pub file: DroppedFile,
+}
+
+/// Pick one file (None if the user cancelled)
+pub async fn open_file(dialog: FileDialog) -> Option<PickedFile> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let handle = native::dialog(&dialog).pick_file().await?;
+        native::read(handle).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_unsupported("open_file", dialog)
+    }
+}
+
+/// Pick any number of files (empty if the user cancelled)
+pub async fn open_files(dialog: FileDialog) -> Vec<PickedFile> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut picked = Vec::new();
+        for handle in native::dialog(&dialog).pick_files().await.unwrap_or_default() {
+            picked.extend(native::read(handle).await);
+        }
+        info!("🗂️ {} file(s) picked", picked.len());
+        picked
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_unsupported::<()>("open_files", dialog);
+        Vec::new()
+    }
+}
+
+/// Pick a folder
+pub async fn pick_folder(dialog: FileDialog) -> Option<PathBuf> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        native::dialog(&dialog).pick_folder().await.map(|handle| handle.path().to_path_buf())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_unsupported("pick_folder", dialog)
+    }
+}
+
+/// Ask where to save and write `data` there
+/// Returns the chosen path, or None if the user cancelled
+pub async fn save_file(dialog: FileDialog, data: &[u8]) -> Result<Option<PathBuf>, String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let Some(handle) = native::dialog(&dialog).save_file().await else {
+            return Ok(None);
+        };
+        let path = handle.path().to_path_buf();
+        handle.write(data).await.map_err(|e| format!("Failed to save {}: {}", path.display(), e))?;
+        info!("🗂️ Saved {} bytes to {}", data.len(), path.display());
+        Ok(Some(path))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = data;
+        Ok(web_unsupported("save_file", dialog))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn web_unsupported<T>(call: &str, _dialog: FileDialog) -> Option<T> {
+    tracing::warn!("⚠️ dialogs::{} isn't available on the web", call);
+    None
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use super::{FileDialog, PickedFile};
+    use crate::file_drop::DroppedFile;
+    use tracing::warn;
+
+    pub fn dialog(options: &FileDialog) -> rfd::AsyncFileDialog {
+        let mut dialog = rfd::AsyncFileDialog::new();
+        if let Some(title) = &options.title {
+            dialog = dialog.set_title(title);
+        }
+        if let Some(directory) = &options.directory {
+            dialog = dialog.set_directory(directory);
+        }
+        if let Some(name) = &options.file_name {
+            dialog = dialog.set_file_name(name);
+        }
+        for filter in &options.filters {
+            dialog = dialog.add_filter(&filter.name, &filter.extensions);
+        }
+        dialog
+    }
+
+    pub async fn read(handle: rfd::FileHandle) -> Option<PickedFile> {
+        let path = handle.path().to_path_buf();
+        match DroppedFile::from_path(&path) {
+            Ok(file) => Some(PickedFile { path, file }),
+            Err(e) => {
+                warn!("⚠️ {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_list_becomes_extension_filter() {
+        let dialog = FileDialog::new().accept("image/*, application/pdf, .md");
+        let extensions = &dialog.filters[0].extensions;
+        assert!(extensions.iter().any(|ext| ext == "png") && extensions.iter().any(|ext| ext == "jpeg"));
+        assert!(extensions.iter().any(|ext| ext == "pdf") && extensions.iter().any(|ext| ext == "md"));
+        assert!(!extensions.iter().any(|ext| ext == "zip"));
+
+        assert!(FileDialog::new().accept("*/*").filters.is_empty());
+        assert_eq!(FileDialog::new().filter("Text", &[".txt"]).filters[0].extensions, vec!["txt".to_string()]);
+    }
+}
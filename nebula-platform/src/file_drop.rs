@@ -0,0 +1,193 @@
+//! File Drop - Files dragged in from outside the app! 📂
+//!
+//! Dropped files arrive as `DroppedFile`s with their bytes already read,
+//! ready for `FileUpload::add_files`. On desktop, `NebulaWindow` reads
+//! files dropped on the window and hands them to
+//! `InputHandler::on_file_dropped`. On the web, `FileDropTarget` listens
+//! for HTML5 drag-and-drop on an element and reads the files with
+//! `Blob.arrayBuffer()`.
+
+/// A file dropped onto the app
+#[derive(Debug, Clone, PartialEq)]
+pub struct DroppedFile {
+    pub name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+impl DroppedFile {
+    /// Create a dropped file (an empty MIME type is guessed from the name)
+    pub fn new(name: impl Into<String>, mime_type: impl Into<String>, data: Vec<u8>) -> Self {
+        let name = name.into();
+        let mut mime_type = mime_type.into();
+        if mime_type.is_empty() {
+            mime_type = mime_type_from_name(&name).to_string();
+        }
+        Self { name, mime_type, data }
+    }
+
+    /// Read a file from disk (the MIME type is guessed from its name)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read dropped file {}: {}", path.display(), e))?;
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        Ok(Self::new(name, "", data))
+    }
+
+    /// Size in bytes
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// Extensions we know the MIME type of
+pub const KNOWN_TYPES: &[(&str, &str)] = &[
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml"),
+    ("mp4", "video/mp4"),
+    ("webm", "video/webm"),
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("txt", "text/plain"),
+    ("csv", "text/csv"),
+    ("html", "text/html"),
+    ("htm", "text/html"),
+    ("json", "application/json"),
+    ("pdf", "application/pdf"),
+    ("zip", "application/zip"),
+];
+
+/// Guess a MIME type from a file extension
+pub fn mime_type_from_name(name: &str) -> &'static str {
+    let extension = name.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).unwrap_or_default();
+    KNOWN_TYPES
+        .iter()
+        .find(|(known, _)| *known == extension)
+        .map_or("application/octet-stream", |(_, mime_type)| mime_type)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use web::FileDropTarget;
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::DroppedFile;
+    use tracing::{info, warn};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::{DragEvent, Event, EventTarget, File};
+
+    type Listener = (&'static str, Closure<dyn FnMut(Event)>);
+
+    /// HTML5 drag-and-drop listener on a page element
+    ///
+    /// `on_hover` fires with true while files are dragged over the element
+    /// and false when they leave or drop; `on_drop` gets every dropped file
+    /// once all of them are read. Listeners live as long as the target.
+    pub struct FileDropTarget {
+        target: EventTarget,
+        listeners: Vec<Listener>,
+    }
+
+    impl FileDropTarget {
+        /// Start listening on the element with this id
+        pub fn new<H, D>(element_id: &str, on_hover: H, on_drop: D) -> Result<Self, String>
+        where
+            H: Fn(bool) + 'static,
+            D: Fn(Vec<DroppedFile>) + 'static,
+        {
+            let document = web_sys::window()
+                .and_then(|window| window.document())
+                .ok_or("No document")?;
+            let target: EventTarget = document
+                .get_element_by_id(element_id)
+                .ok_or_else(|| format!("Element '{}' not found", element_id))?
+                .into();
+
+            let on_hover = std::rc::Rc::new(on_hover);
+            let on_drop = std::rc::Rc::new(on_drop);
+            let mut listeners: Vec<Listener> = Vec::new();
+
+            for kind in ["dragenter", "dragover"] {
+                let on_hover = on_hover.clone();
+                listeners.push((kind, Closure::new(move |event: Event| {
+                    // Without preventDefault the browser opens the file instead
+                    event.prevent_default();
+                    on_hover(true);
+                })));
+            }
+
+            let hover_end = on_hover.clone();
+            listeners.push(("dragleave", Closure::new(move |_event: Event| hover_end(false))));
+
+            listeners.push(("drop", Closure::new(move |event: Event| {
+                event.prevent_default();
+                on_hover(false);
+                let files = event
+                    .dyn_ref::<DragEvent>()
+                    .and_then(|event| event.data_transfer())
+                    .and_then(|transfer| transfer.files());
+                let Some(files) = files else {
+                    return;
+                };
+                let files: Vec<File> = (0..files.length()).filter_map(|i| files.get(i)).collect();
+                let on_drop = on_drop.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let mut dropped = Vec::with_capacity(files.len());
+                    for file in files {
+                        match read_file(&file).await {
+                            Ok(file) => dropped.push(file),
+                            Err(e) => warn!("⚠️ Failed to read dropped file {}: {}", file.name(), e),
+                        }
+                    }
+                    info!("📂 {} file(s) dropped", dropped.len());
+                    on_drop(dropped);
+                });
+            })));
+
+            for (kind, closure) in &listeners {
+                target
+                    .add_event_listener_with_callback(kind, closure.as_ref().unchecked_ref())
+                    .map_err(|e| format!("Failed to listen for {}: {:?}", kind, e))?;
+            }
+
+            Ok(Self { target, listeners })
+        }
+
+        /// Stop listening
+        pub fn stop(self) {
+            for (kind, closure) in &self.listeners {
+                let _ = self.target.remove_event_listener_with_callback(kind, closure.as_ref().unchecked_ref());
+            }
+        }
+    }
+
+    async fn read_file(file: &File) -> Result<DroppedFile, String> {
+        let buffer = wasm_bindgen_futures::JsFuture::from(file.array_buffer())
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        let data = js_sys::Uint8Array::new(&buffer).to_vec();
+        Ok(DroppedFile::new(file.name(), file.type_(), data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropped_file_guesses_mime_type() {
+        let file = DroppedFile::new("Photo.JPG", "", vec![0; 16]);
+        assert_eq!(file.mime_type, "image/jpeg");
+        assert_eq!(file.size(), 16);
+
+        let file = DroppedFile::new("notes", "text/markdown", vec![]);
+        assert_eq!(file.mime_type, "text/markdown");
+        assert_eq!(mime_type_from_name("archive.tar"), "application/octet-stream");
+    }
+}
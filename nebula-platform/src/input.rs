@@ -1,4 +1,7 @@
-use winit::event::{ElementState, KeyEvent, MouseButton};
+use crate::file_drop::DroppedFile;
+use nebula_core::focus::{FocusKey, ImeEvent, ModifierKeys};
+use nebula_core::InputSettings;
+use winit::event::{ElementState, Ime, KeyEvent, Modifiers, MouseButton, MouseScrollDelta, TouchPhase};
 use winit::keyboard::{KeyCode, PhysicalKey};
 
 /// Mouse button press event
@@ -23,6 +26,27 @@ impl From<MouseButton> for MouseButtonEvent {
     }
 }
 
+/// Stage of a touch (one finger, identified by its id)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhaseEvent {
+    Started,
+    Moved,
+    Ended,
+    /// The OS took the touch back (e.g. a system gesture)
+    Cancelled,
+}
+
+impl From<TouchPhase> for TouchPhaseEvent {
+    fn from(phase: TouchPhase) -> Self {
+        match phase {
+            TouchPhase::Started => TouchPhaseEvent::Started,
+            TouchPhase::Moved => TouchPhaseEvent::Moved,
+            TouchPhase::Ended => TouchPhaseEvent::Ended,
+            TouchPhase::Cancelled => TouchPhaseEvent::Cancelled,
+        }
+    }
+}
+
 /// Mouse position
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct MousePosition {
@@ -70,6 +94,8 @@ pub enum Key {
     Meta, // Command on Mac, Windows key on Windows
     
     // Other
+    /// The dedicated menu key (next to right Ctrl on PC keyboards)
+    ContextMenu,
     Unknown,
 }
 
@@ -150,11 +176,52 @@ impl From<KeyCode> for Key {
             KeyCode::AltLeft | KeyCode::AltRight => Key::Alt,
             KeyCode::SuperLeft | KeyCode::SuperRight => Key::Meta,
             
+            KeyCode::ContextMenu => Key::ContextMenu,
             _ => Key::Unknown,
         }
     }
 }
 
+impl Key {
+    /// Is this Shift, Control, Alt or Meta?
+    pub fn is_modifier(self) -> bool {
+        matches!(self, Key::Shift | Key::Control | Key::Alt | Key::Meta)
+    }
+
+    /// Does this key open the focused element's context menu?
+    /// (the menu key, or Shift+F10 on keyboards without one)
+    pub fn opens_context_menu(self, modifiers: ModifierKeys) -> bool {
+        self == Key::ContextMenu || (self == Key::F10 && modifiers.shift && !modifiers.control && !modifiers.alt)
+    }
+
+    /// The key as the focus and shortcut systems see it
+    /// (letters lowercase; modifiers and function keys have none)
+    pub fn focus_key(self) -> Option<FocusKey> {
+        use Key::*;
+        const LETTERS: [Key; 26] = [A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z];
+        const DIGITS: [Key; 10] = [Num0, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9];
+        if let Some(index) = LETTERS.iter().position(|&letter| letter == self) {
+            return Some(FocusKey::Char((b'a' + index as u8) as char));
+        }
+        if let Some(index) = DIGITS.iter().position(|&digit| digit == self) {
+            return Some(FocusKey::Char((b'0' + index as u8) as char));
+        }
+        Some(match self {
+            Escape => FocusKey::Escape,
+            Enter => FocusKey::Enter,
+            Space => FocusKey::Space,
+            Backspace => FocusKey::Backspace,
+            Tab => FocusKey::Tab,
+            Delete => FocusKey::Delete,
+            ArrowUp => FocusKey::Up,
+            ArrowDown => FocusKey::Down,
+            ArrowLeft => FocusKey::Left,
+            ArrowRight => FocusKey::Right,
+            _ => return None,
+        })
+    }
+}
+
 /// Input event handler trait
 pub trait InputHandler {
     /// Called when a mouse button is pressed
@@ -172,7 +239,20 @@ pub trait InputHandler {
         let _ = position; // Default: do nothing
     }
     
-    /// Called when a key is pressed
+    /// Called when the wheel or touchpad scrolls, in logical pixels ready
+    /// for `scroll_by` (positive y scrolls down; wheel lines are converted
+    /// with `InputSettings`)
+    fn on_scroll(&mut self, dx: f32, dy: f32, position: MousePosition) {
+        let _ = (dx, dy, position); // Default: do nothing
+    }
+
+    /// Called for each touch point; `id` tells fingers apart
+    fn on_touch(&mut self, phase: TouchPhaseEvent, id: u64, position: MousePosition) {
+        let _ = (phase, id, position); // Default: do nothing
+    }
+
+    /// Called when a key is pressed (and while it's held, if
+    /// `InputSettings::key_repeat` is on)
     fn on_key_down(&mut self, key: Key) {
         let _ = key; // Default: do nothing
     }
@@ -181,6 +261,32 @@ pub trait InputHandler {
     fn on_key_up(&mut self, key: Key) {
         let _ = key; // Default: do nothing
     }
+
+    /// Called when the held modifier keys change (before the key event they modify)
+    fn on_modifiers_changed(&mut self, modifiers: ModifierKeys) {
+        let _ = modifiers; // Default: do nothing
+    }
+
+    /// Called for input method (CJK) composition events
+    fn on_ime(&mut self, event: ImeEvent) {
+        let _ = event; // Default: do nothing
+    }
+
+    /// Called while files from the OS are dragged over the window (false when they leave or drop)
+    fn on_file_hover(&mut self, position: MousePosition, hovering: bool) {
+        let _ = (position, hovering); // Default: do nothing
+    }
+
+    /// Called once per file dropped on the window, already read from disk
+    fn on_file_dropped(&mut self, file: DroppedFile, position: MousePosition) {
+        let _ = (file, position); // Default: do nothing
+    }
+
+    /// Logical caret area for the IME candidate window (x, y, width, height)
+    /// Asked after every key and IME event; None leaves it where it was
+    fn ime_cursor_area(&self) -> Option<(f32, f32, f32, f32)> {
+        None
+    }
 }
 
 /// Helper to extract key from KeyEvent
@@ -192,6 +298,41 @@ pub fn key_from_event(event: &KeyEvent) -> Option<Key> {
     }
 }
 
+/// Helper to read winit's modifier state
+pub fn modifiers_from_event(modifiers: &Modifiers) -> ModifierKeys {
+    let state = modifiers.state();
+    ModifierKeys {
+        shift: state.shift_key(),
+        control: state.control_key(),
+        alt: state.alt_key(),
+        meta: state.super_key(),
+    }
+}
+
+/// Helper to convert winit's IME event
+pub fn ime_from_event(ime: &Ime) -> ImeEvent {
+    match ime {
+        Ime::Enabled => ImeEvent::Enabled,
+        Ime::Preedit(text, cursor) => ImeEvent::Preedit { text: text.clone(), cursor: *cursor },
+        Ime::Commit(text) => ImeEvent::Commit(text.clone()),
+        Ime::Disabled => ImeEvent::Disabled,
+    }
+}
+
+/// Helper to turn a wheel event into logical pixels for `scroll_by`
+pub fn scroll_from_event(delta: &MouseScrollDelta, scale_factor: f64) -> (f32, f32) {
+    match delta {
+        MouseScrollDelta::LineDelta(x, y) => {
+            let settings = InputSettings::current();
+            (-settings.lines_to_pixels(*x), -settings.lines_to_pixels(*y))
+        }
+        MouseScrollDelta::PixelDelta(position) => {
+            let logical = position.to_logical::<f64>(scale_factor);
+            (-logical.x as f32, -logical.y as f32)
+        }
+    }
+}
+
 /// Helper to check if key event is pressed
 pub fn is_key_pressed(event: &KeyEvent) -> bool {
     event.state == ElementState::Pressed
@@ -228,4 +369,38 @@ mod tests {
         assert_eq!(Key::from(KeyCode::Space), Key::Space);
         assert_eq!(Key::from(KeyCode::ArrowUp), Key::ArrowUp);
     }
+
+    #[test]
+    fn keys_map_to_focus_keys() {
+        assert_eq!(Key::N.focus_key(), Some(FocusKey::Char('n')));
+        assert_eq!(Key::Num7.focus_key(), Some(FocusKey::Char('7')));
+        assert_eq!(Key::ArrowLeft.focus_key(), Some(FocusKey::Left));
+        assert_eq!(Key::Meta.focus_key(), None);
+        assert!(Key::Meta.is_modifier() && !Key::Z.is_modifier());
+    }
+
+    #[test]
+    fn context_menu_key_and_shift_f10_open_menus() {
+        let shift = ModifierKeys { shift: true, ..Default::default() };
+        assert_eq!(Key::from(KeyCode::ContextMenu), Key::ContextMenu);
+        assert!(Key::ContextMenu.opens_context_menu(ModifierKeys::default()));
+        assert!(Key::F10.opens_context_menu(shift));
+        assert!(!Key::F10.opens_context_menu(ModifierKeys::default()));
+        assert!(!Key::F10.opens_context_menu(ModifierKeys { control: true, ..shift }));
+    }
+
+    #[test]
+    fn wheel_lines_scroll_by_input_settings() {
+        let lines = MouseScrollDelta::LineDelta(0.0, -1.0);
+        assert_eq!(scroll_from_event(&lines, 2.0), (-0.0, InputSettings::current().lines_to_pixels(1.0)));
+        let pixels = MouseScrollDelta::PixelDelta(winit::dpi::PhysicalPosition::new(0.0, 40.0));
+        assert_eq!(scroll_from_event(&pixels, 2.0), (-0.0, -20.0));
+    }
+
+    #[test]
+    fn ime_conversion() {
+        let preedit = Ime::Preedit("にほん".to_string(), Some((9, 9)));
+        assert_eq!(ime_from_event(&preedit), ImeEvent::Preedit { text: "にほん".to_string(), cursor: Some((9, 9)) });
+        assert_eq!(ime_from_event(&Ime::Commit("日本".to_string())), ImeEvent::Commit("日本".to_string()));
+    }
 }
@@ -0,0 +1,149 @@
+//! Input Settings - Read the OS mouse and keyboard tuning 🖱️
+//!
+//! Double-click speed, drag distance, wheel lines and key repeat live in
+//! the OS settings. `system_input_settings` reads what it can without
+//! native code and keeps the defaults for the rest:
+//! - Linux (GNOME and friends): `gsettings … peripherals.mouse / keyboard`
+//! - macOS: `defaults read -g` (double-click threshold, key repeat)
+//! - Windows: the `Control Panel\Mouse`, `Desktop` and `Keyboard` registry values
+//! - Browser: the defaults (pages can't see these settings)
+//!
+//! `apply_system_input_settings` hands the result to
+//! `InputSettings::set_system`, which components follow unless the app
+//! overrides it.
+
+use nebula_core::InputSettings;
+use tracing::info;
+
+/// The OS settings, with defaults for anything that can't be read
+pub fn system_input_settings() -> InputSettings {
+    #[allow(unused_mut)]
+    let mut settings = InputSettings::DEFAULT;
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::time::Duration;
+        let millis = |ms: f32| Duration::from_millis(ms.max(1.0) as u64);
+        if cfg!(target_os = "macos") {
+            if let Some(seconds) = defaults("com.apple.mouse.doubleClickThreshold") {
+                settings.double_click_interval = millis(seconds * 1000.0);
+            }
+            // Key repeat values are in ticks of 15 ms
+            if let Some(ticks) = defaults("InitialKeyRepeat") {
+                settings.key_repeat_delay = millis(ticks * 15.0);
+            }
+            if let Some(ticks) = defaults("KeyRepeat") {
+                settings.key_repeat_interval = millis(ticks * 15.0);
+            }
+        } else if cfg!(target_os = "windows") {
+            const MOUSE: &str = r"HKCU\Control Panel\Mouse";
+            const DESKTOP: &str = r"HKCU\Control Panel\Desktop";
+            const KEYBOARD: &str = r"HKCU\Control Panel\Keyboard";
+            if let Some(ms) = registry(MOUSE, "DoubleClickSpeed") {
+                settings.double_click_interval = millis(ms);
+            }
+            // Widths are of a box centered on the press
+            if let Some(width) = registry(MOUSE, "DoubleClickWidth") {
+                settings.double_click_distance = width / 2.0;
+            }
+            if let Some(width) = registry(DESKTOP, "DragWidth") {
+                settings.drag_threshold = width / 2.0;
+            }
+            if let Some(lines) = registry(DESKTOP, "WheelScrollLines") {
+                settings.wheel_scroll_lines = lines;
+            }
+            // Delay 0-3 is 250-1000 ms; speed 0-31 is about 2.5-30 repeats a second
+            if let Some(delay) = registry(KEYBOARD, "KeyboardDelay") {
+                settings.key_repeat_delay = millis((delay + 1.0) * 250.0);
+            }
+            if let Some(speed) = registry(KEYBOARD, "KeyboardSpeed") {
+                settings.key_repeat_interval = millis(1000.0 / (2.5 + speed * 27.5 / 31.0));
+            }
+        } else {
+            const MOUSE: &str = "org.gnome.desktop.peripherals.mouse";
+            const KEYBOARD: &str = "org.gnome.desktop.peripherals.keyboard";
+            if let Some(ms) = gsettings(MOUSE, "double-click").and_then(|value| parse_number(&value)) {
+                settings.double_click_interval = millis(ms);
+            }
+            if let Some(pixels) = gsettings(MOUSE, "drag-threshold").and_then(|value| parse_number(&value)) {
+                settings.drag_threshold = pixels;
+            }
+            if let Some(repeat) = gsettings(KEYBOARD, "repeat") {
+                settings.key_repeat = repeat.trim() != "false";
+            }
+            if let Some(ms) = gsettings(KEYBOARD, "delay").and_then(|value| parse_number(&value)) {
+                settings.key_repeat_delay = millis(ms);
+            }
+            if let Some(ms) = gsettings(KEYBOARD, "repeat-interval").and_then(|value| parse_number(&value)) {
+                settings.key_repeat_interval = millis(ms);
+            }
+        }
+    }
+    settings
+}
+
+/// Read the OS settings into `InputSettings` (call at startup and when
+/// the window regains focus); returns what was applied
+pub fn apply_system_input_settings() -> InputSettings {
+    let settings = system_input_settings();
+    info!(
+        "🖱️ System input settings: double-click {:?}, drag {}px, {} wheel lines",
+        settings.double_click_interval, settings.drag_threshold, settings.wheel_scroll_lines
+    );
+    InputSettings::set_system(settings);
+    settings
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program).args(args).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn gsettings(schema: &str, key: &str) -> Option<String> {
+    run("gsettings", &["get", schema, key])
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn defaults(key: &str) -> Option<f32> {
+    parse_number(&run("defaults", &["read", "-g", key])?)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn registry(path: &str, name: &str) -> Option<f32> {
+    parse_registry(&run("reg", &["query", path, "/v", name])?, name)
+}
+
+/// Last word of a value: "uint32 500" (gsettings), "0.5" (defaults)
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_number(output: &str) -> Option<f32> {
+    output.split_whitespace().last()?.trim_matches('\'').parse().ok()
+}
+
+/// `reg query` prints "    DoubleClickSpeed    REG_SZ    500" (DWORDs as 0x…)
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_registry(output: &str, name: &str) -> Option<f32> {
+    let line = output.lines().find(|line| line.trim_start().starts_with(name))?;
+    let value = line.split_whitespace().last()?;
+    match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok().map(|value| value as f32),
+        None => value.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_platforms_answer() {
+        assert_eq!(parse_number("uint32 500\n"), Some(500.0));
+        assert_eq!(parse_number("400\n"), Some(400.0));
+        assert_eq!(parse_number("0.5\n"), Some(0.5));
+        assert_eq!(parse_number("The domain/default pair does not exist"), None);
+        let windows = "\r\nHKEY_CURRENT_USER\\Control Panel\\Mouse\r\n    DoubleClickSpeed    REG_SZ    450\r\n";
+        assert_eq!(parse_registry(windows, "DoubleClickSpeed"), Some(450.0));
+        assert_eq!(parse_registry("    WheelScrollLines    REG_DWORD    0x5", "WheelScrollLines"), Some(5.0));
+        assert_eq!(parse_registry("", "DragWidth"), None);
+    }
+}
@@ -1,5 +1,30 @@
+pub mod clipboard;
+pub mod dialogs;
+pub mod file_drop;
 pub mod input;
+pub mod input_settings;
+pub mod motion;
+pub mod contrast;
+pub mod network;
+pub mod notify;
+pub mod print;
+pub mod screen_capture;
 pub mod window;
+pub mod window_state;
 
-pub use input::{InputHandler, Key, MouseButtonEvent, MousePosition};
-pub use window::{NebulaWindow, RenderCallback};
+pub use clipboard::Clipboard;
+pub use dialogs::{FileDialog, FileFilter, PickedFile};
+pub use file_drop::DroppedFile;
+#[cfg(target_arch = "wasm32")]
+pub use file_drop::FileDropTarget;
+pub use screen_capture::{Display, ScreenCapture, ScreenSource, SystemScreenSource};
+pub use print::{PrintBackend, Printer, SystemPrinter};
+pub use network::NetworkMonitor;
+pub use notify::{Notification, NotificationAction, NotificationEvent};
+pub use motion::{apply_system_motion_preference, system_motion_preference};
+pub use contrast::{apply_system_high_contrast, cached_high_contrast, refresh_high_contrast, system_high_contrast, update_system_high_contrast};
+pub use input_settings::{apply_system_input_settings, system_input_settings};
+pub use input::{InputHandler, Key, MouseButtonEvent, MousePosition, TouchPhaseEvent};
+pub use window::{NebulaWindow, RenderCallback, WindowAction};
+pub use window_state::{MonitorArea, WindowState};
+pub use nebula_gfx::{PageOrientation, PaperSize, PrintJob, RgbaImage};
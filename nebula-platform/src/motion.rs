@@ -0,0 +1,97 @@
+//! Motion - Read the OS "reduce motion" setting 🧘
+//!
+//! Every desktop OS and browser has an accessibility switch for less
+//! animation. `system_motion_preference` reads it without native code:
+//! - Linux (GNOME and friends): `gsettings … enable-animations`
+//! - macOS: `defaults read com.apple.universalaccess reduceMotion`
+//! - Windows: the `MinAnimate` registry value behind "Show animations"
+//! - Browser: the `prefers-reduced-motion` media query
+//!
+//! `apply_system_motion_preference` hands the answer to
+//! `MotionPreference::set_system`, which the animation system follows
+//! unless the app overrides it.
+
+use nebula_core::MotionPreference;
+use tracing::info;
+
+/// The OS setting, or None when it can't be read
+pub fn system_motion_preference() -> Option<MotionPreference> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let query = web_sys::window()?.match_media("(prefers-reduced-motion: reduce)").ok()??;
+        Some(if query.matches() { MotionPreference::Reduced } else { MotionPreference::Full })
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        type Probe = (&'static str, &'static [&'static str], fn(&str) -> Option<bool>);
+        let (program, args, reduced): Probe = if cfg!(target_os = "macos") {
+            ("defaults", &["read", "com.apple.universalaccess", "reduceMotion"], parse_macos)
+        } else if cfg!(target_os = "windows") {
+            ("reg", &["query", r"HKCU\Control Panel\Desktop\WindowMetrics", "/v", "MinAnimate"], parse_windows)
+        } else {
+            ("gsettings", &["get", "org.gnome.desktop.interface", "enable-animations"], parse_gnome)
+        };
+        let output = std::process::Command::new(program).args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let reduced = reduced(&String::from_utf8_lossy(&output.stdout))?;
+        Some(if reduced { MotionPreference::Reduced } else { MotionPreference::Full })
+    }
+}
+
+/// Read the OS setting into `MotionPreference` (call at startup and when
+/// the window regains focus); returns what was applied
+pub fn apply_system_motion_preference() -> MotionPreference {
+    let preference = system_motion_preference().unwrap_or_default();
+    info!("🧘 System motion preference: {:?}", preference);
+    MotionPreference::set_system(preference);
+    preference
+}
+
+/// `gsettings` prints "true" / "false" (animations on = full motion)
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_gnome(output: &str) -> Option<bool> {
+    match output.trim() {
+        "true" => Some(false),
+        "false" => Some(true),
+        _ => None,
+    }
+}
+
+/// `defaults` prints 1 when Reduce Motion is on
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_macos(output: &str) -> Option<bool> {
+    match output.trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// `reg query` prints "    MinAnimate    REG_SZ    0" when animations are off
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn parse_windows(output: &str) -> Option<bool> {
+    let line = output.lines().find(|line| line.trim_start().starts_with("MinAnimate"))?;
+    match line.split_whitespace().last()? {
+        "0" => Some(true),
+        "1" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_platforms_answer() {
+        assert_eq!(parse_gnome("false\n"), Some(true));
+        assert_eq!(parse_gnome("true\n"), Some(false));
+        assert_eq!(parse_macos("1\n"), Some(true));
+        assert_eq!(parse_macos("The domain/default pair does not exist"), None);
+        let windows = "\r\nHKEY_CURRENT_USER\\Control Panel\\Desktop\\WindowMetrics\r\n    MinAnimate    REG_SZ    0\r\n";
+        assert_eq!(parse_windows(windows), Some(true));
+        assert_eq!(parse_windows(""), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Network - Is the app online right now? 📶
+//!
+//! `NetworkMonitor` keeps an `online` Signal up to date so caches and
+//! sync code can wait for connectivity instead of failing requests.
+//!
+//! On the desktop a background thread asks the OS every few seconds
+//! whether there is a route out (a default route in `/proc/net/route` on
+//! Linux; other systems report online), without sending any traffic. Apps
+//! that want a real end-to-end check opt in with `with_probes` and their
+//! own hosts. In the browser it reads `navigator.onLine`. Either way,
+//! `update()` (once per frame) copies the latest answer into the Signal
+//! on the UI thread.
+
+use nebula_core::Signal;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+/// NetworkMonitor - Online/offline as a Signal 📶
+///
+/// # Example
+/// ```rust,ignore
+/// let network = NetworkMonitor::new();
+/// let online = network.online();
+/// // every frame:
+/// network.update();
+/// ```
+pub struct NetworkMonitor {
+    online: Signal<bool>,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    latest: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+}
+
+impl NetworkMonitor {
+    /// Watch connectivity through the OS (every 5 seconds, no traffic)
+    pub fn new() -> Self {
+        Self::watch(Vec::new(), Duration::from_secs(5))
+    }
+
+    /// Watch connectivity by connecting to `probes` every `interval`
+    /// (ignored in the browser, which knows on its own)
+    ///
+    /// Opt-in: use hosts you control (e.g. your API server), since every
+    /// check opens a TCP connection to them.
+    pub fn with_probes(probes: Vec<SocketAddr>, interval: Duration) -> Self {
+        Self::watch(probes, interval)
+    }
+
+    /// Start the checks: TCP probes if given, otherwise OS routes
+    fn watch(probes: Vec<SocketAddr>, interval: Duration) -> Self {
+        let latest = Arc::new(AtomicBool::new(true));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (latest, stop) = (latest.clone(), stop.clone());
+            let timeout = interval.min(Duration::from_secs(2));
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let reachable = if probes.is_empty() {
+                        has_route()
+                    } else {
+                        probes.iter().any(|addr| std::net::TcpStream::connect_timeout(addr, timeout).is_ok())
+                    };
+                    latest.store(reachable, Ordering::Relaxed);
+                    std::thread::sleep(interval);
+                }
+            });
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = (probes, interval);
+
+        let monitor = Self { online: Signal::new(true), latest, stop };
+        monitor.update();
+        monitor
+    }
+
+    /// The online signal (true until a check says otherwise)
+    pub fn online(&self) -> Signal<bool> {
+        self.online.clone()
+    }
+
+    /// Copy the latest check into the signal; returns whether online
+    pub fn update(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        let online = self.latest.load(Ordering::Relaxed);
+        #[cfg(target_arch = "wasm32")]
+        let online = web_sys::window().map(|window| window.navigator().on_line()).unwrap_or(true);
+
+        if online != self.online.get() {
+            info!("📶 Network is {}", if online { "back online" } else { "offline" });
+            self.online.set(online);
+        }
+        online
+    }
+
+    /// Is the app online (as of the last `update`)?
+    pub fn is_online(&self) -> bool {
+        self.online.get()
+    }
+}
+
+/// Does the OS have a default route (IPv4 or IPv6)?
+#[cfg(target_os = "linux")]
+fn has_route() -> bool {
+    let ipv4 = std::fs::read_to_string("/proc/net/route").map(|table| default_route_v4(&table));
+    let ipv6 = std::fs::read_to_string("/proc/net/ipv6_route").map(|table| default_route_v6(&table));
+    match (ipv4, ipv6) {
+        (Err(_), Err(_)) => true, // No procfs (a sandbox?): don't claim offline
+        (ipv4, ipv6) => ipv4.unwrap_or(false) || ipv6.unwrap_or(false),
+    }
+}
+
+/// Without a cheap OS check, report online (use probes to know better)
+#[cfg(all(not(target_os = "linux"), not(target_arch = "wasm32")))]
+fn has_route() -> bool {
+    true
+}
+
+/// A usable (RTF_UP) default route in /proc/net/route
+#[cfg(any(target_os = "linux", test))]
+fn default_route_v4(table: &str) -> bool {
+    table.lines().skip(1).any(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let flags = fields.get(3).and_then(|flags| u32::from_str_radix(flags, 16).ok()).unwrap_or(0);
+        fields.get(1) == Some(&"00000000") && fields.get(7) == Some(&"00000000") && flags & 0x1 != 0
+    })
+}
+
+/// A usable default route (::/0, not via loopback) in /proc/net/ipv6_route
+#[cfg(any(target_os = "linux", test))]
+fn default_route_v6(table: &str) -> bool {
+    table.lines().any(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let flags = fields.get(8).and_then(|flags| u32::from_str_radix(flags, 16).ok()).unwrap_or(0);
+        fields.first().is_some_and(|dest| dest.bytes().all(|b| b == b'0'))
+            && fields.get(1) == Some(&"00")
+            && fields.get(9) != Some(&"lo")
+            && flags & 0x1 != 0
+    })
+}
+
+impl Default for NetworkMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for NetworkMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::time::Instant;
+
+    #[test]
+    fn follows_probe_reachability() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let monitor = NetworkMonitor::with_probes(vec![addr], Duration::from_millis(5));
+        let wait_for = |expected: bool| {
+            let start = Instant::now();
+            while monitor.update() != expected && start.elapsed() < Duration::from_secs(5) {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            monitor.is_online()
+        };
+        assert!(wait_for(true));
+
+        drop(listener);
+        assert!(!wait_for(false));
+        assert!(!monitor.online().get());
+    }
+
+    #[test]
+    fn default_route_means_reachable() {
+        let header = "Iface\tDestination\tGateway\tFlags\tRefCnt\tUse\tMetric\tMask\n";
+        let default = format!("{}eth0\t00000000\t0100A8C0\t0003\t0\t0\t100\t00000000\n", header);
+        let local_only = format!("{}eth0\t0000A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\n", header);
+        assert!(default_route_v4(&default));
+        assert!(!default_route_v4(&local_only));
+        assert!(!default_route_v6("00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200 lo\n"));
+    }
+}
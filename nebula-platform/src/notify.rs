@@ -0,0 +1,221 @@
+//! Notify - System notifications, even when the window is hidden! 🔔
+//!
+//! The `Toast` component only shows while the app is on screen. When the
+//! window is minimized or behind others, `Notification` posts to the OS
+//! notification center instead (freedesktop notifications on Linux,
+//! Notification Center on macOS, toast notifications on Windows).
+//!
+//! Clicks and action buttons come back through `on_click`. Waiting for
+//! the user blocks, so the callback runs on a notification thread; send
+//! the event to the UI (a channel or a Signal update on the next frame)
+//! rather than touching widgets from it.
+//!
+//! On the web the page's Notification API is used: call
+//! `request_permission` from a click handler first, and expect body
+//! clicks only (page notifications have no action buttons).
+
+use std::sync::Arc;
+use tracing::info;
+
+/// A button on a notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationAction {
+    /// Reported back in `NotificationEvent::Action`
+    pub id: String,
+    pub label: String,
+}
+
+/// What the user did with a notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationEvent {
+    /// Clicked the notification itself
+    Clicked,
+    /// Clicked an action button (its id)
+    Action(String),
+    /// Closed it or let it expire
+    Dismissed,
+}
+
+impl NotificationEvent {
+    /// Map a notification center response ("default", "__closed" or an action id)
+    pub fn from_response(response: &str) -> Self {
+        match response {
+            "default" => NotificationEvent::Clicked,
+            "__closed" | "" => NotificationEvent::Dismissed,
+            action => NotificationEvent::Action(action.to_string()),
+        }
+    }
+}
+
+type ClickCallback = Arc<dyn Fn(NotificationEvent) + Send + Sync>;
+
+/// Notification - A message in the OS notification center 🔔
+///
+/// # Example
+/// ```rust,ignore
+/// Notification::new("Upload finished")
+///     .body("report.pdf is ready to share")
+///     .action("open", "Open")
+///     .on_click(move |event| { let _ = events.send(event); })
+///     .show()?;
+/// ```
+#[derive(Clone)]
+pub struct Notification {
+    pub title: String,
+    pub body: String,
+    /// Icon name (freedesktop) or path / URL to an image
+    pub icon: Option<String>,
+    pub actions: Vec<NotificationAction>,
+    /// Application name shown by some notification centers
+    pub app_name: Option<String>,
+    on_click: Option<ClickCallback>,
+}
+
+impl Notification {
+    /// Notification with a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), body: String::new(), icon: None, actions: Vec::new(), app_name: None, on_click: None }
+    }
+
+    /// Set the body text
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Set the icon
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Add an action button
+    pub fn action(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.actions.push(NotificationAction { id: id.into(), label: label.into() });
+        self
+    }
+
+    /// Set the application name
+    pub fn app_name(mut self, name: impl Into<String>) -> Self {
+        self.app_name = Some(name.into());
+        self
+    }
+
+    /// Called when the notification is clicked, an action is chosen or it is dismissed
+    pub fn on_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(NotificationEvent) + Send + Sync + 'static,
+    {
+        self.on_click = Some(Arc::new(callback));
+        self
+    }
+
+    /// Post the notification
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn show(&self) -> Result<(), String> {
+        let notification = native::build(self);
+        let Some(callback) = self.on_click.clone() else {
+            notification.show().map_err(|e| format!("Failed to show notification: {}", e))?;
+            info!("🔔 Notification: {}", self.title);
+            return Ok(());
+        };
+
+        // Waiting for the user blocks, so it happens on its own thread
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::Builder::new()
+            .name("nebula-notification".to_string())
+            .spawn(move || match notification.show() {
+                Ok(handle) => {
+                    let _ = sender.send(Ok(()));
+                    handle.wait_for_action(|response| callback(NotificationEvent::from_response(response)));
+                }
+                Err(e) => {
+                    let _ = sender.send(Err(format!("Failed to show notification: {}", e)));
+                }
+            })
+            .map_err(|e| format!("Could not start notification thread: {}", e))?;
+        receiver.recv().unwrap_or_else(|_| Err("Notification thread exited".to_string()))?;
+        info!("🔔 Notification: {} ({} actions)", self.title, self.actions.len());
+        Ok(())
+    }
+
+    /// Post the notification
+    #[cfg(target_arch = "wasm32")]
+    pub fn show(&self) -> Result<(), String> {
+        use wasm_bindgen::prelude::*;
+        use wasm_bindgen::JsCast;
+        use web_sys::{NotificationOptions, NotificationPermission};
+
+        if web_sys::Notification::permission() != NotificationPermission::Granted {
+            return Err("Notification permission not granted (call notify::request_permission first)".to_string());
+        }
+        if !self.actions.is_empty() {
+            tracing::warn!("⚠️ Page notifications can't have action buttons; showing without them");
+        }
+        let options = NotificationOptions::new();
+        options.set_body(&self.body);
+        if let Some(icon) = &self.icon {
+            options.set_icon(icon);
+        }
+        let notification = web_sys::Notification::new_with_options(&self.title, &options)
+            .map_err(|e| format!("Failed to show notification: {:?}", e))?;
+        if let Some(callback) = self.on_click.clone() {
+            let on_click = Closure::<dyn FnMut()>::new(move || callback(NotificationEvent::Clicked));
+            notification.set_onclick(Some(on_click.as_ref().unchecked_ref()));
+            // Lives as long as the page; notifications are rare enough not to matter
+            on_click.forget();
+        }
+        info!("🔔 Notification: {}", self.title);
+        Ok(())
+    }
+}
+
+/// Ask for permission to notify (the web needs it, from a user gesture)
+/// Desktop notification centers don't ask, so this does nothing there
+pub fn request_permission() {
+    #[cfg(target_arch = "wasm32")]
+    if let Err(e) = web_sys::Notification::request_permission() {
+        tracing::warn!("⚠️ Couldn't request notification permission: {:?}", e);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use super::Notification;
+
+    pub fn build(options: &Notification) -> notify_rust::Notification {
+        let mut notification = notify_rust::Notification::new();
+        notification.summary(&options.title).body(&options.body);
+        if let Some(icon) = &options.icon {
+            notification.icon(icon);
+        }
+        if let Some(name) = &options.app_name {
+            notification.appname(name);
+        }
+        for action in &options.actions {
+            notification.action(&action.id, &action.label);
+        }
+        notification
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_collects_actions_and_maps_responses() {
+        let notification = Notification::new("Upload finished")
+            .body("report.pdf is ready")
+            .action("open", "Open")
+            .action("share", "Share")
+            .on_click(|_| {});
+        assert_eq!(notification.actions.len(), 2);
+        assert_eq!(notification.actions[1], NotificationAction { id: "share".to_string(), label: "Share".to_string() });
+        assert!(notification.on_click.is_some());
+
+        assert_eq!(NotificationEvent::from_response("default"), NotificationEvent::Clicked);
+        assert_eq!(NotificationEvent::from_response("__closed"), NotificationEvent::Dismissed);
+        assert_eq!(NotificationEvent::from_response("open"), NotificationEvent::Action("open".to_string()));
+    }
+}
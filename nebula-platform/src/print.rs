@@ -0,0 +1,212 @@
+//! Print - Send rendered pages to a real printer! 🖨️
+//!
+//! `Printer` hands a `PrintJob` (usually from the `PrintPreview`
+//! component) to a `PrintBackend`. The built-in `SystemPrinter` writes the
+//! job as a PDF and passes it to the OS spooler (lp on macOS / Linux,
+//! the shell's print verb on Windows), so apps need no native code.
+//!
+//! Browsers only print whole pages through their own dialog, so the
+//! system backend reports an error on the web; offer `PrintJob::save_pdf`
+//! or a download there instead.
+
+use nebula_gfx::PrintJob;
+use tracing::{info, warn};
+
+/// Where print jobs go
+pub trait PrintBackend {
+    /// Print every page of `job`
+    fn print(&mut self, job: &PrintJob, printer: Option<&str>) -> Result<(), String>;
+
+    /// Printers the backend can reach (first is the default)
+    fn printers(&mut self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Prints through the OS spooler 🖨️
+#[derive(Debug, Clone, Default)]
+pub struct SystemPrinter;
+
+impl SystemPrinter {
+    /// Create the system printer backend
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Spooler command for a PDF at `path`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn command(path: &str, job: &PrintJob, printer: Option<&str>) -> Vec<String> {
+        if cfg!(target_os = "windows") {
+            // The default PDF handler's print verb ignores copies, so repeat it
+            let verb = match printer {
+                Some(name) => format!("-Verb PrintTo -ArgumentList '\"{}\"'", name.replace('\'', "''")),
+                None => "-Verb Print".to_string(),
+            };
+            let script = format!(
+                "1..{} | ForEach-Object {{ Start-Process -FilePath '{}' {} -Wait }}",
+                job.copies,
+                path.replace('\'', "''"),
+                verb
+            );
+            vec!["powershell".to_string(), "-NoProfile".to_string(), "-Command".to_string(), script]
+        } else {
+            let mut command = vec!["lp".to_string(), "-t".to_string(), job.title.clone(), "-n".to_string(), job.copies.to_string()];
+            if let Some(name) = printer {
+                command.extend(["-d".to_string(), name.to_string()]);
+            }
+            command.push(path.to_string());
+            command
+        }
+    }
+}
+
+impl PrintBackend for SystemPrinter {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn print(&mut self, job: &PrintJob, printer: Option<&str>) -> Result<(), String> {
+        // Created exclusively with a random name, so nothing else can plant or read it
+        let file = tempfile::Builder::new()
+            .prefix("nebula-print-")
+            .suffix(".pdf")
+            .tempfile()
+            .map_err(|e| format!("Failed to create print file: {}", e))?
+            .into_temp_path();
+        job.save_pdf(&file)?;
+        let command = Self::command(&file.to_string_lossy(), job, printer);
+        let status = std::process::Command::new(&command[0])
+            .args(&command[1..])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+        // Windows viewers may still be reading the file, so leave it for the OS temp cleanup
+        if cfg!(target_os = "windows") {
+            let _ = file.keep();
+        }
+        match status {
+            Ok(status) if status.success() => {
+                info!("🖨️ Sent '{}' ({} pages x{}) to {}", job.title, job.pages.len(), job.copies, printer.unwrap_or("default printer"));
+                Ok(())
+            }
+            Ok(status) => {
+                warn!("⚠️ {} exited with {}", command[0], status);
+                Err(format!("{} exited with {}", command[0], status))
+            }
+            Err(e) => Err(format!("No print spooler available ({}): {}", command[0], e)),
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn print(&mut self, _job: &PrintJob, _printer: Option<&str>) -> Result<(), String> {
+        Err("Printing pages is not available in the browser".to_string())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn printers(&mut self) -> Vec<String> {
+        if cfg!(target_os = "windows") {
+            return Vec::new();
+        }
+        let output = std::process::Command::new("lpstat").args(["-d", "-p"]).output();
+        let Ok(output) = output else {
+            return Vec::new();
+        };
+        let text = String::from_utf8_lossy(&output.stdout);
+        let default = text.lines().find_map(|line| line.split("system default destination: ").nth(1)).map(str::trim);
+        let mut printers: Vec<String> = text
+            .lines()
+            .filter_map(|line| line.strip_prefix("printer "))
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_string)
+            .collect();
+        if let Some(index) = default.and_then(|name| printers.iter().position(|printer| printer == name)) {
+            let name = printers.remove(index);
+            printers.insert(0, name);
+        }
+        printers
+    }
+}
+
+/// Print API 🖨️
+///
+/// # Example
+/// ```rust,ignore
+/// let mut printer = Printer::new();
+/// let job = preview.print_job()?;
+/// printer.print(&job)?;
+/// ```
+pub struct Printer {
+    backend: Box<dyn PrintBackend>,
+    printer: Option<String>,
+}
+
+impl Printer {
+    /// Print through the OS spooler
+    pub fn new() -> Self {
+        Self::with_backend(Box::new(SystemPrinter::new()))
+    }
+
+    /// Print through a custom backend
+    pub fn with_backend(backend: Box<dyn PrintBackend>) -> Self {
+        Self { backend, printer: None }
+    }
+
+    /// Print to a named printer instead of the default
+    pub fn select(&mut self, printer: Option<String>) {
+        self.printer = printer;
+    }
+
+    /// Chosen printer (None = default)
+    pub fn selected(&self) -> Option<&str> {
+        self.printer.as_deref()
+    }
+
+    /// Available printers
+    pub fn printers(&mut self) -> Vec<String> {
+        self.backend.printers()
+    }
+
+    /// Print a job
+    pub fn print(&mut self, job: &PrintJob) -> Result<(), String> {
+        if job.pages.is_empty() {
+            return Err("Nothing to print".to_string());
+        }
+        self.backend.print(job, self.printer.as_deref())
+    }
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nebula_gfx::RgbaImage;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Remembers what it was asked to print
+    struct Spool(Rc<RefCell<Vec<Spooled>>>);
+
+    /// Title, page count and printer
+    type Spooled = (String, usize, Option<String>);
+
+    impl PrintBackend for Spool {
+        fn print(&mut self, job: &PrintJob, printer: Option<&str>) -> Result<(), String> {
+            self.0.borrow_mut().push((job.title.clone(), job.pages.len(), printer.map(str::to_string)));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn printer_forwards_jobs_to_backend() {
+        let spooled = Rc::new(RefCell::new(Vec::new()));
+        let mut printer = Printer::with_backend(Box::new(Spool(spooled.clone())));
+        assert!(printer.print(&PrintJob::new("empty")).is_err());
+
+        printer.select(Some("office".to_string()));
+        printer.print(&PrintJob::new("invoice").page(RgbaImage::new(2, 2))).unwrap();
+        assert_eq!(*spooled.borrow(), vec![("invoice".to_string(), 1, Some("office".to_string()))]);
+        assert!(printer.printers().is_empty());
+    }
+}
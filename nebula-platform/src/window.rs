@@ -1,14 +1,59 @@
-use crate::input::{is_key_pressed, is_key_released, key_from_event, InputHandler, MouseButtonEvent, MousePosition};
+use crate::file_drop::DroppedFile;
+use crate::input::{ime_from_event, is_key_pressed, is_key_released, key_from_event, modifiers_from_event, scroll_from_event, InputHandler, MouseButtonEvent, MousePosition};
+use crate::window_state::{MonitorArea, WindowState};
+use nebula_core::{QualityManager, Scheduler, SettingsStore, ThemeProvider};
+use nebula_gfx::RgbaImage;
+use std::path::{Path, PathBuf};
 use winit::{
     application::ApplicationHandler,
     event::{ElementState, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
-    window::{Window, WindowId},
+    window::{Window, WindowId, WindowLevel},
 };
 
+/// Something a custom titlebar (or any widget) asks the window to do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowAction {
+    /// Start moving the window with the pressed mouse (from a drag region)
+    Drag,
+    Minimize,
+    ToggleMaximize,
+    Close,
+    SetAlwaysOnTop(bool),
+    SetResizable(bool),
+    SetDecorations(bool),
+    /// Capture the current frame and save it as a PNG (see `save_screenshot`)
+    SaveScreenshot(PathBuf),
+}
+
 /// Callback trait for rendering and input
 pub trait RenderCallback: InputHandler {
     fn render(&mut self, window: &Window);
+
+    /// Called when the window's scale factor (device pixel ratio) is known or changes
+    /// Pass it on to the renderer; `on_layout` follows right after
+    fn on_scale_factor_changed(&mut self, scale_factor: f64) {
+        let _ = scale_factor; // Default: do nothing
+    }
+
+    /// Lay the UI out at the window's size in logical pixels
+    /// Called once the window exists and after every resize or scale factor change
+    fn on_layout(&mut self, width: f64, height: f64) {
+        let _ = (width, height); // Default: do nothing
+    }
+
+    /// Read back the last rendered frame from the renderer
+    /// Return None if the renderer can't capture frames
+    fn capture_frame(&mut self) -> Option<RgbaImage> {
+        None // Default: capture not supported
+    }
+
+    /// Window action requested while handling the last input event
+    /// Asked after every mouse press and release and every key press or
+    /// release (see `Titlebar` in nebula-components)
+    fn take_window_action(&mut self) -> Option<WindowAction> {
+        None // Default: the app never drives the window
+    }
 }
 
 /// Window manager for Nebula UI
@@ -19,6 +64,22 @@ pub struct NebulaWindow<R: RenderCallback> {
     height: u32,
     render_callback: Option<R>,
     mouse_position: MousePosition,
+    /// Physical pixels per logical pixel
+    scale_factor: f64,
+    /// Frame budget watchdog fed with every frame's render time
+    quality: Option<QualityManager>,
+    /// Cooperative tasks run after every frame
+    scheduler: Option<Scheduler>,
+    /// Native titlebar and borders (off for custom-drawn chrome)
+    decorations: bool,
+    always_on_top: bool,
+    resizable: bool,
+    /// Where window geometry is saved on close (store and key)
+    saved_state: Option<(SettingsStore, String)>,
+    /// Last geometry while neither maximized nor minimized
+    normal_state: Option<WindowState>,
+    /// This window's own theme (None = the app-wide one)
+    theme: Option<ThemeProvider>,
 }
 
 impl<R: RenderCallback> NebulaWindow<R> {
@@ -31,6 +92,141 @@ impl<R: RenderCallback> NebulaWindow<R> {
             height,
             render_callback: None,
             mouse_position: MousePosition::new(0.0, 0.0),
+            scale_factor: 1.0,
+            quality: None,
+            scheduler: None,
+            decorations: true,
+            always_on_top: false,
+            resizable: true,
+            saved_state: None,
+            normal_state: None,
+            theme: None,
+        }
+    }
+
+    /// Remember size, position and maximized state across sessions under
+    /// `key` in `store` (saved on close, restored at startup)
+    pub fn with_saved_state(mut self, store: SettingsStore, key: impl Into<String>) -> Self {
+        self.saved_state = Some((store, key.into()));
+        self
+    }
+
+    /// Write the window's geometry to the settings store
+    fn save_state(&mut self) {
+        let (Some((store, key)), Some(window)) = (&self.saved_state, &self.window) else {
+            return;
+        };
+        let Some(current) = WindowState::capture(window).or_else(|| self.normal_state.clone()) else {
+            return;
+        };
+        // A maximized window restores to its last normal geometry
+        let state = match (&self.normal_state, current.maximized) {
+            (Some(normal), true) => WindowState { maximized: true, monitor: current.monitor, ..normal.clone() },
+            _ => current,
+        };
+        let saved = store.set(key, &state).and_then(|_| store.flush());
+        match saved {
+            Ok(()) => tracing::info!("📐 Saved window state {:?}", state),
+            Err(e) => tracing::warn!("Couldn't save window state: {}", e),
+        }
+    }
+
+    /// Track the geometry to restore to after un-maximizing
+    fn track_normal_state(&mut self) {
+        if let Some(window) = &self.window {
+            if let Some(state) = WindowState::capture(window).filter(|state| !state.maximized) {
+                self.normal_state = Some(state);
+            }
+        }
+    }
+
+    /// Give this window its own theme: components built, painted or handling
+    /// input for it see `provider` as `ThemeProvider::current()`
+    pub fn with_theme(mut self, provider: ThemeProvider) -> Self {
+        self.theme = Some(provider);
+        self
+    }
+
+    /// Show the native titlebar and borders (false = borderless, draw your own `Titlebar`)
+    pub fn with_decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Keep the window above other windows
+    pub fn with_always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
+    /// Let the user resize the window
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Keep the window above other windows (applies right away once created)
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.always_on_top = always_on_top;
+        if let Some(window) = &self.window {
+            window.set_window_level(window_level(always_on_top));
+        }
+    }
+
+    /// Let the user resize the window (applies right away once created)
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.resizable = resizable;
+        if let Some(window) = &self.window {
+            window.set_resizable(resizable);
+        }
+    }
+
+    /// Show or hide the native decorations (applies right away once created)
+    pub fn set_decorations(&mut self, decorations: bool) {
+        self.decorations = decorations;
+        if let Some(window) = &self.window {
+            window.set_decorations(decorations);
+        }
+    }
+
+    /// Carry out an action requested by the app
+    fn apply_window_action(&mut self, action: WindowAction, event_loop: &ActiveEventLoop) {
+        tracing::info!("🪟 Window action: {:?}", action);
+        if action == WindowAction::Close {
+            self.save_state();
+            event_loop.exit();
+        } else {
+            self.apply_action(action);
+        }
+    }
+
+    /// Carry out an action that doesn't end the event loop
+    fn apply_action(&mut self, action: WindowAction) {
+        match action {
+            WindowAction::Close => {}
+            WindowAction::SaveScreenshot(path) => {
+                if let Err(e) = self.save_screenshot(&path) {
+                    tracing::warn!("Couldn't save screenshot: {}", e);
+                }
+            }
+            WindowAction::SetAlwaysOnTop(always_on_top) => self.set_always_on_top(always_on_top),
+            WindowAction::SetResizable(resizable) => self.set_resizable(resizable),
+            WindowAction::SetDecorations(decorations) => self.set_decorations(decorations),
+            _ => {
+                let Some(window) = &self.window else {
+                    return;
+                };
+                match action {
+                    WindowAction::Drag => {
+                        if let Err(e) = window.drag_window() {
+                            tracing::warn!("Can't drag window: {}", e);
+                        }
+                    }
+                    WindowAction::Minimize => window.set_minimized(true),
+                    WindowAction::ToggleMaximize => window.set_maximized(!window.is_maximized()),
+                    _ => {}
+                }
+            }
         }
     }
 
@@ -40,11 +236,83 @@ impl<R: RenderCallback> NebulaWindow<R> {
         self
     }
 
+    /// Degrade effects automatically when frames run over budget
+    pub fn with_quality_manager(mut self, quality: QualityManager) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Run a scheduler's tasks after every frame, within its budget
+    pub fn with_scheduler(mut self, scheduler: Scheduler) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
     /// Get a reference to the window
     pub fn window(&self) -> Option<&Window> {
         self.window.as_ref()
     }
 
+    /// Get the current scale factor (physical pixels per logical pixel)
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Update the scale factor, notify the render callback and re-lay out
+    fn set_scale_factor(&mut self, scale_factor: f64) {
+        tracing::info!("Scale factor set to {}", scale_factor);
+        self.scale_factor = scale_factor;
+        if let Some(callback) = &mut self.render_callback {
+            callback.on_scale_factor_changed(scale_factor);
+        }
+        self.relayout();
+    }
+
+    /// Window size in logical pixels (the requested size until it exists)
+    pub fn logical_size(&self) -> (f64, f64) {
+        match &self.window {
+            Some(window) => {
+                let size = window.inner_size().to_logical::<f64>(self.scale_factor);
+                (size.width, size.height)
+            }
+            None => (self.width as f64, self.height as f64),
+        }
+    }
+
+    /// Have the render callback lay out again at the current logical size
+    fn relayout(&mut self) {
+        let (width, height) = self.logical_size();
+        if let Some(callback) = &mut self.render_callback {
+            callback.on_layout(width, height);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+
+    /// Move the IME candidate window next to the focused caret
+    fn update_ime_cursor_area(&self) {
+        if let (Some(window), Some(callback)) = (&self.window, &self.render_callback) {
+            if let Some((x, y, width, height)) = callback.ime_cursor_area() {
+                window.set_ime_cursor_area(
+                    winit::dpi::LogicalPosition::new(x, y),
+                    winit::dpi::LogicalSize::new(width, height),
+                );
+            }
+        }
+    }
+
+    /// Capture the current frame and save it as a PNG
+    /// Handy for bug reports and visual tests; while the app runs, return
+    /// `WindowAction::SaveScreenshot` from the render callback instead
+    pub fn save_screenshot(&mut self, path: impl AsRef<Path>) -> Result<(), String> {
+        let callback = self.render_callback.as_mut()
+            .ok_or_else(|| "No render callback set".to_string())?;
+        let image = callback.capture_frame()
+            .ok_or_else(|| "Renderer does not support frame capture".to_string())?;
+        image.save_png(path)
+    }
+
     /// Run the event loop
     pub fn run(mut self) -> Result<(), Box<dyn std::error::Error>> {
         tracing::info!("Starting Nebula UI window: {}", self.title);
@@ -61,14 +329,31 @@ impl<R: RenderCallback> NebulaWindow<R> {
 impl<R: RenderCallback> ApplicationHandler for NebulaWindow<R> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.window.is_none() {
-            let window_attributes = Window::default_attributes()
+            let mut window_attributes = Window::default_attributes()
                 .with_title(&self.title)
-                .with_inner_size(winit::dpi::LogicalSize::new(self.width, self.height));
+                .with_inner_size(winit::dpi::LogicalSize::new(self.width, self.height))
+                .with_decorations(self.decorations)
+                .with_resizable(self.resizable)
+                .with_window_level(window_level(self.always_on_top));
+            let restored = self.saved_state.as_ref().and_then(|(store, key)| store.get::<WindowState>(key));
+            if let Some(state) = restored {
+                let state = state.fit_to(&MonitorArea::available(event_loop));
+                tracing::info!("📐 Restoring window state {:?}", state);
+                window_attributes = window_attributes
+                    .with_position(winit::dpi::PhysicalPosition::new(state.x, state.y))
+                    .with_inner_size(winit::dpi::LogicalSize::new(state.width, state.height))
+                    .with_maximized(state.maximized);
+                self.normal_state = Some(WindowState { maximized: false, ..state });
+            }
             
             match event_loop.create_window(window_attributes) {
                 Ok(window) => {
                     tracing::info!("Window created successfully");
+                    let scale_factor = window.scale_factor();
+                    // Let CJK input methods compose into text fields
+                    window.set_ime_allowed(true);
                     self.window = Some(window);
+                    self.set_scale_factor(scale_factor);
                 }
                 Err(e) => {
                     tracing::error!("Failed to create window: {}", e);
@@ -83,22 +368,48 @@ impl<R: RenderCallback> ApplicationHandler for NebulaWindow<R> {
         _window_id: WindowId,
         event: WindowEvent,
     ) {
+        match self.theme.clone() {
+            Some(theme) => theme.scoped(|| self.handle_window_event(event_loop, event)),
+            None => self.handle_window_event(event_loop, event),
+        }
+    }
+}
+
+impl<R: RenderCallback> NebulaWindow<R> {
+    fn handle_window_event(&mut self, event_loop: &ActiveEventLoop, event: WindowEvent) {
         match event {
             WindowEvent::CloseRequested => {
                 tracing::info!("Close requested, exiting");
+                self.save_state();
                 event_loop.exit();
             }
             WindowEvent::RedrawRequested => {
                 // Call the render callback
                 if let (Some(window), Some(callback)) = (&self.window, &mut self.render_callback) {
+                    let started = std::time::Instant::now();
                     callback.render(window);
+                    if nebula_core::Analytics::is_enabled() {
+                        nebula_core::Analytics::frame(started.elapsed());
+                    }
+                    if let Some(quality) = &mut self.quality {
+                        quality.record_frame(started.elapsed());
+                    }
+                    nebula_core::AnimationClock::end_frame();
+                    if let Some(scheduler) = &self.scheduler {
+                        scheduler.run_frame();
+                    }
                     window.request_redraw();
                 }
             }
             WindowEvent::Resized(size) => {
                 tracing::info!("Window resized to {}x{}", size.width, size.height);
+                self.track_normal_state();
+                self.relayout();
             }
+            WindowEvent::Moved(_) => self.track_normal_state(),
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => self.set_scale_factor(scale_factor),
             WindowEvent::MouseInput { state, button, .. } => {
+                nebula_core::idle::record_input();
                 if let Some(callback) = &mut self.render_callback {
                     let button_event = MouseButtonEvent::from(button);
                     match state {
@@ -111,15 +422,72 @@ impl<R: RenderCallback> ApplicationHandler for NebulaWindow<R> {
                             callback.on_mouse_up(button_event, self.mouse_position);
                         }
                     }
+                    if let Some(action) = callback.take_window_action() {
+                        self.apply_window_action(action, event_loop);
+                    }
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
-                self.mouse_position = MousePosition::new(position.x, position.y);
+                // Components work in logical coordinates
+                let logical = position.to_logical::<f64>(self.scale_factor);
+                nebula_core::idle::record_input();
+                self.mouse_position = MousePosition::new(logical.x, logical.y);
                 if let Some(callback) = &mut self.render_callback {
                     callback.on_mouse_move(self.mouse_position);
                 }
             }
+            WindowEvent::MouseWheel { delta, .. } => {
+                nebula_core::idle::record_input();
+                let (dx, dy) = scroll_from_event(&delta, self.scale_factor);
+                if let Some(callback) = &mut self.render_callback {
+                    callback.on_scroll(dx, dy, self.mouse_position);
+                }
+            }
+            WindowEvent::Touch(touch) => {
+                nebula_core::idle::record_input();
+                let logical = touch.location.to_logical::<f64>(self.scale_factor);
+                if let Some(callback) = &mut self.render_callback {
+                    callback.on_touch(touch.phase.into(), touch.id, MousePosition::new(logical.x, logical.y));
+                }
+            }
+            WindowEvent::HoveredFile(_) => {
+                if let Some(callback) = &mut self.render_callback {
+                    callback.on_file_hover(self.mouse_position, true);
+                }
+            }
+            WindowEvent::HoveredFileCancelled => {
+                if let Some(callback) = &mut self.render_callback {
+                    callback.on_file_hover(self.mouse_position, false);
+                }
+            }
+            WindowEvent::DroppedFile(path) => {
+                if let Some(callback) = &mut self.render_callback {
+                    match DroppedFile::from_path(&path) {
+                        Ok(file) => {
+                            tracing::info!("📂 Dropped {} ({}, {} bytes)", file.name, file.mime_type, file.size());
+                            callback.on_file_dropped(file, self.mouse_position);
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                    callback.on_file_hover(self.mouse_position, false);
+                }
+            }
+            WindowEvent::Ime(ime) => {
+                if let Some(callback) = &mut self.render_callback {
+                    callback.on_ime(ime_from_event(&ime));
+                }
+                self.update_ime_cursor_area();
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                if let Some(callback) = &mut self.render_callback {
+                    callback.on_modifiers_changed(modifiers_from_event(&modifiers));
+                }
+            }
             WindowEvent::KeyboardInput { event, .. } => {
+                if event.repeat && !nebula_core::InputSettings::current().key_repeat {
+                    return;
+                }
+                nebula_core::idle::record_input();
                 if let Some(callback) = &mut self.render_callback {
                     if let Some(key) = key_from_event(&event) {
                         if is_key_pressed(&event) {
@@ -130,9 +498,97 @@ impl<R: RenderCallback> ApplicationHandler for NebulaWindow<R> {
                             callback.on_key_up(key);
                         }
                     }
+                    if let Some(action) = callback.take_window_action() {
+                        self.apply_window_action(action, event_loop);
+                    }
                 }
+                self.update_ime_cursor_area();
             }
             _ => {}
         }
     }
 }
+
+fn window_level(always_on_top: bool) -> WindowLevel {
+    if always_on_top { WindowLevel::AlwaysOnTop } else { WindowLevel::Normal }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asks for a screenshot on F12 and renders a fixed 2x1 frame
+    #[derive(Default)]
+    struct ScreenshotApp {
+        pending: Option<WindowAction>,
+        path: PathBuf,
+    }
+
+    impl InputHandler for ScreenshotApp {
+        fn on_key_down(&mut self, key: crate::Key) {
+            if key == crate::Key::F12 {
+                self.pending = Some(WindowAction::SaveScreenshot(self.path.clone()));
+            }
+        }
+    }
+
+    impl RenderCallback for ScreenshotApp {
+        fn render(&mut self, _window: &Window) {}
+
+        fn capture_frame(&mut self) -> Option<RgbaImage> {
+            RgbaImage::from_raw(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 255]).ok()
+        }
+
+        fn take_window_action(&mut self) -> Option<WindowAction> {
+            self.pending.take()
+        }
+    }
+
+    #[test]
+    fn screenshot_action_saves_png() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shot.png");
+        let app = ScreenshotApp { path: path.clone(), ..ScreenshotApp::default() };
+        let mut window = NebulaWindow::new("test", 2, 1).with_render_callback(app);
+
+        // What the event loop does after a key press
+        let callback = window.render_callback.as_mut().unwrap();
+        callback.on_key_down(crate::Key::F12);
+        let action = callback.take_window_action().unwrap();
+        window.apply_action(action);
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+    }
+
+    /// Records what the window asked it to do
+    #[derive(Default)]
+    struct LayoutApp {
+        scale_factor: Option<f64>,
+        layouts: Vec<(f64, f64)>,
+    }
+
+    impl InputHandler for LayoutApp {}
+
+    impl RenderCallback for LayoutApp {
+        fn render(&mut self, _window: &Window) {}
+
+        fn on_scale_factor_changed(&mut self, scale_factor: f64) {
+            self.scale_factor = Some(scale_factor);
+        }
+
+        fn on_layout(&mut self, width: f64, height: f64) {
+            self.layouts.push((width, height));
+        }
+    }
+
+    #[test]
+    fn scale_factor_change_relays_out() {
+        let mut window = NebulaWindow::new("test", 800, 600).with_render_callback(LayoutApp::default());
+        window.set_scale_factor(2.0);
+
+        let app = window.render_callback.as_ref().unwrap();
+        assert_eq!(app.scale_factor, Some(2.0));
+        assert_eq!(app.layouts, vec![(800.0, 600.0)]);
+    }
+}
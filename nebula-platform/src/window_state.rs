@@ -0,0 +1,139 @@
+//! Window State - Reopen where the user left off! 📐
+//!
+//! `WindowState` is the window's size, position, maximized flag and the
+//! monitor it was on. `NebulaWindow::with_saved_state` stores it in a
+//! `SettingsStore` on close and restores it on the next launch.
+//!
+//! Monitors come and go (a laptop undocked, a projector unplugged), so a
+//! saved position is only reused if the window's top edge would still be
+//! visible on some monitor. Otherwise it is centered on the monitor it was
+//! on (or the first one) and shrunk to fit.
+
+use serde::{Deserialize, Serialize};
+
+/// How much of the window's top edge must be on screen to keep a saved position
+pub const MIN_VISIBLE: i32 = 64;
+
+/// Saved window geometry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    /// Outer position in physical pixels (desktop coordinates)
+    pub x: i32,
+    pub y: i32,
+    /// Inner size in logical pixels
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+    /// Name of the monitor the window was on
+    pub monitor: Option<String>,
+}
+
+/// A monitor's area in physical desktop pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorArea {
+    pub name: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+}
+
+impl MonitorArea {
+    /// Every monitor the event loop can see
+    pub fn available(event_loop: &winit::event_loop::ActiveEventLoop) -> Vec<Self> {
+        event_loop
+            .available_monitors()
+            .map(|monitor| {
+                let (position, size) = (monitor.position(), monitor.size());
+                Self {
+                    name: monitor.name(),
+                    x: position.x,
+                    y: position.y,
+                    width: size.width,
+                    height: size.height,
+                    scale_factor: monitor.scale_factor(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl WindowState {
+    /// Geometry of a live window (None while minimized, when it's meaningless)
+    pub fn capture(window: &winit::window::Window) -> Option<Self> {
+        if window.is_minimized() == Some(true) {
+            return None;
+        }
+        let position = window.outer_position().ok()?;
+        let size = window.inner_size().to_logical::<f64>(window.scale_factor());
+        Some(Self {
+            x: position.x,
+            y: position.y,
+            width: size.width.round() as u32,
+            height: size.height.round() as u32,
+            maximized: window.is_maximized(),
+            monitor: window.current_monitor().and_then(|monitor| monitor.name()),
+        })
+    }
+
+    /// Keep the saved position if it's still reachable, otherwise center
+    /// on the saved (or first) monitor and shrink to fit
+    pub fn fit_to(&self, monitors: &[MonitorArea]) -> Self {
+        let reachable = monitors.iter().any(|monitor| {
+            let width = (self.width as f64 * monitor.scale_factor) as i32;
+            let overlap = (self.x + width).min(monitor.x + monitor.width as i32) - self.x.max(monitor.x);
+            overlap >= MIN_VISIBLE && self.y >= monitor.y && self.y <= monitor.y + monitor.height as i32 - MIN_VISIBLE
+        });
+        if reachable || monitors.is_empty() {
+            return self.clone();
+        }
+        let monitor = monitors
+            .iter()
+            .find(|monitor| monitor.name.is_some() && monitor.name == self.monitor)
+            .unwrap_or(&monitors[0]);
+
+        let width = self.width.min((monitor.width as f64 / monitor.scale_factor) as u32);
+        let height = self.height.min((monitor.height as f64 / monitor.scale_factor) as u32);
+        let (physical_width, physical_height) =
+            ((width as f64 * monitor.scale_factor) as i32, (height as f64 * monitor.scale_factor) as i32);
+        tracing::info!("📐 Saved window position is off-screen, moving it to {:?}", monitor.name);
+        Self {
+            x: monitor.x + (monitor.width as i32 - physical_width) / 2,
+            y: monitor.y + (monitor.height as i32 - physical_height) / 2,
+            width,
+            height,
+            maximized: self.maximized,
+            monitor: monitor.name.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, width: u32, scale_factor: f64) -> MonitorArea {
+        MonitorArea { name: Some(name.to_string()), x, y: 0, width, height: 1080, scale_factor }
+    }
+
+    #[test]
+    fn off_screen_windows_move_back() {
+        let laptop = monitor("eDP-1", 0, 1920, 1.0);
+        let external = monitor("HDMI-1", 1920, 2560, 1.0);
+        let state = WindowState { x: 2200, y: 100, width: 800, height: 600, maximized: false, monitor: Some("HDMI-1".to_string()) };
+
+        // Still docked: kept as saved
+        assert_eq!(state.fit_to(&[laptop.clone(), external]), state);
+
+        // Undocked: centered on the laptop screen
+        let fitted = state.fit_to(&[laptop]);
+        assert_eq!((fitted.x, fitted.y), (560, 240));
+        assert_eq!(fitted.monitor.as_deref(), Some("eDP-1"));
+
+        // Too big for a HiDPI screen: shrunk to its logical size
+        let big = WindowState { x: -5000, width: 2000, height: 1200, ..state };
+        let fitted = big.fit_to(&[monitor("Retina", 0, 2880, 2.0)]);
+        assert_eq!((fitted.width, fitted.height), (1440, 540));
+    }
+}
@@ -1,7 +1,17 @@
+use nebula_gfx::draw_list::{DrawList, ImageId, TextRasterizer};
+use nebula_gfx::path::Path;
+use nebula_gfx::{NinePatchInsets, OpacityStack, RenderError, RendererCapabilities, RgbaImage, StreamingTexture};
 use softbuffer::{Context, Surface};
+use std::collections::HashMap;
 use std::num::NonZeroU32;
 use tracing::{info, warn};
 
+pub mod render_target;
+pub mod replay;
+pub use replay::ReplayStats;
+pub use render_target::{RenderTarget, RenderTargetId};
+pub use nebula_gfx::Renderer;
+
 /// CPU-based renderer using softbuffer + tiny-skia
 /// This is Tier C - the emergency fallback that ALWAYS works
 pub struct CpuRenderer<D, W> {
@@ -10,6 +20,33 @@ pub struct CpuRenderer<D, W> {
     width: u32,
     height: u32,
     clear_color: Color,
+    /// Physical pixels per logical pixel
+    scale_factor: f64,
+    render_targets: HashMap<RenderTargetId, RenderTarget>,
+    next_render_target: u32,
+    /// Render target drawn into instead of the frame (None = the window)
+    active_render_target: Option<RenderTargetId>,
+    /// Frame being drawn, copied to the surface and presented in end_frame
+    frame: RenderTarget,
+    /// Copy of the last presented buffer (for capture_frame)
+    last_frame: Vec<u32>,
+    opacity: OpacityStack,
+    /// Open transparency groups, innermost last
+    layers: Vec<OpacityLayer>,
+    /// Images uploaded for DrawList replay
+    images: HashMap<ImageId, RenderTarget>,
+    /// Attached streams and the last version copied into `images`
+    streams: Vec<(StreamingTexture, u64)>,
+    text_rasterizer: Option<TextRasterizer>,
+    replay_stats: ReplayStats,
+    /// Occlusion flags for the list being replayed (kept across frames)
+    occluded: Vec<bool>,
+}
+
+/// Offscreen layer for one push_opacity group (None = opaque, draws pass through)
+struct OpacityLayer {
+    target: Option<RenderTarget>,
+    opacity: f32,
 }
 
 /// RGBA color
@@ -91,6 +128,7 @@ where
 
         let context = Context::new(display_handle)?;
         let surface = Surface::new(&context, window_handle)?;
+        let frame = RenderTarget::new(width.max(1), height.max(1))?;
 
         Ok(Self {
             context,
@@ -98,57 +136,357 @@ where
             width,
             height,
             clear_color: Color::NEBULA_BLUE,
+            scale_factor: 1.0,
+            render_targets: HashMap::new(),
+            next_render_target: 1,
+            active_render_target: None,
+            frame,
+            last_frame: Vec::new(),
+            opacity: OpacityStack::new(),
+            layers: Vec::new(),
+            images: HashMap::new(),
+            streams: Vec::new(),
+            text_rasterizer: None,
+            replay_stats: ReplayStats::default(),
+            occluded: Vec::new(),
         })
     }
 
-    /// Set the clear color
-    pub fn set_clear_color(&mut self, color: Color) {
+    /// Get a render target
+    pub fn render_target(&self, id: RenderTargetId) -> Option<&RenderTarget> {
+        self.render_targets.get(&id)
+    }
+
+    /// Get a render target for drawing
+    pub fn render_target_mut(&mut self, id: RenderTargetId) -> Option<&mut RenderTarget> {
+        self.render_targets.get_mut(&id)
+    }
+
+    /// Render target currently being drawn into
+    pub fn active_render_target(&self) -> Option<RenderTargetId> {
+        self.active_render_target
+    }
+
+    /// Size of whatever draws land in when no opacity layer is open
+    fn target_size(&self) -> (u32, u32) {
+        match self.active_render_target.and_then(|id| self.render_targets.get(&id)) {
+            Some(target) => (target.width(), target.height()),
+            None => (self.width.max(1), self.height.max(1)),
+        }
+    }
+
+    /// Upload an image for `DrawCommand::Image` (replaces any image with the same id)
+    pub fn upload_image(&mut self, id: ImageId, image: &RgbaImage) -> Result<(), String> {
+        self.images.insert(id, RenderTarget::from_image(image)?);
+        Ok(())
+    }
+
+    /// Draw a stream's frames as its image id (camera preview, video) 🎞️
+    /// New frames are copied into the same pixmap before each `render`
+    pub fn attach_stream(&mut self, stream: StreamingTexture) {
+        self.detach_stream(stream.id());
+        self.streams.push((stream, 0));
+    }
+
+    /// Stop following a stream (its last frame stays uploaded)
+    pub fn detach_stream(&mut self, id: ImageId) {
+        self.streams.retain(|(stream, _)| stream.id() != id);
+    }
+
+    fn sync_streams(&mut self) -> Result<(), String> {
+        for (stream, seen) in &mut self.streams {
+            let images = &mut self.images;
+            let synced = stream.read_newer(*seen, |width, height, pixels| match images.get_mut(&stream.id()) {
+                Some(target) if (target.width(), target.height()) == (width, height) => target.write_pixels(pixels),
+                _ => {
+                    let image = RgbaImage::from_raw(width, height, pixels.to_vec())?;
+                    images.insert(stream.id(), RenderTarget::from_image(&image)?);
+                    Ok(())
+                }
+            });
+            if let Some((version, upload)) = synced {
+                *seen = version;
+                upload?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Rasterize `DrawCommand::Text` with this (the CPU tier has no font stack of its own)
+    pub fn set_text_rasterizer(&mut self, rasterizer: TextRasterizer) {
+        self.text_rasterizer = Some(rasterizer);
+    }
+
+    /// Culling and fill counts from the last `render`
+    pub fn replay_stats(&self) -> ReplayStats {
+        self.replay_stats
+    }
+
+    /// Draw an image as a nine-patch onto the frame (or open opacity layer)
+    /// dst is (x, y, width, height) in physical pixels, origin top-left
+    pub fn draw_nine_patch(&mut self, image: &RenderTarget, insets: NinePatchInsets, dst: (f32, f32, f32, f32)) -> Result<(), Box<dyn std::error::Error>> {
+        let (x, y, width, height) = dst;
+        let (left, top) = (x.floor(), y.floor());
+        let mut scratch = RenderTarget::new(
+            (x + width - left).ceil().max(1.0) as u32,
+            (y + height - top).ceil().max(1.0) as u32,
+        )?;
+        scratch.draw_nine_patch(image, insets, (x - left, y - top, width, height));
+
+        Ok(self.composite(&scratch, left as i32, top as i32, 1.0)?)
+    }
+
+    /// Effective opacity of the current group
+    pub fn current_opacity(&self) -> f32 {
+        self.opacity.current()
+    }
+
+    /// Draw into the innermost open layer, the active render target, or the frame
+    fn composite(&mut self, source: &RenderTarget, x: i32, y: i32, opacity: f32) -> Result<(), RenderError> {
+        let canvas = canvas(&mut self.layers, &mut self.render_targets, self.active_render_target, &mut self.frame);
+        canvas.draw_target_with_opacity(source, x, y, opacity);
+        Ok(())
+    }
+
+    /// Set the scale factor (physical pixels per logical pixel)
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        info!("CPU renderer scale factor set to {}", scale_factor);
+        self.scale_factor = scale_factor;
+    }
+}
+
+impl<D, W> Renderer for CpuRenderer<D, W>
+where
+    D: raw_window_handle::HasDisplayHandle,
+    W: raw_window_handle::HasWindowHandle,
+{
+    type Color = Color;
+    type Error = RenderError;
+
+    fn set_clear_color(&mut self, color: Color) {
         self.clear_color = color;
     }
 
-    /// Resize the renderer
-    pub fn resize(&mut self, width: u32, height: u32) {
+    fn resize(&mut self, width: u32, height: u32) {
         info!("Resizing CPU renderer to {}x{}", width, height);
         self.width = width;
         self.height = height;
+        match RenderTarget::new(width.max(1), height.max(1)) {
+            Ok(frame) => self.frame = frame,
+            Err(e) => warn!("Failed to resize frame: {}", e),
+        }
     }
 
-    /// Begin a new frame
-    pub fn begin_frame(&mut self) {
+    fn begin_frame(&mut self) {
         // Nothing to do for CPU renderer
     }
 
-    /// Clear the screen with the current clear color
-    pub fn clear(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    /// Clear the frame with the current clear color
+    fn clear(&mut self) -> Result<(), RenderError> {
+        self.frame.clear(self.clear_color);
+        Ok(())
+    }
+
+    /// Copy the finished frame to the window surface and present it once
+    fn end_frame(&mut self) -> Result<(), RenderError> {
+        if !self.layers.is_empty() {
+            warn!("{} opacity layer(s) still open at end_frame", self.layers.len());
+        }
         let width = NonZeroU32::new(self.width).ok_or("Width is zero")?;
         let height = NonZeroU32::new(self.height).ok_or("Height is zero")?;
+        self.surface.resize(width, height).map_err(surface_error)?;
 
-        self.surface.resize(width, height)?;
+        let mut buffer = self.surface.buffer_mut().map_err(surface_error)?;
+        self.frame.copy_into(&mut buffer, self.width);
 
-        let mut buffer = self.surface.buffer_mut()?;
-        let clear_color = self.clear_color.to_argb();
+        self.last_frame.clear();
+        self.last_frame.extend_from_slice(&buffer);
+        buffer.present().map_err(surface_error)?;
 
-        // Fill the entire buffer with the clear color
-        for pixel in buffer.iter_mut() {
-            *pixel = clear_color;
+        Ok(())
+    }
+
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Read back the last presented frame (or the active render target's contents)
+    fn capture_frame(&mut self) -> Result<RgbaImage, RenderError> {
+        if let Some(target) = self.active_render_target.and_then(|id| self.render_targets.get(&id)) {
+            return Ok(target.capture());
+        }
+        if self.last_frame.is_empty() {
+            return Err("No frame has been presented yet".into());
         }
+        let image = RgbaImage::from_argb(self.width, self.height, &self.last_frame)?;
+        info!("📸 Captured {}x{} frame (CPU)", self.width, self.height);
+        Ok(image)
+    }
 
-        buffer.present()?;
+    /// Fill and/or stroke a vector path onto the current target (or open opacity layer)
+    /// Path coordinates are in physical pixels, origin top-left
+    fn draw_path(&mut self, path: &Path, fill: Option<Color>, stroke: Option<(Color, f32)>) -> Result<(), RenderError> {
+        let Some((x, y, width, height)) = path.bounds() else {
+            return Ok(());
+        };
 
-        Ok(())
+        // Rasterize into a scratch target covering the path (plus stroke overhang)
+        let pad = stroke.map_or(0.0, |(_, w)| w / 2.0).ceil() + 1.0;
+        let (left, top) = ((x - pad).floor(), (y - pad).floor());
+        let mut scratch = RenderTarget::new(
+            (width + 2.0 * pad).ceil() as u32 + 1,
+            (height + 2.0 * pad).ceil() as u32 + 1,
+        )?;
+        let translated = Path {
+            commands: path.commands.iter().map(|c| translate(*c, -left, -top)).collect(),
+            fill_rule: path.fill_rule,
+        };
+        scratch.draw_path(&translated, fill, stroke);
+
+        self.composite(&scratch, left as i32, top as i32, 1.0)
     }
 
-    /// End the current frame and present
-    pub fn end_frame(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        // For CPU renderer, we present in clear() for now
-        // In the future, we'll accumulate draw calls and present here
+    /// Start a transparency group
+    /// Draws go into an offscreen layer until `pop_opacity` fades it in as one
+    fn push_opacity(&mut self, opacity: f32) {
+        let effective = self.opacity.push(opacity);
+        let level = self.opacity.top();
+        // Fully opaque groups don't need a layer; if one can't be allocated
+        // the group draws straight through rather than unbalancing the stack
+        let target = if level < 1.0 {
+            let (width, height) = self.target_size();
+            RenderTarget::new(width, height)
+                .map_err(|e| warn!("Failed to allocate opacity layer: {}", e))
+                .ok()
+        } else {
+            None
+        };
+        info!("👻 Opacity layer {} pushed (effective {:.2})", self.opacity.depth(), effective);
+        self.layers.push(OpacityLayer { target, opacity: level });
+    }
+
+    /// End the innermost transparency group and composite it
+    fn pop_opacity(&mut self) -> Result<(), RenderError> {
+        let layer = self.layers.pop().ok_or("pop_opacity called without push_opacity")?;
+        self.opacity.pop();
+        match layer.target {
+            Some(target) => self.composite(&target, 0, 0, layer.opacity),
+            None => Ok(()),
+        }
+    }
+
+    /// Rasterize the list straight onto the current target (or open opacity layer)
+    /// The list is in logical pixels and drawn at the scale factor; fill rate
+    /// is the CPU tier's bottleneck, so hidden commands are skipped
+    fn render(&mut self, list: &DrawList) -> Result<(), RenderError> {
+        self.sync_streams()?;
+        let commands_culled = list.mark_occluded(&mut self.occluded);
+
+        let scale = self.scale_factor as f32;
+        let canvas = canvas(&mut self.layers, &mut self.render_targets, self.active_render_target, &mut self.frame);
+        let pixels_filled = replay::replay(canvas, list, &self.occluded, scale, &self.images, self.text_rasterizer.as_mut())?;
+        self.replay_stats = ReplayStats { commands_culled, pixels_filled };
         Ok(())
     }
 
-    /// Get current dimensions
-    pub fn dimensions(&self) -> (u32, u32) {
-        (self.width, self.height)
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    fn supports_render_targets(&self) -> bool {
+        true
     }
+
+    /// Create an offscreen render target (size in physical pixels)
+    /// Draw into it after `set_render_target`, or directly through `render_target_mut`
+    fn create_render_target(&mut self, width: u32, height: u32) -> Option<RenderTargetId> {
+        let target = RenderTarget::new(width, height)
+            .map_err(|e| warn!("Failed to create render target: {}", e))
+            .ok()?;
+        let id = RenderTargetId(self.next_render_target);
+        self.next_render_target += 1;
+        self.render_targets.insert(id, target);
+        Some(id)
+    }
+
+    /// Redirect drawing into a render target (None = back to the window)
+    /// Opacity groups already open keep collecting draws until they're popped
+    fn set_render_target(&mut self, target: Option<RenderTargetId>) -> bool {
+        if let Some(id) = target.filter(|id| !self.render_targets.contains_key(id)) {
+            warn!("Failed to bind unknown render target {:?}", id);
+            return false;
+        }
+        self.active_render_target = target;
+        true
+    }
+
+    /// Composite a render target onto the current target (or open opacity layer) at (x, y)
+    fn draw_render_target(&mut self, id: RenderTargetId, x: f32, y: f32) -> Result<(), RenderError> {
+        if self.active_render_target == Some(id) {
+            return Err(format!("Cannot draw render target {:?} into itself", id).into());
+        }
+        // Take the target out so it can be read while compositing mutably
+        let target = self.render_targets.remove(&id)
+            .ok_or_else(|| format!("Unknown render target {:?}", id))?;
+        let result = self.composite(&target, x.round() as i32, y.round() as i32, 1.0);
+        self.render_targets.insert(id, target);
+        result
+    }
+
+    fn delete_render_target(&mut self, id: RenderTargetId) {
+        if self.render_targets.remove(&id).is_none() {
+            warn!("Tried to delete unknown render target {:?}", id);
+        }
+        if self.active_render_target == Some(id) {
+            warn!("Deleted the active render target {:?}, switching back to the window", id);
+            self.active_render_target = None;
+        }
+    }
+
+    /// Effects this renderer can draw (no blur: too slow per frame)
+    fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities { render_targets: true, ..RendererCapabilities::CPU }
+    }
+
+    fn name(&self) -> &'static str {
+        "CPU (softbuffer + tiny-skia)"
+    }
+}
+
+/// Where draws land: the innermost open opacity layer, else the active render target, else the frame
+fn canvas<'a>(
+    layers: &'a mut [OpacityLayer],
+    render_targets: &'a mut HashMap<RenderTargetId, RenderTarget>,
+    active: Option<RenderTargetId>,
+    frame: &'a mut RenderTarget,
+) -> &'a mut RenderTarget {
+    if let Some(layer) = layers.iter_mut().rev().find_map(|l| l.target.as_mut()) {
+        return layer;
+    }
+    match active.and_then(|id| render_targets.get_mut(&id)) {
+        Some(target) => target,
+        None => frame,
+    }
+}
+
+fn surface_error(error: softbuffer::SoftBufferError) -> RenderError {
+    RenderError(format!("softbuffer: {}", error))
+}
+
+fn translate(command: nebula_gfx::path::PathCommand, dx: f32, dy: f32) -> nebula_gfx::path::PathCommand {
+    use nebula_gfx::path::PathCommand::*;
+    match command {
+        MoveTo(x, y) => MoveTo(x + dx, y + dy),
+        LineTo(x, y) => LineTo(x + dx, y + dy),
+        QuadTo(cx, cy, x, y) => QuadTo(cx + dx, cy + dy, x + dx, y + dy),
+        CubicTo(c1x, c1y, c2x, c2y, x, y) => CubicTo(c1x + dx, c1y + dy, c2x + dx, c2y + dy, x + dx, y + dy),
+        Close => Close,
+    }
+}
+
+/// Convert a physical size to logical pixels
+pub fn logical_size(width: u32, height: u32, scale_factor: f64) -> (f64, f64) {
+    (width as f64 / scale_factor, height as f64 / scale_factor)
 }
 
 #[cfg(test)]
@@ -208,6 +546,12 @@ mod tests {
         assert_eq!(color.a, 255);
     }
 
+    #[test]
+    fn logical_size_divides_by_scale() {
+        assert_eq!(logical_size(1600, 1200, 2.0), (800.0, 600.0));
+        assert_eq!(logical_size(800, 600, 1.0), (800.0, 600.0));
+    }
+
     #[test]
     fn color_to_argb_conversion() {
         let color = Color::rgba(255, 128, 64, 200);
@@ -0,0 +1,465 @@
+use nebula_gfx::path::{FillRule, Path, PathCommand};
+use nebula_gfx::{nine_patch_slices, NinePatchInsets, RgbaImage};
+use tiny_skia::{
+    FilterQuality, Paint, PathBuilder, Pattern, Pixmap, PixmapPaint, Rect, SpreadMode, Stroke, Transform,
+};
+use tracing::info;
+
+use crate::Color;
+
+/// Handle to an offscreen render target (shared with the `Renderer` trait)
+pub use nebula_gfx::RenderTargetId;
+
+/// Offscreen render target - a tiny-skia pixmap 🎯
+///
+/// Draw into it, then composite it into the window buffer or another
+/// target. Used for caching expensive subtrees, blur passes, and exports.
+#[derive(Debug, Clone)]
+pub struct RenderTarget {
+    pixmap: Pixmap,
+}
+
+impl RenderTarget {
+    /// Create a transparent render target (size in physical pixels)
+    pub fn new(width: u32, height: u32) -> Result<Self, String> {
+        let pixmap = Pixmap::new(width, height)
+            .ok_or_else(|| format!("Invalid render target size {}x{}", width, height))?;
+        info!("🎯 Created {}x{} CPU render target", width, height);
+        Ok(Self { pixmap })
+    }
+
+    /// Create a render target holding an image (for use as a draw source)
+    pub fn from_image(image: &RgbaImage) -> Result<Self, String> {
+        let mut target = Self::new(image.width, image.height)?;
+        target.write_pixels(&image.pixels)?;
+        Ok(target)
+    }
+
+    /// Overwrite the contents with straight-alpha RGBA8 pixels of the same size
+    pub fn write_pixels(&mut self, pixels: &[u8]) -> Result<(), String> {
+        let expected = self.width() as usize * self.height() as usize * 4;
+        if pixels.len() != expected {
+            return Err(format!("Pixel data is {} bytes, expected {} for {}x{}", pixels.len(), expected, self.width(), self.height()));
+        }
+        for (dst, src) in self.pixmap.pixels_mut().iter_mut().zip(pixels.chunks_exact(4)) {
+            *dst = tiny_skia::ColorU8::from_rgba(src[0], src[1], src[2], src[3]).premultiply();
+        }
+        Ok(())
+    }
+
+    /// Width in pixels
+    pub fn width(&self) -> u32 {
+        self.pixmap.width()
+    }
+
+    /// Height in pixels
+    pub fn height(&self) -> u32 {
+        self.pixmap.height()
+    }
+
+    /// Fill the whole target with a color
+    pub fn clear(&mut self, color: Color) {
+        self.pixmap.fill(to_skia(color));
+    }
+
+    /// Fill a rectangle (pixel coordinates, origin top-left)
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        if let Some(rect) = Rect::from_xywh(x, y, width, height) {
+            let mut paint = Paint::default();
+            paint.set_color(to_skia(color));
+            self.pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+        }
+    }
+
+    /// Fill and/or stroke a vector path (pixel coordinates, origin top-left)
+    pub fn draw_path(&mut self, path: &Path, fill: Option<Color>, stroke: Option<(Color, f32)>) {
+        let Some(skia_path) = to_skia_path(path) else {
+            return;
+        };
+
+        if let Some(color) = fill {
+            let mut paint = Paint::default();
+            paint.set_color(to_skia(color));
+            paint.anti_alias = true;
+            let fill_rule = match path.fill_rule {
+                FillRule::NonZero => tiny_skia::FillRule::Winding,
+                FillRule::EvenOdd => tiny_skia::FillRule::EvenOdd,
+            };
+            self.pixmap.fill_path(&skia_path, &paint, fill_rule, Transform::identity(), None);
+        }
+
+        if let Some((color, width)) = stroke {
+            let mut paint = Paint::default();
+            paint.set_color(to_skia(color));
+            paint.anti_alias = true;
+            let stroke = Stroke { width, ..Stroke::default() };
+            self.pixmap.stroke_path(&skia_path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    /// Draw part of another target, scaled to fill a destination rect
+    /// Rects are (x, y, width, height) in pixels, origin top-left
+    pub fn draw_image_rect(&mut self, source: &RenderTarget, src: (f32, f32, f32, f32), dst: (f32, f32, f32, f32)) {
+        self.draw_image_rect_with_opacity(source, src, dst, 1.0);
+    }
+
+    /// Draw part of another target scaled over a destination rect, faded by `opacity`
+    pub fn draw_image_rect_with_opacity(
+        &mut self,
+        source: &RenderTarget,
+        src: (f32, f32, f32, f32),
+        dst: (f32, f32, f32, f32),
+        opacity: f32,
+    ) {
+        let (sx, sy, sw, sh) = src;
+        let (dx, dy, dw, dh) = dst;
+        let Some(rect) = Rect::from_xywh(dx, dy, dw, dh) else {
+            return;
+        };
+        if sw <= 0.0 || sh <= 0.0 {
+            return;
+        }
+
+        // Copy the source rect out so filtering can't bleed in neighbouring pixels
+        let Some(region) = tiny_skia::IntRect::from_xywh(sx as i32, sy as i32, sw.ceil() as u32, sh.ceil() as u32)
+            .and_then(|r| source.pixmap.clone_rect(r))
+        else {
+            return;
+        };
+
+        // Map the source rect onto the destination rect
+        let (scale_x, scale_y) = (dw / sw, dh / sh);
+        let transform = Transform::from_row(scale_x, 0.0, 0.0, scale_y, dx, dy);
+        let quality = if scale_x == 1.0 && scale_y == 1.0 {
+            FilterQuality::Nearest
+        } else {
+            FilterQuality::Bilinear
+        };
+
+        let paint = Paint {
+            shader: Pattern::new(region.as_ref(), SpreadMode::Pad, quality, opacity.clamp(0.0, 1.0), transform),
+            ..Paint::default()
+        };
+        self.pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+    }
+
+    /// Draw an image as a nine-patch stretched over a destination rect 🧩
+    /// Corners keep their size, edges and center stretch
+    pub fn draw_nine_patch(&mut self, source: &RenderTarget, insets: NinePatchInsets, dst: (f32, f32, f32, f32)) {
+        for slice in nine_patch_slices(source.width(), source.height(), insets, dst) {
+            self.draw_image_rect(source, slice.src, slice.dst);
+        }
+    }
+
+    /// Blend a solid color through an 8-bit coverage mask (e.g. a text glyph)
+    /// The mask is `mask_width` pixels wide, rows top to bottom, placed at (x, y)
+    pub fn draw_mask(&mut self, x: i32, y: i32, mask_width: usize, mask: &[u8], color: Color) {
+        if mask_width == 0 {
+            return;
+        }
+        let (width, height) = (self.width() as i32, self.height() as i32);
+        let pixels = self.pixmap.pixels_mut();
+
+        for (row, coverage_row) in mask.chunks(mask_width).enumerate() {
+            let dy = y + row as i32;
+            if dy < 0 || dy >= height {
+                continue;
+            }
+            for (col, &coverage) in coverage_row.iter().enumerate() {
+                let dx = x + col as i32;
+                if coverage == 0 || dx < 0 || dx >= width {
+                    continue;
+                }
+
+                // Premultiplied source over destination
+                let alpha = coverage as u32 * color.a as u32 / 255;
+                let inv = 255 - alpha;
+                let index = (dy * width + dx) as usize;
+                let dst = pixels[index];
+                let blend = |s: u8, d: u8| ((s as u32 * alpha + d as u32 * inv + 127) / 255) as u8;
+                let a = (alpha + (dst.alpha() as u32 * inv + 127) / 255).min(255) as u8;
+                let (r, g, b) = (
+                    blend(color.r, dst.red()).min(a),
+                    blend(color.g, dst.green()).min(a),
+                    blend(color.b, dst.blue()).min(a),
+                );
+                if let Some(blended) = tiny_skia::PremultipliedColorU8::from_rgba(r, g, b, a) {
+                    pixels[index] = blended;
+                }
+            }
+        }
+    }
+
+    /// Draw another render target into this one at (x, y)
+    pub fn draw_target(&mut self, source: &RenderTarget, x: i32, y: i32) {
+        self.draw_target_with_opacity(source, x, y, 1.0);
+    }
+
+    /// Draw another render target into this one at (x, y), faded as a whole
+    pub fn draw_target_with_opacity(&mut self, source: &RenderTarget, x: i32, y: i32, opacity: f32) {
+        let paint = PixmapPaint {
+            opacity: opacity.clamp(0.0, 1.0),
+            ..PixmapPaint::default()
+        };
+        self.pixmap.draw_pixmap(x, y, source.pixmap.as_ref(), &paint, Transform::identity(), None);
+    }
+
+    /// Read a pixel (straight alpha)
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        self.pixmap.pixel(x, y).map(|p| {
+            let c = p.demultiply();
+            Color::rgba(c.red(), c.green(), c.blue(), c.alpha())
+        })
+    }
+
+    /// Contents as straight-alpha RGBA8 bytes, rows top to bottom
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.pixmap
+            .pixels()
+            .iter()
+            .flat_map(|p| {
+                let c = p.demultiply();
+                [c.red(), c.green(), c.blue(), c.alpha()]
+            })
+            .collect()
+    }
+
+    /// Copy the contents into an image
+    pub fn capture(&self) -> nebula_gfx::RgbaImage {
+        nebula_gfx::RgbaImage {
+            width: self.width(),
+            height: self.height(),
+            pixels: self.to_rgba8(),
+        }
+    }
+
+    /// Copy into a softbuffer-style 0RGB buffer at the origin, replacing its contents
+    /// Translucent pixels end up over black; rows and columns past either edge are skipped
+    pub fn copy_into(&self, buffer: &mut [u32], buffer_width: u32) {
+        let (width, buffer_width) = (self.width() as usize, buffer_width as usize);
+        let columns = width.min(buffer_width);
+        for (src_row, dst_row) in self.pixmap.pixels().chunks(width).zip(buffer.chunks_mut(buffer_width)) {
+            for (dst, src) in dst_row[..columns].iter_mut().zip(src_row) {
+                *dst = (0xFF << 24) | ((src.red() as u32) << 16) | ((src.green() as u32) << 8) | src.blue() as u32;
+            }
+        }
+    }
+
+    /// Composite into a softbuffer-style 0RGB buffer at (x, y), clipping to its bounds
+    pub fn blit_into(&self, buffer: &mut [u32], buffer_width: u32, buffer_height: u32, x: i32, y: i32) {
+        self.blit_into_with_opacity(buffer, buffer_width, buffer_height, x, y, 1.0);
+    }
+
+    /// Composite into a 0RGB buffer, fading the whole target by `opacity`
+    pub fn blit_into_with_opacity(
+        &self,
+        buffer: &mut [u32],
+        buffer_width: u32,
+        buffer_height: u32,
+        x: i32,
+        y: i32,
+        opacity: f32,
+    ) {
+        let opacity = (opacity.clamp(0.0, 1.0) * 255.0).round() as u32;
+        let width = self.width() as i32;
+        let pixels = self.pixmap.pixels();
+
+        for (row, chunk) in pixels.chunks(width as usize).enumerate() {
+            let dy = y + row as i32;
+            if dy < 0 || dy >= buffer_height as i32 {
+                continue;
+            }
+            for (col, src) in chunk.iter().enumerate() {
+                let dx = x + col as i32;
+                if dx < 0 || dx >= buffer_width as i32 {
+                    continue;
+                }
+
+                // Source is premultiplied: out = src + dst * (1 - src_alpha)
+                let index = (dy as u32 * buffer_width + dx as u32) as usize;
+                let dst = buffer[index];
+                let fade = |c: u8| (c as u32 * opacity + 127) / 255;
+                let inv = 255 - fade(src.alpha());
+                let blend = |s: u8, d: u32| (fade(s) + (d * inv + 127) / 255).min(255);
+
+                let r = blend(src.red(), (dst >> 16) & 0xFF);
+                let g = blend(src.green(), (dst >> 8) & 0xFF);
+                let b = blend(src.blue(), dst & 0xFF);
+                buffer[index] = (0xFF << 24) | (r << 16) | (g << 8) | b;
+            }
+        }
+    }
+}
+
+fn to_skia_path(path: &Path) -> Option<tiny_skia::Path> {
+    let mut builder = PathBuilder::new();
+    for command in &path.commands {
+        match *command {
+            PathCommand::MoveTo(x, y) => builder.move_to(x, y),
+            PathCommand::LineTo(x, y) => builder.line_to(x, y),
+            PathCommand::QuadTo(cx, cy, x, y) => builder.quad_to(cx, cy, x, y),
+            PathCommand::CubicTo(c1x, c1y, c2x, c2y, x, y) => builder.cubic_to(c1x, c1y, c2x, c2y, x, y),
+            PathCommand::Close => builder.close(),
+        }
+    }
+    builder.finish()
+}
+
+fn to_skia(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba8(color.r, color.g, color.b, color.a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_target_rejects_zero_size() {
+        assert!(RenderTarget::new(0, 10).is_err());
+    }
+
+    #[test]
+    fn render_target_starts_transparent() {
+        let target = RenderTarget::new(4, 4).unwrap();
+        assert_eq!(target.pixel(0, 0), Some(Color::TRANSPARENT));
+        assert_eq!(target.to_rgba8().len(), 4 * 4 * 4);
+        assert_eq!(target.capture().pixel(3, 3), Some([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn render_target_fill_rect() {
+        let mut target = RenderTarget::new(4, 4).unwrap();
+        target.clear(Color::WHITE);
+        target.fill_rect(0.0, 0.0, 2.0, 2.0, Color::RED);
+        assert_eq!(target.pixel(1, 1), Some(Color::RED));
+        assert_eq!(target.pixel(3, 3), Some(Color::WHITE));
+    }
+
+    #[test]
+    fn render_target_draw_path_fill_and_stroke() {
+        let mut target = RenderTarget::new(20, 20).unwrap();
+        let triangle = nebula_gfx::PathBuilder::new()
+            .move_to(2.0, 2.0)
+            .line_to(18.0, 2.0)
+            .line_to(2.0, 18.0)
+            .close()
+            .build();
+        target.draw_path(&triangle, Some(Color::RED), None);
+        assert_eq!(target.pixel(5, 5), Some(Color::RED));
+        assert_eq!(target.pixel(16, 16), Some(Color::TRANSPARENT));
+
+        let line = nebula_gfx::PathBuilder::new().move_to(0.0, 10.0).line_to(20.0, 10.0).build();
+        target.draw_path(&line, None, Some((Color::BLUE, 4.0)));
+        assert_eq!(target.pixel(15, 10), Some(Color::BLUE));
+    }
+
+    #[test]
+    fn render_target_even_odd_leaves_hole() {
+        let mut target = RenderTarget::new(20, 20).unwrap();
+        let ring = nebula_gfx::PathBuilder::new()
+            .rect(0.0, 0.0, 20.0, 20.0)
+            .rect(5.0, 5.0, 10.0, 10.0)
+            .fill_rule(FillRule::EvenOdd)
+            .build();
+        target.draw_path(&ring, Some(Color::GREEN), None);
+        assert_eq!(target.pixel(2, 2), Some(Color::GREEN));
+        assert_eq!(target.pixel(10, 10), Some(Color::TRANSPARENT));
+    }
+
+    #[test]
+    fn render_target_from_image_round_trips() {
+        let image = RgbaImage::from_raw(1, 1, vec![255, 0, 0, 255]).unwrap();
+        let mut target = RenderTarget::from_image(&image).unwrap();
+        assert_eq!(target.capture(), image);
+
+        // Streamed frames overwrite the same pixmap
+        target.write_pixels(&[0, 0, 255, 255]).unwrap();
+        assert_eq!(target.pixel(0, 0), Some(Color::BLUE));
+        assert!(target.write_pixels(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn render_target_nine_patch_keeps_corners() {
+        // 3x3 source: red border, blue center
+        let mut source = RenderTarget::new(3, 3).unwrap();
+        source.clear(Color::RED);
+        source.fill_rect(1.0, 1.0, 1.0, 1.0, Color::BLUE);
+
+        let mut dest = RenderTarget::new(10, 10).unwrap();
+        dest.draw_nine_patch(&source, NinePatchInsets::uniform(1.0), (0.0, 0.0, 10.0, 10.0));
+        assert_eq!(dest.pixel(0, 0), Some(Color::RED));
+        assert_eq!(dest.pixel(9, 9), Some(Color::RED));
+        assert_eq!(dest.pixel(5, 0), Some(Color::RED));
+        assert_eq!(dest.pixel(5, 5), Some(Color::BLUE));
+        assert_eq!(dest.pixel(2, 7), Some(Color::BLUE));
+    }
+
+    #[test]
+    fn render_target_draw_mask_blends_coverage() {
+        let mut target = RenderTarget::new(3, 1).unwrap();
+        target.clear(Color::WHITE);
+        target.draw_mask(0, 0, 2, &[255, 0], Color::BLACK);
+        assert_eq!(target.pixel(0, 0), Some(Color::BLACK));
+        assert_eq!(target.pixel(1, 0), Some(Color::WHITE));
+
+        target.draw_mask(2, 0, 1, &[128], Color::BLACK);
+        let half = target.pixel(2, 0).unwrap();
+        assert!(half.r > 120 && half.r < 135 && half.a == 255);
+    }
+
+    #[test]
+    fn render_target_opacity_composites_as_group() {
+        // Two overlapping opaque rects in a layer, faded together
+        let mut layer = RenderTarget::new(4, 1).unwrap();
+        layer.fill_rect(0.0, 0.0, 3.0, 1.0, Color::RED);
+        layer.fill_rect(1.0, 0.0, 3.0, 1.0, Color::RED);
+
+        let mut dest = RenderTarget::new(4, 1).unwrap();
+        dest.clear(Color::WHITE);
+        dest.draw_target_with_opacity(&layer, 0, 0, 0.5);
+        // The overlap isn't darker than the rest
+        assert_eq!(dest.pixel(0, 0), dest.pixel(1, 0));
+        assert_eq!(dest.pixel(1, 0), dest.pixel(3, 0));
+
+        let mut buffer = vec![Color::WHITE.to_argb(); 4];
+        layer.blit_into_with_opacity(&mut buffer, 4, 1, 0, 0, 0.5);
+        assert_eq!(buffer[1], buffer[3]);
+        assert_eq!((buffer[1] >> 16) & 0xFF, 255);
+        assert!((buffer[1] & 0xFF) > 120 && (buffer[1] & 0xFF) < 135);
+    }
+
+    #[test]
+    fn render_target_draw_into_other_target() {
+        let mut source = RenderTarget::new(2, 2).unwrap();
+        source.clear(Color::BLUE);
+        let mut dest = RenderTarget::new(4, 4).unwrap();
+        dest.draw_target(&source, 2, 2);
+        assert_eq!(dest.pixel(3, 3), Some(Color::BLUE));
+        assert_eq!(dest.pixel(0, 0), Some(Color::TRANSPARENT));
+    }
+
+    #[test]
+    fn render_target_copy_replaces_buffer() {
+        let mut target = RenderTarget::new(2, 2).unwrap();
+        target.fill_rect(0.0, 0.0, 1.0, 1.0, Color::RED);
+
+        let mut buffer = vec![Color::WHITE.to_argb(); 3 * 2];
+        target.copy_into(&mut buffer, 3);
+        assert_eq!(buffer[0], Color::RED.to_argb());
+        // Transparent pixels don't keep what was in the buffer
+        assert_eq!(buffer[3 + 1], Color::BLACK.to_argb());
+        assert_eq!(buffer[2], Color::WHITE.to_argb());
+    }
+
+    #[test]
+    fn render_target_blit_clips_and_blends() {
+        let mut target = RenderTarget::new(2, 2).unwrap();
+        target.fill_rect(0.0, 0.0, 1.0, 2.0, Color::RED);
+
+        let mut buffer = vec![Color::BLUE.to_argb(); 3 * 3];
+        target.blit_into(&mut buffer, 3, 3, 2, -1);
+        assert_eq!(buffer[2], Color::RED.to_argb());
+        assert_eq!(buffer[3 + 2], Color::BLUE.to_argb());
+        assert_eq!(buffer[0], Color::BLUE.to_argb());
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use nebula_gfx::draw_list::{intersect_rects, ClipStack, DrawCommand, DrawList, ImageId, Rect, Rgba, TextRasterizer};
+use nebula_gfx::path::PathBuilder;
+
+use crate::{Color, RenderTarget};
+
+/// What the last DrawList replay did (to check the CPU tier's fill rate)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplayStats {
+    /// Commands dropped by `DrawList::cull_occluded`
+    pub commands_culled: usize,
+    /// Pixels rasterized, overdraw included
+    pub pixels_filled: u64,
+}
+
+/// Offscreen layer for one clip, with its top-left on the frame
+/// None when the clip is empty (nothing inside it can show)
+type ClipLayer = Option<(RenderTarget, i32, i32)>;
+
+/// Rasterize a DrawList into a target with tiny-skia 📝
+///
+/// Each clip draws into its own layer the size of the clip rect, so
+/// everything inside is cropped for free; popping the clip composites the
+/// layer back into its parent. `images` are the uploads keyed by ImageId.
+/// Text commands need a rasterizer and are skipped without one. Commands
+/// flagged in `occluded` (see `DrawList::mark_occluded`) are skipped too.
+/// The list is in logical pixels; `scale` maps it onto the physical frame.
+///
+/// Returns the number of pixels filled (each fill counted after cropping
+/// to its layer, so overdraw shows up and offscreen content doesn't).
+pub fn replay(
+    frame: &mut RenderTarget,
+    list: &DrawList,
+    occluded: &[bool],
+    scale: f32,
+    images: &HashMap<ImageId, RenderTarget>,
+    mut text: Option<&mut TextRasterizer>,
+) -> Result<u64, String> {
+    let bounds = (0.0, 0.0, frame.width() as f32, frame.height() as f32);
+    let mut clips = ClipStack::new();
+    let mut layers: Vec<ClipLayer> = Vec::new();
+    let mut filled = 0;
+
+    for (index, command) in list.commands().enumerate() {
+        if occluded.get(index) == Some(&true) {
+            continue;
+        }
+        match command {
+            DrawCommand::PushClip(rect) => {
+                let (x, y, width, height) = intersect_rects(bounds, clips.push(scale_rect(*rect, scale)));
+                let (left, top) = (x.floor(), y.floor());
+                let layer = if width > 0.0 && height > 0.0 {
+                    let target = RenderTarget::new((x + width - left).ceil() as u32, (y + height - top).ceil() as u32)?;
+                    Some((target, left as i32, top as i32))
+                } else {
+                    None
+                };
+                layers.push(layer);
+            }
+            DrawCommand::PopClip => {
+                clips.pop();
+                if let Some(Some((layer, x, y))) = layers.pop() {
+                    if let Some((parent, px, py)) = current_layer(frame, &mut layers) {
+                        parent.draw_target(&layer, x - px, y - py);
+                        filled += covered_pixels(parent, (x - px) as f32, (y - py) as f32, layer.width() as f32, layer.height() as f32);
+                    }
+                }
+            }
+            _ => {
+                let Some((canvas, ox, oy)) = current_layer(frame, &mut layers) else {
+                    continue;
+                };
+                let (ox, oy) = (ox as f32, oy as f32);
+                match command {
+                    DrawCommand::Clear(color) => {
+                        canvas.clear(to_color(*color));
+                        filled += canvas.width() as u64 * canvas.height() as u64;
+                    }
+                    DrawCommand::Rect { rect, color } => {
+                        let rect = scale_rect(*rect, scale);
+                        canvas.fill_rect(rect.0 - ox, rect.1 - oy, rect.2, rect.3, to_color(*color));
+                        filled += covered_pixels(canvas, rect.0 - ox, rect.1 - oy, rect.2, rect.3);
+                    }
+                    DrawCommand::RoundedRect { rect, radius, color } => {
+                        let rect = scale_rect(*rect, scale);
+                        let path = PathBuilder::new().rounded_rect(rect.0 - ox, rect.1 - oy, rect.2, rect.3, radius * scale).build();
+                        canvas.draw_path(&path, Some(to_color(*color)), None);
+                        filled += covered_pixels(canvas, rect.0 - ox, rect.1 - oy, rect.2, rect.3);
+                    }
+                    DrawCommand::Text { text: line, x, y, size, color } => {
+                        let Some(rasterize) = text.as_deref_mut() else {
+                            continue;
+                        };
+                        let (left, top) = ((x * scale - ox).round() as i32, (y * scale - oy).round() as i32);
+                        for glyph in rasterize(line, size * scale) {
+                            let (gx, gy) = (left + glyph.x, top + glyph.y);
+                            canvas.draw_mask(gx, gy, glyph.width, &glyph.coverage, to_color(*color));
+                            let rows = glyph.coverage.len().checked_div(glyph.width).unwrap_or(0);
+                            filled += covered_pixels(canvas, gx as f32, gy as f32, glyph.width as f32, rows as f32);
+                        }
+                    }
+                    DrawCommand::Image { image, src, dst, opacity } => {
+                        let source = images.get(image).ok_or_else(|| format!("Image {:?} was not uploaded", image))?;
+                        let src = source_rect(*src, source.width(), source.height());
+                        let dst = scale_rect(*dst, scale);
+                        canvas.draw_image_rect_with_opacity(source, src, (dst.0 - ox, dst.1 - oy, dst.2, dst.3), *opacity);
+                        filled += covered_pixels(canvas, dst.0 - ox, dst.1 - oy, dst.2, dst.3);
+                    }
+                    DrawCommand::PushClip(_) | DrawCommand::PopClip => {}
+                }
+            }
+        }
+    }
+    Ok(filled)
+}
+
+/// Logical rect to physical pixels
+fn scale_rect(rect: Rect, scale: f32) -> Rect {
+    (rect.0 * scale, rect.1 * scale, rect.2 * scale, rect.3 * scale)
+}
+
+/// Whole pixels a rect touches once cropped to the canvas
+fn covered_pixels(canvas: &RenderTarget, x: f32, y: f32, width: f32, height: f32) -> u64 {
+    let (left, top) = (x.floor().max(0.0), y.floor().max(0.0));
+    let right = (x + width).ceil().min(canvas.width() as f32);
+    let bottom = (y + height).ceil().min(canvas.height() as f32);
+    if width <= 0.0 || height <= 0.0 || right <= left || bottom <= top {
+        return 0;
+    }
+    ((right - left) * (bottom - top)) as u64
+}
+
+/// Innermost open clip layer (or the frame), with its offset on the frame
+fn current_layer<'a>(frame: &'a mut RenderTarget, layers: &'a mut [ClipLayer]) -> Option<(&'a mut RenderTarget, i32, i32)> {
+    match layers.last_mut() {
+        None => Some((frame, 0, 0)),
+        Some(Some((layer, x, y))) => Some((layer, *x, *y)),
+        Some(None) => None,
+    }
+}
+
+/// Source region of an image, defaulting to the whole image and clamped to its bounds
+fn source_rect(src: Option<(f32, f32, f32, f32)>, width: u32, height: u32) -> (f32, f32, f32, f32) {
+    let (width, height) = (width as f32, height as f32);
+    let (x, y, w, h) = src.unwrap_or((0.0, 0.0, width, height));
+    let (x, y) = (x.clamp(0.0, width), y.clamp(0.0, height));
+    (x, y, w.clamp(0.0, width - x), h.clamp(0.0, height - y))
+}
+
+fn to_color(color: Rgba) -> Color {
+    Color::rgba(color.0, color.1, color.2, color.3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nebula_gfx::draw_list::GlyphMask;
+
+    #[test]
+    fn replay_fills_and_clips() {
+        let mut frame = RenderTarget::new(20, 20).unwrap();
+        let mut list = DrawList::new();
+        list.clear((0, 0, 0, 255))
+            .push_clip((5.0, 5.0, 5.0, 5.0))
+            .rect((0.0, 0.0, 20.0, 20.0), (255, 0, 0, 255))
+            .pop_clip()
+            .rounded_rect((12.0, 12.0, 8.0, 8.0), 2.0, (0, 0, 255, 255));
+        replay(&mut frame, &list, &[], 1.0, &HashMap::new(), None).unwrap();
+
+        assert_eq!(frame.pixel(7, 7), Some(Color::RED));
+        assert_eq!(frame.pixel(4, 7), Some(Color::BLACK));
+        assert_eq!(frame.pixel(10, 10), Some(Color::BLACK));
+        assert_eq!(frame.pixel(16, 16), Some(Color::BLUE));
+        // Rounded corner leaves the very corner pixel uncovered
+        assert_eq!(frame.pixel(12, 12).map(|c| c.b < 255), Some(true));
+    }
+
+    #[test]
+    fn replay_scales_logical_pixels() {
+        let mut frame = RenderTarget::new(20, 20).unwrap();
+        let mut list = DrawList::new();
+        list.push_clip((0.0, 0.0, 4.0, 4.0))
+            .rect((2.0, 2.0, 5.0, 5.0), (255, 0, 0, 255))
+            .pop_clip();
+        let filled = replay(&mut frame, &list, &[], 2.0, &HashMap::new(), None).unwrap();
+
+        // Physically the rect starts at 4 and the clip ends at 8
+        assert_eq!(frame.pixel(3, 3).map(|c| c.a), Some(0));
+        assert_eq!(frame.pixel(4, 4), Some(Color::RED));
+        assert_eq!(frame.pixel(7, 7), Some(Color::RED));
+        assert_eq!(frame.pixel(8, 8).map(|c| c.a), Some(0));
+        assert_eq!(filled, 8 * 8 + 4 * 4);
+    }
+
+    #[test]
+    fn culling_fills_fewer_pixels_for_the_same_frame() {
+        let mut list = DrawList::new();
+        list.clear((0, 0, 0, 255))
+            .rounded_rect((2.0, 2.0, 10.0, 10.0), 3.0, (255, 0, 0, 255))
+            .rect((40.0, 40.0, 100.0, 100.0), (0, 255, 0, 255))
+            // Opaque panel over most of the frame, like a modal backdrop
+            .rect((0.0, 0.0, 50.0, 50.0), (0, 0, 255, 255));
+
+        let mut full = RenderTarget::new(50, 50).unwrap();
+        let filled = replay(&mut full, &list, &[], 1.0, &HashMap::new(), None).unwrap();
+        // Offscreen parts of the green rect aren't counted
+        assert_eq!(filled, 2500 + 100 + 100 + 2500);
+
+        let mut occluded = Vec::new();
+        assert_eq!(list.mark_occluded(&mut occluded), 1);
+        let mut culled = RenderTarget::new(50, 50).unwrap();
+        let culled_filled = replay(&mut culled, &list, &occluded, 1.0, &HashMap::new(), None).unwrap();
+        assert_eq!(culled_filled, filled - 100);
+        assert_eq!(culled.to_rgba8(), full.to_rgba8());
+    }
+
+    #[test]
+    fn replay_draws_images_and_text() {
+        let mut frame = RenderTarget::new(8, 8).unwrap();
+        let mut image = RenderTarget::new(2, 2).unwrap();
+        image.clear(Color::GREEN);
+        let images = HashMap::from([(ImageId(1), image)]);
+        let mut rasterizer: TextRasterizer =
+            Box::new(|_, _| vec![GlyphMask { x: 1, y: 0, width: 1, coverage: vec![255] }]);
+
+        let mut list = DrawList::new();
+        list.image(ImageId(1), (0.0, 0.0, 4.0, 4.0)).text("i", 5.0, 5.0, 10.0, (255, 255, 255, 255));
+        replay(&mut frame, &list, &[], 1.0, &images, Some(&mut rasterizer)).unwrap();
+        assert_eq!(frame.pixel(3, 3), Some(Color::GREEN));
+        assert_eq!(frame.pixel(6, 5), Some(Color::WHITE));
+
+        list.image(ImageId(9), (0.0, 0.0, 1.0, 1.0));
+        assert!(replay(&mut frame, &list, &[], 1.0, &images, None).is_err());
+    }
+}
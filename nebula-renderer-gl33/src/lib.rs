@@ -1,8 +1,27 @@
 use glow::HasContext;
+use nebula_gfx::draw_list::{ClipStack, DrawCommand, DrawList, GlyphMask, ImageId, Rgba, TextRasterizer};
+use nebula_gfx::path::{fan_triangles, stroke_triangles, FillRule, Path, PathBuilder};
+use nebula_gfx::{nine_patch_slices, NinePatchInsets, OpacityStack, RenderError, RendererCapabilities, RgbaImage, StreamingTexture};
+use std::collections::HashMap;
 use tracing::{info, warn};
 
 pub mod shader;
-use shader::{ShaderProgram, BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER};
+pub mod texture;
+pub mod render_target;
+pub mod vertex_buffer;
+pub mod shader_cache;
+pub use nebula_gfx::Renderer;
+use shader::{
+    ShaderProgram, BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER,
+    TEXTURE_VERTEX_SHADER, TEXTURE_FRAGMENT_SHADER,
+};
+use texture::{TextureFormat, TextureId, TextureManager};
+use render_target::{RenderTarget, RenderTargetId};
+use shader_cache::{ShaderCache, ShaderCacheStats, COMMON_SHADERS};
+use vertex_buffer::{vertex_bytes, BufferStats, OrphanStrategy, StreamingBuffer};
+
+/// Initial size of each streaming VBO (grows if one draw needs more)
+const VERTEX_BUFFER_SIZE: usize = 256 * 1024;
 
 /// OpenGL 3.3 renderer - Tier A (Standard)
 /// Works on 2010+ hardware - our PRIMARY renderer!
@@ -13,7 +32,58 @@ pub struct Gl33Renderer {
     clear_color: Color,
     shader_program: Option<ShaderProgram>,
     vao: Option<glow::VertexArray>,
-    vbo: Option<glow::Buffer>,
+    /// Persistent VBOs every draw appends to (see `StreamingBuffer`)
+    vbo: Option<StreamingBuffer>,
+    texture_program: Option<ShaderProgram>,
+    texture_vao: Option<glow::VertexArray>,
+    texture_vbo: Option<StreamingBuffer>,
+    orphan_strategy: OrphanStrategy,
+    /// Where program binaries are saved between runs (None = memory only)
+    shader_cache_dir: Option<std::path::PathBuf>,
+    /// Created with the GL resources (it queries the driver)
+    shader_cache: Option<ShaderCache>,
+    textures: TextureManager,
+    render_targets: HashMap<RenderTargetId, RenderTarget>,
+    next_render_target: u32,
+    /// Render target currently bound (None = window framebuffer)
+    active_render_target: Option<RenderTargetId>,
+    /// Physical pixels per logical pixel
+    scale_factor: f64,
+    opacity: OpacityStack,
+    /// Open transparency groups, innermost last
+    opacity_layers: Vec<OpacityLayer>,
+    /// Render targets from finished opacity groups, reused by size
+    layer_pool: Vec<RenderTargetId>,
+    /// Images uploaded for DrawList replay
+    images: HashMap<ImageId, TextureId>,
+    /// Attached streams and the last version copied to their texture
+    streams: Vec<(StreamingTexture, u64)>,
+    text_rasterizer: Option<TextRasterizer>,
+}
+
+/// Offscreen layer for one push_opacity group
+struct OpacityLayer {
+    /// None = opaque group, drawn straight through
+    target: Option<RenderTargetId>,
+    opacity: f32,
+    /// Target to return to when the group ends
+    previous: Option<RenderTargetId>,
+}
+
+/// Axis-aligned rectangle (x, y, width, height)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    /// Create a new rectangle
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
 }
 
 /// RGBA color (same as CPU renderer for consistency)
@@ -110,6 +180,23 @@ impl Gl33Renderer {
             shader_program: None,
             vao: None,
             vbo: None,
+            texture_program: None,
+            texture_vao: None,
+            texture_vbo: None,
+            orphan_strategy: OrphanStrategy::WhenFull,
+            shader_cache_dir: None,
+            shader_cache: None,
+            textures: TextureManager::new(),
+            render_targets: HashMap::new(),
+            next_render_target: 1,
+            active_render_target: None,
+            scale_factor: 1.0,
+            opacity: OpacityStack::new(),
+            opacity_layers: Vec::new(),
+            layer_pool: Vec::new(),
+            images: HashMap::new(),
+            streams: Vec::new(),
+            text_rasterizer: None,
         })
     }
 
@@ -118,8 +205,10 @@ impl Gl33Renderer {
     pub fn init_resources(&mut self) -> Result<(), String> {
         info!("🎨 Initializing OpenGL resources...");
         
-        // Compile shaders
-        let shader = ShaderProgram::new(&self.gl, BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER)?;
+        // Load or compile every common shader now, not when a widget first needs it
+        let mut cache = ShaderCache::new(&self.gl, self.shader_cache_dir.clone());
+        cache.warmup(&self.gl, COMMON_SHADERS)?;
+        let shader = cache.program(&self.gl, "basic", BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER)?;
         self.shader_program = Some(shader);
         
         // Create VAO and VBO for rectangle rendering
@@ -128,9 +217,8 @@ impl Gl33Renderer {
                 .map_err(|e| format!("Failed to create VAO: {}", e))?;
             self.gl.bind_vertex_array(Some(vao));
             
-            let vbo = self.gl.create_buffer()
-                .map_err(|e| format!("Failed to create VBO: {}", e))?;
-            self.gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
+            // Stays bound to ARRAY_BUFFER for the attribute setup below
+            let vbo = StreamingBuffer::new(&self.gl, VERTEX_BUFFER_SIZE, self.orphan_strategy)?;
             
             // Set up vertex attributes (position + color)
             // Position (location = 0)
@@ -158,44 +246,78 @@ impl Gl33Renderer {
             self.vao = Some(vao);
             self.vbo = Some(vbo);
         }
+
+        // Textured quads for images
+        let texture_shader = cache.program(&self.gl, "texture", TEXTURE_VERTEX_SHADER, TEXTURE_FRAGMENT_SHADER)?;
+        self.texture_program = Some(texture_shader);
+        self.shader_cache = Some(cache);
+
+        unsafe {
+            let vao = self.gl.create_vertex_array()
+                .map_err(|e| format!("Failed to create texture VAO: {}", e))?;
+            self.gl.bind_vertex_array(Some(vao));
+
+            let vbo = StreamingBuffer::new(&self.gl, VERTEX_BUFFER_SIZE, self.orphan_strategy)?;
+
+            // Position (location = 0)
+            self.gl.enable_vertex_attrib_array(0);
+            self.gl.vertex_attrib_pointer_f32(
+                0,
+                2,
+                glow::FLOAT,
+                false,
+                4 * std::mem::size_of::<f32>() as i32,
+                0,
+            );
+
+            // Texture coordinates (location = 1)
+            self.gl.enable_vertex_attrib_array(1);
+            self.gl.vertex_attrib_pointer_f32(
+                1,
+                2,
+                glow::FLOAT,
+                false,
+                4 * std::mem::size_of::<f32>() as i32,
+                2 * std::mem::size_of::<f32>() as i32,
+            );
+
+            self.texture_vao = Some(vao);
+            self.texture_vbo = Some(vbo);
+        }
         
         info!("✅ OpenGL resources initialized!");
         Ok(())
     }
 
-    /// Set the clear color
-    pub fn set_clear_color(&mut self, color: Color) {
-        self.clear_color = color;
-        info!("🎨 Clear color set to: #{:02X}{:02X}{:02X}", color.r, color.g, color.b);
+    /// Save shader binaries here so later launches skip compilation (call before `init_resources`)
+    pub fn set_shader_cache_dir(&mut self, dir: impl Into<std::path::PathBuf>) {
+        self.shader_cache_dir = Some(dir.into());
     }
 
-    /// Resize the renderer
-    pub fn resize(&mut self, width: u32, height: u32) {
-        info!("Resizing OpenGL renderer to {}x{}", width, height);
-        self.width = width;
-        self.height = height;
-        
-        // In a real implementation:
-        // unsafe { self.gl.viewport(0, 0, width as i32, height as i32); }
+    /// A program through the shader cache (custom effects, extra warmup)
+    pub fn shader(&mut self, name: &str, vertex_src: &str, fragment_src: &str) -> Result<ShaderProgram, String> {
+        let cache = self.shader_cache.as_mut().ok_or_else(|| "GL resources not initialized".to_string())?;
+        cache.program(&self.gl, name, vertex_src, fragment_src)
     }
 
-    /// Begin a new frame
-    pub fn begin_frame(&mut self) {
-        let (r, g, b, a) = self.clear_color.to_gl();
-        info!("🎬 Begin frame with color: ({:.2}, {:.2}, {:.2}, {:.2})", r, g, b, a);
-        
-        // In a real implementation:
-        // unsafe { self.gl.clear_color(r, g, b, a); }
+    /// Shader load and compile counters
+    pub fn shader_cache_stats(&self) -> ShaderCacheStats {
+        self.shader_cache.as_ref().map(ShaderCache::stats).unwrap_or_default()
     }
 
-    /// Clear the screen
-    pub fn clear(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        info!("🧹 Clearing screen (OpenGL 3.3)");
-        
-        // In a real implementation:
-        // unsafe { self.gl.clear(glow::COLOR_BUFFER_BIT); }
-        
-        Ok(())
+    /// How the streaming VBOs get fresh memory (call before `init_resources`)
+    pub fn set_orphan_strategy(&mut self, strategy: OrphanStrategy) {
+        self.orphan_strategy = strategy;
+    }
+
+    /// Vertex upload counters of both streaming VBOs
+    pub fn vertex_buffer_stats(&self) -> BufferStats {
+        [&self.vbo, &self.texture_vbo].into_iter().flatten().map(StreamingBuffer::stats).fold(BufferStats::default(), |sum, stats| BufferStats {
+            uploads: sum.uploads + stats.uploads,
+            bytes: sum.bytes + stats.bytes,
+            orphans: sum.orphans + stats.orphans,
+            reallocations: sum.reallocations + stats.reallocations,
+        })
     }
 
     /// Render a colored rectangle
@@ -221,31 +343,427 @@ impl Gl33Renderer {
         ];
         
         unsafe {
-            // Bind VAO and VBO
-            if let (Some(vao), Some(vbo)) = (self.vao, self.vbo) {
+            // Bind VAO and append to the persistent VBO
+            if let (Some(vao), Some(vbo)) = (self.vao, self.vbo.as_mut()) {
                 self.gl.bind_vertex_array(Some(vao));
-                self.gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
-                
-                // Upload vertex data
-                let vertex_data = std::slice::from_raw_parts(
-                    vertices.as_ptr() as *const u8,
-                    vertices.len() * std::mem::size_of::<f32>(),
-                );
-                self.gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, vertex_data, glow::DYNAMIC_DRAW);
+                let first = vbo.upload(&self.gl, vertex_bytes(&vertices), 6 * std::mem::size_of::<f32>());
                 
                 // Use shader and draw
                 if let Some(shader) = &self.shader_program {
                     shader.use_program(&self.gl);
-                    self.gl.draw_arrays(glow::TRIANGLES, 0, 6);
+                    self.gl.draw_arrays(glow::TRIANGLES, first, 6);
+                }
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Draw solid triangles given in pixel coordinates of the current target
+    fn draw_triangles(&mut self, points: &[(f32, f32)], color: Color) {
+        if points.is_empty() {
+            return;
+        }
+
+        let (width, height) = self.current_target_size();
+        let (r, g, b, a) = color.to_gl();
+        let vertices: Vec<f32> = points
+            .iter()
+            .flat_map(|&(x, y)| {
+                let (nx, ny) = pixel_to_ndc(x, y, width, height);
+                [nx, ny, r, g, b, a]
+            })
+            .collect();
+
+        unsafe {
+            if let (Some(vao), Some(vbo)) = (self.vao, self.vbo.as_mut()) {
+                self.gl.bind_vertex_array(Some(vao));
+                let first = vbo.upload(&self.gl, vertex_bytes(&vertices), 6 * std::mem::size_of::<f32>());
+
+                if let Some(shader) = &self.shader_program {
+                    shader.use_program(&self.gl);
+                    self.gl.draw_arrays(glow::TRIANGLES, first, points.len() as i32);
+                }
+            }
+        }
+    }
+
+    /// Create a texture from pixel data
+    /// Use `Image::get_rgba_bytes()` with `TextureFormat::Rgba8` for decoded images
+    pub fn create_texture(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        format: TextureFormat,
+        mipmaps: bool,
+    ) -> Result<TextureId, String> {
+        self.textures.create(&self.gl, width, height, data, format, mipmaps)
+    }
+
+    /// Replace the full contents of a texture
+    pub fn upload_texture(
+        &mut self,
+        id: TextureId,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        format: TextureFormat,
+    ) -> Result<(), String> {
+        self.textures.upload(&self.gl, id, width, height, data, format)
+    }
+
+    /// Update a region of a texture (src is in texels)
+    pub fn update_texture(
+        &mut self,
+        id: TextureId,
+        region: (u32, u32, u32, u32),
+        data: &[u8],
+        format: TextureFormat,
+    ) -> Result<(), String> {
+        let (x, y, width, height) = region;
+        self.textures.update(&self.gl, id, x, y, width, height, data, format)
+    }
+
+    /// Re-upload the changed rectangles of a texture (glyph atlas growth, image edits)
+    /// `source` is the whole image; only the `dirty` rects go to the GPU
+    pub fn update_texture_regions(
+        &mut self,
+        id: TextureId,
+        source: &[u8],
+        size: (u32, u32),
+        format: TextureFormat,
+        dirty: &[(u32, u32, u32, u32)],
+    ) -> Result<(), String> {
+        self.textures.update_regions(&self.gl, id, source, size.0, size.1, format, dirty)
+    }
+
+    /// Upload an image for `DrawCommand::Image` (replaces any image with the same id)
+    pub fn upload_image(&mut self, id: ImageId, image: &RgbaImage) -> Result<(), String> {
+        let texture = self.create_texture(image.width, image.height, &image.pixels, TextureFormat::Rgba8, false)?;
+        if let Some(old) = self.images.insert(id, texture) {
+            self.delete_texture(old);
+        }
+        Ok(())
+    }
+
+    /// Draw a stream's frames as its image id (camera preview, video) 🎞️
+    /// New frames are copied into one texture in place before each `render`
+    pub fn attach_stream(&mut self, stream: StreamingTexture) {
+        self.detach_stream(stream.id());
+        self.streams.push((stream, 0));
+    }
+
+    /// Stop following a stream (its last frame stays uploaded)
+    pub fn detach_stream(&mut self, id: ImageId) {
+        self.streams.retain(|(stream, _)| stream.id() != id);
+    }
+
+    /// Copy new stream frames to their textures (same size = glTexSubImage2D)
+    fn sync_streams(&mut self) -> Result<(), String> {
+        let mut streams = std::mem::take(&mut self.streams);
+        let mut result = Ok(());
+        for (stream, seen) in &mut streams {
+            let id = stream.id();
+            let synced = stream.read_newer(*seen, |width, height, pixels| match self.images.get(&id).copied() {
+                Some(texture) if self.textures.get(texture).is_some_and(|t| (t.width, t.height) == (width, height)) => {
+                    self.update_texture(texture, (0, 0, width, height), pixels, TextureFormat::Rgba8)
+                }
+                Some(texture) => self.upload_texture(texture, width, height, pixels, TextureFormat::Rgba8),
+                None => self
+                    .create_texture(width, height, pixels, TextureFormat::Rgba8, false)
+                    .map(|texture| {
+                        self.images.insert(id, texture);
+                    }),
+            });
+            if let Some((version, upload)) = synced {
+                *seen = version;
+                if upload.is_err() {
+                    result = upload;
+                }
+            }
+        }
+        self.streams = streams;
+        result
+    }
+
+    /// Rasterize `DrawCommand::Text` with this (glyph masks are uploaded per line)
+    pub fn set_text_rasterizer(&mut self, rasterizer: TextRasterizer) {
+        self.text_rasterizer = Some(rasterizer);
+    }
+
+    /// Delete a texture
+    pub fn delete_texture(&mut self, id: TextureId) {
+        self.textures.delete(&self.gl, id);
+    }
+
+    /// Get the texture manager
+    pub fn textures(&self) -> &TextureManager {
+        &self.textures
+    }
+
+    /// Draw part of a texture
+    /// src_rect is in texels, dst_rect is in normalized device coordinates (-1.0 to 1.0)
+    pub fn draw_image(&mut self, texture_id: TextureId, src_rect: Rect, dst_rect: Rect) -> Result<(), String> {
+        self.draw_image_with_opacity(texture_id, src_rect, dst_rect, 1.0)
+    }
+
+    /// Draw a region of a texture faded by `opacity` (0.0 - 1.0)
+    pub fn draw_image_with_opacity(&mut self, texture_id: TextureId, src_rect: Rect, dst_rect: Rect, opacity: f32) -> Result<(), String> {
+        let texture = self.textures.get(texture_id)
+            .ok_or_else(|| format!("Unknown texture {:?}", texture_id))?;
+
+        info!("🖼️ Drawing texture {:?} at ({}, {}) with size {}x{}",
+            texture_id, dst_rect.x, dst_rect.y, dst_rect.width, dst_rect.height);
+
+        let (u0, v0, u1, v1) = texture.uv_rect(src_rect);
+        let Rect { x, y, width, height } = dst_rect;
+
+        // Texture rows start at the top, NDC y grows upwards
+        // Format: [x, y, u, v]
+        #[rustfmt::skip]
+        let vertices: [f32; 24] = [
+            // Triangle 1
+            x,         y,          u0, v1,  // Bottom-left
+            x + width, y,          u1, v1,  // Bottom-right
+            x + width, y + height, u1, v0,  // Top-right
+
+            // Triangle 2
+            x,         y,          u0, v1,  // Bottom-left
+            x + width, y + height, u1, v0,  // Top-right
+            x,         y + height, u0, v0,  // Top-left
+        ];
+
+        unsafe {
+            if let (Some(vao), Some(vbo), Some(shader)) =
+                (self.texture_vao, self.texture_vbo.as_mut(), &self.texture_program)
+            {
+                self.gl.bind_vertex_array(Some(vao));
+                let first = vbo.upload(&self.gl, vertex_bytes(&vertices), 4 * std::mem::size_of::<f32>());
+
+                shader.use_program(&self.gl);
+                self.gl.active_texture(glow::TEXTURE0);
+                self.gl.bind_texture(glow::TEXTURE_2D, Some(texture.handle));
+                let sampler = shader.get_uniform_location(&self.gl, "uTexture");
+                self.gl.uniform_1_i32(sampler.as_ref(), 0);
+                let opacity_location = shader.get_uniform_location(&self.gl, "uOpacity");
+                self.gl.uniform_1_f32(opacity_location.as_ref(), opacity.clamp(0.0, 1.0));
+                self.gl.draw_arrays(glow::TRIANGLES, first, 6);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw a texture as a nine-patch: corners keep their size, edges and center stretch 🧩
+    /// Insets are in texture pixels, dst_rect is in normalized device coordinates
+    pub fn draw_nine_patch(&mut self, texture_id: TextureId, insets: NinePatchInsets, dst_rect: Rect) -> Result<(), String> {
+        let texture = self.textures.get(texture_id)
+            .ok_or_else(|| format!("Unknown texture {:?}", texture_id))?;
+        let (texture_width, texture_height) = (texture.width, texture.height);
+
+        // Slice in pixels so the corners stay crisp, then map each piece back to NDC
+        let (width, height) = self.current_target_size();
+        let dst = ndc_rect_to_pixels(dst_rect, width, height);
+        for slice in nine_patch_slices(texture_width, texture_height, insets, dst) {
+            let (sx, sy, sw, sh) = slice.src;
+            self.draw_image(
+                texture_id,
+                Rect::new(sx, sy, sw, sh),
+                pixel_rect_to_ndc(slice.dst, width, height),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draw one line of text through the text rasterizer as a single texture
+    fn draw_text_line(&mut self, text: &str, position: (f32, f32), size: f32, color: Color) -> Result<(), String> {
+        let Some(rasterize) = self.text_rasterizer.as_mut() else {
+            return Ok(());
+        };
+        let glyphs = rasterize(text, size);
+        let Some((x, y, width, height, pixels)) = text_line_rgba(&glyphs, color) else {
+            return Ok(());
+        };
+
+        let texture = self.create_texture(width, height, &pixels, TextureFormat::Rgba8, false)?;
+        let (target_width, target_height) = self.current_target_size();
+        let dst = (position.0.round() + x as f32, position.1.round() + y as f32, width as f32, height as f32);
+        let result = self.draw_image(
+            texture,
+            Rect::new(0.0, 0.0, width as f32, height as f32),
+            pixel_rect_to_ndc(dst, target_width, target_height),
+        );
+        self.delete_texture(texture);
+        result
+    }
+
+    /// Restrict drawing to a clip rect in pixels (None = whole target)
+    fn set_scissor(&self, clip: Option<(f32, f32, f32, f32)>) {
+        let (_, target_height) = self.current_target_size();
+        unsafe {
+            match clip {
+                Some(clip) => {
+                    let (x, y, width, height) = scissor_box(clip, target_height);
+                    self.gl.enable(glow::SCISSOR_TEST);
+                    self.gl.scissor(x, y, width, height);
+                }
+                None => self.gl.disable(glow::SCISSOR_TEST),
+            }
+        }
+    }
+
+    /// Size of the framebuffer being drawn into
+    fn current_target_size(&self) -> (u32, u32) {
+        match self.active_render_target.and_then(|id| self.render_targets.get(&id)) {
+            Some(target) => (target.width, target.height),
+            None => (self.width, self.height),
+        }
+    }
+
+    /// Create an offscreen render target (size in physical pixels)
+    pub fn create_render_target(&mut self, width: u32, height: u32) -> Result<RenderTargetId, String> {
+        let target = RenderTarget::new(&self.gl, &mut self.textures, width, height)?;
+        let id = RenderTargetId(self.next_render_target);
+        self.next_render_target += 1;
+        self.render_targets.insert(id, target);
+        Ok(id)
+    }
+
+    /// Redirect drawing into a render target (None = back to the window)
+    pub fn set_render_target(&mut self, target: Option<RenderTargetId>) -> Result<(), String> {
+        let (framebuffer, width, height) = match target {
+            Some(id) => {
+                let target = self.render_targets.get(&id)
+                    .ok_or_else(|| format!("Unknown render target {:?}", id))?;
+                (Some(target.framebuffer), target.width, target.height)
+            }
+            None => (None, self.width, self.height),
+        };
+
+        unsafe {
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, framebuffer);
+            self.gl.viewport(0, 0, width as i32, height as i32);
+        }
+
+        self.active_render_target = target;
+        Ok(())
+    }
+
+    /// Render target currently being drawn into
+    pub fn active_render_target(&self) -> Option<RenderTargetId> {
+        self.active_render_target
+    }
+
+    /// Get a render target
+    pub fn render_target(&self, id: RenderTargetId) -> Option<&RenderTarget> {
+        self.render_targets.get(&id)
+    }
+
+    /// Draw the contents of a render target
+    /// dst_rect is in normalized device coordinates (-1.0 to 1.0)
+    pub fn draw_render_target(&mut self, id: RenderTargetId, dst_rect: Rect) -> Result<(), String> {
+        let target = self.render_targets.get(&id)
+            .ok_or_else(|| format!("Unknown render target {:?}", id))?;
+        if self.active_render_target == Some(id) {
+            return Err(format!("Cannot draw render target {:?} into itself", id));
+        }
+        let (texture, src_rect) = (target.texture, target.source_rect());
+        self.draw_image(texture, src_rect, dst_rect)
+    }
+
+    /// Delete a render target and its texture
+    pub fn delete_render_target(&mut self, id: RenderTargetId) {
+        match self.render_targets.remove(&id) {
+            Some(target) => {
+                if self.active_render_target == Some(id) {
+                    warn!("Deleting the active render target {:?}, switching back to the window", id);
+                    let _ = self.set_render_target(None);
                 }
+                target.delete(&self.gl, &mut self.textures);
             }
+            None => warn!("Tried to delete unknown render target {:?}", id),
+        }
+    }
+
+    /// Take a pooled layer the size of the current target (or make one), bind and clear it
+    fn bind_opacity_layer(&mut self) -> Result<RenderTargetId, String> {
+        let (width, height) = self.current_target_size();
+        let id = match self.layer_pool.iter().position(|id| {
+            self.render_targets.get(id).is_some_and(|t| (t.width, t.height) == (width, height))
+        }) {
+            Some(index) => self.layer_pool.swap_remove(index),
+            None => self.create_render_target(width, height)?,
+        };
+        self.set_render_target(Some(id))?;
+        unsafe {
+            self.gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            self.gl.clear(glow::COLOR_BUFFER_BIT);
+        }
+        Ok(id)
+    }
+
+    /// Effective opacity of the current group
+    pub fn current_opacity(&self) -> f32 {
+        self.opacity.current()
+    }
+
+    /// Set the scale factor (physical pixels per logical pixel)
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        info!("OpenGL renderer scale factor set to {}", scale_factor);
+        self.scale_factor = scale_factor;
+    }
+
+    /// Get OpenGL context for advanced usage
+    pub fn gl_context(&self) -> &glow::Context {
+        &self.gl
+    }
+}
+
+impl Renderer for Gl33Renderer {
+    type Color = Color;
+    type Error = RenderError;
+
+    fn set_clear_color(&mut self, color: Color) {
+        self.clear_color = color;
+        info!("🎨 Clear color set to: #{:02X}{:02X}{:02X}", color.r, color.g, color.b);
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        info!("Resizing OpenGL renderer to {}x{}", width, height);
+        self.width = width;
+        self.height = height;
+
+        // Pooled opacity layers are window-sized, so they're stale now
+        for id in std::mem::take(&mut self.layer_pool) {
+            self.delete_render_target(id);
+        }
+        
+        // In a real implementation:
+        // unsafe { self.gl.viewport(0, 0, width as i32, height as i32); }
+    }
+
+    fn begin_frame(&mut self) {
+        for vbo in [&mut self.vbo, &mut self.texture_vbo].into_iter().flatten() {
+            vbo.begin_frame(&self.gl);
         }
+        let (r, g, b, a) = self.clear_color.to_gl();
+        info!("🎬 Begin frame with color: ({:.2}, {:.2}, {:.2}, {:.2})", r, g, b, a);
+        
+        // In a real implementation:
+        // unsafe { self.gl.clear_color(r, g, b, a); }
+    }
+
+    fn clear(&mut self) -> Result<(), RenderError> {
+        info!("🧹 Clearing screen (OpenGL 3.3)");
+        
+        // In a real implementation:
+        // unsafe { self.gl.clear(glow::COLOR_BUFFER_BIT); }
         
         Ok(())
     }
 
-    /// End the current frame and present
-    pub fn end_frame(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn end_frame(&mut self) -> Result<(), RenderError> {
         info!("🎬 End frame (OpenGL 3.3)");
         
         // In a real implementation, we'd swap buffers here
@@ -253,17 +771,307 @@ impl Gl33Renderer {
         Ok(())
     }
 
-    /// Get current dimensions
-    pub fn dimensions(&self) -> (u32, u32) {
+    fn dimensions(&self) -> (u32, u32) {
         (self.width, self.height)
     }
 
-    /// Get OpenGL context for advanced usage
-    pub fn gl_context(&self) -> &glow::Context {
-        &self.gl
+    /// Read back the current framebuffer (the window, or the active render target)
+    /// Call before end_frame - the back buffer is undefined after swapping
+    fn capture_frame(&mut self) -> Result<RgbaImage, RenderError> {
+        let (width, height) = self.current_target_size();
+
+        let mut image = RgbaImage::new(width, height);
+        unsafe {
+            self.gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+            self.gl.read_pixels(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut image.pixels),
+            );
+        }
+
+        // glReadPixels starts at the bottom row
+        image.flip_vertical();
+        info!("📸 Captured {}x{} frame (OpenGL 3.3)", width, height);
+        Ok(image)
+    }
+
+    /// Fill and/or stroke a vector path ✏️
+    /// Path coordinates are in pixels (origin top-left) of the current target.
+    /// Fills use stencil-then-cover, so concave and self-intersecting paths
+    /// follow the path's fill rule. Requires a stencil buffer.
+    fn draw_path(&mut self, path: &Path, fill: Option<Color>, stroke: Option<(Color, f32)>) -> Result<(), RenderError> {
+        let polylines = path.flatten(PATH_TOLERANCE);
+        if polylines.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(color) = fill {
+            let fan: Vec<(f32, f32)> = polylines.iter().flat_map(fan_triangles).collect();
+            if let Some((x, y, width, height)) = path.bounds() {
+                let cover = [
+                    (x, y), (x + width, y), (x + width, y + height),
+                    (x, y), (x + width, y + height), (x, y + height),
+                ];
+
+                unsafe {
+                    self.gl.enable(glow::STENCIL_TEST);
+                    self.gl.clear_stencil(0);
+                    self.gl.clear(glow::STENCIL_BUFFER_BIT);
+
+                    // Pass 1: count windings into the stencil, no color writes
+                    self.gl.color_mask(false, false, false, false);
+                    self.gl.stencil_func(glow::ALWAYS, 0, 0xFF);
+                    match path.fill_rule {
+                        FillRule::NonZero => {
+                            self.gl.stencil_op_separate(glow::FRONT, glow::KEEP, glow::KEEP, glow::INCR_WRAP);
+                            self.gl.stencil_op_separate(glow::BACK, glow::KEEP, glow::KEEP, glow::DECR_WRAP);
+                        }
+                        FillRule::EvenOdd => {
+                            self.gl.stencil_op(glow::KEEP, glow::KEEP, glow::INVERT);
+                        }
+                    }
+                }
+                self.draw_triangles(&fan, color);
+
+                unsafe {
+                    // Pass 2: cover the bounds where the stencil says "inside"
+                    self.gl.color_mask(true, true, true, true);
+                    match path.fill_rule {
+                        FillRule::NonZero => self.gl.stencil_func(glow::NOTEQUAL, 0, 0xFF),
+                        FillRule::EvenOdd => self.gl.stencil_func(glow::NOTEQUAL, 0, 0x01),
+                    }
+                    self.gl.stencil_op(glow::ZERO, glow::ZERO, glow::ZERO);
+                }
+                self.draw_triangles(&cover, color);
+
+                unsafe {
+                    self.gl.disable(glow::STENCIL_TEST);
+                }
+            }
+        }
+
+        if let Some((color, width)) = stroke {
+            let triangles: Vec<(f32, f32)> = polylines
+                .iter()
+                .flat_map(|polyline| stroke_triangles(polyline, width))
+                .collect();
+            self.draw_triangles(&triangles, color);
+        }
+
+        Ok(())
+    }
+
+    /// Start a transparency group 👻
+    /// Draws go into a pooled offscreen layer until `pop_opacity` fades it in as one
+    fn push_opacity(&mut self, opacity: f32) {
+        let effective = self.opacity.push(opacity);
+        let level = self.opacity.top();
+        let previous = self.active_render_target;
+
+        // Fully opaque groups draw straight through, and so does a group
+        // whose layer can't be bound (better unfaded than an unbalanced stack)
+        let target = if level < 1.0 {
+            self.bind_opacity_layer()
+                .map_err(|e| warn!("Failed to bind opacity layer: {}", e))
+                .ok()
+        } else {
+            None
+        };
+
+        info!("👻 Opacity layer {} pushed (effective {:.2})", self.opacity.depth(), effective);
+        self.opacity_layers.push(OpacityLayer { target, opacity: level, previous });
+    }
+
+    /// End the innermost transparency group and composite it
+    fn pop_opacity(&mut self) -> Result<(), RenderError> {
+        let layer = self.opacity_layers.pop()
+            .ok_or("pop_opacity called without push_opacity")?;
+        self.opacity.pop();
+
+        if let Some(id) = layer.target {
+            Gl33Renderer::set_render_target(self, layer.previous)?;
+            let target = self.render_targets.get(&id)
+                .ok_or_else(|| format!("Opacity layer {:?} was deleted", id))?;
+            let (texture, src_rect) = (target.texture, target.source_rect());
+            self.draw_image_with_opacity(texture, src_rect, Rect::new(-1.0, -1.0, 2.0, 2.0), layer.opacity)?;
+            self.layer_pool.push(id);
+        }
+        Ok(())
+    }
+
+    /// Replay a DrawList into the current target 📝
+    /// The list is in logical pixels and drawn at the scale factor;
+    /// clips become scissor rects, rounded rects go through the path fill
+    fn render(&mut self, list: &DrawList) -> Result<(), RenderError> {
+        self.sync_streams()?;
+        let (width, height) = self.current_target_size();
+        let scale = self.scale_factor as f32;
+        let mut clips = ClipStack::new();
+
+        for command in list.commands() {
+            match command {
+                DrawCommand::Clear(color) => {
+                    let (r, g, b, a) = to_color(*color).to_gl();
+                    unsafe {
+                        self.gl.clear_color(r, g, b, a);
+                        self.gl.clear(glow::COLOR_BUFFER_BIT);
+                    }
+                }
+                DrawCommand::Rect { rect, color } => {
+                    let (x, y, w, h) = scale_rect(*rect, scale);
+                    let quad = [(x, y), (x + w, y), (x + w, y + h), (x, y), (x + w, y + h), (x, y + h)];
+                    self.draw_triangles(&quad, to_color(*color));
+                }
+                DrawCommand::RoundedRect { rect, radius, color } => {
+                    let (x, y, w, h) = scale_rect(*rect, scale);
+                    let path = PathBuilder::new().rounded_rect(x, y, w, h, radius * scale).build();
+                    self.draw_path(&path, Some(to_color(*color)), None)?;
+                }
+                DrawCommand::Text { text, x, y, size, color } => {
+                    self.draw_text_line(text, (x * scale, y * scale), size * scale, to_color(*color))?;
+                }
+                DrawCommand::Image { image, src, dst, opacity } => {
+                    let texture = *self.images.get(image)
+                        .ok_or_else(|| format!("Image {:?} was not uploaded", image))?;
+                    let (image_width, image_height) = self.textures.get(texture)
+                        .map_or((0, 0), |t| (t.width, t.height));
+                    let (sx, sy, sw, sh) = src.unwrap_or((0.0, 0.0, image_width as f32, image_height as f32));
+                    let dst = pixel_rect_to_ndc(scale_rect(*dst, scale), width, height);
+                    self.draw_image_with_opacity(texture, Rect::new(sx, sy, sw, sh), dst, *opacity)?;
+                }
+                DrawCommand::PushClip(rect) => {
+                    let clip = clips.push(scale_rect(*rect, scale));
+                    self.set_scissor(Some(clip));
+                }
+                DrawCommand::PopClip => {
+                    let clip = clips.pop();
+                    self.set_scissor(clip);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    fn supports_render_targets(&self) -> bool {
+        true
+    }
+
+    fn create_render_target(&mut self, width: u32, height: u32) -> Option<RenderTargetId> {
+        Gl33Renderer::create_render_target(self, width, height)
+            .map_err(|e| warn!("Failed to create render target: {}", e))
+            .ok()
+    }
+
+    fn set_render_target(&mut self, target: Option<RenderTargetId>) -> bool {
+        Gl33Renderer::set_render_target(self, target)
+            .map_err(|e| warn!("Failed to bind render target: {}", e))
+            .is_ok()
+    }
+
+    /// Draw a render target at (x, y) in pixels of the current target, origin top-left
+    fn draw_render_target(&mut self, id: RenderTargetId, x: f32, y: f32) -> Result<(), RenderError> {
+        let target = self.render_targets.get(&id)
+            .ok_or_else(|| format!("Unknown render target {:?}", id))?;
+        let source = (x, y, target.width as f32, target.height as f32);
+        let (width, height) = self.current_target_size();
+        Ok(Gl33Renderer::draw_render_target(self, id, pixel_rect_to_ndc(source, width, height))?)
+    }
+
+    fn delete_render_target(&mut self, id: RenderTargetId) {
+        Gl33Renderer::delete_render_target(self, id);
+    }
+
+    /// Effects this renderer can draw (all of them)
+    fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities::FULL
+    }
+
+    fn name(&self) -> &'static str {
+        "OpenGL 3.3"
     }
 }
 
+/// Convert an NDC rect (y = bottom edge) to pixels (x, y, width, height), origin top-left
+pub fn ndc_rect_to_pixels(rect: Rect, width: u32, height: u32) -> (f32, f32, f32, f32) {
+    let (width, height) = (width.max(1) as f32, height.max(1) as f32);
+    (
+        (rect.x + 1.0) / 2.0 * width,
+        (1.0 - (rect.y + rect.height)) / 2.0 * height,
+        rect.width / 2.0 * width,
+        rect.height / 2.0 * height,
+    )
+}
+
+/// Convert a pixel rect (origin top-left) to an NDC rect
+pub fn pixel_rect_to_ndc(rect: (f32, f32, f32, f32), width: u32, height: u32) -> Rect {
+    let (x, y, w, h) = rect;
+    let (left, bottom) = pixel_to_ndc(x, y + h, width, height);
+    Rect::new(left, bottom, w / width.max(1) as f32 * 2.0, h / height.max(1) as f32 * 2.0)
+}
+
+/// Convert a logical rect to physical pixels
+pub fn scale_rect(rect: (f32, f32, f32, f32), scale: f32) -> (f32, f32, f32, f32) {
+    (rect.0 * scale, rect.1 * scale, rect.2 * scale, rect.3 * scale)
+}
+
+/// GL scissor box (x, y from the bottom, width, height) covering a pixel clip rect
+pub fn scissor_box(clip: (f32, f32, f32, f32), target_height: u32) -> (i32, i32, i32, i32) {
+    let (x, y, width, height) = clip;
+    let (left, top) = (x.floor(), y.floor());
+    let (right, bottom) = ((x + width).ceil(), (y + height).ceil());
+    (left as i32, target_height as i32 - bottom as i32, (right - left).max(0.0) as i32, (bottom - top).max(0.0) as i32)
+}
+
+/// Merge a line's glyph masks into one tinted RGBA image
+/// Returns its offset from the line box, size, and pixels (None if nothing is drawn)
+pub fn text_line_rgba(glyphs: &[GlyphMask], color: Color) -> Option<(i32, i32, u32, u32, Vec<u8>)> {
+    let rows = |glyph: &GlyphMask| glyph.coverage.len().checked_div(glyph.width).unwrap_or(0) as i32;
+    let drawn = || glyphs.iter().filter(|glyph| glyph.width > 0 && rows(glyph) > 0);
+    let left = drawn().map(|glyph| glyph.x).min()?;
+    let top = drawn().map(|glyph| glyph.y).min()?;
+    let right = drawn().map(|glyph| glyph.x + glyph.width as i32).max()?;
+    let bottom = drawn().map(|glyph| glyph.y + rows(glyph)).max()?;
+    let (width, height) = ((right - left) as u32, (bottom - top) as u32);
+
+    let mut pixels = vec![0u8; width as usize * height as usize * 4];
+    for glyph in drawn() {
+        for (row, coverage_row) in glyph.coverage.chunks(glyph.width).enumerate() {
+            for (col, &coverage) in coverage_row.iter().enumerate() {
+                let px = (glyph.x - left) as usize + col;
+                let py = (glyph.y - top) as usize + row;
+                let index = (py * width as usize + px) * 4;
+                let alpha = ((coverage as u32 * color.a as u32 / 255) as u8).max(pixels[index + 3]);
+                pixels[index..index + 4].copy_from_slice(&[color.r, color.g, color.b, alpha]);
+            }
+        }
+    }
+    Some((left, top, width, height, pixels))
+}
+
+fn to_color(color: Rgba) -> Color {
+    Color::rgba(color.0, color.1, color.2, color.3)
+}
+
+/// Maximum distance (in pixels) between flattened curves and the true curve
+const PATH_TOLERANCE: f32 = 0.25;
+
+/// Convert a pixel position (origin top-left) to normalized device coordinates
+pub fn pixel_to_ndc(x: f32, y: f32, width: u32, height: u32) -> (f32, f32) {
+    (
+        x / width.max(1) as f32 * 2.0 - 1.0,
+        1.0 - y / height.max(1) as f32 * 2.0,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,6 +1132,14 @@ mod tests {
         assert_eq!(color.a, 255);
     }
 
+    #[test]
+    fn rect_creation() {
+        let rect = Rect::new(-1.0, -1.0, 2.0, 0.5);
+        assert_eq!(rect.x, -1.0);
+        assert_eq!(rect.width, 2.0);
+        assert_eq!(rect.height, 0.5);
+    }
+
     #[test]
     fn renderer_dimensions_tracking() {
         // Test that dimensions are tracked correctly without needing OpenGL context
@@ -336,6 +1152,50 @@ mod tests {
         assert_eq!((new_width, new_height), (1024, 768));
     }
 
+    #[test]
+    fn scissor_box_flips_to_bottom_origin() {
+        assert_eq!(scissor_box((10.0, 20.0, 30.0, 40.0), 100), (10, 40, 30, 40));
+        assert_eq!(scissor_box((0.5, 0.5, 1.0, 1.0), 10), (0, 8, 2, 2));
+    }
+
+    #[test]
+    fn logical_clip_scales_to_physical_scissor() {
+        let clip = scale_rect((10.0, 20.0, 30.0, 40.0), 2.0);
+        assert_eq!(clip, (20.0, 40.0, 60.0, 80.0));
+        assert_eq!(scissor_box(clip, 200), (20, 80, 60, 80));
+    }
+
+    #[test]
+    fn text_line_merges_glyph_masks() {
+        let glyphs = [
+            GlyphMask { x: 0, y: 2, width: 1, coverage: vec![255] },
+            GlyphMask { x: 3, y: 0, width: 2, coverage: vec![0, 128, 0, 255] },
+            GlyphMask { x: 9, y: 9, width: 0, coverage: vec![] },
+        ];
+        let (x, y, width, height, pixels) = text_line_rgba(&glyphs, Color::WHITE).unwrap();
+        assert_eq!((x, y, width, height), (0, 0, 5, 3));
+        assert_eq!(&pixels[(2 * 5) * 4..(2 * 5) * 4 + 4], &[255, 255, 255, 255]);
+        assert_eq!(pixels[4 * 4 + 3], 128);
+        assert!(text_line_rgba(&[], Color::WHITE).is_none());
+    }
+
+    #[test]
+    fn pixel_to_ndc_flips_y() {
+        assert_eq!(pixel_to_ndc(0.0, 0.0, 800, 600), (-1.0, 1.0));
+        assert_eq!(pixel_to_ndc(400.0, 300.0, 800, 600), (0.0, 0.0));
+        assert_eq!(pixel_to_ndc(800.0, 600.0, 800, 600), (1.0, -1.0));
+    }
+
+    #[test]
+    fn ndc_and_pixel_rects_round_trip() {
+        let full = ndc_rect_to_pixels(Rect::new(-1.0, -1.0, 2.0, 2.0), 800, 600);
+        assert_eq!(full, (0.0, 0.0, 800.0, 600.0));
+
+        let top_left = pixel_rect_to_ndc((0.0, 0.0, 400.0, 300.0), 800, 600);
+        assert_eq!(top_left, Rect::new(-1.0, 0.0, 1.0, 1.0));
+        assert_eq!(ndc_rect_to_pixels(top_left, 800, 600), (0.0, 0.0, 400.0, 300.0));
+    }
+
     #[test]
     fn renderer_color_tracking() {
         // Test that colors are tracked correctly
@@ -0,0 +1,98 @@
+use glow::HasContext;
+use tracing::info;
+
+use crate::texture::{TextureFormat, TextureId, TextureManager};
+use crate::Rect;
+
+/// Handle to an offscreen render target (shared with the `Renderer` trait)
+pub use nebula_gfx::RenderTargetId;
+
+/// Offscreen render target - a framebuffer with a color texture 🎯
+///
+/// Draw into it, then draw its texture like any other image.
+/// Used for caching expensive subtrees, blur passes, and exports.
+#[derive(Debug)]
+pub struct RenderTarget {
+    pub framebuffer: glow::Framebuffer,
+    pub texture: TextureId,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl RenderTarget {
+    /// Create a framebuffer backed by a new RGBA8 texture
+    pub fn new(
+        gl: &glow::Context,
+        textures: &mut TextureManager,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, String> {
+        if width == 0 || height == 0 {
+            return Err(format!("Render target size must be non-zero, got {}x{}", width, height));
+        }
+
+        let blank = vec![0u8; width as usize * height as usize * 4];
+        let texture = textures.create(gl, width, height, &blank, TextureFormat::Rgba8, false)?;
+        let handle = textures.get(texture)
+            .ok_or_else(|| format!("Render target texture {:?} missing", texture))?
+            .handle;
+
+        let framebuffer = unsafe {
+            let framebuffer = gl.create_framebuffer()
+                .map_err(|e| format!("Failed to create framebuffer: {}", e))?;
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(handle),
+                0,
+            );
+
+            let status = gl.check_framebuffer_status(glow::FRAMEBUFFER);
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+            if status != glow::FRAMEBUFFER_COMPLETE {
+                gl.delete_framebuffer(framebuffer);
+                textures.delete(gl, texture);
+                return Err(format!("Framebuffer incomplete (status 0x{:X})", status));
+            }
+            framebuffer
+        };
+
+        info!("🎯 Created {}x{} render target", width, height);
+
+        Ok(Self { framebuffer, texture, width, height })
+    }
+
+    /// Source rectangle covering the whole target, flipped so it draws upright
+    /// (framebuffer rows start at the bottom, uploaded textures at the top)
+    pub fn source_rect(&self) -> Rect {
+        flipped_source_rect(self.width, self.height)
+    }
+
+    /// Free the framebuffer and its texture
+    pub fn delete(self, gl: &glow::Context, textures: &mut TextureManager) {
+        unsafe {
+            gl.delete_framebuffer(self.framebuffer);
+        }
+        textures.delete(gl, self.texture);
+    }
+}
+
+/// Full-size source rectangle with a negative height, which flips the V axis
+pub fn flipped_source_rect(width: u32, height: u32) -> Rect {
+    Rect::new(0.0, height as f32, width as f32, -(height as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::texture::uv_rect;
+
+    #[test]
+    fn flipped_source_rect_inverts_v() {
+        let rect = flipped_source_rect(128, 64);
+        assert_eq!(uv_rect(rect, 128, 64), (0.0, 1.0, 1.0, 0.0));
+    }
+}
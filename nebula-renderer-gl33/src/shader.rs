@@ -2,6 +2,7 @@ use glow::HasContext;
 use tracing::info;
 
 /// Shader program wrapper
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ShaderProgram {
     pub program: glow::Program,
 }
@@ -12,6 +13,16 @@ impl ShaderProgram {
         gl: &glow::Context,
         vertex_src: &str,
         fragment_src: &str,
+    ) -> Result<Self, String> {
+        Self::compile(gl, vertex_src, fragment_src, false)
+    }
+
+    /// Compile and link; `retrievable` asks the driver to keep the binary for `binary()`
+    pub fn compile(
+        gl: &glow::Context,
+        vertex_src: &str,
+        fragment_src: &str,
+        retrievable: bool,
     ) -> Result<Self, String> {
         unsafe {
             // Compile vertex shader
@@ -47,6 +58,9 @@ impl ShaderProgram {
             
             gl.attach_shader(program, vertex_shader);
             gl.attach_shader(program, fragment_shader);
+            if retrievable {
+                gl.program_binary_retrievable_hint(program, true);
+            }
             gl.link_program(program);
             
             if !gl.get_program_link_status(program) {
@@ -67,6 +81,26 @@ impl ShaderProgram {
         }
     }
     
+    /// Load a program from a driver binary (see `binary`)
+    /// Fails if the driver changed since the binary was saved
+    pub fn from_binary(gl: &glow::Context, binary: &glow::ProgramBinary) -> Result<Self, String> {
+        unsafe {
+            let program = gl.create_program()
+                .map_err(|e| format!("Failed to create program: {}", e))?;
+            gl.program_binary(program, binary);
+            if !gl.get_program_link_status(program) {
+                gl.delete_program(program);
+                return Err("Program binary was rejected by the driver".to_string());
+            }
+            Ok(Self { program })
+        }
+    }
+
+    /// The linked program as a driver binary (None if the driver won't give it out)
+    pub fn binary(&self, gl: &glow::Context) -> Option<glow::ProgramBinary> {
+        unsafe { gl.get_program_binary(self.program) }.filter(|binary| !binary.buffer.is_empty())
+    }
+
     /// Use this shader program
     pub fn use_program(&self, gl: &glow::Context) {
         unsafe {
@@ -111,6 +145,32 @@ void main() {
 }
 "#;
 
+/// Textured quad shader for images (OpenGL 3.3)
+pub const TEXTURE_VERTEX_SHADER: &str = r#"#version 330 core
+layout (location = 0) in vec2 aPos;
+layout (location = 1) in vec2 aTexCoord;
+
+out vec2 vTexCoord;
+
+void main() {
+    gl_Position = vec4(aPos, 0.0, 1.0);
+    vTexCoord = aTexCoord;
+}
+"#;
+
+pub const TEXTURE_FRAGMENT_SHADER: &str = r#"#version 330 core
+in vec2 vTexCoord;
+out vec4 FragColor;
+
+uniform sampler2D uTexture;
+uniform float uOpacity;
+
+void main() {
+    vec4 color = texture(uTexture, vTexCoord);
+    FragColor = vec4(color.rgb, color.a * uOpacity);
+}
+"#;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +184,14 @@ mod tests {
         assert!(BASIC_FRAGMENT_SHADER.contains("#version 330"));
         assert!(BASIC_FRAGMENT_SHADER.contains("FragColor"));
     }
+
+    #[test]
+    fn texture_shader_sources_are_valid() {
+        assert!(TEXTURE_VERTEX_SHADER.contains("#version 330"));
+        assert!(TEXTURE_VERTEX_SHADER.contains("aTexCoord"));
+
+        assert!(TEXTURE_FRAGMENT_SHADER.contains("sampler2D uTexture"));
+        assert!(TEXTURE_FRAGMENT_SHADER.contains("uniform float uOpacity"));
+        assert!(TEXTURE_FRAGMENT_SHADER.contains("FragColor"));
+    }
 }
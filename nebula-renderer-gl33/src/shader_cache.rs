@@ -0,0 +1,185 @@
+use crate::shader::{
+    ShaderProgram, BASIC_FRAGMENT_SHADER, BASIC_VERTEX_SHADER, TEXTURE_FRAGMENT_SHADER, TEXTURE_VERTEX_SHADER,
+};
+use glow::HasContext;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Shaders every app ends up using (rects, text and images)
+/// `ShaderCache::warmup` compiles these before the first frame
+pub const COMMON_SHADERS: &[(&str, &str, &str)] = &[
+    ("basic", BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER),
+    ("texture", TEXTURE_VERTEX_SHADER, TEXTURE_FRAGMENT_SHADER),
+];
+
+/// Header of a cached binary file
+const MAGIC: &[u8; 4] = b"NBSC";
+
+/// Where programs came from, for profiling startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShaderCacheStats {
+    /// Loaded from a saved driver binary
+    pub disk_hits: u32,
+    /// Compiled from source
+    pub compiled: u32,
+    /// Saved binaries the driver refused (driver update, different GPU)
+    pub rejected: u32,
+}
+
+/// ShaderCache - Compile each shader once, ever 💾
+///
+/// Programs are kept by name for the session, and their driver binaries
+/// (glProgramBinary) are saved to disk so the next launch skips
+/// compilation entirely. Binaries are keyed by the driver's vendor,
+/// renderer and version strings plus the sources, so a driver update or a
+/// shader change simply misses and recompiles.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut cache = ShaderCache::new(&gl, Some(cache_dir.join("shaders")));
+/// cache.warmup(&gl, COMMON_SHADERS)?;
+/// let basic = cache.program(&gl, "basic", BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER)?;
+/// ```
+pub struct ShaderCache {
+    dir: Option<PathBuf>,
+    driver: String,
+    binaries_supported: bool,
+    programs: HashMap<String, ShaderProgram>,
+    stats: ShaderCacheStats,
+}
+
+impl ShaderCache {
+    /// Cache for the current context; `dir` None keeps programs in memory only
+    pub fn new(gl: &glow::Context, dir: Option<PathBuf>) -> Self {
+        let (driver, binaries_supported) = unsafe {
+            let driver = [glow::VENDOR, glow::RENDERER, glow::VERSION]
+                .map(|name| gl.get_parameter_string(name))
+                .join(" | ");
+            (driver, gl.get_parameter_i32(glow::NUM_PROGRAM_BINARY_FORMATS) > 0)
+        };
+        if dir.is_some() && !binaries_supported {
+            info!("💾 Driver has no program binary formats, shader cache is memory-only");
+        }
+        Self { dir, driver, binaries_supported, programs: HashMap::new(), stats: ShaderCacheStats::default() }
+    }
+
+    /// Program by name, loading or compiling it on first use
+    pub fn program(&mut self, gl: &glow::Context, name: &str, vertex_src: &str, fragment_src: &str) -> Result<ShaderProgram, String> {
+        if let Some(program) = self.programs.get(name) {
+            return Ok(*program);
+        }
+        let path = self.binary_path(vertex_src, fragment_src);
+        let program = match path.as_deref().and_then(|path| self.load(gl, path)) {
+            Some(program) => program,
+            None => {
+                let program = ShaderProgram::compile(gl, vertex_src, fragment_src, path.is_some())?;
+                self.stats.compiled += 1;
+                if let (Some(path), Some(binary)) = (&path, program.binary(gl)) {
+                    if let Err(e) = save_binary(path, &binary) {
+                        warn!("💾 Couldn't save shader binary: {}", e);
+                    }
+                }
+                program
+            }
+        };
+        self.programs.insert(name.to_string(), program);
+        Ok(program)
+    }
+
+    /// Compile (or load) every shader up front so no widget hitches on first use
+    /// Returns how many programs are ready
+    pub fn warmup(&mut self, gl: &glow::Context, shaders: &[(&str, &str, &str)]) -> Result<usize, String> {
+        for (name, vertex_src, fragment_src) in shaders {
+            self.program(gl, name, vertex_src, fragment_src)?;
+        }
+        info!("💾 Shader warmup: {} programs ({:?})", self.programs.len(), self.stats);
+        Ok(self.programs.len())
+    }
+
+    /// Load and compile counters
+    pub fn stats(&self) -> ShaderCacheStats {
+        self.stats
+    }
+
+    /// Free every program
+    pub fn clear(&mut self, gl: &glow::Context) {
+        for (_, program) in self.programs.drain() {
+            unsafe { gl.delete_program(program.program) };
+        }
+    }
+
+    fn binary_path(&self, vertex_src: &str, fragment_src: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref().filter(|_| self.binaries_supported)?;
+        Some(dir.join(format!("{:016x}.bin", cache_key(&self.driver, vertex_src, fragment_src))))
+    }
+
+    fn load(&mut self, gl: &glow::Context, path: &Path) -> Option<ShaderProgram> {
+        let binary = decode_binary(&std::fs::read(path).ok()?)?;
+        match ShaderProgram::from_binary(gl, &binary) {
+            Ok(program) => {
+                self.stats.disk_hits += 1;
+                Some(program)
+            }
+            Err(e) => {
+                warn!("💾 {} ({}), recompiling", e, path.display());
+                self.stats.rejected += 1;
+                None
+            }
+        }
+    }
+}
+
+/// FNV-1a of driver and sources (stable across runs)
+pub fn cache_key(driver: &str, vertex_src: &str, fragment_src: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in [driver, vertex_src, fragment_src] {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// File layout: magic, binary format (u32 LE), driver bytes
+pub fn encode_binary(binary: &glow::ProgramBinary) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + binary.buffer.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&binary.format.to_le_bytes());
+    bytes.extend_from_slice(&binary.buffer);
+    bytes
+}
+
+/// Parse a file written by `encode_binary` (None if it isn't one)
+pub fn decode_binary(bytes: &[u8]) -> Option<glow::ProgramBinary> {
+    let rest = bytes.strip_prefix(MAGIC)?;
+    let (format, buffer) = rest.split_first_chunk::<4>()?;
+    (!buffer.is_empty()).then(|| glow::ProgramBinary { buffer: buffer.to_vec(), format: u32::from_le_bytes(*format) })
+}
+
+fn save_binary(path: &Path, binary: &glow::ProgramBinary) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, encode_binary(binary)).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binaries_round_trip_and_keys_track_driver() {
+        let binary = glow::ProgramBinary { buffer: vec![1, 2, 3, 4, 5], format: 0x8741 };
+        let decoded = decode_binary(&encode_binary(&binary)).unwrap();
+        assert_eq!((decoded.buffer, decoded.format), (binary.buffer, binary.format));
+        assert!(decode_binary(b"NBSC\x01\x00").is_none());
+        assert!(decode_binary(b"garbage!").is_none());
+
+        let key = cache_key("Mesa | llvmpipe | 4.5", BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER);
+        assert_eq!(key, cache_key("Mesa | llvmpipe | 4.5", BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER));
+        assert_ne!(key, cache_key("Mesa | llvmpipe | 4.6", BASIC_VERTEX_SHADER, BASIC_FRAGMENT_SHADER));
+        assert_ne!(key, cache_key("Mesa | llvmpipe | 4.5", TEXTURE_VERTEX_SHADER, TEXTURE_FRAGMENT_SHADER));
+    }
+}
@@ -0,0 +1,444 @@
+use glow::HasContext;
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+/// Handle to a texture owned by the TextureManager
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextureId(pub u32);
+
+/// Pixel layout of data handed to the TextureManager
+/// Everything is converted to RGBA8 before upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureFormat {
+    /// 8-bit red, green, blue, alpha (native format, no conversion)
+    Rgba8,
+    /// 8-bit red, green, blue (alpha = 255)
+    Rgb8,
+    /// 8-bit blue, green, red, alpha (common on Windows surfaces)
+    Bgra8,
+    /// 8-bit grayscale (alpha = 255)
+    Gray8,
+    /// 8-bit alpha only (white, used for glyph masks)
+    Alpha8,
+}
+
+impl TextureFormat {
+    /// Bytes per pixel for this format
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            TextureFormat::Rgba8 | TextureFormat::Bgra8 => 4,
+            TextureFormat::Rgb8 => 3,
+            TextureFormat::Gray8 | TextureFormat::Alpha8 => 1,
+        }
+    }
+}
+
+/// Convert pixel data to RGBA8
+/// Returns an error if the data length doesn't match the dimensions
+pub fn to_rgba8(data: &[u8], width: u32, height: u32, format: TextureFormat) -> Result<Vec<u8>, String> {
+    let pixel_count = width as usize * height as usize;
+    let expected = pixel_count * format.bytes_per_pixel();
+    if data.len() != expected {
+        return Err(format!(
+            "Texture data is {} bytes, expected {} for {}x{} {:?}",
+            data.len(), expected, width, height, format
+        ));
+    }
+
+    let rgba = match format {
+        TextureFormat::Rgba8 => data.to_vec(),
+        TextureFormat::Rgb8 => data
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        TextureFormat::Bgra8 => data
+            .chunks_exact(4)
+            .flat_map(|p| [p[2], p[1], p[0], p[3]])
+            .collect(),
+        TextureFormat::Gray8 => data.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+        TextureFormat::Alpha8 => data.iter().flat_map(|&a| [255, 255, 255, a]).collect(),
+    };
+
+    Ok(rgba)
+}
+
+/// Number of mip levels for a texture of the given size (including level 0)
+pub fn mip_level_count(width: u32, height: u32) -> u32 {
+    32 - width.max(height).max(1).leading_zeros()
+}
+
+/// A texture living on the GPU
+#[derive(Debug)]
+pub struct Texture {
+    pub handle: glow::Texture,
+    pub width: u32,
+    pub height: u32,
+    pub mipmapped: bool,
+}
+
+impl Texture {
+    /// Convert a source rectangle in texels to normalized UVs (u0, v0, u1, v1)
+    pub fn uv_rect(&self, src: crate::Rect) -> (f32, f32, f32, f32) {
+        uv_rect(src, self.width, self.height)
+    }
+}
+
+/// Convert a source rectangle in texels to normalized UVs (u0, v0, u1, v1)
+pub fn uv_rect(src: crate::Rect, width: u32, height: u32) -> (f32, f32, f32, f32) {
+    let w = width.max(1) as f32;
+    let h = height.max(1) as f32;
+    (
+        src.x / w,
+        src.y / h,
+        (src.x + src.width) / w,
+        (src.y + src.height) / h,
+    )
+}
+
+/// Texture manager - owns every GPU texture! 🖼️
+///
+/// Creates, uploads, updates, and deletes textures.
+/// All data is converted to RGBA8 so the shaders only deal with one format.
+pub struct TextureManager {
+    textures: HashMap<TextureId, Texture>,
+    next_id: u32,
+}
+
+impl TextureManager {
+    /// Create an empty texture manager
+    pub fn new() -> Self {
+        Self {
+            textures: HashMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Create a texture and upload its pixels
+    pub fn create(
+        &mut self,
+        gl: &glow::Context,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        format: TextureFormat,
+        mipmaps: bool,
+    ) -> Result<TextureId, String> {
+        let rgba = to_rgba8(data, width, height, format)?;
+
+        let handle = unsafe {
+            let handle = gl.create_texture()
+                .map_err(|e| format!("Failed to create texture: {}", e))?;
+            gl.bind_texture(glow::TEXTURE_2D, Some(handle));
+            gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::RGBA8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                Some(&rgba),
+            );
+
+            let min_filter = if mipmaps { glow::LINEAR_MIPMAP_LINEAR } else { glow::LINEAR };
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MIN_FILTER, min_filter as i32);
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MAG_FILTER, glow::LINEAR as i32);
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_S, glow::CLAMP_TO_EDGE as i32);
+            gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_T, glow::CLAMP_TO_EDGE as i32);
+
+            if mipmaps {
+                gl.generate_mipmap(glow::TEXTURE_2D);
+            }
+
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            handle
+        };
+
+        let id = TextureId(self.next_id);
+        self.next_id += 1;
+
+        info!("🖼️ Created texture {:?} ({}x{}, {} mip levels)", id, width, height,
+            if mipmaps { mip_level_count(width, height) } else { 1 });
+
+        self.textures.insert(id, Texture { handle, width, height, mipmapped: mipmaps });
+        Ok(id)
+    }
+
+    /// Replace the full contents of an existing texture
+    /// The size may change; mipmaps are regenerated if enabled
+    pub fn upload(
+        &mut self,
+        gl: &glow::Context,
+        id: TextureId,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        format: TextureFormat,
+    ) -> Result<(), String> {
+        let rgba = to_rgba8(data, width, height, format)?;
+        let texture = self.textures.get_mut(&id)
+            .ok_or_else(|| format!("Unknown texture {:?}", id))?;
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture.handle));
+            gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::RGBA8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                Some(&rgba),
+            );
+            if texture.mipmapped {
+                gl.generate_mipmap(glow::TEXTURE_2D);
+            }
+            gl.bind_texture(glow::TEXTURE_2D, None);
+        }
+
+        texture.width = width;
+        texture.height = height;
+        Ok(())
+    }
+
+    /// Update a sub-rectangle of an existing texture
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        gl: &glow::Context,
+        id: TextureId,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        format: TextureFormat,
+    ) -> Result<(), String> {
+        let rgba = to_rgba8(data, width, height, format)?;
+        let texture = self.textures.get(&id)
+            .ok_or_else(|| format!("Unknown texture {:?}", id))?;
+
+        if x.checked_add(width).is_none_or(|right| right > texture.width)
+            || y.checked_add(height).is_none_or(|bottom| bottom > texture.height)
+        {
+            return Err(format!(
+                "Update region {}x{} at ({}, {}) is outside texture {:?} ({}x{})",
+                width, height, x, y, id, texture.width, texture.height
+            ));
+        }
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture.handle));
+            gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+            gl.tex_sub_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                x as i32,
+                y as i32,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(&rgba),
+            );
+            if texture.mipmapped {
+                gl.generate_mipmap(glow::TEXTURE_2D);
+            }
+            gl.bind_texture(glow::TEXTURE_2D, None);
+        }
+
+        Ok(())
+    }
+
+    /// Re-upload only the dirty rectangles of a texture from its full source image
+    /// Binds once and regenerates mipmaps once, so a glyph atlas that gained a
+    /// few glyphs (or an image with a changed area) costs just those texels
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_regions(
+        &mut self,
+        gl: &glow::Context,
+        id: TextureId,
+        source: &[u8],
+        source_width: u32,
+        source_height: u32,
+        format: TextureFormat,
+        dirty: &[(u32, u32, u32, u32)],
+    ) -> Result<(), String> {
+        let texture = self.textures.get(&id)
+            .ok_or_else(|| format!("Unknown texture {:?}", id))?;
+        if source_width != texture.width || source_height != texture.height {
+            return Err(format!(
+                "Source is {}x{} but texture {:?} is {}x{}",
+                source_width, source_height, id, texture.width, texture.height
+            ));
+        }
+
+        // Convert everything up front so a bad rectangle uploads nothing
+        let mut uploads = Vec::with_capacity(dirty.len());
+        for &(x, y, width, height) in dirty {
+            if width == 0 || height == 0 {
+                continue;
+            }
+            let region = extract_region(source, source_width, source_height, format, (x, y, width, height))?;
+            uploads.push(((x, y, width, height), to_rgba8(&region, width, height, format)?));
+        }
+        if uploads.is_empty() {
+            return Ok(());
+        }
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture.handle));
+            gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+            for ((x, y, width, height), rgba) in &uploads {
+                gl.tex_sub_image_2d(
+                    glow::TEXTURE_2D,
+                    0,
+                    *x as i32,
+                    *y as i32,
+                    *width as i32,
+                    *height as i32,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelUnpackData::Slice(rgba),
+                );
+            }
+            if texture.mipmapped {
+                gl.generate_mipmap(glow::TEXTURE_2D);
+            }
+            gl.bind_texture(glow::TEXTURE_2D, None);
+        }
+
+        Ok(())
+    }
+
+    /// Delete a texture and free its GPU memory
+    pub fn delete(&mut self, gl: &glow::Context, id: TextureId) {
+        match self.textures.remove(&id) {
+            Some(texture) => unsafe {
+                gl.delete_texture(texture.handle);
+                info!("🗑️ Deleted texture {:?}", id);
+            },
+            None => warn!("Tried to delete unknown texture {:?}", id),
+        }
+    }
+
+    /// Get a texture by ID
+    pub fn get(&self, id: TextureId) -> Option<&Texture> {
+        self.textures.get(&id)
+    }
+
+    /// Number of live textures
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    /// Are there no live textures?
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+}
+
+impl Default for TextureManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copy a sub-rectangle out of a tightly packed image (same format in and out)
+pub fn extract_region(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    (x, y, region_width, region_height): (u32, u32, u32, u32),
+) -> Result<Vec<u8>, String> {
+    let bpp = format.bytes_per_pixel();
+    if data.len() != width as usize * height as usize * bpp {
+        return Err(format!("Source data is {} bytes, expected {}x{} {:?}", data.len(), width, height, format));
+    }
+    if x.checked_add(region_width).is_none_or(|right| right > width)
+        || y.checked_add(region_height).is_none_or(|bottom| bottom > height)
+    {
+        return Err(format!(
+            "Region {}x{} at ({}, {}) is outside the {}x{} source",
+            region_width, region_height, x, y, width, height
+        ));
+    }
+    let row_bytes = region_width as usize * bpp;
+    let mut region = Vec::with_capacity(row_bytes * region_height as usize);
+    for row in y..y + region_height {
+        let start = (row as usize * width as usize + x as usize) * bpp;
+        region.extend_from_slice(&data[start..start + row_bytes]);
+    }
+    Ok(region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rect;
+
+    #[test]
+    fn rgba8_passthrough() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(to_rgba8(&data, 2, 1, TextureFormat::Rgba8).unwrap(), data);
+    }
+
+    #[test]
+    fn rgb8_adds_opaque_alpha() {
+        let rgba = to_rgba8(&[10, 20, 30], 1, 1, TextureFormat::Rgb8).unwrap();
+        assert_eq!(rgba, vec![10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn bgra8_swaps_channels() {
+        let rgba = to_rgba8(&[30, 20, 10, 128], 1, 1, TextureFormat::Bgra8).unwrap();
+        assert_eq!(rgba, vec![10, 20, 30, 128]);
+    }
+
+    #[test]
+    fn gray_and_alpha_conversion() {
+        assert_eq!(to_rgba8(&[77], 1, 1, TextureFormat::Gray8).unwrap(), vec![77, 77, 77, 255]);
+        assert_eq!(to_rgba8(&[77], 1, 1, TextureFormat::Alpha8).unwrap(), vec![255, 255, 255, 77]);
+    }
+
+    #[test]
+    fn conversion_rejects_wrong_length() {
+        assert!(to_rgba8(&[0; 7], 2, 1, TextureFormat::Rgba8).is_err());
+    }
+
+    #[test]
+    fn mip_levels() {
+        assert_eq!(mip_level_count(1, 1), 1);
+        assert_eq!(mip_level_count(256, 256), 9);
+        assert_eq!(mip_level_count(300, 20), 9);
+    }
+
+    #[test]
+    fn uv_rect_normalizes() {
+        let uv = uv_rect(Rect::new(32.0, 0.0, 32.0, 64.0), 64, 64);
+        assert_eq!(uv, (0.5, 0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn manager_starts_empty() {
+        let manager = TextureManager::new();
+        assert!(manager.is_empty());
+        assert!(manager.get(TextureId(1)).is_none());
+    }
+
+    #[test]
+    fn extract_region_copies_rows() {
+        // 3x2 gray image, take the right 2x2 block
+        let data = [1, 2, 3, 4, 5, 6];
+        assert_eq!(extract_region(&data, 3, 2, TextureFormat::Gray8, (1, 0, 2, 2)).unwrap(), vec![2, 3, 5, 6]);
+        assert!(extract_region(&data, 3, 2, TextureFormat::Gray8, (2, 0, 2, 1)).is_err());
+        // Offsets near u32::MAX must not wrap around into bounds
+        assert!(extract_region(&data, 3, 2, TextureFormat::Gray8, (u32::MAX, 0, 2, 1)).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use glow::HasContext;
+use tracing::info;
+
+/// How a StreamingBuffer gets fresh memory once it has been written through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrphanStrategy {
+    /// Orphan (re-specify the storage) only when the buffer is full
+    /// The driver hands back new memory, so in-flight draws never stall us
+    WhenFull,
+    /// Orphan at the start of every frame and when full
+    /// Simplest for drivers that track buffers per frame
+    EveryFrame,
+    /// Never orphan: wrap around and overwrite from the start
+    /// Cheapest on drivers that synchronize sub-data well, may stall elsewhere
+    Never,
+}
+
+/// Where the next upload goes (see `RingAllocator::reserve`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reservation {
+    /// Byte offset to write at
+    pub offset: usize,
+    /// First vertex for draw_arrays
+    pub first_vertex: i32,
+    /// Orphan the storage before writing
+    pub orphan: bool,
+    /// Re-allocate the storage at this size before writing
+    pub grow_to: Option<usize>,
+}
+
+/// Bookkeeping for a streaming vertex buffer (no GL calls, so it's testable)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingAllocator {
+    pub capacity: usize,
+    pub offset: usize,
+    pub strategy: OrphanStrategy,
+}
+
+impl RingAllocator {
+    /// Allocator over `capacity` bytes
+    pub fn new(capacity: usize, strategy: OrphanStrategy) -> Self {
+        Self { capacity, offset: 0, strategy }
+    }
+
+    /// A new frame starts; true if the storage should be orphaned now
+    pub fn begin_frame(&mut self) -> bool {
+        if self.strategy == OrphanStrategy::EveryFrame && self.offset > 0 {
+            self.offset = 0;
+            return true;
+        }
+        false
+    }
+
+    /// Find room for `len` bytes of vertices `stride` bytes apart
+    pub fn reserve(&mut self, len: usize, stride: usize) -> Reservation {
+        let stride = stride.max(1);
+        // draw_arrays counts whole vertices, so uploads start on a vertex boundary
+        let mut offset = self.offset.div_ceil(stride) * stride;
+        let mut orphan = false;
+        let mut grow_to = None;
+        if len > self.capacity {
+            grow_to = Some(len.next_power_of_two());
+            self.capacity = len.next_power_of_two();
+            offset = 0;
+        } else if offset + len > self.capacity {
+            orphan = self.strategy != OrphanStrategy::Never;
+            offset = 0;
+        }
+        self.offset = offset + len;
+        Reservation { offset, first_vertex: (offset / stride) as i32, orphan, grow_to }
+    }
+}
+
+/// Upload counters for profiling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BufferStats {
+    pub uploads: u64,
+    pub bytes: u64,
+    pub orphans: u64,
+    pub reallocations: u64,
+}
+
+/// StreamingBuffer - One persistent VBO reused for every draw 🔁
+///
+/// Instead of re-allocating the buffer with glBufferData on every draw,
+/// uploads are appended with glBufferSubData; when the buffer is full it
+/// is orphaned (or wraps around) per the chosen `OrphanStrategy`, and it
+/// only grows when a single upload doesn't fit.
+pub struct StreamingBuffer {
+    pub buffer: glow::Buffer,
+    allocator: RingAllocator,
+    stats: BufferStats,
+}
+
+impl StreamingBuffer {
+    /// Create the VBO with `capacity` bytes of storage
+    pub fn new(gl: &glow::Context, capacity: usize, strategy: OrphanStrategy) -> Result<Self, String> {
+        let buffer = unsafe {
+            let buffer = gl.create_buffer().map_err(|e| format!("Failed to create VBO: {}", e))?;
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer));
+            gl.buffer_data_size(glow::ARRAY_BUFFER, capacity as i32, glow::STREAM_DRAW);
+            buffer
+        };
+        Ok(Self { buffer, allocator: RingAllocator::new(capacity, strategy), stats: BufferStats::default() })
+    }
+
+    /// Start of a frame (orphans under `OrphanStrategy::EveryFrame`)
+    pub fn begin_frame(&mut self, gl: &glow::Context) {
+        if self.allocator.begin_frame() {
+            self.orphan(gl);
+        }
+    }
+
+    /// Upload vertices and return the first vertex for draw_arrays
+    /// Leaves the buffer bound to ARRAY_BUFFER
+    pub fn upload(&mut self, gl: &glow::Context, data: &[u8], stride: usize) -> i32 {
+        let reservation = self.allocator.reserve(data.len(), stride);
+        unsafe {
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.buffer));
+            if let Some(capacity) = reservation.grow_to {
+                info!("🔁 Growing streaming VBO to {} bytes", capacity);
+                gl.buffer_data_size(glow::ARRAY_BUFFER, capacity as i32, glow::STREAM_DRAW);
+                self.stats.reallocations += 1;
+            } else if reservation.orphan {
+                self.orphan(gl);
+            }
+            gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, reservation.offset as i32, data);
+        }
+        self.stats.uploads += 1;
+        self.stats.bytes += data.len() as u64;
+        reservation.first_vertex
+    }
+
+    /// Current storage size in bytes
+    pub fn capacity(&self) -> usize {
+        self.allocator.capacity
+    }
+
+    /// Upload counters since creation
+    pub fn stats(&self) -> BufferStats {
+        self.stats
+    }
+
+    /// Free the VBO
+    pub fn delete(self, gl: &glow::Context) {
+        unsafe { gl.delete_buffer(self.buffer) };
+    }
+
+    fn orphan(&mut self, gl: &glow::Context) {
+        unsafe {
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.buffer));
+            gl.buffer_data_size(glow::ARRAY_BUFFER, self.allocator.capacity as i32, glow::STREAM_DRAW);
+        }
+        self.stats.orphans += 1;
+    }
+}
+
+/// Reinterpret vertex floats as bytes for upload
+pub fn vertex_bytes(vertices: &[f32]) -> &[u8] {
+    // SAFETY: f32 has no padding and any byte pattern is a valid u8
+    unsafe { std::slice::from_raw_parts(vertices.as_ptr() as *const u8, std::mem::size_of_val(vertices)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_appends_orphans_and_grows() {
+        let mut ring = RingAllocator::new(96, OrphanStrategy::WhenFull);
+        let first = ring.reserve(48, 24);
+        assert_eq!((first.offset, first.first_vertex, first.orphan), (0, 0, false));
+
+        // 10 bytes of 8-byte vertices end mid-vertex; the next upload realigns
+        ring.offset = 50;
+        let second = ring.reserve(24, 24);
+        assert_eq!((second.offset, second.first_vertex), (72, 3));
+
+        let wrapped = ring.reserve(48, 24);
+        assert_eq!((wrapped.offset, wrapped.orphan), (0, true));
+        let grown = ring.reserve(200, 24);
+        assert_eq!((grown.grow_to, ring.capacity), (Some(256), 256));
+
+        let mut never = RingAllocator::new(48, OrphanStrategy::Never);
+        never.reserve(48, 24);
+        assert!(!never.reserve(24, 24).orphan);
+        let mut every = RingAllocator::new(48, OrphanStrategy::EveryFrame);
+        assert!(!every.begin_frame());
+        every.reserve(24, 24);
+        assert!(every.begin_frame() && every.offset == 0);
+    }
+}
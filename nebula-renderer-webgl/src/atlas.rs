@@ -0,0 +1,202 @@
+//! Glyph Atlas - Packs rasterized glyphs into one texture! 🔤
+//!
+//! Platform independent so the packing logic is testable off the web;
+//! the WebGL backend rasterizes glyphs and uploads them into the slots.
+
+use std::collections::HashMap;
+
+/// Glyph cache key: the character and its pixel size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    pub ch: char,
+    pub size_px: u32,
+}
+
+impl GlyphKey {
+    /// Create a key (sizes are rounded to whole pixels)
+    pub fn new(ch: char, size: f32) -> Self {
+        Self { ch, size_px: size.round().max(1.0) as u32 }
+    }
+}
+
+/// A glyph's slot in the atlas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasGlyph {
+    /// Slot position and size in atlas pixels
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Horizontal advance in pixels
+    pub advance: f32,
+}
+
+impl AtlasGlyph {
+    /// Texture coordinates (u0, v0, u1, v1)
+    pub fn uv(&self, atlas_width: u32, atlas_height: u32) -> (f32, f32, f32, f32) {
+        let (w, h) = (atlas_width as f32, atlas_height as f32);
+        (
+            self.x as f32 / w,
+            self.y as f32 / h,
+            (self.x + self.width) as f32 / w,
+            (self.y + self.height) as f32 / h,
+        )
+    }
+}
+
+/// Shelf-packed glyph atlas
+///
+/// Glyphs fill rows ("shelves") left to right; a new shelf starts when a
+/// row is full. When the atlas runs out of room, `reset` and re-rasterize.
+#[derive(Debug, Clone)]
+pub struct GlyphAtlas {
+    width: u32,
+    height: u32,
+    padding: u32,
+    glyphs: HashMap<GlyphKey, AtlasGlyph>,
+    shelf_y: u32,
+    shelf_height: u32,
+    cursor_x: u32,
+}
+
+impl GlyphAtlas {
+    /// Create an empty atlas
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            padding: 1,
+            glyphs: HashMap::new(),
+            shelf_y: 0,
+            shelf_height: 0,
+            cursor_x: 0,
+        }
+    }
+
+    /// Atlas size in pixels
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Look up a packed glyph
+    pub fn get(&self, key: GlyphKey) -> Option<AtlasGlyph> {
+        self.glyphs.get(&key).copied()
+    }
+
+    /// Reserve a slot for a glyph
+    /// Returns None if the atlas is full (or the glyph is larger than it)
+    pub fn insert(&mut self, key: GlyphKey, width: u32, height: u32, advance: f32) -> Option<AtlasGlyph> {
+        if let Some(glyph) = self.get(key) {
+            return Some(glyph);
+        }
+        let padded_width = width + self.padding;
+        let padded_height = height + self.padding;
+        if padded_width > self.width {
+            return None;
+        }
+
+        if self.cursor_x + padded_width > self.width {
+            // Start a new shelf
+            self.shelf_y += self.shelf_height;
+            self.shelf_height = 0;
+            self.cursor_x = 0;
+        }
+        if self.shelf_y + padded_height > self.height {
+            return None;
+        }
+
+        let glyph = AtlasGlyph { x: self.cursor_x, y: self.shelf_y, width, height, advance };
+        self.cursor_x += padded_width;
+        self.shelf_height = self.shelf_height.max(padded_height);
+        self.glyphs.insert(key, glyph);
+        Some(glyph)
+    }
+
+    /// Number of packed glyphs
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Is the atlas empty?
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Drop every glyph
+    pub fn reset(&mut self) {
+        self.glyphs.clear();
+        self.shelf_y = 0;
+        self.shelf_height = 0;
+        self.cursor_x = 0;
+    }
+}
+
+/// Two triangles for a pixel rect, as interleaved (x, y, u, v) in clip space
+/// `viewport` is the canvas size in pixels; uv is (u0, v0, u1, v1)
+pub fn quad_vertices(
+    rect: (f32, f32, f32, f32),
+    uv: (f32, f32, f32, f32),
+    viewport: (u32, u32),
+) -> [f32; 24] {
+    let (vw, vh) = (viewport.0.max(1) as f32, viewport.1.max(1) as f32);
+    let (x, y, w, h) = rect;
+    let x0 = x / vw * 2.0 - 1.0;
+    let x1 = (x + w) / vw * 2.0 - 1.0;
+    // Pixel y grows down, clip-space y grows up
+    let y0 = 1.0 - y / vh * 2.0;
+    let y1 = 1.0 - (y + h) / vh * 2.0;
+    let (u0, v0, u1, v1) = uv;
+    [
+        x0, y0, u0, v0,
+        x1, y0, u1, v0,
+        x1, y1, u1, v1,
+        x0, y0, u0, v0,
+        x1, y1, u1, v1,
+        x0, y1, u0, v1,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atlas_packs_shelves() {
+        let mut atlas = GlyphAtlas::new(32, 32);
+        let a = atlas.insert(GlyphKey::new('a', 12.0), 10, 12, 7.0).unwrap();
+        let b = atlas.insert(GlyphKey::new('b', 12.0), 10, 14, 7.0).unwrap();
+        assert_eq!((a.x, a.y), (0, 0));
+        assert_eq!((b.x, b.y), (11, 0));
+
+        // Doesn't fit on the first shelf
+        let c = atlas.insert(GlyphKey::new('c', 12.0), 12, 10, 7.0).unwrap();
+        assert_eq!((c.x, c.y), (0, 15));
+
+        // Cached glyphs keep their slot
+        assert_eq!(atlas.insert(GlyphKey::new('a', 12.0), 10, 12, 7.0), Some(a));
+        assert_eq!(atlas.len(), 3);
+    }
+
+    #[test]
+    fn atlas_reports_full() {
+        let mut atlas = GlyphAtlas::new(16, 16);
+        assert!(atlas.insert(GlyphKey::new('a', 40.0), 20, 20, 10.0).is_none());
+        assert!(atlas.insert(GlyphKey::new('a', 12.0), 15, 15, 10.0).is_some());
+        assert!(atlas.insert(GlyphKey::new('b', 12.0), 15, 15, 10.0).is_none());
+        atlas.reset();
+        assert!(atlas.is_empty());
+    }
+
+    #[test]
+    fn glyph_uv_is_normalized() {
+        let glyph = AtlasGlyph { x: 64, y: 0, width: 64, height: 128, advance: 0.0 };
+        assert_eq!(glyph.uv(256, 256), (0.25, 0.0, 0.5, 0.5));
+    }
+
+    #[test]
+    fn quad_maps_pixels_to_clip_space() {
+        let vertices = quad_vertices((0.0, 0.0, 400.0, 300.0), (0.0, 0.0, 1.0, 1.0), (800, 600));
+        assert_eq!(&vertices[0..4], &[-1.0, 1.0, 0.0, 0.0]);
+        assert_eq!(&vertices[8..12], &[0.0, 0.0, 1.0, 1.0]);
+    }
+}
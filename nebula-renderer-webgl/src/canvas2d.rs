@@ -1,10 +1,17 @@
 //! Canvas2D Context - Fallback for ancient browsers! 🦕
 
-use wasm_bindgen::JsCast;
-use web_sys::{HtmlCanvasElement, CanvasRenderingContext2d};
+use std::collections::HashMap;
+
+use nebula_gfx::draw_list::{DrawCommand, DrawList, ImageId, Rect, Rgba};
+use nebula_gfx::RgbaImage;
+use wasm_bindgen::{Clamped, JsCast};
+use web_sys::{HtmlCanvasElement, CanvasRenderingContext2d, ImageData};
 use tracing::{info, error};
 
+use crate::encoding::{clamp_radius, css_color, css_font, image_source_rect, is_empty_rect};
+
 /// Canvas2D Context
+#[derive(Debug)]
 pub struct Canvas2DContext {
     canvas: HtmlCanvasElement,
     ctx: CanvasRenderingContext2d,
@@ -81,6 +88,160 @@ impl Canvas2DContext {
     }
 }
 
+/// Uploaded images and state for replaying a DrawList with Canvas2D 🦕
+///
+/// Images live in offscreen canvases so `drawImage` can blit them.
+/// Clips map to save/clip/restore, which nest the same way PushClip does.
+#[derive(Debug, Default)]
+pub struct Canvas2DPipeline {
+    images: HashMap<ImageId, HtmlCanvasElement>,
+    clip_depth: usize,
+}
+
+impl Canvas2DPipeline {
+    /// Create an empty pipeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Upload (or replace) an image
+    pub fn upload_image(&mut self, id: ImageId, image: &RgbaImage) -> Result<(), String> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("No document object")?;
+        let canvas = document
+            .create_element("canvas")
+            .map_err(|_| "Failed to create image canvas".to_string())?
+            .dyn_into::<HtmlCanvasElement>()
+            .map_err(|_| "Element is not a canvas".to_string())?;
+        canvas.set_width(image.width);
+        canvas.set_height(image.height);
+
+        let ctx = canvas
+            .get_context("2d")
+            .map_err(|_| "Failed to get 2D context".to_string())?
+            .ok_or("2D context is null")?
+            .dyn_into::<CanvasRenderingContext2d>()
+            .map_err(|_| "Failed to cast to 2D context".to_string())?;
+        let data = ImageData::new_with_u8_clamped_array_and_sh(Clamped(&image.pixels), image.width, image.height)
+            .map_err(|_| "Failed to create image data".to_string())?;
+        ctx.put_image_data(&data, 0.0, 0.0)
+            .map_err(|_| "Failed to upload image".to_string())?;
+
+        self.images.insert(id, canvas);
+        Ok(())
+    }
+
+    /// Free an uploaded image
+    pub fn remove_image(&mut self, id: ImageId) {
+        self.images.remove(&id);
+    }
+
+    /// Replay a draw list onto the canvas
+    pub fn execute(&mut self, context: &Canvas2DContext, list: &DrawList) -> Result<(), String> {
+        let ctx = &context.ctx;
+        self.clip_depth = 0;
+
+        for command in list.commands() {
+            match command {
+                DrawCommand::Clear(color) => {
+                    let (width, height) = (context.canvas.width() as f64, context.canvas.height() as f64);
+                    ctx.clear_rect(0.0, 0.0, width, height);
+                    fill_rect(ctx, (0.0, 0.0, width as f32, height as f32), *color);
+                }
+                DrawCommand::Rect { rect, color } => fill_rect(ctx, *rect, *color),
+                DrawCommand::RoundedRect { rect, radius, color } => fill_rounded_rect(ctx, *rect, *radius, *color)?,
+                DrawCommand::Text { text, x, y, size, color } => {
+                    ctx.set_font(&css_font(*size));
+                    ctx.set_text_baseline("top");
+                    ctx.set_fill_style(&css_color(*color).into());
+                    ctx.fill_text(text, *x as f64, *y as f64)
+                        .map_err(|_| "Failed to draw text".to_string())?;
+                }
+                DrawCommand::Image { image, src, dst, opacity } => {
+                    let source = self
+                        .images
+                        .get(image)
+                        .ok_or_else(|| format!("Image {:?} was not uploaded", image))?;
+                    let (sx, sy, sw, sh) = image_source_rect(*src, source.width(), source.height());
+                    if is_empty_rect((sx, sy, sw, sh)) || is_empty_rect(*dst) {
+                        continue;
+                    }
+                    ctx.set_global_alpha(*opacity as f64);
+                    let result = ctx.draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                        source,
+                        sx as f64,
+                        sy as f64,
+                        sw as f64,
+                        sh as f64,
+                        dst.0 as f64,
+                        dst.1 as f64,
+                        dst.2 as f64,
+                        dst.3 as f64,
+                    );
+                    ctx.set_global_alpha(1.0);
+                    result.map_err(|_| "Failed to draw image".to_string())?;
+                }
+                DrawCommand::PushClip(rect) => {
+                    // The clip region intersects with the saved one, like ClipStack
+                    ctx.save();
+                    ctx.begin_path();
+                    ctx.rect(rect.0 as f64, rect.1 as f64, rect.2 as f64, rect.3 as f64);
+                    ctx.clip();
+                    self.clip_depth += 1;
+                }
+                DrawCommand::PopClip => {
+                    if self.clip_depth > 0 {
+                        ctx.restore();
+                        self.clip_depth -= 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn fill_rect(ctx: &CanvasRenderingContext2d, rect: Rect, color: Rgba) {
+    ctx.set_fill_style(&css_color(color).into());
+    ctx.fill_rect(rect.0 as f64, rect.1 as f64, rect.2 as f64, rect.3 as f64);
+}
+
+/// Rounded rect built from arcTo (roundRect is too new for the browsers we fall back on)
+fn fill_rounded_rect(ctx: &CanvasRenderingContext2d, rect: Rect, radius: f32, color: Rgba) -> Result<(), String> {
+    if is_empty_rect(rect) {
+        return Ok(());
+    }
+    let r = clamp_radius(rect, radius) as f64;
+    let (x, y, w, h) = (rect.0 as f64, rect.1 as f64, rect.2 as f64, rect.3 as f64);
+
+    ctx.begin_path();
+    ctx.move_to(x + r, y);
+    ctx.arc_to(x + w, y, x + w, y + h, r)
+        .and_then(|_| ctx.arc_to(x + w, y + h, x, y + h, r))
+        .and_then(|_| ctx.arc_to(x, y + h, x, y, r))
+        .and_then(|_| ctx.arc_to(x, y, x + w, y, r))
+        .map_err(|_| "Failed to build rounded rect".to_string())?;
+    ctx.close_path();
+    ctx.set_fill_style(&css_color(color).into());
+    ctx.fill();
+    Ok(())
+}
+
+/// Read back the canvas as RGBA8 (rows top to bottom)
+pub fn capture_frame(canvas_id: &str) -> Result<nebula_gfx::RgbaImage, String> {
+    let context = Canvas2DContext::new(canvas_id)?;
+    let width = context.canvas.width();
+    let height = context.canvas.height();
+
+    let data = context
+        .ctx
+        .get_image_data(0.0, 0.0, width as f64, height as f64)
+        .map_err(|_| "Failed to read Canvas2D pixels".to_string())?;
+
+    nebula_gfx::RgbaImage::from_raw(width, height, data.data().0)
+}
+
 /// Clear the canvas (convenience function)
 pub fn clear(canvas_id: &str, r: f32, g: f32, b: f32, a: f32) -> Result<(), String> {
     let context = Canvas2DContext::new(canvas_id)?;
@@ -0,0 +1,71 @@
+//! Shared Command Encoding - One set of rules for both web backends! 🧾
+//!
+//! WebGL2 and Canvas2D turn the same `DrawCommand`s into very different
+//! calls. Anything that decides *what* gets drawn (colors, fonts, radii,
+//! image regions, line boxes) lives here so both backends agree on it.
+
+use nebula_gfx::draw_list::{Rect, Rgba};
+
+/// Font family used for text on every web backend
+pub const FONT_FAMILY: &str = "sans-serif";
+
+/// Height of a line box relative to the font size (room for descenders)
+pub const LINE_HEIGHT: f32 = 1.25;
+
+/// CSS color string for a straight-alpha color
+pub fn css_color(color: Rgba) -> String {
+    let alpha = color.3 as f32 / 255.0;
+    format!("rgba({}, {}, {}, {})", color.0, color.1, color.2, (alpha * 1000.0).round() / 1000.0)
+}
+
+/// CSS font shorthand for a pixel size
+pub fn css_font(size: f32) -> String {
+    format!("{}px {}", size.round().max(1.0), FONT_FAMILY)
+}
+
+/// Corner radius limited to half the shorter side
+pub fn clamp_radius(rect: Rect, radius: f32) -> f32 {
+    radius.clamp(0.0, (rect.2.min(rect.3) / 2.0).max(0.0))
+}
+
+/// Source region of an image, defaulting to the whole image and clamped to its bounds
+pub fn image_source_rect(src: Option<Rect>, width: u32, height: u32) -> Rect {
+    let (width, height) = (width as f32, height as f32);
+    let (x, y, w, h) = src.unwrap_or((0.0, 0.0, width, height));
+    let x = x.clamp(0.0, width);
+    let y = y.clamp(0.0, height);
+    (x, y, w.clamp(0.0, width - x), h.clamp(0.0, height - y))
+}
+
+/// Height of the line box for a font size
+pub fn line_height(size: f32) -> f32 {
+    (size.round().max(1.0) * LINE_HEIGHT).ceil()
+}
+
+/// Is the rect too small to produce any pixels?
+pub fn is_empty_rect(rect: Rect) -> bool {
+    rect.2 <= 0.0 || rect.3 <= 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_strings() {
+        assert_eq!(css_color((255, 128, 0, 255)), "rgba(255, 128, 0, 1)");
+        assert_eq!(css_color((0, 0, 0, 128)), "rgba(0, 0, 0, 0.502)");
+        assert_eq!(css_font(15.6), "16px sans-serif");
+        assert_eq!(line_height(16.0), 20.0);
+    }
+
+    #[test]
+    fn radius_and_source_clamping() {
+        assert_eq!(clamp_radius((0.0, 0.0, 100.0, 20.0), 50.0), 10.0);
+        assert_eq!(clamp_radius((0.0, 0.0, 100.0, 20.0), -1.0), 0.0);
+
+        assert_eq!(image_source_rect(None, 64, 32), (0.0, 0.0, 64.0, 32.0));
+        assert_eq!(image_source_rect(Some((60.0, -4.0, 10.0, 10.0)), 64, 32), (60.0, 0.0, 4.0, 10.0));
+        assert!(is_empty_rect((0.0, 0.0, 0.0, 5.0)));
+    }
+}
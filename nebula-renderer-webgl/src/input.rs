@@ -0,0 +1,231 @@
+//! Web Input - DOM events in, desktop InputHandler calls out! 🖱️
+//!
+//! Platform independent so the translation is testable off the web;
+//! `run_loop` feeds real DOM events through an `InputDispatcher`.
+
+use nebula_platform::{InputHandler, Key, MouseButtonEvent, MousePosition};
+
+/// A DOM input event, already unpacked from web-sys
+/// Coordinates are CSS pixels relative to the canvas
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebInputEvent {
+    MouseDown { button: i16, x: f64, y: f64 },
+    MouseUp { button: i16, x: f64, y: f64 },
+    MouseMove { x: f64, y: f64 },
+    /// `code` is `KeyboardEvent.code` (physical key, e.g. "KeyA")
+    KeyDown { code: String },
+    KeyUp { code: String },
+    TouchStart { id: i32, x: f64, y: f64 },
+    TouchMove { id: i32, x: f64, y: f64 },
+    TouchEnd { id: i32, x: f64, y: f64 },
+}
+
+/// Map `KeyboardEvent.code` to a Key (same physical keys as desktop)
+pub fn key_from_code(code: &str) -> Key {
+    let letter = code
+        .strip_prefix("Key")
+        .and_then(|rest| rest.chars().next().filter(|_| rest.len() == 1));
+    if let Some(c) = letter {
+        const LETTERS: [Key; 26] = [
+            Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I,
+            Key::J, Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q, Key::R,
+            Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+        ];
+        if c.is_ascii_uppercase() {
+            return LETTERS[(c as u8 - b'A') as usize];
+        }
+    }
+
+    let digit = code
+        .strip_prefix("Digit")
+        .and_then(|rest| rest.parse::<usize>().ok())
+        .filter(|&d| d < 10);
+    if let Some(d) = digit {
+        const DIGITS: [Key; 10] = [
+            Key::Num0, Key::Num1, Key::Num2, Key::Num3, Key::Num4,
+            Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9,
+        ];
+        return DIGITS[d];
+    }
+
+    let function = code
+        .strip_prefix('F')
+        .and_then(|rest| rest.parse::<usize>().ok())
+        .filter(|f| (1..=12).contains(f));
+    if let Some(f) = function {
+        const FUNCTION: [Key; 12] = [
+            Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6,
+            Key::F7, Key::F8, Key::F9, Key::F10, Key::F11, Key::F12,
+        ];
+        return FUNCTION[f - 1];
+    }
+
+    match code {
+        "Escape" => Key::Escape,
+        "Enter" | "NumpadEnter" => Key::Enter,
+        "Space" => Key::Space,
+        "Backspace" => Key::Backspace,
+        "Tab" => Key::Tab,
+        "Delete" => Key::Delete,
+        "ArrowUp" => Key::ArrowUp,
+        "ArrowDown" => Key::ArrowDown,
+        "ArrowLeft" => Key::ArrowLeft,
+        "ArrowRight" => Key::ArrowRight,
+        "ShiftLeft" | "ShiftRight" => Key::Shift,
+        "ControlLeft" | "ControlRight" => Key::Control,
+        "AltLeft" | "AltRight" => Key::Alt,
+        "MetaLeft" | "MetaRight" | "OSLeft" | "OSRight" => Key::Meta,
+        _ => Key::Unknown,
+    }
+}
+
+/// Map `MouseEvent.button` to a mouse button
+pub fn mouse_button_from_dom(button: i16) -> MouseButtonEvent {
+    match button {
+        0 => MouseButtonEvent::Left,
+        1 => MouseButtonEvent::Middle,
+        2 => MouseButtonEvent::Right,
+        other => MouseButtonEvent::Other(other.max(0) as u16),
+    }
+}
+
+/// Turns DOM events into InputHandler calls
+///
+/// Positions are converted from CSS pixels to physical pixels, matching
+/// the desktop window. The first finger down acts as the left mouse
+/// button; other fingers are ignored until it lifts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputDispatcher {
+    pub device_pixel_ratio: f64,
+    primary_touch: Option<i32>,
+}
+
+impl InputDispatcher {
+    /// Create a dispatcher for a device pixel ratio
+    pub fn new(device_pixel_ratio: f64) -> Self {
+        Self { device_pixel_ratio, primary_touch: None }
+    }
+
+    fn position(&self, x: f64, y: f64) -> MousePosition {
+        MousePosition::new(x * self.device_pixel_ratio, y * self.device_pixel_ratio)
+    }
+
+    /// Deliver one event
+    /// Returns true if the handler was called (so the DOM default can be prevented)
+    pub fn dispatch<H: InputHandler + ?Sized>(&mut self, handler: &mut H, event: &WebInputEvent) -> bool {
+        match *event {
+            WebInputEvent::MouseDown { button, x, y } => {
+                handler.on_mouse_down(mouse_button_from_dom(button), self.position(x, y));
+            }
+            WebInputEvent::MouseUp { button, x, y } => {
+                handler.on_mouse_up(mouse_button_from_dom(button), self.position(x, y));
+            }
+            WebInputEvent::MouseMove { x, y } => handler.on_mouse_move(self.position(x, y)),
+            WebInputEvent::KeyDown { ref code } => handler.on_key_down(key_from_code(code)),
+            WebInputEvent::KeyUp { ref code } => handler.on_key_up(key_from_code(code)),
+            WebInputEvent::TouchStart { id, x, y } => {
+                if self.primary_touch.is_some() {
+                    return false;
+                }
+                self.primary_touch = Some(id);
+                let position = self.position(x, y);
+                handler.on_mouse_move(position);
+                handler.on_mouse_down(MouseButtonEvent::Left, position);
+            }
+            WebInputEvent::TouchMove { id, x, y } => {
+                if self.primary_touch != Some(id) {
+                    return false;
+                }
+                handler.on_mouse_move(self.position(x, y));
+            }
+            WebInputEvent::TouchEnd { id, x, y } => {
+                if self.primary_touch != Some(id) {
+                    return false;
+                }
+                self.primary_touch = None;
+                handler.on_mouse_up(MouseButtonEvent::Left, self.position(x, y));
+            }
+        }
+        true
+    }
+}
+
+impl Default for InputDispatcher {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// Physical canvas size for a CSS size and device pixel ratio
+pub fn physical_size(css_width: f64, css_height: f64, device_pixel_ratio: f64) -> (u32, u32) {
+    (
+        (css_width * device_pixel_ratio).round().max(1.0) as u32,
+        (css_height * device_pixel_ratio).round().max(1.0) as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl InputHandler for Recorder {
+        fn on_mouse_down(&mut self, button: MouseButtonEvent, position: MousePosition) {
+            self.events.push(format!("down {:?} {} {}", button, position.x, position.y));
+        }
+
+        fn on_mouse_up(&mut self, button: MouseButtonEvent, _position: MousePosition) {
+            self.events.push(format!("up {:?}", button));
+        }
+
+        fn on_mouse_move(&mut self, position: MousePosition) {
+            self.events.push(format!("move {} {}", position.x, position.y));
+        }
+
+        fn on_key_down(&mut self, key: Key) {
+            self.events.push(format!("key {:?}", key));
+        }
+    }
+
+    #[test]
+    fn key_codes_match_desktop_keys() {
+        assert_eq!(key_from_code("KeyA"), Key::A);
+        assert_eq!(key_from_code("KeyZ"), Key::Z);
+        assert_eq!(key_from_code("Digit7"), Key::Num7);
+        assert_eq!(key_from_code("F12"), Key::F12);
+        assert_eq!(key_from_code("F13"), Key::Unknown);
+        assert_eq!(key_from_code("ControlRight"), Key::Control);
+        assert_eq!(key_from_code("Keyboard"), Key::Unknown);
+    }
+
+    #[test]
+    fn mouse_positions_scale_to_physical_pixels() {
+        let mut dispatcher = InputDispatcher::new(2.0);
+        let mut recorder = Recorder::default();
+        dispatcher.dispatch(&mut recorder, &WebInputEvent::MouseDown { button: 2, x: 10.0, y: 5.0 });
+        dispatcher.dispatch(&mut recorder, &WebInputEvent::KeyDown { code: "Enter".to_string() });
+        assert_eq!(recorder.events, vec!["down Right 20 10", "key Enter"]);
+        assert_eq!(mouse_button_from_dom(4), MouseButtonEvent::Other(4));
+    }
+
+    #[test]
+    fn primary_touch_emulates_left_button() {
+        let mut dispatcher = InputDispatcher::default();
+        let mut recorder = Recorder::default();
+        assert!(dispatcher.dispatch(&mut recorder, &WebInputEvent::TouchStart { id: 7, x: 1.0, y: 2.0 }));
+        assert!(!dispatcher.dispatch(&mut recorder, &WebInputEvent::TouchStart { id: 8, x: 9.0, y: 9.0 }));
+        assert!(dispatcher.dispatch(&mut recorder, &WebInputEvent::TouchMove { id: 7, x: 3.0, y: 4.0 }));
+        assert!(dispatcher.dispatch(&mut recorder, &WebInputEvent::TouchEnd { id: 7, x: 3.0, y: 4.0 }));
+        assert_eq!(recorder.events, vec!["move 1 2", "down Left 1 2", "move 3 4", "up Left"]);
+    }
+
+    #[test]
+    fn physical_size_rounds() {
+        assert_eq!(physical_size(400.0, 300.5, 1.5), (600, 451));
+        assert_eq!(physical_size(0.0, 0.0, 2.0), (1, 1));
+    }
+}
@@ -6,14 +6,25 @@
 //! - < 1 MB gzipped bundle size
 //! - Works everywhere!
 
+use nebula_gfx::draw_list::{DrawList, ImageId};
+use nebula_gfx::{RendererCapabilities, RgbaImage};
 use tracing::{info, warn, error};
 
+pub mod atlas;
+pub mod encoding;
+pub mod input;
+
 #[cfg(target_arch = "wasm32")]
 pub mod webgl;
 
 #[cfg(target_arch = "wasm32")]
 pub mod canvas2d;
 
+#[cfg(target_arch = "wasm32")]
+pub mod run_loop;
+
+pub use input::{InputDispatcher, WebInputEvent};
+
 /// WebGL Renderer - Runs in the browser! 🌐
 /// 
 /// Features:
@@ -27,9 +38,17 @@ pub struct WebGLRenderer {
     canvas_id: String,
     /// Renderer backend
     backend: RendererBackend,
-    /// Canvas dimensions
+    /// Canvas dimensions (physical pixels)
     width: u32,
     height: u32,
+    /// Device pixel ratio (physical pixels per CSS pixel)
+    device_pixel_ratio: f64,
+    /// Live WebGL 2.0 context and draw pipeline
+    #[cfg(target_arch = "wasm32")]
+    webgl: Option<(webgl::WebGL2Context, webgl::WebGL2Pipeline)>,
+    /// Live Canvas2D context and draw pipeline
+    #[cfg(target_arch = "wasm32")]
+    canvas2d: Option<(canvas2d::Canvas2DContext, canvas2d::Canvas2DPipeline)>,
 }
 
 /// Renderer backend type
@@ -54,6 +73,11 @@ impl WebGLRenderer {
             backend: RendererBackend::None,
             width: 800,
             height: 600,
+            device_pixel_ratio: 1.0,
+            #[cfg(target_arch = "wasm32")]
+            webgl: None,
+            #[cfg(target_arch = "wasm32")]
+            canvas2d: None,
         }
     }
 
@@ -63,11 +87,20 @@ impl WebGLRenderer {
     pub fn initialize(&mut self) -> Result<(), String> {
         info!("🚀 Initializing WebGL Renderer...");
 
+        if let Some(window) = web_sys::window() {
+            self.device_pixel_ratio = window.device_pixel_ratio();
+            info!("📐 Device pixel ratio: {}", self.device_pixel_ratio);
+        }
+
         // Try WebGL 2.0 first
-        match webgl::WebGL2Context::new(&self.canvas_id) {
-            Ok(context) => {
+        match webgl::WebGL2Context::new(&self.canvas_id)
+            .and_then(|context| webgl::WebGL2Pipeline::new(context.gl()).map(|pipeline| (context, pipeline)))
+        {
+            Ok(webgl) => {
                 info!("✅ WebGL 2.0 initialized!");
+                self.webgl = Some(webgl);
                 self.backend = RendererBackend::WebGL2;
+                RendererCapabilities::set_current(self.capabilities());
                 Ok(())
             }
             Err(e) => {
@@ -78,7 +111,9 @@ impl WebGLRenderer {
                 match canvas2d::Canvas2DContext::new(&self.canvas_id) {
                     Ok(context) => {
                         info!("✅ Canvas2D initialized!");
+                        self.canvas2d = Some((context, canvas2d::Canvas2DPipeline::new()));
                         self.backend = RendererBackend::Canvas2D;
+                        RendererCapabilities::set_current(self.capabilities());
                         Ok(())
                     }
                     Err(e) => {
@@ -97,6 +132,15 @@ impl WebGLRenderer {
         Err("WebGL renderer requires WASM target".to_string())
     }
 
+    /// Effects the active backend can draw
+    pub fn capabilities(&self) -> RendererCapabilities {
+        match self.backend {
+            RendererBackend::WebGL2 => RendererCapabilities { render_targets: false, ..RendererCapabilities::FULL },
+            RendererBackend::Canvas2D => RendererCapabilities::CANVAS2D,
+            RendererBackend::None => RendererCapabilities::BASIC,
+        }
+    }
+
     /// Get current backend
     pub fn backend(&self) -> RendererBackend {
         self.backend
@@ -114,6 +158,25 @@ impl WebGLRenderer {
         (self.width, self.height)
     }
 
+    /// Set the device pixel ratio (e.g. after the page is zoomed or moved to another display)
+    pub fn set_device_pixel_ratio(&mut self, ratio: f64) {
+        self.device_pixel_ratio = ratio;
+        info!("📐 Device pixel ratio set to {}", ratio);
+    }
+
+    /// Get the device pixel ratio
+    pub fn device_pixel_ratio(&self) -> f64 {
+        self.device_pixel_ratio
+    }
+
+    /// Get canvas size in CSS (logical) pixels
+    pub fn logical_size(&self) -> (f64, f64) {
+        (
+            self.width as f64 / self.device_pixel_ratio,
+            self.height as f64 / self.device_pixel_ratio,
+        )
+    }
+
     /// Clear the canvas
     #[cfg(target_arch = "wasm32")]
     pub fn clear(&self, r: f32, g: f32, b: f32, a: f32) -> Result<(), String> {
@@ -136,6 +199,67 @@ impl WebGLRenderer {
         Err("WebGL renderer requires WASM target".to_string())
     }
 
+    /// Read back the current canvas contents
+    #[cfg(target_arch = "wasm32")]
+    pub fn capture_frame(&self) -> Result<nebula_gfx::RgbaImage, String> {
+        match self.backend {
+            RendererBackend::WebGL2 => webgl::capture_frame(&self.canvas_id),
+            RendererBackend::Canvas2D => canvas2d::capture_frame(&self.canvas_id),
+            RendererBackend::None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Read back the current canvas contents (non-WASM stub)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn capture_frame(&self) -> Result<nebula_gfx::RgbaImage, String> {
+        Err("WebGL renderer requires WASM target".to_string())
+    }
+
+    /// Upload an image so draw lists can reference it by id
+    #[cfg(target_arch = "wasm32")]
+    pub fn upload_image(&mut self, id: ImageId, image: &RgbaImage) -> Result<(), String> {
+        match self.backend {
+            RendererBackend::WebGL2 => match self.webgl.as_mut() {
+                Some((context, pipeline)) => pipeline.upload_image(context.gl(), id, image),
+                None => Err("WebGL 2.0 context missing".to_string()),
+            },
+            RendererBackend::Canvas2D => match self.canvas2d.as_mut() {
+                Some((_, pipeline)) => pipeline.upload_image(id, image),
+                None => Err("Canvas2D context missing".to_string()),
+            },
+            RendererBackend::None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Upload an image (non-WASM stub)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn upload_image(&mut self, _id: ImageId, _image: &RgbaImage) -> Result<(), String> {
+        Err("WebGL renderer requires WASM target".to_string())
+    }
+
+    /// Draw a frame from a draw list
+    #[cfg(target_arch = "wasm32")]
+    pub fn render(&mut self, list: &DrawList) -> Result<(), String> {
+        let viewport = (self.width, self.height);
+        match self.backend {
+            RendererBackend::WebGL2 => match self.webgl.as_mut() {
+                Some((context, pipeline)) => pipeline.execute(context.gl(), list, viewport),
+                None => Err("WebGL 2.0 context missing".to_string()),
+            },
+            RendererBackend::Canvas2D => match self.canvas2d.as_mut() {
+                Some((context, pipeline)) => pipeline.execute(context, list),
+                None => Err("Canvas2D context missing".to_string()),
+            },
+            RendererBackend::None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Draw a frame from a draw list (non-WASM stub)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render(&mut self, _list: &DrawList) -> Result<(), String> {
+        Err("WebGL renderer requires WASM target".to_string())
+    }
+
     /// Present the frame
     pub fn present(&self) {
         // WebGL/Canvas2D automatically presents
@@ -173,6 +297,16 @@ mod tests {
         assert_eq!(renderer.size(), (1920, 1080));
     }
 
+    #[test]
+    fn renderer_device_pixel_ratio() {
+        let mut renderer = WebGLRenderer::new("test");
+        assert_eq!(renderer.device_pixel_ratio(), 1.0);
+
+        renderer.set_size(1600, 1200);
+        renderer.set_device_pixel_ratio(2.0);
+        assert_eq!(renderer.logical_size(), (800.0, 600.0));
+    }
+
     #[test]
     fn renderer_backend() {
         let renderer = WebGLRenderer::new("test");
@@ -194,6 +328,23 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn capture_fails_on_non_wasm() {
+        let renderer = WebGLRenderer::new("test");
+        assert!(renderer.capture_frame().is_err());
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_fails_on_non_wasm() {
+        let mut renderer = WebGLRenderer::new("test");
+        let mut list = DrawList::new();
+        list.rect((0.0, 0.0, 10.0, 10.0), (255, 0, 0, 255));
+        assert!(renderer.render(&list).is_err());
+        assert!(renderer.upload_image(ImageId(1), &RgbaImage::new(1, 1)).is_err());
+    }
+
     #[test]
     #[cfg(not(target_arch = "wasm32"))]
     fn clear_fails_on_non_wasm() {
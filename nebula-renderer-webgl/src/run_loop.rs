@@ -0,0 +1,228 @@
+//! Browser Run Loop - requestAnimationFrame + DOM input! 🔁
+//!
+//! The web equivalent of `NebulaWindow::run`: frames come from
+//! requestAnimationFrame, size changes from a ResizeObserver, and mouse,
+//! touch and keyboard events go to the same `InputHandler` as on desktop.
+
+use crate::input::{physical_size, InputDispatcher, WebInputEvent};
+use nebula_platform::InputHandler;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use tracing::info;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    AddEventListenerOptions, Event, EventTarget, HtmlCanvasElement, KeyboardEvent, MouseEvent,
+    ResizeObserver, ResizeObserverEntry, TouchEvent,
+};
+
+/// A browser app driven by the run loop
+pub trait WebApp: InputHandler {
+    /// Draw a frame (`timestamp_ms` is the requestAnimationFrame time)
+    fn frame(&mut self, timestamp_ms: f64);
+
+    /// The canvas was resized (physical pixels)
+    fn resize(&mut self, _width: u32, _height: u32, _device_pixel_ratio: f64) {}
+}
+
+struct State<A> {
+    app: A,
+    dispatcher: InputDispatcher,
+}
+
+type Listener = (EventTarget, &'static str, Closure<dyn FnMut(Event)>);
+
+/// Handle to a running loop
+///
+/// DOM listeners live as long as the handle: `stop` ends the loop, while
+/// `forget` keeps it running for the lifetime of the page.
+pub struct RunLoop {
+    running: Rc<Cell<bool>>,
+    listeners: Vec<Listener>,
+    observer: ResizeObserver,
+    _on_resize: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl RunLoop {
+    /// Is the loop still scheduling frames?
+    pub fn is_running(&self) -> bool {
+        self.running.get()
+    }
+
+    /// Leak the handle so the loop runs until the page unloads
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+
+    /// Stop drawing frames and detach every DOM listener
+    pub fn stop(self) {
+        self.running.set(false);
+        self.observer.disconnect();
+        for (target, kind, closure) in &self.listeners {
+            let _ = target.remove_event_listener_with_callback(kind, closure.as_ref().unchecked_ref());
+        }
+        info!("⏹️ Web run loop stopped");
+    }
+}
+
+/// Start the run loop on a canvas
+///
+/// Keyboard events are read from the window, so the page doesn't need to
+/// focus the canvas first.
+pub fn run<A: WebApp + 'static>(canvas_id: &str, app: A) -> Result<RunLoop, String> {
+    let window = web_sys::window().ok_or("No window")?;
+    let document = window.document().ok_or("No document")?;
+    let canvas = document
+        .get_element_by_id(canvas_id)
+        .ok_or_else(|| format!("Canvas '{}' not found", canvas_id))?
+        .dyn_into::<HtmlCanvasElement>()
+        .map_err(|_| "Element is not a canvas")?;
+
+    let state = Rc::new(RefCell::new(State {
+        app,
+        dispatcher: InputDispatcher::new(window.device_pixel_ratio()),
+    }));
+    let running = Rc::new(Cell::new(true));
+
+    // Input listeners
+    let mut listeners: Vec<Listener> = Vec::new();
+    let canvas_target: EventTarget = canvas.clone().into();
+    let window_target: EventTarget = window.clone().into();
+
+    let mouse = |kind: &'static str| {
+        let state = state.clone();
+        let closure = Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+            let Some(event) = event.dyn_ref::<MouseEvent>() else {
+                return;
+            };
+            let (x, y) = (event.offset_x() as f64, event.offset_y() as f64);
+            let input = match kind {
+                "mousedown" => WebInputEvent::MouseDown { button: event.button(), x, y },
+                "mouseup" => WebInputEvent::MouseUp { button: event.button(), x, y },
+                _ => WebInputEvent::MouseMove { x, y },
+            };
+            let mut state = state.borrow_mut();
+            let State { app, dispatcher } = &mut *state;
+            dispatcher.dispatch(app, &input);
+        });
+        (canvas_target.clone(), kind, closure)
+    };
+    listeners.extend(["mousedown", "mouseup", "mousemove"].map(mouse));
+
+    let touch = |kind: &'static str| {
+        let state = state.clone();
+        let canvas = canvas.clone();
+        let closure = Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+            let Some(touch_event) = event.dyn_ref::<TouchEvent>() else {
+                return;
+            };
+            let bounds = canvas.get_bounding_client_rect();
+            let touches = touch_event.changed_touches();
+            let mut handled = false;
+            for i in 0..touches.length() {
+                let Some(touch) = touches.get(i) else {
+                    continue;
+                };
+                let id = touch.identifier();
+                let x = touch.client_x() as f64 - bounds.left();
+                let y = touch.client_y() as f64 - bounds.top();
+                let input = match kind {
+                    "touchstart" => WebInputEvent::TouchStart { id, x, y },
+                    "touchmove" => WebInputEvent::TouchMove { id, x, y },
+                    _ => WebInputEvent::TouchEnd { id, x, y },
+                };
+                let mut state = state.borrow_mut();
+                let State { app, dispatcher } = &mut *state;
+                handled |= dispatcher.dispatch(app, &input);
+            }
+            // Keep the browser from scrolling or synthesizing mouse events
+            if handled {
+                event.prevent_default();
+            }
+        });
+        (canvas_target.clone(), kind, closure)
+    };
+    listeners.extend(["touchstart", "touchmove", "touchend", "touchcancel"].map(touch));
+
+    let key = |kind: &'static str| {
+        let state = state.clone();
+        let closure = Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+            let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                return;
+            };
+            let input = match kind {
+                "keydown" => WebInputEvent::KeyDown { code: event.code() },
+                _ => WebInputEvent::KeyUp { code: event.code() },
+            };
+            let mut state = state.borrow_mut();
+            let State { app, dispatcher } = &mut *state;
+            dispatcher.dispatch(app, &input);
+        });
+        (window_target.clone(), kind, closure)
+    };
+    listeners.extend(["keydown", "keyup"].map(key));
+
+    for (target, kind, closure) in &listeners {
+        let options = AddEventListenerOptions::new();
+        // Touch handlers call preventDefault, so they can't be passive
+        options.set_passive(false);
+        target
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                kind,
+                closure.as_ref().unchecked_ref(),
+                &options,
+            )
+            .map_err(|e| format!("Failed to listen for {}: {:?}", kind, e))?;
+    }
+
+    // Resize observer: keep the backing store at physical resolution
+    let on_resize = {
+        let state = state.clone();
+        let canvas = canvas.clone();
+        let window = window.clone();
+        Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            let Ok(entry) = entries.get(0).dyn_into::<ResizeObserverEntry>() else {
+                return;
+            };
+            let rect = entry.content_rect();
+            let ratio = window.device_pixel_ratio();
+            let (width, height) = physical_size(rect.width(), rect.height(), ratio);
+            canvas.set_width(width);
+            canvas.set_height(height);
+
+            let mut state = state.borrow_mut();
+            state.dispatcher.device_pixel_ratio = ratio;
+            state.app.resize(width, height, ratio);
+        })
+    };
+    let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref())
+        .map_err(|e| format!("Failed to create ResizeObserver: {:?}", e))?;
+    observer.observe(&canvas);
+
+    // requestAnimationFrame loop: the closure reschedules itself
+    let frame: Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>> = Rc::new(RefCell::new(None));
+    {
+        let frame_handle = frame.clone();
+        let running = running.clone();
+        let window = window.clone();
+        *frame.borrow_mut() = Some(Closure::new(move |timestamp: f64| {
+            if !running.get() {
+                // Break the cycle so the closure can be freed
+                frame_handle.borrow_mut().take();
+                return;
+            }
+            state.borrow_mut().app.frame(timestamp);
+            if let Some(callback) = frame_handle.borrow().as_ref() {
+                let _ = window.request_animation_frame(callback.as_ref().unchecked_ref());
+            }
+        }));
+    }
+    if let Some(callback) = frame.borrow().as_ref() {
+        window
+            .request_animation_frame(callback.as_ref().unchecked_ref())
+            .map_err(|e| format!("requestAnimationFrame failed: {:?}", e))?;
+    }
+
+    info!("🔁 Web run loop started on canvas: {}", canvas_id);
+    Ok(RunLoop { running, listeners, observer, _on_resize: on_resize })
+}
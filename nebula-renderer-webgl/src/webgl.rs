@@ -1,10 +1,23 @@
 //! WebGL 2.0 Context - Modern browser rendering! 🚀
 
+use std::collections::HashMap;
+
+use nebula_gfx::draw_list::{ClipStack, DrawCommand, DrawList, ImageId, Rect, Rgba};
+use nebula_gfx::RgbaImage;
 use wasm_bindgen::JsCast;
-use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
+use web_sys::{
+    CanvasRenderingContext2d, HtmlCanvasElement, WebGl2RenderingContext, WebGlBuffer, WebGlProgram,
+    WebGlShader, WebGlTexture, WebGlUniformLocation, WebGlVertexArrayObject,
+};
 use tracing::{info, error};
 
+use crate::atlas::{quad_vertices, GlyphAtlas, GlyphKey};
+use crate::encoding::{clamp_radius, css_font, image_source_rect, is_empty_rect, line_height};
+
+type Gl = WebGl2RenderingContext;
+
 /// WebGL 2.0 Context
+#[derive(Debug)]
 pub struct WebGL2Context {
     canvas: HtmlCanvasElement,
     gl: WebGl2RenderingContext,
@@ -65,6 +78,462 @@ impl WebGL2Context {
     }
 }
 
+/// Rect and rounded-rect shader: one SDF covers both (radius 0 = sharp corners)
+const SHAPE_VERTEX_SHADER: &str = r#"#version 300 es
+in vec2 aPos;
+in vec2 aLocal;
+out vec2 vLocal;
+void main() {
+    vLocal = aLocal;
+    gl_Position = vec4(aPos, 0.0, 1.0);
+}
+"#;
+
+const SHAPE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision mediump float;
+in vec2 vLocal;
+uniform vec2 uHalfSize;
+uniform float uRadius;
+uniform vec4 uColor;
+out vec4 fragColor;
+void main() {
+    vec2 q = abs(vLocal) - uHalfSize + uRadius;
+    float dist = length(max(q, 0.0)) + min(max(q.x, q.y), 0.0) - uRadius;
+    float coverage = clamp(0.5 - dist, 0.0, 1.0);
+    fragColor = vec4(uColor.rgb, uColor.a * coverage);
+}
+"#;
+
+/// Textured quads: images (white tint) and glyphs from the atlas (text color tint)
+const TEXTURE_VERTEX_SHADER: &str = r#"#version 300 es
+in vec2 aPos;
+in vec2 aUv;
+out vec2 vUv;
+void main() {
+    vUv = aUv;
+    gl_Position = vec4(aPos, 0.0, 1.0);
+}
+"#;
+
+const TEXTURE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision mediump float;
+in vec2 vUv;
+uniform sampler2D uTexture;
+uniform vec4 uTint;
+uniform float uOpacity;
+out vec4 fragColor;
+void main() {
+    vec4 texel = texture(uTexture, vUv);
+    fragColor = vec4(texel.rgb * uTint.rgb, texel.a * uTint.a * uOpacity);
+}
+"#;
+
+/// Size of the glyph atlas texture
+const ATLAS_SIZE: u32 = 1024;
+
+/// Uploaded image texture and its size
+#[derive(Debug)]
+struct ImageTexture {
+    texture: WebGlTexture,
+    width: u32,
+    height: u32,
+}
+
+/// Shaders, buffers, textures and glyph atlas for replaying a DrawList 🎨
+#[derive(Debug)]
+pub struct WebGL2Pipeline {
+    shape_program: WebGlProgram,
+    texture_program: WebGlProgram,
+    vao: WebGlVertexArrayObject,
+    buffer: WebGlBuffer,
+    images: HashMap<ImageId, ImageTexture>,
+    atlas: GlyphAtlas,
+    atlas_texture: WebGlTexture,
+    glyph_canvas: HtmlCanvasElement,
+    glyph_ctx: CanvasRenderingContext2d,
+    clips: ClipStack,
+}
+
+impl WebGL2Pipeline {
+    /// Compile shaders and create the shared buffers
+    pub fn new(gl: &Gl) -> Result<Self, String> {
+        info!("🎨 Creating WebGL 2.0 draw pipeline");
+        let shape_program = link_program(gl, SHAPE_VERTEX_SHADER, SHAPE_FRAGMENT_SHADER)?;
+        let texture_program = link_program(gl, TEXTURE_VERTEX_SHADER, TEXTURE_FRAGMENT_SHADER)?;
+        let vao = gl.create_vertex_array().ok_or("Failed to create vertex array")?;
+        let buffer = gl.create_buffer().ok_or("Failed to create vertex buffer")?;
+
+        let atlas_texture = create_texture(gl, ATLAS_SIZE, ATLAS_SIZE, None)?;
+
+        // Glyphs are rasterized by the browser into a small scratch canvas
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("No document object")?;
+        let glyph_canvas = document
+            .create_element("canvas")
+            .map_err(|_| "Failed to create glyph canvas".to_string())?
+            .dyn_into::<HtmlCanvasElement>()
+            .map_err(|_| "Element is not a canvas".to_string())?;
+        let glyph_ctx = glyph_canvas
+            .get_context("2d")
+            .map_err(|_| "Failed to get 2D context".to_string())?
+            .ok_or("2D context is null")?
+            .dyn_into::<CanvasRenderingContext2d>()
+            .map_err(|_| "Failed to cast to 2D context".to_string())?;
+
+        Ok(Self {
+            shape_program,
+            texture_program,
+            vao,
+            buffer,
+            images: HashMap::new(),
+            atlas: GlyphAtlas::new(ATLAS_SIZE, ATLAS_SIZE),
+            atlas_texture,
+            glyph_canvas,
+            glyph_ctx,
+            clips: ClipStack::new(),
+        })
+    }
+
+    /// Upload (or replace) an image
+    pub fn upload_image(&mut self, gl: &Gl, id: ImageId, image: &RgbaImage) -> Result<(), String> {
+        if let Some(old) = self.images.remove(&id) {
+            gl.delete_texture(Some(&old.texture));
+        }
+        let texture = create_texture(gl, image.width, image.height, Some(&image.pixels))?;
+        self.images.insert(id, ImageTexture { texture, width: image.width, height: image.height });
+        Ok(())
+    }
+
+    /// Free an uploaded image
+    pub fn remove_image(&mut self, gl: &Gl, id: ImageId) {
+        if let Some(old) = self.images.remove(&id) {
+            gl.delete_texture(Some(&old.texture));
+        }
+    }
+
+    /// Replay a draw list onto the canvas
+    pub fn execute(&mut self, gl: &Gl, list: &DrawList, viewport: (u32, u32)) -> Result<(), String> {
+        gl.viewport(0, 0, viewport.0 as i32, viewport.1 as i32);
+        gl.enable(Gl::BLEND);
+        gl.blend_func(Gl::SRC_ALPHA, Gl::ONE_MINUS_SRC_ALPHA);
+        gl.bind_vertex_array(Some(&self.vao));
+        gl.bind_buffer(Gl::ARRAY_BUFFER, Some(&self.buffer));
+        self.clips = ClipStack::new();
+        gl.disable(Gl::SCISSOR_TEST);
+
+        for command in list.commands() {
+            match command {
+                DrawCommand::Clear(color) => {
+                    let [r, g, b, a] = normalize(*color);
+                    gl.clear_color(r, g, b, a);
+                    gl.clear(Gl::COLOR_BUFFER_BIT);
+                }
+                DrawCommand::Rect { rect, color } => self.draw_shape(gl, *rect, 0.0, *color, viewport),
+                DrawCommand::RoundedRect { rect, radius, color } => {
+                    self.draw_shape(gl, *rect, *radius, *color, viewport)
+                }
+                DrawCommand::Text { text, x, y, size, color } => {
+                    self.draw_text(gl, text, *x, *y, *size, *color, viewport)?
+                }
+                DrawCommand::Image { image, src, dst, opacity } => {
+                    self.draw_image(gl, *image, *src, *dst, *opacity, viewport)?
+                }
+                DrawCommand::PushClip(rect) => {
+                    let clip = self.clips.push(*rect);
+                    apply_scissor(gl, Some(clip), viewport);
+                }
+                DrawCommand::PopClip => {
+                    let clip = self.clips.pop();
+                    apply_scissor(gl, clip, viewport);
+                }
+            }
+        }
+
+        gl.disable(Gl::SCISSOR_TEST);
+        gl.bind_vertex_array(None);
+        Ok(())
+    }
+
+    fn draw_shape(&self, gl: &Gl, rect: Rect, radius: f32, color: Rgba, viewport: (u32, u32)) {
+        if is_empty_rect(rect) {
+            return;
+        }
+        let (half_w, half_h) = (rect.2 / 2.0, rect.3 / 2.0);
+        // Local coordinates run from -half to +half, in pixels
+        let vertices = quad_vertices(rect, (-half_w, -half_h, half_w, half_h), viewport);
+
+        gl.use_program(Some(&self.shape_program));
+        self.upload_vertices(gl, &self.shape_program, &vertices, "aLocal");
+        let [r, g, b, a] = normalize(color);
+        gl.uniform4f(uniform(gl, &self.shape_program, "uColor").as_ref(), r, g, b, a);
+        gl.uniform2f(uniform(gl, &self.shape_program, "uHalfSize").as_ref(), half_w, half_h);
+        gl.uniform1f(uniform(gl, &self.shape_program, "uRadius").as_ref(), clamp_radius(rect, radius));
+        gl.draw_arrays(Gl::TRIANGLES, 0, 6);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_text(
+        &mut self,
+        gl: &Gl,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Rgba,
+        viewport: (u32, u32),
+    ) -> Result<(), String> {
+        let mut vertices = Vec::with_capacity(text.len() * 24);
+        let mut pen_x = x;
+        for ch in text.chars() {
+            let key = GlyphKey::new(ch, size);
+            let glyph = match self.atlas.get(key) {
+                Some(glyph) => glyph,
+                None => self.rasterize_glyph(gl, key)?,
+            };
+            if !ch.is_whitespace() {
+                let rect = (pen_x, y, glyph.width as f32, glyph.height as f32);
+                vertices.extend_from_slice(&quad_vertices(rect, glyph.uv(ATLAS_SIZE, ATLAS_SIZE), viewport));
+            }
+            pen_x += glyph.advance;
+        }
+        if vertices.is_empty() {
+            return Ok(());
+        }
+
+        gl.use_program(Some(&self.texture_program));
+        self.upload_vertices(gl, &self.texture_program, &vertices, "aUv");
+        gl.active_texture(Gl::TEXTURE0);
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&self.atlas_texture));
+        gl.uniform1i(uniform(gl, &self.texture_program, "uTexture").as_ref(), 0);
+        let [r, g, b, a] = normalize(color);
+        gl.uniform4f(uniform(gl, &self.texture_program, "uTint").as_ref(), r, g, b, a);
+        gl.uniform1f(uniform(gl, &self.texture_program, "uOpacity").as_ref(), 1.0);
+        gl.draw_arrays(Gl::TRIANGLES, 0, (vertices.len() / 4) as i32);
+        Ok(())
+    }
+
+    fn draw_image(
+        &self,
+        gl: &Gl,
+        id: ImageId,
+        src: Option<Rect>,
+        dst: Rect,
+        opacity: f32,
+        viewport: (u32, u32),
+    ) -> Result<(), String> {
+        let image = self.images.get(&id).ok_or_else(|| format!("Image {:?} was not uploaded", id))?;
+        let (w, h) = (image.width as f32, image.height as f32);
+        let (sx, sy, sw, sh) = image_source_rect(src, image.width, image.height);
+        if is_empty_rect((sx, sy, sw, sh)) || is_empty_rect(dst) {
+            return Ok(());
+        }
+        let uv = (sx / w, sy / h, (sx + sw) / w, (sy + sh) / h);
+        let vertices = quad_vertices(dst, uv, viewport);
+
+        gl.use_program(Some(&self.texture_program));
+        self.upload_vertices(gl, &self.texture_program, &vertices, "aUv");
+        gl.active_texture(Gl::TEXTURE0);
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&image.texture));
+        gl.uniform1i(uniform(gl, &self.texture_program, "uTexture").as_ref(), 0);
+        gl.uniform4f(uniform(gl, &self.texture_program, "uTint").as_ref(), 1.0, 1.0, 1.0, 1.0);
+        gl.uniform1f(uniform(gl, &self.texture_program, "uOpacity").as_ref(), opacity);
+        gl.draw_arrays(Gl::TRIANGLES, 0, 6);
+        Ok(())
+    }
+
+    /// Rasterize a glyph with the browser's font engine and copy it into the atlas
+    fn rasterize_glyph(&mut self, gl: &Gl, key: GlyphKey) -> Result<crate::atlas::AtlasGlyph, String> {
+        let font = css_font(key.size_px as f32);
+        let text = key.ch.to_string();
+        self.glyph_ctx.set_font(&font);
+        let advance = self
+            .glyph_ctx
+            .measure_text(&text)
+            .map_err(|_| "Failed to measure glyph".to_string())?
+            .width() as f32;
+
+        // Line box: the glyph is drawn with a top baseline, plus room for descenders
+        let width = (advance.ceil() as u32).max(1);
+        let height = line_height(key.size_px as f32) as u32;
+
+        let glyph = match self.atlas.insert(key, width, height, advance) {
+            Some(glyph) => glyph,
+            None => {
+                // Atlas full: start over (cached glyphs get re-rasterized on demand)
+                self.atlas.reset();
+                self.atlas
+                    .insert(key, width, height, advance)
+                    .ok_or_else(|| format!("Glyph {:?} is larger than the atlas", key))?
+            }
+        };
+
+        if self.glyph_canvas.width() < width || self.glyph_canvas.height() < height {
+            self.glyph_canvas.set_width(self.glyph_canvas.width().max(width));
+            self.glyph_canvas.set_height(self.glyph_canvas.height().max(height));
+        }
+        // Resizing the canvas resets its state
+        self.glyph_ctx.set_font(&font);
+        self.glyph_ctx.set_text_baseline("top");
+        self.glyph_ctx.clear_rect(0.0, 0.0, width as f64, height as f64);
+        self.glyph_ctx.set_fill_style(&"#ffffff".into());
+        self.glyph_ctx
+            .fill_text(&text, 0.0, 0.0)
+            .map_err(|_| "Failed to rasterize glyph".to_string())?;
+        let pixels = self
+            .glyph_ctx
+            .get_image_data(0.0, 0.0, width as f64, height as f64)
+            .map_err(|_| "Failed to read glyph pixels".to_string())?
+            .data()
+            .0;
+
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&self.atlas_texture));
+        gl.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_u8_array(
+            Gl::TEXTURE_2D,
+            0,
+            glyph.x as i32,
+            glyph.y as i32,
+            width as i32,
+            height as i32,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE,
+            Some(&pixels),
+        )
+        .map_err(|_| "Failed to upload glyph".to_string())?;
+
+        Ok(glyph)
+    }
+
+    /// Upload interleaved (x, y, a, b) vertices and point both attributes at them
+    fn upload_vertices(&self, gl: &Gl, program: &WebGlProgram, vertices: &[f32], second: &str) {
+        let data = js_sys::Float32Array::from(vertices);
+        gl.buffer_data_with_array_buffer_view(Gl::ARRAY_BUFFER, &data, Gl::STREAM_DRAW);
+
+        let stride = 4 * std::mem::size_of::<f32>() as i32;
+        for (name, offset) in [("aPos", 0), (second, 2 * std::mem::size_of::<f32>() as i32)] {
+            let location = gl.get_attrib_location(program, name);
+            if location < 0 {
+                continue;
+            }
+            gl.enable_vertex_attrib_array(location as u32);
+            gl.vertex_attrib_pointer_with_i32(location as u32, 2, Gl::FLOAT, false, stride, offset);
+        }
+    }
+}
+
+fn normalize(color: Rgba) -> [f32; 4] {
+    [
+        color.0 as f32 / 255.0,
+        color.1 as f32 / 255.0,
+        color.2 as f32 / 255.0,
+        color.3 as f32 / 255.0,
+    ]
+}
+
+fn uniform(gl: &Gl, program: &WebGlProgram, name: &str) -> Option<WebGlUniformLocation> {
+    gl.get_uniform_location(program, name)
+}
+
+/// Scissor uses bottom-left origin
+fn apply_scissor(gl: &Gl, clip: Option<Rect>, viewport: (u32, u32)) {
+    match clip {
+        Some((x, y, w, h)) => {
+            gl.enable(Gl::SCISSOR_TEST);
+            gl.scissor(
+                x.floor() as i32,
+                (viewport.1 as f32 - (y + h)).floor() as i32,
+                w.ceil() as i32,
+                h.ceil() as i32,
+            );
+        }
+        None => gl.disable(Gl::SCISSOR_TEST),
+    }
+}
+
+fn compile_shader(gl: &Gl, shader_type: u32, source: &str) -> Result<WebGlShader, String> {
+    let shader = gl.create_shader(shader_type).ok_or("Failed to create shader")?;
+    gl.shader_source(&shader, source);
+    gl.compile_shader(&shader);
+
+    if gl.get_shader_parameter(&shader, Gl::COMPILE_STATUS).as_bool().unwrap_or(false) {
+        Ok(shader)
+    } else {
+        let log = gl.get_shader_info_log(&shader).unwrap_or_default();
+        gl.delete_shader(Some(&shader));
+        error!("❌ Shader compilation failed: {}", log);
+        Err(format!("Shader compilation failed: {}", log))
+    }
+}
+
+fn link_program(gl: &Gl, vertex: &str, fragment: &str) -> Result<WebGlProgram, String> {
+    let vertex = compile_shader(gl, Gl::VERTEX_SHADER, vertex)?;
+    let fragment = compile_shader(gl, Gl::FRAGMENT_SHADER, fragment)?;
+    let program = gl.create_program().ok_or("Failed to create program")?;
+    gl.attach_shader(&program, &vertex);
+    gl.attach_shader(&program, &fragment);
+    gl.link_program(&program);
+    gl.delete_shader(Some(&vertex));
+    gl.delete_shader(Some(&fragment));
+
+    if gl.get_program_parameter(&program, Gl::LINK_STATUS).as_bool().unwrap_or(false) {
+        Ok(program)
+    } else {
+        let log = gl.get_program_info_log(&program).unwrap_or_default();
+        error!("❌ Program link failed: {}", log);
+        Err(format!("Program link failed: {}", log))
+    }
+}
+
+/// RGBA8 texture with linear filtering and clamped edges
+fn create_texture(gl: &Gl, width: u32, height: u32, pixels: Option<&[u8]>) -> Result<WebGlTexture, String> {
+    let texture = gl.create_texture().ok_or("Failed to create texture")?;
+    gl.bind_texture(Gl::TEXTURE_2D, Some(&texture));
+    gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        Gl::TEXTURE_2D,
+        0,
+        Gl::RGBA as i32,
+        width as i32,
+        height as i32,
+        0,
+        Gl::RGBA,
+        Gl::UNSIGNED_BYTE,
+        pixels,
+    )
+    .map_err(|_| "Failed to upload texture".to_string())?;
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, Gl::LINEAR as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, Gl::LINEAR as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
+    Ok(texture)
+}
+
+/// Read back the canvas as RGBA8 (rows top to bottom)
+/// The context must be created with preserveDrawingBuffer, or this must run
+/// in the same task that rendered the frame
+pub fn capture_frame(canvas_id: &str) -> Result<nebula_gfx::RgbaImage, String> {
+    let context = WebGL2Context::new(canvas_id)?;
+    let width = context.canvas.width();
+    let height = context.canvas.height();
+
+    let mut image = nebula_gfx::RgbaImage::new(width, height);
+    context
+        .gl
+        .read_pixels_with_opt_u8_array(
+            0,
+            0,
+            width as i32,
+            height as i32,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            Some(&mut image.pixels),
+        )
+        .map_err(|_| "Failed to read WebGL pixels".to_string())?;
+
+    // readPixels starts at the bottom row
+    image.flip_vertical();
+    Ok(image)
+}
+
 /// Clear the canvas (convenience function)
 pub fn clear(canvas_id: &str, r: f32, g: f32, b: f32, a: f32) -> Result<(), String> {
     let context = WebGL2Context::new(canvas_id)?;
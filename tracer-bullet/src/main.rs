@@ -1,8 +1,8 @@
 use nebula_core::Signal;
 use nebula_platform::{
-    InputHandler, Key, MouseButtonEvent, MousePosition, NebulaWindow, RenderCallback,
+    InputHandler, Key, MouseButtonEvent, MousePosition, NebulaWindow, RenderCallback, RgbaImage,
 };
-use nebula_renderer_cpu::{Color, CpuRenderer};
+use nebula_renderer_cpu::{Color, CpuRenderer, Renderer};
 use std::sync::{Arc, Mutex};
 use tracing_subscriber;
 use winit::window::Window;
@@ -104,6 +104,17 @@ impl InputHandler for App {
 }
 
 impl RenderCallback for App {
+    fn capture_frame(&mut self) -> Option<RgbaImage> {
+        let mut renderer = self.renderer.lock().unwrap();
+        renderer.as_mut()?.capture_frame().ok()
+    }
+
+    fn on_scale_factor_changed(&mut self, scale_factor: f64) {
+        if let Some(renderer) = self.renderer.lock().unwrap().as_mut() {
+            renderer.set_scale_factor(scale_factor);
+        }
+    }
+
     fn render(&mut self, window: &Window) {
         // Initialize renderer on first render
         if self.handles.is_none() {
@@ -118,6 +129,7 @@ impl RenderCallback for App {
                     Ok(mut renderer) => {
                         // Set initial color from signal! 🌟
                         renderer.set_clear_color(self.background_color.get());
+                        renderer.set_scale_factor(window.scale_factor());
                         *self.renderer.lock().unwrap() = Some(renderer);
                         tracing::info!("🌌 CPU Renderer initialized successfully!");
                         tracing::info!("🎨 Initial color: Nebula Blue (#0A0E17)!");
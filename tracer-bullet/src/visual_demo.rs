@@ -12,7 +12,7 @@ use nebula_core::Signal;
 use nebula_platform::{
     InputHandler, Key, MouseButtonEvent, MousePosition, NebulaWindow, RenderCallback,
 };
-use nebula_renderer_cpu::{Color, CpuRenderer};
+use nebula_renderer_cpu::{Color, CpuRenderer, Renderer};
 use std::sync::{Arc, Mutex};
 use tracing_subscriber;
 use winit::window::Window;